@@ -24,6 +24,29 @@ mod m20260429_000005_create_release_plans;
 mod m20260429_000006_create_release_plan_repositories;
 mod m20260429_000007_add_notifications_chat_id_to_repositories;
 mod m20260429_000008_pending_notifications_user_id_nullable;
+mod m20261018_000001_add_version_control_type_to_repositories;
+mod m20261018_000002_create_webhook_deliveries;
+mod m20261018_000003_add_repository_to_webhook_deliveries;
+mod m20261018_000004_add_webhook_secret_to_repositories;
+mod m20261018_000005_create_commit_checks;
+mod m20261018_000006_create_environment_deployments;
+mod m20261018_000007_add_ref_events_filter_to_repositories;
+mod m20261018_000008_create_merged_branches;
+mod m20261018_000009_add_is_archived_to_repositories;
+mod m20261018_000010_add_kind_to_repository_task_trackers;
+mod m20261018_000011_change_repository_task_tracker_ids_to_string;
+mod m20261018_000012_create_task_tracker_connections;
+mod m20261018_000013_add_connection_id_to_repository_task_trackers;
+mod m20261018_000014_create_repository_task_tracker_rules;
+mod m20261018_000015_create_task_tracker_moves;
+mod m20261018_000016_create_pull_request_task_links;
+mod m20261018_000017_add_merge_state_to_pull_request_task_links;
+mod m20261018_000018_add_require_task_id_to_repository_task_trackers;
+mod m20261018_000019_add_slack_channel_id_to_repositories;
+mod m20261018_000020_create_slack_messages;
+mod m20261018_000021_add_qa_outcome_columns_to_repository_task_trackers;
+mod m20261018_000022_add_issue_column_id_to_repository_task_trackers;
+mod m20261018_000026_create_user_slack_accounts;
 
 pub struct Migrator;
 
@@ -59,6 +82,31 @@ impl MigratorTrait for Migrator {
             Box::new(m20260429_000006_create_release_plan_repositories::Migration),
             Box::new(m20260429_000007_add_notifications_chat_id_to_repositories::Migration),
             Box::new(m20260429_000008_pending_notifications_user_id_nullable::Migration),
+            Box::new(m20261018_000001_add_version_control_type_to_repositories::Migration),
+            Box::new(m20261018_000002_create_webhook_deliveries::Migration),
+            Box::new(m20261018_000003_add_repository_to_webhook_deliveries::Migration),
+            Box::new(m20261018_000004_add_webhook_secret_to_repositories::Migration),
+            Box::new(m20261018_000005_create_commit_checks::Migration),
+            Box::new(m20261018_000006_create_environment_deployments::Migration),
+            Box::new(m20261018_000007_add_ref_events_filter_to_repositories::Migration),
+            Box::new(m20261018_000008_create_merged_branches::Migration),
+            Box::new(m20261018_000009_add_is_archived_to_repositories::Migration),
+            Box::new(m20261018_000010_add_kind_to_repository_task_trackers::Migration),
+            Box::new(m20261018_000011_change_repository_task_tracker_ids_to_string::Migration),
+            Box::new(m20261018_000012_create_task_tracker_connections::Migration),
+            Box::new(m20261018_000013_add_connection_id_to_repository_task_trackers::Migration),
+            Box::new(m20261018_000014_create_repository_task_tracker_rules::Migration),
+            Box::new(m20261018_000015_create_task_tracker_moves::Migration),
+            Box::new(m20261018_000016_create_pull_request_task_links::Migration),
+            Box::new(m20261018_000017_add_merge_state_to_pull_request_task_links::Migration),
+            Box::new(m20261018_000018_add_require_task_id_to_repository_task_trackers::Migration),
+            Box::new(m20261018_000019_add_slack_channel_id_to_repositories::Migration),
+            Box::new(m20261018_000020_create_slack_messages::Migration),
+            Box::new(
+                m20261018_000021_add_qa_outcome_columns_to_repository_task_trackers::Migration,
+            ),
+            Box::new(m20261018_000022_add_issue_column_id_to_repository_task_trackers::Migration),
+            Box::new(m20261018_000026_create_user_slack_accounts::Migration),
         ]
     }
 }
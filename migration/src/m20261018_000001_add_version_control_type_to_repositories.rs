@@ -0,0 +1,88 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Все существующие репозитории — GitHub
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Repositories::Table)
+                    .add_column(
+                        ColumnDef::new(Repositories::VersionControlType)
+                            .string_len(64)
+                            .not_null()
+                            .default("github"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Зеркало GitHub-репозитория в Gitea имеет те же owner/name
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-repositories-provider-owner-name-unique")
+                    .table(Repositories::Table)
+                    .col(Repositories::VersionControlType)
+                    .col(Repositories::Owner)
+                    .col(Repositories::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-repositories-owner-name-unique")
+                    .table(Repositories::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-repositories-owner-name-unique")
+                    .table(Repositories::Table)
+                    .col(Repositories::Owner)
+                    .col(Repositories::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-repositories-provider-owner-name-unique")
+                    .table(Repositories::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Repositories::Table)
+                    .drop_column(Repositories::VersionControlType)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Repositories {
+    Table,
+    VersionControlType,
+    Owner,
+    Name,
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Секреты хранятся зашифрованными (ReversibleCipher), NULL — глобальный секрет
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Repositories::Table)
+                    .add_column(ColumnDef::new(Repositories::WebhookSecret).text().null())
+                    .add_column(
+                        ColumnDef::new(Repositories::PreviousWebhookSecret)
+                            .text()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Repositories::WebhookSecretRotatedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Repositories::Table)
+                    .drop_column(Repositories::WebhookSecret)
+                    .drop_column(Repositories::PreviousWebhookSecret)
+                    .drop_column(Repositories::WebhookSecretRotatedAt)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Repositories {
+    Table,
+    WebhookSecret,
+    PreviousWebhookSecret,
+    WebhookSecretRotatedAt,
+}
@@ -0,0 +1,187 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Состояние каждой проверки (check_run / check_suite) на коммите
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommitChecks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommitChecks::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::Repo)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::HeadSha)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::CheckKey)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CommitChecks::Kind).string_len(16).not_null())
+                    .col(
+                        ColumnDef::new(CommitChecks::Name)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::CheckSuiteId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::Status)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::Conclusion)
+                            .string_len(32)
+                            .null(),
+                    )
+                    .col(ColumnDef::new(CommitChecks::HtmlUrl).text().null())
+                    .col(
+                        ColumnDef::new(CommitChecks::HeadBranch)
+                            .string_len(255)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::PrNumbers)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CommitChecks::Actor).string_len(64).null())
+                    .col(
+                        ColumnDef::new(CommitChecks::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitChecks::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .extra("ON UPDATE CURRENT_TIMESTAMP")
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_commit_checks_repo_sha_key")
+                            .col(CommitChecks::Repo)
+                            .col(CommitChecks::HeadSha)
+                            .col(CommitChecks::CheckKey)
+                            .unique(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_commit_checks_updated_at")
+                            .col(CommitChecks::UpdatedAt),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Последнее отправленное сводное состояние — одно событие на смену состояния SHA
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommitCiStatuses::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommitCiStatuses::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitCiStatuses::Repo)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitCiStatuses::HeadSha)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitCiStatuses::ReportedState)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitCiStatuses::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .extra("ON UPDATE CURRENT_TIMESTAMP")
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_commit_ci_statuses_repo_sha")
+                            .col(CommitCiStatuses::Repo)
+                            .col(CommitCiStatuses::HeadSha)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommitCiStatuses::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(CommitChecks::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommitChecks {
+    Table,
+    Id,
+    Repo,
+    HeadSha,
+    CheckKey,
+    Kind,
+    Name,
+    CheckSuiteId,
+    Status,
+    Conclusion,
+    HtmlUrl,
+    HeadBranch,
+    PrNumbers,
+    Actor,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum CommitCiStatuses {
+    Table,
+    Id,
+    Repo,
+    HeadSha,
+    ReportedState,
+    UpdatedAt,
+}
@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Последний успешный деплой в каждое окружение репозитория
+        manager
+            .create_table(
+                Table::create()
+                    .table(EnvironmentDeployments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::VersionControlType)
+                            .string_len(64)
+                            .not_null()
+                            .default("github"),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::Repo)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::Environment)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::DeploymentId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::Sha)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::RefName)
+                            .string_len(255)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::DeployedBy)
+                            .string_len(64)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::EnvironmentUrl)
+                            .text()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::DeployedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EnvironmentDeployments::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .extra("ON UPDATE CURRENT_TIMESTAMP")
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_environment_deployments_provider_repo_environment")
+                            .col(EnvironmentDeployments::VersionControlType)
+                            .col(EnvironmentDeployments::Repo)
+                            .col(EnvironmentDeployments::Environment)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(EnvironmentDeployments::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum EnvironmentDeployments {
+    Table,
+    Id,
+    VersionControlType,
+    Repo,
+    Environment,
+    DeploymentId,
+    Sha,
+    RefName,
+    DeployedBy,
+    EnvironmentUrl,
+    DeployedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Head-ветки смерженных PR — для напоминания удалить ветку
+        manager
+            .create_table(
+                Table::create()
+                    .table(MergedBranches::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MergedBranches::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MergedBranches::VersionControlType)
+                            .string_len(64)
+                            .not_null()
+                            .default("github"),
+                    )
+                    .col(
+                        ColumnDef::new(MergedBranches::Repo)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MergedBranches::Branch)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MergedBranches::PrNumber)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MergedBranches::PrUrl).text().null())
+                    .col(
+                        ColumnDef::new(MergedBranches::AuthorLogin)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MergedBranches::MergedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MergedBranches::RemindedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(MergedBranches::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_merged_branches_provider_repo_branch")
+                            .col(MergedBranches::VersionControlType)
+                            .col(MergedBranches::Repo)
+                            .col(MergedBranches::Branch)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MergedBranches::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MergedBranches {
+    Table,
+    Id,
+    VersionControlType,
+    Repo,
+    Branch,
+    PrNumber,
+    PrUrl,
+    AuthorLogin,
+    MergedAt,
+    RemindedAt,
+    CreatedAt,
+}
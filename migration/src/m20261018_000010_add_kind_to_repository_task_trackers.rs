@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Все существующие настройки трекера — Kaiten
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .add_column(
+                        ColumnDef::new(RepositoryTaskTracker::Kind)
+                            .string_len(32)
+                            .not_null()
+                            .default("kaiten"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .drop_column(RepositoryTaskTracker::Kind)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RepositoryTaskTracker {
+    Table,
+    Kind,
+}
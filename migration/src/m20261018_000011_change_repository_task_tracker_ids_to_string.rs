@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Linear использует UUID для команд и статусов — числовые id Kaiten/Jira
+        // переводятся в строки без потерь
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .modify_column(
+                        ColumnDef::new(RepositoryTaskTracker::SpaceId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(RepositoryTaskTracker::QaColumnId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .modify_column(
+                        ColumnDef::new(RepositoryTaskTracker::SpaceId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(RepositoryTaskTracker::QaColumnId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RepositoryTaskTracker {
+    Table,
+    SpaceId,
+    QaColumnId,
+}
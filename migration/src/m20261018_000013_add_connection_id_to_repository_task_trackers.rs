@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // NULL — клиент трекера из переменных окружения. Подключение, на котором
+        // висят репозитории, удалить нельзя — иначе они молча сменят трекер
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .add_column(
+                        ColumnDef::new(RepositoryTaskTracker::ConnectionId)
+                            .integer()
+                            .null(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_task_tracker_connection")
+                            .from_tbl(RepositoryTaskTracker::Table)
+                            .from_col(RepositoryTaskTracker::ConnectionId)
+                            .to_tbl(TaskTrackerConnections::Table)
+                            .to_col(TaskTrackerConnections::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .drop_foreign_key(Alias::new("fk_task_tracker_connection"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .drop_column(RepositoryTaskTracker::ConnectionId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RepositoryTaskTracker {
+    Table,
+    ConnectionId,
+}
+
+#[derive(DeriveIden)]
+enum TaskTrackerConnections {
+    Table,
+    Id,
+}
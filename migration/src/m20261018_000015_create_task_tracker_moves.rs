@@ -0,0 +1,100 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Журнал автоматических переводов карточек. rule_id без FK —
+        // запись остаётся после удаления правила
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskTrackerMoves::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskTrackerMoves::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskTrackerMoves::RepositoryId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TaskTrackerMoves::RuleId).integer().null())
+                    .col(
+                        ColumnDef::new(TaskTrackerMoves::Trigger)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskTrackerMoves::TaskId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskTrackerMoves::ColumnId)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TaskTrackerMoves::PrNumber)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(TaskTrackerMoves::Error).text().null())
+                    .col(
+                        ColumnDef::new(TaskTrackerMoves::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ttm_repository")
+                            .from(TaskTrackerMoves::Table, TaskTrackerMoves::RepositoryId)
+                            .to(Repositories::Table, Repositories::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_task_tracker_moves_repository_created")
+                            .col(TaskTrackerMoves::RepositoryId)
+                            .col(TaskTrackerMoves::CreatedAt),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskTrackerMoves::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskTrackerMoves {
+    Table,
+    Id,
+    RepositoryId,
+    RuleId,
+    Trigger,
+    TaskId,
+    ColumnId,
+    PrNumber,
+    Error,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Repositories {
+    Table,
+    Id,
+}
@@ -0,0 +1,121 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Какие задачи трекера упомянуты в PR — по ним входящие события
+        // трекера находят автора PR
+        manager
+            .create_table(
+                Table::create()
+                    .table(PullRequestTaskLinks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::RepositoryId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::TaskId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::PrNumber)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::PrTitle)
+                            .string_len(512)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::PrUrl)
+                            .string_len(512)
+                            .null(),
+                    )
+                    // GitLab присылает только author_id — логин автора MR известен не всегда
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::AuthorLogin)
+                            .string_len(255)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PullRequestTaskLinks::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .extra("ON UPDATE CURRENT_TIMESTAMP")
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_prtl_repository")
+                            .from(
+                                PullRequestTaskLinks::Table,
+                                PullRequestTaskLinks::RepositoryId,
+                            )
+                            .to(Repositories::Table, Repositories::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_pull_request_task_links")
+                            .col(PullRequestTaskLinks::RepositoryId)
+                            .col(PullRequestTaskLinks::PrNumber)
+                            .col(PullRequestTaskLinks::TaskId)
+                            .unique(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_pull_request_task_links_task_id")
+                            .col(PullRequestTaskLinks::TaskId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PullRequestTaskLinks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PullRequestTaskLinks {
+    Table,
+    Id,
+    RepositoryId,
+    TaskId,
+    PrNumber,
+    PrTitle,
+    PrUrl,
+    AuthorLogin,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Repositories {
+    Table,
+    Id,
+}
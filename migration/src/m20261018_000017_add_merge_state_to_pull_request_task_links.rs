@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // head_sha — по нему ищется статус CI, merged_at заполняется при мерже PR
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PullRequestTaskLinks::Table)
+                    .add_column(
+                        ColumnDef::new(PullRequestTaskLinks::HeadSha)
+                            .string_len(64)
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(PullRequestTaskLinks::MergedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PullRequestTaskLinks::Table)
+                    .drop_column(PullRequestTaskLinks::HeadSha)
+                    .drop_column(PullRequestTaskLinks::MergedAt)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum PullRequestTaskLinks {
+    Table,
+    HeadSha,
+    MergedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Проверка ID задачи в PR выключена, пока её не включат в админке
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .add_column(
+                        ColumnDef::new(RepositoryTaskTracker::RequireTaskId)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .drop_column(RepositoryTaskTracker::RequireTaskId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RepositoryTaskTracker {
+    Table,
+    RequireTaskId,
+}
@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Отправленные в Slack сообщения: по ним правятся и удаляются дубли из Telegram.
+        // Без `social_chat_id` — сообщение ушло в Slack напрямую
+        manager
+            .create_table(
+                Table::create()
+                    .table(SlackMessages::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SlackMessages::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SlackMessages::SocialChatId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SlackMessages::SocialMessageId)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SlackMessages::SlackChannelId)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SlackMessages::SlackTs)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SlackMessages::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_slack_messages_social_message")
+                            .col(SlackMessages::SocialChatId)
+                            .col(SlackMessages::SocialMessageId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SlackMessages::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SlackMessages {
+    Table,
+    Id,
+    SocialChatId,
+    SocialMessageId,
+    SlackChannelId,
+    SlackTs,
+    CreatedAt,
+}
@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Куда `/test_queue` переводит карточку после тестирования. NULL — кнопка скрыта
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .add_column(
+                        ColumnDef::new(RepositoryTaskTracker::QaPassedColumnId)
+                            .string()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(RepositoryTaskTracker::QaFailedColumnId)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RepositoryTaskTracker::Table)
+                    .drop_column(RepositoryTaskTracker::QaPassedColumnId)
+                    .drop_column(RepositoryTaskTracker::QaFailedColumnId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RepositoryTaskTracker {
+    Table,
+    QaPassedColumnId,
+    QaFailedColumnId,
+}
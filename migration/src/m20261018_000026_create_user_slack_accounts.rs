@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Slack-аккаунт привязывается только после подтверждения кодом из Slack
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserSlackAccounts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserSlackAccounts::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserSlackAccounts::UserId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserSlackAccounts::SlackUserId)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserSlackAccounts::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserSlackAccounts::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .default(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+                            .extra("ON UPDATE CURRENT_TIMESTAMP")
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_slack_accounts_user")
+                            .from(UserSlackAccounts::Table, UserSlackAccounts::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Slack ID в соцаккаунтах сохранялись без проверки владельца — не переносим
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(UserSocialAccounts::Table)
+                    .and_where(Expr::col(UserSocialAccounts::SocialType).eq("slack"))
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserSlackAccounts::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserSlackAccounts {
+    Table,
+    Id,
+    UserId,
+    SlackUserId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum UserSocialAccounts {
+    Table,
+    SocialType,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
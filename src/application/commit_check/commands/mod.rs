@@ -0,0 +1,2 @@
+pub mod prune_commit_checks;
+pub mod record_commit_check;
@@ -0,0 +1,32 @@
+use crate::application::commit_check::commands::prune_commit_checks::command::PruneCommitChecksExecutorCommand;
+use crate::application::commit_check::commands::prune_commit_checks::error::PruneCommitChecksExecutorError;
+use crate::application::commit_check::commands::prune_commit_checks::response::PruneCommitChecksExecutorResponse;
+use crate::domain::commit_check::repositories::commit_check_repository::CommitCheckRepository;
+use crate::domain::shared::command::CommandExecutor;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Состояние проверок нужно, пока по коммиту идёт CI, — старое не храним
+const RETENTION_DAYS: i64 = 30;
+
+pub struct PruneCommitChecksExecutor {
+    pub commit_check_repo: Arc<dyn CommitCheckRepository>,
+}
+
+impl CommandExecutor for PruneCommitChecksExecutor {
+    type Command = PruneCommitChecksExecutorCommand;
+    type Response = PruneCommitChecksExecutorResponse;
+    type Error = PruneCommitChecksExecutorError;
+
+    async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let before = Utc::now() - Duration::days(RETENTION_DAYS);
+
+        let deleted_count = self
+            .commit_check_repo
+            .delete_older_than(before)
+            .await
+            .map_err(|e| PruneCommitChecksExecutorError::DbError(e.to_string()))?;
+
+        Ok(PruneCommitChecksExecutorResponse { deleted_count })
+    }
+}
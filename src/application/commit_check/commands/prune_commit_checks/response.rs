@@ -0,0 +1,3 @@
+pub struct PruneCommitChecksExecutorResponse {
+    pub deleted_count: u64,
+}
@@ -0,0 +1,5 @@
+use crate::domain::commit_check::entities::commit_check::CommitCheck;
+
+pub struct RecordCommitCheckExecutorCommand {
+    pub check: CommitCheck,
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RecordCommitCheckExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Failed to publish event: {0}")]
+    PublishError(String),
+}
@@ -0,0 +1,123 @@
+use crate::application::commit_check::commands::record_commit_check::command::RecordCommitCheckExecutorCommand;
+use crate::application::commit_check::commands::record_commit_check::error::RecordCommitCheckExecutorError;
+use crate::application::commit_check::commands::record_commit_check::response::RecordCommitCheckExecutorResponse;
+use crate::domain::commit_check::entities::commit_checks_summary::CommitChecksSummary;
+use crate::domain::commit_check::events::checks_completed::CommitChecksCompletedEvent;
+use crate::domain::commit_check::repositories::commit_check_repository::CommitCheckRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use std::sync::Arc;
+
+pub struct RecordCommitCheckExecutor {
+    pub commit_check_repo: Arc<dyn CommitCheckRepository>,
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+}
+
+impl CommandExecutor for RecordCommitCheckExecutor {
+    type Command = RecordCommitCheckExecutorCommand;
+    type Response = RecordCommitCheckExecutorResponse;
+    type Error = RecordCommitCheckExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let check = &cmd.check;
+
+        self.commit_check_repo
+            .upsert(check)
+            .await
+            .map_err(|e| RecordCommitCheckExecutorError::DbError(e.to_string()))?;
+
+        let checks = self
+            .commit_check_repo
+            .find_by_commit(&check.repo, &check.head_sha)
+            .await
+            .map_err(|e| RecordCommitCheckExecutorError::DbError(e.to_string()))?;
+
+        let summary = CommitChecksSummary::from_checks(&checks);
+
+        if !summary.state.is_terminal() {
+            return Ok(RecordCommitCheckExecutorResponse {
+                summary,
+                reported: false,
+            });
+        }
+
+        let reported_state = self
+            .commit_check_repo
+            .find_reported_state(&check.repo, &check.head_sha)
+            .await
+            .map_err(|e| RecordCommitCheckExecutorError::DbError(e.to_string()))?;
+
+        // Одно событие на SHA, пока итог не изменится (например, после перезапуска)
+        if reported_state == Some(summary.state) {
+            return Ok(RecordCommitCheckExecutorResponse {
+                summary,
+                reported: false,
+            });
+        }
+
+        // Сначала атомарно занимаем итог, чтобы параллельный check_run по тому
+        // же SHA не опубликовал событие второй раз
+        let claimed = self
+            .commit_check_repo
+            .save_reported_state(&check.repo, &check.head_sha, summary.state)
+            .await
+            .map_err(|e| RecordCommitCheckExecutorError::DbError(e.to_string()))?;
+
+        if !claimed {
+            return Ok(RecordCommitCheckExecutorResponse {
+                summary,
+                reported: false,
+            });
+        }
+
+        tracing::info!(
+            repo = %check.repo,
+            sha = %check.head_sha,
+            state = %summary.state,
+            total = summary.total,
+            failing = summary.failing.len(),
+            "Commit checks completed"
+        );
+
+        let published = self
+            .publisher
+            .publish(&CommitChecksCompletedEvent {
+                repo: check.repo.clone(),
+                head_sha: check.head_sha.clone(),
+                head_branch: summary.head_branch.clone(),
+                pr_numbers: summary.pr_numbers.clone(),
+                actor: summary.actor.clone(),
+                state: summary.state,
+                total: summary.total,
+                failing: summary.failing.clone(),
+            })
+            .await;
+
+        // Публикация упала — возвращаем прежний итог, чтобы событие ушло со
+        // следующим check_run, а не потерялось
+        if let Err(e) = published {
+            if let Err(restore_err) = self
+                .commit_check_repo
+                .restore_reported_state(&check.repo, &check.head_sha, summary.state, reported_state)
+                .await
+            {
+                tracing::error!(
+                    error = %restore_err,
+                    repo = %check.repo,
+                    sha = %check.head_sha,
+                    "Failed to restore reported commit checks state"
+                );
+            }
+
+            return Err(RecordCommitCheckExecutorError::PublishError(format!(
+                "{:?}",
+                e
+            )));
+        }
+
+        Ok(RecordCommitCheckExecutorResponse {
+            summary,
+            reported: true,
+        })
+    }
+}
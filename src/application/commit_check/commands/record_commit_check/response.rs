@@ -0,0 +1,7 @@
+use crate::domain::commit_check::entities::commit_checks_summary::CommitChecksSummary;
+
+pub struct RecordCommitCheckExecutorResponse {
+    pub summary: CommitChecksSummary,
+    /// Итоговое состояние сменилось и событие опубликовано
+    pub reported: bool,
+}
@@ -0,0 +1,5 @@
+use crate::domain::deployment::entities::environment_deployment::EnvironmentDeployment;
+
+pub struct RecordDeploymentExecutorCommand {
+    pub deployment: EnvironmentDeployment,
+}
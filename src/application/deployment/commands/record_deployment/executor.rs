@@ -0,0 +1,32 @@
+use crate::application::deployment::commands::record_deployment::command::RecordDeploymentExecutorCommand;
+use crate::application::deployment::commands::record_deployment::error::RecordDeploymentExecutorError;
+use crate::application::deployment::commands::record_deployment::response::RecordDeploymentExecutorResponse;
+use crate::domain::deployment::repositories::environment_deployment_repository::EnvironmentDeploymentRepository;
+use crate::domain::shared::command::CommandExecutor;
+use std::sync::Arc;
+
+pub struct RecordDeploymentExecutor {
+    pub environment_deployment_repo: Arc<dyn EnvironmentDeploymentRepository>,
+}
+
+impl CommandExecutor for RecordDeploymentExecutor {
+    type Command = RecordDeploymentExecutorCommand;
+    type Response = RecordDeploymentExecutorResponse;
+    type Error = RecordDeploymentExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        self.environment_deployment_repo
+            .upsert(&cmd.deployment)
+            .await
+            .map_err(|e| RecordDeploymentExecutorError::DbError(e.to_string()))?;
+
+        tracing::info!(
+            repo = %cmd.deployment.repo,
+            environment = %cmd.deployment.environment,
+            sha = %cmd.deployment.sha,
+            "Deployment recorded"
+        );
+
+        Ok(RecordDeploymentExecutorResponse {})
+    }
+}
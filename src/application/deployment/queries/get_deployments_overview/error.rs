@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetDeploymentsOverviewError {
+    #[error("User not found")]
+    UserNotFound,
+    #[error("Database error: {0}")]
+    DbError(String),
+}
@@ -0,0 +1,221 @@
+use crate::application::deployment::queries::get_deployments_overview::error::GetDeploymentsOverviewError;
+use crate::application::deployment::queries::get_deployments_overview::query::GetDeploymentsOverviewQuery;
+use crate::application::deployment::queries::get_deployments_overview::response::{
+    GetDeploymentsOverviewResponse, RepositoryDeploymentsOverview, UndeployedPullRequest,
+};
+use crate::domain::deployment::entities::environment_deployment::EnvironmentDeployment;
+use crate::domain::deployment::repositories::environment_deployment_repository::EnvironmentDeploymentRepository;
+use crate::domain::deployment::value_objects::environment_kind::EnvironmentKind;
+use crate::domain::repository::entities::repository::Repository;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::repositories::user_connection_repositories_repository::UserConnectionRepositoriesRepository;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client::ComparedCommit;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use std::sync::Arc;
+
+pub struct GetDeploymentsOverviewExecutor {
+    user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    user_connection_repositories_repo: Arc<dyn UserConnectionRepositoriesRepository>,
+    repository_repo: Arc<dyn RepositoryRepository>,
+    environment_deployment_repo: Arc<dyn EnvironmentDeploymentRepository>,
+    version_control_clients: Arc<dyn VersionControlClientProvider>,
+    version_control_tokens: Arc<dyn VersionControlTokenProvider>,
+    reversible_cipher: Arc<ReversibleCipher>,
+}
+
+impl GetDeploymentsOverviewExecutor {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+        user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+        user_connection_repositories_repo: Arc<dyn UserConnectionRepositoriesRepository>,
+        repository_repo: Arc<dyn RepositoryRepository>,
+        environment_deployment_repo: Arc<dyn EnvironmentDeploymentRepository>,
+        version_control_clients: Arc<dyn VersionControlClientProvider>,
+        version_control_tokens: Arc<dyn VersionControlTokenProvider>,
+        reversible_cipher: Arc<ReversibleCipher>,
+    ) -> Self {
+        Self {
+            user_socials_repo,
+            user_vc_accounts_repo,
+            user_connection_repositories_repo,
+            repository_repo,
+            environment_deployment_repo,
+            version_control_clients,
+            version_control_tokens,
+            reversible_cipher,
+        }
+    }
+
+    /// PR из коммитов между production-SHA и основной веткой
+    async fn undeployed_pull_requests(
+        &self,
+        repo: &Repository,
+        production: &EnvironmentDeployment,
+        user_token: Option<&(VersionControlType, String)>,
+    ) -> Option<Vec<UndeployedPullRequest>> {
+        // OAuth-токен пользователя годится только для его же провайдера
+        let token = match user_token {
+            Some((provider, token)) if *provider == repo.version_control_type => token.clone(),
+            _ => self.version_control_tokens.service_token(repo).await?,
+        };
+
+        let client = self
+            .version_control_clients
+            .client(&repo.version_control_type);
+
+        let branch = match client
+            .get_default_branch(&token, &repo.owner, &repo.name)
+            .await
+        {
+            Ok(branch) => branch,
+            Err(e) => {
+                tracing::warn!(
+                    repo = %format!("{}/{}", repo.owner, repo.name),
+                    error = %e,
+                    "Failed to resolve default branch"
+                );
+                return None;
+            }
+        };
+
+        let commits = match client
+            .compare_commits(&token, &repo.owner, &repo.name, &production.sha, &branch)
+            .await
+        {
+            Ok(commits) => commits,
+            Err(e) => {
+                tracing::warn!(
+                    repo = %format!("{}/{}", repo.owner, repo.name),
+                    base = %production.sha,
+                    head = %branch,
+                    error = %e,
+                    "Failed to compare production with default branch"
+                );
+                return None;
+            }
+        };
+
+        let mut prs: Vec<UndeployedPullRequest> = Vec::new();
+        // Сравнение отдаёт коммиты от старых к новым — показываем свежие сверху
+        for commit in commits.iter().rev() {
+            let Some((number, title)) = parse_pull_request(commit) else {
+                continue;
+            };
+            if prs.iter().any(|pr| pr.number == number) {
+                continue;
+            }
+            prs.push(UndeployedPullRequest {
+                number,
+                title,
+                url: repo.pull_request_url(number),
+                author_login: commit.author_login.clone(),
+            });
+        }
+
+        Some(prs)
+    }
+}
+
+impl CommandExecutor for GetDeploymentsOverviewExecutor {
+    type Command = GetDeploymentsOverviewQuery;
+    type Response = GetDeploymentsOverviewResponse;
+    type Error = GetDeploymentsOverviewError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let social = self
+            .user_socials_repo
+            .find_by_social_user_id(&cmd.social_user_id)
+            .await
+            .map_err(|_| GetDeploymentsOverviewError::UserNotFound)?;
+
+        // Без привязанного аккаунта всё равно показываем окружения из БД
+        let user_token = match self
+            .user_vc_accounts_repo
+            .find_by_user_id(&VersionControlType::Github, &social.user_id)
+            .await
+        {
+            Ok(vc) => self
+                .reversible_cipher
+                .decrypt(vc.access_token.value())
+                .ok()
+                .map(|token| (vc.version_control_type, token)),
+            Err(_) => None,
+        };
+
+        let connections = self
+            .user_connection_repositories_repo
+            .find_by_user_id(social.user_id)
+            .await
+            .map_err(|e| GetDeploymentsOverviewError::DbError(e.to_string()))?;
+
+        let mut repositories = Vec::with_capacity(connections.len());
+
+        for conn in connections {
+            let Ok(repo) = self.repository_repo.find_by_id(conn.repository_id).await else {
+                continue;
+            };
+            let full_name = format!("{}/{}", repo.owner, repo.name);
+
+            let mut deployments = self
+                .environment_deployment_repo
+                .find_by_repo(&repo.version_control_type, &full_name)
+                .await
+                .map_err(|e| GetDeploymentsOverviewError::DbError(e.to_string()))?;
+
+            deployments.sort_by(|a, b| {
+                a.kind()
+                    .cmp(&b.kind())
+                    .then_with(|| a.environment.cmp(&b.environment))
+            });
+
+            let undeployed = match deployments
+                .iter()
+                .find(|d| d.kind() == EnvironmentKind::Production)
+            {
+                Some(production) => {
+                    self.undeployed_pull_requests(&repo, production, user_token.as_ref())
+                        .await
+                }
+                None => None,
+            };
+
+            repositories.push(RepositoryDeploymentsOverview {
+                repo: full_name,
+                deployments,
+                undeployed,
+            });
+        }
+
+        Ok(GetDeploymentsOverviewResponse { repositories })
+    }
+}
+
+/// Номер и заголовок PR из сообщения коммита:
+/// merge-коммит `Merge pull request #12 from ...` или squash `Title (#12)`.
+/// Rebase-мерж следов PR не оставляет — такие коммиты пропускаем.
+fn parse_pull_request(commit: &ComparedCommit) -> Option<(u64, String)> {
+    let mut lines = commit.message.lines();
+    let headline = lines.next()?.trim();
+
+    if let Some(rest) = headline.strip_prefix("Merge pull request #") {
+        let number = rest.split_whitespace().next()?.parse().ok()?;
+        let title = lines
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or(headline)
+            .to_string();
+        return Some((number, title));
+    }
+
+    let open = headline.rfind("(#")?;
+    let number = headline[open + 2..].strip_suffix(')')?.parse().ok()?;
+    Some((number, headline[..open].trim().to_string()))
+}
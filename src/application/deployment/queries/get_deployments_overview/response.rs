@@ -0,0 +1,23 @@
+use crate::domain::deployment::entities::environment_deployment::EnvironmentDeployment;
+
+/// PR, смерженный в основную ветку, но ещё не выкаченный в production
+#[derive(Debug, Clone)]
+pub struct UndeployedPullRequest {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub author_login: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RepositoryDeploymentsOverview {
+    pub repo: String, // owner/repo
+    /// Production, затем staging, затем остальные окружения по имени
+    pub deployments: Vec<EnvironmentDeployment>,
+    /// `None` — production-деплоя нет или сравнение не удалось
+    pub undeployed: Option<Vec<UndeployedPullRequest>>,
+}
+
+pub struct GetDeploymentsOverviewResponse {
+    pub repositories: Vec<RepositoryDeploymentsOverview>,
+}
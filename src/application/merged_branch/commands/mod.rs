@@ -0,0 +1 @@
+pub mod send_branch_cleanup_reminders;
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SendBranchCleanupRemindersExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
@@ -0,0 +1,200 @@
+use crate::application::merged_branch::commands::send_branch_cleanup_reminders::command::SendBranchCleanupRemindersExecutorCommand;
+use crate::application::merged_branch::commands::send_branch_cleanup_reminders::error::SendBranchCleanupRemindersExecutorError;
+use crate::application::merged_branch::commands::send_branch_cleanup_reminders::response::SendBranchCleanupRemindersExecutorResponse;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::merged_branch::entities::merged_branch::MergedBranch;
+use crate::domain::merged_branch::repositories::merged_branch_repository::MergedBranchRepository;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+pub struct SendBranchCleanupRemindersExecutor {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub merged_branch_repo: Arc<dyn MergedBranchRepository>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    pub user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub version_control_tokens: Arc<dyn VersionControlTokenProvider>,
+    /// 0 — напоминания выключены
+    pub reminder_days: i64,
+}
+
+impl SendBranchCleanupRemindersExecutor {
+    fn build_message(&self, branch: &MergedBranch) -> MessageBuilder {
+        let days = (Utc::now() - branch.merged_at).num_days();
+
+        let mut msg = MessageBuilder::new()
+            .bold(t!("telegram_bot.notifications.branch_cleanup.title").as_ref())
+            .empty_line()
+            .with_html_escape(true)
+            .section(
+                t!("telegram_bot.notifications.branch_cleanup.repository").as_ref(),
+                &branch.repo,
+            )
+            .section_code(
+                t!("telegram_bot.notifications.branch_cleanup.branch").as_ref(),
+                &branch.branch,
+            )
+            .section(
+                t!("telegram_bot.notifications.branch_cleanup.pr").as_ref(),
+                &format!("#{}", branch.pr_number),
+            )
+            .section(
+                t!("telegram_bot.notifications.branch_cleanup.merged").as_ref(),
+                t!(
+                    "telegram_bot.notifications.branch_cleanup.merged_days_ago",
+                    days = days
+                )
+                .as_ref(),
+            )
+            .with_html_escape(false)
+            .empty_line()
+            .line(t!("telegram_bot.notifications.branch_cleanup.hint").as_ref());
+
+        if let Some(url) = branch.pr_url.as_deref().filter(|u| !u.is_empty()) {
+            msg = msg.empty_line().raw(&format!(
+                "<a href=\"{}\">{}</a>",
+                MessageBuilder::escape_html(url),
+                t!("telegram_bot.notifications.branch_cleanup.open")
+            ));
+        }
+
+        msg
+    }
+}
+
+impl CommandExecutor for SendBranchCleanupRemindersExecutor {
+    type Command = SendBranchCleanupRemindersExecutorCommand;
+    type Response = SendBranchCleanupRemindersExecutorResponse;
+    type Error = SendBranchCleanupRemindersExecutorError;
+
+    async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let mut response = SendBranchCleanupRemindersExecutorResponse {
+            sent_count: 0,
+            cleaned_count: 0,
+        };
+
+        if self.reminder_days <= 0 {
+            return Ok(response);
+        }
+
+        let branches = self
+            .merged_branch_repo
+            .find_unreminded_merged_before(Utc::now() - Duration::days(self.reminder_days))
+            .await
+            .map_err(|e| SendBranchCleanupRemindersExecutorError::DbError(e.to_string()))?;
+
+        if branches.is_empty() {
+            return Ok(response);
+        }
+
+        for branch in branches {
+            let Some((owner, name)) = branch.repo.rsplit_once('/') else {
+                continue;
+            };
+
+            // Репозиторий удалили из бота — следить за веткой больше некому
+            let repository = match self
+                .repository_repo
+                .find_by_owner_and_name(&branch.version_control_type, owner, name)
+                .await
+            {
+                Ok(r) => r,
+                Err(_) => {
+                    self.merged_branch_repo
+                        .delete(&branch.version_control_type, &branch.repo, &branch.branch)
+                        .await
+                        .ok();
+                    continue;
+                }
+            };
+
+            let Some(token) = self
+                .version_control_tokens
+                .background_token(&repository)
+                .await
+            else {
+                tracing::debug!(
+                    repo = %branch.repo,
+                    "No token available — skipping branch cleanup check"
+                );
+                continue;
+            };
+
+            let exists = match self
+                .version_control_clients
+                .client(&repository.version_control_type)
+                .branch_exists(&token, owner, name, &branch.branch)
+                .await
+            {
+                Ok(exists) => exists,
+                Err(e) => {
+                    tracing::warn!(
+                        repo = %branch.repo,
+                        branch = %branch.branch,
+                        error = %e,
+                        "Failed to check merged branch existence"
+                    );
+                    continue;
+                }
+            };
+
+            if !exists {
+                self.merged_branch_repo
+                    .delete(&branch.version_control_type, &branch.repo, &branch.branch)
+                    .await
+                    .map_err(|e| SendBranchCleanupRemindersExecutorError::DbError(e.to_string()))?;
+                response.cleaned_count += 1;
+                continue;
+            }
+
+            let social = match self
+                .user_vc_accounts_repo
+                .find_by_login(&repository.version_control_type, &branch.author_login)
+                .await
+            {
+                Ok(vc) => self
+                    .user_socials_repo
+                    .find_by_user_id(&vc.user_id)
+                    .await
+                    .ok(),
+                Err(_) => None,
+            };
+
+            // Автор не привязан — напоминание всё равно считаем отправленным,
+            // чтобы не проверять ветку каждый день
+            if let Some(social) = social {
+                self.publisher
+                    .publish(&SendSocialNotifyJob {
+                        social_type: SocialType::Telegram,
+                        chat_id: social.social_chat_id,
+                        message: self.build_message(&branch),
+                    })
+                    .await
+                    .ok();
+                response.sent_count += 1;
+            }
+
+            self.merged_branch_repo
+                .mark_reminded(
+                    &branch.version_control_type,
+                    &branch.repo,
+                    &branch.branch,
+                    Utc::now(),
+                )
+                .await
+                .map_err(|e| SendBranchCleanupRemindersExecutorError::DbError(e.to_string()))?;
+        }
+
+        Ok(response)
+    }
+}
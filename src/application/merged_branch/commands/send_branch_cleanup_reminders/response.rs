@@ -0,0 +1,5 @@
+pub struct SendBranchCleanupRemindersExecutorResponse {
+    pub sent_count: usize,
+    /// Ветки, которые успели удалить без события `delete`
+    pub cleaned_count: usize,
+}
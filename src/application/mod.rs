@@ -1,6 +1,9 @@
 pub mod auth;
+pub mod commit_check;
+pub mod deployment;
 pub mod digest;
 pub mod health_ping;
+pub mod merged_branch;
 pub mod monitoring;
 pub mod notification;
 pub mod release_plan;
@@ -1,5 +1,6 @@
 pub mod buffer_notification;
 pub mod flush_pending_notifications;
+pub mod prune_slack_messages;
 pub mod scan_pr_conflicts;
 pub mod scan_stale_pull_requests;
 pub mod send_social_notify;
@@ -0,0 +1,32 @@
+use crate::application::notification::commands::prune_slack_messages::command::PruneSlackMessagesExecutorCommand;
+use crate::application::notification::commands::prune_slack_messages::error::PruneSlackMessagesExecutorError;
+use crate::application::notification::commands::prune_slack_messages::response::PruneSlackMessagesExecutorResponse;
+use crate::domain::notification::repositories::slack_message_repository::SlackMessageRepository;
+use crate::domain::shared::command::CommandExecutor;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Старые уведомления не правят и не удаляют — их ссылки в Slack не храним
+const RETENTION_DAYS: i64 = 30;
+
+pub struct PruneSlackMessagesExecutor {
+    pub slack_message_repo: Arc<dyn SlackMessageRepository>,
+}
+
+impl CommandExecutor for PruneSlackMessagesExecutor {
+    type Command = PruneSlackMessagesExecutorCommand;
+    type Response = PruneSlackMessagesExecutorResponse;
+    type Error = PruneSlackMessagesExecutorError;
+
+    async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let before = Utc::now() - Duration::days(RETENTION_DAYS);
+
+        let deleted_count = self
+            .slack_message_repo
+            .delete_older_than(before)
+            .await
+            .map_err(|e| PruneSlackMessagesExecutorError::DbError(e.to_string()))?;
+
+        Ok(PruneSlackMessagesExecutorResponse { deleted_count })
+    }
+}
@@ -0,0 +1,3 @@
+pub struct PruneSlackMessagesExecutorResponse {
+    pub deleted_count: u64,
+}
@@ -4,16 +4,14 @@ use crate::application::notification::commands::scan_pr_conflicts::response::Sca
 use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
 use crate::domain::notification_log::repositories::notification_log_repository::NotificationLogRepository;
 use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
-use crate::domain::role::value_objects::role_name::RoleName;
 use crate::domain::shared::command::CommandExecutor;
-use crate::domain::user::repositories::user_has_roles_repository::UserHasRolesRepository;
 use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
 use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
 use crate::domain::user::value_objects::social_type::SocialType;
-use crate::domain::version_control::ports::version_control_client::VersionControlClient;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
 use crate::utils::builder::message::MessageBuilder;
-use crate::utils::security::crypto::reversible::ReversibleCipher;
 use chrono::{Duration, Utc};
 use std::sync::Arc;
 
@@ -24,29 +22,10 @@ pub struct ScanPrConflictsExecutor {
     pub publisher: Arc<dyn MessageBrokerPublisher>,
     pub repository_repo: Arc<dyn RepositoryRepository>,
     pub notification_log_repo: Arc<dyn NotificationLogRepository>,
-    pub user_has_roles_repo: Arc<dyn UserHasRolesRepository>,
     pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
     pub user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
-    pub version_control_client: Arc<dyn VersionControlClient>,
-    pub reversible_cipher: Arc<ReversibleCipher>,
-}
-
-impl ScanPrConflictsExecutor {
-    async fn admin_token(&self) -> Option<String> {
-        let admin_ids = self
-            .user_has_roles_repo
-            .find_user_ids_by_role(RoleName::Admin)
-            .await
-            .ok()?;
-        for user_id in admin_ids {
-            if let Ok(vc) = self.user_vc_accounts_repo.find_by_user_id(&user_id).await {
-                if let Ok(token) = self.reversible_cipher.decrypt(vc.access_token.value()) {
-                    return Some(token);
-                }
-            }
-        }
-        None
-    }
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub version_control_tokens: Arc<dyn VersionControlTokenProvider>,
 }
 
 impl CommandExecutor for ScanPrConflictsExecutor {
@@ -55,17 +34,6 @@ impl CommandExecutor for ScanPrConflictsExecutor {
     type Error = ScanPrConflictsExecutorError;
 
     async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
-        let token = match self.admin_token().await {
-            Some(t) => t,
-            None => {
-                tracing::debug!("No admin token available — skipping PR conflict scan");
-                return Ok(ScanPrConflictsExecutorResponse {
-                    repos_scanned: 0,
-                    conflicts_count: 0,
-                });
-            }
-        };
-
         let repos = self
             .repository_repo
             .find_all()
@@ -76,9 +44,22 @@ impl CommandExecutor for ScanPrConflictsExecutor {
         let mut repos_scanned = 0usize;
         let since = Utc::now() - Duration::hours(COOLDOWN_HOURS);
 
-        for repo in repos {
-            let prs = match self
-                .version_control_client
+        for repo in repos.into_iter().filter(|r| !r.is_archived) {
+            let token = match self.version_control_tokens.background_token(&repo).await {
+                Some(t) => t,
+                None => {
+                    tracing::debug!(
+                        repo = %format!("{}/{}", repo.owner, repo.name),
+                        "No token available — skipping PR conflict scan"
+                    );
+                    continue;
+                }
+            };
+            let client = self
+                .version_control_clients
+                .client(&repo.version_control_type);
+
+            let prs = match client
                 .list_open_pull_requests(&token, &repo.owner, &repo.name)
                 .await
             {
@@ -97,8 +78,7 @@ impl CommandExecutor for ScanPrConflictsExecutor {
             let repo_full = format!("{}/{}", repo.owner, repo.name);
 
             for pr in prs {
-                let mergeable = match self
-                    .version_control_client
+                let mergeable = match client
                     .get_pr_mergeable_state(&token, &repo.owner, &repo.name, pr.number)
                     .await
                 {
@@ -120,7 +100,7 @@ impl CommandExecutor for ScanPrConflictsExecutor {
 
                 let vc_account = match self
                     .user_vc_accounts_repo
-                    .find_by_login(&pr.author_login)
+                    .find_by_login(&repo.version_control_type, &pr.author_login)
                     .await
                 {
                     Ok(a) => a,
@@ -4,18 +4,16 @@ use crate::application::notification::commands::scan_stale_pull_requests::respon
 use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
 use crate::domain::pr_review::repositories::pr_review_repository::PrReviewRepository;
 use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
-use crate::domain::role::value_objects::role_name::RoleName;
 use crate::domain::shared::command::CommandExecutor;
-use crate::domain::user::repositories::user_has_roles_repository::UserHasRolesRepository;
 use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
 use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
 use crate::domain::user::value_objects::social_type::SocialType;
-use crate::domain::version_control::ports::version_control_client::{
-    OpenPullRequestSummary, VersionControlClient,
-};
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client::OpenPullRequestSummary;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
 use crate::utils::builder::message::MessageBuilder;
-use crate::utils::security::crypto::reversible::ReversibleCipher;
 use chrono::{Duration, Utc};
 use std::sync::Arc;
 
@@ -23,35 +21,22 @@ pub struct ScanStalePullRequestsExecutor {
     pub publisher: Arc<dyn MessageBrokerPublisher>,
     pub repository_repo: Arc<dyn RepositoryRepository>,
     pub pr_review_repo: Arc<dyn PrReviewRepository>,
-    pub user_has_roles_repo: Arc<dyn UserHasRolesRepository>,
     pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
     pub user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
-    pub version_control_client: Arc<dyn VersionControlClient>,
-    pub reversible_cipher: Arc<ReversibleCipher>,
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub version_control_tokens: Arc<dyn VersionControlTokenProvider>,
     pub stale_threshold_hours: i64,
 }
 
 impl ScanStalePullRequestsExecutor {
-    async fn admin_token(&self) -> Option<String> {
-        let admin_ids = self
-            .user_has_roles_repo
-            .find_user_ids_by_role(RoleName::Admin)
-            .await
-            .ok()?;
-        for user_id in admin_ids {
-            if let Ok(vc) = self.user_vc_accounts_repo.find_by_user_id(&user_id).await {
-                if let Ok(token) = self.reversible_cipher.decrypt(vc.access_token.value()) {
-                    return Some(token);
-                }
-            }
-        }
-        None
-    }
-
-    async fn resolve_tg_username(&self, github_login: &str) -> Option<String> {
+    async fn resolve_tg_username(
+        &self,
+        version_control_type: &VersionControlType,
+        login: &str,
+    ) -> Option<String> {
         let vc = self
             .user_vc_accounts_repo
-            .find_by_login(github_login)
+            .find_by_login(version_control_type, login)
             .await
             .ok()?;
         let social = self
@@ -69,17 +54,6 @@ impl CommandExecutor for ScanStalePullRequestsExecutor {
     type Error = ScanStalePullRequestsExecutorError;
 
     async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
-        let token = match self.admin_token().await {
-            Some(t) => t,
-            None => {
-                tracing::debug!("No admin token available — skipping stale PR scan");
-                return Ok(ScanStalePullRequestsExecutorResponse {
-                    repos_scanned: 0,
-                    stale_count: 0,
-                });
-            }
-        };
-
         let repos = self
             .repository_repo
             .find_all()
@@ -91,14 +65,27 @@ impl CommandExecutor for ScanStalePullRequestsExecutor {
         let mut stale_total = 0usize;
         let mut repos_scanned = 0usize;
 
-        for repo in repos {
+        for repo in repos.into_iter().filter(|r| !r.is_archived) {
             let chat_id = match repo.notifications_chat_id.or(repo.social_chat_id) {
                 Some(c) => c,
                 None => continue,
             };
 
+            // Сервисный токен (Gitea, GitHub App), без него — токен администратора
+            let token = match self.version_control_tokens.background_token(&repo).await {
+                Some(t) => t,
+                None => {
+                    tracing::debug!(
+                        repo = %format!("{}/{}", repo.owner, repo.name),
+                        "No token available — skipping stale PR scan"
+                    );
+                    continue;
+                }
+            };
+
             let prs = match self
-                .version_control_client
+                .version_control_clients
+                .client(&repo.version_control_type)
                 .list_open_pull_requests(&token, &repo.owner, &repo.name)
                 .await
             {
@@ -160,7 +147,10 @@ impl CommandExecutor for ScanStalePullRequestsExecutor {
 
                 let mut tags: Vec<String> = Vec::new();
                 for login in &pr.requested_reviewers {
-                    let display = match self.resolve_tg_username(login).await {
+                    let display = match self
+                        .resolve_tg_username(&repo.version_control_type, login)
+                        .await
+                    {
                         Some(tg) => format!("@{}", tg),
                         None => login.clone(),
                     };
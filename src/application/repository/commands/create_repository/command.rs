@@ -1,5 +1,8 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+
 pub struct CreateRepositoryCommand {
     pub name: String,
     pub owner: String,
     pub url: String,
+    pub version_control_type: VersionControlType,
 }
@@ -46,8 +46,15 @@ impl CommandExecutor for CreateRepositoryExecutor {
             name: cmd.name.clone(),
             owner: cmd.owner.clone(),
             url: cmd.url.clone(),
+            version_control_type: cmd.version_control_type.clone(),
             social_chat_id: None,
             notifications_chat_id: None,
+            webhook_secret: None,
+            previous_webhook_secret: None,
+            webhook_secret_rotated_at: None,
+            ref_events_filter: None,
+            is_archived: false,
+            slack_channel_id: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
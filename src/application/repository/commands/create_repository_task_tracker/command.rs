@@ -1,9 +1,12 @@
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 
 pub struct CreateRepositoryTaskTrackerCommand {
     pub repository_id: RepositoryId,
-    pub space_id: i32,
-    pub qa_column_id: i32,
+    pub space_id: String,
+    pub qa_column_id: String,
     pub extract_pattern_regexp: String,
     pub path_to_card: String,
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
 }
@@ -43,10 +43,16 @@ impl CommandExecutor for CreateRepositoryTaskTrackerExecutor {
         let placeholder = RepositoryTaskTracker {
             id: Default::default(),
             repository_id: cmd.repository_id.clone(),
-            space_id: cmd.space_id,
-            qa_column_id: cmd.qa_column_id,
+            space_id: cmd.space_id.clone(),
+            qa_column_id: cmd.qa_column_id.clone(),
             extract_pattern_regexp: cmd.extract_pattern_regexp.clone(),
             path_to_card: cmd.path_to_card.clone(),
+            kind: cmd.kind,
+            connection_id: cmd.connection_id,
+            require_task_id: false,
+            qa_passed_column_id: None,
+            qa_failed_column_id: None,
+            issue_column_id: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -0,0 +1,9 @@
+use crate::domain::repository::value_objects::pull_request_lifecycle_trigger::PullRequestLifecycleTrigger;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+
+pub struct CreateRepositoryTaskTrackerRuleCommand {
+    pub repository_id: RepositoryId,
+    pub trigger: PullRequestLifecycleTrigger,
+    pub column_id: String,
+    pub base_branch_pattern: Option<String>,
+}
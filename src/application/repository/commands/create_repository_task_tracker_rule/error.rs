@@ -0,0 +1,16 @@
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::CreateRepositoryTaskTrackerRuleError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CreateRepositoryTaskTrackerRuleExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<CreateRepositoryTaskTrackerRuleError> for CreateRepositoryTaskTrackerRuleExecutorError {
+    fn from(e: CreateRepositoryTaskTrackerRuleError) -> Self {
+        match e {
+            CreateRepositoryTaskTrackerRuleError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
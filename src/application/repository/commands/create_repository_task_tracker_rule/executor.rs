@@ -0,0 +1,52 @@
+use crate::application::repository::commands::create_repository_task_tracker_rule::command::CreateRepositoryTaskTrackerRuleCommand;
+use crate::application::repository::commands::create_repository_task_tracker_rule::error::CreateRepositoryTaskTrackerRuleExecutorError;
+use crate::application::repository::commands::create_repository_task_tracker_rule::response::CreateRepositoryTaskTrackerRuleResponse;
+use crate::domain::repository::entities::repository_task_tracker_rule::RepositoryTaskTrackerRule;
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::RepositoryTaskTrackerRuleRepository;
+use crate::domain::shared::command::CommandExecutor;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub struct CreateRepositoryTaskTrackerRuleExecutor {
+    repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
+}
+
+impl CreateRepositoryTaskTrackerRuleExecutor {
+    pub fn new(
+        repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
+    ) -> Self {
+        Self {
+            repository_task_tracker_rule_repo,
+        }
+    }
+}
+
+impl CommandExecutor for CreateRepositoryTaskTrackerRuleExecutor {
+    type Command = CreateRepositoryTaskTrackerRuleCommand;
+    type Response = CreateRepositoryTaskTrackerRuleResponse;
+    type Error = CreateRepositoryTaskTrackerRuleExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let now = Utc::now();
+
+        let rule = self
+            .repository_task_tracker_rule_repo
+            .create(&RepositoryTaskTrackerRule {
+                id: 0,
+                repository_id: cmd.repository_id,
+                trigger: cmd.trigger,
+                column_id: cmd.column_id.clone(),
+                base_branch_pattern: cmd
+                    .base_branch_pattern
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|p| !p.is_empty())
+                    .map(str::to_string),
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+
+        Ok(CreateRepositoryTaskTrackerRuleResponse { rule })
+    }
+}
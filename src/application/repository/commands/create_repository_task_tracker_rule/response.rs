@@ -0,0 +1,5 @@
+use crate::domain::repository::entities::repository_task_tracker_rule::RepositoryTaskTrackerRule;
+
+pub struct CreateRepositoryTaskTrackerRuleResponse {
+    pub rule: RepositoryTaskTrackerRule,
+}
@@ -0,0 +1,3 @@
+pub struct DeleteRepositoryTaskTrackerRuleCommand {
+    pub id: i32,
+}
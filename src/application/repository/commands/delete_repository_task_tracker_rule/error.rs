@@ -0,0 +1,20 @@
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::DeleteRepositoryTaskTrackerRuleError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeleteRepositoryTaskTrackerRuleExecutorError {
+    #[error("Repository task tracker rule not found")]
+    NotFound,
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<DeleteRepositoryTaskTrackerRuleError> for DeleteRepositoryTaskTrackerRuleExecutorError {
+    fn from(e: DeleteRepositoryTaskTrackerRuleError) -> Self {
+        match e {
+            DeleteRepositoryTaskTrackerRuleError::NotFound => Self::NotFound,
+            DeleteRepositoryTaskTrackerRuleError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
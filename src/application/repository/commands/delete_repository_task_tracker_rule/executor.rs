@@ -0,0 +1,34 @@
+use crate::application::repository::commands::delete_repository_task_tracker_rule::command::DeleteRepositoryTaskTrackerRuleCommand;
+use crate::application::repository::commands::delete_repository_task_tracker_rule::error::DeleteRepositoryTaskTrackerRuleExecutorError;
+use crate::application::repository::commands::delete_repository_task_tracker_rule::response::DeleteRepositoryTaskTrackerRuleResponse;
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::RepositoryTaskTrackerRuleRepository;
+use crate::domain::shared::command::CommandExecutor;
+use std::sync::Arc;
+
+pub struct DeleteRepositoryTaskTrackerRuleExecutor {
+    repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
+}
+
+impl DeleteRepositoryTaskTrackerRuleExecutor {
+    pub fn new(
+        repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
+    ) -> Self {
+        Self {
+            repository_task_tracker_rule_repo,
+        }
+    }
+}
+
+impl CommandExecutor for DeleteRepositoryTaskTrackerRuleExecutor {
+    type Command = DeleteRepositoryTaskTrackerRuleCommand;
+    type Response = DeleteRepositoryTaskTrackerRuleResponse;
+    type Error = DeleteRepositoryTaskTrackerRuleExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        self.repository_task_tracker_rule_repo
+            .delete(cmd.id)
+            .await?;
+
+        Ok(DeleteRepositoryTaskTrackerRuleResponse)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::domain::version_control::ports::version_control_client::VersionControlClientRepositoryHookError;
+use crate::utils::security::crypto::reversible::CipherError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum EnsureRepositoryWebhookError {
+    #[error("Repository not found")]
+    NotFound,
+
+    #[error("No token with access to repository hooks")]
+    NoToken,
+
+    #[error("Webhook secret is not configured")]
+    NoSecret,
+
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Transport error: {0}")]
+    Transport(String),
+
+    #[error("Cipher error: {0}")]
+    CipherError(#[from] CipherError),
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<VersionControlClientRepositoryHookError> for EnsureRepositoryWebhookError {
+    fn from(e: VersionControlClientRepositoryHookError) -> Self {
+        match e {
+            // 404 на /hooks — у токена нет прав администратора репозитория
+            VersionControlClientRepositoryHookError::NotFound => {
+                Self::Unauthorized("Repository hooks are not accessible".to_string())
+            }
+            VersionControlClientRepositoryHookError::Unauthorized(msg) => Self::Unauthorized(msg),
+            VersionControlClientRepositoryHookError::Transport(msg) => Self::Transport(msg),
+        }
+    }
+}
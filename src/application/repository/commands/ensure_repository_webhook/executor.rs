@@ -0,0 +1,171 @@
+use crate::application::repository::commands::ensure_repository_webhook::command::EnsureRepositoryWebhookCommand;
+use crate::application::repository::commands::ensure_repository_webhook::error::EnsureRepositoryWebhookError;
+use crate::application::repository::commands::ensure_repository_webhook::response::{
+    EnsureRepositoryWebhookOutcome, EnsureRepositoryWebhookResponse,
+};
+use crate::domain::repository::entities::repository::Repository;
+use crate::domain::repository::repositories::repository_repository::{
+    FindRepositoryByIdError, RepositoryRepository,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client::RepositoryHookSettings;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
+use crate::infrastructure::contracts::gitea::event_type::GITEA_REPOSITORY_HOOK_EVENTS;
+use crate::infrastructure::contracts::github::event_type::GITHUB_REPOSITORY_HOOK_EVENTS;
+use crate::infrastructure::contracts::gitlab::event_type::GITLAB_REPOSITORY_HOOK_EVENTS;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use std::sync::Arc;
+
+pub struct EnsureRepositoryWebhookExecutor {
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub version_control_tokens: Arc<dyn VersionControlTokenProvider>,
+    pub reversible_cipher: Arc<ReversibleCipher>,
+    pub base_url: String,
+    pub github_webhook_secret: String,
+    pub gitea_webhook_secret: String,
+    pub gitlab_webhook_secret: String,
+}
+
+impl EnsureRepositoryWebhookExecutor {
+    fn hook_url(&self, version_control_type: &VersionControlType) -> String {
+        let path = match version_control_type {
+            VersionControlType::Github => "/webhook/github",
+            VersionControlType::Gitea => "/webhook/gitea",
+            VersionControlType::Gitlab => "/webhook/gitlab",
+        };
+        format!("{}{}", self.base_url.trim_end_matches('/'), path)
+    }
+
+    fn hook_events(version_control_type: &VersionControlType) -> &'static [&'static str] {
+        match version_control_type {
+            VersionControlType::Github => GITHUB_REPOSITORY_HOOK_EVENTS,
+            VersionControlType::Gitea => GITEA_REPOSITORY_HOOK_EVENTS,
+            VersionControlType::Gitlab => GITLAB_REPOSITORY_HOOK_EVENTS,
+        }
+    }
+
+    /// Секрет, которым middleware проверяет подпись этого репозитория
+    fn hook_secret(&self, repository: &Repository) -> Result<String, EnsureRepositoryWebhookError> {
+        let secret = match repository.version_control_type {
+            VersionControlType::Github => match &repository.webhook_secret {
+                Some(value) => self.reversible_cipher.decrypt(value.value())?,
+                None => self.github_webhook_secret.clone(),
+            },
+            VersionControlType::Gitea => self.gitea_webhook_secret.clone(),
+            VersionControlType::Gitlab => self.gitlab_webhook_secret.clone(),
+        };
+
+        if secret.is_empty() {
+            return Err(EnsureRepositoryWebhookError::NoSecret);
+        }
+
+        Ok(secret)
+    }
+}
+
+impl CommandExecutor for EnsureRepositoryWebhookExecutor {
+    type Command = EnsureRepositoryWebhookCommand;
+    type Response = EnsureRepositoryWebhookResponse;
+    type Error = EnsureRepositoryWebhookError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let repository = self
+            .repository_repo
+            .find_by_id(cmd.repository_id)
+            .await
+            .map_err(|e| match e {
+                FindRepositoryByIdError::NotFound => EnsureRepositoryWebhookError::NotFound,
+                FindRepositoryByIdError::DbError(msg) => EnsureRepositoryWebhookError::DbError(msg),
+            })?;
+
+        let token = self
+            .version_control_tokens
+            .background_token(&repository)
+            .await
+            .ok_or(EnsureRepositoryWebhookError::NoToken)?;
+
+        let client = self
+            .version_control_clients
+            .client(&repository.version_control_type);
+
+        let url = self.hook_url(&repository.version_control_type);
+        let events = Self::hook_events(&repository.version_control_type);
+
+        let hooks = client
+            .list_repository_hooks(&token, &repository.owner, &repository.name)
+            .await?;
+
+        let mut matching = hooks
+            .into_iter()
+            .filter(|hook| hook.url.trim_end_matches('/') == url);
+
+        let existing = matching.next();
+
+        // Дубликаты с тем же URL доставляли бы каждое событие дважды
+        for duplicate in matching {
+            client
+                .delete_repository_hook(&token, &repository.owner, &repository.name, duplicate.id)
+                .await?;
+        }
+
+        let outcome = match existing {
+            None => {
+                let settings = RepositoryHookSettings {
+                    url: url.clone(),
+                    secret: self.hook_secret(&repository)?,
+                    events: events.iter().map(|e| e.to_string()).collect(),
+                };
+
+                client
+                    .create_repository_hook(&token, &repository.owner, &repository.name, &settings)
+                    .await?;
+
+                EnsureRepositoryWebhookOutcome::Created
+            }
+            Some(hook) => {
+                let missing_events: Vec<String> = events
+                    .iter()
+                    .filter(|e| !hook.events.iter().any(|h| h == *e || h == "*"))
+                    .map(|e| e.to_string())
+                    .collect();
+
+                if missing_events.is_empty() && hook.active {
+                    EnsureRepositoryWebhookOutcome::UpToDate
+                } else {
+                    // Лишние события, добавленные руками, не трогаем
+                    let mut hook_events = hook.events.clone();
+                    hook_events.extend(missing_events.iter().cloned());
+
+                    let settings = RepositoryHookSettings {
+                        url: url.clone(),
+                        secret: self.hook_secret(&repository)?,
+                        events: hook_events,
+                    };
+
+                    client
+                        .update_repository_hook(
+                            &token,
+                            &repository.owner,
+                            &repository.name,
+                            hook.id,
+                            &settings,
+                        )
+                        .await?;
+
+                    EnsureRepositoryWebhookOutcome::Updated { missing_events }
+                }
+            }
+        };
+
+        tracing::info!(
+            repo = %format!("{}/{}", repository.owner, repository.name),
+            outcome = ?outcome,
+            "Repository webhook ensured"
+        );
+
+        Ok(EnsureRepositoryWebhookResponse { url, outcome })
+    }
+}
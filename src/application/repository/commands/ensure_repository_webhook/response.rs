@@ -0,0 +1,14 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnsureRepositoryWebhookOutcome {
+    Created,
+    /// Вебхук уже был, но не слушал часть событий или был выключен
+    Updated {
+        missing_events: Vec<String>,
+    },
+    UpToDate,
+}
+
+pub struct EnsureRepositoryWebhookResponse {
+    pub url: String,
+    pub outcome: EnsureRepositoryWebhookOutcome,
+}
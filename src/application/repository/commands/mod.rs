@@ -1,8 +1,16 @@
 pub mod create_repository;
 pub mod create_repository_task_tracker;
+pub mod create_repository_task_tracker_rule;
 pub mod delete_repository;
+pub mod delete_repository_task_tracker_rule;
+pub mod ensure_repository_webhook;
+pub mod register_installation_repositories;
+pub mod rotate_repository_webhook_secret;
 pub mod set_repository_notification_chat;
 pub mod set_repository_notifications_chat;
+pub mod set_repository_ref_events_filter;
+pub mod set_repository_slack_channel;
+pub mod sync_repository_from_webhook;
 pub mod unset_repository_notification_chat;
 pub mod update_repository;
 pub mod update_repository_task_tracker;
@@ -0,0 +1,4 @@
+pub struct RegisterInstallationRepositoriesCommand {
+    /// owner/name репозиториев, к которым у GitHub App появился доступ
+    pub repositories: Vec<String>,
+}
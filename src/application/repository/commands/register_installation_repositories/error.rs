@@ -0,0 +1,16 @@
+use crate::application::repository::commands::create_repository::error::CreateRepositoryExecutorError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RegisterInstallationRepositoriesError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<CreateRepositoryExecutorError> for RegisterInstallationRepositoriesError {
+    fn from(e: CreateRepositoryExecutorError) -> Self {
+        match e {
+            CreateRepositoryExecutorError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
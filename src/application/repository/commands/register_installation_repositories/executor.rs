@@ -0,0 +1,67 @@
+use crate::application::repository::commands::create_repository::command::CreateRepositoryCommand;
+use crate::application::repository::commands::create_repository::executor::CreateRepositoryExecutor;
+use crate::application::repository::commands::register_installation_repositories::command::RegisterInstallationRepositoriesCommand;
+use crate::application::repository::commands::register_installation_repositories::error::RegisterInstallationRepositoriesError;
+use crate::application::repository::commands::register_installation_repositories::response::RegisterInstallationRepositoriesResponse;
+use crate::domain::repository::repositories::repository_repository::{
+    FindRepositoryByOwnerAndNameError, RepositoryRepository,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use std::sync::Arc;
+
+pub struct RegisterInstallationRepositoriesExecutor {
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub create_repository: Arc<CreateRepositoryExecutor>,
+    /// https://github.com — из него собираем ссылку на репозиторий
+    pub github_base: String,
+}
+
+impl CommandExecutor for RegisterInstallationRepositoriesExecutor {
+    type Command = RegisterInstallationRepositoriesCommand;
+    type Response = RegisterInstallationRepositoriesResponse;
+    type Error = RegisterInstallationRepositoriesError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let mut created = Vec::new();
+
+        for full_name in &cmd.repositories {
+            let Some((owner, name)) = full_name.split_once('/') else {
+                tracing::warn!(repo = %full_name, "Invalid repository name from installation");
+                continue;
+            };
+
+            match self
+                .repository_repo
+                .find_by_owner_and_name(&VersionControlType::Github, owner, name)
+                .await
+            {
+                Ok(_) => continue,
+                Err(FindRepositoryByOwnerAndNameError::NotFound) => {}
+                Err(FindRepositoryByOwnerAndNameError::DbError(msg)) => {
+                    return Err(RegisterInstallationRepositoriesError::DbError(msg));
+                }
+            }
+
+            let response = self
+                .create_repository
+                .execute(&CreateRepositoryCommand {
+                    name: name.to_string(),
+                    owner: owner.to_string(),
+                    url: format!("{}/{}", self.github_base.trim_end_matches('/'), full_name),
+                    version_control_type: VersionControlType::Github,
+                })
+                .await?;
+
+            tracing::info!(
+                repository_id = response.repository.id.0,
+                repo = %full_name,
+                "Repository registered from GitHub App installation"
+            );
+
+            created.push(response.repository);
+        }
+
+        Ok(RegisterInstallationRepositoriesResponse { created })
+    }
+}
@@ -0,0 +1,6 @@
+use crate::domain::repository::entities::repository::Repository;
+
+pub struct RegisterInstallationRepositoriesResponse {
+    /// Только новые — уже зарегистрированные не трогаем
+    pub created: Vec<Repository>,
+}
@@ -0,0 +1,7 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+
+pub struct RotateRepositoryWebhookSecretCommand {
+    pub repository_id: RepositoryId,
+    /// `None` — вернуться к глобальному `GITHUB_WEBHOOK_SECRET`
+    pub secret: Option<String>,
+}
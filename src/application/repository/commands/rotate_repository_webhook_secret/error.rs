@@ -0,0 +1,17 @@
+use crate::utils::security::crypto::reversible::CipherError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RotateRepositoryWebhookSecretError {
+    #[error("Repository not found")]
+    NotFound,
+
+    #[error("Webhook secret must not be empty")]
+    EmptySecret,
+
+    #[error("Cipher error: {0}")]
+    CipherError(#[from] CipherError),
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
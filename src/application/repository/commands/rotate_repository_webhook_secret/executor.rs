@@ -0,0 +1,87 @@
+use crate::application::repository::commands::rotate_repository_webhook_secret::command::RotateRepositoryWebhookSecretCommand;
+use crate::application::repository::commands::rotate_repository_webhook_secret::error::RotateRepositoryWebhookSecretError;
+use crate::application::repository::commands::rotate_repository_webhook_secret::response::RotateRepositoryWebhookSecretResponse;
+use crate::domain::repository::repositories::repository_repository::{
+    FindRepositoryByIdError, RepositoryRepository, UpdateRepositoryError,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use std::sync::Arc;
+
+pub struct RotateRepositoryWebhookSecretExecutor {
+    pub db: Arc<DatabaseConnection>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub reversible_cipher: Arc<ReversibleCipher>,
+}
+
+impl RotateRepositoryWebhookSecretExecutor {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        repository_repo: Arc<dyn RepositoryRepository>,
+        reversible_cipher: Arc<ReversibleCipher>,
+    ) -> Self {
+        Self {
+            db,
+            repository_repo,
+            reversible_cipher,
+        }
+    }
+}
+
+impl CommandExecutor for RotateRepositoryWebhookSecretExecutor {
+    type Command = RotateRepositoryWebhookSecretCommand;
+    type Response = RotateRepositoryWebhookSecretResponse;
+    type Error = RotateRepositoryWebhookSecretError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let new_secret = match cmd.secret.as_deref().map(str::trim) {
+            Some("") => return Err(RotateRepositoryWebhookSecretError::EmptySecret),
+            Some(secret) => Some(self.reversible_cipher.encrypt(secret)?),
+            None => None,
+        };
+
+        let mut repository = self
+            .repository_repo
+            .find_by_id(cmd.repository_id)
+            .await
+            .map_err(|e| match e {
+                FindRepositoryByIdError::NotFound => RotateRepositoryWebhookSecretError::NotFound,
+                FindRepositoryByIdError::DbError(msg) => {
+                    RotateRepositoryWebhookSecretError::DbError(msg)
+                }
+            })?;
+
+        // Текущий секрет становится предыдущим и принимается ещё grace-период.
+        // None в previous при заданном rotated_at — до ротации был глобальный секрет.
+        let now = Utc::now();
+        repository.previous_webhook_secret = repository.webhook_secret.take();
+        repository.webhook_secret = new_secret;
+        repository.webhook_secret_rotated_at = Some(now);
+        repository.updated_at = now;
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| RotateRepositoryWebhookSecretError::DbError(e.to_string()))?;
+
+        let repository = self
+            .repository_repo
+            .update(&txn, &repository)
+            .await
+            .map_err(|e| match e {
+                UpdateRepositoryError::NotFound => RotateRepositoryWebhookSecretError::NotFound,
+                UpdateRepositoryError::DbError(msg) => {
+                    RotateRepositoryWebhookSecretError::DbError(msg)
+                }
+            })?;
+
+        txn.commit()
+            .await
+            .map_err(|e| RotateRepositoryWebhookSecretError::DbError(e.to_string()))?;
+
+        Ok(RotateRepositoryWebhookSecretResponse { repository })
+    }
+}
@@ -0,0 +1,7 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+
+pub struct SetRepositoryRefEventsFilterCommand {
+    pub repository_id: RepositoryId,
+    /// `None` — слать все создания/удаления веток и тегов
+    pub filter: Option<String>,
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SetRepositoryRefEventsFilterError {
+    #[error("Repository not found")]
+    NotFound,
+
+    #[error("Invalid ref events filter: {0}")]
+    InvalidFilter(String),
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
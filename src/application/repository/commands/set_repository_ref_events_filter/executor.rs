@@ -0,0 +1,81 @@
+use crate::application::repository::commands::set_repository_ref_events_filter::command::SetRepositoryRefEventsFilterCommand;
+use crate::application::repository::commands::set_repository_ref_events_filter::error::SetRepositoryRefEventsFilterError;
+use crate::application::repository::commands::set_repository_ref_events_filter::response::SetRepositoryRefEventsFilterResponse;
+use crate::domain::repository::repositories::repository_repository::{
+    FindRepositoryByIdError, RepositoryRepository, UpdateRepositoryError,
+};
+use crate::domain::repository::value_objects::ref_events_filter::RefEventsFilter;
+use crate::domain::shared::command::CommandExecutor;
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct SetRepositoryRefEventsFilterExecutor {
+    db: Arc<DatabaseConnection>,
+    repository_repo: Arc<dyn RepositoryRepository>,
+}
+
+impl SetRepositoryRefEventsFilterExecutor {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        repository_repo: Arc<dyn RepositoryRepository>,
+    ) -> Self {
+        Self {
+            db,
+            repository_repo,
+        }
+    }
+}
+
+impl CommandExecutor for SetRepositoryRefEventsFilterExecutor {
+    type Command = SetRepositoryRefEventsFilterCommand;
+    type Response = SetRepositoryRefEventsFilterResponse;
+    type Error = SetRepositoryRefEventsFilterError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let filter = cmd
+            .filter
+            .as_deref()
+            .map(RefEventsFilter::from_str)
+            .transpose()
+            .map_err(SetRepositoryRefEventsFilterError::InvalidFilter)?;
+
+        let mut repository = self
+            .repository_repo
+            .find_by_id(cmd.repository_id)
+            .await
+            .map_err(|e| match e {
+                FindRepositoryByIdError::NotFound => SetRepositoryRefEventsFilterError::NotFound,
+                FindRepositoryByIdError::DbError(msg) => {
+                    SetRepositoryRefEventsFilterError::DbError(msg)
+                }
+            })?;
+
+        repository.ref_events_filter = filter;
+        repository.updated_at = Utc::now();
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| SetRepositoryRefEventsFilterError::DbError(e.to_string()))?;
+
+        let repository = self
+            .repository_repo
+            .update(&txn, &repository)
+            .await
+            .map_err(|e| match e {
+                UpdateRepositoryError::NotFound => SetRepositoryRefEventsFilterError::NotFound,
+                UpdateRepositoryError::DbError(msg) => {
+                    SetRepositoryRefEventsFilterError::DbError(msg)
+                }
+            })?;
+
+        txn.commit()
+            .await
+            .map_err(|e| SetRepositoryRefEventsFilterError::DbError(e.to_string()))?;
+
+        Ok(SetRepositoryRefEventsFilterResponse { repository })
+    }
+}
@@ -0,0 +1,5 @@
+use crate::domain::repository::entities::repository::Repository;
+
+pub struct SetRepositoryRefEventsFilterResponse {
+    pub repository: Repository,
+}
@@ -0,0 +1,7 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+
+pub struct SetRepositorySlackChannelCommand {
+    pub repository_id: RepositoryId,
+    /// `None` — не дублировать уведомления репозитория в Slack
+    pub slack_channel_id: Option<String>,
+}
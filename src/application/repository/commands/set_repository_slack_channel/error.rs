@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SetRepositorySlackChannelError {
+    #[error("Repository not found")]
+    NotFound,
+
+    #[error("Invalid Slack channel id: {0}")]
+    InvalidChannelId(String),
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
@@ -0,0 +1,81 @@
+use crate::application::repository::commands::set_repository_slack_channel::command::SetRepositorySlackChannelCommand;
+use crate::application::repository::commands::set_repository_slack_channel::error::SetRepositorySlackChannelError;
+use crate::application::repository::commands::set_repository_slack_channel::response::SetRepositorySlackChannelResponse;
+use crate::domain::repository::repositories::repository_repository::{
+    FindRepositoryByIdError, RepositoryRepository, UpdateRepositoryError,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use std::sync::Arc;
+
+pub struct SetRepositorySlackChannelExecutor {
+    db: Arc<DatabaseConnection>,
+    repository_repo: Arc<dyn RepositoryRepository>,
+}
+
+impl SetRepositorySlackChannelExecutor {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        repository_repo: Arc<dyn RepositoryRepository>,
+    ) -> Self {
+        Self {
+            db,
+            repository_repo,
+        }
+    }
+}
+
+impl CommandExecutor for SetRepositorySlackChannelExecutor {
+    type Command = SetRepositorySlackChannelCommand;
+    type Response = SetRepositorySlackChannelResponse;
+    type Error = SetRepositorySlackChannelError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let slack_channel_id = cmd
+            .slack_channel_id
+            .as_deref()
+            .map(|value| {
+                SlackChannelId::parse(value).ok_or_else(|| {
+                    SetRepositorySlackChannelError::InvalidChannelId(value.to_string())
+                })
+            })
+            .transpose()?;
+
+        let mut repository = self
+            .repository_repo
+            .find_by_id(cmd.repository_id)
+            .await
+            .map_err(|e| match e {
+                FindRepositoryByIdError::NotFound => SetRepositorySlackChannelError::NotFound,
+                FindRepositoryByIdError::DbError(msg) => {
+                    SetRepositorySlackChannelError::DbError(msg)
+                }
+            })?;
+
+        repository.slack_channel_id = slack_channel_id;
+        repository.updated_at = Utc::now();
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| SetRepositorySlackChannelError::DbError(e.to_string()))?;
+
+        let repository = self
+            .repository_repo
+            .update(&txn, &repository)
+            .await
+            .map_err(|e| match e {
+                UpdateRepositoryError::NotFound => SetRepositorySlackChannelError::NotFound,
+                UpdateRepositoryError::DbError(msg) => SetRepositorySlackChannelError::DbError(msg),
+            })?;
+
+        txn.commit()
+            .await
+            .map_err(|e| SetRepositorySlackChannelError::DbError(e.to_string()))?;
+
+        Ok(SetRepositorySlackChannelResponse { repository })
+    }
+}
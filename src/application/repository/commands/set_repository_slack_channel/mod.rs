@@ -0,0 +1,4 @@
+pub mod command;
+pub mod error;
+pub mod executor;
+pub mod response;
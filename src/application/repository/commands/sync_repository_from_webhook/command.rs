@@ -0,0 +1,8 @@
+use crate::domain::webhook::events::repository::WebhookRepositoryAction;
+
+pub struct SyncRepositoryFromWebhookCommand {
+    pub action: WebhookRepositoryAction,
+    pub repo: String,
+    pub previous_repo: Option<String>,
+    pub repo_url: Option<String>,
+}
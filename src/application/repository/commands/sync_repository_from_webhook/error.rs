@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SyncRepositoryFromWebhookError {
+    #[error("Invalid repository name: {0}")]
+    InvalidName(String),
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
@@ -0,0 +1,134 @@
+use crate::application::repository::commands::sync_repository_from_webhook::command::SyncRepositoryFromWebhookCommand;
+use crate::application::repository::commands::sync_repository_from_webhook::error::SyncRepositoryFromWebhookError;
+use crate::application::repository::commands::sync_repository_from_webhook::response::SyncRepositoryFromWebhookResponse;
+use crate::domain::repository::entities::repository::Repository;
+use crate::domain::repository::repositories::repository_repository::{
+    FindRepositoryByOwnerAndNameError, RepositoryRepository,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::webhook::events::repository::WebhookRepositoryAction;
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use std::sync::Arc;
+
+pub struct SyncRepositoryFromWebhookExecutor {
+    db: Arc<DatabaseConnection>,
+    repository_repo: Arc<dyn RepositoryRepository>,
+}
+
+impl SyncRepositoryFromWebhookExecutor {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        repository_repo: Arc<dyn RepositoryRepository>,
+    ) -> Self {
+        Self {
+            db,
+            repository_repo,
+        }
+    }
+
+    fn split(full_name: &str) -> Result<(&str, &str), SyncRepositoryFromWebhookError> {
+        full_name
+            .split_once('/')
+            .ok_or_else(|| SyncRepositoryFromWebhookError::InvalidName(full_name.to_string()))
+    }
+
+    async fn find(
+        &self,
+        full_name: &str,
+    ) -> Result<Option<Repository>, SyncRepositoryFromWebhookError> {
+        let (owner, name) = Self::split(full_name)?;
+
+        match self
+            .repository_repo
+            .find_by_owner_and_name(&VersionControlType::Github, owner, name)
+            .await
+        {
+            Ok(repository) => Ok(Some(repository)),
+            Err(FindRepositoryByOwnerAndNameError::NotFound) => Ok(None),
+            Err(FindRepositoryByOwnerAndNameError::DbError(msg)) => {
+                Err(SyncRepositoryFromWebhookError::DbError(msg))
+            }
+        }
+    }
+}
+
+impl CommandExecutor for SyncRepositoryFromWebhookExecutor {
+    type Command = SyncRepositoryFromWebhookCommand;
+    type Response = SyncRepositoryFromWebhookResponse;
+    type Error = SyncRepositoryFromWebhookError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        // После переименования / передачи в базе ещё лежит старое имя
+        let lookup = match cmd.action {
+            WebhookRepositoryAction::Renamed | WebhookRepositoryAction::Transferred => {
+                cmd.previous_repo.as_deref().unwrap_or(&cmd.repo)
+            }
+            _ => &cmd.repo,
+        };
+
+        let Some(mut repository) = self.find(lookup).await? else {
+            return Ok(SyncRepositoryFromWebhookResponse { repository: None });
+        };
+
+        let changed = match cmd.action {
+            WebhookRepositoryAction::Created => false,
+            WebhookRepositoryAction::Archived | WebhookRepositoryAction::Deleted => {
+                let changed = !repository.is_archived;
+                repository.is_archived = true;
+                changed
+            }
+            WebhookRepositoryAction::Unarchived => {
+                let changed = repository.is_archived;
+                repository.is_archived = false;
+                changed
+            }
+            WebhookRepositoryAction::Renamed | WebhookRepositoryAction::Transferred => {
+                let (owner, name) = Self::split(&cmd.repo)?;
+                let changed = repository.owner != owner || repository.name != name;
+                repository.owner = owner.to_string();
+                repository.name = name.to_string();
+                if let Some(url) = &cmd.repo_url {
+                    repository.url = url.clone();
+                }
+                changed
+            }
+        };
+
+        if !changed {
+            return Ok(SyncRepositoryFromWebhookResponse {
+                repository: Some(repository),
+            });
+        }
+
+        repository.updated_at = Utc::now();
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| SyncRepositoryFromWebhookError::DbError(e.to_string()))?;
+
+        let repository = self
+            .repository_repo
+            .update(&txn, &repository)
+            .await
+            .map_err(|e| SyncRepositoryFromWebhookError::DbError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| SyncRepositoryFromWebhookError::DbError(e.to_string()))?;
+
+        tracing::info!(
+            repository_id = repository.id.0,
+            repo = %cmd.repo,
+            action = ?cmd.action,
+            "Repository synced from webhook"
+        );
+
+        Ok(SyncRepositoryFromWebhookResponse {
+            repository: Some(repository),
+        })
+    }
+}
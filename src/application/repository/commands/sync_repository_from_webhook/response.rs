@@ -0,0 +1,6 @@
+use crate::domain::repository::entities::repository::Repository;
+
+pub struct SyncRepositoryFromWebhookResponse {
+    /// `None` — репозиторий не зарегистрирован в боте
+    pub repository: Option<Repository>,
+}
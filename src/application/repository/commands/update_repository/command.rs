@@ -1,8 +1,10 @@
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 
 pub struct UpdateRepositoryCommand {
     pub id: RepositoryId,
     pub name: String,
     pub owner: String,
     pub url: String,
+    pub version_control_type: VersionControlType,
 }
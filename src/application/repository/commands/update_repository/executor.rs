@@ -35,6 +35,7 @@ impl CommandExecutor for UpdateRepositoryExecutor {
         repository.name = cmd.name.clone();
         repository.owner = cmd.owner.clone();
         repository.url = cmd.url.clone();
+        repository.version_control_type = cmd.version_control_type.clone();
         repository.updated_at = Utc::now();
 
         let txn = self
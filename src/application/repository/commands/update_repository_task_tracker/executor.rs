@@ -46,10 +46,16 @@ impl CommandExecutor for UpdateRepositoryTaskTrackerExecutor {
 
         let tracker = match existing {
             Ok(mut tracker) => {
-                tracker.space_id = cmd.space_id;
-                tracker.qa_column_id = cmd.qa_column_id;
+                tracker.space_id = cmd.space_id.clone();
+                tracker.qa_column_id = cmd.qa_column_id.clone();
                 tracker.extract_pattern_regexp = cmd.extract_pattern_regexp.clone();
                 tracker.path_to_card = cmd.path_to_card.clone();
+                tracker.kind = cmd.kind;
+                tracker.connection_id = cmd.connection_id;
+                tracker.require_task_id = cmd.require_task_id;
+                tracker.qa_passed_column_id = cmd.qa_passed_column_id.clone();
+                tracker.qa_failed_column_id = cmd.qa_failed_column_id.clone();
+                tracker.issue_column_id = cmd.issue_column_id.clone();
                 tracker.updated_at = Utc::now();
                 self.task_tracker_repo
                     .update(&txn, &tracker)
@@ -60,10 +66,16 @@ impl CommandExecutor for UpdateRepositoryTaskTrackerExecutor {
                 let placeholder = RepositoryTaskTracker {
                     id: Default::default(),
                     repository_id: cmd.repository_id,
-                    space_id: cmd.space_id,
-                    qa_column_id: cmd.qa_column_id,
+                    space_id: cmd.space_id.clone(),
+                    qa_column_id: cmd.qa_column_id.clone(),
                     extract_pattern_regexp: cmd.extract_pattern_regexp.clone(),
                     path_to_card: cmd.path_to_card.clone(),
+                    kind: cmd.kind,
+                    connection_id: cmd.connection_id,
+                    require_task_id: cmd.require_task_id,
+                    qa_passed_column_id: cmd.qa_passed_column_id.clone(),
+                    qa_failed_column_id: cmd.qa_failed_column_id.clone(),
+                    issue_column_id: cmd.issue_column_id.clone(),
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
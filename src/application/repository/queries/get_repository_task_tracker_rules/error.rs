@@ -0,0 +1,16 @@
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::FindRepositoryTaskTrackerRulesError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetRepositoryTaskTrackerRulesError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<FindRepositoryTaskTrackerRulesError> for GetRepositoryTaskTrackerRulesError {
+    fn from(e: FindRepositoryTaskTrackerRulesError) -> Self {
+        match e {
+            FindRepositoryTaskTrackerRulesError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::application::repository::queries::get_repository_task_tracker_rules::error::GetRepositoryTaskTrackerRulesError;
+use crate::application::repository::queries::get_repository_task_tracker_rules::query::GetRepositoryTaskTrackerRulesQuery;
+use crate::application::repository::queries::get_repository_task_tracker_rules::response::GetRepositoryTaskTrackerRulesResponse;
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::RepositoryTaskTrackerRuleRepository;
+use crate::domain::shared::command::CommandExecutor;
+use std::sync::Arc;
+
+pub struct GetRepositoryTaskTrackerRulesExecutor {
+    repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
+}
+
+impl GetRepositoryTaskTrackerRulesExecutor {
+    pub fn new(
+        repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
+    ) -> Self {
+        Self {
+            repository_task_tracker_rule_repo,
+        }
+    }
+}
+
+impl CommandExecutor for GetRepositoryTaskTrackerRulesExecutor {
+    type Command = GetRepositoryTaskTrackerRulesQuery;
+    type Response = GetRepositoryTaskTrackerRulesResponse;
+    type Error = GetRepositoryTaskTrackerRulesError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let rules = self
+            .repository_task_tracker_rule_repo
+            .find_by_repository_id(cmd.repository_id)
+            .await?;
+
+        Ok(GetRepositoryTaskTrackerRulesResponse { rules })
+    }
+}
@@ -0,0 +1,5 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+
+pub struct GetRepositoryTaskTrackerRulesQuery {
+    pub repository_id: RepositoryId,
+}
@@ -1 +1,3 @@
 pub mod get_all_repositories;
+pub mod get_repository_task_tracker_rules;
+pub mod resolve_repository_webhook_secrets;
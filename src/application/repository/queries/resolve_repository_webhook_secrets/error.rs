@@ -0,0 +1,11 @@
+use crate::utils::security::crypto::reversible::CipherError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ResolveRepositoryWebhookSecretsError {
+    #[error("Cipher error: {0}")]
+    CipherError(#[from] CipherError),
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
@@ -0,0 +1,114 @@
+use crate::application::repository::queries::resolve_repository_webhook_secrets::error::ResolveRepositoryWebhookSecretsError;
+use crate::application::repository::queries::resolve_repository_webhook_secrets::query::ResolveRepositoryWebhookSecretsQuery;
+use crate::application::repository::queries::resolve_repository_webhook_secrets::response::ResolveRepositoryWebhookSecretsResponse;
+use crate::domain::repository::entities::repository::Repository;
+use crate::domain::repository::repositories::repository_repository::{
+    FindRepositoryByOwnerAndNameError, RepositoryRepository,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::utils::security::crypto::reversible::{ReversibleCipher, ReversibleCipherValue};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+pub struct ResolveRepositoryWebhookSecretsExecutor {
+    repository_repo: Arc<dyn RepositoryRepository>,
+    reversible_cipher: Arc<ReversibleCipher>,
+    global_secret: String,
+    rotation_grace_hours: i64,
+}
+
+impl ResolveRepositoryWebhookSecretsExecutor {
+    pub fn new(
+        repository_repo: Arc<dyn RepositoryRepository>,
+        reversible_cipher: Arc<ReversibleCipher>,
+        global_secret: String,
+        rotation_grace_hours: i64,
+    ) -> Self {
+        Self {
+            repository_repo,
+            reversible_cipher,
+            global_secret,
+            rotation_grace_hours,
+        }
+    }
+
+    async fn find_repository(
+        &self,
+        full_name: &str,
+    ) -> Result<Option<Repository>, ResolveRepositoryWebhookSecretsError> {
+        let Some((owner, name)) = full_name.split_once('/') else {
+            return Ok(None);
+        };
+
+        match self
+            .repository_repo
+            .find_by_owner_and_name(&VersionControlType::Github, owner, name)
+            .await
+        {
+            Ok(repository) => Ok(Some(repository)),
+            Err(FindRepositoryByOwnerAndNameError::NotFound) => Ok(None),
+            Err(FindRepositoryByOwnerAndNameError::DbError(msg)) => {
+                Err(ResolveRepositoryWebhookSecretsError::DbError(msg))
+            }
+        }
+    }
+
+    /// `None` — глобальный секрет
+    fn decrypt_or_global(
+        &self,
+        value: Option<&ReversibleCipherValue>,
+    ) -> Result<String, ResolveRepositoryWebhookSecretsError> {
+        match value {
+            Some(value) => Ok(self.reversible_cipher.decrypt(value.value())?),
+            None => Ok(self.global_secret.clone()),
+        }
+    }
+}
+
+impl CommandExecutor for ResolveRepositoryWebhookSecretsExecutor {
+    type Command = ResolveRepositoryWebhookSecretsQuery;
+    type Response = ResolveRepositoryWebhookSecretsResponse;
+    type Error = ResolveRepositoryWebhookSecretsError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let mut repository = None;
+        for full_name in [
+            &cmd.repository_full_name,
+            &cmd.previous_repository_full_name,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            repository = self.find_repository(full_name).await?;
+            if repository.is_some() {
+                break;
+            }
+        }
+
+        let Some(repository) = repository else {
+            // Незарегистрированный репозиторий или событие без репозитория — только глобальный секрет
+            let secrets = Some(self.global_secret.clone())
+                .filter(|s| !s.is_empty())
+                .into_iter()
+                .collect();
+
+            return Ok(ResolveRepositoryWebhookSecretsResponse { secrets });
+        };
+
+        let mut secrets = vec![self.decrypt_or_global(repository.webhook_secret.as_ref())?];
+
+        let in_grace_period = repository
+            .webhook_secret_rotated_at
+            .is_some_and(|at| Utc::now() - at < Duration::hours(self.rotation_grace_hours));
+
+        if in_grace_period {
+            secrets.push(self.decrypt_or_global(repository.previous_webhook_secret.as_ref())?);
+        }
+
+        secrets.retain(|s| !s.is_empty());
+        secrets.dedup();
+
+        Ok(ResolveRepositoryWebhookSecretsResponse { secrets })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod executor;
+pub mod query;
+pub mod response;
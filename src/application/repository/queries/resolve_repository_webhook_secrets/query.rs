@@ -0,0 +1,7 @@
+pub struct ResolveRepositoryWebhookSecretsQuery {
+    /// `owner/name` из payload. `None` — событие без репозитория (org-level)
+    pub repository_full_name: Option<String>,
+    /// Прежний `owner/name` из `renamed` / `transferred` — в базе репозиторий
+    /// записан под ним, пока событие не обработано
+    pub previous_repository_full_name: Option<String>,
+}
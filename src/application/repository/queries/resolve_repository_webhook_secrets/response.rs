@@ -0,0 +1,5 @@
+pub struct ResolveRepositoryWebhookSecretsResponse {
+    /// Секреты, которыми может быть подписана доставка: текущий и,
+    /// в течение grace-периода после ротации, предыдущий
+    pub secrets: Vec<String>,
+}
@@ -0,0 +1,10 @@
+use crate::domain::task::value_objects::task_card_annotation::TaskCardAnnotation;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+
+pub struct AnnotateTaskCardExecutorCommand {
+    pub task_id: TaskId,
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
+    pub annotation: TaskCardAnnotation,
+}
@@ -0,0 +1,12 @@
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClientWriteError;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolveError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AnnotateTaskCardExecutorError {
+    #[error("{0}")]
+    TaskTrackerClientWriteError(#[from] TaskTrackerClientWriteError),
+
+    #[error("{0}")]
+    TaskTrackerClientResolveError(#[from] TaskTrackerClientResolveError),
+}
@@ -0,0 +1,45 @@
+use crate::application::task::commands::annotate_task_card::command::AnnotateTaskCardExecutorCommand;
+use crate::application::task::commands::annotate_task_card::error::AnnotateTaskCardExecutorError;
+use crate::application::task::commands::annotate_task_card::response::AnnotateTaskCardExecutorResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::task::value_objects::task_card_annotation::TaskCardAnnotation;
+use std::sync::Arc;
+
+pub struct AnnotateTaskCardExecutor {
+    task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+}
+
+impl AnnotateTaskCardExecutor {
+    pub fn new(task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>) -> Self {
+        Self {
+            task_tracker_client_resolver,
+        }
+    }
+}
+
+impl CommandExecutor for AnnotateTaskCardExecutor {
+    type Command = AnnotateTaskCardExecutorCommand;
+    type Response = AnnotateTaskCardExecutorResponse;
+    type Error = AnnotateTaskCardExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let client = self
+            .task_tracker_client_resolver
+            .resolve(cmd.kind, cmd.connection_id)
+            .await?;
+
+        match &cmd.annotation {
+            TaskCardAnnotation::Link { url, title } => {
+                client.add_link(cmd.task_id.clone(), url, title).await?
+            }
+            TaskCardAnnotation::Comment { text } => {
+                client.add_comment(cmd.task_id.clone(), text).await?
+            }
+        }
+
+        tracing::info!(task_id = %cmd.task_id.0, kind = %cmd.kind, "Task card annotated");
+
+        Ok(AnnotateTaskCardExecutorResponse {})
+    }
+}
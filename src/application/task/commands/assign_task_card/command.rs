@@ -0,0 +1,11 @@
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+
+pub struct AssignTaskCardExecutorCommand {
+    pub task_id: TaskId,
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
+    /// Кто берёт карточку: ищется его аккаунт системы контроля версий
+    pub social_user_id: SocialUserId,
+}
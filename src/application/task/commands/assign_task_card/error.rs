@@ -0,0 +1,20 @@
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClientWriteError;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolveError;
+use crate::domain::user::repositories::user_social_accounts_repository::FindSocialServiceByIdError;
+use crate::domain::user::repositories::user_vc_accounts_repository::FindVersionControlServiceByUserIdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AssignTaskCardExecutorError {
+    #[error("{0}")]
+    UserNotFound(#[from] FindSocialServiceByIdError),
+
+    #[error("{0}")]
+    VersionControlAccountNotFound(#[from] FindVersionControlServiceByUserIdError),
+
+    #[error("{0}")]
+    TaskTrackerClientWriteError(#[from] TaskTrackerClientWriteError),
+
+    #[error("{0}")]
+    TaskTrackerClientResolveError(#[from] TaskTrackerClientResolveError),
+}
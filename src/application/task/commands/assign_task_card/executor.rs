@@ -0,0 +1,69 @@
+use crate::application::task::commands::assign_task_card::command::AssignTaskCardExecutorCommand;
+use crate::application::task::commands::assign_task_card::error::AssignTaskCardExecutorError;
+use crate::application::task::commands::assign_task_card::response::AssignTaskCardExecutorResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::ports::task_tracker_client::TaskTrackerAssignee;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use std::sync::Arc;
+
+pub struct AssignTaskCardExecutor {
+    task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+    user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+}
+
+impl AssignTaskCardExecutor {
+    pub fn new(
+        task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+        user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+        user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    ) -> Self {
+        Self {
+            task_tracker_client_resolver,
+            user_socials_repo,
+            user_vc_accounts_repo,
+        }
+    }
+}
+
+impl CommandExecutor for AssignTaskCardExecutor {
+    type Command = AssignTaskCardExecutorCommand;
+    type Response = AssignTaskCardExecutorResponse;
+    type Error = AssignTaskCardExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let social_user = self
+            .user_socials_repo
+            .find_by_social_user_id(&cmd.social_user_id)
+            .await?;
+
+        let vc_account = self
+            .user_vc_accounts_repo
+            .find_by_user_id(&VersionControlType::Github, &social_user.user_id)
+            .await?;
+
+        let client = self
+            .task_tracker_client_resolver
+            .resolve(cmd.kind, cmd.connection_id)
+            .await?;
+
+        let assignee = TaskTrackerAssignee {
+            login: vc_account.version_control_login,
+            email: vc_account.version_control_email,
+        };
+
+        client.assign(cmd.task_id.clone(), &assignee).await?;
+
+        tracing::info!(
+            task_id = %cmd.task_id.0,
+            kind = %cmd.kind,
+            assignee = %assignee.login,
+            "Task card assigned"
+        );
+
+        Ok(AssignTaskCardExecutorResponse {})
+    }
+}
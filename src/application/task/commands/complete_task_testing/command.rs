@@ -0,0 +1,13 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+
+pub struct CompleteTaskTestingExecutorCommand {
+    pub task_id: TaskId,
+    /// Чей трекер задаёт колонки итогов тестирования
+    pub repository_id: RepositoryId,
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
+    /// `true` — тестирование пройдено, `false` — вернуть в разработку
+    pub passed: bool,
+}
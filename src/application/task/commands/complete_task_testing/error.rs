@@ -0,0 +1,14 @@
+use crate::domain::repository::repositories::repository_task_tracker_repository::FindRepositoryTaskTrackerByRepositoryIdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CompleteTaskTestingExecutorError {
+    #[error("No column is configured for this QA outcome")]
+    NotConfigured,
+
+    #[error("{0}")]
+    FindTrackerError(#[from] FindRepositoryTaskTrackerByRepositoryIdError),
+
+    #[error("Failed to publish task move: {0}")]
+    PublishError(String),
+}
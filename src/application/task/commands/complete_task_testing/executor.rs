@@ -0,0 +1,66 @@
+use crate::application::task::commands::complete_task_testing::command::CompleteTaskTestingExecutorCommand;
+use crate::application::task::commands::complete_task_testing::error::CompleteTaskTestingExecutorError;
+use crate::application::task::commands::complete_task_testing::response::CompleteTaskTestingExecutorResponse;
+use crate::delivery::jobs::consumers::move_task_to_test::payload::MoveTaskToTestJob;
+use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::entities::task_move::TaskMoveOrigin;
+use crate::domain::task::value_objects::task_move_trigger::TaskMoveTrigger;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use std::sync::Arc;
+
+pub struct CompleteTaskTestingExecutor {
+    publisher: Arc<dyn MessageBrokerPublisher>,
+    repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+}
+
+impl CompleteTaskTestingExecutor {
+    pub fn new(
+        publisher: Arc<dyn MessageBrokerPublisher>,
+        repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    ) -> Self {
+        Self {
+            publisher,
+            repository_task_tracker_repo,
+        }
+    }
+}
+
+impl CommandExecutor for CompleteTaskTestingExecutor {
+    type Command = CompleteTaskTestingExecutorCommand;
+    type Response = CompleteTaskTestingExecutorResponse;
+    type Error = CompleteTaskTestingExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let tracker = self
+            .repository_task_tracker_repo
+            .find_by_repository_id(cmd.repository_id)
+            .await?;
+
+        let (trigger, column_id) = if cmd.passed {
+            (TaskMoveTrigger::TestPassed, tracker.qa_passed_column_id)
+        } else {
+            (TaskMoveTrigger::TestFailed, tracker.qa_failed_column_id)
+        };
+
+        let column_id = column_id.ok_or(CompleteTaskTestingExecutorError::NotConfigured)?;
+
+        self.publisher
+            .publish(&MoveTaskToTestJob {
+                task_id: cmd.task_id.clone(),
+                column_id,
+                kind: cmd.kind,
+                connection_id: cmd.connection_id,
+                origin: Some(TaskMoveOrigin {
+                    repository_id: cmd.repository_id,
+                    rule_id: None,
+                    trigger,
+                    pr_number: None,
+                }),
+            })
+            .await
+            .map_err(|e| CompleteTaskTestingExecutorError::PublishError(format!("{:?}", e)))?;
+
+        Ok(CompleteTaskTestingExecutorResponse {})
+    }
+}
@@ -0,0 +1,12 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+
+pub struct CreateTaskCardExecutorCommand {
+    /// Карточка заводится в пространстве трекера этого репозитория
+    pub repository_id: RepositoryId,
+    pub title: String,
+    pub description: String,
+    /// `None` — колонка по умолчанию трекера
+    pub column_id: Option<String>,
+    /// Логин в системе контроля версий; email для трекера берётся из аккаунта
+    pub assignee_login: Option<String>,
+}
@@ -0,0 +1,16 @@
+use crate::domain::repository::repositories::repository_task_tracker_repository::FindRepositoryTaskTrackerByRepositoryIdError;
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClientWriteError;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolveError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CreateTaskCardExecutorError {
+    #[error("{0}")]
+    TrackerNotConfigured(#[from] FindRepositoryTaskTrackerByRepositoryIdError),
+
+    #[error("{0}")]
+    WriteFailed(#[from] TaskTrackerClientWriteError),
+
+    #[error("{0}")]
+    ClientUnavailable(#[from] TaskTrackerClientResolveError),
+}
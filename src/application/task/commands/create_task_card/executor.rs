@@ -0,0 +1,103 @@
+use crate::application::task::commands::create_task_card::command::CreateTaskCardExecutorCommand;
+use crate::application::task::commands::create_task_card::error::CreateTaskCardExecutorError;
+use crate::application::task::commands::create_task_card::response::CreateTaskCardExecutorResponse;
+use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::ports::task_tracker_client::{TaskTrackerAssignee, TaskTrackerCardDraft};
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::task::services::task_tracker_service::TaskTrackerService;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use std::sync::Arc;
+
+pub struct CreateTaskCardExecutor {
+    repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+    task_tracker_service: Arc<dyn TaskTrackerService>,
+    user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+}
+
+impl CreateTaskCardExecutor {
+    pub fn new(
+        repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+        task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+        task_tracker_service: Arc<dyn TaskTrackerService>,
+        user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    ) -> Self {
+        Self {
+            repository_task_tracker_repo,
+            task_tracker_client_resolver,
+            task_tracker_service,
+            user_vc_accounts_repo,
+        }
+    }
+}
+
+impl CommandExecutor for CreateTaskCardExecutor {
+    type Command = CreateTaskCardExecutorCommand;
+    type Response = CreateTaskCardExecutorResponse;
+    type Error = CreateTaskCardExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let tracker = self
+            .repository_task_tracker_repo
+            .find_by_repository_id(cmd.repository_id)
+            .await?;
+
+        let client = self
+            .task_tracker_client_resolver
+            .resolve(tracker.kind, tracker.connection_id)
+            .await?;
+
+        let draft = TaskTrackerCardDraft {
+            title: cmd.title.clone(),
+            description: cmd.description.clone(),
+        };
+
+        let card = client
+            .create_card(&tracker.space_id, cmd.column_id.as_deref(), &draft)
+            .await?;
+
+        tracing::info!(
+            task_id = %card.id.0,
+            kind = %tracker.kind,
+            repository_id = cmd.repository_id.0,
+            "Task card created"
+        );
+
+        // Карточка уже создана — ошибка назначения не должна её терять
+        let mut assigned = true;
+        if let Some(login) = &cmd.assignee_login {
+            // В боте привязываются только аккаунты GitHub
+            let email = self
+                .user_vc_accounts_repo
+                .find_by_login(&VersionControlType::Github, login)
+                .await
+                .ok()
+                .and_then(|account| account.version_control_email);
+
+            let assignee = TaskTrackerAssignee {
+                login: login.clone(),
+                email,
+            };
+
+            if let Err(e) = client.assign(card.id.clone(), &assignee).await {
+                tracing::warn!(error = %e, task_id = %card.id.0, assignee = %login, "Failed to assign created task card");
+                assigned = false;
+            }
+        }
+
+        let branch_name = self.task_tracker_service.suggest_branch_name(
+            &card.id,
+            &cmd.title,
+            &tracker.extract_pattern_regexp,
+        );
+
+        Ok(CreateTaskCardExecutorResponse {
+            task_id: card.id,
+            url: card.url,
+            branch_name,
+            assigned,
+        })
+    }
+}
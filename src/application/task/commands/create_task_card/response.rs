@@ -0,0 +1,10 @@
+use crate::domain::task::value_objects::task_id::TaskId;
+
+pub struct CreateTaskCardExecutorResponse {
+    pub task_id: TaskId,
+    pub url: String,
+    /// Ветка, из которой паттерн репозитория извлечёт id задачи
+    pub branch_name: Option<String>,
+    /// `false` — исполнитель был указан, но трекер его не принял
+    pub assigned: bool,
+}
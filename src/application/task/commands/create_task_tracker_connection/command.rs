@@ -0,0 +1,10 @@
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+
+pub struct CreateTaskTrackerConnectionCommand {
+    pub kind: TaskTrackerKind,
+    pub name: String,
+    pub base_url: String,
+    pub email: Option<String>,
+    /// Токен в открытом виде — шифруется перед сохранением
+    pub api_token: String,
+}
@@ -0,0 +1,23 @@
+use crate::domain::task::repositories::task_tracker_connection_repository::CreateTaskTrackerConnectionError;
+use crate::utils::security::crypto::reversible::CipherError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CreateTaskTrackerConnectionExecutorError {
+    #[error("Task tracker rejected the credentials: {0}")]
+    InvalidCredentials(String),
+
+    #[error("Cipher error: {0}")]
+    CipherError(#[from] CipherError),
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<CreateTaskTrackerConnectionError> for CreateTaskTrackerConnectionExecutorError {
+    fn from(e: CreateTaskTrackerConnectionError) -> Self {
+        match e {
+            CreateTaskTrackerConnectionError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::application::task::commands::create_task_tracker_connection::command::CreateTaskTrackerConnectionCommand;
+use crate::application::task::commands::create_task_tracker_connection::error::CreateTaskTrackerConnectionExecutorError;
+use crate::application::task::commands::create_task_tracker_connection::response::CreateTaskTrackerConnectionResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::entities::task_tracker_connection::TaskTrackerConnection;
+use crate::domain::task::ports::task_tracker_client_factory::{
+    TaskTrackerClientFactory, TaskTrackerCredentials,
+};
+use crate::domain::task::repositories::task_tracker_connection_repository::TaskTrackerConnectionRepository;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub struct CreateTaskTrackerConnectionExecutor {
+    task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>,
+    task_tracker_client_factory: Arc<dyn TaskTrackerClientFactory>,
+    reversible_cipher: Arc<ReversibleCipher>,
+}
+
+impl CreateTaskTrackerConnectionExecutor {
+    pub fn new(
+        task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>,
+        task_tracker_client_factory: Arc<dyn TaskTrackerClientFactory>,
+        reversible_cipher: Arc<ReversibleCipher>,
+    ) -> Self {
+        Self {
+            task_tracker_connection_repo,
+            task_tracker_client_factory,
+            reversible_cipher,
+        }
+    }
+}
+
+impl CommandExecutor for CreateTaskTrackerConnectionExecutor {
+    type Command = CreateTaskTrackerConnectionCommand;
+    type Response = CreateTaskTrackerConnectionResponse;
+    type Error = CreateTaskTrackerConnectionExecutorError;
+
+    /// Сохраняем только подключение, с которым трекер отдаёт список пространств
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let client = self
+            .task_tracker_client_factory
+            .build(&TaskTrackerCredentials {
+                kind: cmd.kind,
+                base_url: cmd.base_url.clone(),
+                email: cmd.email.clone(),
+                api_token: cmd.api_token.clone(),
+            });
+
+        client.list_spaces().await.map_err(|e| {
+            CreateTaskTrackerConnectionExecutorError::InvalidCredentials(e.to_string())
+        })?;
+
+        let connection = TaskTrackerConnection {
+            id: Default::default(),
+            kind: cmd.kind,
+            name: cmd.name.clone(),
+            base_url: cmd.base_url.trim_end_matches('/').to_string(),
+            email: cmd.email.clone(),
+            api_token: self.reversible_cipher.encrypt(&cmd.api_token)?,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let connection = self
+            .task_tracker_connection_repo
+            .create(&connection)
+            .await?;
+
+        tracing::info!(
+            connection_id = connection.id,
+            kind = %connection.kind,
+            "Task tracker connection created"
+        );
+
+        Ok(CreateTaskTrackerConnectionResponse { connection })
+    }
+}
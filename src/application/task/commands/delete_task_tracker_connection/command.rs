@@ -0,0 +1,3 @@
+pub struct DeleteTaskTrackerConnectionCommand {
+    pub id: i32,
+}
@@ -0,0 +1,24 @@
+use crate::domain::task::repositories::task_tracker_connection_repository::DeleteTaskTrackerConnectionError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeleteTaskTrackerConnectionExecutorError {
+    #[error("Task tracker connection not found")]
+    NotFound,
+
+    #[error("Task tracker connection is used by repositories")]
+    InUse,
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<DeleteTaskTrackerConnectionError> for DeleteTaskTrackerConnectionExecutorError {
+    fn from(e: DeleteTaskTrackerConnectionError) -> Self {
+        match e {
+            DeleteTaskTrackerConnectionError::NotFound => Self::NotFound,
+            DeleteTaskTrackerConnectionError::InUse => Self::InUse,
+            DeleteTaskTrackerConnectionError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::application::task::commands::delete_task_tracker_connection::command::DeleteTaskTrackerConnectionCommand;
+use crate::application::task::commands::delete_task_tracker_connection::error::DeleteTaskTrackerConnectionExecutorError;
+use crate::application::task::commands::delete_task_tracker_connection::response::DeleteTaskTrackerConnectionResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::repositories::task_tracker_connection_repository::TaskTrackerConnectionRepository;
+use std::sync::Arc;
+
+pub struct DeleteTaskTrackerConnectionExecutor {
+    task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>,
+}
+
+impl DeleteTaskTrackerConnectionExecutor {
+    pub fn new(task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>) -> Self {
+        Self {
+            task_tracker_connection_repo,
+        }
+    }
+}
+
+impl CommandExecutor for DeleteTaskTrackerConnectionExecutor {
+    type Command = DeleteTaskTrackerConnectionCommand;
+    type Response = DeleteTaskTrackerConnectionResponse;
+    type Error = DeleteTaskTrackerConnectionExecutorError;
+
+    /// Пока подключение указано в настройках репозиториев, удалить его нельзя (FK on delete restrict)
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        self.task_tracker_connection_repo.delete(cmd.id).await?;
+
+        Ok(DeleteTaskTrackerConnectionResponse)
+    }
+}
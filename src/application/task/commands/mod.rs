@@ -1 +1,7 @@
+pub mod annotate_task_card;
+pub mod assign_task_card;
+pub mod complete_task_testing;
+pub mod create_task_card;
+pub mod create_task_tracker_connection;
+pub mod delete_task_tracker_connection;
 pub mod move_task_to_test;
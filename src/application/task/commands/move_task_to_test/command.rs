@@ -1,6 +1,12 @@
+use crate::domain::task::entities::task_move::TaskMoveOrigin;
 use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 
 pub struct MoveTaskToTestExecutorCommand {
     pub task_id: TaskId,
-    pub column_id: u64,
+    pub column_id: String,
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
+    /// `None` — перевод не пишется в журнал
+    pub origin: Option<TaskMoveOrigin>,
 }
@@ -1,8 +1,12 @@
 use crate::domain::task::ports::task_tracker_client::TaskTrackerClientMoveToColumnError;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolveError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum MoveTaskToTestExecutorError {
     #[error("{0}")]
     TaskTrackerClientMoveToColumnError(#[from] TaskTrackerClientMoveToColumnError),
+
+    #[error("{0}")]
+    TaskTrackerClientResolveError(#[from] TaskTrackerClientResolveError),
 }
@@ -2,25 +2,47 @@ use crate::application::task::commands::move_task_to_test::command::MoveTaskToTe
 use crate::application::task::commands::move_task_to_test::error::MoveTaskToTestExecutorError;
 use crate::application::task::commands::move_task_to_test::response::MoveTaskToTestExecutorResponse;
 use crate::domain::shared::command::CommandExecutor;
-use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::entities::task_move::TaskMove;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::task::repositories::task_move_repository::TaskMoveRepository;
 use crate::domain::task::services::task_tracker_service::TaskTrackerService;
+use chrono::Utc;
 use std::sync::Arc;
 
 pub struct MoveTaskToTestExecutor {
-    task_tracker_client: Arc<dyn TaskTrackerClient>,
+    task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
     task_tracker_service: Arc<dyn TaskTrackerService>,
+    task_move_repo: Arc<dyn TaskMoveRepository>,
 }
 
 impl MoveTaskToTestExecutor {
     pub fn new(
-        task_tracker_client: Arc<dyn TaskTrackerClient>,
+        task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
         task_tracker_service: Arc<dyn TaskTrackerService>,
+        task_move_repo: Arc<dyn TaskMoveRepository>,
     ) -> Self {
         Self {
-            task_tracker_client,
+            task_tracker_client_resolver,
             task_tracker_service,
+            task_move_repo,
         }
     }
+
+    async fn move_task(
+        &self,
+        cmd: &MoveTaskToTestExecutorCommand,
+    ) -> Result<(), MoveTaskToTestExecutorError> {
+        let client = self
+            .task_tracker_client_resolver
+            .resolve(cmd.kind, cmd.connection_id)
+            .await?;
+
+        client
+            .move_task_to_column(cmd.task_id.clone(), &cmd.column_id)
+            .await?;
+
+        Ok(())
+    }
 }
 
 impl CommandExecutor for MoveTaskToTestExecutor {
@@ -29,19 +51,40 @@ impl CommandExecutor for MoveTaskToTestExecutor {
     type Error = MoveTaskToTestExecutorError;
 
     async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
-        self.task_tracker_client
-            .move_task_to_column(cmd.task_id, cmd.column_id)
-            .await
-            .inspect_err(|e| {
-                tracing::error!(
-                    task_id = %cmd.task_id.0,
-                    column_id = %cmd.column_id,
-                    error = %e,
-                    "Failed to move task to test column"
-                );
-            })?;
-
-        tracing::info!(task_id = %cmd.task_id.0, column_id = %cmd.column_id, "Task moved to test column");
+        let result = self.move_task(cmd).await;
+
+        // Каждая попытка попадает в журнал, включая неудачные перед ретраем
+        if let Some(origin) = &cmd.origin {
+            let task_move = TaskMove {
+                id: 0,
+                repository_id: origin.repository_id,
+                rule_id: origin.rule_id,
+                trigger: origin.trigger,
+                task_id: cmd.task_id.clone(),
+                column_id: cmd.column_id.clone(),
+                pr_number: origin.pr_number,
+                error: result.as_ref().err().map(|e| e.to_string()),
+                created_at: Utc::now(),
+            };
+
+            match self.task_move_repo.create(&task_move).await {
+                Ok(saved) => tracing::debug!(move_id = saved.id, "Task move recorded"),
+                Err(e) => {
+                    tracing::warn!(task_id = %cmd.task_id.0, error = %e, "Failed to record task move")
+                }
+            }
+        }
+
+        result.inspect_err(|e| {
+            tracing::error!(
+                task_id = %cmd.task_id.0,
+                column_id = %cmd.column_id,
+                error = %e,
+                "Failed to move task to column"
+            );
+        })?;
+
+        tracing::info!(task_id = %cmd.task_id.0, column_id = %cmd.column_id, "Task moved to column");
 
         Ok(MoveTaskToTestExecutorResponse {})
     }
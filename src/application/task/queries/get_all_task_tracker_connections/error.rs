@@ -0,0 +1,17 @@
+use crate::domain::task::repositories::task_tracker_connection_repository::FindTaskTrackerConnectionError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetAllTaskTrackerConnectionsError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<FindTaskTrackerConnectionError> for GetAllTaskTrackerConnectionsError {
+    fn from(e: FindTaskTrackerConnectionError) -> Self {
+        match e {
+            FindTaskTrackerConnectionError::DbError(msg) => Self::DbError(msg),
+            FindTaskTrackerConnectionError::NotFound => Self::DbError("Not found".to_string()),
+        }
+    }
+}
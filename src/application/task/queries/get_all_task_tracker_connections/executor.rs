@@ -0,0 +1,30 @@
+use crate::application::task::queries::get_all_task_tracker_connections::error::GetAllTaskTrackerConnectionsError;
+use crate::application::task::queries::get_all_task_tracker_connections::query::GetAllTaskTrackerConnectionsQuery;
+use crate::application::task::queries::get_all_task_tracker_connections::response::GetAllTaskTrackerConnectionsResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::repositories::task_tracker_connection_repository::TaskTrackerConnectionRepository;
+use std::sync::Arc;
+
+pub struct GetAllTaskTrackerConnectionsExecutor {
+    task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>,
+}
+
+impl GetAllTaskTrackerConnectionsExecutor {
+    pub fn new(task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>) -> Self {
+        Self {
+            task_tracker_connection_repo,
+        }
+    }
+}
+
+impl CommandExecutor for GetAllTaskTrackerConnectionsExecutor {
+    type Command = GetAllTaskTrackerConnectionsQuery;
+    type Response = GetAllTaskTrackerConnectionsResponse;
+    type Error = GetAllTaskTrackerConnectionsError;
+
+    async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let connections = self.task_tracker_connection_repo.find_all().await?;
+
+        Ok(GetAllTaskTrackerConnectionsResponse { connections })
+    }
+}
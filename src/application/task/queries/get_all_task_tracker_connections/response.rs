@@ -0,0 +1,5 @@
+use crate::domain::task::entities::task_tracker_connection::TaskTrackerConnection;
+
+pub struct GetAllTaskTrackerConnectionsResponse {
+    pub connections: Vec<TaskTrackerConnection>,
+}
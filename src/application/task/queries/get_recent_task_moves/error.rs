@@ -0,0 +1,16 @@
+use crate::domain::task::repositories::task_move_repository::FindTaskMovesError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetRecentTaskMovesError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<FindTaskMovesError> for GetRecentTaskMovesError {
+    fn from(e: FindTaskMovesError) -> Self {
+        match e {
+            FindTaskMovesError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::application::task::queries::get_recent_task_moves::error::GetRecentTaskMovesError;
+use crate::application::task::queries::get_recent_task_moves::query::GetRecentTaskMovesQuery;
+use crate::application::task::queries::get_recent_task_moves::response::GetRecentTaskMovesResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::repositories::task_move_repository::TaskMoveRepository;
+use std::sync::Arc;
+
+pub struct GetRecentTaskMovesExecutor {
+    task_move_repo: Arc<dyn TaskMoveRepository>,
+}
+
+impl GetRecentTaskMovesExecutor {
+    pub fn new(task_move_repo: Arc<dyn TaskMoveRepository>) -> Self {
+        Self { task_move_repo }
+    }
+}
+
+impl CommandExecutor for GetRecentTaskMovesExecutor {
+    type Command = GetRecentTaskMovesQuery;
+    type Response = GetRecentTaskMovesResponse;
+    type Error = GetRecentTaskMovesError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let moves = self
+            .task_move_repo
+            .find_recent_by_repository_id(cmd.repository_id, cmd.limit)
+            .await?;
+
+        Ok(GetRecentTaskMovesResponse { moves })
+    }
+}
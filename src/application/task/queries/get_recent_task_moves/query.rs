@@ -0,0 +1,6 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+
+pub struct GetRecentTaskMovesQuery {
+    pub repository_id: RepositoryId,
+    pub limit: u64,
+}
@@ -0,0 +1,5 @@
+use crate::domain::task::entities::task_move::TaskMove;
+
+pub struct GetRecentTaskMovesResponse {
+    pub moves: Vec<TaskMove>,
+}
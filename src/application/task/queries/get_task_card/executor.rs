@@ -2,19 +2,18 @@ use crate::application::task::queries::get_task_card::error::GetTaskCardError;
 use crate::application::task::queries::get_task_card::query::GetTaskCardQuery;
 use crate::application::task::queries::get_task_card::response::GetTaskCardResponse;
 use crate::domain::shared::command::CommandExecutor;
-use crate::domain::task::ports::task_tracker_client::{
-    TaskTrackerClient, TaskTrackerClientGetCardError,
-};
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClientGetCardError;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
 use std::sync::Arc;
 
 pub struct GetTaskCardExecutor {
-    pub task_tracker_client: Arc<dyn TaskTrackerClient>,
+    pub task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
 }
 
 impl GetTaskCardExecutor {
-    pub fn new(task_tracker_client: Arc<dyn TaskTrackerClient>) -> Self {
+    pub fn new(task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>) -> Self {
         Self {
-            task_tracker_client,
+            task_tracker_client_resolver,
         }
     }
 }
@@ -24,21 +23,51 @@ impl CommandExecutor for GetTaskCardExecutor {
     type Response = GetTaskCardResponse;
     type Error = GetTaskCardError;
 
+    /// Трекер по id однозначно не определить — спрашиваем по очереди
+    /// все настроенные и подключённые, которые понимают такой формат id
     async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
-        let card = self
-            .task_tracker_client
-            .get_card(cmd.task_id)
+        let sources = self
+            .task_tracker_client_resolver
+            .sources()
             .await
-            .map_err(|e| match e {
-                TaskTrackerClientGetCardError::NotFound => GetTaskCardError::NotFound,
-                TaskTrackerClientGetCardError::ClientError(msg) => {
-                    GetTaskCardError::ClientError(msg)
+            .map_err(|e| GetTaskCardError::ClientError(e.to_string()))?;
+
+        let mut last_error = GetTaskCardError::NotFound;
+
+        for source in sources {
+            let kind = source.kind;
+
+            if !kind.accepts(&cmd.task_id) {
+                continue;
+            }
+
+            let client = match self
+                .task_tracker_client_resolver
+                .resolve(kind, source.connection_id)
+                .await
+            {
+                Ok(client) => client,
+                Err(e) => {
+                    tracing::warn!(kind = %kind, error = %e, "Task tracker client unavailable");
+                    continue;
                 }
-            })?;
+            };
+
+            match client.get_card(cmd.task_id.clone()).await {
+                Ok(card) => {
+                    return Ok(GetTaskCardResponse {
+                        title: card.title,
+                        url: card.url,
+                    });
+                }
+                Err(TaskTrackerClientGetCardError::NotFound) => continue,
+                Err(TaskTrackerClientGetCardError::ClientError(msg)) => {
+                    tracing::warn!(kind = %kind, error = %msg, "Task tracker lookup failed");
+                    last_error = GetTaskCardError::ClientError(msg);
+                }
+            }
+        }
 
-        Ok(GetTaskCardResponse {
-            title: card.title,
-            url: card.url,
-        })
+        Err(last_error)
     }
 }
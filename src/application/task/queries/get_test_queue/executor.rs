@@ -0,0 +1,225 @@
+use crate::application::task::queries::get_test_queue::error::GetTestQueueError;
+use crate::application::task::queries::get_test_queue::query::GetTestQueueQuery;
+use crate::application::task::queries::get_test_queue::response::{
+    GetTestQueueResponse, TestQueueCard, TestQueuePullRequest,
+};
+use crate::domain::commit_check::entities::commit_checks_summary::CommitChecksSummary;
+use crate::domain::commit_check::repositories::commit_check_repository::CommitCheckRepository;
+use crate::domain::repository::entities::repository::Repository;
+use crate::domain::repository::entities::repository_task_tracker::RepositoryTaskTracker;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::repository::repositories::repository_task_tracker_repository::{
+    FindRepositoryTaskTrackerByRepositoryIdError, RepositoryTaskTrackerRepository,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::entities::pull_request_task_link::PullRequestTaskLink;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::task::repositories::pull_request_task_link_repository::PullRequestTaskLinkRepository;
+use std::sync::Arc;
+
+pub struct GetTestQueueExecutor {
+    repository_repo: Arc<dyn RepositoryRepository>,
+    repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+    pull_request_task_link_repo: Arc<dyn PullRequestTaskLinkRepository>,
+    commit_check_repo: Arc<dyn CommitCheckRepository>,
+}
+
+/// Репозитории, которые смотрят в одну и ту же QA-колонку: её читаем один раз.
+/// Колонки итогов тестирования у них могут различаться — трекер храним для каждого
+struct QaColumn {
+    tracker: RepositoryTaskTracker,
+    repositories: Vec<(Repository, RepositoryTaskTracker)>,
+}
+
+impl QaColumn {
+    fn same_column(&self, tracker: &RepositoryTaskTracker) -> bool {
+        self.tracker.kind == tracker.kind
+            && self.tracker.connection_id == tracker.connection_id
+            && self.tracker.space_id == tracker.space_id
+            && self.tracker.qa_column_id == tracker.qa_column_id
+    }
+}
+
+impl GetTestQueueExecutor {
+    pub fn new(
+        repository_repo: Arc<dyn RepositoryRepository>,
+        repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+        task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+        pull_request_task_link_repo: Arc<dyn PullRequestTaskLinkRepository>,
+        commit_check_repo: Arc<dyn CommitCheckRepository>,
+    ) -> Self {
+        Self {
+            repository_repo,
+            repository_task_tracker_repo,
+            task_tracker_client_resolver,
+            pull_request_task_link_repo,
+            commit_check_repo,
+        }
+    }
+
+    async fn qa_columns(&self) -> Result<Vec<QaColumn>, GetTestQueueError> {
+        let repos = self
+            .repository_repo
+            .find_all()
+            .await
+            .map_err(|e| GetTestQueueError::DbError(e.to_string()))?;
+
+        let mut columns: Vec<QaColumn> = Vec::new();
+
+        for repo in repos.into_iter().filter(|r| !r.is_archived) {
+            let tracker = match self
+                .repository_task_tracker_repo
+                .find_by_repository_id(repo.id)
+                .await
+            {
+                Ok(tracker) => tracker,
+                Err(FindRepositoryTaskTrackerByRepositoryIdError::NotFound) => continue,
+                Err(FindRepositoryTaskTrackerByRepositoryIdError::DbError(e)) => {
+                    return Err(GetTestQueueError::DbError(e));
+                }
+            };
+
+            match columns.iter_mut().find(|c| c.same_column(&tracker)) {
+                Some(column) => column.repositories.push((repo, tracker)),
+                None => columns.push(QaColumn {
+                    tracker: tracker.clone(),
+                    repositories: vec![(repo, tracker)],
+                }),
+            }
+        }
+
+        Ok(columns)
+    }
+
+    async fn pull_request(
+        &self,
+        repo: &Repository,
+        link: PullRequestTaskLink,
+    ) -> TestQueuePullRequest {
+        let repository = format!("{}/{}", repo.owner, repo.name);
+
+        let ci_state = match &link.head_sha {
+            Some(head_sha) => match self
+                .commit_check_repo
+                .find_by_commit(&repository, head_sha)
+                .await
+            {
+                Ok(checks) if !checks.is_empty() => {
+                    Some(CommitChecksSummary::from_checks(&checks).state)
+                }
+                Ok(_) => None,
+                Err(e) => {
+                    tracing::warn!(error = %e, repo = %repository, "Failed to load commit checks for test queue");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        TestQueuePullRequest {
+            repository,
+            number: link.pr_number,
+            title: link.pr_title,
+            url: link.pr_url,
+            author_login: link.author_login,
+            merged_at: link.merged_at,
+            ci_state,
+        }
+    }
+}
+
+impl CommandExecutor for GetTestQueueExecutor {
+    type Command = GetTestQueueQuery;
+    type Response = GetTestQueueResponse;
+    type Error = GetTestQueueError;
+
+    async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let mut cards = Vec::new();
+        let mut failed_trackers = Vec::new();
+
+        for column in self.qa_columns().await? {
+            let tracker = &column.tracker;
+
+            let listed = match self
+                .task_tracker_client_resolver
+                .resolve(tracker.kind, tracker.connection_id)
+                .await
+            {
+                Ok(client) => client
+                    .list_cards(&tracker.space_id, &tracker.qa_column_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+
+            let listed = match listed {
+                Ok(listed) => listed,
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        kind = %tracker.kind,
+                        column_id = %tracker.qa_column_id,
+                        "Failed to list QA column cards"
+                    );
+                    failed_trackers.push(tracker.kind);
+                    continue;
+                }
+            };
+
+            for card in listed {
+                let links = self
+                    .pull_request_task_link_repo
+                    .find_by_task_id(&card.id)
+                    .await
+                    .map_err(|e| GetTestQueueError::DbError(e.to_string()))?;
+
+                let links: Vec<(&Repository, PullRequestTaskLink)> = links
+                    .into_iter()
+                    .filter_map(|link| {
+                        column
+                            .repositories
+                            .iter()
+                            .find(|(r, _)| r.id == link.repository_id)
+                            .map(|(repo, _)| (repo, link))
+                    })
+                    .collect();
+
+                // Колонки итогов тестирования — у репозитория самого свежего PR по задаче
+                let repository_id = links
+                    .first()
+                    .map(|(repo, _)| repo.id)
+                    .unwrap_or(column.repositories[0].0.id);
+
+                let owner = column
+                    .repositories
+                    .iter()
+                    .find(|(repo, _)| repo.id == repository_id)
+                    .map(|(_, tracker)| tracker)
+                    .unwrap_or(tracker);
+
+                let mut pull_requests = Vec::with_capacity(links.len());
+                for (repo, link) in links {
+                    pull_requests.push(self.pull_request(repo, link).await);
+                }
+
+                cards.push(TestQueueCard {
+                    task_id: card.id,
+                    title: card.title,
+                    url: card.url,
+                    repository_id,
+                    kind: tracker.kind,
+                    connection_id: tracker.connection_id,
+                    can_pass: owner.qa_passed_column_id.is_some(),
+                    can_fail: owner.qa_failed_column_id.is_some(),
+                    pull_requests,
+                });
+            }
+        }
+
+        Ok(GetTestQueueResponse {
+            cards,
+            failed_trackers,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use chrono::{DateTime, Utc};
+
+pub struct GetTestQueueResponse {
+    pub cards: Vec<TestQueueCard>,
+    /// Трекеры, которые не ответили: очередь по ним неполная
+    pub failed_trackers: Vec<TaskTrackerKind>,
+}
+
+/// Карточка из QA-колонки вместе с PR, в которых она упомянута
+pub struct TestQueueCard {
+    pub task_id: TaskId,
+    pub title: String,
+    pub url: String,
+    /// Репозиторий, трекер которого задаёт колонки итогов тестирования
+    pub repository_id: RepositoryId,
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
+    /// У трекера репозитория задана колонка для принятых задач
+    pub can_pass: bool,
+    /// У трекера репозитория задана колонка для возврата в разработку
+    pub can_fail: bool,
+    pub pull_requests: Vec<TestQueuePullRequest>,
+}
+
+pub struct TestQueuePullRequest {
+    pub repository: String,
+    pub number: u64,
+    pub title: String,
+    pub url: Option<String>,
+    pub author_login: Option<String>,
+    pub merged_at: Option<DateTime<Utc>>,
+    /// `None` — head-коммит неизвестен или проверок по нему нет
+    pub ci_state: Option<CommitCiState>,
+}
@@ -1 +1,4 @@
+pub mod get_all_task_tracker_connections;
+pub mod get_recent_task_moves;
 pub mod get_task_card;
+pub mod get_test_queue;
@@ -0,0 +1,7 @@
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+
+pub struct ConfirmSlackAccountLinkCommand {
+    pub social_user_id: SocialUserId,
+    /// Код из личного сообщения бота в Slack
+    pub code: String,
+}
@@ -0,0 +1,24 @@
+use crate::domain::user::repositories::user_slack_accounts_repository::UpsertUserSlackAccountError;
+use crate::domain::user::repositories::user_social_accounts_repository::FindSocialServiceByIdError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfirmSlackAccountLinkExecutorError {
+    #[error("{0}")]
+    UserNotFound(#[from] FindSocialServiceByIdError),
+
+    #[error("No pending Slack link or it has expired")]
+    NotRequested,
+
+    #[error("Invalid confirmation code")]
+    InvalidCode,
+
+    #[error("Cache error: {0}")]
+    Cache(String),
+
+    #[error("{0}")]
+    Deserialize(#[from] serde_json::Error),
+
+    #[error("{0}")]
+    UpsertError(#[from] UpsertUserSlackAccountError),
+}
@@ -0,0 +1,69 @@
+use crate::application::user::commands::confirm_slack_account_link::command::ConfirmSlackAccountLinkCommand;
+use crate::application::user::commands::confirm_slack_account_link::error::ConfirmSlackAccountLinkExecutorError;
+use crate::application::user::commands::confirm_slack_account_link::response::ConfirmSlackAccountLinkResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::entities::pending_slack_account_link::PendingSlackAccountLink;
+use crate::domain::user::entities::user_slack_account::UserSlackAccount;
+use crate::domain::user::repositories::user_slack_accounts_repository::UserSlackAccountsRepository;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::infrastructure::drivers::cache::contract::CacheService;
+use std::sync::Arc;
+
+pub struct ConfirmSlackAccountLinkExecutor {
+    user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository>,
+    cache: Arc<dyn CacheService>,
+}
+
+impl ConfirmSlackAccountLinkExecutor {
+    pub fn new(
+        user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+        user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository>,
+        cache: Arc<dyn CacheService>,
+    ) -> Self {
+        Self {
+            user_socials_repo,
+            user_slack_accounts_repo,
+            cache,
+        }
+    }
+}
+
+impl CommandExecutor for ConfirmSlackAccountLinkExecutor {
+    type Command = ConfirmSlackAccountLinkCommand;
+    type Response = ConfirmSlackAccountLinkResponse;
+    type Error = ConfirmSlackAccountLinkExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let social_user = self
+            .user_socials_repo
+            .find_by_social_user_id(&cmd.social_user_id)
+            .await?;
+
+        // Одна попытка на код: после ошибки нужно запросить новый — код не подобрать
+        let pending = self
+            .cache
+            .take(&PendingSlackAccountLink::cache_key(&social_user.user_id))
+            .await
+            .map_err(ConfirmSlackAccountLinkExecutorError::Cache)?
+            .ok_or(ConfirmSlackAccountLinkExecutorError::NotRequested)?;
+
+        let pending: PendingSlackAccountLink = serde_json::from_str(&pending)?;
+
+        if pending.code != cmd.code.trim() {
+            return Err(ConfirmSlackAccountLinkExecutorError::InvalidCode);
+        }
+
+        let account = self
+            .user_slack_accounts_repo
+            .upsert(&UserSlackAccount {
+                user_id: social_user.user_id,
+                slack_user_id: pending.slack_user_id,
+            })
+            .await?;
+
+        Ok(ConfirmSlackAccountLinkResponse {
+            slack_user_id: account.slack_user_id,
+        })
+    }
+}
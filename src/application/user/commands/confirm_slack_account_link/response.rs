@@ -0,0 +1,5 @@
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+
+pub struct ConfirmSlackAccountLinkResponse {
+    pub slack_user_id: SlackChannelId,
+}
@@ -0,0 +1,7 @@
+use crate::domain::user::entities::pending_version_control_account_link::PendingVersionControlAccountLink;
+
+#[derive(Debug, Clone)]
+pub struct LinkVersionControlAccountCommand {
+    pub code: String,
+    pub pending: PendingVersionControlAccountLink,
+}
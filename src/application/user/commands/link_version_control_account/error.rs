@@ -0,0 +1,31 @@
+use crate::domain::auth::ports::oauth_client::OAuthClientExchangeCodeError;
+use crate::domain::user::repositories::user_vc_accounts_repository::{
+    CreateVersionControlServiceError, DeleteVersionControlServiceError,
+};
+use crate::domain::version_control::ports::version_control_client::VersionControlClientGetUserError;
+use crate::utils::security::crypto::reversible::CipherError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LinkVersionControlAccountExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Account '{0}' is already linked to another user")]
+    AlreadyLinked(String),
+
+    #[error("{0}")]
+    OAuthClientExchangeCodeError(#[from] OAuthClientExchangeCodeError),
+
+    #[error("{0}")]
+    VersionControlClientError(#[from] VersionControlClientGetUserError),
+
+    #[error("{0}")]
+    CreateVersionControlServiceError(#[from] CreateVersionControlServiceError),
+
+    #[error("{0}")]
+    DeleteVersionControlServiceError(#[from] DeleteVersionControlServiceError),
+
+    #[error("{0}")]
+    CipherError(#[from] CipherError),
+}
@@ -0,0 +1,119 @@
+use crate::application::user::commands::link_version_control_account::command::LinkVersionControlAccountCommand;
+use crate::application::user::commands::link_version_control_account::error::LinkVersionControlAccountExecutorError;
+use crate::application::user::commands::link_version_control_account::response::LinkVersionControlAccountResponse;
+use crate::domain::auth::ports::oauth_client::OAuthClient;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::entities::user_vc_account::UserVersionControlAccount;
+use crate::domain::user::repositories::user_vc_accounts_repository::{
+    FindVersionControlServiceByLoginError, UserVersionControlAccountsRepository,
+};
+use crate::domain::user::value_objects::version_control_user_id::VersionControlUserId;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::utils::mutex::key_locker::KeyLocker;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use std::sync::Arc;
+
+/// Привязывает к зарегистрированному пользователю аккаунт ещё одного провайдера
+/// (сейчас GitLab). Прежний аккаунт того же провайдера заменяется
+pub struct LinkVersionControlAccountExecutor {
+    pub db: Arc<DatabaseConnection>,
+    pub user_version_control_service_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    pub oauth_client: Arc<dyn OAuthClient>,
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub reversible_cipher: Arc<ReversibleCipher>,
+    pub mutex: Arc<KeyLocker<String>>,
+}
+
+impl CommandExecutor for LinkVersionControlAccountExecutor {
+    type Command = LinkVersionControlAccountCommand;
+    type Response = LinkVersionControlAccountResponse;
+    type Error = LinkVersionControlAccountExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let _guard = self.mutex.lock(cmd.code.clone()).await;
+
+        let version_control_type = &cmd.pending.version_control_type;
+
+        let exchange_code_response = self.oauth_client.exchange_code(&cmd.code).await?;
+
+        let version_control_client_user = self
+            .version_control_clients
+            .client(version_control_type)
+            .get_user(&exchange_code_response.access_token)
+            .await?;
+
+        // Логин провайдера определяет адресата уведомлений — у двух пользователей
+        // он совпадать не должен
+        match self
+            .user_version_control_service_repo
+            .find_by_login(version_control_type, &version_control_client_user.login)
+            .await
+        {
+            Ok(existing) if existing.user_id != cmd.pending.user_id => {
+                return Err(LinkVersionControlAccountExecutorError::AlreadyLinked(
+                    version_control_client_user.login,
+                ));
+            }
+            Ok(_) | Err(FindVersionControlServiceByLoginError::NotFound) => {}
+            Err(FindVersionControlServiceByLoginError::DbError(e)) => {
+                return Err(LinkVersionControlAccountExecutorError::DbError(e));
+            }
+        }
+
+        let encrypted_access_token = self
+            .reversible_cipher
+            .encrypt(&exchange_code_response.access_token)?;
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| LinkVersionControlAccountExecutorError::DbError(e.to_string()))?;
+
+        self.user_version_control_service_repo
+            .delete_by_user_id(&txn, version_control_type, &cmd.pending.user_id)
+            .await?;
+
+        let account = self
+            .user_version_control_service_repo
+            .create(
+                &txn,
+                &UserVersionControlAccount {
+                    id: Default::default(),
+                    user_id: cmd.pending.user_id,
+                    version_control_type: version_control_type.clone(),
+                    version_control_user_id: VersionControlUserId(
+                        version_control_client_user.id as i32,
+                    ),
+                    version_control_login: version_control_client_user.login.clone(),
+                    version_control_email: version_control_client_user.email.clone(),
+                    version_control_avatar_url: None,
+                    access_token: encrypted_access_token,
+                    refresh_token: None,
+                    token_type: Some(exchange_code_response.token_type),
+                    scope: Some(exchange_code_response.scope),
+                    expires_at: exchange_code_response
+                        .expires_in
+                        .map(|expires_in| Utc::now().timestamp() + expires_in),
+                    created_at: Default::default(),
+                    updated_at: Default::default(),
+                },
+            )
+            .await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| LinkVersionControlAccountExecutorError::DbError(e.to_string()))?;
+
+        tracing::info!(
+            user_id = cmd.pending.user_id.0,
+            version_control_type = %version_control_type,
+            login = %account.version_control_login,
+            "Version control account linked"
+        );
+
+        Ok(LinkVersionControlAccountResponse { account })
+    }
+}
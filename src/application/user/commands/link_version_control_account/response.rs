@@ -0,0 +1,6 @@
+use crate::domain::user::entities::user_vc_account::UserVersionControlAccount;
+
+#[derive(Debug)]
+pub struct LinkVersionControlAccountResponse {
+    pub account: UserVersionControlAccount,
+}
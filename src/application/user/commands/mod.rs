@@ -1,7 +1,13 @@
 pub mod assign_user_role;
 pub mod bind_repository;
+pub mod confirm_slack_account_link;
 pub mod deactivate_user;
+pub mod link_version_control_account;
 pub mod register_via_oauth;
 pub mod remove_user_role;
+pub mod request_slack_account_link;
+pub mod request_version_control_account_link;
+pub mod sync_org_membership;
 pub mod toggle_user_active;
 pub mod unbind_repository;
+pub mod unlink_slack_account;
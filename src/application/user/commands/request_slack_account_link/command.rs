@@ -0,0 +1,8 @@
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+
+pub struct RequestSlackAccountLinkCommand {
+    pub social_user_id: SocialUserId,
+    /// Member ID (`U…`), которому уйдёт код подтверждения
+    pub slack_user_id: SlackChannelId,
+}
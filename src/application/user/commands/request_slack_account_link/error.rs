@@ -0,0 +1,20 @@
+use crate::domain::user::repositories::user_social_accounts_repository::FindSocialServiceByIdError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RequestSlackAccountLinkExecutorError {
+    #[error("{0}")]
+    UserNotFound(#[from] FindSocialServiceByIdError),
+
+    #[error("Slack is not configured")]
+    SlackUnavailable,
+
+    #[error("Failed to send confirmation code to Slack: {0}")]
+    Send(String),
+
+    #[error("Cache error: {0}")]
+    Cache(String),
+
+    #[error("{0}")]
+    Serialize(#[from] serde_json::Error),
+}
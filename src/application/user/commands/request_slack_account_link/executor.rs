@@ -0,0 +1,89 @@
+use crate::application::user::commands::request_slack_account_link::command::RequestSlackAccountLinkCommand;
+use crate::application::user::commands::request_slack_account_link::error::RequestSlackAccountLinkExecutorError;
+use crate::application::user::commands::request_slack_account_link::response::RequestSlackAccountLinkResponse;
+use crate::domain::notification::services::slack_notification_service::SlackNotificationService;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::entities::pending_slack_account_link::PendingSlackAccountLink;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::infrastructure::drivers::cache::contract::CacheService;
+use crate::utils::builder::message::MessageBuilder;
+use rand::RngCore;
+use rand::rngs::OsRng;
+use std::sync::Arc;
+use std::time::Duration;
+
+const CONFIRMATION_TTL_SECONDS: u64 = Duration::from_mins(10).as_secs();
+
+pub struct RequestSlackAccountLinkExecutor {
+    user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    slack: Option<Arc<dyn SlackNotificationService>>,
+    cache: Arc<dyn CacheService>,
+}
+
+impl RequestSlackAccountLinkExecutor {
+    pub fn new(
+        user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+        slack: Option<Arc<dyn SlackNotificationService>>,
+        cache: Arc<dyn CacheService>,
+    ) -> Self {
+        Self {
+            user_socials_repo,
+            slack,
+            cache,
+        }
+    }
+
+    fn build_message(code: &str) -> MessageBuilder {
+        MessageBuilder::new()
+            .bold(t!("telegram_bot.notifications.slack_link.title").as_ref())
+            .empty_line()
+            .section_code(
+                t!("telegram_bot.notifications.slack_link.code").as_ref(),
+                code,
+            )
+            .empty_line()
+            .line(t!("telegram_bot.notifications.slack_link.hint", code = code).as_ref())
+    }
+}
+
+impl CommandExecutor for RequestSlackAccountLinkExecutor {
+    type Command = RequestSlackAccountLinkCommand;
+    type Response = RequestSlackAccountLinkResponse;
+    type Error = RequestSlackAccountLinkExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let social_user = self
+            .user_socials_repo
+            .find_by_social_user_id(&cmd.social_user_id)
+            .await?;
+
+        let slack = self
+            .slack
+            .as_ref()
+            .ok_or(RequestSlackAccountLinkExecutorError::SlackUnavailable)?;
+
+        let code = format!("{:06}", OsRng.next_u32() % 1_000_000);
+
+        // Код видит только владелец Slack-аккаунта — этим и подтверждается привязка
+        slack
+            .send_message(&cmd.slack_user_id, &Self::build_message(&code))
+            .await
+            .map_err(|e| RequestSlackAccountLinkExecutorError::Send(e.to_string()))?;
+
+        let pending = PendingSlackAccountLink {
+            slack_user_id: cmd.slack_user_id.clone(),
+            code,
+        };
+
+        self.cache
+            .set(
+                &PendingSlackAccountLink::cache_key(&social_user.user_id),
+                &serde_json::to_string(&pending)?,
+                CONFIRMATION_TTL_SECONDS,
+            )
+            .await
+            .map_err(RequestSlackAccountLinkExecutorError::Cache)?;
+
+        Ok(RequestSlackAccountLinkResponse)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+
+#[derive(Debug, Clone)]
+pub struct RequestVersionControlAccountLinkCommandVersionControl {
+    pub r#type: VersionControlType,
+    /// Адрес страницы авторизации, например `https://gitlab.com/oauth/authorize`
+    pub authorize_url: String,
+    pub redirect_uri: String,
+    pub client_id: String,
+    pub scope: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestVersionControlAccountLinkCommand {
+    pub social_user_id: SocialUserId,
+    pub social_chat_id: SocialChatId,
+    pub version_control: RequestVersionControlAccountLinkCommandVersionControl,
+}
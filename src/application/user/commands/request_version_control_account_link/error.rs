@@ -0,0 +1,17 @@
+use crate::domain::user::repositories::user_social_accounts_repository::FindSocialServiceByIdError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RequestVersionControlAccountLinkExecutorError {
+    #[error("{0}")]
+    UserNotFound(#[from] FindSocialServiceByIdError),
+
+    #[error("{0}")]
+    UrlParse(#[from] url::ParseError),
+
+    #[error("{0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Cache error: {0}")]
+    Cache(String),
+}
@@ -0,0 +1,77 @@
+use crate::application::user::commands::request_version_control_account_link::command::RequestVersionControlAccountLinkCommand;
+use crate::application::user::commands::request_version_control_account_link::error::RequestVersionControlAccountLinkExecutorError;
+use crate::application::user::commands::request_version_control_account_link::response::RequestVersionControlAccountLinkResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::entities::pending_version_control_account_link::PendingVersionControlAccountLink;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::infrastructure::drivers::cache::contract::CacheService;
+use rand::RngCore;
+use rand::rngs::OsRng;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+const OAUTH_STATE_TTL_SECONDS: u64 = Duration::from_mins(10).as_secs();
+
+pub struct RequestVersionControlAccountLinkExecutor {
+    user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    cache: Arc<dyn CacheService>,
+}
+
+impl RequestVersionControlAccountLinkExecutor {
+    pub fn new(
+        user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+        cache: Arc<dyn CacheService>,
+    ) -> Self {
+        Self {
+            user_socials_repo,
+            cache,
+        }
+    }
+}
+
+impl CommandExecutor for RequestVersionControlAccountLinkExecutor {
+    type Command = RequestVersionControlAccountLinkCommand;
+    type Response = RequestVersionControlAccountLinkResponse;
+    type Error = RequestVersionControlAccountLinkExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        // Привязка только для зарегистрированных — новый пользователь идёт через /register
+        let social_user = self
+            .user_socials_repo
+            .find_by_social_user_id(&cmd.social_user_id)
+            .await?;
+
+        let mut url = Url::parse(&cmd.version_control.authorize_url)?;
+
+        let mut state_bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut state_bytes);
+        let state_key = hex::encode(state_bytes);
+
+        url.query_pairs_mut()
+            .append_pair("client_id", &cmd.version_control.client_id)
+            .append_pair("redirect_uri", &cmd.version_control.redirect_uri)
+            .append_pair("response_type", "code")
+            .append_pair("scope", &cmd.version_control.scope)
+            .append_pair("state", &state_key);
+
+        let pending = PendingVersionControlAccountLink {
+            user_id: social_user.user_id,
+            version_control_type: cmd.version_control.r#type.clone(),
+            social_chat_id: cmd.social_chat_id,
+        };
+
+        self.cache
+            .set(
+                &PendingVersionControlAccountLink::cache_key(&state_key),
+                &serde_json::to_string(&pending)?,
+                OAUTH_STATE_TTL_SECONDS,
+            )
+            .await
+            .map_err(RequestVersionControlAccountLinkExecutorError::Cache)?;
+
+        Ok(RequestVersionControlAccountLinkResponse {
+            url: url.to_string(),
+        })
+    }
+}
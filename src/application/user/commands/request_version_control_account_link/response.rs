@@ -0,0 +1,4 @@
+#[derive(Debug, Clone)]
+pub struct RequestVersionControlAccountLinkResponse {
+    pub url: String,
+}
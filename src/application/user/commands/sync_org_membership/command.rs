@@ -0,0 +1,8 @@
+use crate::domain::webhook::events::member::{WebhookMemberAction, WebhookMemberScope};
+
+pub struct SyncOrgMembershipExecutorCommand {
+    pub action: WebhookMemberAction,
+    pub scope: WebhookMemberScope,
+    pub organization: String,
+    pub version_control_user_id: u64,
+}
@@ -0,0 +1,20 @@
+use crate::domain::user::repositories::user_repository::{FindUserByIdError, SetUserActiveError};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SyncOrgMembershipExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<FindUserByIdError> for SyncOrgMembershipExecutorError {
+    fn from(e: FindUserByIdError) -> Self {
+        Self::DbError(e.to_string())
+    }
+}
+
+impl From<SetUserActiveError> for SyncOrgMembershipExecutorError {
+    fn from(e: SetUserActiveError) -> Self {
+        Self::DbError(e.to_string())
+    }
+}
@@ -0,0 +1,116 @@
+use crate::application::user::commands::sync_org_membership::command::SyncOrgMembershipExecutorCommand;
+use crate::application::user::commands::sync_org_membership::error::SyncOrgMembershipExecutorError;
+use crate::application::user::commands::sync_org_membership::response::SyncOrgMembershipExecutorResponse;
+use crate::application::user::queries::check_org_membership::executor::CheckOrgMembershipExecutor;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::repositories::user_repository::UserRepository;
+use crate::domain::user::repositories::user_social_accounts_repository::{
+    FindSocialServiceByUserIdError, UserSocialAccountsRepository,
+};
+use crate::domain::user::repositories::user_vc_accounts_repository::{
+    FindVersionControlServiceByIdError, UserVersionControlAccountsRepository,
+};
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::user::value_objects::version_control_user_id::VersionControlUserId;
+use crate::domain::webhook::events::member::{WebhookMemberAction, WebhookMemberScope};
+use crate::infrastructure::drivers::cache::contract::CacheService;
+use std::sync::Arc;
+
+pub struct SyncOrgMembershipExecutor {
+    pub user_repo: Arc<dyn UserRepository>,
+    pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    pub user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    pub cache: Arc<dyn CacheService>,
+    pub required_organization: Option<String>,
+    pub admin_social_user_id: SocialUserId,
+}
+
+impl SyncOrgMembershipExecutor {
+    fn unchanged() -> SyncOrgMembershipExecutorResponse {
+        SyncOrgMembershipExecutorResponse { changed: false }
+    }
+}
+
+impl CommandExecutor for SyncOrgMembershipExecutor {
+    type Command = SyncOrgMembershipExecutorCommand;
+    type Response = SyncOrgMembershipExecutorResponse;
+    type Error = SyncOrgMembershipExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        // Доступ к боту завязан только на обязательную организацию
+        let Some(org) = self
+            .required_organization
+            .as_deref()
+            .filter(|org| org.eq_ignore_ascii_case(&cmd.organization))
+        else {
+            return Ok(Self::unchanged());
+        };
+
+        let vc = match self
+            .user_vc_accounts_repo
+            .find_by_version_control_user_id(
+                &VersionControlType::Github,
+                &VersionControlUserId(cmd.version_control_user_id as i32),
+            )
+            .await
+        {
+            Ok(vc) => vc,
+            Err(FindVersionControlServiceByIdError::NotFound) => return Ok(Self::unchanged()),
+            Err(FindVersionControlServiceByIdError::DbError(msg)) => {
+                return Err(SyncOrgMembershipExecutorError::DbError(msg));
+            }
+        };
+
+        let social = match self.user_socials_repo.find_by_user_id(&vc.user_id).await {
+            Ok(social) => Some(social),
+            Err(FindSocialServiceByUserIdError::NotFound) => None,
+            Err(FindSocialServiceByUserIdError::DbError(msg)) => {
+                return Err(SyncOrgMembershipExecutorError::DbError(msg));
+            }
+        };
+
+        // Закэшированный вердикт больше не актуален — следующая проверка сходит в GitHub
+        if let Some(social) = &social {
+            let key = CheckOrgMembershipExecutor::cache_key(&social.social_user_id, org);
+            if let Err(e) = self.cache.del(&key).await {
+                tracing::warn!(error = %e, key = %key, "Failed to drop cached membership");
+            }
+        }
+
+        // Из команды могли убрать, но в организации человек остался —
+        // тут хватает сброса кэша
+        let is_active = match (cmd.scope, cmd.action) {
+            (_, WebhookMemberAction::Added) => true,
+            (WebhookMemberScope::Organization, WebhookMemberAction::Removed) => false,
+            (WebhookMemberScope::Team, WebhookMemberAction::Removed) => {
+                return Ok(Self::unchanged());
+            }
+        };
+
+        if !is_active
+            && social
+                .as_ref()
+                .is_some_and(|s| s.social_user_id == self.admin_social_user_id)
+        {
+            return Ok(Self::unchanged());
+        }
+
+        let user = self.user_repo.find_by_id(vc.user_id).await?;
+
+        if user.is_active == is_active {
+            return Ok(Self::unchanged());
+        }
+
+        self.user_repo.set_active(user.id, is_active).await?;
+
+        tracing::info!(
+            user_id = user.id.0,
+            login = %vc.version_control_login,
+            is_active,
+            "User activity synced from organization membership"
+        );
+
+        Ok(SyncOrgMembershipExecutorResponse { changed: true })
+    }
+}
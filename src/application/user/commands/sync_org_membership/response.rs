@@ -0,0 +1,4 @@
+pub struct SyncOrgMembershipExecutorResponse {
+    /// Изменился ли `is_active`
+    pub changed: bool,
+}
@@ -0,0 +1,5 @@
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+
+pub struct UnlinkSlackAccountCommand {
+    pub social_user_id: SocialUserId,
+}
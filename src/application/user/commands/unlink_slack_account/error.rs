@@ -0,0 +1,12 @@
+use crate::domain::user::repositories::user_slack_accounts_repository::DeleteUserSlackAccountError;
+use crate::domain::user::repositories::user_social_accounts_repository::FindSocialServiceByIdError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UnlinkSlackAccountExecutorError {
+    #[error("{0}")]
+    UserNotFound(#[from] FindSocialServiceByIdError),
+
+    #[error("{0}")]
+    DeleteError(#[from] DeleteUserSlackAccountError),
+}
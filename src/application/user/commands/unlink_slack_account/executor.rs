@@ -0,0 +1,43 @@
+use crate::application::user::commands::unlink_slack_account::command::UnlinkSlackAccountCommand;
+use crate::application::user::commands::unlink_slack_account::error::UnlinkSlackAccountExecutorError;
+use crate::application::user::commands::unlink_slack_account::response::UnlinkSlackAccountResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::repositories::user_slack_accounts_repository::UserSlackAccountsRepository;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use std::sync::Arc;
+
+pub struct UnlinkSlackAccountExecutor {
+    user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository>,
+}
+
+impl UnlinkSlackAccountExecutor {
+    pub fn new(
+        user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+        user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository>,
+    ) -> Self {
+        Self {
+            user_socials_repo,
+            user_slack_accounts_repo,
+        }
+    }
+}
+
+impl CommandExecutor for UnlinkSlackAccountExecutor {
+    type Command = UnlinkSlackAccountCommand;
+    type Response = UnlinkSlackAccountResponse;
+    type Error = UnlinkSlackAccountExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let social_user = self
+            .user_socials_repo
+            .find_by_social_user_id(&cmd.social_user_id)
+            .await?;
+
+        self.user_slack_accounts_repo
+            .delete_by_user_id(&social_user.user_id)
+            .await?;
+
+        Ok(UnlinkSlackAccountResponse)
+    }
+}
@@ -8,6 +8,7 @@ use crate::domain::user::repositories::user_social_accounts_repository::{
 };
 use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
 use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use crate::domain::version_control::ports::version_control_client::VersionControlClient;
 use crate::infrastructure::drivers::cache::contract::CacheService;
 use crate::utils::security::crypto::reversible::ReversibleCipher;
@@ -27,7 +28,7 @@ pub struct CheckOrgMembershipExecutor {
 }
 
 impl CheckOrgMembershipExecutor {
-    fn cache_key(social_user_id: &SocialUserId, org: &str) -> String {
+    pub fn cache_key(social_user_id: &SocialUserId, org: &str) -> String {
         format!("org_membership:{}:{}", org, social_user_id.0)
     }
 }
@@ -95,7 +96,7 @@ impl CommandExecutor for CheckOrgMembershipExecutor {
 
         let vc = self
             .user_vc_accounts_repo
-            .find_by_user_id(&social.user_id)
+            .find_by_user_id(&VersionControlType::Github, &social.user_id)
             .await
             .map_err(|e| CheckOrgMembershipError::DbError(e.to_string()))?;
 
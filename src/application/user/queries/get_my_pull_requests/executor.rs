@@ -6,6 +6,7 @@ use crate::domain::shared::command::CommandExecutor;
 use crate::domain::user::repositories::user_connection_repositories_repository::UserConnectionRepositoriesRepository;
 use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
 use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use crate::domain::version_control::ports::version_control_client::VersionControlClient;
 use crate::utils::security::crypto::reversible::ReversibleCipher;
 use std::sync::Arc;
@@ -53,7 +54,7 @@ impl CommandExecutor for GetMyPullRequestsExecutor {
 
         let vc = self
             .user_vc_accounts_repo
-            .find_by_user_id(&social.user_id)
+            .find_by_user_id(&VersionControlType::Github, &social.user_id)
             .await
             .map_err(|_| GetMyPullRequestsError::NoGithubAccount)?;
 
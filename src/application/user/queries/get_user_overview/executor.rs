@@ -7,6 +7,7 @@ use crate::domain::user::repositories::user_connection_repositories_repository::
 use crate::domain::user::repositories::user_has_roles_repository::UserHasRolesRepository;
 use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
 use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use crate::domain::user_preferences::repositories::user_preferences_repository::UserPreferencesRepository;
 use std::sync::Arc;
 
@@ -54,7 +55,7 @@ impl CommandExecutor for GetUserOverviewExecutor {
 
         let github_login = self
             .user_vc_accounts_repo
-            .find_by_user_id(&user_id)
+            .find_by_user_id(&VersionControlType::Github, &user_id)
             .await
             .ok()
             .map(|a| a.version_control_login);
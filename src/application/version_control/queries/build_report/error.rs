@@ -33,4 +33,7 @@ pub enum BuildVersionControlDateRangeReportExecutorError {
 
     #[error("APPLICATION_BASE_URL is not configured — report URL cannot be generated")]
     BaseUrlNotConfigured,
+
+    #[error("No access token for {0} repository")]
+    NoToken(String),
 }
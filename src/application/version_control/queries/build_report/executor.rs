@@ -8,12 +8,16 @@ use crate::application::version_control::queries::build_report::response::BuildV
 use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
 use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
 use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
 use crate::domain::task::services::task_tracker_service::TaskTrackerService;
 use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
-use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
-use crate::domain::version_control::ports::version_control_client::{
-    VersionControlClient, VersionControlClientDateRangeReportError,
+use crate::domain::user::repositories::user_vc_accounts_repository::{
+    FindVersionControlServiceByUserIdError, UserVersionControlAccountsRepository,
 };
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client::VersionControlClientDateRangeReportError;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
 use crate::infrastructure::drivers::cache::contract::CacheService;
 use crate::utils::builder::message::MessageBuilder;
 use crate::utils::security::crypto::reversible::ReversibleCipher;
@@ -30,26 +34,29 @@ pub struct BuildVersionControlDateRangeReportExecutor {
     reversible_cipher: Arc<ReversibleCipher>,
     user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
     user_version_control_service_repo: Arc<dyn UserVersionControlAccountsRepository>,
-    version_control_client: Arc<dyn VersionControlClient>,
+    version_control_clients: Arc<dyn VersionControlClientProvider>,
+    version_control_tokens: Arc<dyn VersionControlTokenProvider>,
     repository_repo: Arc<dyn RepositoryRepository>,
     repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
     task_tracker_service: Arc<dyn TaskTrackerService>,
-    kaiten_base: String,
+    task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
     base_url: String,
     cache: Arc<dyn CacheService>,
     report_url_secret: String,
 }
 
 impl BuildVersionControlDateRangeReportExecutor {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         reversible_cipher: Arc<ReversibleCipher>,
         user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
         user_version_control_service_repo: Arc<dyn UserVersionControlAccountsRepository>,
-        version_control_client: Arc<dyn VersionControlClient>,
+        version_control_clients: Arc<dyn VersionControlClientProvider>,
+        version_control_tokens: Arc<dyn VersionControlTokenProvider>,
         repository_repo: Arc<dyn RepositoryRepository>,
         repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
         task_tracker_service: Arc<dyn TaskTrackerService>,
-        kaiten_base: String,
+        task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
         base_url: String,
         cache: Arc<dyn CacheService>,
         report_url_secret: String,
@@ -58,11 +65,12 @@ impl BuildVersionControlDateRangeReportExecutor {
             reversible_cipher,
             user_socials_repo,
             user_version_control_service_repo,
-            version_control_client,
+            version_control_clients,
+            version_control_tokens,
             repository_repo,
             repository_task_tracker_repo,
             task_tracker_service,
-            kaiten_base,
+            task_tracker_client_resolver,
             base_url,
             cache,
             report_url_secret,
@@ -100,6 +108,10 @@ impl BuildVersionControlDateRangeReportExecutor {
                 t!("report.errors.not_configured").to_string()
             }
 
+            BuildVersionControlDateRangeReportExecutorError::NoToken(provider) => {
+                t!("report.errors.no_token", provider = provider).to_string()
+            }
+
             _ => t!("report.errors.unknown").to_string(),
         }
     }
@@ -147,28 +159,55 @@ impl CommandExecutor for BuildVersionControlDateRangeReportExecutor {
             .find_by_social_user_id(&cmd.social_user_id)
             .await?;
 
-        let version_control_user = self
-            .user_version_control_service_repo
-            .find_by_user_id(&social_user.user_id)
-            .await?;
-
-        let decrypted_token = self
-            .reversible_cipher
-            .decrypt(version_control_user.access_token.value())?;
-
         let repository = self.repository_repo.find_by_id(cmd.repository_id).await?;
 
+        // Аккаунт того же провайдера, что и репозиторий, если он привязан,
+        // иначе — основной аккаунт GitHub
+        let version_control_user = match self
+            .user_version_control_service_repo
+            .find_by_user_id(&repository.version_control_type, &social_user.user_id)
+            .await
+        {
+            Ok(account) => account,
+            Err(FindVersionControlServiceByUserIdError::NotFound) => {
+                self.user_version_control_service_repo
+                    .find_by_user_id(&VersionControlType::Github, &social_user.user_id)
+                    .await?
+            }
+            Err(e) => return Err(e.into()),
+        };
+
         let author = match cmd.for_who {
             BuildVersionControlDateRangeReportExecutorCommandForWho::Me => {
-                Some(version_control_user.version_control_login)
+                Some(version_control_user.version_control_login.clone())
             }
             BuildVersionControlDateRangeReportExecutorCommandForWho::Repository => None,
         };
 
+        // OAuth-токен пользователя годится только для его же провайдера и пока
+        // не истёк — иначе нужны сервисные учётные данные
+        let access_token = if version_control_user.version_control_type
+            == repository.version_control_type
+            && !version_control_user.is_token_expired()
+        {
+            self.reversible_cipher
+                .decrypt(version_control_user.access_token.value())?
+        } else {
+            self.version_control_tokens
+                .service_token(&repository)
+                .await
+                .ok_or_else(|| {
+                    BuildVersionControlDateRangeReportExecutorError::NoToken(
+                        repository.version_control_type.to_string(),
+                    )
+                })?
+        };
+
         let report = self
-            .version_control_client
+            .version_control_clients
+            .client(&repository.version_control_type)
             .get_details_by_range(
-                &decrypted_token,
+                &access_token,
                 &repository.owner,
                 &repository.name,
                 &cmd.branch,
@@ -190,6 +229,16 @@ impl CommandExecutor for BuildVersionControlDateRangeReportExecutor {
             .await
             .ok();
 
+        let tracker_base = match tracker.as_ref() {
+            Some(t) => self
+                .task_tracker_client_resolver
+                .resolve(t.kind, t.connection_id)
+                .await
+                .map(|client| client.base_url().to_string())
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+
         // ── Render HTML via template ──────────────────────────────────────────
 
         let html = renderer::build_html_report(
@@ -201,7 +250,7 @@ impl CommandExecutor for BuildVersionControlDateRangeReportExecutor {
             &cmd.branch,
             tracker.as_ref(),
             self.task_tracker_service.as_ref(),
-            &self.kaiten_base,
+            &tracker_base,
         )
         .map_err(|e| {
             BuildVersionControlDateRangeReportExecutorError::TemplateRender(e.to_string())
@@ -206,9 +206,9 @@ pub fn build_html_report(
     branch: &str,
     tracker: Option<&RepositoryTaskTracker>,
     task_tracker_service: &dyn TaskTrackerService,
-    kaiten_base: &str,
+    tracker_base: &str,
 ) -> Result<String, askama::Error> {
-    let task_url_template = tracker.map(|t| format!("{}{}", kaiten_base, t.path_to_card));
+    let task_url_template = tracker.map(|t| format!("{}{}", tracker_base, t.path_to_card));
     let extract_pattern = tracker.map(|t| t.extract_pattern_regexp.as_str());
 
     let period = format!(
@@ -666,16 +666,16 @@ fn build_tasks(
     let mut texts: Vec<&str> = commits.iter().map(|c| c.message.as_str()).collect();
     texts.extend(prs.iter().map(|p| p.title.as_str()));
 
-    let mut seen: HashSet<u64> = HashSet::new();
+    let mut seen: HashSet<String> = HashSet::new();
     let mut result = Vec::new();
 
     for text in texts {
         for (matched, task_id) in
             task_tracker_service.extract_all_matches_with_pattern(text, pattern)
         {
-            if seen.insert(task_id.0) {
+            if seen.insert(task_id.0.clone()) {
                 result.push(TaskItem {
-                    url: url_template.replace("{id}", &task_id.0.to_string()),
+                    url: url_template.replace("{id}", &task_id.0),
                     label: html_escape(&matched),
                 });
             }
@@ -756,16 +756,16 @@ fn extract_contributor_tasks(
         return vec![];
     };
 
-    let mut seen: HashSet<u64> = HashSet::new();
+    let mut seen: HashSet<String> = HashSet::new();
     let mut tasks = Vec::new();
 
     for msg in commit_messages {
         for (matched, task_id) in
             task_tracker_service.extract_all_matches_with_pattern(msg, pattern)
         {
-            if seen.insert(task_id.0) {
+            if seen.insert(task_id.0.clone()) {
                 tasks.push(TaskItem {
-                    url: url_template.replace("{id}", &task_id.0.to_string()),
+                    url: url_template.replace("{id}", &task_id.0),
                     label: html_escape(&matched),
                 });
             }
@@ -777,9 +777,9 @@ fn extract_contributor_tasks(
             for (matched, task_id) in
                 task_tracker_service.extract_all_matches_with_pattern(&pr.title, pattern)
             {
-                if seen.insert(task_id.0) {
+                if seen.insert(task_id.0.clone()) {
                     tasks.push(TaskItem {
-                        url: url_template.replace("{id}", &task_id.0.to_string()),
+                        url: url_template.replace("{id}", &task_id.0),
                         label: html_escape(&matched),
                     });
                 }
@@ -903,7 +903,7 @@ fn linkify_task(
         return html_escape(text);
     };
 
-    let url = url_template.replace("{id}", &task_id.0.to_string());
+    let url = url_template.replace("{id}", &task_id.0);
 
     if let Some(pos) = text.find(&matched) {
         let before = &text[..pos];
@@ -1,5 +1,10 @@
 use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 
 pub struct DispatchWebhookEventExecutorCommand {
     pub event: Box<dyn WebhookEvent>,
+    /// Провайдер, в котором искать репозиторий по `repository_full_name`
+    pub provider: WebhookProvider,
+    /// owner/name из payload — по нему проверяем, не заархивирован ли репозиторий
+    pub repository_full_name: Option<String>,
 }
@@ -1,4 +1,7 @@
 use thiserror::Error;
 
 #[derive(Error, Debug)]
-pub enum DispatchWebhookEventExecutorError {}
+pub enum DispatchWebhookEventExecutorError {
+    #[error("Failed to publish webhook event: {0}")]
+    PublishError(String),
+}
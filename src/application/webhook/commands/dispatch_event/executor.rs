@@ -1,7 +1,10 @@
 use crate::application::webhook::commands::dispatch_event::command::DispatchWebhookEventExecutorCommand;
 use crate::application::webhook::commands::dispatch_event::error::DispatchWebhookEventExecutorError;
 use crate::application::webhook::commands::dispatch_event::response::DispatchWebhookEventExecutorResponse;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
 use crate::domain::shared::command::CommandExecutor;
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::repository::WebhookRepositoryEvent;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::{
     MessageBrokerMessage, MessageBrokerPublisher,
 };
@@ -9,9 +12,34 @@ use std::sync::Arc;
 
 pub struct DispatchWebhookEventExecutor {
     pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
 }
 
-impl DispatchWebhookEventExecutor {}
+impl DispatchWebhookEventExecutor {
+    /// Уведомления по заархивированным репозиториям не шлём. События самого
+    /// репозитория пропускаем всегда — иначе не узнаем о разархивировании.
+    async fn is_archived(&self, cmd: &DispatchWebhookEventExecutorCommand) -> bool {
+        if cmd.event.event_name() == WebhookRepositoryEvent::EVENT_NAME {
+            return false;
+        }
+
+        let Some((owner, name)) = cmd
+            .repository_full_name
+            .as_deref()
+            .and_then(|full_name| full_name.rsplit_once('/'))
+        else {
+            return false;
+        };
+
+        let version_control_type = cmd.provider.version_control_type();
+
+        self.repository_repo
+            .find_by_owner_and_name(&version_control_type, owner, name)
+            .await
+            .map(|repository| repository.is_archived)
+            .unwrap_or(false)
+    }
+}
 
 impl CommandExecutor for DispatchWebhookEventExecutor {
     type Command = DispatchWebhookEventExecutorCommand;
@@ -19,13 +47,22 @@ impl CommandExecutor for DispatchWebhookEventExecutor {
     type Error = DispatchWebhookEventExecutorError;
 
     async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        if self.is_archived(cmd).await {
+            tracing::debug!(
+                event = cmd.event.event_name(),
+                repository = cmd.repository_full_name.as_deref().unwrap_or("-"),
+                "Repository is archived, skipping webhook event"
+            );
+            return Ok(DispatchWebhookEventExecutorResponse { skipped: true });
+        }
+
         tracing::debug!("Dispatching webhook event: {}", cmd.event.event_name());
 
         self.publisher
             .publish(cmd.event.as_ref() as &dyn MessageBrokerMessage)
             .await
-            .ok();
+            .map_err(|e| DispatchWebhookEventExecutorError::PublishError(format!("{:?}", e)))?;
 
-        Ok(DispatchWebhookEventExecutorResponse {})
+        Ok(DispatchWebhookEventExecutorResponse { skipped: false })
     }
 }
@@ -1,2 +1,5 @@
 #[derive(Debug, Clone)]
-pub struct DispatchWebhookEventExecutorResponse {}
+pub struct DispatchWebhookEventExecutorResponse {
+    /// Событие не опубликовано: репозиторий заархивирован
+    pub skipped: bool,
+}
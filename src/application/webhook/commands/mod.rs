@@ -1 +1,7 @@
 pub mod dispatch_event;
+pub mod prune_deliveries;
+pub mod record_delivery;
+pub mod record_rejected_delivery;
+pub mod recover_deliveries;
+pub mod replay_deliveries;
+pub mod update_delivery_status;
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PruneWebhookDeliveriesExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
@@ -0,0 +1,30 @@
+use crate::application::webhook::commands::prune_deliveries::command::PruneWebhookDeliveriesExecutorCommand;
+use crate::application::webhook::commands::prune_deliveries::error::PruneWebhookDeliveriesExecutorError;
+use crate::application::webhook::commands::prune_deliveries::response::PruneWebhookDeliveriesExecutorResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+pub struct PruneWebhookDeliveriesExecutor {
+    pub webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    pub retention_days: i64,
+}
+
+impl CommandExecutor for PruneWebhookDeliveriesExecutor {
+    type Command = PruneWebhookDeliveriesExecutorCommand;
+    type Response = PruneWebhookDeliveriesExecutorResponse;
+    type Error = PruneWebhookDeliveriesExecutorError;
+
+    async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let before = Utc::now() - Duration::days(self.retention_days);
+
+        let deleted_count = self
+            .webhook_delivery_repo
+            .delete_received_before(before)
+            .await
+            .map_err(|e| PruneWebhookDeliveriesExecutorError::DbError(e.to_string()))?;
+
+        Ok(PruneWebhookDeliveriesExecutorResponse { deleted_count })
+    }
+}
@@ -0,0 +1,3 @@
+pub struct PruneWebhookDeliveriesExecutorResponse {
+    pub deleted_count: u64,
+}
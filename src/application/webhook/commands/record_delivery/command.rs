@@ -0,0 +1,9 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+
+pub struct RecordWebhookDeliveryExecutorCommand {
+    pub provider: VersionControlType,
+    pub delivery_id: String,
+    pub event_type: String,
+    pub repository_full_name: Option<String>,
+    pub payload: String,
+}
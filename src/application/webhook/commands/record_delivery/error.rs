@@ -0,0 +1,28 @@
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::{
+    CreateWebhookDeliveryError, FindWebhookDeliveryError, UpdateWebhookDeliveryError,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RecordWebhookDeliveryExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<CreateWebhookDeliveryError> for RecordWebhookDeliveryExecutorError {
+    fn from(e: CreateWebhookDeliveryError) -> Self {
+        Self::DbError(e.to_string())
+    }
+}
+
+impl From<FindWebhookDeliveryError> for RecordWebhookDeliveryExecutorError {
+    fn from(e: FindWebhookDeliveryError) -> Self {
+        Self::DbError(e.to_string())
+    }
+}
+
+impl From<UpdateWebhookDeliveryError> for RecordWebhookDeliveryExecutorError {
+    fn from(e: UpdateWebhookDeliveryError) -> Self {
+        Self::DbError(e.to_string())
+    }
+}
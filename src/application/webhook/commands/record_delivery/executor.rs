@@ -0,0 +1,101 @@
+use crate::application::webhook::commands::record_delivery::command::RecordWebhookDeliveryExecutorCommand;
+use crate::application::webhook::commands::record_delivery::error::RecordWebhookDeliveryExecutorError;
+use crate::application::webhook::commands::record_delivery::response::RecordWebhookDeliveryExecutorResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::{
+    CreateWebhookDeliveryError, FindWebhookDeliveryError, WebhookDeliveryRepository,
+};
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub struct RecordWebhookDeliveryExecutor {
+    pub webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl RecordWebhookDeliveryExecutor {
+    pub fn new(webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self {
+            webhook_delivery_repo,
+        }
+    }
+}
+
+impl CommandExecutor for RecordWebhookDeliveryExecutor {
+    type Command = RecordWebhookDeliveryExecutorCommand;
+    type Response = RecordWebhookDeliveryExecutorResponse;
+    type Error = RecordWebhookDeliveryExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        match self
+            .webhook_delivery_repo
+            .find_by_delivery_id(&cmd.provider, &cmd.delivery_id)
+            .await
+        {
+            Ok(existing) => {
+                if existing.status.blocks_redelivery() {
+                    return Ok(RecordWebhookDeliveryExecutorResponse {
+                        duplicate: true,
+                        delivery: existing,
+                    });
+                }
+
+                // Redeliver после ошибки или падения посреди обработки — обрабатываем заново
+                let mut delivery = existing;
+                delivery.event_type = cmd.event_type.clone();
+                delivery.repository_full_name = cmd.repository_full_name.clone();
+                delivery.payload = cmd.payload.clone();
+                delivery.signature_valid = true;
+                delivery.status = WebhookDeliveryStatus::Received;
+                delivery.error_message = None;
+                delivery.received_at = Utc::now();
+                delivery.processed_at = None;
+
+                let delivery = self.webhook_delivery_repo.update(&delivery).await?;
+
+                Ok(RecordWebhookDeliveryExecutorResponse {
+                    delivery,
+                    duplicate: false,
+                })
+            }
+            Err(FindWebhookDeliveryError::NotFound) => {
+                let placeholder = WebhookDelivery {
+                    id: WebhookDeliveryId(0),
+                    provider: cmd.provider.clone(),
+                    delivery_id: Some(cmd.delivery_id.clone()),
+                    event_type: cmd.event_type.clone(),
+                    repository_full_name: cmd.repository_full_name.clone(),
+                    payload: cmd.payload.clone(),
+                    signature_valid: true,
+                    status: WebhookDeliveryStatus::Received,
+                    error_message: None,
+                    received_at: Utc::now(),
+                    processed_at: None,
+                };
+
+                match self.webhook_delivery_repo.create(&placeholder).await {
+                    Ok(delivery) => Ok(RecordWebhookDeliveryExecutorResponse {
+                        delivery,
+                        duplicate: false,
+                    }),
+                    // Параллельная доставка с тем же id успела записаться раньше
+                    Err(CreateWebhookDeliveryError::AlreadyExists) => {
+                        let delivery = self
+                            .webhook_delivery_repo
+                            .find_by_delivery_id(&cmd.provider, &cmd.delivery_id)
+                            .await?;
+
+                        Ok(RecordWebhookDeliveryExecutorResponse {
+                            delivery,
+                            duplicate: true,
+                        })
+                    }
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
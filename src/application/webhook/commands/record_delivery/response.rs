@@ -0,0 +1,7 @@
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+
+pub struct RecordWebhookDeliveryExecutorResponse {
+    pub delivery: WebhookDelivery,
+    /// Доставка с таким id уже принималась — обрабатывать повторно не нужно
+    pub duplicate: bool,
+}
@@ -0,0 +1,10 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+
+/// Запрос, не прошедший проверку подписи. Id доставки из заголовков сюда
+/// намеренно не попадает
+pub struct RecordRejectedWebhookDeliveryExecutorCommand {
+    pub provider: VersionControlType,
+    pub event_type: String,
+    pub repository_full_name: Option<String>,
+    pub payload: String,
+}
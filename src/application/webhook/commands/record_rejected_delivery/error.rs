@@ -0,0 +1,14 @@
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::CreateWebhookDeliveryError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RecordRejectedWebhookDeliveryExecutorError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<CreateWebhookDeliveryError> for RecordRejectedWebhookDeliveryExecutorError {
+    fn from(e: CreateWebhookDeliveryError) -> Self {
+        Self::DbError(e.to_string())
+    }
+}
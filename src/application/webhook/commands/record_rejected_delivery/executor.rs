@@ -0,0 +1,65 @@
+use crate::application::webhook::commands::record_rejected_delivery::command::RecordRejectedWebhookDeliveryExecutorCommand;
+use crate::application::webhook::commands::record_rejected_delivery::error::RecordRejectedWebhookDeliveryExecutorError;
+use crate::application::webhook::commands::record_rejected_delivery::response::RecordRejectedWebhookDeliveryExecutorResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// Запрос без подписи может прислать кто угодно — храним только начало тела,
+/// чтобы журнал нельзя было раздуть
+const MAX_REJECTED_PAYLOAD_BYTES: usize = 64 * 1024;
+
+pub struct RecordRejectedWebhookDeliveryExecutor {
+    pub webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl RecordRejectedWebhookDeliveryExecutor {
+    pub fn new(webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self {
+            webhook_delivery_repo,
+        }
+    }
+
+    fn truncate_payload(payload: &str) -> String {
+        let mut end = payload.len().min(MAX_REJECTED_PAYLOAD_BYTES);
+        while !payload.is_char_boundary(end) {
+            end -= 1;
+        }
+        payload[..end].to_string()
+    }
+}
+
+impl CommandExecutor for RecordRejectedWebhookDeliveryExecutor {
+    type Command = RecordRejectedWebhookDeliveryExecutorCommand;
+    type Response = RecordRejectedWebhookDeliveryExecutorResponse;
+    type Error = RecordRejectedWebhookDeliveryExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let now = Utc::now();
+
+        // Без delivery_id запись не участвует в дедупликации и не занимает
+        // id настоящей доставки
+        let delivery = self
+            .webhook_delivery_repo
+            .create(&WebhookDelivery {
+                id: WebhookDeliveryId(0),
+                provider: cmd.provider.clone(),
+                delivery_id: None,
+                event_type: cmd.event_type.clone(),
+                repository_full_name: cmd.repository_full_name.clone(),
+                payload: Self::truncate_payload(&cmd.payload),
+                signature_valid: false,
+                status: WebhookDeliveryStatus::Rejected,
+                error_message: Some("Signature verification failed".to_string()),
+                received_at: now,
+                processed_at: Some(now),
+            })
+            .await?;
+
+        Ok(RecordRejectedWebhookDeliveryExecutorResponse { delivery })
+    }
+}
@@ -0,0 +1,5 @@
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+
+pub struct RecordRejectedWebhookDeliveryExecutorResponse {
+    pub delivery: WebhookDelivery,
+}
@@ -0,0 +1,348 @@
+use crate::application::webhook::commands::dispatch_event::command::DispatchWebhookEventExecutorCommand;
+use crate::application::webhook::commands::dispatch_event::executor::DispatchWebhookEventExecutor;
+use crate::application::webhook::commands::record_delivery::command::RecordWebhookDeliveryExecutorCommand;
+use crate::application::webhook::commands::record_delivery::executor::RecordWebhookDeliveryExecutor;
+use crate::application::webhook::commands::recover_deliveries::command::RecoverWebhookDeliveriesExecutorCommand;
+use crate::application::webhook::commands::recover_deliveries::error::RecoverWebhookDeliveriesExecutorError;
+use crate::application::webhook::commands::recover_deliveries::response::RecoverWebhookDeliveriesExecutorResponse;
+use crate::application::webhook::commands::update_delivery_status::executor::UpdateWebhookDeliveryStatusExecutor;
+use crate::domain::repository::entities::repository::Repository;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client::{
+    RepositoryHookDelivery, VersionControlClient, VersionControlClientRepositoryHookError,
+};
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::{
+    FindWebhookDeliveryError, WebhookDeliveryRepository,
+};
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use crate::infrastructure::contracts::github::event_type::GithubEventType;
+use crate::infrastructure::contracts::github::mapper::{
+    GithubWebhookEventMapper, GithubWebhookEventMapperError,
+};
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Забираем пропущенные доставки GitHub из истории вебхука репозитория и
+/// прогоняем их через тот же путь, что и живые запросы. Журнал доставок по
+/// `X-GitHub-Delivery` не даёт обработать событие дважды.
+pub struct RecoverWebhookDeliveriesExecutor {
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub version_control_tokens: Arc<dyn VersionControlTokenProvider>,
+    pub record_webhook_delivery: Arc<RecordWebhookDeliveryExecutor>,
+    pub update_webhook_delivery_status: Arc<UpdateWebhookDeliveryStatusExecutor>,
+    pub dispatch_webhook_event: Arc<DispatchWebhookEventExecutor>,
+    pub base_url: String,
+    pub lookback_hours: i64,
+}
+
+impl RecoverWebhookDeliveriesExecutor {
+    /// Доставки, которые GitHub так и не смог отдать и которые не доведены до
+    /// конца по журналу: нет записи, упали или застряли в `received`.
+    async fn missed_deliveries(
+        &self,
+        deliveries: Vec<RepositoryHookDelivery>,
+    ) -> Result<Vec<RepositoryHookDelivery>, RecoverWebhookDeliveriesExecutorError> {
+        let mut by_guid: HashMap<String, Vec<RepositoryHookDelivery>> = HashMap::new();
+        for delivery in deliveries {
+            by_guid
+                .entry(delivery.guid.clone())
+                .or_default()
+                .push(delivery);
+        }
+
+        let mut missed = Vec::new();
+
+        for (guid, attempts) in by_guid {
+            if attempts.iter().any(|a| (200..300).contains(&a.status_code)) {
+                continue;
+            }
+
+            let processed = match self
+                .webhook_delivery_repo
+                .find_by_delivery_id(&VersionControlType::Github, &guid)
+                .await
+            {
+                Ok(delivery) => delivery.status.blocks_redelivery(),
+                Err(FindWebhookDeliveryError::NotFound) => false,
+                Err(e) => {
+                    return Err(RecoverWebhookDeliveriesExecutorError::DbError(
+                        e.to_string(),
+                    ));
+                }
+            };
+
+            if processed {
+                continue;
+            }
+
+            // Payload у всех попыток одинаковый — берём самую раннюю
+            if let Some(first) = attempts.into_iter().min_by_key(|a| a.delivered_at) {
+                missed.push(first);
+            }
+        }
+
+        // Слушатели чувствительны к порядку — восстанавливаем в порядке отправки
+        missed.sort_by_key(|d| d.delivered_at);
+
+        Ok(missed)
+    }
+
+    async fn recover_one(
+        &self,
+        client: &Arc<dyn VersionControlClient>,
+        token: &str,
+        repository: &Repository,
+        hook_id: u64,
+        delivery: &RepositoryHookDelivery,
+    ) -> Result<bool, String> {
+        let details = client
+            .get_repository_hook_delivery(
+                token,
+                &repository.owner,
+                &repository.name,
+                hook_id,
+                delivery.id,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&details.payload).map_err(|e| e.to_string())?;
+        let repository_full_name = GithubWebhookEventMapper::extract_repository_full_name(&payload);
+
+        // Запрос к API авторизован — подпись здесь не нужна
+        let recorded = self
+            .record_webhook_delivery
+            .execute(&RecordWebhookDeliveryExecutorCommand {
+                provider: VersionControlType::Github,
+                delivery_id: details.guid.clone(),
+                event_type: details.event.clone(),
+                repository_full_name: repository_full_name.clone(),
+                payload: details.payload.clone(),
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Пока мы ходили в API, GitHub успел доставить событие сам
+        if recorded.duplicate {
+            return Ok(false);
+        }
+
+        let journal_id = recorded.delivery.id;
+
+        let github_event_type = GithubEventType::from_str(&details.event)
+            .unwrap_or(GithubEventType::Unknown(details.event.clone()));
+
+        if github_event_type == GithubEventType::Ping {
+            self.update_webhook_delivery_status
+                .mark(journal_id, WebhookDeliveryStatus::Skipped, None)
+                .await;
+            return Ok(false);
+        }
+
+        let event = match GithubWebhookEventMapper::map_to_domain_event(&github_event_type, payload)
+        {
+            Ok(event) => event,
+            Err(
+                e @ (GithubWebhookEventMapperError::UnsupportedEventType
+                | GithubWebhookEventMapperError::Skipped(_)),
+            ) => {
+                self.update_webhook_delivery_status
+                    .mark(
+                        journal_id,
+                        WebhookDeliveryStatus::Skipped,
+                        Some(e.to_string()),
+                    )
+                    .await;
+                return Ok(false);
+            }
+            Err(e) => {
+                self.update_webhook_delivery_status
+                    .mark(
+                        journal_id,
+                        WebhookDeliveryStatus::Failed,
+                        Some(e.to_string()),
+                    )
+                    .await;
+                return Err(e.to_string());
+            }
+        };
+
+        let cmd = DispatchWebhookEventExecutorCommand {
+            event,
+            provider: WebhookProvider::Github,
+            repository_full_name,
+        };
+
+        match self.dispatch_webhook_event.execute(&cmd).await {
+            Ok(r) if r.skipped => {
+                self.update_webhook_delivery_status
+                    .mark(
+                        journal_id,
+                        WebhookDeliveryStatus::Skipped,
+                        Some("Repository is archived".to_string()),
+                    )
+                    .await;
+                Ok(false)
+            }
+            Ok(_) => {
+                self.update_webhook_delivery_status
+                    .mark(journal_id, WebhookDeliveryStatus::Dispatched, None)
+                    .await;
+                Ok(true)
+            }
+            Err(e) => {
+                self.update_webhook_delivery_status
+                    .mark(
+                        journal_id,
+                        WebhookDeliveryStatus::Failed,
+                        Some(e.to_string()),
+                    )
+                    .await;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    /// (восстановлено, ошибок)
+    async fn recover_repository(
+        &self,
+        repository: &Repository,
+    ) -> Result<(usize, usize), VersionControlClientRepositoryHookError> {
+        let Some(token) = self
+            .version_control_tokens
+            .background_token(repository)
+            .await
+        else {
+            return Ok((0, 0));
+        };
+
+        let client = self
+            .version_control_clients
+            .client(&repository.version_control_type);
+
+        let hook_url = format!("{}/webhook/github", self.base_url.trim_end_matches('/'));
+
+        let Some(hook) = client
+            .list_repository_hooks(&token, &repository.owner, &repository.name)
+            .await?
+            .into_iter()
+            .find(|hook| hook.url.trim_end_matches('/') == hook_url)
+        else {
+            return Ok((0, 0));
+        };
+
+        let since = Utc::now() - Duration::hours(self.lookback_hours);
+
+        let deliveries = client
+            .list_repository_hook_deliveries(
+                &token,
+                &repository.owner,
+                &repository.name,
+                hook.id,
+                since,
+            )
+            .await?;
+
+        let missed = match self.missed_deliveries(deliveries).await {
+            Ok(missed) => missed,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to check webhook journal");
+                return Ok((0, 0));
+            }
+        };
+
+        let mut recovered = 0;
+        let mut failed = 0;
+
+        for delivery in &missed {
+            match self
+                .recover_one(&client, &token, repository, hook.id, delivery)
+                .await
+            {
+                Ok(true) => {
+                    recovered += 1;
+                    tracing::info!(
+                        repo = %format!("{}/{}", repository.owner, repository.name),
+                        delivery = %delivery.guid,
+                        event_type = %delivery.event,
+                        "Missed GitHub delivery recovered"
+                    );
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    failed += 1;
+                    tracing::warn!(
+                        repo = %format!("{}/{}", repository.owner, repository.name),
+                        delivery = %delivery.guid,
+                        error = %e,
+                        "Failed to recover GitHub delivery"
+                    );
+                }
+            }
+        }
+
+        Ok((recovered, failed))
+    }
+}
+
+impl CommandExecutor for RecoverWebhookDeliveriesExecutor {
+    type Command = RecoverWebhookDeliveriesExecutorCommand;
+    type Response = RecoverWebhookDeliveriesExecutorResponse;
+    type Error = RecoverWebhookDeliveriesExecutorError;
+
+    async fn execute(&self, _cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        if self.lookback_hours <= 0 {
+            return Ok(RecoverWebhookDeliveriesExecutorResponse {
+                repos_scanned: 0,
+                recovered_count: 0,
+                failed_count: 0,
+            });
+        }
+
+        let repos = self
+            .repository_repo
+            .find_all()
+            .await
+            .map_err(|e| RecoverWebhookDeliveriesExecutorError::DbError(e.to_string()))?;
+
+        let mut response = RecoverWebhookDeliveriesExecutorResponse {
+            repos_scanned: 0,
+            recovered_count: 0,
+            failed_count: 0,
+        };
+
+        // История доставок есть только у GitHub
+        for repo in repos
+            .into_iter()
+            .filter(|r| !r.is_archived && r.version_control_type == VersionControlType::Github)
+        {
+            response.repos_scanned += 1;
+
+            match self.recover_repository(&repo).await {
+                Ok((recovered, failed)) => {
+                    response.recovered_count += recovered;
+                    response.failed_count += failed;
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        repo = %format!("{}/{}", repo.owner, repo.name),
+                        error = %e,
+                        "Failed to list GitHub hook deliveries"
+                    );
+                }
+            }
+        }
+
+        Ok(response)
+    }
+}
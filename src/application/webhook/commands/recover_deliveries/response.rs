@@ -0,0 +1,5 @@
+pub struct RecoverWebhookDeliveriesExecutorResponse {
+    pub repos_scanned: usize,
+    pub recovered_count: usize,
+    pub failed_count: usize,
+}
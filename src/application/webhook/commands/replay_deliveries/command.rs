@@ -0,0 +1,23 @@
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_cursor::WebhookDeliveryCursor;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone)]
+pub enum ReplayWebhookDeliveriesSelector {
+    /// Одна конкретная доставка из журнала
+    Delivery(WebhookDeliveryId),
+    /// Все доставки репозитория (owner/name) за окно `[from, to]`
+    RepositoryWindow {
+        repository_full_name: String,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        /// Продолжение окна: только доставки после курсора прошлого прогона
+        after: Option<WebhookDeliveryCursor>,
+    },
+}
+
+pub struct ReplayWebhookDeliveriesExecutorCommand {
+    pub selector: ReplayWebhookDeliveriesSelector,
+    /// Только показать, какие слушатели сработают, ничего не отправляя
+    pub dry_run: bool,
+}
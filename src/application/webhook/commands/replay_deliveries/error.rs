@@ -0,0 +1,20 @@
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::FindWebhookDeliveryError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ReplayWebhookDeliveriesExecutorError {
+    #[error("Webhook delivery not found")]
+    NotFound,
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<FindWebhookDeliveryError> for ReplayWebhookDeliveriesExecutorError {
+    fn from(e: FindWebhookDeliveryError) -> Self {
+        match e {
+            FindWebhookDeliveryError::DbError(msg) => Self::DbError(msg),
+            FindWebhookDeliveryError::NotFound => Self::NotFound,
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::application::webhook::commands::dispatch_event::command::DispatchWebhookEventExecutorCommand;
+use crate::application::webhook::commands::dispatch_event::executor::DispatchWebhookEventExecutor;
+use crate::application::webhook::commands::replay_deliveries::command::{
+    ReplayWebhookDeliveriesExecutorCommand, ReplayWebhookDeliveriesSelector,
+};
+use crate::application::webhook::commands::replay_deliveries::error::ReplayWebhookDeliveriesExecutorError;
+use crate::application::webhook::commands::replay_deliveries::response::{
+    ReplayWebhookDeliveriesExecutorResponse, ReplayedWebhookDelivery,
+    ReplayedWebhookDeliveryOutcome,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_cursor::WebhookDeliveryCursor;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use crate::infrastructure::contracts::github::event_type::GithubEventType;
+use crate::infrastructure::contracts::github::mapper::{
+    GithubWebhookEventMapper, GithubWebhookEventMapperError,
+};
+use crate::infrastructure::processing::event_bus::EventBus;
+use chrono::Utc;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Защита от случайной переотправки всего журнала одним запросом:
+/// остаток окна отдаётся через `next_after`
+const MAX_REPLAY_DELIVERIES: usize = 100;
+
+pub struct ReplayWebhookDeliveriesExecutor {
+    pub webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    pub dispatch_webhook_event: Arc<DispatchWebhookEventExecutor>,
+    pub event_bus: Arc<EventBus>,
+}
+
+impl ReplayWebhookDeliveriesExecutor {
+    async fn replay_one(
+        &self,
+        delivery: WebhookDelivery,
+        dry_run: bool,
+    ) -> ReplayedWebhookDelivery {
+        let mut result = ReplayedWebhookDelivery {
+            id: delivery.id,
+            delivery_id: delivery.delivery_id.clone(),
+            event_type: delivery.event_type.clone(),
+            event_name: None,
+            listeners: Vec::new(),
+            outcome: ReplayedWebhookDeliveryOutcome::DryRun,
+        };
+
+        if delivery.provider != VersionControlType::Github {
+            result.outcome = ReplayedWebhookDeliveryOutcome::Skipped(format!(
+                "Replay is not supported for provider {}",
+                delivery.provider
+            ));
+            return result;
+        }
+
+        // Тело отклонённого запроса никто не подписывал — в слушатели его не пускаем
+        if !delivery.signature_valid {
+            result.outcome = ReplayedWebhookDeliveryOutcome::Skipped(
+                "Signature verification failed".to_string(),
+            );
+            return result;
+        }
+
+        if delivery.payload.is_empty() {
+            result.outcome =
+                ReplayedWebhookDeliveryOutcome::Skipped("Payload is not stored".to_string());
+            return result;
+        }
+
+        let payload: serde_json::Value = match serde_json::from_str(&delivery.payload) {
+            Ok(payload) => payload,
+            Err(e) => {
+                result.outcome = ReplayedWebhookDeliveryOutcome::Failed(e.to_string());
+                return result;
+            }
+        };
+
+        let github_event_type = GithubEventType::from_str(&delivery.event_type)
+            .unwrap_or(GithubEventType::Unknown(delivery.event_type.clone()));
+
+        let event = match GithubWebhookEventMapper::map_to_domain_event(&github_event_type, payload)
+        {
+            Ok(event) => event,
+            Err(
+                e @ (GithubWebhookEventMapperError::UnsupportedEventType
+                | GithubWebhookEventMapperError::Skipped(_)),
+            ) => {
+                result.outcome = ReplayedWebhookDeliveryOutcome::Skipped(e.to_string());
+                return result;
+            }
+            Err(e) => {
+                result.outcome = ReplayedWebhookDeliveryOutcome::Failed(e.to_string());
+                return result;
+            }
+        };
+
+        result.event_name = Some(event.event_name().to_string());
+        result.listeners = self.event_bus.listener_names(event.event_name()).await;
+
+        if dry_run {
+            return result;
+        }
+
+        let cmd = DispatchWebhookEventExecutorCommand {
+            event,
+            provider: WebhookProvider::Github,
+            repository_full_name: delivery.repository_full_name.clone(),
+        };
+
+        let (status, error_message) = match self.dispatch_webhook_event.execute(&cmd).await {
+            Ok(r) if r.skipped => {
+                let reason = "Repository is archived".to_string();
+                result.outcome = ReplayedWebhookDeliveryOutcome::Skipped(reason.clone());
+                (WebhookDeliveryStatus::Skipped, Some(reason))
+            }
+            Ok(_) => {
+                result.outcome = ReplayedWebhookDeliveryOutcome::Dispatched;
+                (WebhookDeliveryStatus::Dispatched, None)
+            }
+            Err(e) => {
+                result.outcome = ReplayedWebhookDeliveryOutcome::Failed(e.to_string());
+                (WebhookDeliveryStatus::Failed, Some(e.to_string()))
+            }
+        };
+
+        let mut delivery = delivery;
+        delivery.status = status;
+        delivery.error_message = error_message;
+        delivery.processed_at = Some(Utc::now());
+
+        if let Err(e) = self.webhook_delivery_repo.update(&delivery).await {
+            tracing::warn!(
+                error = %e,
+                delivery_id = delivery.id.0,
+                "Failed to update replayed webhook delivery status"
+            );
+        }
+
+        result
+    }
+}
+
+impl CommandExecutor for ReplayWebhookDeliveriesExecutor {
+    type Command = ReplayWebhookDeliveriesExecutorCommand;
+    type Response = ReplayWebhookDeliveriesExecutorResponse;
+    type Error = ReplayWebhookDeliveriesExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let mut next_after = None;
+
+        let deliveries = match &cmd.selector {
+            ReplayWebhookDeliveriesSelector::Delivery(id) => {
+                vec![self.webhook_delivery_repo.find_by_id(*id).await?]
+            }
+            ReplayWebhookDeliveriesSelector::RepositoryWindow {
+                repository_full_name,
+                from,
+                to,
+                after,
+            } => {
+                // Лишняя запись показывает, что окно не поместилось в лимит
+                let mut deliveries = self
+                    .webhook_delivery_repo
+                    .find_by_repository_between(
+                        repository_full_name,
+                        *from,
+                        *to,
+                        *after,
+                        MAX_REPLAY_DELIVERIES as u64 + 1,
+                    )
+                    .await?;
+
+                if deliveries.len() > MAX_REPLAY_DELIVERIES {
+                    deliveries.truncate(MAX_REPLAY_DELIVERIES);
+                    next_after = deliveries.last().map(WebhookDeliveryCursor::from);
+                }
+
+                deliveries
+            }
+        };
+
+        let mut results = Vec::with_capacity(deliveries.len());
+
+        // Строго по порядку получения — слушатели чувствительны к последовательности
+        for delivery in deliveries {
+            let result = self.replay_one(delivery, cmd.dry_run).await;
+
+            tracing::info!(
+                delivery = result.delivery_id.as_deref().unwrap_or("-"),
+                event_type = %result.event_type,
+                dry_run = cmd.dry_run,
+                outcome = ?result.outcome,
+                "Webhook delivery replayed"
+            );
+
+            results.push(result);
+        }
+
+        Ok(ReplayWebhookDeliveriesExecutorResponse {
+            dry_run: cmd.dry_run,
+            results,
+            next_after,
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_cursor::WebhookDeliveryCursor;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+
+#[derive(Debug, Clone)]
+pub enum ReplayedWebhookDeliveryOutcome {
+    Dispatched,
+    DryRun,
+    Skipped(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayedWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub delivery_id: Option<String>,
+    pub event_type: String,
+    /// Доменное событие после маппинга, если payload удалось разобрать
+    pub event_name: Option<String>,
+    pub listeners: Vec<String>,
+    pub outcome: ReplayedWebhookDeliveryOutcome,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayWebhookDeliveriesExecutorResponse {
+    pub dry_run: bool,
+    pub results: Vec<ReplayedWebhookDelivery>,
+    /// Окно не поместилось в лимит — остаток идёт строго после этого курсора
+    pub next_after: Option<WebhookDeliveryCursor>,
+}
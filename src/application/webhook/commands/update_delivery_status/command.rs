@@ -0,0 +1,8 @@
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+
+pub struct UpdateWebhookDeliveryStatusExecutorCommand {
+    pub id: WebhookDeliveryId,
+    pub status: WebhookDeliveryStatus,
+    pub error_message: Option<String>,
+}
@@ -0,0 +1,31 @@
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::{
+    FindWebhookDeliveryError, UpdateWebhookDeliveryError,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UpdateWebhookDeliveryStatusExecutorError {
+    #[error("Webhook delivery not found")]
+    NotFound,
+
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<UpdateWebhookDeliveryError> for UpdateWebhookDeliveryStatusExecutorError {
+    fn from(e: UpdateWebhookDeliveryError) -> Self {
+        match e {
+            UpdateWebhookDeliveryError::NotFound => Self::NotFound,
+            UpdateWebhookDeliveryError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
+
+impl From<FindWebhookDeliveryError> for UpdateWebhookDeliveryStatusExecutorError {
+    fn from(e: FindWebhookDeliveryError) -> Self {
+        match e {
+            FindWebhookDeliveryError::NotFound => Self::NotFound,
+            FindWebhookDeliveryError::DbError(msg) => Self::DbError(msg),
+        }
+    }
+}
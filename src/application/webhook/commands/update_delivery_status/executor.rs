@@ -0,0 +1,58 @@
+use crate::application::webhook::commands::update_delivery_status::command::UpdateWebhookDeliveryStatusExecutorCommand;
+use crate::application::webhook::commands::update_delivery_status::error::UpdateWebhookDeliveryStatusExecutorError;
+use crate::application::webhook::commands::update_delivery_status::response::UpdateWebhookDeliveryStatusExecutorResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub struct UpdateWebhookDeliveryStatusExecutor {
+    pub webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl UpdateWebhookDeliveryStatusExecutor {
+    pub fn new(webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self {
+            webhook_delivery_repo,
+        }
+    }
+
+    /// Хелпер для контроллеров: ошибку журнала только логируем,
+    /// ответ провайдеру от неё не зависит
+    pub async fn mark(
+        &self,
+        id: WebhookDeliveryId,
+        status: WebhookDeliveryStatus,
+        error_message: Option<String>,
+    ) {
+        let cmd = UpdateWebhookDeliveryStatusExecutorCommand {
+            id,
+            status,
+            error_message,
+        };
+
+        if let Err(e) = self.execute(&cmd).await {
+            tracing::warn!(error = %e, delivery = id.0, "Failed to update webhook delivery status");
+        }
+    }
+}
+
+impl CommandExecutor for UpdateWebhookDeliveryStatusExecutor {
+    type Command = UpdateWebhookDeliveryStatusExecutorCommand;
+    type Response = UpdateWebhookDeliveryStatusExecutorResponse;
+    type Error = UpdateWebhookDeliveryStatusExecutorError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let mut delivery = self.webhook_delivery_repo.find_by_id(cmd.id).await?;
+
+        delivery.status = cmd.status;
+        delivery.error_message = cmd.error_message.clone();
+        delivery.processed_at = Some(Utc::now());
+
+        self.webhook_delivery_repo.update(&delivery).await?;
+
+        Ok(UpdateWebhookDeliveryStatusExecutorResponse {})
+    }
+}
@@ -0,0 +1,17 @@
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::FindWebhookDeliveryError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ListWebhookDeliveriesError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+impl From<FindWebhookDeliveryError> for ListWebhookDeliveriesError {
+    fn from(e: FindWebhookDeliveryError) -> Self {
+        match e {
+            FindWebhookDeliveryError::DbError(msg) => Self::DbError(msg),
+            FindWebhookDeliveryError::NotFound => Self::DbError("Not found".to_string()),
+        }
+    }
+}
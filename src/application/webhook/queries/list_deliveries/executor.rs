@@ -0,0 +1,48 @@
+use crate::application::webhook::queries::list_deliveries::error::ListWebhookDeliveriesError;
+use crate::application::webhook::queries::list_deliveries::query::ListWebhookDeliveriesQuery;
+use crate::application::webhook::queries::list_deliveries::response::ListWebhookDeliveriesResponse;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+const MAX_LIST_LIMIT: u64 = 200;
+
+pub struct ListWebhookDeliveriesExecutor {
+    webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl ListWebhookDeliveriesExecutor {
+    pub fn new(webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self {
+            webhook_delivery_repo,
+        }
+    }
+}
+
+impl CommandExecutor for ListWebhookDeliveriesExecutor {
+    type Command = ListWebhookDeliveriesQuery;
+    type Response = ListWebhookDeliveriesResponse;
+    type Error = ListWebhookDeliveriesError;
+
+    async fn execute(&self, cmd: &Self::Command) -> Result<Self::Response, Self::Error> {
+        let limit = cmd.limit.clamp(1, MAX_LIST_LIMIT);
+
+        let deliveries = match &cmd.repository_full_name {
+            Some(repository_full_name) => {
+                self.webhook_delivery_repo
+                    .find_by_repository_between(
+                        repository_full_name,
+                        cmd.from.unwrap_or(DateTime::<Utc>::MIN_UTC),
+                        cmd.to.unwrap_or_else(Utc::now),
+                        None,
+                        limit,
+                    )
+                    .await?
+            }
+            None => self.webhook_delivery_repo.find_recent(limit).await?,
+        };
+
+        Ok(ListWebhookDeliveriesResponse { deliveries })
+    }
+}
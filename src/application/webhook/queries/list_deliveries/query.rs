@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+
+pub struct ListWebhookDeliveriesQuery {
+    /// owner/name — без него отдаём последние доставки по всем репозиториям
+    pub repository_full_name: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub limit: u64,
+}
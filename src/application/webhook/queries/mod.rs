@@ -0,0 +1 @@
+pub mod list_deliveries;
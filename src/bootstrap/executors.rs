@@ -1,4 +1,8 @@
 use crate::application::auth::commands::create_oauth_link::executor::CreateOAuthLinkExecutor;
+use crate::application::commit_check::commands::prune_commit_checks::executor::PruneCommitChecksExecutor;
+use crate::application::commit_check::commands::record_commit_check::executor::RecordCommitCheckExecutor;
+use crate::application::deployment::commands::record_deployment::executor::RecordDeploymentExecutor;
+use crate::application::deployment::queries::get_deployments_overview::executor::GetDeploymentsOverviewExecutor;
 use crate::application::digest::commands::create_digest_subscription::executor::CreateDigestSubscriptionExecutor;
 use crate::application::digest::commands::delete_digest_subscription::executor::DeleteDigestSubscriptionExecutor;
 use crate::application::digest::commands::send_due_digests::executor::SendDueDigestsExecutor;
@@ -11,9 +15,11 @@ use crate::application::health_ping::commands::delete_health_ping::executor::Del
 use crate::application::health_ping::commands::update_health_ping::executor::UpdateHealthPingExecutor;
 use crate::application::health_ping::commands::update_health_ping_status::executor::UpdateHealthPingStatusExecutor;
 use crate::application::health_ping::queries::get_all_health_pings::executor::GetAllHealthPingsExecutor;
+use crate::application::merged_branch::commands::send_branch_cleanup_reminders::executor::SendBranchCleanupRemindersExecutor;
 use crate::application::monitoring::queries::get_queues_stats::executor::GetQueuesStatsExecutor;
 use crate::application::notification::commands::buffer_notification::executor::BufferNotificationExecutor;
 use crate::application::notification::commands::flush_pending_notifications::executor::FlushPendingNotificationsExecutor;
+use crate::application::notification::commands::prune_slack_messages::executor::PruneSlackMessagesExecutor;
 use crate::application::notification::commands::scan_pr_conflicts::executor::ScanPrConflictsExecutor;
 use crate::application::notification::commands::scan_stale_pull_requests::executor::ScanStalePullRequestsExecutor;
 use crate::application::notification::commands::send_social_notify::executor::SendSocialNotifyExecutor;
@@ -26,22 +32,46 @@ use crate::application::release_plan::commands::update_release_plan::executor::U
 use crate::application::release_plan::queries::get_upcoming_release_plans::executor::GetUpcomingReleasePlansExecutor;
 use crate::application::repository::commands::create_repository::executor::CreateRepositoryExecutor;
 use crate::application::repository::commands::create_repository_task_tracker::executor::CreateRepositoryTaskTrackerExecutor;
+use crate::application::repository::commands::create_repository_task_tracker_rule::executor::CreateRepositoryTaskTrackerRuleExecutor;
 use crate::application::repository::commands::delete_repository::executor::DeleteRepositoryExecutor;
+use crate::application::repository::commands::delete_repository_task_tracker_rule::executor::DeleteRepositoryTaskTrackerRuleExecutor;
+use crate::application::repository::commands::ensure_repository_webhook::executor::EnsureRepositoryWebhookExecutor;
+use crate::application::repository::commands::register_installation_repositories::executor::RegisterInstallationRepositoriesExecutor;
+use crate::application::repository::commands::rotate_repository_webhook_secret::executor::RotateRepositoryWebhookSecretExecutor;
 use crate::application::repository::commands::set_repository_notification_chat::executor::SetRepositoryNotificationChatExecutor;
 use crate::application::repository::commands::set_repository_notifications_chat::executor::SetRepositoryNotificationsChatExecutor;
+use crate::application::repository::commands::set_repository_ref_events_filter::executor::SetRepositoryRefEventsFilterExecutor;
+use crate::application::repository::commands::set_repository_slack_channel::executor::SetRepositorySlackChannelExecutor;
+use crate::application::repository::commands::sync_repository_from_webhook::executor::SyncRepositoryFromWebhookExecutor;
 use crate::application::repository::commands::unset_repository_notification_chat::executor::UnsetRepositoryNotificationChatExecutor;
 use crate::application::repository::commands::update_repository::executor::UpdateRepositoryExecutor;
 use crate::application::repository::commands::update_repository_task_tracker::executor::UpdateRepositoryTaskTrackerExecutor;
 use crate::application::repository::queries::get_all_repositories::executor::GetAllRepositoriesExecutor;
+use crate::application::repository::queries::get_repository_task_tracker_rules::executor::GetRepositoryTaskTrackerRulesExecutor;
+use crate::application::repository::queries::resolve_repository_webhook_secrets::executor::ResolveRepositoryWebhookSecretsExecutor;
+use crate::application::task::commands::assign_task_card::executor::AssignTaskCardExecutor;
+use crate::application::task::commands::complete_task_testing::executor::CompleteTaskTestingExecutor;
+use crate::application::task::commands::create_task_card::executor::CreateTaskCardExecutor;
+use crate::application::task::commands::create_task_tracker_connection::executor::CreateTaskTrackerConnectionExecutor;
+use crate::application::task::commands::delete_task_tracker_connection::executor::DeleteTaskTrackerConnectionExecutor;
 use crate::application::task::commands::move_task_to_test::executor::MoveTaskToTestExecutor;
+use crate::application::task::queries::get_all_task_tracker_connections::executor::GetAllTaskTrackerConnectionsExecutor;
+use crate::application::task::queries::get_recent_task_moves::executor::GetRecentTaskMovesExecutor;
 use crate::application::task::queries::get_task_card::executor::GetTaskCardExecutor;
+use crate::application::task::queries::get_test_queue::executor::GetTestQueueExecutor;
 use crate::application::user::commands::assign_user_role::executor::AssignUserRoleExecutor;
 use crate::application::user::commands::bind_repository::executor::BindRepositoryExecutor;
+use crate::application::user::commands::confirm_slack_account_link::executor::ConfirmSlackAccountLinkExecutor;
 use crate::application::user::commands::deactivate_user::executor::DeactivateUserExecutor;
+use crate::application::user::commands::link_version_control_account::executor::LinkVersionControlAccountExecutor;
 use crate::application::user::commands::register_via_oauth::executor::RegisterUserViaOAuthExecutor;
 use crate::application::user::commands::remove_user_role::executor::RemoveUserRoleExecutor;
+use crate::application::user::commands::request_slack_account_link::executor::RequestSlackAccountLinkExecutor;
+use crate::application::user::commands::request_version_control_account_link::executor::RequestVersionControlAccountLinkExecutor;
+use crate::application::user::commands::sync_org_membership::executor::SyncOrgMembershipExecutor;
 use crate::application::user::commands::toggle_user_active::executor::ToggleUserActiveExecutor;
 use crate::application::user::commands::unbind_repository::executor::UnbindRepositoryExecutor;
+use crate::application::user::commands::unlink_slack_account::executor::UnlinkSlackAccountExecutor;
 use crate::application::user::queries::check_org_membership::executor::CheckOrgMembershipExecutor;
 use crate::application::user::queries::get_all_users::executor::GetAllUsersExecutor;
 use crate::application::user::queries::get_my_pull_requests::executor::GetMyPullRequestsExecutor;
@@ -53,6 +83,13 @@ use crate::application::user_preferences::commands::update_user_preferences::exe
 use crate::application::user_preferences::queries::get_user_preferences::executor::GetUserPreferencesExecutor;
 use crate::application::version_control::queries::build_report::executor::BuildVersionControlDateRangeReportExecutor;
 use crate::application::webhook::commands::dispatch_event::executor::DispatchWebhookEventExecutor;
+use crate::application::webhook::commands::prune_deliveries::executor::PruneWebhookDeliveriesExecutor;
+use crate::application::webhook::commands::record_delivery::executor::RecordWebhookDeliveryExecutor;
+use crate::application::webhook::commands::record_rejected_delivery::executor::RecordRejectedWebhookDeliveryExecutor;
+use crate::application::webhook::commands::recover_deliveries::executor::RecoverWebhookDeliveriesExecutor;
+use crate::application::webhook::commands::replay_deliveries::executor::ReplayWebhookDeliveriesExecutor;
+use crate::application::webhook::commands::update_delivery_status::executor::UpdateWebhookDeliveryStatusExecutor;
+use crate::application::webhook::queries::list_deliveries::executor::ListWebhookDeliveriesExecutor;
 use crate::bootstrap::shared_dependency::ApplicationSharedDependency;
 use crate::config::application::ApplicationConfig;
 use crate::domain::monitoring::ports::workers_stats_provider::WorkersStatsProvider;
@@ -66,36 +103,65 @@ pub struct ApplicationBoostrapExecutorsQueries {
     pub get_user_roles_by_telegram_id: Arc<GetUserRolesByTelegramIdExecutor>,
     pub get_user_bound_repositories: Arc<GetUserBoundRepositoriesExecutor>,
     pub get_all_repositories: Arc<GetAllRepositoriesExecutor>,
+    pub resolve_repository_webhook_secrets: Arc<ResolveRepositoryWebhookSecretsExecutor>,
     pub get_task_card: Arc<GetTaskCardExecutor>,
     pub get_queues_stats: Arc<GetQueuesStatsExecutor>,
     pub get_user_digest_subscriptions: Arc<GetUserDigestSubscriptionsExecutor>,
     pub get_all_health_pings: Arc<GetAllHealthPingsExecutor>,
+    pub get_all_task_tracker_connections: Arc<GetAllTaskTrackerConnectionsExecutor>,
+    pub get_repository_task_tracker_rules: Arc<GetRepositoryTaskTrackerRulesExecutor>,
+    pub get_recent_task_moves: Arc<GetRecentTaskMovesExecutor>,
+    pub get_test_queue: Arc<GetTestQueueExecutor>,
     pub get_all_users: Arc<GetAllUsersExecutor>,
     pub get_user_preferences: Arc<GetUserPreferencesExecutor>,
     pub get_upcoming_release_plans: Arc<GetUpcomingReleasePlansExecutor>,
     pub get_user_overview: Arc<GetUserOverviewExecutor>,
     pub get_my_pull_requests: Arc<GetMyPullRequestsExecutor>,
     pub get_pending_reviews: Arc<GetPendingReviewsExecutor>,
+    pub get_deployments_overview: Arc<GetDeploymentsOverviewExecutor>,
     pub check_org_membership: Arc<CheckOrgMembershipExecutor>,
+    pub list_webhook_deliveries: Arc<ListWebhookDeliveriesExecutor>,
 }
 
 pub struct ApplicationBoostrapExecutorsCommands {
     pub register_user_via_oauth: Arc<RegisterUserViaOAuthExecutor>,
     pub create_oauth_link: Arc<CreateOAuthLinkExecutor>,
+    pub request_version_control_account_link: Arc<RequestVersionControlAccountLinkExecutor>,
+    pub link_version_control_account: Arc<LinkVersionControlAccountExecutor>,
     pub dispatch_webhook_event: Arc<DispatchWebhookEventExecutor>,
+    pub record_webhook_delivery: Arc<RecordWebhookDeliveryExecutor>,
+    pub record_rejected_webhook_delivery: Arc<RecordRejectedWebhookDeliveryExecutor>,
+    pub update_webhook_delivery_status: Arc<UpdateWebhookDeliveryStatusExecutor>,
+    pub prune_webhook_deliveries: Arc<PruneWebhookDeliveriesExecutor>,
+    pub record_commit_check: Arc<RecordCommitCheckExecutor>,
+    pub prune_commit_checks: Arc<PruneCommitChecksExecutor>,
+    pub prune_slack_messages: Arc<PruneSlackMessagesExecutor>,
+    pub record_deployment: Arc<RecordDeploymentExecutor>,
+    pub replay_webhook_deliveries: Arc<ReplayWebhookDeliveriesExecutor>,
+    pub recover_webhook_deliveries: Arc<RecoverWebhookDeliveriesExecutor>,
     pub send_social_notify: Arc<SendSocialNotifyExecutor>,
     pub move_task_to_test: Arc<MoveTaskToTestExecutor>,
     pub create_repository: Arc<CreateRepositoryExecutor>,
     pub create_repository_task_tracker: Arc<CreateRepositoryTaskTrackerExecutor>,
+    pub register_installation_repositories: Arc<RegisterInstallationRepositoriesExecutor>,
     pub update_repository: Arc<UpdateRepositoryExecutor>,
     pub update_repository_task_tracker: Arc<UpdateRepositoryTaskTrackerExecutor>,
     pub set_repository_notification_chat: Arc<SetRepositoryNotificationChatExecutor>,
     pub set_repository_notifications_chat: Arc<SetRepositoryNotificationsChatExecutor>,
+    pub rotate_repository_webhook_secret: Arc<RotateRepositoryWebhookSecretExecutor>,
+    pub set_repository_ref_events_filter: Arc<SetRepositoryRefEventsFilterExecutor>,
+    pub set_repository_slack_channel: Arc<SetRepositorySlackChannelExecutor>,
+    pub sync_repository_from_webhook: Arc<SyncRepositoryFromWebhookExecutor>,
     pub unset_repository_notification_chat: Arc<UnsetRepositoryNotificationChatExecutor>,
     pub bind_repository: Arc<BindRepositoryExecutor>,
     pub unbind_repository: Arc<UnbindRepositoryExecutor>,
+    pub request_slack_account_link: Arc<RequestSlackAccountLinkExecutor>,
+    pub confirm_slack_account_link: Arc<ConfirmSlackAccountLinkExecutor>,
+    pub unlink_slack_account: Arc<UnlinkSlackAccountExecutor>,
     pub delete_repository: Arc<DeleteRepositoryExecutor>,
+    pub ensure_repository_webhook: Arc<EnsureRepositoryWebhookExecutor>,
     pub deactivate_user: Arc<DeactivateUserExecutor>,
+    pub sync_org_membership: Arc<SyncOrgMembershipExecutor>,
     pub create_digest_subscription: Arc<CreateDigestSubscriptionExecutor>,
     pub update_digest_subscription: Arc<UpdateDigestSubscriptionExecutor>,
     pub toggle_digest_subscription: Arc<ToggleDigestSubscriptionExecutor>,
@@ -105,6 +171,13 @@ pub struct ApplicationBoostrapExecutorsCommands {
     pub update_health_ping: Arc<UpdateHealthPingExecutor>,
     pub update_health_ping_status: Arc<UpdateHealthPingStatusExecutor>,
     pub delete_health_ping: Arc<DeleteHealthPingExecutor>,
+    pub create_task_tracker_connection: Arc<CreateTaskTrackerConnectionExecutor>,
+    pub delete_task_tracker_connection: Arc<DeleteTaskTrackerConnectionExecutor>,
+    pub create_repository_task_tracker_rule: Arc<CreateRepositoryTaskTrackerRuleExecutor>,
+    pub delete_repository_task_tracker_rule: Arc<DeleteRepositoryTaskTrackerRuleExecutor>,
+    pub assign_task_card: Arc<AssignTaskCardExecutor>,
+    pub complete_task_testing: Arc<CompleteTaskTestingExecutor>,
+    pub create_task_card: Arc<CreateTaskCardExecutor>,
 
     pub send_due_digests: Arc<SendDueDigestsExecutor>,
 
@@ -118,6 +191,7 @@ pub struct ApplicationBoostrapExecutorsCommands {
 
     pub scan_stale_pull_requests: Arc<ScanStalePullRequestsExecutor>,
     pub scan_pr_conflicts: Arc<ScanPrConflictsExecutor>,
+    pub send_branch_cleanup_reminders: Arc<SendBranchCleanupRemindersExecutor>,
 
     pub create_release_plan: Arc<CreateReleasePlanExecutor>,
     pub update_release_plan: Arc<UpdateReleasePlanExecutor>,
@@ -144,11 +218,12 @@ impl ApplicationBoostrapExecutors {
                 shared_dependency.reversible_cipher.clone(),
                 shared_dependency.user_socials_repo.clone(),
                 shared_dependency.user_version_controls_repo.clone(),
-                shared_dependency.version_control_client.clone(),
+                shared_dependency.version_control_clients.clone(),
+                shared_dependency.version_control_tokens.clone(),
                 shared_dependency.repository_repo.clone(),
                 shared_dependency.repository_task_tracker_repo.clone(),
                 shared_dependency.task_tracker_service.clone(),
-                config.kaiten.base.clone(),
+                shared_dependency.task_tracker_client_resolver.clone(),
                 config.base_url.clone(),
                 shared_dependency.cache.clone(),
                 config.secret.reversible_cipher_secret.clone(),
@@ -166,8 +241,16 @@ impl ApplicationBoostrapExecutors {
             get_all_repositories: Arc::new(GetAllRepositoriesExecutor::new(
                 shared_dependency.repository_repo.clone(),
             )),
+            resolve_repository_webhook_secrets: Arc::new(
+                ResolveRepositoryWebhookSecretsExecutor::new(
+                    shared_dependency.repository_repo.clone(),
+                    shared_dependency.reversible_cipher.clone(),
+                    config.github.webhook_secret.clone(),
+                    config.github.webhook_secret_rotation_grace_hours,
+                ),
+            ),
             get_task_card: Arc::new(GetTaskCardExecutor::new(
-                shared_dependency.task_tracker_client.clone(),
+                shared_dependency.task_tracker_client_resolver.clone(),
             )),
             get_queues_stats: Arc::new(GetQueuesStatsExecutor { stats_provider }),
 
@@ -180,6 +263,28 @@ impl ApplicationBoostrapExecutors {
                 shared_dependency.health_ping_repo.clone(),
             )),
 
+            get_all_task_tracker_connections: Arc::new(GetAllTaskTrackerConnectionsExecutor::new(
+                shared_dependency.task_tracker_connection_repo.clone(),
+            )),
+
+            get_repository_task_tracker_rules: Arc::new(
+                GetRepositoryTaskTrackerRulesExecutor::new(
+                    shared_dependency.repository_task_tracker_rule_repo.clone(),
+                ),
+            ),
+
+            get_recent_task_moves: Arc::new(GetRecentTaskMovesExecutor::new(
+                shared_dependency.task_move_repo.clone(),
+            )),
+
+            get_test_queue: Arc::new(GetTestQueueExecutor::new(
+                shared_dependency.repository_repo.clone(),
+                shared_dependency.repository_task_tracker_repo.clone(),
+                shared_dependency.task_tracker_client_resolver.clone(),
+                shared_dependency.pull_request_task_link_repo.clone(),
+                shared_dependency.commit_check_repo.clone(),
+            )),
+
             get_all_users: Arc::new(GetAllUsersExecutor::new(
                 shared_dependency.user_repo.clone(),
                 shared_dependency.user_socials_repo.clone(),
@@ -222,6 +327,17 @@ impl ApplicationBoostrapExecutors {
                 shared_dependency.reversible_cipher.clone(),
             )),
 
+            get_deployments_overview: Arc::new(GetDeploymentsOverviewExecutor::new(
+                shared_dependency.user_socials_repo.clone(),
+                shared_dependency.user_version_controls_repo.clone(),
+                shared_dependency.user_connection_repositories_repo.clone(),
+                shared_dependency.repository_repo.clone(),
+                shared_dependency.environment_deployment_repo.clone(),
+                shared_dependency.version_control_clients.clone(),
+                shared_dependency.version_control_tokens.clone(),
+                shared_dependency.reversible_cipher.clone(),
+            )),
+
             check_org_membership: Arc::new(CheckOrgMembershipExecutor {
                 user_repo: shared_dependency.user_repo.clone(),
                 user_socials_repo: shared_dependency.user_socials_repo.clone(),
@@ -236,14 +352,52 @@ impl ApplicationBoostrapExecutors {
                 },
                 admin_social_user_id: SocialUserId(config.telegram.admin_user_id as i32),
             }),
+
+            list_webhook_deliveries: Arc::new(ListWebhookDeliveriesExecutor::new(
+                shared_dependency.webhook_delivery_repo.clone(),
+            )),
         };
 
+        let dispatch_webhook_event = Arc::new(DispatchWebhookEventExecutor {
+            publisher: shared_dependency.publisher.clone(),
+            repository_repo: shared_dependency.repository_repo.clone(),
+        });
+
+        let record_webhook_delivery = Arc::new(RecordWebhookDeliveryExecutor::new(
+            shared_dependency.webhook_delivery_repo.clone(),
+        ));
+
+        let update_webhook_delivery_status = Arc::new(UpdateWebhookDeliveryStatusExecutor::new(
+            shared_dependency.webhook_delivery_repo.clone(),
+        ));
+
+        let create_repository = Arc::new(CreateRepositoryExecutor::new(
+            mysql_pool.clone(),
+            shared_dependency.repository_repo.clone(),
+        ));
+
         let commands = ApplicationBoostrapExecutorsCommands {
             create_oauth_link: Arc::new(CreateOAuthLinkExecutor::new(
                 shared_dependency.user_repo.clone(),
                 shared_dependency.user_socials_repo.clone(),
                 shared_dependency.cache.clone(),
             )),
+            request_version_control_account_link: Arc::new(
+                RequestVersionControlAccountLinkExecutor::new(
+                    shared_dependency.user_socials_repo.clone(),
+                    shared_dependency.cache.clone(),
+                ),
+            ),
+            link_version_control_account: Arc::new(LinkVersionControlAccountExecutor {
+                db: mysql_pool.clone(),
+                user_version_control_service_repo: shared_dependency
+                    .user_version_controls_repo
+                    .clone(),
+                oauth_client: shared_dependency.gitlab_oauth_client.clone(),
+                version_control_clients: shared_dependency.version_control_clients.clone(),
+                reversible_cipher: shared_dependency.reversible_cipher.clone(),
+                mutex: Arc::new(KeyLocker::new()),
+            }),
             register_user_via_oauth: Arc::new(RegisterUserViaOAuthExecutor {
                 db: mysql_pool.clone(),
                 user_has_role: shared_dependency.user_has_roles_repo.clone(),
@@ -264,9 +418,45 @@ impl ApplicationBoostrapExecutors {
                     Some(config.github.repository_owner.clone())
                 },
             }),
-            dispatch_webhook_event: Arc::new(DispatchWebhookEventExecutor {
+            dispatch_webhook_event: dispatch_webhook_event.clone(),
+            record_webhook_delivery: record_webhook_delivery.clone(),
+            record_rejected_webhook_delivery: Arc::new(RecordRejectedWebhookDeliveryExecutor::new(
+                shared_dependency.webhook_delivery_repo.clone(),
+            )),
+            update_webhook_delivery_status: update_webhook_delivery_status.clone(),
+            prune_webhook_deliveries: Arc::new(PruneWebhookDeliveriesExecutor {
+                webhook_delivery_repo: shared_dependency.webhook_delivery_repo.clone(),
+                retention_days: config.webhook.delivery_retention_days,
+            }),
+            record_commit_check: Arc::new(RecordCommitCheckExecutor {
+                commit_check_repo: shared_dependency.commit_check_repo.clone(),
                 publisher: shared_dependency.publisher.clone(),
             }),
+            prune_commit_checks: Arc::new(PruneCommitChecksExecutor {
+                commit_check_repo: shared_dependency.commit_check_repo.clone(),
+            }),
+            prune_slack_messages: Arc::new(PruneSlackMessagesExecutor {
+                slack_message_repo: shared_dependency.slack_message_repo.clone(),
+            }),
+            record_deployment: Arc::new(RecordDeploymentExecutor {
+                environment_deployment_repo: shared_dependency.environment_deployment_repo.clone(),
+            }),
+            replay_webhook_deliveries: Arc::new(ReplayWebhookDeliveriesExecutor {
+                webhook_delivery_repo: shared_dependency.webhook_delivery_repo.clone(),
+                dispatch_webhook_event: dispatch_webhook_event.clone(),
+                event_bus: shared_dependency.event_bus.clone(),
+            }),
+            recover_webhook_deliveries: Arc::new(RecoverWebhookDeliveriesExecutor {
+                repository_repo: shared_dependency.repository_repo.clone(),
+                webhook_delivery_repo: shared_dependency.webhook_delivery_repo.clone(),
+                version_control_clients: shared_dependency.version_control_clients.clone(),
+                version_control_tokens: shared_dependency.version_control_tokens.clone(),
+                record_webhook_delivery,
+                update_webhook_delivery_status,
+                dispatch_webhook_event,
+                base_url: config.base_url.clone(),
+                lookback_hours: config.webhook.recovery_lookback_hours,
+            }),
             send_social_notify: Arc::new(SendSocialNotifyExecutor::new(
                 shared_dependency.notification_service.clone(),
                 shared_dependency.user_socials_repo.clone(),
@@ -277,13 +467,18 @@ impl ApplicationBoostrapExecutors {
                 )),
             )),
             move_task_to_test: Arc::new(MoveTaskToTestExecutor::new(
-                shared_dependency.task_tracker_client.clone(),
+                shared_dependency.task_tracker_client_resolver.clone(),
                 shared_dependency.task_tracker_service.clone(),
-            )),
-            create_repository: Arc::new(CreateRepositoryExecutor::new(
-                mysql_pool.clone(),
-                shared_dependency.repository_repo.clone(),
-            )),
+                shared_dependency.task_move_repo.clone(),
+            )),
+            create_repository: create_repository.clone(),
+            register_installation_repositories: Arc::new(
+                RegisterInstallationRepositoriesExecutor {
+                    repository_repo: shared_dependency.repository_repo.clone(),
+                    create_repository,
+                    github_base: config.github.base.clone(),
+                },
+            ),
             create_repository_task_tracker: Arc::new(CreateRepositoryTaskTrackerExecutor::new(
                 mysql_pool.clone(),
                 shared_dependency.repository_task_tracker_repo.clone(),
@@ -306,6 +501,23 @@ impl ApplicationBoostrapExecutors {
                     shared_dependency.repository_repo.clone(),
                 ),
             ),
+            rotate_repository_webhook_secret: Arc::new(RotateRepositoryWebhookSecretExecutor::new(
+                mysql_pool.clone(),
+                shared_dependency.repository_repo.clone(),
+                shared_dependency.reversible_cipher.clone(),
+            )),
+            set_repository_ref_events_filter: Arc::new(SetRepositoryRefEventsFilterExecutor::new(
+                mysql_pool.clone(),
+                shared_dependency.repository_repo.clone(),
+            )),
+            set_repository_slack_channel: Arc::new(SetRepositorySlackChannelExecutor::new(
+                mysql_pool.clone(),
+                shared_dependency.repository_repo.clone(),
+            )),
+            sync_repository_from_webhook: Arc::new(SyncRepositoryFromWebhookExecutor::new(
+                mysql_pool.clone(),
+                shared_dependency.repository_repo.clone(),
+            )),
             unset_repository_notification_chat: Arc::new(
                 UnsetRepositoryNotificationChatExecutor::new(
                     mysql_pool.clone(),
@@ -323,15 +535,51 @@ impl ApplicationBoostrapExecutors {
                 shared_dependency.user_socials_repo.clone(),
                 shared_dependency.user_connection_repositories_repo.clone(),
             )),
+            request_slack_account_link: Arc::new(RequestSlackAccountLinkExecutor::new(
+                shared_dependency.user_socials_repo.clone(),
+                shared_dependency.slack_notification_service.clone(),
+                shared_dependency.cache.clone(),
+            )),
+            confirm_slack_account_link: Arc::new(ConfirmSlackAccountLinkExecutor::new(
+                shared_dependency.user_socials_repo.clone(),
+                shared_dependency.user_slack_accounts_repo.clone(),
+                shared_dependency.cache.clone(),
+            )),
+            unlink_slack_account: Arc::new(UnlinkSlackAccountExecutor::new(
+                shared_dependency.user_socials_repo.clone(),
+                shared_dependency.user_slack_accounts_repo.clone(),
+            )),
             delete_repository: Arc::new(DeleteRepositoryExecutor::new(
                 mysql_pool.clone(),
                 shared_dependency.repository_repo.clone(),
             )),
+            ensure_repository_webhook: Arc::new(EnsureRepositoryWebhookExecutor {
+                repository_repo: shared_dependency.repository_repo.clone(),
+                version_control_clients: shared_dependency.version_control_clients.clone(),
+                version_control_tokens: shared_dependency.version_control_tokens.clone(),
+                reversible_cipher: shared_dependency.reversible_cipher.clone(),
+                base_url: config.base_url.clone(),
+                github_webhook_secret: config.github.webhook_secret.clone(),
+                gitea_webhook_secret: config.gitea.webhook_secret.clone(),
+                gitlab_webhook_secret: config.gitlab.webhook_secret.clone(),
+            }),
 
             deactivate_user: Arc::new(DeactivateUserExecutor::new(
                 shared_dependency.user_repo.clone(),
                 shared_dependency.user_socials_repo.clone(),
             )),
+            sync_org_membership: Arc::new(SyncOrgMembershipExecutor {
+                user_repo: shared_dependency.user_repo.clone(),
+                user_socials_repo: shared_dependency.user_socials_repo.clone(),
+                user_vc_accounts_repo: shared_dependency.user_version_controls_repo.clone(),
+                cache: shared_dependency.cache.clone(),
+                required_organization: if config.github.repository_owner.is_empty() {
+                    None
+                } else {
+                    Some(config.github.repository_owner.clone())
+                },
+                admin_social_user_id: SocialUserId(config.telegram.admin_user_id as i32),
+            }),
 
             create_digest_subscription: Arc::new(CreateDigestSubscriptionExecutor::new(
                 shared_dependency.user_socials_repo.clone(),
@@ -374,6 +622,43 @@ impl ApplicationBoostrapExecutors {
                 shared_dependency.health_ping_repo.clone(),
             )),
 
+            create_task_tracker_connection: Arc::new(CreateTaskTrackerConnectionExecutor::new(
+                shared_dependency.task_tracker_connection_repo.clone(),
+                shared_dependency.task_tracker_client_factory.clone(),
+                shared_dependency.reversible_cipher.clone(),
+            )),
+
+            delete_task_tracker_connection: Arc::new(DeleteTaskTrackerConnectionExecutor::new(
+                shared_dependency.task_tracker_connection_repo.clone(),
+            )),
+
+            create_repository_task_tracker_rule: Arc::new(
+                CreateRepositoryTaskTrackerRuleExecutor::new(
+                    shared_dependency.repository_task_tracker_rule_repo.clone(),
+                ),
+            ),
+
+            delete_repository_task_tracker_rule: Arc::new(
+                DeleteRepositoryTaskTrackerRuleExecutor::new(
+                    shared_dependency.repository_task_tracker_rule_repo.clone(),
+                ),
+            ),
+            assign_task_card: Arc::new(AssignTaskCardExecutor::new(
+                shared_dependency.task_tracker_client_resolver.clone(),
+                shared_dependency.user_socials_repo.clone(),
+                shared_dependency.user_version_controls_repo.clone(),
+            )),
+            complete_task_testing: Arc::new(CompleteTaskTestingExecutor::new(
+                shared_dependency.publisher.clone(),
+                shared_dependency.repository_task_tracker_repo.clone(),
+            )),
+            create_task_card: Arc::new(CreateTaskCardExecutor::new(
+                shared_dependency.repository_task_tracker_repo.clone(),
+                shared_dependency.task_tracker_client_resolver.clone(),
+                shared_dependency.task_tracker_service.clone(),
+                shared_dependency.user_version_controls_repo.clone(),
+            )),
+
             send_due_digests: Arc::new(SendDueDigestsExecutor::new(
                 shared_dependency.digest_subscription_repo.clone(),
                 shared_dependency.user_socials_repo.clone(),
@@ -407,11 +692,10 @@ impl ApplicationBoostrapExecutors {
                 publisher: shared_dependency.publisher.clone(),
                 repository_repo: shared_dependency.repository_repo.clone(),
                 pr_review_repo: shared_dependency.pr_review_repo.clone(),
-                user_has_roles_repo: shared_dependency.user_has_roles_repo.clone(),
                 user_socials_repo: shared_dependency.user_socials_repo.clone(),
                 user_vc_accounts_repo: shared_dependency.user_version_controls_repo.clone(),
-                version_control_client: shared_dependency.version_control_client.clone(),
-                reversible_cipher: shared_dependency.reversible_cipher.clone(),
+                version_control_clients: shared_dependency.version_control_clients.clone(),
+                version_control_tokens: shared_dependency.version_control_tokens.clone(),
                 stale_threshold_hours: config.notifications.stale_threshold_hours,
             }),
 
@@ -419,11 +703,20 @@ impl ApplicationBoostrapExecutors {
                 publisher: shared_dependency.publisher.clone(),
                 repository_repo: shared_dependency.repository_repo.clone(),
                 notification_log_repo: shared_dependency.notification_log_repo.clone(),
-                user_has_roles_repo: shared_dependency.user_has_roles_repo.clone(),
                 user_socials_repo: shared_dependency.user_socials_repo.clone(),
                 user_vc_accounts_repo: shared_dependency.user_version_controls_repo.clone(),
-                version_control_client: shared_dependency.version_control_client.clone(),
-                reversible_cipher: shared_dependency.reversible_cipher.clone(),
+                version_control_clients: shared_dependency.version_control_clients.clone(),
+                version_control_tokens: shared_dependency.version_control_tokens.clone(),
+            }),
+            send_branch_cleanup_reminders: Arc::new(SendBranchCleanupRemindersExecutor {
+                publisher: shared_dependency.publisher.clone(),
+                merged_branch_repo: shared_dependency.merged_branch_repo.clone(),
+                repository_repo: shared_dependency.repository_repo.clone(),
+                user_socials_repo: shared_dependency.user_socials_repo.clone(),
+                user_vc_accounts_repo: shared_dependency.user_version_controls_repo.clone(),
+                version_control_clients: shared_dependency.version_control_clients.clone(),
+                version_control_tokens: shared_dependency.version_control_tokens.clone(),
+                reminder_days: config.notifications.branch_cleanup_reminder_days,
             }),
 
             create_release_plan: Arc::new(CreateReleasePlanExecutor {
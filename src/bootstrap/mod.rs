@@ -6,6 +6,7 @@ pub mod workers;
 
 use crate::application::notification::commands::buffer_notification::executor::BufferNotificationExecutor;
 use crate::application::notification::commands::send_social_notify::executor::SendSocialNotifyExecutor;
+use crate::application::task::commands::annotate_task_card::executor::AnnotateTaskCardExecutor;
 use crate::application::task::commands::move_task_to_test::executor::MoveTaskToTestExecutor;
 use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::bootstrap::queues::ApplicationQueues;
@@ -17,6 +18,7 @@ use crate::delivery::bot::telegram::DeliveryBotMessengerTelegram;
 use crate::delivery::contract::ApplicationDelivery;
 use crate::delivery::events::listeners::DeliveryEventListeners;
 use crate::delivery::http::axum::DeliveryHttpServerAxum;
+use crate::delivery::jobs::consumers::annotate_task_card::consumer::AnnotateTaskCardJobConsumer;
 use crate::delivery::jobs::consumers::move_task_to_test::consumer::MoveTaskToTestJobConsumer;
 use crate::delivery::jobs::consumers::send_social_notify::consumer::SendSocialNotifyJobConsumer;
 use crate::delivery::scheduler::DeliveryScheduler;
@@ -66,8 +68,12 @@ impl ApplicationBootstrap {
             )),
         ));
         let move_task_to_test_executor = Arc::new(MoveTaskToTestExecutor::new(
-            shared_dependency.task_tracker_client.clone(),
+            shared_dependency.task_tracker_client_resolver.clone(),
             shared_dependency.task_tracker_service.clone(),
+            shared_dependency.task_move_repo.clone(),
+        ));
+        let annotate_task_card_executor = Arc::new(AnnotateTaskCardExecutor::new(
+            shared_dependency.task_tracker_client_resolver.clone(),
         ));
 
         let job_consumers_registry = Arc::new(
@@ -79,6 +85,10 @@ impl ApplicationBootstrap {
                 .register(Arc::new(MoveTaskToTestJobConsumer {
                     executor: move_task_to_test_executor,
                 }))
+                .await
+                .register(Arc::new(AnnotateTaskCardJobConsumer {
+                    executor: annotate_task_card_executor,
+                }))
                 .await,
         );
 
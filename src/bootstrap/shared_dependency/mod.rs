@@ -1,26 +1,44 @@
 use crate::config::application::ApplicationConfig;
 use crate::domain::auth::ports::oauth_client::OAuthClient;
+use crate::domain::commit_check::repositories::commit_check_repository::CommitCheckRepository;
+use crate::domain::deployment::repositories::environment_deployment_repository::EnvironmentDeploymentRepository;
 use crate::domain::digest::repositories::digest_subscription_repository::DigestSubscriptionRepository;
 use crate::domain::health_ping::ports::health_check_client::HealthCheckClient;
 use crate::domain::health_ping::repositories::health_ping_repository::HealthPingRepository;
+use crate::domain::merged_branch::repositories::merged_branch_repository::MergedBranchRepository;
+use crate::domain::notification::repositories::slack_message_repository::SlackMessageRepository;
+use crate::domain::notification::services::slack_notification_service::SlackNotificationService;
 use crate::domain::notification_log::repositories::notification_log_repository::NotificationLogRepository;
 use crate::domain::pending_notification::repositories::pending_notification_repository::PendingNotificationsRepository;
 use crate::domain::pr_review::repositories::pr_review_repository::PrReviewRepository;
 use crate::domain::release_plan::repositories::release_plan_repository::ReleasePlanRepository;
 use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
 use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::RepositoryTaskTrackerRuleRepository;
 use crate::domain::role::repositories::role_repository::RoleRepository;
-use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::ports::task_tracker_client_factory::{
+    TaskTrackerClientFactory, TaskTrackerCredentials,
+};
+use crate::domain::task::ports::task_tracker_client_provider::TaskTrackerClientProvider;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::task::repositories::pull_request_task_link_repository::PullRequestTaskLinkRepository;
+use crate::domain::task::repositories::task_move_repository::TaskMoveRepository;
+use crate::domain::task::repositories::task_tracker_connection_repository::TaskTrackerConnectionRepository;
 use crate::domain::task::services::task_tracker_service::TaskTrackerService;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 use crate::domain::user::repositories::user_connection_repositories_repository::UserConnectionRepositoriesRepository;
 use crate::domain::user::repositories::user_has_roles_repository::UserHasRolesRepository;
 use crate::domain::user::repositories::user_repository::UserRepository;
+use crate::domain::user::repositories::user_slack_accounts_repository::UserSlackAccountsRepository;
 use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
 use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
 use crate::domain::user_preferences::repositories::user_preferences_repository::UserPreferencesRepository;
 use crate::domain::user_preferences::services::quiet_hours_resolver::QuietHoursResolver;
 use crate::domain::user_preferences::value_objects::quiet_hours_window::QuietHoursWindow;
 use crate::domain::version_control::ports::version_control_client::VersionControlClient;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::WebhookDeliveryRepository;
 use crate::infrastructure::drivers::cache::contract::CacheService;
 use crate::infrastructure::drivers::cache::redis::RedisCache;
 use crate::infrastructure::drivers::message_broker::contracts::broker::MessageBroker;
@@ -29,28 +47,47 @@ use crate::infrastructure::drivers::message_broker::rabbitmq::broker::MessageBro
 use crate::infrastructure::drivers::message_broker::rabbitmq::publisher::MessageBrokerRabbitMQPublisher;
 use crate::infrastructure::integrations::health_check::ReqwestHealthCheckClient;
 use crate::infrastructure::integrations::oauth::github::GithubOAuthClient;
-use crate::infrastructure::integrations::task_tracker::kaiten::{
-    KaitenClient, KaitenClientBase, KaitenClientToken,
-};
+use crate::infrastructure::integrations::oauth::gitlab::GitlabOAuthClient;
+use crate::infrastructure::integrations::version_control::gitea::client::GiteaVersionControlClient;
+use crate::infrastructure::integrations::version_control::github::app::GithubAppClient;
 use crate::infrastructure::integrations::version_control::github::client::GithubVersionControlClient;
+use crate::infrastructure::integrations::version_control::gitlab::client::GitlabVersionControlClient;
 use crate::infrastructure::processing::event_bus::EventBus;
+use crate::infrastructure::repositories::mysql::commit_check::MySQLCommitCheckRepository;
 use crate::infrastructure::repositories::mysql::digest_subscription::MySQLDigestSubscriptionRepository;
+use crate::infrastructure::repositories::mysql::environment_deployment::MySQLEnvironmentDeploymentRepository;
 use crate::infrastructure::repositories::mysql::health_ping::MySQLHealthPingRepository;
+use crate::infrastructure::repositories::mysql::merged_branch::MySQLMergedBranchRepository;
 use crate::infrastructure::repositories::mysql::notification_log::MySQLNotificationLogRepository;
 use crate::infrastructure::repositories::mysql::pending_notifications::MySQLPendingNotificationsRepository;
 use crate::infrastructure::repositories::mysql::pr_review::MySQLPrReviewRepository;
+use crate::infrastructure::repositories::mysql::pull_request_task_link::MySQLPullRequestTaskLinkRepository;
 use crate::infrastructure::repositories::mysql::release_plan::MySQLReleasePlanRepository;
 use crate::infrastructure::repositories::mysql::repository::MySQLRepositoryRepository;
 use crate::infrastructure::repositories::mysql::repository_task_tracker::MySQLRepositoryTaskTrackerRepository;
+use crate::infrastructure::repositories::mysql::repository_task_tracker_rule::MySQLRepositoryTaskTrackerRuleRepository;
 use crate::infrastructure::repositories::mysql::role::MySQLRoleRepository;
+use crate::infrastructure::repositories::mysql::slack_message::MySQLSlackMessageRepository;
+use crate::infrastructure::repositories::mysql::task_move::MySQLTaskMoveRepository;
+use crate::infrastructure::repositories::mysql::task_tracker_connection::MySQLTaskTrackerConnectionRepository;
 use crate::infrastructure::repositories::mysql::user::MySQLUserRepository;
 use crate::infrastructure::repositories::mysql::user_connection_repositories::MySQLUserConnectionRepositoriesRepository;
 use crate::infrastructure::repositories::mysql::user_has_roles::MySQLUserHasRolesRepository;
 use crate::infrastructure::repositories::mysql::user_preferences::MySQLUserPreferencesRepository;
+use crate::infrastructure::repositories::mysql::user_slack_accounts::MySQLUserSlackAccountsRepository;
 use crate::infrastructure::repositories::mysql::user_social_accounts::MySQLUserSocialServicesRepository;
 use crate::infrastructure::repositories::mysql::user_vc_accounts::MySQLUserVersionControlServicesRepository;
+use crate::infrastructure::repositories::mysql::webhook_delivery::MySQLWebhookDeliveryRepository;
 use crate::infrastructure::services::notification::CompositionNotificationService;
+use crate::infrastructure::services::notification::slack::{
+    SlackClientBase, SlackClientToken, SlackNotificationClient,
+};
+use crate::infrastructure::services::task_tracker::CompositionTaskTrackerClientProvider;
+use crate::infrastructure::services::task_tracker::factory::HttpTaskTrackerClientFactory;
 use crate::infrastructure::services::task_tracker::kaiten::KaitenTaskTrackerService;
+use crate::infrastructure::services::task_tracker::resolver::CompositionTaskTrackerClientResolver;
+use crate::infrastructure::services::version_control::CompositionVersionControlClientProvider;
+use crate::infrastructure::services::version_control::token_provider::CompositionVersionControlTokenProvider;
 use crate::utils::security::crypto::reversible::ReversibleCipher;
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
@@ -72,17 +109,33 @@ pub struct ApplicationSharedDependency {
     pub health_check_client: Arc<dyn HealthCheckClient>,
     pub repository_repo: Arc<dyn RepositoryRepository>,
     pub repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    pub repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
     pub notification_service: Arc<CompositionNotificationService>,
     pub oauth_client: Arc<dyn OAuthClient>,
-    pub task_tracker_client: Arc<dyn TaskTrackerClient>,
+    pub gitlab_oauth_client: Arc<dyn OAuthClient>,
+    pub task_move_repo: Arc<dyn TaskMoveRepository>,
+    pub pull_request_task_link_repo: Arc<dyn PullRequestTaskLinkRepository>,
+    pub task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>,
+    pub task_tracker_client_factory: Arc<dyn TaskTrackerClientFactory>,
+    pub task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
     pub task_tracker_service: Arc<dyn TaskTrackerService>,
     pub version_control_client: Arc<dyn VersionControlClient>,
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub github_app: Option<Arc<GithubAppClient>>,
+    pub version_control_tokens: Arc<dyn VersionControlTokenProvider>,
     pub user_preferences_repo: Arc<dyn UserPreferencesRepository>,
     pub quiet_hours_resolver: Arc<QuietHoursResolver>,
     pub pending_notifications_repo: Arc<dyn PendingNotificationsRepository>,
     pub pr_review_repo: Arc<dyn PrReviewRepository>,
     pub notification_log_repo: Arc<dyn NotificationLogRepository>,
     pub release_plan_repo: Arc<dyn ReleasePlanRepository>,
+    pub webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    pub commit_check_repo: Arc<dyn CommitCheckRepository>,
+    pub environment_deployment_repo: Arc<dyn EnvironmentDeploymentRepository>,
+    pub merged_branch_repo: Arc<dyn MergedBranchRepository>,
+    pub slack_message_repo: Arc<dyn SlackMessageRepository>,
+    pub slack_notification_service: Option<Arc<dyn SlackNotificationService>>,
+    pub user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository>,
 }
 
 impl ApplicationSharedDependency {
@@ -140,8 +193,41 @@ impl ApplicationSharedDependency {
             MySQLRepositoryTaskTrackerRepository::new(mysql_pool.clone()),
         );
 
+        let repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository> =
+            Arc::new(MySQLRepositoryTaskTrackerRuleRepository::new(
+                mysql_pool.clone(),
+            ));
+
+        let task_move_repo: Arc<dyn TaskMoveRepository> =
+            Arc::new(MySQLTaskMoveRepository::new(mysql_pool.clone()));
+        let pull_request_task_link_repo: Arc<dyn PullRequestTaskLinkRepository> =
+            Arc::new(MySQLPullRequestTaskLinkRepository::new(mysql_pool.clone()));
+
+        let user_preferences_repo: Arc<dyn UserPreferencesRepository> =
+            Arc::new(MySQLUserPreferencesRepository::new(mysql_pool.clone()));
+
+        let slack_message_repo: Arc<dyn SlackMessageRepository> =
+            Arc::new(MySQLSlackMessageRepository::new(mysql_pool.clone()));
+        let user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository> =
+            Arc::new(MySQLUserSlackAccountsRepository::new(mysql_pool.clone()));
+
+        let slack_notification_service: Option<Arc<dyn SlackNotificationService>> =
+            if config.slack.bot_token.is_empty() {
+                None
+            } else {
+                Some(Arc::new(SlackNotificationClient::new(
+                    SlackClientBase(config.slack.api_base.clone()),
+                    SlackClientToken(config.slack.bot_token.clone()),
+                )))
+            };
+
         let notification_service = Arc::new(CompositionNotificationService::new(
             config.telegram.bot_token.clone(),
+            slack_notification_service.clone(),
+            repository_repo.clone(),
+            user_socials_repo.clone(),
+            user_slack_accounts_repo.clone(),
+            slack_message_repo.clone(),
         ));
 
         let oauth_client: Arc<dyn OAuthClient> = Arc::new(GithubOAuthClient::new(
@@ -150,11 +236,88 @@ impl ApplicationSharedDependency {
             config.github.oauth_client_secret.clone(),
         ));
 
-        let task_tracker_client: Arc<dyn TaskTrackerClient> = Arc::new(KaitenClient::new(
-            KaitenClientBase(config.kaiten.base.clone()),
-            KaitenClientToken(config.kaiten.api_token.clone()),
+        let gitlab_oauth_client: Arc<dyn OAuthClient> = Arc::new(GitlabOAuthClient::new(
+            config.gitlab.base.clone(),
+            config.gitlab.oauth_client_id.clone(),
+            config.gitlab.oauth_client_secret.clone(),
+            format!("{}/oauth/gitlab", config.base_url),
         ));
 
+        let task_tracker_client_factory: Arc<dyn TaskTrackerClientFactory> =
+            Arc::new(HttpTaskTrackerClientFactory);
+
+        let kaiten_client = task_tracker_client_factory.build(&TaskTrackerCredentials {
+            kind: TaskTrackerKind::Kaiten,
+            base_url: config.kaiten.base.clone(),
+            email: None,
+            api_token: config.kaiten.api_token.clone(),
+        });
+
+        let jira_client = if config.jira.base.is_empty() {
+            None
+        } else {
+            Some(task_tracker_client_factory.build(&TaskTrackerCredentials {
+                kind: TaskTrackerKind::Jira,
+                base_url: config.jira.base.clone(),
+                email: Some(config.jira.email.clone()),
+                api_token: config.jira.api_token.clone(),
+            }))
+        };
+
+        let linear_client = if config.linear.api_token.is_empty() {
+            None
+        } else {
+            Some(task_tracker_client_factory.build(&TaskTrackerCredentials {
+                kind: TaskTrackerKind::Linear,
+                base_url: config.linear.base.clone(),
+                email: None,
+                api_token: config.linear.api_token.clone(),
+            }))
+        };
+
+        let youtrack_client = if config.youtrack.base.is_empty() {
+            None
+        } else {
+            Some(task_tracker_client_factory.build(&TaskTrackerCredentials {
+                kind: TaskTrackerKind::YouTrack,
+                base_url: config.youtrack.base.clone(),
+                email: None,
+                api_token: config.youtrack.api_token.clone(),
+            }))
+        };
+
+        let github_projects_client = if config.github_projects.base.is_empty() {
+            None
+        } else {
+            Some(task_tracker_client_factory.build(&TaskTrackerCredentials {
+                kind: TaskTrackerKind::GithubProjects,
+                base_url: config.github_projects.base.clone(),
+                email: None,
+                api_token: config.github_projects.api_token.clone(),
+            }))
+        };
+
+        let task_tracker_clients: Arc<dyn TaskTrackerClientProvider> =
+            Arc::new(CompositionTaskTrackerClientProvider::new(
+                kaiten_client,
+                jira_client,
+                linear_client,
+                youtrack_client,
+                github_projects_client,
+            ));
+
+        let task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository> = Arc::new(
+            MySQLTaskTrackerConnectionRepository::new(mysql_pool.clone()),
+        );
+
+        let task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver> =
+            Arc::new(CompositionTaskTrackerClientResolver::new(
+                task_tracker_clients,
+                task_tracker_connection_repo.clone(),
+                task_tracker_client_factory.clone(),
+                reversible_cipher.clone(),
+            ));
+
         let task_tracker_service: Arc<dyn TaskTrackerService> = Arc::new(
             KaitenTaskTrackerService::new(config.task_tracker.extract_pattern.clone()),
         );
@@ -163,8 +326,45 @@ impl ApplicationSharedDependency {
             GithubVersionControlClient::new(config.github.api_base.clone()),
         );
 
-        let user_preferences_repo: Arc<dyn UserPreferencesRepository> =
-            Arc::new(MySQLUserPreferencesRepository::new(mysql_pool.clone()));
+        let version_control_clients: Arc<dyn VersionControlClientProvider> =
+            Arc::new(CompositionVersionControlClientProvider::new(
+                version_control_client.clone(),
+                Arc::new(GiteaVersionControlClient::new(
+                    config.gitea.api_base.clone(),
+                )),
+                config.gitea.api_token.clone(),
+                Arc::new(GitlabVersionControlClient::new(
+                    config.gitlab.api_base.clone(),
+                )),
+                config.gitlab.api_token.clone(),
+            ));
+
+        let github_app = if config.github.app_id.is_empty() {
+            None
+        } else {
+            match GithubAppClient::new(
+                config.github.api_base.clone(),
+                config.github.app_id.clone(),
+                &config.github.app_private_key,
+                cache.clone(),
+                reversible_cipher.clone(),
+            ) {
+                Ok(app) => Some(Arc::new(app)),
+                Err(e) => {
+                    tracing::error!(error = %e, "GitHub App is misconfigured; falling back to admin OAuth token");
+                    None
+                }
+            }
+        };
+
+        let version_control_tokens: Arc<dyn VersionControlTokenProvider> =
+            Arc::new(CompositionVersionControlTokenProvider::new(
+                version_control_clients.clone(),
+                github_app.clone(),
+                user_has_roles_repo.clone(),
+                user_version_controls_repo.clone(),
+                reversible_cipher.clone(),
+            ));
 
         let default_quiet_hours_window = QuietHoursWindow::new(
             config.notifications.default_dnd_start,
@@ -187,6 +387,19 @@ impl ApplicationSharedDependency {
         let release_plan_repo: Arc<dyn ReleasePlanRepository> =
             Arc::new(MySQLReleasePlanRepository::new(mysql_pool.clone()));
 
+        let webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository> =
+            Arc::new(MySQLWebhookDeliveryRepository::new(mysql_pool.clone()));
+
+        let commit_check_repo: Arc<dyn CommitCheckRepository> =
+            Arc::new(MySQLCommitCheckRepository::new(mysql_pool.clone()));
+
+        let environment_deployment_repo: Arc<dyn EnvironmentDeploymentRepository> = Arc::new(
+            MySQLEnvironmentDeploymentRepository::new(mysql_pool.clone()),
+        );
+
+        let merged_branch_repo: Arc<dyn MergedBranchRepository> =
+            Arc::new(MySQLMergedBranchRepository::new(mysql_pool.clone()));
+
         Ok(Self {
             event_bus,
             message_broker,
@@ -204,17 +417,33 @@ impl ApplicationSharedDependency {
             health_check_client,
             repository_repo,
             repository_task_tracker_repo,
+            repository_task_tracker_rule_repo,
             notification_service,
             oauth_client,
-            task_tracker_client,
+            gitlab_oauth_client,
+            task_move_repo,
+            pull_request_task_link_repo,
+            task_tracker_connection_repo,
+            task_tracker_client_factory,
+            task_tracker_client_resolver,
             task_tracker_service,
             version_control_client,
+            version_control_clients,
+            github_app,
+            version_control_tokens,
             user_preferences_repo,
             quiet_hours_resolver,
             pending_notifications_repo,
             pr_review_repo,
             notification_log_repo,
             release_plan_repo,
+            webhook_delivery_repo,
+            commit_check_repo,
+            environment_deployment_repo,
+            merged_branch_repo,
+            slack_message_repo,
+            slack_notification_service,
+            user_slack_accounts_repo,
         })
     }
 }
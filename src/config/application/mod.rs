@@ -8,6 +8,8 @@ pub struct ApplicationNotificationsConfig {
     pub default_timezone: Tz,
     pub re_review_nudge_dedup_hours: i64,
     pub stale_threshold_hours: i64,
+    /// Через сколько дней после мержа напомнить автору удалить ветку. 0 — выключено.
+    pub branch_cleanup_reminder_days: i64,
 }
 
 pub struct ApplicationReleasePlanConfig {
@@ -22,6 +24,42 @@ pub struct ApplicationTaskTrackerConfig {
 pub struct ApplicationKaitenConfig {
     pub base: String,
     pub api_token: String,
+    /// Секрет входящих вебхуков Kaiten, передаётся в `?token=`
+    pub webhook_secret: String,
+}
+
+/// Jira Cloud: `email` + API-токен. Jira Server/DC: пустой `email`,
+/// в `api_token` — Personal Access Token. Пустой `base` — Jira выключена.
+pub struct ApplicationJiraConfig {
+    pub base: String,
+    pub email: String,
+    pub api_token: String,
+}
+
+/// `base` — адрес рабочего пространства (`https://linear.app/acme`) для ссылок
+/// в отчётах. Пустой `api_token` — Linear выключен.
+pub struct ApplicationLinearConfig {
+    pub base: String,
+    pub api_token: String,
+}
+
+/// Пустой `base` — YouTrack выключен
+pub struct ApplicationYouTrackConfig {
+    pub base: String,
+    pub api_token: String,
+}
+
+/// Пустой `base` — GitHub Projects как трекер выключен. `base` — адрес
+/// репозитория, задачи которого пишут в PR как `#123`
+pub struct ApplicationGithubProjectsConfig {
+    pub base: String,
+    pub api_token: String,
+}
+
+/// Пустой `bot_token` — Slack выключен. `api_base` подменяется в тестах
+pub struct ApplicationSlackConfig {
+    pub api_base: String,
+    pub bot_token: String,
 }
 
 pub struct ApplicationSecretConfig {
@@ -32,11 +70,39 @@ pub struct ApplicationGithubConfig {
     pub base: String,
     pub api_base: String,
     pub webhook_secret: String,
+    pub webhook_secret_rotation_grace_hours: i64,
     pub oauth_pathname: String,
     pub oauth_client_scope: String,
     pub oauth_client_id: String,
     pub oauth_client_secret: String,
     pub repository_owner: String,
+    /// GitHub App для фоновых задач. Пустой `app_id` — режим App выключен.
+    pub app_id: String,
+    pub app_private_key: String,
+}
+
+/// `base` — адрес веб-интерфейса (для OAuth), `api_base` — `.../api/v4`.
+/// Пустой `oauth_client_id` — привязка аккаунтов GitLab выключена.
+pub struct ApplicationGitlabConfig {
+    pub base: String,
+    pub api_base: String,
+    pub api_token: String,
+    pub webhook_secret: String,
+    pub oauth_client_id: String,
+    pub oauth_client_secret: String,
+    pub oauth_client_scope: String,
+}
+
+pub struct ApplicationGiteaConfig {
+    pub api_base: String,
+    pub api_token: String,
+    pub webhook_secret: String,
+}
+
+pub struct ApplicationWebhookConfig {
+    pub delivery_retention_days: i64,
+    pub recovery_lookback_hours: i64,
+    pub admin_api_token: String,
 }
 
 pub struct ApplicationRedisConfig {
@@ -76,8 +142,16 @@ pub struct ApplicationConfig {
     pub rabbit_mq: ApplicationRabbitMqConfig,
     pub redis: ApplicationRedisConfig,
     pub github: ApplicationGithubConfig,
+    pub gitlab: ApplicationGitlabConfig,
+    pub gitea: ApplicationGiteaConfig,
+    pub webhook: ApplicationWebhookConfig,
     pub secret: ApplicationSecretConfig,
     pub kaiten: ApplicationKaitenConfig,
+    pub jira: ApplicationJiraConfig,
+    pub linear: ApplicationLinearConfig,
+    pub youtrack: ApplicationYouTrackConfig,
+    pub github_projects: ApplicationGithubProjectsConfig,
+    pub slack: ApplicationSlackConfig,
     pub task_tracker: ApplicationTaskTrackerConfig,
     pub notifications: ApplicationNotificationsConfig,
     pub release_plan: ApplicationReleasePlanConfig,
@@ -93,8 +167,16 @@ impl ApplicationConfig {
         let rabbit_mq = Self::build_rabbit_mq_config();
         let redis = Self::build_redis_config();
         let github = Self::build_github_config();
+        let gitlab = Self::build_gitlab_config();
+        let gitea = Self::build_gitea_config();
+        let webhook = Self::build_webhook_config();
         let secret = Self::build_secret_config();
         let kaiten = Self::build_kaiten_config();
+        let jira = Self::build_jira_config();
+        let linear = Self::build_linear_config();
+        let youtrack = Self::build_youtrack_config();
+        let github_projects = Self::build_github_projects_config();
+        let slack = Self::build_slack_config();
         let task_tracker = Self::build_task_tracker_config();
         let notifications = Self::build_notifications_config();
         let release_plan = Self::build_release_plan_config();
@@ -108,8 +190,16 @@ impl ApplicationConfig {
             rabbit_mq,
             redis,
             github,
+            gitlab,
+            gitea,
+            webhook,
             secret,
             kaiten,
+            jira,
+            linear,
+            youtrack,
+            github_projects,
+            slack,
             task_tracker,
             notifications,
             release_plan,
@@ -180,6 +270,11 @@ impl ApplicationConfig {
         let repository_owner = ENV.get_or("GITHUB_REPOSITORY_OWNER", "");
 
         let webhook_secret = ENV.get_or("GITHUB_WEBHOOK_SECRET", "");
+        // Сколько после ротации принимается и предыдущий секрет репозитория
+        let webhook_secret_rotation_grace_hours: i64 = ENV
+            .get_or("GITHUB_WEBHOOK_SECRET_ROTATION_GRACE_HOURS", "24")
+            .parse()
+            .unwrap();
 
         if webhook_secret.is_empty() {
             tracing::warn!(
@@ -195,6 +290,19 @@ impl ApplicationConfig {
             )
         }
 
+        let app_id = ENV.get_or("GITHUB_APP_ID", "");
+        // PEM в одну строку: переносы можно передать как \n
+        let app_private_key = ENV
+            .get_or("GITHUB_APP_PRIVATE_KEY", "")
+            .replace("\\n", "\n");
+
+        if app_id.is_empty() {
+            tracing::warn!(
+                "GITHUB_APP_ID is not set. \
+                 Background jobs will use an admin's OAuth token for GitHub."
+            )
+        }
+
         ApplicationGithubConfig {
             base,
             api_base,
@@ -203,7 +311,76 @@ impl ApplicationConfig {
             oauth_client_id,
             oauth_client_secret,
             webhook_secret,
+            webhook_secret_rotation_grace_hours,
             repository_owner,
+            app_id,
+            app_private_key,
+        }
+    }
+
+    pub fn build_gitlab_config() -> ApplicationGitlabConfig {
+        let base = ENV.get_or("GITLAB_BASE", "");
+        let api_base = ENV.get_or("GITLAB_API_BASE", "");
+        let api_token = ENV.get_or("GITLAB_API_TOKEN", "");
+        let webhook_secret = ENV.get_or("GITLAB_WEBHOOK_SECRET", "");
+        let oauth_client_id = ENV.get_or("GITLAB_OAUTH_CLIENT_ID", "");
+        let oauth_client_secret = ENV.get_or("GITLAB_OAUTH_CLIENT_SECRET", "");
+        let oauth_client_scope = ENV.get_or("GITLAB_OAUTH_CLIENT_SCOPE", "read_user");
+
+        if webhook_secret.is_empty() {
+            tracing::warn!(
+                "GITLAB_WEBHOOK_SECRET is not set or empty. \
+                 GitLab webhooks will be rejected."
+            )
+        }
+
+        ApplicationGitlabConfig {
+            base,
+            api_base,
+            api_token,
+            webhook_secret,
+            oauth_client_id,
+            oauth_client_secret,
+            oauth_client_scope,
+        }
+    }
+
+    pub fn build_gitea_config() -> ApplicationGiteaConfig {
+        let api_base = ENV.get_or("GITEA_API_BASE", "");
+        let api_token = ENV.get_or("GITEA_API_TOKEN", "");
+        let webhook_secret = ENV.get_or("GITEA_WEBHOOK_SECRET", "");
+
+        if webhook_secret.is_empty() {
+            tracing::warn!(
+                "GITEA_WEBHOOK_SECRET is not set or empty. \
+                 Gitea webhooks will be rejected."
+            )
+        }
+
+        ApplicationGiteaConfig {
+            api_base,
+            api_token,
+            webhook_secret,
+        }
+    }
+
+    pub fn build_webhook_config() -> ApplicationWebhookConfig {
+        let delivery_retention_days: i64 = ENV
+            .get_or("WEBHOOK_DELIVERY_RETENTION_DAYS", "14")
+            .parse()
+            .unwrap();
+        // Насколько глубоко смотреть историю доставок GitHub; 0 — не восстанавливать
+        let recovery_lookback_hours: i64 = ENV
+            .get_or("WEBHOOK_RECOVERY_LOOKBACK_HOURS", "24")
+            .parse()
+            .unwrap();
+        // Пустой токен — админский HTTP API журнала доставок выключен
+        let admin_api_token = ENV.get_or("WEBHOOK_ADMIN_API_TOKEN", "");
+
+        ApplicationWebhookConfig {
+            delivery_retention_days,
+            recovery_lookback_hours,
+            admin_api_token,
         }
     }
 
@@ -218,8 +395,63 @@ impl ApplicationConfig {
     pub fn build_kaiten_config() -> ApplicationKaitenConfig {
         let base = ENV.get("KAITEN_BASE");
         let api_token = ENV.get("KAITEN_API_TOKEN");
+        let webhook_secret = ENV.get_or("KAITEN_WEBHOOK_SECRET", "");
+
+        if webhook_secret.is_empty() {
+            tracing::warn!(
+                "KAITEN_WEBHOOK_SECRET is not set or empty. \
+                 Kaiten webhooks will be rejected."
+            )
+        }
+
+        ApplicationKaitenConfig {
+            base,
+            api_token,
+            webhook_secret,
+        }
+    }
 
-        ApplicationKaitenConfig { base, api_token }
+    pub fn build_jira_config() -> ApplicationJiraConfig {
+        let base = ENV.get_or("JIRA_BASE", "");
+        let email = ENV.get_or("JIRA_EMAIL", "");
+        let api_token = ENV.get_or("JIRA_API_TOKEN", "");
+
+        ApplicationJiraConfig {
+            base,
+            email,
+            api_token,
+        }
+    }
+
+    pub fn build_linear_config() -> ApplicationLinearConfig {
+        let base = ENV.get_or("LINEAR_BASE", "https://linear.app");
+        let api_token = ENV.get_or("LINEAR_API_TOKEN", "");
+
+        ApplicationLinearConfig { base, api_token }
+    }
+
+    pub fn build_youtrack_config() -> ApplicationYouTrackConfig {
+        let base = ENV.get_or("YOUTRACK_BASE", "");
+        let api_token = ENV.get_or("YOUTRACK_API_TOKEN", "");
+
+        ApplicationYouTrackConfig { base, api_token }
+    }
+
+    pub fn build_github_projects_config() -> ApplicationGithubProjectsConfig {
+        let base = ENV.get_or("GITHUB_PROJECTS_BASE", "");
+        let api_token = ENV.get_or("GITHUB_PROJECTS_TOKEN", "");
+
+        ApplicationGithubProjectsConfig { base, api_token }
+    }
+
+    pub fn build_slack_config() -> ApplicationSlackConfig {
+        let api_base = ENV.get_or("SLACK_API_BASE", "https://slack.com/api");
+        let bot_token = ENV.get_or("SLACK_BOT_TOKEN", "");
+
+        ApplicationSlackConfig {
+            api_base,
+            bot_token,
+        }
     }
 
     pub fn build_task_tracker_config() -> ApplicationTaskTrackerConfig {
@@ -251,6 +483,10 @@ impl ApplicationConfig {
             .get_or("REVIEW_STALE_THRESHOLD_HOURS", "24")
             .parse()
             .unwrap();
+        let branch_cleanup_reminder_days: i64 = ENV
+            .get_or("BRANCH_CLEANUP_REMINDER_DAYS", "0")
+            .parse()
+            .unwrap();
 
         ApplicationNotificationsConfig {
             default_dnd_start,
@@ -258,6 +494,7 @@ impl ApplicationConfig {
             default_timezone,
             re_review_nudge_dedup_hours,
             stale_threshold_hours,
+            branch_cleanup_reminder_days,
         }
     }
 
@@ -68,9 +68,11 @@ impl TelegramBotAdminCommandHandler {
         let keyboard = KeyboardBuilder::new()
             .row::<TelegramBotAdminAction>(vec![TelegramBotAdminAction::ConfigureRepository])
             .row::<TelegramBotAdminAction>(vec![TelegramBotAdminAction::ConfigureTaskTracker])
+            .row::<TelegramBotAdminAction>(vec![TelegramBotAdminAction::TaskTrackerConnections])
             .row::<TelegramBotAdminAction>(vec![TelegramBotAdminAction::QueuesStats])
             .row::<TelegramBotAdminAction>(vec![TelegramBotAdminAction::HealthPings])
             .row::<TelegramBotAdminAction>(vec![TelegramBotAdminAction::ManageUsers])
+            .row::<TelegramBotAdminAction>(vec![TelegramBotAdminAction::WebhookDeliveries])
             .build();
 
         self.dialogue
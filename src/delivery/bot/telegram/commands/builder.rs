@@ -4,8 +4,11 @@ use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::config::application::ApplicationConfig;
 use crate::delivery::bot::telegram::commands::admin::TelegramBotAdminCommandHandler;
 use crate::delivery::bot::telegram::commands::bind_repository::TelegramBotBindRepositoryCommandHandler;
+use crate::delivery::bot::telegram::commands::deployments::TelegramBotDeploymentsCommandHandler;
 use crate::delivery::bot::telegram::commands::digest::TelegramBotDigestCommandHandler;
+use crate::delivery::bot::telegram::commands::gitlab::TelegramBotGitlabCommandHandler;
 use crate::delivery::bot::telegram::commands::my_prs::TelegramBotMyPrsCommandHandler;
+use crate::delivery::bot::telegram::commands::new_task::TelegramBotNewTaskCommandHandler;
 use crate::delivery::bot::telegram::commands::notifications::TelegramBotNotificationsCommandHandler;
 use crate::delivery::bot::telegram::commands::pending_reviews::TelegramBotPendingReviewsCommandHandler;
 use crate::delivery::bot::telegram::commands::register::TelegramBotRegisterCommandHandler;
@@ -16,9 +19,11 @@ use crate::delivery::bot::telegram::commands::report::TelegramBotVersionControlR
 use crate::delivery::bot::telegram::commands::setup::TelegramBotSetupCommandHandler;
 use crate::delivery::bot::telegram::commands::setup_notifications::TelegramBotSetupNotificationsCommandHandler;
 use crate::delivery::bot::telegram::commands::setup_webhook::TelegramBotSetupWebhookCommandHandler;
+use crate::delivery::bot::telegram::commands::slack::TelegramBotSlackCommandHandler;
 use crate::delivery::bot::telegram::commands::start::TelegramBotStartCommandHandler;
 use crate::delivery::bot::telegram::commands::status::TelegramBotStatusCommandHandler;
 use crate::delivery::bot::telegram::commands::task::TelegramBotTaskCommandHandler;
+use crate::delivery::bot::telegram::commands::test_queue::TelegramBotTestQueueCommandHandler;
 use crate::delivery::bot::telegram::commands::unregister::TelegramBotUnregisterCommandHandler;
 use crate::delivery::bot::telegram::commands::vacation::TelegramBotVacationCommandHandler;
 use crate::delivery::bot::telegram::context::TelegramBotCommandContext;
@@ -47,7 +52,7 @@ pub enum TelegramBotCommand {
     BindRepository,
     #[command(description = "Панель администратора")]
     Admin,
-    #[command(description = "Получить карточку по ID: /task 12345")]
+    #[command(description = "Получить карточку по ID или ключу: /task 12345, /task PROJ-123")]
     Task(String),
     #[command(
         rename = "setup_webhook",
@@ -73,6 +78,12 @@ pub enum TelegramBotCommand {
     #[command(description = "Уйти в отпуск: /vacation 5d или /vacation off")]
     Vacation(String),
 
+    #[command(description = "Уведомления в Slack: /slack U0123ABCD, /slack confirm КОД или /slack off")]
+    Slack(String),
+
+    #[command(description = "Привязать аккаунт GitLab")]
+    Gitlab,
+
     #[command(description = "Завершить настройку: репо + тихие часы")]
     Setup,
 
@@ -93,6 +104,15 @@ pub enum TelegramBotCommand {
 
     #[command(rename = "pending_reviews", description = "PR, ожидающие моего ревью")]
     PendingReviews,
+
+    #[command(description = "Что и куда задеплоено")]
+    Deployments,
+
+    #[command(rename = "test_queue", description = "Очередь тестирования (QA)")]
+    TestQueue,
+
+    #[command(rename = "new_task", description = "Создать карточку в трекере")]
+    NewTask,
 }
 
 pub async fn handle(
@@ -323,6 +343,27 @@ pub async fn handle(
             .await?;
         }
 
+        TelegramBotCommand::Slack(raw_arg) => {
+            let social_user_id = crate::domain::user::value_objects::social_user_id::SocialUserId(
+                context.user.id.0 as i32,
+            );
+            TelegramBotSlackCommandHandler::new(
+                context.bot,
+                context.msg,
+                executors.clone(),
+                raw_arg,
+                social_user_id,
+            )
+            .execute()
+            .await?;
+        }
+
+        TelegramBotCommand::Gitlab => {
+            TelegramBotGitlabCommandHandler::new(context, executors.clone())
+                .execute()
+                .await?;
+        }
+
         TelegramBotCommand::Setup => {
             let social_user_id = crate::domain::user::value_objects::social_user_id::SocialUserId(
                 context.user.id.0 as i32,
@@ -374,6 +415,24 @@ pub async fn handle(
                 .await?;
         }
 
+        TelegramBotCommand::Deployments => {
+            TelegramBotDeploymentsCommandHandler::new(context, executors.clone())
+                .execute()
+                .await?;
+        }
+
+        TelegramBotCommand::TestQueue => {
+            TelegramBotTestQueueCommandHandler::new(context, executors.clone(), Arc::new(dialogue))
+                .execute()
+                .await?;
+        }
+
+        TelegramBotCommand::NewTask => {
+            TelegramBotNewTaskCommandHandler::new(context, executors.clone(), Arc::new(dialogue))
+                .execute()
+                .await?;
+        }
+
         // Handled above before private-chat guard
         TelegramBotCommand::SetupWebhook
         | TelegramBotCommand::SetupNotifications
@@ -0,0 +1,172 @@
+use crate::application::deployment::queries::get_deployments_overview::error::GetDeploymentsOverviewError;
+use crate::application::deployment::queries::get_deployments_overview::query::GetDeploymentsOverviewQuery;
+use crate::application::deployment::queries::get_deployments_overview::response::RepositoryDeploymentsOverview;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::context::TelegramBotCommandContext;
+use crate::domain::deployment::value_objects::environment_kind::EnvironmentKind;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use crate::utils::builder::message::MessageBuilder;
+use chrono_tz::Europe::Moscow;
+use std::sync::Arc;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::Requester;
+use teloxide::types::ParseMode;
+
+/// Сколько неразвёрнутых PR показываем на репозиторий
+const MAX_UNDEPLOYED_PRS: usize = 10;
+
+pub struct TelegramBotDeploymentsCommandHandler {
+    context: TelegramBotCommandContext,
+    executors: Arc<ApplicationBoostrapExecutors>,
+}
+
+impl TelegramBotDeploymentsCommandHandler {
+    pub fn new(
+        context: TelegramBotCommandContext,
+        executors: Arc<ApplicationBoostrapExecutors>,
+    ) -> Self {
+        Self { context, executors }
+    }
+
+    pub async fn execute(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let social_user_id = SocialUserId(self.context.user.id.0 as i32);
+
+        let repositories = match self
+            .executors
+            .queries
+            .get_deployments_overview
+            .execute(&GetDeploymentsOverviewQuery { social_user_id })
+            .await
+        {
+            Ok(r) => r.repositories,
+            Err(GetDeploymentsOverviewError::UserNotFound) => {
+                self.context
+                    .bot
+                    .send_message(
+                        self.context.msg.chat.id,
+                        t!("telegram_bot.commands.deployments.not_registered").to_string(),
+                    )
+                    .await?;
+                return Ok(());
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load deployments overview");
+                self.context
+                    .bot
+                    .send_message(
+                        self.context.msg.chat.id,
+                        t!("telegram_bot.commands.deployments.error").to_string(),
+                    )
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let text = if repositories.is_empty() {
+            t!("telegram_bot.commands.deployments.no_repositories").to_string()
+        } else {
+            let mut builder = MessageBuilder::new()
+                .bold(t!("telegram_bot.commands.deployments.title").as_ref())
+                .empty_line();
+
+            for overview in &repositories {
+                builder = render_repository(builder, overview);
+            }
+
+            builder.build()
+        };
+
+        self.context
+            .bot
+            .send_message(self.context.msg.chat.id, text)
+            .parse_mode(ParseMode::Html)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn render_repository(
+    mut builder: MessageBuilder,
+    overview: &RepositoryDeploymentsOverview,
+) -> MessageBuilder {
+    builder = builder.with_html_escape(false).raw(&format!(
+        "📦 <b>{}</b>\n",
+        MessageBuilder::escape_html(&overview.repo)
+    ));
+
+    if overview.deployments.is_empty() {
+        return builder
+            .line(t!("telegram_bot.commands.deployments.no_deployments").as_ref())
+            .empty_line();
+    }
+
+    for deployment in &overview.deployments {
+        let icon = match deployment.kind() {
+            EnvironmentKind::Production => "🟢",
+            EnvironmentKind::Staging => "🟡",
+            EnvironmentKind::Other => "⚪",
+        };
+        let short_sha = &deployment.sha[..7.min(deployment.sha.len())];
+        let deployed_at = deployment
+            .deployed_at
+            .with_timezone(&Moscow)
+            .format("%d.%m %H:%M");
+        let by = deployment
+            .deployed_by
+            .as_deref()
+            .map(|login| format!(" — @{}", MessageBuilder::escape_html(login)))
+            .unwrap_or_default();
+
+        builder = builder.raw(&format!(
+            "{} {}: <code>{}</code> ({}){}\n",
+            icon,
+            MessageBuilder::escape_html(&deployment.environment),
+            short_sha,
+            deployed_at,
+            by,
+        ));
+    }
+
+    match &overview.undeployed {
+        Some(prs) if prs.is_empty() => {
+            builder = builder.line(t!("telegram_bot.commands.deployments.up_to_date").as_ref());
+        }
+        Some(prs) => {
+            builder = builder.raw(&format!(
+                "{}\n",
+                t!(
+                    "telegram_bot.commands.deployments.undeployed",
+                    count = prs.len()
+                )
+            ));
+            for pr in prs.iter().take(MAX_UNDEPLOYED_PRS) {
+                let author = pr
+                    .author_login
+                    .as_deref()
+                    .map(|login| format!(" — @{}", MessageBuilder::escape_html(login)))
+                    .unwrap_or_default();
+                builder = builder.raw(&format!(
+                    "• <a href=\"{}\">#{}</a> {}{}\n",
+                    MessageBuilder::escape_html(&pr.url),
+                    pr.number,
+                    MessageBuilder::escape_html(&pr.title),
+                    author,
+                ));
+            }
+            if prs.len() > MAX_UNDEPLOYED_PRS {
+                builder = builder.line(
+                    t!(
+                        "telegram_bot.commands.deployments.more",
+                        count = prs.len() - MAX_UNDEPLOYED_PRS
+                    )
+                    .as_ref(),
+                );
+            }
+        }
+        None => {}
+    }
+
+    builder.empty_line()
+}
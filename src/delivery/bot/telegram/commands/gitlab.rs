@@ -0,0 +1,86 @@
+use crate::application::user::commands::request_version_control_account_link::command::{
+    RequestVersionControlAccountLinkCommand, RequestVersionControlAccountLinkCommandVersionControl,
+};
+use crate::application::user::commands::request_version_control_account_link::error::RequestVersionControlAccountLinkExecutorError;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::context::TelegramBotCommandContext;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::repositories::user_social_accounts_repository::FindSocialServiceByIdError;
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::utils::builder::message::MessageBuilder;
+use std::sync::Arc;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::Requester;
+use teloxide::types::ParseMode;
+
+/// Ссылка на авторизацию в GitLab: аккаунт привязывается к уже
+/// зарегистрированному пользователю, результат придёт сообщением
+pub struct TelegramBotGitlabCommandHandler {
+    context: TelegramBotCommandContext,
+    executors: Arc<ApplicationBoostrapExecutors>,
+}
+
+impl TelegramBotGitlabCommandHandler {
+    pub fn new(
+        context: TelegramBotCommandContext,
+        executors: Arc<ApplicationBoostrapExecutors>,
+    ) -> Self {
+        Self { context, executors }
+    }
+
+    pub async fn execute(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let gitlab = &self.context.config.gitlab;
+
+        let reply = if gitlab.oauth_client_id.is_empty() {
+            t!("telegram_bot.commands.gitlab.unavailable").to_string()
+        } else {
+            let cmd = RequestVersionControlAccountLinkCommand {
+                social_user_id: SocialUserId(self.context.user.id.0 as i32),
+                social_chat_id: SocialChatId(self.context.msg.chat.id.0),
+                version_control: RequestVersionControlAccountLinkCommandVersionControl {
+                    r#type: VersionControlType::Gitlab,
+                    authorize_url: format!("{}/oauth/authorize", gitlab.base),
+                    redirect_uri: format!("{}/oauth/gitlab", self.context.config.base_url),
+                    client_id: gitlab.oauth_client_id.clone(),
+                    scope: gitlab.oauth_client_scope.clone(),
+                },
+            };
+
+            match self
+                .executors
+                .commands
+                .request_version_control_account_link
+                .execute(&cmd)
+                .await
+            {
+                Ok(response) => MessageBuilder::new()
+                    .line(t!("telegram_bot.commands.gitlab.title").as_ref())
+                    .empty_line()
+                    .link(
+                        t!("telegram_bot.commands.gitlab.body").as_ref(),
+                        response.url.as_str(),
+                    )
+                    .empty_line()
+                    .line(t!("telegram_bot.commands.register.expiration_time").as_ref())
+                    .build(),
+                Err(RequestVersionControlAccountLinkExecutorError::UserNotFound(
+                    FindSocialServiceByIdError::NotFound,
+                )) => t!("telegram_bot.commands.gitlab.not_registered").to_string(),
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to create GitLab account link");
+                    t!("telegram_bot.commands.gitlab.error").to_string()
+                }
+            }
+        };
+
+        self.context
+            .bot
+            .send_message(self.context.msg.chat.id, reply)
+            .parse_mode(ParseMode::Html)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,8 +1,11 @@
 pub mod admin;
 pub mod bind_repository;
 pub mod builder;
+pub mod deployments;
 pub mod digest;
+pub mod gitlab;
 pub mod my_prs;
+pub mod new_task;
 pub mod notifications;
 pub mod pending_reviews;
 pub mod register;
@@ -13,8 +16,10 @@ pub mod report;
 pub mod setup;
 pub mod setup_notifications;
 pub mod setup_webhook;
+pub mod slack;
 pub mod start;
 pub mod status;
 pub mod task;
+pub mod test_queue;
 pub mod unregister;
 pub mod vacation;
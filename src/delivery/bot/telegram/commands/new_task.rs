@@ -0,0 +1,91 @@
+use crate::application::user::queries::get_user_bound_repositories::query::GetUserBoundRepositoriesQuery;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::context::TelegramBotCommandContext;
+use crate::delivery::bot::telegram::dialogues::new_task::TelegramBotNewTaskState;
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::new_task::TelegramBotNewTaskAction;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use std::sync::Arc;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::Requester;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+pub struct TelegramBotNewTaskCommandHandler {
+    context: TelegramBotCommandContext,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    dialogue: Arc<TelegramBotDialogueType>,
+}
+
+impl TelegramBotNewTaskCommandHandler {
+    pub fn new(
+        context: TelegramBotCommandContext,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        dialogue: Arc<TelegramBotDialogueType>,
+    ) -> Self {
+        Self {
+            context,
+            executors,
+            dialogue,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let social_user_id = SocialUserId(self.context.user.id.0 as i32);
+
+        let repositories = self
+            .executors
+            .queries
+            .get_user_bound_repositories
+            .execute(&GetUserBoundRepositoriesQuery { social_user_id })
+            .await
+            .map(|response| response.repositories)
+            .unwrap_or_default();
+
+        if repositories.is_empty() {
+            self.context
+                .bot
+                .send_message(
+                    self.context.msg.chat.id,
+                    t!("telegram_bot.commands.new_task.no_bound_repos").to_string(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let mut buttons: Vec<Vec<InlineKeyboardButton>> = repositories
+            .into_iter()
+            .map(|r| {
+                vec![InlineKeyboardButton::callback(
+                    format!("{}/{}", r.owner, r.name),
+                    TelegramBotNewTaskAction::repository_callback(r.id.0),
+                )]
+            })
+            .collect();
+
+        buttons.push(vec![InlineKeyboardButton::callback(
+            TelegramBotNewTaskAction::Cancel.label(),
+            TelegramBotNewTaskAction::Cancel.to_callback_data(),
+        )]);
+
+        self.dialogue
+            .update(TelegramBotDialogueState::NewTask(
+                TelegramBotNewTaskState::ChooseRepository,
+            ))
+            .await?;
+
+        self.context
+            .bot
+            .send_message(
+                self.context.msg.chat.id,
+                t!("telegram_bot.commands.new_task.choose_repository").to_string(),
+            )
+            .reply_markup(InlineKeyboardMarkup::new(buttons))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::application::user::commands::confirm_slack_account_link::command::ConfirmSlackAccountLinkCommand;
+use crate::application::user::commands::confirm_slack_account_link::error::ConfirmSlackAccountLinkExecutorError;
+use crate::application::user::commands::request_slack_account_link::command::RequestSlackAccountLinkCommand;
+use crate::application::user::commands::request_slack_account_link::error::RequestSlackAccountLinkExecutorError;
+use crate::application::user::commands::unlink_slack_account::command::UnlinkSlackAccountCommand;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use std::sync::Arc;
+use teloxide::Bot;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::Requester;
+use teloxide::types::{Message, ParseMode};
+
+pub struct TelegramBotSlackCommandHandler {
+    bot: Bot,
+    msg: Message,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    raw_arg: String,
+    social_user_id: SocialUserId,
+}
+
+impl TelegramBotSlackCommandHandler {
+    pub fn new(
+        bot: Bot,
+        msg: Message,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        raw_arg: String,
+        social_user_id: SocialUserId,
+    ) -> Self {
+        Self {
+            bot,
+            msg,
+            executors,
+            raw_arg,
+            social_user_id,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let arg = self.raw_arg.trim();
+
+        let reply = if arg.eq_ignore_ascii_case("off") || arg == "-" {
+            self.unlink().await
+        } else if let Some(code) = arg
+            .strip_prefix("confirm")
+            .filter(|code| code.starts_with(char::is_whitespace))
+        {
+            self.confirm(code.trim()).await
+        } else if let Some(slack_user_id) = SlackChannelId::parse(arg).filter(|id| id.is_user()) {
+            self.request(slack_user_id).await
+        } else {
+            t!("telegram_bot.commands.slack.usage").to_string()
+        };
+
+        self.bot
+            .send_message(self.msg.chat.id, reply)
+            .parse_mode(ParseMode::Html)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Аккаунт сохраняется только после `/slack confirm` — код приходит владельцу в Slack
+    async fn request(&self, slack_user_id: SlackChannelId) -> String {
+        match self
+            .executors
+            .commands
+            .request_slack_account_link
+            .execute(&RequestSlackAccountLinkCommand {
+                social_user_id: self.social_user_id,
+                slack_user_id: slack_user_id.clone(),
+            })
+            .await
+        {
+            Ok(_) => t!(
+                "telegram_bot.commands.slack.code_sent",
+                slack_user_id = slack_user_id.0
+            )
+            .to_string(),
+            Err(RequestSlackAccountLinkExecutorError::SlackUnavailable) => {
+                t!("telegram_bot.commands.slack.unavailable").to_string()
+            }
+            Err(RequestSlackAccountLinkExecutorError::Send(e)) => {
+                tracing::warn!(error = %e, slack_user_id = %slack_user_id.0, "Failed to send Slack confirmation code");
+                t!(
+                    "telegram_bot.commands.slack.send_failed",
+                    slack_user_id = slack_user_id.0
+                )
+                .to_string()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to request Slack account link");
+                t!("telegram_bot.commands.slack.error").to_string()
+            }
+        }
+    }
+
+    async fn confirm(&self, code: &str) -> String {
+        match self
+            .executors
+            .commands
+            .confirm_slack_account_link
+            .execute(&ConfirmSlackAccountLinkCommand {
+                social_user_id: self.social_user_id,
+                code: code.to_string(),
+            })
+            .await
+        {
+            Ok(response) => t!(
+                "telegram_bot.commands.slack.set",
+                slack_user_id = response.slack_user_id.0
+            )
+            .to_string(),
+            Err(
+                ConfirmSlackAccountLinkExecutorError::NotRequested
+                | ConfirmSlackAccountLinkExecutorError::InvalidCode,
+            ) => t!("telegram_bot.commands.slack.invalid_code").to_string(),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to confirm Slack account link");
+                t!("telegram_bot.commands.slack.error").to_string()
+            }
+        }
+    }
+
+    async fn unlink(&self) -> String {
+        match self
+            .executors
+            .commands
+            .unlink_slack_account
+            .execute(&UnlinkSlackAccountCommand {
+                social_user_id: self.social_user_id,
+            })
+            .await
+        {
+            Ok(_) => t!("telegram_bot.commands.slack.cleared").to_string(),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to unlink Slack account");
+                t!("telegram_bot.commands.slack.error").to_string()
+            }
+        }
+    }
+}
@@ -32,19 +32,19 @@ impl TelegramBotTaskCommandHandler {
     }
 
     pub async fn execute(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let id: u64 = match self.raw_id.trim().parse() {
-            Ok(v) => v,
-            Err(_) => {
-                self.bot
-                    .send_message(
-                        self.msg.chat.id,
-                        t!("telegram_bot.commands.task.invalid_id").to_string(),
-                    )
-                    .parse_mode(ParseMode::Html)
-                    .await?;
-                return Ok(());
-            }
-        };
+        let id = self.raw_id.trim();
+
+        // Kaiten — число, GitHub — #123 или owner/repo#123, остальные — ключ вида PROJ-123
+        if id.is_empty() || id.contains(char::is_whitespace) {
+            self.bot
+                .send_message(
+                    self.msg.chat.id,
+                    t!("telegram_bot.commands.task.invalid_id").to_string(),
+                )
+                .parse_mode(ParseMode::Html)
+                .await?;
+            return Ok(());
+        }
 
         let loading = self
             .bot
@@ -57,7 +57,7 @@ impl TelegramBotTaskCommandHandler {
         let text = match self
             .get_task_card
             .execute(&GetTaskCardQuery {
-                task_id: TaskId(id),
+                task_id: TaskId(id.to_string()),
             })
             .await
         {
@@ -67,9 +67,11 @@ impl TelegramBotTaskCommandHandler {
                 url = card.url
             )
             .to_string(),
-            Err(GetTaskCardError::NotFound) => {
-                t!("telegram_bot.commands.task.not_found", id = id).to_string()
-            }
+            Err(GetTaskCardError::NotFound) => t!(
+                "telegram_bot.commands.task.not_found",
+                id = teloxide::utils::html::escape(id)
+            )
+            .to_string(),
             Err(GetTaskCardError::ClientError(e)) => {
                 tracing::error!(error = %e, task_id = id, "Failed to fetch task card");
                 t!("telegram_bot.commands.task.error").to_string()
@@ -0,0 +1,231 @@
+use crate::application::task::queries::get_test_queue::query::GetTestQueueQuery;
+use crate::application::task::queries::get_test_queue::response::TestQueuePullRequest;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::context::TelegramBotCommandContext;
+use crate::delivery::bot::telegram::dialogues::test_queue::{
+    TelegramBotTestQueueCard, TelegramBotTestQueueState,
+};
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::test_queue::TelegramBotTestQueueAction;
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
+use crate::domain::shared::command::CommandExecutor;
+use crate::utils::builder::message::MessageBuilder;
+use std::sync::Arc;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::Requester;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
+
+/// Больше карточек не помещается в одно сообщение вместе с кнопками
+const MAX_CARDS: usize = 20;
+
+pub struct TelegramBotTestQueueCommandHandler {
+    context: TelegramBotCommandContext,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    dialogue: Arc<TelegramBotDialogueType>,
+}
+
+impl TelegramBotTestQueueCommandHandler {
+    pub fn new(
+        context: TelegramBotCommandContext,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        dialogue: Arc<TelegramBotDialogueType>,
+    ) -> Self {
+        Self {
+            context,
+            executors,
+            dialogue,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let queue = match self
+            .executors
+            .queries
+            .get_test_queue
+            .execute(&GetTestQueueQuery {})
+            .await
+        {
+            Ok(queue) => queue,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load test queue");
+
+                self.context
+                    .bot
+                    .send_message(
+                        self.context.msg.chat.id,
+                        t!("telegram_bot.commands.test_queue.error").to_string(),
+                    )
+                    .await?;
+
+                return Ok(());
+            }
+        };
+
+        let mut builder = MessageBuilder::new().with_html_escape(false);
+
+        for kind in &queue.failed_trackers {
+            builder = builder.line(
+                t!(
+                    "telegram_bot.commands.test_queue.tracker_unavailable",
+                    kind = kind.to_string()
+                )
+                .as_ref(),
+            );
+        }
+
+        if queue.cards.is_empty() {
+            builder = builder.line(t!("telegram_bot.commands.test_queue.empty").as_ref());
+
+            self.context
+                .bot
+                .send_message(self.context.msg.chat.id, builder.build())
+                .parse_mode(ParseMode::Html)
+                .await?;
+
+            return Ok(());
+        }
+
+        builder = builder
+            .bold(
+                t!(
+                    "telegram_bot.commands.test_queue.title",
+                    count = queue.cards.len()
+                )
+                .as_ref(),
+            )
+            .empty_line();
+
+        let mut cards = Vec::new();
+        let mut rows: Vec<Vec<InlineKeyboardButton>> = Vec::new();
+
+        for (index, card) in queue.cards.iter().take(MAX_CARDS).enumerate() {
+            builder = builder.raw(&format!(
+                "{}. <a href=\"{}\">{}</a> {}\n",
+                index + 1,
+                MessageBuilder::escape_html(&card.url),
+                MessageBuilder::escape_html(&card.task_id.0),
+                MessageBuilder::escape_html(&card.title),
+            ));
+
+            if card.pull_requests.is_empty() {
+                builder = builder.line(&format!(
+                    "   {}",
+                    t!("telegram_bot.commands.test_queue.no_pull_requests")
+                ));
+            }
+
+            for pr in &card.pull_requests {
+                builder = builder.raw(&format!("   • {}\n", pull_request_line(pr)));
+            }
+
+            // Без колонок итогов тестирования в настройках трекера кнопки не показываем
+            let mut row = vec![InlineKeyboardButton::callback(
+                format!("🙋 {}", index + 1),
+                TelegramBotTestQueueAction::take_callback(index),
+            )];
+            if card.can_pass {
+                row.push(InlineKeyboardButton::callback(
+                    format!("✅ {}", index + 1),
+                    TelegramBotTestQueueAction::done_callback(index),
+                ));
+            }
+            if card.can_fail {
+                row.push(InlineKeyboardButton::callback(
+                    format!("↩️ {}", index + 1),
+                    TelegramBotTestQueueAction::back_callback(index),
+                ));
+            }
+            rows.push(row);
+
+            cards.push(TelegramBotTestQueueCard {
+                task_id: card.task_id.clone(),
+                repository_id: card.repository_id,
+                kind: card.kind,
+                connection_id: card.connection_id,
+            });
+        }
+
+        if queue.cards.len() > MAX_CARDS {
+            builder = builder.empty_line().line(
+                t!(
+                    "telegram_bot.commands.test_queue.more",
+                    count = queue.cards.len() - MAX_CARDS
+                )
+                .as_ref(),
+            );
+        }
+
+        builder = builder
+            .empty_line()
+            .line(t!("telegram_bot.commands.test_queue.legend").as_ref());
+
+        rows.push(vec![InlineKeyboardButton::callback(
+            TelegramBotTestQueueAction::Close.label(),
+            TelegramBotTestQueueAction::Close.to_callback_data(),
+        )]);
+
+        self.dialogue
+            .update(TelegramBotDialogueState::TestQueue(
+                TelegramBotTestQueueState::List { cards },
+            ))
+            .await?;
+
+        self.context
+            .bot
+            .send_message(self.context.msg.chat.id, builder.build())
+            .parse_mode(ParseMode::Html)
+            .reply_markup(InlineKeyboardMarkup::new(rows))
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn pull_request_line(pr: &TestQueuePullRequest) -> String {
+    let title = match &pr.url {
+        Some(url) => format!(
+            "<a href=\"{}\">{} #{}</a>",
+            MessageBuilder::escape_html(url),
+            MessageBuilder::escape_html(&pr.repository),
+            pr.number
+        ),
+        None => format!(
+            "{} #{}",
+            MessageBuilder::escape_html(&pr.repository),
+            pr.number
+        ),
+    };
+
+    let merged = match pr.merged_at {
+        Some(merged_at) => t!(
+            "telegram_bot.commands.test_queue.merged_at",
+            at = merged_at.format("%d.%m %H:%M").to_string()
+        )
+        .to_string(),
+        None => t!("telegram_bot.commands.test_queue.not_merged").to_string(),
+    };
+
+    let ci = match pr.ci_state {
+        Some(CommitCiState::Success) => "✅",
+        Some(CommitCiState::Failure) => "❌",
+        Some(CommitCiState::Pending) => "⏳",
+        None => "—",
+    };
+
+    let author = match &pr.author_login {
+        Some(login) => format!("@{}", MessageBuilder::escape_html(login)),
+        None => "—".to_string(),
+    };
+
+    format!(
+        "{} {} — {}, {}, CI {}",
+        title,
+        MessageBuilder::escape_html(&pr.title),
+        author,
+        merged,
+        ci
+    )
+}
@@ -2,18 +2,24 @@ pub mod helpers;
 pub mod modules;
 
 use crate::application::monitoring::queries::get_queues_stats::query::GetQueuesStatsQuery;
+use crate::application::webhook::commands::replay_deliveries::command::ReplayWebhookDeliveriesSelector;
 use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::delivery::bot::telegram::dialogues::admin::modules::health_ping::TelegramBotDialogueAdminHealthPingDispatcher;
 use crate::delivery::bot::telegram::dialogues::admin::modules::repository::TelegramBotDialogueAdminRepositoryDispatcher;
 use crate::delivery::bot::telegram::dialogues::admin::modules::task_tracker::TelegramBotDialogueAdminTaskTrackerDispatcher;
+use crate::delivery::bot::telegram::dialogues::admin::modules::task_tracker_connections::TelegramBotDialogueAdminTaskTrackerConnectionsDispatcher;
+use crate::delivery::bot::telegram::dialogues::admin::modules::task_tracker_rules::TelegramBotDialogueAdminTaskTrackerRulesDispatcher;
 use crate::delivery::bot::telegram::dialogues::admin::modules::users::TelegramBotDialogueAdminUsersDispatcher;
+use crate::delivery::bot::telegram::dialogues::admin::modules::webhook_deliveries::TelegramBotDialogueAdminWebhookDeliveriesDispatcher;
 use crate::delivery::bot::telegram::dialogues::helpers::parse_callback;
 use crate::delivery::bot::telegram::dialogues::{
     TelegramBotDialogueState, TelegramBotDialogueType,
 };
 use crate::delivery::bot::telegram::keyboards::actions::admin::TelegramBotAdminAction;
 use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
+use crate::domain::repository::value_objects::pull_request_lifecycle_trigger::PullRequestLifecycleTrigger;
 use crate::domain::shared::command::CommandExecutor as _;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 use crate::utils::builder::message::MessageBuilder;
 use std::error::Error;
 use std::sync::Arc;
@@ -32,9 +38,12 @@ use crate::delivery::bot::telegram::keyboards::actions::admin_repository::Telegr
 /// Структура:
 ///   Menu
 ///   ├── ConfigureRepository → меню репозитория
-///   │     ├── Create: CreateRepository{Name,Owner,Url,ExternalId}
-///   │     └── Edit:   EditRepository{Select,Menu,Name,Owner,Url,ExternalId}
-///   └── ConfigureTaskTracker → TaskTracker{SelectRepository,...поля...}
+///   │     ├── Create: CreateRepository{Name,Owner,Url,Provider}
+///   │     └── Edit:   EditRepository{Select,Menu,Name,Owner,Url,Provider}
+///   ├── ConfigureTaskTracker → TaskTracker{SelectRepository,SelectKind,...поля...}
+///   │     └── Rules: TaskTrackerRule{List, SelectTrigger,SelectBoard,SelectColumn,EnterBranch, DeleteSelect}
+///   ├── TaskTrackerConnections → TaskTrackerConnection{List, Create{Kind,Name,BaseUrl,Email,Token}, Delete{Select,Confirm}}
+///   └── WebhookDeliveries → WebhookDeliveries{EnterId | SelectRepository,SelectWindow} → ReplayConfirm
 #[derive(Debug, Clone, Default)]
 pub enum TelegramBotDialogueAdminState {
     #[default]
@@ -52,6 +61,11 @@ pub enum TelegramBotDialogueAdminState {
         name: String,
         owner: String,
     },
+    CreateRepositoryProvider {
+        name: String,
+        owner: String,
+        url: String,
+    },
 
     // Редактирование
     EditRepositorySelect,
@@ -67,6 +81,18 @@ pub enum TelegramBotDialogueAdminState {
     EditRepositoryUrl {
         repository_id: i32,
     },
+    EditRepositoryProvider {
+        repository_id: i32,
+    },
+    EditRepositoryWebhookSecret {
+        repository_id: i32,
+    },
+    EditRepositoryRefEventsFilter {
+        repository_id: i32,
+    },
+    EditRepositorySlackChannel {
+        repository_id: i32,
+    },
 
     // Просмотр
     ViewRepositorySelect,
@@ -93,23 +119,121 @@ pub enum TelegramBotDialogueAdminState {
         repository_id: i32,
     },
 
-    // Интерактивный выбор через API (создание / перенастройка)
+    // Выбор трекера (из окружения или подключение), если вариантов несколько
+    ConfigureTaskTrackerSelectKind {
+        repository_id: i32,
+    },
+
+    // Интерактивный выбор через API (создание / перенастройка).
+    // `connection_id = None` — клиент трекера `kind` из окружения.
     ConfigureTaskTrackerSelectSpace {
         repository_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
     },
     ConfigureTaskTrackerSelectBoard {
         repository_id: i32,
-        space_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+        space_id: String,
     },
     ConfigureTaskTrackerSelectColumn {
         repository_id: i32,
-        space_id: i32,
-        board_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+        space_id: String,
+        board_id: String,
+    },
+    // Колонки итогов тестирования — с той же доски, можно пропустить
+    ConfigureTaskTrackerSelectPassedColumn {
+        repository_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+        space_id: String,
+        board_id: String,
+        qa_column_id: String,
+    },
+    ConfigureTaskTrackerSelectFailedColumn {
+        repository_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+        space_id: String,
+        board_id: String,
+        qa_column_id: String,
+        qa_passed_column_id: Option<String>,
+    },
+    // Колонка для карточек из новых GitHub issue — с той же доски, можно пропустить
+    ConfigureTaskTrackerSelectIssueColumn {
+        repository_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+        space_id: String,
+        board_id: String,
+        qa_column_id: String,
+        qa_passed_column_id: Option<String>,
+        qa_failed_column_id: Option<String>,
     },
     ConfigureTaskTrackerEnterPattern {
         repository_id: i32,
-        space_id: i32,
-        qa_column_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+        space_id: String,
+        qa_column_id: String,
+        qa_passed_column_id: Option<String>,
+        qa_failed_column_id: Option<String>,
+        issue_column_id: Option<String>,
+    },
+
+    // Правила перевода карточек по событиям PR
+    ConfigureTaskTrackerRuleList {
+        repository_id: i32,
+    },
+    ConfigureTaskTrackerRuleSelectTrigger {
+        repository_id: i32,
+    },
+    ConfigureTaskTrackerRuleSelectBoard {
+        repository_id: i32,
+        trigger: PullRequestLifecycleTrigger,
+    },
+    ConfigureTaskTrackerRuleSelectColumn {
+        repository_id: i32,
+        trigger: PullRequestLifecycleTrigger,
+    },
+    ConfigureTaskTrackerRuleEnterBranch {
+        repository_id: i32,
+        trigger: PullRequestLifecycleTrigger,
+        column_id: String,
+    },
+    ConfigureTaskTrackerRuleDeleteSelect {
+        repository_id: i32,
+    },
+
+    // ── Подключения трекеров ───────────────────────────────────────────────
+    TaskTrackerConnectionList,
+
+    TaskTrackerConnectionCreateKind,
+    TaskTrackerConnectionCreateName {
+        kind: TaskTrackerKind,
+    },
+    TaskTrackerConnectionCreateBaseUrl {
+        kind: TaskTrackerKind,
+        name: String,
+    },
+    TaskTrackerConnectionCreateEmail {
+        kind: TaskTrackerKind,
+        name: String,
+        base_url: String,
+    },
+    TaskTrackerConnectionCreateToken {
+        kind: TaskTrackerKind,
+        name: String,
+        base_url: String,
+        email: Option<String>,
+    },
+
+    TaskTrackerConnectionDeleteSelect,
+    TaskTrackerConnectionDeleteConfirm {
+        connection_id: i32,
     },
 
     // ── Пинги ──────────────────────────────────────────────────────────────
@@ -156,6 +280,20 @@ pub enum TelegramBotDialogueAdminState {
     UserRemoveRole {
         user_id: i32,
     },
+
+    // ── Вебхук-доставки ─────────────────────────────────────────────────
+    WebhookDeliveriesMenu,
+
+    WebhookDeliveriesEnterId,
+
+    WebhookDeliveriesSelectRepository,
+    WebhookDeliveriesSelectWindow {
+        repository_full_name: String,
+    },
+
+    WebhookDeliveriesReplayConfirm {
+        selector: ReplayWebhookDeliveriesSelector,
+    },
 }
 
 pub struct TelegramBotDialogueAdminDispatcher {}
@@ -171,13 +309,19 @@ impl TelegramBotDialogueAdminDispatcher {
             .branch(TelegramBotDialogueAdminRepositoryDispatcher::query_branches())
             .branch(TelegramBotDialogueAdminTaskTrackerDispatcher::query_branches())
             .branch(TelegramBotDialogueAdminTaskTrackerDispatcher::menu_query_branches())
+            .branch(TelegramBotDialogueAdminTaskTrackerRulesDispatcher::query_branches())
+            .branch(TelegramBotDialogueAdminTaskTrackerConnectionsDispatcher::query_branches())
             .branch(TelegramBotDialogueAdminHealthPingDispatcher::query_branches())
-            .branch(TelegramBotDialogueAdminUsersDispatcher::query_branches());
+            .branch(TelegramBotDialogueAdminUsersDispatcher::query_branches())
+            .branch(TelegramBotDialogueAdminWebhookDeliveriesDispatcher::query_branches());
 
         let messages = Update::filter_message()
             .branch(TelegramBotDialogueAdminRepositoryDispatcher::message_branches())
             .branch(TelegramBotDialogueAdminTaskTrackerDispatcher::message_branches())
-            .branch(TelegramBotDialogueAdminHealthPingDispatcher::message_branches());
+            .branch(TelegramBotDialogueAdminTaskTrackerRulesDispatcher::message_branches())
+            .branch(TelegramBotDialogueAdminTaskTrackerConnectionsDispatcher::message_branches())
+            .branch(TelegramBotDialogueAdminHealthPingDispatcher::message_branches())
+            .branch(TelegramBotDialogueAdminWebhookDeliveriesDispatcher::message_branches());
 
         dptree::entry().branch(callback_queries).branch(messages)
     }
@@ -291,6 +435,19 @@ impl TelegramBotDialogueAdminDispatcher {
                 .await?;
             }
 
+            TelegramBotAdminAction::TaskTrackerConnections => {
+                dialogue
+                    .update(TelegramBotDialogueState::Admin(
+                        TelegramBotDialogueAdminState::TaskTrackerConnectionList,
+                    ))
+                    .await?;
+
+                TelegramBotDialogueAdminTaskTrackerConnectionsDispatcher::show_list(
+                    &bot, chat_id, message_id, &executors,
+                )
+                .await?;
+            }
+
             TelegramBotAdminAction::ManageUsers => {
                 dialogue
                     .update(TelegramBotDialogueState::Admin(
@@ -304,6 +461,19 @@ impl TelegramBotDialogueAdminDispatcher {
                 .await?;
             }
 
+            TelegramBotAdminAction::WebhookDeliveries => {
+                dialogue
+                    .update(TelegramBotDialogueState::Admin(
+                        TelegramBotDialogueAdminState::WebhookDeliveriesMenu,
+                    ))
+                    .await?;
+
+                TelegramBotDialogueAdminWebhookDeliveriesDispatcher::show_list(
+                    &bot, chat_id, message_id, &executors,
+                )
+                .await?;
+            }
+
             TelegramBotAdminAction::ConfigureTaskTracker => {
                 let repositories = executors
                     .commands
@@ -3,6 +3,7 @@ use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::delivery::bot::telegram::dialogues::TelegramBotDialogueType;
 use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
 use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use std::error::Error;
 use std::sync::Arc;
 use teloxide::dispatching::DpHandlerDescription;
@@ -106,7 +107,12 @@ impl TelegramBotDialogueAdminCreateRepositoryDispatcher {
             }
         };
 
-        let cmd = CreateRepositoryCommand { name, owner, url };
+        let cmd = CreateRepositoryCommand {
+            name,
+            owner,
+            url,
+            version_control_type: VersionControlType::Github,
+        };
 
         let loading_message = bot
             .send_message(msg.chat.id, "Создаем репозиторий...")
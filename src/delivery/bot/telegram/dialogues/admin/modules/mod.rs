@@ -2,4 +2,7 @@ pub mod create_repository;
 pub mod health_ping;
 pub mod repository;
 pub mod task_tracker;
+pub mod task_tracker_connections;
+pub mod task_tracker_rules;
 pub mod users;
+pub mod webhook_deliveries;
@@ -2,9 +2,13 @@ use crate::application::repository::commands::create_repository::command::Create
 use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
 use crate::delivery::bot::telegram::dialogues::admin::helpers::extract_text;
+use crate::delivery::bot::telegram::dialogues::setup_webhook::ensure_webhook_report;
 use crate::delivery::bot::telegram::dialogues::{
     TelegramBotDialogueState, TelegramBotDialogueType,
 };
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::admin_repository_provider::TelegramBotAdminRepositoryProviderAction;
+use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
 use crate::domain::shared::command::CommandExecutor;
 use std::error::Error;
 use std::sync::Arc;
@@ -16,6 +20,14 @@ use teloxide::{Bot, dptree};
 pub struct TelegramBotDialogueAdminRepositoryCreateDispatcher {}
 
 impl TelegramBotDialogueAdminRepositoryCreateDispatcher {
+    pub fn query_branches()
+    -> Handler<'static, Result<(), Box<dyn Error + Send + Sync>>, DpHandlerDescription> {
+        dptree::entry().branch(
+            case![TelegramBotDialogueAdminState::CreateRepositoryProvider { name, owner, url }]
+                .endpoint(Self::handle_provider),
+        )
+    }
+
     pub fn message_branches()
     -> Handler<'static, Result<(), Box<dyn Error + Send + Sync>>, DpHandlerDescription> {
         dptree::entry()
@@ -100,7 +112,6 @@ impl TelegramBotDialogueAdminRepositoryCreateDispatcher {
         bot: Bot,
         dialogue: TelegramBotDialogueType,
         msg: Message,
-        executors: Arc<ApplicationBoostrapExecutors>,
         (name, owner): (String, String),
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let url = match extract_text(&msg) {
@@ -115,29 +126,86 @@ impl TelegramBotDialogueAdminRepositoryCreateDispatcher {
             }
         };
 
-        let loading = bot
-            .send_message(
-                msg.chat.id,
-                t!("telegram_bot.dialogues.admin.repository.create.loading").to_string(),
-            )
+        let keyboard = KeyboardBuilder::new()
+            .row::<TelegramBotAdminRepositoryProviderAction>(vec![
+                TelegramBotAdminRepositoryProviderAction::Github,
+                TelegramBotAdminRepositoryProviderAction::Gitea,
+                TelegramBotAdminRepositoryProviderAction::Gitlab,
+            ])
+            .build();
+
+        dialogue
+            .update(TelegramBotDialogueState::Admin(
+                TelegramBotDialogueAdminState::CreateRepositoryProvider { name, owner, url },
+            ))
             .await?;
 
+        bot.send_message(
+            msg.chat.id,
+            t!("telegram_bot.dialogues.admin.repository.create.choose_provider").to_string(),
+        )
+        .reply_markup(keyboard)
+        .await?;
+        Ok(())
+    }
+
+    async fn handle_provider(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        query: CallbackQuery,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        (name, owner, url): (String, String, String),
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        bot.answer_callback_query(query.id.clone()).await?;
+
+        let data = query.data.as_deref().unwrap_or("");
+        let version_control_type =
+            match TelegramBotAdminRepositoryProviderAction::from_callback_data(data) {
+                Ok(p) => p.to_version_control_type(),
+                Err(e) => {
+                    tracing::error!(error = %e, "Unknown provider");
+                    return Ok(());
+                }
+            };
+
+        let msg = match query.message {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        bot.edit_message_text(
+            msg.chat().id,
+            msg.id(),
+            t!("telegram_bot.dialogues.admin.repository.create.loading").to_string(),
+        )
+        .reply_markup(teloxide::types::InlineKeyboardMarkup::default())
+        .await?;
+
         match executors
             .commands
             .create_repository
-            .execute(&CreateRepositoryCommand { name, owner, url })
+            .execute(&CreateRepositoryCommand {
+                name,
+                owner,
+                url,
+                version_control_type,
+            })
             .await
         {
             Ok(r) => {
+                let hook = ensure_webhook_report(&executors, r.repository.id).await;
                 bot.edit_message_text(
-                    msg.chat.id,
-                    loading.id,
-                    t!(
-                        "telegram_bot.dialogues.admin.repository.create.success",
-                        owner = r.repository.owner,
-                        name = r.repository.name
-                    )
-                    .to_string(),
+                    msg.chat().id,
+                    msg.id(),
+                    format!(
+                        "{}\n\n{}",
+                        t!(
+                            "telegram_bot.dialogues.admin.repository.create.success",
+                            owner = r.repository.owner,
+                            name = r.repository.name
+                        ),
+                        hook
+                    ),
                 )
                 .parse_mode(teloxide::types::ParseMode::Html)
                 .await?;
@@ -145,8 +213,8 @@ impl TelegramBotDialogueAdminRepositoryCreateDispatcher {
             Err(e) => {
                 tracing::error!(error = %e, "Failed to create repository");
                 bot.edit_message_text(
-                    msg.chat.id,
-                    loading.id,
+                    msg.chat().id,
+                    msg.id(),
                     t!("telegram_bot.dialogues.admin.repository.create.db_error").to_string(),
                 )
                 .await?;
@@ -1,5 +1,12 @@
+use crate::application::repository::commands::rotate_repository_webhook_secret::command::RotateRepositoryWebhookSecretCommand;
+use crate::application::repository::commands::rotate_repository_webhook_secret::error::RotateRepositoryWebhookSecretError;
+use crate::application::repository::commands::set_repository_ref_events_filter::command::SetRepositoryRefEventsFilterCommand;
+use crate::application::repository::commands::set_repository_ref_events_filter::error::SetRepositoryRefEventsFilterError;
+use crate::application::repository::commands::set_repository_slack_channel::command::SetRepositorySlackChannelCommand;
+use crate::application::repository::commands::set_repository_slack_channel::error::SetRepositorySlackChannelError;
 use crate::application::repository::commands::update_repository::command::UpdateRepositoryCommand;
 use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::config::application::ApplicationConfig;
 use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
 use crate::delivery::bot::telegram::dialogues::admin::helpers::extract_text;
 use crate::delivery::bot::telegram::dialogues::{
@@ -8,9 +15,12 @@ use crate::delivery::bot::telegram::dialogues::{
 use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
 use crate::delivery::bot::telegram::keyboards::actions::admin_repository::REPO_SELECT_PREFIX;
 use crate::delivery::bot::telegram::keyboards::actions::admin_repository_edit_field::TelegramBotAdminRepositoryEditField;
+use crate::delivery::bot::telegram::keyboards::actions::admin_repository_provider::TelegramBotAdminRepositoryProviderAction;
 use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
 use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::utils::builder::message::MessageBuilder;
 use std::error::Error;
 use std::sync::Arc;
 use teloxide::dispatching::DpHandlerDescription;
@@ -22,6 +32,7 @@ enum EditField {
     Name,
     Owner,
     Url,
+    Provider(VersionControlType),
 }
 
 pub struct TelegramBotDialogueAdminRepositoryEditDispatcher {}
@@ -38,6 +49,10 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
                 case![TelegramBotDialogueAdminState::EditRepositoryMenu { repository_id }]
                     .endpoint(Self::handle_field_choice),
             )
+            .branch(
+                case![TelegramBotDialogueAdminState::EditRepositoryProvider { repository_id }]
+                    .endpoint(Self::handle_edit_provider),
+            )
     }
 
     pub fn message_branches()
@@ -55,6 +70,20 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
                 case![TelegramBotDialogueAdminState::EditRepositoryUrl { repository_id }]
                     .endpoint(Self::handle_edit_url),
             )
+            .branch(
+                case![TelegramBotDialogueAdminState::EditRepositoryWebhookSecret { repository_id }]
+                    .endpoint(Self::handle_edit_webhook_secret),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::EditRepositoryRefEventsFilter { repository_id }
+                ]
+                .endpoint(Self::handle_edit_ref_events_filter),
+            )
+            .branch(
+                case![TelegramBotDialogueAdminState::EditRepositorySlackChannel { repository_id }]
+                    .endpoint(Self::handle_edit_slack_channel),
+            )
     }
 
     async fn handle_select(
@@ -89,7 +118,37 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
             .find_by_id(RepositoryId(repository_id))
             .await
         {
-            Ok(r) => format!("📦 <b>{}/{}</b>\n🔗 {}", r.owner, r.name, r.url),
+            Ok(r) => format!(
+                "📦 <b>{}/{}</b>\n🔗 {}\n🌐 {}\n🔑 {}\n🌿 {}\n💬 {}{}",
+                r.owner,
+                r.name,
+                r.url,
+                r.version_control_type,
+                if r.webhook_secret.is_some() {
+                    t!("telegram_bot.dialogues.admin.repository.edit.webhook_secret_own")
+                } else {
+                    t!("telegram_bot.dialogues.admin.repository.edit.webhook_secret_global")
+                },
+                match &r.ref_events_filter {
+                    Some(filter) => MessageBuilder::escape_html(&filter.to_string()),
+                    None =>
+                        t!("telegram_bot.dialogues.admin.repository.edit.ref_events_filter_all")
+                            .to_string(),
+                },
+                match &r.slack_channel_id {
+                    Some(channel_id) => channel_id.0.clone(),
+                    None => t!("telegram_bot.dialogues.admin.repository.edit.slack_channel_none")
+                        .to_string(),
+                },
+                if r.is_archived {
+                    format!(
+                        "\n{}",
+                        t!("telegram_bot.dialogues.admin.repository.edit.archived")
+                    )
+                } else {
+                    String::new()
+                }
+            ),
             Err(_) => t!("telegram_bot.dialogues.admin.repository.data_unavailable").to_string(),
         };
 
@@ -100,6 +159,14 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
             ])
             .row::<TelegramBotAdminRepositoryEditField>(vec![
                 TelegramBotAdminRepositoryEditField::Url,
+                TelegramBotAdminRepositoryEditField::Provider,
+            ])
+            .row::<TelegramBotAdminRepositoryEditField>(vec![
+                TelegramBotAdminRepositoryEditField::WebhookSecret,
+                TelegramBotAdminRepositoryEditField::RefEventsFilter,
+            ])
+            .row::<TelegramBotAdminRepositoryEditField>(vec![
+                TelegramBotAdminRepositoryEditField::SlackChannel,
             ])
             .build();
 
@@ -128,6 +195,7 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
     async fn handle_field_choice(
         bot: Bot,
         dialogue: TelegramBotDialogueType,
+        config: Arc<ApplicationConfig>,
         query: CallbackQuery,
         repository_id: i32,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -160,6 +228,95 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
                 TelegramBotDialogueAdminState::EditRepositoryUrl { repository_id },
                 t!("telegram_bot.dialogues.admin.repository.edit.enter_url"),
             ),
+            TelegramBotAdminRepositoryEditField::WebhookSecret => {
+                dialogue
+                    .update(TelegramBotDialogueState::Admin(
+                        TelegramBotDialogueAdminState::EditRepositoryWebhookSecret {
+                            repository_id,
+                        },
+                    ))
+                    .await?;
+
+                bot.edit_message_text(
+                    msg.chat().id,
+                    msg.id(),
+                    t!(
+                        "telegram_bot.dialogues.admin.repository.edit.enter_webhook_secret",
+                        hours = config.github.webhook_secret_rotation_grace_hours
+                    )
+                    .to_string(),
+                )
+                .parse_mode(teloxide::types::ParseMode::Html)
+                .reply_markup(teloxide::types::InlineKeyboardMarkup::default())
+                .await?;
+
+                return Ok(());
+            }
+            TelegramBotAdminRepositoryEditField::RefEventsFilter => {
+                dialogue
+                    .update(TelegramBotDialogueState::Admin(
+                        TelegramBotDialogueAdminState::EditRepositoryRefEventsFilter {
+                            repository_id,
+                        },
+                    ))
+                    .await?;
+
+                bot.edit_message_text(
+                    msg.chat().id,
+                    msg.id(),
+                    t!("telegram_bot.dialogues.admin.repository.edit.enter_ref_events_filter")
+                        .to_string(),
+                )
+                .parse_mode(teloxide::types::ParseMode::Html)
+                .reply_markup(teloxide::types::InlineKeyboardMarkup::default())
+                .await?;
+
+                return Ok(());
+            }
+            TelegramBotAdminRepositoryEditField::SlackChannel => {
+                dialogue
+                    .update(TelegramBotDialogueState::Admin(
+                        TelegramBotDialogueAdminState::EditRepositorySlackChannel { repository_id },
+                    ))
+                    .await?;
+
+                bot.edit_message_text(
+                    msg.chat().id,
+                    msg.id(),
+                    t!("telegram_bot.dialogues.admin.repository.edit.enter_slack_channel")
+                        .to_string(),
+                )
+                .parse_mode(teloxide::types::ParseMode::Html)
+                .reply_markup(teloxide::types::InlineKeyboardMarkup::default())
+                .await?;
+
+                return Ok(());
+            }
+            TelegramBotAdminRepositoryEditField::Provider => {
+                let keyboard = KeyboardBuilder::new()
+                    .row::<TelegramBotAdminRepositoryProviderAction>(vec![
+                        TelegramBotAdminRepositoryProviderAction::Github,
+                        TelegramBotAdminRepositoryProviderAction::Gitea,
+                        TelegramBotAdminRepositoryProviderAction::Gitlab,
+                    ])
+                    .build();
+
+                dialogue
+                    .update(TelegramBotDialogueState::Admin(
+                        TelegramBotDialogueAdminState::EditRepositoryProvider { repository_id },
+                    ))
+                    .await?;
+
+                bot.edit_message_text(
+                    msg.chat().id,
+                    msg.id(),
+                    t!("telegram_bot.dialogues.admin.repository.edit.choose_provider").to_string(),
+                )
+                .reply_markup(keyboard)
+                .await?;
+
+                return Ok(());
+            }
         };
 
         dialogue
@@ -194,7 +351,7 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
             bot,
             dialogue,
             executors,
-            msg,
+            msg.chat.id,
             repository_id,
             EditField::Name,
             new_value,
@@ -224,7 +381,7 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
             bot,
             dialogue,
             executors,
-            msg,
+            msg.chat.id,
             repository_id,
             EditField::Owner,
             new_value,
@@ -254,7 +411,7 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
             bot,
             dialogue,
             executors,
-            msg,
+            msg.chat.id,
             repository_id,
             EditField::Url,
             new_value,
@@ -262,12 +419,272 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
         .await
     }
 
-    async fn apply_field_edit(
+    async fn handle_edit_webhook_secret(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        config: Arc<ApplicationConfig>,
+        msg: Message,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let new_value = match extract_text(&msg) {
+            Some(v) => v,
+            None => {
+                bot.send_message(
+                    msg.chat.id,
+                    t!("telegram_bot.dialogues.admin.repository.edit.webhook_secret_required")
+                        .to_string(),
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+
+        // Секрет не должен оставаться в истории чата
+        bot.delete_message(msg.chat.id, msg.id).await.ok();
+
+        let cmd = RotateRepositoryWebhookSecretCommand {
+            repository_id: RepositoryId(repository_id),
+            secret: if new_value == "-" {
+                None
+            } else {
+                Some(new_value)
+            },
+        };
+
+        let loading = bot
+            .send_message(
+                msg.chat.id,
+                t!("telegram_bot.dialogues.admin.repository.edit.loading").to_string(),
+            )
+            .await?;
+
+        let text = match executors
+            .commands
+            .rotate_repository_webhook_secret
+            .execute(&cmd)
+            .await
+        {
+            Ok(r) => t!(
+                "telegram_bot.dialogues.admin.repository.edit.webhook_secret_success",
+                owner = r.repository.owner,
+                name = r.repository.name,
+                hours = config.github.webhook_secret_rotation_grace_hours
+            )
+            .to_string(),
+            Err(RotateRepositoryWebhookSecretError::NotFound) => {
+                t!("telegram_bot.dialogues.admin.repository.not_found").to_string()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to rotate repository webhook secret");
+                t!("telegram_bot.dialogues.admin.repository.edit.db_error").to_string()
+            }
+        };
+
+        bot.edit_message_text(msg.chat.id, loading.id, text)
+            .parse_mode(teloxide::types::ParseMode::Html)
+            .await?;
+
+        dialogue.exit().await.ok();
+        Ok(())
+    }
+
+    async fn handle_edit_ref_events_filter(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        msg: Message,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let new_value = match extract_text(&msg) {
+            Some(v) => v,
+            None => {
+                bot.send_message(
+                    msg.chat.id,
+                    t!("telegram_bot.dialogues.admin.repository.edit.ref_events_filter_required")
+                        .to_string(),
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+
+        let cmd = SetRepositoryRefEventsFilterCommand {
+            repository_id: RepositoryId(repository_id),
+            filter: if new_value == "-" {
+                None
+            } else {
+                Some(new_value)
+            },
+        };
+
+        let text = match executors
+            .commands
+            .set_repository_ref_events_filter
+            .execute(&cmd)
+            .await
+        {
+            Ok(r) => t!(
+                "telegram_bot.dialogues.admin.repository.edit.ref_events_filter_success",
+                owner = r.repository.owner,
+                name = r.repository.name,
+                filter = match &r.repository.ref_events_filter {
+                    Some(filter) => MessageBuilder::escape_html(&filter.to_string()),
+                    None =>
+                        t!("telegram_bot.dialogues.admin.repository.edit.ref_events_filter_all")
+                            .to_string(),
+                }
+            )
+            .to_string(),
+            Err(SetRepositoryRefEventsFilterError::InvalidFilter(error)) => {
+                // Остаёмся в том же состоянии — админ может поправить правила
+                bot.send_message(
+                    msg.chat.id,
+                    t!(
+                        "telegram_bot.dialogues.admin.repository.edit.ref_events_filter_invalid",
+                        error = MessageBuilder::escape_html(&error)
+                    )
+                    .to_string(),
+                )
+                .parse_mode(teloxide::types::ParseMode::Html)
+                .await?;
+                return Ok(());
+            }
+            Err(SetRepositoryRefEventsFilterError::NotFound) => {
+                t!("telegram_bot.dialogues.admin.repository.not_found").to_string()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to update repository ref events filter");
+                t!("telegram_bot.dialogues.admin.repository.edit.db_error").to_string()
+            }
+        };
+
+        bot.send_message(msg.chat.id, text)
+            .parse_mode(teloxide::types::ParseMode::Html)
+            .await?;
+
+        dialogue.exit().await.ok();
+        Ok(())
+    }
+
+    async fn handle_edit_slack_channel(
         bot: Bot,
         dialogue: TelegramBotDialogueType,
         executors: Arc<ApplicationBoostrapExecutors>,
         msg: Message,
         repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let new_value = match extract_text(&msg) {
+            Some(v) => v,
+            None => {
+                bot.send_message(
+                    msg.chat.id,
+                    t!("telegram_bot.dialogues.admin.repository.edit.slack_channel_required")
+                        .to_string(),
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+
+        let cmd = SetRepositorySlackChannelCommand {
+            repository_id: RepositoryId(repository_id),
+            slack_channel_id: if new_value == "-" {
+                None
+            } else {
+                Some(new_value)
+            },
+        };
+
+        let text = match executors
+            .commands
+            .set_repository_slack_channel
+            .execute(&cmd)
+            .await
+        {
+            Ok(r) => t!(
+                "telegram_bot.dialogues.admin.repository.edit.slack_channel_success",
+                owner = r.repository.owner,
+                name = r.repository.name,
+                channel = match &r.repository.slack_channel_id {
+                    Some(channel_id) => channel_id.0.clone(),
+                    None => t!("telegram_bot.dialogues.admin.repository.edit.slack_channel_none")
+                        .to_string(),
+                }
+            )
+            .to_string(),
+            Err(SetRepositorySlackChannelError::InvalidChannelId(value)) => {
+                // Остаёмся в том же состоянии — админ может поправить ID
+                bot.send_message(
+                    msg.chat.id,
+                    t!(
+                        "telegram_bot.dialogues.admin.repository.edit.slack_channel_invalid",
+                        value = MessageBuilder::escape_html(&value)
+                    )
+                    .to_string(),
+                )
+                .parse_mode(teloxide::types::ParseMode::Html)
+                .await?;
+                return Ok(());
+            }
+            Err(SetRepositorySlackChannelError::NotFound) => {
+                t!("telegram_bot.dialogues.admin.repository.not_found").to_string()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to update repository Slack channel");
+                t!("telegram_bot.dialogues.admin.repository.edit.db_error").to_string()
+            }
+        };
+
+        bot.send_message(msg.chat.id, text)
+            .parse_mode(teloxide::types::ParseMode::Html)
+            .await?;
+
+        dialogue.exit().await.ok();
+        Ok(())
+    }
+
+    async fn handle_edit_provider(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        query: CallbackQuery,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        bot.answer_callback_query(query.id.clone()).await?;
+
+        let data = query.data.as_deref().unwrap_or("");
+        let provider = match TelegramBotAdminRepositoryProviderAction::from_callback_data(data) {
+            Ok(p) => p.to_version_control_type(),
+            Err(e) => {
+                tracing::error!(error = %e, "Unknown provider");
+                return Ok(());
+            }
+        };
+
+        let chat_id = match query.message {
+            Some(m) => m.chat().id,
+            None => return Ok(()),
+        };
+
+        Self::apply_field_edit(
+            bot,
+            dialogue,
+            executors,
+            chat_id,
+            repository_id,
+            EditField::Provider(provider),
+            String::new(),
+        )
+        .await
+    }
+
+    async fn apply_field_edit(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        executors: Arc<ApplicationBoostrapExecutors>,
+        chat_id: ChatId,
+        repository_id: i32,
         field: EditField,
         new_value: String,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -282,7 +699,7 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
             Err(e) => {
                 tracing::error!(error = %e, repository_id = repository_id, "Repository not found for edit");
                 bot.send_message(
-                    msg.chat.id,
+                    chat_id,
                     t!("telegram_bot.dialogues.admin.repository.not_found").to_string(),
                 )
                 .await?;
@@ -297,24 +714,34 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
                 name: new_value,
                 owner: repo.owner,
                 url: repo.url,
+                version_control_type: repo.version_control_type,
             },
             EditField::Owner => UpdateRepositoryCommand {
                 id: repo.id,
                 name: repo.name,
                 owner: new_value,
                 url: repo.url,
+                version_control_type: repo.version_control_type,
             },
             EditField::Url => UpdateRepositoryCommand {
                 id: repo.id,
                 name: repo.name,
                 owner: repo.owner,
                 url: new_value,
+                version_control_type: repo.version_control_type,
+            },
+            EditField::Provider(version_control_type) => UpdateRepositoryCommand {
+                id: repo.id,
+                name: repo.name,
+                owner: repo.owner,
+                url: repo.url,
+                version_control_type,
             },
         };
 
         let loading = bot
             .send_message(
-                msg.chat.id,
+                chat_id,
                 t!("telegram_bot.dialogues.admin.repository.edit.loading").to_string(),
             )
             .await?;
@@ -322,7 +749,7 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
         match executors.commands.update_repository.execute(&cmd).await {
             Ok(r) => {
                 bot.edit_message_text(
-                    msg.chat.id,
+                    chat_id,
                     loading.id,
                     t!(
                         "telegram_bot.dialogues.admin.repository.edit.success",
@@ -337,7 +764,7 @@ impl TelegramBotDialogueAdminRepositoryEditDispatcher {
             Err(e) => {
                 tracing::error!(error = %e, "Failed to update repository");
                 bot.edit_message_text(
-                    msg.chat.id,
+                    chat_id,
                     loading.id,
                     t!("telegram_bot.dialogues.admin.repository.edit.db_error").to_string(),
                 )
@@ -38,6 +38,7 @@ impl TelegramBotDialogueAdminRepositoryDispatcher {
                 case![TelegramBotDialogueAdminState::ConfigureRepository]
                     .endpoint(Self::handle_menu),
             )
+            .branch(TelegramBotDialogueAdminRepositoryCreateDispatcher::query_branches())
             .branch(TelegramBotDialogueAdminRepositoryEditDispatcher::query_branches())
             .branch(
                 case![TelegramBotDialogueAdminState::ViewRepositorySelect]
@@ -3,6 +3,7 @@ use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::bootstrap::shared_dependency::ApplicationSharedDependency;
 use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
 use crate::delivery::bot::telegram::dialogues::admin::helpers::extract_text;
+use crate::delivery::bot::telegram::dialogues::admin::modules::task_tracker_rules::TelegramBotDialogueAdminTaskTrackerRulesDispatcher;
 use crate::delivery::bot::telegram::dialogues::{
     TelegramBotDialogueState, TelegramBotDialogueType,
 };
@@ -13,14 +14,44 @@ use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
 use crate::domain::shared::command::CommandExecutor;
 use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 use crate::utils::builder::message::MessageBuilder;
 use std::error::Error;
+use std::str::FromStr;
 use std::sync::Arc;
 use teloxide::Bot;
 use teloxide::dispatching::DpHandlerDescription;
 use teloxide::dptree::case;
 use teloxide::prelude::*;
-use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
+use teloxide::types::{ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId, ParseMode};
+
+/// Callback кнопки «пропустить» при выборе необязательных колонок
+const SKIP_COLUMN: &str = "admin_tt_skip_column";
+
+/// Поля `ConfigureTaskTrackerSelectIssueColumn` в порядке объявления
+type SelectIssueColumnState = (
+    i32,
+    TaskTrackerKind,
+    Option<i32>,
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+);
+
+/// Поля `ConfigureTaskTrackerEnterPattern` в порядке объявления
+type EnterPatternState = (
+    i32,
+    TaskTrackerKind,
+    Option<i32>,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
 
 pub struct TelegramBotDialogueAdminTaskTrackerDispatcher {}
 
@@ -50,10 +81,18 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                 ]
                 .endpoint(Self::handle_edit_select_field),
             )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectKind { repository_id }
+                ]
+                .endpoint(Self::handle_select_kind),
+            )
             .branch(
                 case![
                     TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectSpace {
-                        repository_id
+                        repository_id,
+                        kind,
+                        connection_id
                     }
                 ]
                 .endpoint(Self::handle_select_space),
@@ -62,6 +101,8 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                 case![
                     TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectBoard {
                         repository_id,
+                        kind,
+                        connection_id,
                         space_id
                     }
                 ]
@@ -71,12 +112,56 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                 case![
                     TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectColumn {
                         repository_id,
+                        kind,
+                        connection_id,
                         space_id,
                         board_id
                     }
                 ]
                 .endpoint(Self::handle_select_column),
             )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectPassedColumn {
+                        repository_id,
+                        kind,
+                        connection_id,
+                        space_id,
+                        board_id,
+                        qa_column_id
+                    }
+                ]
+                .endpoint(Self::handle_select_passed_column),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectFailedColumn {
+                        repository_id,
+                        kind,
+                        connection_id,
+                        space_id,
+                        board_id,
+                        qa_column_id,
+                        qa_passed_column_id
+                    }
+                ]
+                .endpoint(Self::handle_select_failed_column),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectIssueColumn {
+                        repository_id,
+                        kind,
+                        connection_id,
+                        space_id,
+                        board_id,
+                        qa_column_id,
+                        qa_passed_column_id,
+                        qa_failed_column_id
+                    }
+                ]
+                .endpoint(Self::handle_select_issue_column),
+            )
     }
 
     /// Ветки для текстовых сообщений (ввод паттерна).
@@ -87,8 +172,13 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                 case![
                     TelegramBotDialogueAdminState::ConfigureTaskTrackerEnterPattern {
                         repository_id,
+                        kind,
+                        connection_id,
                         space_id,
-                        qa_column_id
+                        qa_column_id,
+                        qa_passed_column_id,
+                        qa_failed_column_id,
+                        issue_column_id
                     }
                 ]
                 .endpoint(Self::handle_enter_pattern),
@@ -142,6 +232,10 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                         TelegramBotAdminTaskTrackerAction::View,
                         TelegramBotAdminTaskTrackerAction::Edit,
                     ])
+                    .row::<TelegramBotAdminTaskTrackerAction>(vec![
+                        TelegramBotAdminTaskTrackerAction::Rules,
+                        TelegramBotAdminTaskTrackerAction::Moves,
+                    ])
                     .row::<TelegramBotAdminTaskTrackerAction>(vec![
                         TelegramBotAdminTaskTrackerAction::Reconfigure,
                     ])
@@ -162,10 +256,10 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                 .await?;
             }
             Err(_) => {
-                Self::start_space_selection(
+                Self::start_kind_selection(
                     &bot,
                     &dialogue,
-                    &shared_dependency.task_tracker_client,
+                    &shared_dependency.task_tracker_client_resolver,
                     msg.chat().id,
                     msg.id(),
                     repository_id,
@@ -214,6 +308,17 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
 
                 match tracker {
                     Ok(t) => {
+                        let connection = match t.connection_id {
+                            Some(id) => shared_dependency
+                                .task_tracker_connection_repo
+                                .find_by_id(id)
+                                .await
+                                .map(|c| c.name)
+                                .unwrap_or_else(|_| id.to_string()),
+                            None => t!("telegram_bot.dialogues.admin.task_tracker.connection_env")
+                                .to_string(),
+                        };
+
                         let text = MessageBuilder::new()
                             .with_html_escape(true)
                             .bold(
@@ -221,14 +326,37 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                                     .as_ref(),
                             )
                             .empty_line()
+                            .section_code(
+                                t!("telegram_bot.dialogues.admin.task_tracker.kind").as_ref(),
+                                &t.kind.to_string(),
+                            )
+                            .section_code(
+                                t!("telegram_bot.dialogues.admin.task_tracker.connection").as_ref(),
+                                &connection,
+                            )
                             .section_code(
                                 t!("telegram_bot.dialogues.admin.task_tracker.space_id").as_ref(),
-                                &t.space_id.to_string(),
+                                &t.space_id,
                             )
                             .section_code(
                                 t!("telegram_bot.dialogues.admin.task_tracker.qa_column_id")
                                     .as_ref(),
-                                &t.qa_column_id.to_string(),
+                                &t.qa_column_id,
+                            )
+                            .section_code(
+                                t!("telegram_bot.dialogues.admin.task_tracker.qa_passed_column_id")
+                                    .as_ref(),
+                                t.qa_passed_column_id.as_deref().unwrap_or("—"),
+                            )
+                            .section_code(
+                                t!("telegram_bot.dialogues.admin.task_tracker.qa_failed_column_id")
+                                    .as_ref(),
+                                t.qa_failed_column_id.as_deref().unwrap_or("—"),
+                            )
+                            .section_code(
+                                t!("telegram_bot.dialogues.admin.task_tracker.issue_column_id")
+                                    .as_ref(),
+                                t.issue_column_id.as_deref().unwrap_or("—"),
                             )
                             .section_code(
                                 t!("telegram_bot.dialogues.admin.task_tracker.regex_pattern")
@@ -239,6 +367,16 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                                 t!("telegram_bot.dialogues.admin.task_tracker.card_path").as_ref(),
                                 &t.path_to_card,
                             )
+                            .section(
+                                t!("telegram_bot.dialogues.admin.task_tracker.require_task_id")
+                                    .as_ref(),
+                                if t.require_task_id {
+                                    t!("telegram_bot.dialogues.admin.task_tracker.enabled")
+                                } else {
+                                    t!("telegram_bot.dialogues.admin.task_tracker.disabled")
+                                }
+                                .as_ref(),
+                            )
                             .build();
 
                         bot.edit_message_text(msg.chat().id, msg.id(), text)
@@ -264,6 +402,9 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                         TelegramBotAdminTaskTrackerEditField::ExtractPattern,
                         TelegramBotAdminTaskTrackerEditField::Reconfigure,
                     ])
+                    .row::<TelegramBotAdminTaskTrackerEditField>(vec![
+                        TelegramBotAdminTaskTrackerEditField::RequireTaskId,
+                    ])
                     .build();
 
                 dialogue
@@ -283,15 +424,45 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                 .await?;
             }
             TelegramBotAdminTaskTrackerAction::Reconfigure => {
-                Self::start_space_selection(
+                Self::start_kind_selection(
                     &bot,
                     &dialogue,
-                    &shared_dependency.task_tracker_client,
+                    &shared_dependency.task_tracker_client_resolver,
+                    msg.chat().id,
+                    msg.id(),
+                    repository_id,
+                )
+                .await?;
+            }
+            TelegramBotAdminTaskTrackerAction::Rules => {
+                dialogue
+                    .update(TelegramBotDialogueState::Admin(
+                        TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleList {
+                            repository_id,
+                        },
+                    ))
+                    .await?;
+
+                TelegramBotDialogueAdminTaskTrackerRulesDispatcher::show_list(
+                    &bot,
+                    msg.chat().id,
+                    msg.id(),
+                    &executors,
+                    repository_id,
+                )
+                .await?;
+            }
+            TelegramBotAdminTaskTrackerAction::Moves => {
+                TelegramBotDialogueAdminTaskTrackerRulesDispatcher::show_moves(
+                    &bot,
                     msg.chat().id,
                     msg.id(),
+                    &executors,
                     repository_id,
                 )
                 .await?;
+
+                dialogue.exit().await.ok();
             }
         }
 
@@ -303,6 +474,7 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
     async fn handle_edit_select_field(
         bot: Bot,
         dialogue: TelegramBotDialogueType,
+        executors: Arc<ApplicationBoostrapExecutors>,
         shared_dependency: Arc<ApplicationSharedDependency>,
         query: CallbackQuery,
         repository_id: i32,
@@ -342,21 +514,101 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
                 .await?;
             }
             TelegramBotAdminTaskTrackerEditField::Reconfigure => {
-                Self::start_space_selection(
+                Self::start_kind_selection(
                     &bot,
                     &dialogue,
-                    &shared_dependency.task_tracker_client,
+                    &shared_dependency.task_tracker_client_resolver,
                     msg.chat().id,
                     msg.id(),
                     repository_id,
                 )
                 .await?;
             }
+            TelegramBotAdminTaskTrackerEditField::RequireTaskId => {
+                Self::toggle_require_task_id(
+                    &bot,
+                    &executors,
+                    msg.chat().id,
+                    msg.id(),
+                    repository_id,
+                )
+                .await?;
+                dialogue.exit().await.ok();
+            }
         }
 
         Ok(())
     }
 
+    /// Включает или выключает проверку ID задачи в PR
+    async fn toggle_require_task_id(
+        bot: &Bot,
+        executors: &ApplicationBoostrapExecutors,
+        chat_id: ChatId,
+        message_id: teloxide::types::MessageId,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let tracker = match executors
+            .commands
+            .update_repository_task_tracker
+            .task_tracker_repo
+            .find_by_repository_id(RepositoryId(repository_id))
+            .await
+        {
+            Ok(t) => t,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load task tracker for patch");
+                bot.edit_message_text(
+                    chat_id,
+                    message_id,
+                    t!("telegram_bot.dialogues.admin.task_tracker.load_error").to_string(),
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+
+        let require_task_id = !tracker.require_task_id;
+
+        let cmd = UpdateRepositoryTaskTrackerCommand {
+            repository_id: RepositoryId(repository_id),
+            space_id: tracker.space_id,
+            qa_column_id: tracker.qa_column_id,
+            extract_pattern_regexp: tracker.extract_pattern_regexp,
+            path_to_card: tracker.path_to_card,
+            kind: tracker.kind,
+            connection_id: tracker.connection_id,
+            require_task_id,
+            qa_passed_column_id: tracker.qa_passed_column_id,
+            qa_failed_column_id: tracker.qa_failed_column_id,
+            issue_column_id: tracker.issue_column_id,
+        };
+
+        let text = match executors
+            .commands
+            .update_repository_task_tracker
+            .execute(&cmd)
+            .await
+        {
+            Ok(_) if require_task_id => {
+                t!("telegram_bot.dialogues.admin.task_tracker.require_task_id_enabled").to_string()
+            }
+            Ok(_) => {
+                t!("telegram_bot.dialogues.admin.task_tracker.require_task_id_disabled").to_string()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to toggle task id requirement");
+                t!("telegram_bot.dialogues.admin.task_tracker.save_changes_error").to_string()
+            }
+        };
+
+        bot.edit_message_text(chat_id, message_id, text)
+            .reply_markup(InlineKeyboardMarkup::default())
+            .await?;
+
+        Ok(())
+    }
+
     async fn handle_edit_extract_pattern(
         bot: Bot,
         dialogue: TelegramBotDialogueType,
@@ -404,6 +656,12 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             qa_column_id: tracker.qa_column_id,
             extract_pattern_regexp: tracker.extract_pattern_regexp,
             path_to_card: tracker.path_to_card,
+            kind: tracker.kind,
+            connection_id: tracker.connection_id,
+            require_task_id: tracker.require_task_id,
+            qa_passed_column_id: tracker.qa_passed_column_id,
+            qa_failed_column_id: tracker.qa_failed_column_id,
+            issue_column_id: tracker.issue_column_id,
         };
 
         let loading = bot
@@ -442,8 +700,164 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
         Ok(())
     }
 
+    // ── Выбор трекера ────────────────────────────────────────────────────────
+
+    /// Если доступен один трекер — сразу переходим к выбору пространства.
+    async fn start_kind_selection(
+        bot: &Bot,
+        dialogue: &TelegramBotDialogueType,
+        task_tracker_client_resolver: &Arc<dyn TaskTrackerClientResolver>,
+        chat_id: ChatId,
+        message_id: teloxide::types::MessageId,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let sources = match task_tracker_client_resolver.sources().await {
+            Ok(s) => s,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load task tracker sources");
+                bot.edit_message_text(
+                    chat_id,
+                    message_id,
+                    t!("telegram_bot.dialogues.admin.task_tracker.load_error").to_string(),
+                )
+                .await?;
+                dialogue.exit().await.ok();
+                return Ok(());
+            }
+        };
+
+        if let [source] = sources.as_slice() {
+            let Ok(client) = task_tracker_client_resolver
+                .resolve(source.kind, source.connection_id)
+                .await
+            else {
+                return Ok(());
+            };
+
+            return Self::start_space_selection(
+                bot,
+                dialogue,
+                &client,
+                chat_id,
+                message_id,
+                repository_id,
+                source.kind,
+                source.connection_id,
+            )
+            .await;
+        }
+
+        let buttons: Vec<Vec<InlineKeyboardButton>> = sources
+            .into_iter()
+            .map(|source| {
+                let label = kind_label(source.kind);
+                let (label, data) = match (source.connection_id, source.name) {
+                    (Some(id), Some(name)) => {
+                        (format!("🔌 {} — {}", label, name), format!("conn:{}", id))
+                    }
+                    _ => (label, format!("env:{}", source.kind)),
+                };
+                vec![InlineKeyboardButton::callback(label, data)]
+            })
+            .collect();
+
+        dialogue
+            .update(TelegramBotDialogueState::Admin(
+                TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectKind { repository_id },
+            ))
+            .await?;
+
+        bot.edit_message_text(
+            chat_id,
+            message_id,
+            t!("telegram_bot.dialogues.admin.task_tracker.select_kind").to_string(),
+        )
+        .reply_markup(InlineKeyboardMarkup::new(buttons))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Callback: `env:<kind>` — трекер из окружения, `conn:<id>` — подключение
+    async fn handle_select_kind(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        shared_dependency: Arc<ApplicationSharedDependency>,
+        query: CallbackQuery,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        bot.answer_callback_query(query.id.clone()).await?;
+
+        let data = query.data.as_deref().unwrap_or("");
+        let (kind, connection_id) = match data.split_once(':') {
+            Some(("env", kind)) => match TaskTrackerKind::from_str(kind) {
+                Ok(kind) => (kind, None),
+                Err(_) => {
+                    tracing::error!(data = %data, "Invalid task tracker kind in callback");
+                    return Ok(());
+                }
+            },
+            Some(("conn", id)) => {
+                let connection = match id.parse::<i32>() {
+                    Ok(id) => shared_dependency
+                        .task_tracker_connection_repo
+                        .find_by_id(id)
+                        .await
+                        .ok(),
+                    Err(_) => None,
+                };
+                let Some(connection) = connection else {
+                    tracing::error!(data = %data, "Unknown task tracker connection in callback");
+                    return Ok(());
+                };
+                (connection.kind, Some(connection.id))
+            }
+            _ => {
+                tracing::error!(data = %data, "Invalid task tracker source in callback");
+                return Ok(());
+            }
+        };
+
+        let msg = match query.message {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        let client = match shared_dependency
+            .task_tracker_client_resolver
+            .resolve(kind, connection_id)
+            .await
+        {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to resolve task tracker client");
+                bot.edit_message_text(
+                    msg.chat().id,
+                    msg.id(),
+                    t!("telegram_bot.dialogues.admin.task_tracker.spaces_load_error").to_string(),
+                )
+                .await?;
+                dialogue.exit().await.ok();
+                return Ok(());
+            }
+        };
+
+        Self::start_space_selection(
+            &bot,
+            &dialogue,
+            &client,
+            msg.chat().id,
+            msg.id(),
+            repository_id,
+            kind,
+            connection_id,
+        )
+        .await
+    }
+
     // ── Интерактивный выбор через API ────────────────────────────────────────
 
+    #[allow(clippy::too_many_arguments)]
     async fn start_space_selection(
         bot: &Bot,
         dialogue: &TelegramBotDialogueType,
@@ -451,6 +865,8 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
         chat_id: ChatId,
         message_id: teloxide::types::MessageId,
         repository_id: i32,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let loading_msg = bot
             .edit_message_text(
@@ -494,7 +910,11 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
 
         dialogue
             .update(TelegramBotDialogueState::Admin(
-                TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectSpace { repository_id },
+                TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectSpace {
+                    repository_id,
+                    kind,
+                    connection_id,
+                },
             ))
             .await?;
 
@@ -514,19 +934,26 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
         dialogue: TelegramBotDialogueType,
         shared_dependency: Arc<ApplicationSharedDependency>,
         query: CallbackQuery,
-        repository_id: i32,
+        (repository_id, kind, connection_id): (i32, TaskTrackerKind, Option<i32>),
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         bot.answer_callback_query(query.id.clone()).await?;
 
-        let data = query.data.as_deref().unwrap_or("");
-        let space_id: i32 = match data.parse() {
-            Ok(v) => v,
-            Err(_) => {
-                tracing::error!(data = %data, "Invalid space_id in callback");
-                return Ok(());
-            }
+        let Ok(client) = shared_dependency
+            .task_tracker_client_resolver
+            .resolve(kind, connection_id)
+            .await
+        else {
+            dialogue.exit().await.ok();
+            return Ok(());
         };
 
+        let data = query.data.as_deref().unwrap_or("");
+        if data.is_empty() {
+            tracing::error!("Empty space_id in callback");
+            return Ok(());
+        }
+        let space_id = data.to_string();
+
         let msg = match query.message {
             Some(m) => m,
             None => return Ok(()),
@@ -541,14 +968,10 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             .reply_markup(InlineKeyboardMarkup::default())
             .await?;
 
-        let boards = match shared_dependency
-            .task_tracker_client
-            .list_boards(space_id)
-            .await
-        {
+        let boards = match client.list_boards(&space_id).await {
             Ok(b) => b,
             Err(e) => {
-                tracing::error!(error = %e, space_id = space_id, "Failed to load boards");
+                tracing::error!(error = %e, space_id = %space_id, "Failed to load boards");
                 bot.edit_message_text(
                     msg.chat().id,
                     loading.id,
@@ -580,6 +1003,8 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             .update(TelegramBotDialogueState::Admin(
                 TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectBoard {
                     repository_id,
+                    kind,
+                    connection_id,
                     space_id,
                 },
             ))
@@ -601,19 +1026,26 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
         dialogue: TelegramBotDialogueType,
         shared_dependency: Arc<ApplicationSharedDependency>,
         query: CallbackQuery,
-        (repository_id, space_id): (i32, i32),
+        (repository_id, kind, connection_id, space_id): (i32, TaskTrackerKind, Option<i32>, String),
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         bot.answer_callback_query(query.id.clone()).await?;
 
-        let data = query.data.as_deref().unwrap_or("");
-        let board_id: i32 = match data.parse() {
-            Ok(v) => v,
-            Err(_) => {
-                tracing::error!(data = %data, "Invalid board_id in callback");
-                return Ok(());
-            }
+        let Ok(client) = shared_dependency
+            .task_tracker_client_resolver
+            .resolve(kind, connection_id)
+            .await
+        else {
+            dialogue.exit().await.ok();
+            return Ok(());
         };
 
+        let data = query.data.as_deref().unwrap_or("");
+        if data.is_empty() {
+            tracing::error!("Empty board_id in callback");
+            return Ok(());
+        }
+        let board_id = data.to_string();
+
         let msg = match query.message {
             Some(m) => m,
             None => return Ok(()),
@@ -628,14 +1060,10 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             .reply_markup(InlineKeyboardMarkup::default())
             .await?;
 
-        let columns = match shared_dependency
-            .task_tracker_client
-            .list_columns(board_id)
-            .await
-        {
+        let columns = match client.list_columns(&board_id).await {
             Ok(c) => c,
             Err(e) => {
-                tracing::error!(error = %e, board_id = board_id, "Failed to load columns");
+                tracing::error!(error = %e, board_id = %board_id, "Failed to load columns");
                 bot.edit_message_text(
                     msg.chat().id,
                     loading.id,
@@ -667,6 +1095,8 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             .update(TelegramBotDialogueState::Admin(
                 TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectColumn {
                     repository_id,
+                    kind,
+                    connection_id,
                     space_id,
                     board_id,
                 },
@@ -687,18 +1117,237 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
     async fn handle_select_column(
         bot: Bot,
         dialogue: TelegramBotDialogueType,
+        shared_dependency: Arc<ApplicationSharedDependency>,
         query: CallbackQuery,
-        (repository_id, space_id, _board_id): (i32, i32, i32),
+        (repository_id, kind, connection_id, space_id, board_id): (
+            i32,
+            TaskTrackerKind,
+            Option<i32>,
+            String,
+            String,
+        ),
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         bot.answer_callback_query(query.id.clone()).await?;
 
         let data = query.data.as_deref().unwrap_or("");
-        let qa_column_id: i32 = match data.parse() {
-            Ok(v) => v,
-            Err(_) => {
-                tracing::error!(data = %data, "Invalid column_id in callback");
+        if data.is_empty() {
+            tracing::error!("Empty column_id in callback");
+            return Ok(());
+        }
+        let qa_column_id = data.to_string();
+
+        let msg = match query.message {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        let Some(buttons) = Self::optional_column_buttons(
+            &bot,
+            &shared_dependency,
+            (msg.chat().id, msg.id()),
+            (kind, connection_id),
+            &board_id,
+        )
+        .await?
+        else {
+            dialogue.exit().await.ok();
+            return Ok(());
+        };
+
+        dialogue
+            .update(TelegramBotDialogueState::Admin(
+                TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectPassedColumn {
+                    repository_id,
+                    kind,
+                    connection_id,
+                    space_id,
+                    board_id,
+                    qa_column_id,
+                },
+            ))
+            .await?;
+
+        bot.edit_message_text(
+            msg.chat().id,
+            msg.id(),
+            t!("telegram_bot.dialogues.admin.task_tracker.select_qa_passed_column").to_string(),
+        )
+        .reply_markup(InlineKeyboardMarkup::new(buttons))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn handle_select_passed_column(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        shared_dependency: Arc<ApplicationSharedDependency>,
+        query: CallbackQuery,
+        (repository_id, kind, connection_id, space_id, board_id, qa_column_id): (
+            i32,
+            TaskTrackerKind,
+            Option<i32>,
+            String,
+            String,
+            String,
+        ),
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        bot.answer_callback_query(query.id.clone()).await?;
+
+        let qa_passed_column_id = match query.data.as_deref() {
+            None | Some("") => {
+                tracing::error!("Empty column_id in callback");
+                return Ok(());
+            }
+            Some(SKIP_COLUMN) => None,
+            Some(id) => Some(id.to_string()),
+        };
+
+        let msg = match query.message {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        let Some(buttons) = Self::optional_column_buttons(
+            &bot,
+            &shared_dependency,
+            (msg.chat().id, msg.id()),
+            (kind, connection_id),
+            &board_id,
+        )
+        .await?
+        else {
+            dialogue.exit().await.ok();
+            return Ok(());
+        };
+
+        dialogue
+            .update(TelegramBotDialogueState::Admin(
+                TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectFailedColumn {
+                    repository_id,
+                    kind,
+                    connection_id,
+                    space_id,
+                    board_id,
+                    qa_column_id,
+                    qa_passed_column_id,
+                },
+            ))
+            .await?;
+
+        bot.edit_message_text(
+            msg.chat().id,
+            msg.id(),
+            t!("telegram_bot.dialogues.admin.task_tracker.select_qa_failed_column").to_string(),
+        )
+        .reply_markup(InlineKeyboardMarkup::new(buttons))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn handle_select_failed_column(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        shared_dependency: Arc<ApplicationSharedDependency>,
+        query: CallbackQuery,
+        (
+            repository_id,
+            kind,
+            connection_id,
+            space_id,
+            board_id,
+            qa_column_id,
+            qa_passed_column_id,
+        ): (
+            i32,
+            TaskTrackerKind,
+            Option<i32>,
+            String,
+            String,
+            String,
+            Option<String>,
+        ),
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        bot.answer_callback_query(query.id.clone()).await?;
+
+        let qa_failed_column_id = match query.data.as_deref() {
+            None | Some("") => {
+                tracing::error!("Empty column_id in callback");
                 return Ok(());
             }
+            Some(SKIP_COLUMN) => None,
+            Some(id) => Some(id.to_string()),
+        };
+
+        let msg = match query.message {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        let Some(buttons) = Self::optional_column_buttons(
+            &bot,
+            &shared_dependency,
+            (msg.chat().id, msg.id()),
+            (kind, connection_id),
+            &board_id,
+        )
+        .await?
+        else {
+            dialogue.exit().await.ok();
+            return Ok(());
+        };
+
+        dialogue
+            .update(TelegramBotDialogueState::Admin(
+                TelegramBotDialogueAdminState::ConfigureTaskTrackerSelectIssueColumn {
+                    repository_id,
+                    kind,
+                    connection_id,
+                    space_id,
+                    board_id,
+                    qa_column_id,
+                    qa_passed_column_id,
+                    qa_failed_column_id,
+                },
+            ))
+            .await?;
+
+        bot.edit_message_text(
+            msg.chat().id,
+            msg.id(),
+            t!("telegram_bot.dialogues.admin.task_tracker.select_issue_column").to_string(),
+        )
+        .reply_markup(InlineKeyboardMarkup::new(buttons))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn handle_select_issue_column(
+        bot: Bot,
+        dialogue: TelegramBotDialogueType,
+        query: CallbackQuery,
+        (
+            repository_id,
+            kind,
+            connection_id,
+            space_id,
+            _board_id,
+            qa_column_id,
+            qa_passed_column_id,
+            qa_failed_column_id,
+        ): SelectIssueColumnState,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        bot.answer_callback_query(query.id.clone()).await?;
+
+        let issue_column_id = match query.data.as_deref() {
+            None | Some("") => {
+                tracing::error!("Empty column_id in callback");
+                return Ok(());
+            }
+            Some(SKIP_COLUMN) => None,
+            Some(id) => Some(id.to_string()),
         };
 
         let msg = match query.message {
@@ -710,8 +1359,13 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             .update(TelegramBotDialogueState::Admin(
                 TelegramBotDialogueAdminState::ConfigureTaskTrackerEnterPattern {
                     repository_id,
+                    kind,
+                    connection_id,
                     space_id,
                     qa_column_id,
+                    qa_passed_column_id,
+                    qa_failed_column_id,
+                    issue_column_id,
                 },
             ))
             .await?;
@@ -727,6 +1381,55 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
         Ok(())
     }
 
+    /// Колонки доски для необязательных шагов и кнопка «пропустить».
+    /// `None` — колонок нет или трекер не ответил, сообщение уже отредактировано
+    async fn optional_column_buttons(
+        bot: &Bot,
+        shared_dependency: &ApplicationSharedDependency,
+        (chat_id, message_id): (ChatId, MessageId),
+        (kind, connection_id): (TaskTrackerKind, Option<i32>),
+        board_id: &str,
+    ) -> Result<Option<Vec<Vec<InlineKeyboardButton>>>, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let columns = match shared_dependency
+            .task_tracker_client_resolver
+            .resolve(kind, connection_id)
+            .await
+        {
+            Ok(client) => client
+                .list_columns(board_id)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        let text = match columns {
+            Ok(columns) if !columns.is_empty() => {
+                let mut buttons: Vec<Vec<InlineKeyboardButton>> = columns
+                    .into_iter()
+                    .map(|c| vec![InlineKeyboardButton::callback(c.title, c.id.to_string())])
+                    .collect();
+                buttons.push(vec![InlineKeyboardButton::callback(
+                    t!("telegram_bot.dialogues.admin.task_tracker.skip_column").to_string(),
+                    SKIP_COLUMN,
+                )]);
+
+                return Ok(Some(buttons));
+            }
+            Ok(_) => t!("telegram_bot.dialogues.admin.task_tracker.no_columns"),
+            Err(e) => {
+                tracing::error!(error = %e, board_id = %board_id, "Failed to load columns");
+                t!("telegram_bot.dialogues.admin.task_tracker.columns_load_error")
+            }
+        };
+
+        bot.edit_message_text(chat_id, message_id, text.to_string())
+            .reply_markup(InlineKeyboardMarkup::default())
+            .await?;
+
+        Ok(None)
+    }
+
     // ── Ввод паттерна и финальное сохранение ─────────────────────────────────
 
     async fn handle_enter_pattern(
@@ -734,7 +1437,16 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
         dialogue: TelegramBotDialogueType,
         executors: Arc<ApplicationBoostrapExecutors>,
         msg: Message,
-        (repository_id, space_id, qa_column_id): (i32, i32, i32),
+        (
+            repository_id,
+            kind,
+            connection_id,
+            space_id,
+            qa_column_id,
+            qa_passed_column_id,
+            qa_failed_column_id,
+            issue_column_id,
+        ): EnterPatternState,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let extract_pattern = match extract_text(&msg) {
             Some(v) => v,
@@ -748,7 +1460,22 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             }
         };
 
-        let path_to_card = format!("/space/{}/boards/card/{{id}}", space_id);
+        let path_to_card = match kind {
+            TaskTrackerKind::Kaiten => format!("/space/{}/boards/card/{{id}}", space_id),
+            TaskTrackerKind::Jira => "/browse/{id}".to_string(),
+            TaskTrackerKind::Linear | TaskTrackerKind::YouTrack => "/issue/{id}".to_string(),
+            TaskTrackerKind::GithubProjects => "/issues/{id}".to_string(),
+        };
+
+        // Перенастройка не сбрасывает проверку ID задачи
+        let require_task_id = executors
+            .commands
+            .update_repository_task_tracker
+            .task_tracker_repo
+            .find_by_repository_id(RepositoryId(repository_id))
+            .await
+            .map(|t| t.require_task_id)
+            .unwrap_or(false);
 
         let cmd = UpdateRepositoryTaskTrackerCommand {
             repository_id: RepositoryId(repository_id),
@@ -756,6 +1483,12 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
             qa_column_id,
             extract_pattern_regexp: extract_pattern,
             path_to_card,
+            kind,
+            connection_id,
+            require_task_id,
+            qa_passed_column_id,
+            qa_failed_column_id,
+            issue_column_id,
         };
 
         let loading = bot
@@ -794,3 +1527,17 @@ impl TelegramBotDialogueAdminTaskTrackerDispatcher {
         Ok(())
     }
 }
+
+/// Подпись трекера на кнопках — общая с экраном подключений
+pub(crate) fn kind_label(kind: TaskTrackerKind) -> String {
+    match kind {
+        TaskTrackerKind::Kaiten => t!("telegram_bot.dialogues.admin.task_tracker.kind_kaiten"),
+        TaskTrackerKind::Jira => t!("telegram_bot.dialogues.admin.task_tracker.kind_jira"),
+        TaskTrackerKind::Linear => t!("telegram_bot.dialogues.admin.task_tracker.kind_linear"),
+        TaskTrackerKind::YouTrack => t!("telegram_bot.dialogues.admin.task_tracker.kind_youtrack"),
+        TaskTrackerKind::GithubProjects => {
+            t!("telegram_bot.dialogues.admin.task_tracker.kind_github_projects")
+        }
+    }
+    .to_string()
+}
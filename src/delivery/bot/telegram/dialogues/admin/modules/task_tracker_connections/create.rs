@@ -0,0 +1,331 @@
+use crate::application::task::commands::create_task_tracker_connection::command::CreateTaskTrackerConnectionCommand;
+use crate::application::task::commands::create_task_tracker_connection::error::CreateTaskTrackerConnectionExecutorError;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
+use crate::delivery::bot::telegram::dialogues::admin::helpers::extract_text;
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::utils::builder::message::MessageBuilder;
+use std::str::FromStr;
+use std::sync::Arc;
+use teloxide::dispatching::DpHandlerDescription;
+use teloxide::dptree::case;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+use teloxide::{Bot, dptree};
+
+pub struct TelegramBotDialogueAdminTaskTrackerConnectionCreateDispatcher;
+
+impl TelegramBotDialogueAdminTaskTrackerConnectionCreateDispatcher {
+    pub fn query_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry().branch(
+            case![TelegramBotDialogueAdminState::TaskTrackerConnectionCreateKind]
+                .endpoint(handle_create_kind),
+        )
+    }
+
+    pub fn message_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry()
+            .branch(
+                case![TelegramBotDialogueAdminState::TaskTrackerConnectionCreateName { kind }]
+                    .endpoint(handle_create_name),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::TaskTrackerConnectionCreateBaseUrl {
+                        kind,
+                        name
+                    }
+                ]
+                .endpoint(handle_create_base_url),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::TaskTrackerConnectionCreateEmail {
+                        kind,
+                        name,
+                        base_url
+                    }
+                ]
+                .endpoint(handle_create_email),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::TaskTrackerConnectionCreateToken {
+                        kind,
+                        name,
+                        base_url,
+                        email
+                    }
+                ]
+                .endpoint(handle_create_token),
+            )
+    }
+}
+
+async fn handle_create_kind(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    query: CallbackQuery,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+    let kind = match TaskTrackerKind::from_str(data) {
+        Ok(k) => k,
+        Err(_) => {
+            tracing::error!(data = %data, "Invalid task tracker kind in callback");
+            return Ok(());
+        }
+    };
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::TaskTrackerConnectionCreateName { kind },
+        ))
+        .await?;
+
+    bot.send_message(
+        msg.chat().id,
+        t!("telegram_bot.dialogues.admin.task_tracker_connections.enter_name").to_string(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_create_name(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    msg: Message,
+    kind: TaskTrackerKind,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let name = match extract_text(&msg) {
+        Some(t) => t,
+        None => {
+            bot.send_message(
+                msg.chat.id,
+                t!("telegram_bot.dialogues.admin.task_tracker_connections.name_required")
+                    .to_string(),
+            )
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::TaskTrackerConnectionCreateBaseUrl { kind, name },
+        ))
+        .await?;
+
+    bot.send_message(
+        msg.chat.id,
+        t!("telegram_bot.dialogues.admin.task_tracker_connections.enter_base_url").to_string(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_create_base_url(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    msg: Message,
+    (kind, name): (TaskTrackerKind, String),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let base_url = match extract_text(&msg) {
+        Some(t) if t.starts_with("http://") || t.starts_with("https://") => t,
+        _ => {
+            bot.send_message(
+                msg.chat.id,
+                t!("telegram_bot.dialogues.admin.task_tracker_connections.base_url_required")
+                    .to_string(),
+            )
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    // Email нужен только Jira Cloud — остальные трекеры работают по токену
+    if kind == TaskTrackerKind::Jira {
+        dialogue
+            .update(TelegramBotDialogueState::Admin(
+                TelegramBotDialogueAdminState::TaskTrackerConnectionCreateEmail {
+                    kind,
+                    name,
+                    base_url,
+                },
+            ))
+            .await?;
+
+        bot.send_message(
+            msg.chat.id,
+            t!("telegram_bot.dialogues.admin.task_tracker_connections.enter_email").to_string(),
+        )
+        .await?;
+
+        return Ok(());
+    }
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::TaskTrackerConnectionCreateToken {
+                kind,
+                name,
+                base_url,
+                email: None,
+            },
+        ))
+        .await?;
+
+    bot.send_message(
+        msg.chat.id,
+        t!("telegram_bot.dialogues.admin.task_tracker_connections.enter_token").to_string(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_create_email(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    msg: Message,
+    (kind, name, base_url): (TaskTrackerKind, String, String),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let email = match extract_text(&msg) {
+        Some(t) if t == "-" => None,
+        Some(t) => Some(t),
+        None => {
+            bot.send_message(
+                msg.chat.id,
+                t!("telegram_bot.dialogues.admin.task_tracker_connections.email_required")
+                    .to_string(),
+            )
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::TaskTrackerConnectionCreateToken {
+                kind,
+                name,
+                base_url,
+                email,
+            },
+        ))
+        .await?;
+
+    bot.send_message(
+        msg.chat.id,
+        t!("telegram_bot.dialogues.admin.task_tracker_connections.enter_token").to_string(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_create_token(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    msg: Message,
+    (kind, name, base_url, email): (TaskTrackerKind, String, String, Option<String>),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let api_token = match extract_text(&msg) {
+        Some(t) => t,
+        None => {
+            bot.send_message(
+                msg.chat.id,
+                t!("telegram_bot.dialogues.admin.task_tracker_connections.token_required")
+                    .to_string(),
+            )
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    // Токен не должен оставаться в истории чата
+    bot.delete_message(msg.chat.id, msg.id).await.ok();
+
+    let loading = bot
+        .send_message(
+            msg.chat.id,
+            t!("telegram_bot.dialogues.admin.task_tracker_connections.checking").to_string(),
+        )
+        .await?;
+
+    let cmd = CreateTaskTrackerConnectionCommand {
+        kind,
+        name,
+        base_url,
+        email,
+        api_token,
+    };
+
+    let reply = match executors
+        .commands
+        .create_task_tracker_connection
+        .execute(&cmd)
+        .await
+    {
+        Ok(response) => {
+            let mut reply = t!(
+                "telegram_bot.dialogues.admin.task_tracker_connections.created",
+                name = MessageBuilder::escape_html(&response.connection.name)
+            )
+            .to_string();
+
+            // Вебхуки Kaiten приходят на общий адрес — подключение различаем по параметру
+            if response.connection.kind == TaskTrackerKind::Kaiten {
+                reply.push_str(&t!(
+                    "telegram_bot.dialogues.admin.task_tracker_connections.kaiten_webhook_hint",
+                    id = response.connection.id
+                ));
+            }
+
+            reply
+        }
+
+        Err(CreateTaskTrackerConnectionExecutorError::InvalidCredentials(error)) => {
+            tracing::warn!(error = %error, "Task tracker rejected connection credentials");
+            t!(
+                "telegram_bot.dialogues.admin.task_tracker_connections.invalid_credentials",
+                error = MessageBuilder::escape_html(&error)
+            )
+            .to_string()
+        }
+
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to create task tracker connection");
+            t!("telegram_bot.dialogues.admin.task_tracker_connections.create_error").to_string()
+        }
+    };
+
+    bot.edit_message_text(msg.chat.id, loading.id, reply)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    dialogue.exit().await.ok();
+
+    Ok(())
+}
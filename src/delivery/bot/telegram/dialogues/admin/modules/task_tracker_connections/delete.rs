@@ -0,0 +1,149 @@
+use crate::application::task::commands::delete_task_tracker_connection::command::DeleteTaskTrackerConnectionCommand;
+use crate::application::task::commands::delete_task_tracker_connection::error::DeleteTaskTrackerConnectionExecutorError;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::confirm::TelegramBotConfirmAction;
+use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
+use crate::domain::shared::command::CommandExecutor;
+use std::sync::Arc;
+use teloxide::dispatching::DpHandlerDescription;
+use teloxide::dptree::case;
+use teloxide::prelude::*;
+use teloxide::{Bot, dptree};
+
+pub struct TelegramBotDialogueAdminTaskTrackerConnectionDeleteDispatcher;
+
+impl TelegramBotDialogueAdminTaskTrackerConnectionDeleteDispatcher {
+    pub fn query_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry()
+            .branch(
+                case![TelegramBotDialogueAdminState::TaskTrackerConnectionDeleteSelect]
+                    .endpoint(handle_delete_select),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::TaskTrackerConnectionDeleteConfirm {
+                        connection_id
+                    }
+                ]
+                .endpoint(handle_delete_confirm),
+            )
+    }
+}
+
+async fn handle_delete_select(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    query: CallbackQuery,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+    let connection_id: i32 = match data.parse() {
+        Ok(v) => v,
+        Err(_) => {
+            tracing::error!(data = %data, "Invalid connection_id in callback");
+            return Ok(());
+        }
+    };
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::TaskTrackerConnectionDeleteConfirm { connection_id },
+        ))
+        .await?;
+
+    let keyboard = KeyboardBuilder::new()
+        .row::<TelegramBotConfirmAction>(vec![
+            TelegramBotConfirmAction::Yes,
+            TelegramBotConfirmAction::No,
+        ])
+        .build();
+
+    bot.send_message(
+        msg.chat().id,
+        t!("telegram_bot.dialogues.admin.task_tracker_connections.confirm_delete").to_string(),
+    )
+    .reply_markup(keyboard)
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_delete_confirm(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+    connection_id: i32,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let action = match TelegramBotConfirmAction::from_callback_data(data) {
+        Ok(a) => a,
+        Err(_) => {
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    match action {
+        TelegramBotConfirmAction::Yes => {
+            let cmd = DeleteTaskTrackerConnectionCommand { id: connection_id };
+
+            let reply = match executors
+                .commands
+                .delete_task_tracker_connection
+                .execute(&cmd)
+                .await
+            {
+                Ok(_) => {
+                    t!("telegram_bot.dialogues.admin.task_tracker_connections.deleted").to_string()
+                }
+
+                Err(DeleteTaskTrackerConnectionExecutorError::InUse) => {
+                    t!("telegram_bot.dialogues.admin.task_tracker_connections.delete_in_use")
+                        .to_string()
+                }
+
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to delete task tracker connection");
+                    t!("telegram_bot.dialogues.admin.task_tracker_connections.delete_error")
+                        .to_string()
+                }
+            };
+
+            bot.send_message(msg.chat().id, reply).await?;
+        }
+
+        TelegramBotConfirmAction::No => {
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.common.cancelled").to_string(),
+            )
+            .await?;
+        }
+    }
+
+    dialogue.exit().await.ok();
+
+    Ok(())
+}
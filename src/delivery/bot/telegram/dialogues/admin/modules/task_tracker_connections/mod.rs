@@ -0,0 +1,239 @@
+mod create;
+mod delete;
+
+use crate::application::task::queries::get_all_task_tracker_connections::query::GetAllTaskTrackerConnectionsQuery;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
+use crate::delivery::bot::telegram::dialogues::admin::modules::task_tracker::kind_label;
+use crate::delivery::bot::telegram::dialogues::admin::modules::task_tracker_connections::create::TelegramBotDialogueAdminTaskTrackerConnectionCreateDispatcher;
+use crate::delivery::bot::telegram::dialogues::admin::modules::task_tracker_connections::delete::TelegramBotDialogueAdminTaskTrackerConnectionDeleteDispatcher;
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::admin_task_tracker_connection::TelegramBotAdminTaskTrackerConnectionAction;
+use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::utils::builder::message::MessageBuilder;
+use std::sync::Arc;
+use teloxide::dispatching::DpHandlerDescription;
+use teloxide::dptree::case;
+use teloxide::payloads::{EditMessageTextSetters, SendMessageSetters};
+use teloxide::prelude::*;
+use teloxide::types::{ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId, ParseMode};
+use teloxide::{Bot, dptree};
+
+pub struct TelegramBotDialogueAdminTaskTrackerConnectionsDispatcher;
+
+impl TelegramBotDialogueAdminTaskTrackerConnectionsDispatcher {
+    pub fn query_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry()
+            .branch(
+                case![TelegramBotDialogueAdminState::TaskTrackerConnectionList]
+                    .endpoint(handle_list_action),
+            )
+            .branch(TelegramBotDialogueAdminTaskTrackerConnectionCreateDispatcher::query_branches())
+            .branch(TelegramBotDialogueAdminTaskTrackerConnectionDeleteDispatcher::query_branches())
+    }
+
+    pub fn message_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry().branch(
+            TelegramBotDialogueAdminTaskTrackerConnectionCreateDispatcher::message_branches(),
+        )
+    }
+
+    pub async fn show_list(
+        bot: &Bot,
+        chat_id: ChatId,
+        message_id: MessageId,
+        executors: &ApplicationBoostrapExecutors,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let connections = executors
+            .queries
+            .get_all_task_tracker_connections
+            .execute(&GetAllTaskTrackerConnectionsQuery)
+            .await;
+
+        let connections = match connections {
+            Ok(r) => r.connections,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to get task tracker connections");
+
+                bot.edit_message_text(
+                    chat_id,
+                    message_id,
+                    t!("telegram_bot.dialogues.admin.task_tracker_connections.load_error")
+                        .to_string(),
+                )
+                .await?;
+
+                return Ok(());
+            }
+        };
+
+        let mut builder = MessageBuilder::new()
+            .bold(t!("telegram_bot.dialogues.admin.task_tracker_connections.title").as_ref())
+            .empty_line()
+            .line(t!("telegram_bot.dialogues.admin.task_tracker_connections.description").as_ref())
+            .empty_line();
+
+        if connections.is_empty() {
+            builder = builder
+                .line(t!("telegram_bot.dialogues.admin.task_tracker_connections.empty").as_ref());
+        } else {
+            for connection in &connections {
+                let line = format!(
+                    "🔌 <b>{}</b> — {} ({})",
+                    MessageBuilder::escape_html(&connection.name),
+                    kind_label(connection.kind),
+                    MessageBuilder::escape_html(&connection.base_url),
+                );
+
+                builder = builder.raw(&line).raw("\n");
+            }
+        }
+
+        let text = builder.build();
+
+        let mut keyboard = KeyboardBuilder::new()
+            .row::<TelegramBotAdminTaskTrackerConnectionAction>(vec![
+                TelegramBotAdminTaskTrackerConnectionAction::Create,
+            ]);
+
+        if !connections.is_empty() {
+            keyboard = keyboard.row::<TelegramBotAdminTaskTrackerConnectionAction>(vec![
+                TelegramBotAdminTaskTrackerConnectionAction::Delete,
+            ]);
+        }
+
+        keyboard = keyboard.row::<TelegramBotAdminTaskTrackerConnectionAction>(vec![
+            TelegramBotAdminTaskTrackerConnectionAction::Cancel,
+        ]);
+
+        bot.edit_message_text(chat_id, message_id, text)
+            .parse_mode(ParseMode::Html)
+            .reply_markup(keyboard.build())
+            .await?;
+
+        Ok(())
+    }
+}
+
+async fn handle_list_action(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let action = match TelegramBotAdminTaskTrackerConnectionAction::from_callback_data(data) {
+        Ok(a) => a,
+        Err(_) => {
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.common.cancelled").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    match action {
+        TelegramBotAdminTaskTrackerConnectionAction::Create => {
+            let rows: Vec<Vec<InlineKeyboardButton>> = TaskTrackerKind::ALL
+                .into_iter()
+                .map(|kind| {
+                    vec![InlineKeyboardButton::callback(
+                        kind_label(kind),
+                        kind.to_string(),
+                    )]
+                })
+                .collect();
+
+            dialogue
+                .update(TelegramBotDialogueState::Admin(
+                    TelegramBotDialogueAdminState::TaskTrackerConnectionCreateKind,
+                ))
+                .await?;
+
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.dialogues.admin.task_tracker_connections.select_kind").to_string(),
+            )
+            .reply_markup(InlineKeyboardMarkup::new(rows))
+            .await?;
+        }
+
+        TelegramBotAdminTaskTrackerConnectionAction::Delete => {
+            let connections = executors
+                .queries
+                .get_all_task_tracker_connections
+                .execute(&GetAllTaskTrackerConnectionsQuery)
+                .await
+                .map(|r| r.connections)
+                .unwrap_or_default();
+
+            if connections.is_empty() {
+                bot.send_message(
+                    msg.chat().id,
+                    t!("telegram_bot.dialogues.admin.task_tracker_connections.empty").to_string(),
+                )
+                .await?;
+
+                dialogue.exit().await.ok();
+                return Ok(());
+            }
+
+            let rows: Vec<Vec<InlineKeyboardButton>> = connections
+                .iter()
+                .map(|c| {
+                    vec![InlineKeyboardButton::callback(
+                        c.name.clone(),
+                        c.id.to_string(),
+                    )]
+                })
+                .collect();
+
+            dialogue
+                .update(TelegramBotDialogueState::Admin(
+                    TelegramBotDialogueAdminState::TaskTrackerConnectionDeleteSelect,
+                ))
+                .await?;
+
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.dialogues.admin.task_tracker_connections.select_for_delete")
+                    .to_string(),
+            )
+            .reply_markup(InlineKeyboardMarkup::new(rows))
+            .await?;
+        }
+
+        TelegramBotAdminTaskTrackerConnectionAction::Cancel => {
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.common.cancelled").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,780 @@
+use crate::application::repository::commands::create_repository_task_tracker_rule::command::CreateRepositoryTaskTrackerRuleCommand;
+use crate::application::repository::commands::delete_repository_task_tracker_rule::command::DeleteRepositoryTaskTrackerRuleCommand;
+use crate::application::repository::queries::get_repository_task_tracker_rules::query::GetRepositoryTaskTrackerRulesQuery;
+use crate::application::task::queries::get_recent_task_moves::query::GetRecentTaskMovesQuery;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::bootstrap::shared_dependency::ApplicationSharedDependency;
+use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
+use crate::delivery::bot::telegram::dialogues::admin::helpers::extract_text;
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::admin_task_tracker_rule::TelegramBotAdminTaskTrackerRuleAction;
+use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
+use crate::domain::repository::value_objects::pull_request_lifecycle_trigger::PullRequestLifecycleTrigger;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::value_objects::task_move_trigger::TaskMoveTrigger;
+use crate::utils::builder::message::MessageBuilder;
+use std::str::FromStr;
+use std::sync::Arc;
+use teloxide::dispatching::DpHandlerDescription;
+use teloxide::dptree::case;
+use teloxide::prelude::*;
+use teloxide::types::{ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId, ParseMode};
+use teloxide::{Bot, dptree};
+
+/// Сколько последних переводов показывать в журнале
+const MOVES_LIMIT: u64 = 15;
+
+pub struct TelegramBotDialogueAdminTaskTrackerRulesDispatcher;
+
+impl TelegramBotDialogueAdminTaskTrackerRulesDispatcher {
+    pub fn query_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry()
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleList { repository_id }
+                ]
+                .endpoint(handle_list_action),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleSelectTrigger {
+                        repository_id
+                    }
+                ]
+                .endpoint(handle_select_trigger),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleSelectBoard {
+                        repository_id,
+                        trigger
+                    }
+                ]
+                .endpoint(handle_select_board),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleSelectColumn {
+                        repository_id,
+                        trigger
+                    }
+                ]
+                .endpoint(handle_select_column),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleDeleteSelect {
+                        repository_id
+                    }
+                ]
+                .endpoint(handle_delete_select),
+            )
+    }
+
+    pub fn message_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry().branch(
+            case![
+                TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleEnterBranch {
+                    repository_id,
+                    trigger,
+                    column_id
+                }
+            ]
+            .endpoint(handle_enter_branch),
+        )
+    }
+
+    pub async fn show_list(
+        bot: &Bot,
+        chat_id: ChatId,
+        message_id: MessageId,
+        executors: &ApplicationBoostrapExecutors,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let rules = executors
+            .queries
+            .get_repository_task_tracker_rules
+            .execute(&GetRepositoryTaskTrackerRulesQuery {
+                repository_id: RepositoryId(repository_id),
+            })
+            .await;
+
+        let rules = match rules {
+            Ok(r) => r.rules,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to get task tracker rules");
+
+                bot.edit_message_text(
+                    chat_id,
+                    message_id,
+                    t!("telegram_bot.dialogues.admin.task_tracker_rules.load_error").to_string(),
+                )
+                .await?;
+
+                return Ok(());
+            }
+        };
+
+        let mut builder = MessageBuilder::new()
+            .bold(t!("telegram_bot.dialogues.admin.task_tracker_rules.title").as_ref())
+            .empty_line()
+            .line(t!("telegram_bot.dialogues.admin.task_tracker_rules.description").as_ref())
+            .empty_line();
+
+        if rules.is_empty() {
+            builder =
+                builder.line(t!("telegram_bot.dialogues.admin.task_tracker_rules.empty").as_ref());
+        } else {
+            for rule in &rules {
+                let line = format!(
+                    "• {} → <code>{}</code> · {}\n",
+                    trigger_label(rule.trigger),
+                    MessageBuilder::escape_html(&rule.column_id),
+                    branch_label(rule.base_branch_pattern.as_deref()),
+                );
+
+                builder = builder.raw(&line);
+            }
+        }
+
+        let mut keyboard =
+            KeyboardBuilder::new().row::<TelegramBotAdminTaskTrackerRuleAction>(vec![
+                TelegramBotAdminTaskTrackerRuleAction::Add,
+            ]);
+
+        if !rules.is_empty() {
+            keyboard = keyboard.row::<TelegramBotAdminTaskTrackerRuleAction>(vec![
+                TelegramBotAdminTaskTrackerRuleAction::Delete,
+            ]);
+        }
+
+        keyboard = keyboard.row::<TelegramBotAdminTaskTrackerRuleAction>(vec![
+            TelegramBotAdminTaskTrackerRuleAction::Cancel,
+        ]);
+
+        bot.edit_message_text(chat_id, message_id, builder.build())
+            .parse_mode(ParseMode::Html)
+            .reply_markup(keyboard.build())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn show_moves(
+        bot: &Bot,
+        chat_id: ChatId,
+        message_id: MessageId,
+        executors: &ApplicationBoostrapExecutors,
+        repository_id: i32,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let moves = executors
+            .queries
+            .get_recent_task_moves
+            .execute(&GetRecentTaskMovesQuery {
+                repository_id: RepositoryId(repository_id),
+                limit: MOVES_LIMIT,
+            })
+            .await;
+
+        let moves = match moves {
+            Ok(r) => r.moves,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to get task moves");
+
+                bot.edit_message_text(
+                    chat_id,
+                    message_id,
+                    t!("telegram_bot.dialogues.admin.task_tracker_rules.moves_load_error")
+                        .to_string(),
+                )
+                .reply_markup(InlineKeyboardMarkup::default())
+                .await?;
+
+                return Ok(());
+            }
+        };
+
+        let mut builder = MessageBuilder::new()
+            .bold(t!("telegram_bot.dialogues.admin.task_tracker_rules.moves_title").as_ref())
+            .empty_line();
+
+        if moves.is_empty() {
+            builder = builder
+                .line(t!("telegram_bot.dialogues.admin.task_tracker_rules.moves_empty").as_ref());
+        } else {
+            for task_move in &moves {
+                let icon = if task_move.error.is_some() {
+                    "❌"
+                } else {
+                    "✅"
+                };
+                let pr = task_move
+                    .pr_number
+                    .map(|n| format!(" · PR #{}", n))
+                    .unwrap_or_default();
+
+                let mut line = format!(
+                    "{} {} <code>{}</code> → <code>{}</code> · {}{}\n",
+                    icon,
+                    task_move.created_at.format("%d.%m %H:%M"),
+                    MessageBuilder::escape_html(&task_move.task_id.0),
+                    MessageBuilder::escape_html(&task_move.column_id),
+                    move_trigger_label(task_move.trigger),
+                    pr,
+                );
+
+                if let Some(error) = &task_move.error {
+                    let error: String = error.chars().take(200).collect();
+                    line.push_str(&format!(
+                        "    <i>{}</i>\n",
+                        MessageBuilder::escape_html(&error)
+                    ));
+                }
+
+                builder = builder.raw(&line);
+            }
+        }
+
+        bot.edit_message_text(chat_id, message_id, builder.build())
+            .parse_mode(ParseMode::Html)
+            .reply_markup(InlineKeyboardMarkup::default())
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn trigger_label(trigger: PullRequestLifecycleTrigger) -> String {
+    match trigger {
+        PullRequestLifecycleTrigger::Opened => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_opened")
+        }
+        PullRequestLifecycleTrigger::ReadyForReview => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_ready_for_review")
+        }
+        PullRequestLifecycleTrigger::Approved => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_approved")
+        }
+        PullRequestLifecycleTrigger::Merged => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_merged")
+        }
+        PullRequestLifecycleTrigger::Released => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_released")
+        }
+    }
+    .to_string()
+}
+
+/// Подпись причины перевода в журнале: событие PR, решение QA или новый issue
+fn move_trigger_label(trigger: TaskMoveTrigger) -> String {
+    match trigger {
+        TaskMoveTrigger::PullRequest(trigger) => trigger_label(trigger),
+        TaskMoveTrigger::TestPassed => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_test_passed").to_string()
+        }
+        TaskMoveTrigger::TestFailed => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_test_failed").to_string()
+        }
+        TaskMoveTrigger::IssueOpened => {
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.trigger_issue_opened").to_string()
+        }
+    }
+}
+
+fn branch_label(pattern: Option<&str>) -> String {
+    match pattern {
+        Some(p) => format!("🌿 <code>{}</code>", MessageBuilder::escape_html(p)),
+        None => t!("telegram_bot.dialogues.admin.task_tracker_rules.any_branch").to_string(),
+    }
+}
+
+async fn handle_list_action(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+    repository_id: i32,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let action = match TelegramBotAdminTaskTrackerRuleAction::from_callback_data(data) {
+        Ok(a) => a,
+        Err(_) => {
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.common.cancelled").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    match action {
+        TelegramBotAdminTaskTrackerRuleAction::Add => {
+            let rows: Vec<Vec<InlineKeyboardButton>> = PullRequestLifecycleTrigger::ALL
+                .into_iter()
+                .map(|trigger| {
+                    vec![InlineKeyboardButton::callback(
+                        trigger_label(trigger),
+                        trigger.to_string(),
+                    )]
+                })
+                .collect();
+
+            dialogue
+                .update(TelegramBotDialogueState::Admin(
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleSelectTrigger {
+                        repository_id,
+                    },
+                ))
+                .await?;
+
+            bot.edit_message_text(
+                msg.chat().id,
+                msg.id(),
+                t!("telegram_bot.dialogues.admin.task_tracker_rules.select_trigger").to_string(),
+            )
+            .reply_markup(InlineKeyboardMarkup::new(rows))
+            .await?;
+        }
+
+        TelegramBotAdminTaskTrackerRuleAction::Delete => {
+            let rules = executors
+                .queries
+                .get_repository_task_tracker_rules
+                .execute(&GetRepositoryTaskTrackerRulesQuery {
+                    repository_id: RepositoryId(repository_id),
+                })
+                .await
+                .map(|r| r.rules)
+                .unwrap_or_default();
+
+            if rules.is_empty() {
+                bot.send_message(
+                    msg.chat().id,
+                    t!("telegram_bot.dialogues.admin.task_tracker_rules.empty").to_string(),
+                )
+                .await?;
+
+                dialogue.exit().await.ok();
+                return Ok(());
+            }
+
+            let rows: Vec<Vec<InlineKeyboardButton>> = rules
+                .iter()
+                .map(|rule| {
+                    let label = format!(
+                        "{} → {}{}",
+                        trigger_label(rule.trigger),
+                        rule.column_id,
+                        rule.base_branch_pattern
+                            .as_deref()
+                            .map(|p| format!(" ({})", p))
+                            .unwrap_or_default(),
+                    );
+
+                    vec![InlineKeyboardButton::callback(label, rule.id.to_string())]
+                })
+                .collect();
+
+            dialogue
+                .update(TelegramBotDialogueState::Admin(
+                    TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleDeleteSelect {
+                        repository_id,
+                    },
+                ))
+                .await?;
+
+            bot.edit_message_text(
+                msg.chat().id,
+                msg.id(),
+                t!("telegram_bot.dialogues.admin.task_tracker_rules.select_for_delete").to_string(),
+            )
+            .reply_markup(InlineKeyboardMarkup::new(rows))
+            .await?;
+        }
+
+        TelegramBotAdminTaskTrackerRuleAction::Cancel => {
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.common.cancelled").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_select_trigger(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    shared_dependency: Arc<ApplicationSharedDependency>,
+    query: CallbackQuery,
+    repository_id: i32,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+    let trigger = match PullRequestLifecycleTrigger::from_str(data) {
+        Ok(t) => t,
+        Err(e) => {
+            tracing::error!(error = %e, "Invalid lifecycle trigger in callback");
+            return Ok(());
+        }
+    };
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let tracker = match shared_dependency
+        .repository_task_tracker_repo
+        .find_by_repository_id(RepositoryId(repository_id))
+        .await
+    {
+        Ok(t) => t,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load task tracker for rule");
+            bot.edit_message_text(
+                msg.chat().id,
+                msg.id(),
+                t!("telegram_bot.dialogues.admin.task_tracker_rules.tracker_missing").to_string(),
+            )
+            .reply_markup(InlineKeyboardMarkup::default())
+            .await?;
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    let loading = bot
+        .edit_message_text(
+            msg.chat().id,
+            msg.id(),
+            t!("telegram_bot.dialogues.admin.task_tracker.loading_boards").to_string(),
+        )
+        .reply_markup(InlineKeyboardMarkup::default())
+        .await?;
+
+    let boards = match shared_dependency
+        .task_tracker_client_resolver
+        .resolve(tracker.kind, tracker.connection_id)
+        .await
+    {
+        Ok(client) => client
+            .list_boards(&tracker.space_id)
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    let boards = match boards {
+        Ok(b) => b,
+        Err(error) => {
+            tracing::error!(error = %error, space_id = %tracker.space_id, "Failed to load boards");
+            bot.edit_message_text(
+                msg.chat().id,
+                loading.id,
+                t!("telegram_bot.dialogues.admin.task_tracker.boards_load_error").to_string(),
+            )
+            .await?;
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    if boards.is_empty() {
+        bot.edit_message_text(
+            msg.chat().id,
+            loading.id,
+            t!("telegram_bot.dialogues.admin.task_tracker.no_boards").to_string(),
+        )
+        .await?;
+        dialogue.exit().await.ok();
+        return Ok(());
+    }
+
+    let buttons: Vec<Vec<InlineKeyboardButton>> = boards
+        .into_iter()
+        .map(|b| vec![InlineKeyboardButton::callback(b.title, b.id)])
+        .collect();
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleSelectBoard {
+                repository_id,
+                trigger,
+            },
+        ))
+        .await?;
+
+    bot.edit_message_text(
+        msg.chat().id,
+        loading.id,
+        t!("telegram_bot.dialogues.admin.task_tracker.select_board").to_string(),
+    )
+    .reply_markup(InlineKeyboardMarkup::new(buttons))
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_select_board(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    shared_dependency: Arc<ApplicationSharedDependency>,
+    query: CallbackQuery,
+    (repository_id, trigger): (i32, PullRequestLifecycleTrigger),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let board_id = query.data.clone().unwrap_or_default();
+    if board_id.is_empty() {
+        tracing::error!("Empty board_id in callback");
+        return Ok(());
+    }
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let loading = bot
+        .edit_message_text(
+            msg.chat().id,
+            msg.id(),
+            t!("telegram_bot.dialogues.admin.task_tracker.loading_columns").to_string(),
+        )
+        .reply_markup(InlineKeyboardMarkup::default())
+        .await?;
+
+    let columns = match shared_dependency
+        .repository_task_tracker_repo
+        .find_by_repository_id(RepositoryId(repository_id))
+        .await
+    {
+        Ok(tracker) => match shared_dependency
+            .task_tracker_client_resolver
+            .resolve(tracker.kind, tracker.connection_id)
+            .await
+        {
+            Ok(client) => client
+                .list_columns(&board_id)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        },
+        Err(e) => Err(e.to_string()),
+    };
+
+    let columns = match columns {
+        Ok(c) => c,
+        Err(error) => {
+            tracing::error!(error = %error, board_id = %board_id, "Failed to load columns");
+            bot.edit_message_text(
+                msg.chat().id,
+                loading.id,
+                t!("telegram_bot.dialogues.admin.task_tracker.columns_load_error").to_string(),
+            )
+            .await?;
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    if columns.is_empty() {
+        bot.edit_message_text(
+            msg.chat().id,
+            loading.id,
+            t!("telegram_bot.dialogues.admin.task_tracker.no_columns").to_string(),
+        )
+        .await?;
+        dialogue.exit().await.ok();
+        return Ok(());
+    }
+
+    let buttons: Vec<Vec<InlineKeyboardButton>> = columns
+        .into_iter()
+        .map(|c| vec![InlineKeyboardButton::callback(c.title, c.id)])
+        .collect();
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleSelectColumn {
+                repository_id,
+                trigger,
+            },
+        ))
+        .await?;
+
+    bot.edit_message_text(
+        msg.chat().id,
+        loading.id,
+        t!("telegram_bot.dialogues.admin.task_tracker_rules.select_column").to_string(),
+    )
+    .reply_markup(InlineKeyboardMarkup::new(buttons))
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_select_column(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    query: CallbackQuery,
+    (repository_id, trigger): (i32, PullRequestLifecycleTrigger),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let column_id = query.data.clone().unwrap_or_default();
+    if column_id.is_empty() {
+        tracing::error!("Empty column_id in callback");
+        return Ok(());
+    }
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::ConfigureTaskTrackerRuleEnterBranch {
+                repository_id,
+                trigger,
+                column_id,
+            },
+        ))
+        .await?;
+
+    bot.edit_message_text(
+        msg.chat().id,
+        msg.id(),
+        t!("telegram_bot.dialogues.admin.task_tracker_rules.enter_branch").to_string(),
+    )
+    .reply_markup(InlineKeyboardMarkup::default())
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_enter_branch(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    msg: Message,
+    (repository_id, trigger, column_id): (i32, PullRequestLifecycleTrigger, String),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let base_branch_pattern = match extract_text(&msg) {
+        Some(t) if t == "-" => None,
+        Some(t) => Some(t),
+        None => {
+            bot.send_message(
+                msg.chat.id,
+                t!("telegram_bot.dialogues.admin.task_tracker_rules.branch_required").to_string(),
+            )
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    let cmd = CreateRepositoryTaskTrackerRuleCommand {
+        repository_id: RepositoryId(repository_id),
+        trigger,
+        column_id,
+        base_branch_pattern,
+    };
+
+    let reply = match executors
+        .commands
+        .create_repository_task_tracker_rule
+        .execute(&cmd)
+        .await
+    {
+        Ok(response) => {
+            tracing::info!(
+                rule_id = response.rule.id,
+                repository_id,
+                "Task tracker rule created"
+            );
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.saved").to_string()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to create task tracker rule");
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.save_error").to_string()
+        }
+    };
+
+    bot.send_message(msg.chat.id, reply).await?;
+
+    dialogue.exit().await.ok();
+
+    Ok(())
+}
+
+async fn handle_delete_select(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+    _repository_id: i32,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+    let rule_id: i32 = match data.parse() {
+        Ok(v) => v,
+        Err(_) => {
+            tracing::error!(data = %data, "Invalid rule_id in callback");
+            return Ok(());
+        }
+    };
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let reply = match executors
+        .commands
+        .delete_repository_task_tracker_rule
+        .execute(&DeleteRepositoryTaskTrackerRuleCommand { id: rule_id })
+        .await
+    {
+        Ok(_) => t!("telegram_bot.dialogues.admin.task_tracker_rules.deleted").to_string(),
+        Err(e) => {
+            tracing::error!(error = %e, rule_id, "Failed to delete task tracker rule");
+            t!("telegram_bot.dialogues.admin.task_tracker_rules.delete_error").to_string()
+        }
+    };
+
+    bot.edit_message_text(msg.chat().id, msg.id(), reply)
+        .reply_markup(InlineKeyboardMarkup::default())
+        .await?;
+
+    dialogue.exit().await.ok();
+
+    Ok(())
+}
@@ -0,0 +1,634 @@
+use crate::application::webhook::commands::replay_deliveries::command::{
+    ReplayWebhookDeliveriesExecutorCommand, ReplayWebhookDeliveriesSelector,
+};
+use crate::application::webhook::commands::replay_deliveries::error::ReplayWebhookDeliveriesExecutorError;
+use crate::application::webhook::commands::replay_deliveries::response::{
+    ReplayWebhookDeliveriesExecutorResponse, ReplayedWebhookDeliveryOutcome,
+};
+use crate::application::webhook::queries::list_deliveries::query::ListWebhookDeliveriesQuery;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
+use crate::delivery::bot::telegram::dialogues::admin::helpers::parse_integer;
+use crate::delivery::bot::telegram::dialogues::helpers::parse_callback;
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::delivery::bot::telegram::keyboards::actions::admin_webhook_deliveries::TelegramBotAdminWebhookDeliveriesAction;
+use crate::delivery::bot::telegram::keyboards::actions::admin_webhook_deliveries_replay::TelegramBotAdminWebhookDeliveriesReplayAction;
+use crate::delivery::bot::telegram::keyboards::actions::admin_webhook_deliveries_window::TelegramBotAdminWebhookDeliveriesWindowAction;
+use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use crate::utils::builder::message::MessageBuilder;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use teloxide::dispatching::DpHandlerDescription;
+use teloxide::dptree::case;
+use teloxide::payloads::{EditMessageTextSetters, SendMessageSetters};
+use teloxide::prelude::*;
+use teloxide::types::{ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId, ParseMode};
+use teloxide::{Bot, dptree};
+
+const RECENT_DELIVERIES_LIMIT: u64 = 10;
+// Ограничение по строкам, чтобы не упереться в лимит длины сообщения Telegram
+const REPORT_MAX_ITEMS: usize = 30;
+
+pub struct TelegramBotDialogueAdminWebhookDeliveriesDispatcher;
+
+impl TelegramBotDialogueAdminWebhookDeliveriesDispatcher {
+    pub fn query_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry()
+            .branch(
+                case![TelegramBotDialogueAdminState::WebhookDeliveriesMenu]
+                    .endpoint(handle_menu_action),
+            )
+            .branch(
+                case![TelegramBotDialogueAdminState::WebhookDeliveriesSelectRepository]
+                    .endpoint(handle_select_repository),
+            )
+            .branch(
+                case![
+                    TelegramBotDialogueAdminState::WebhookDeliveriesSelectWindow {
+                        repository_full_name
+                    }
+                ]
+                .endpoint(handle_select_window),
+            )
+            .branch(
+                case![TelegramBotDialogueAdminState::WebhookDeliveriesReplayConfirm { selector }]
+                    .endpoint(handle_replay_action),
+            )
+    }
+
+    pub fn message_branches()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        dptree::entry().branch(
+            case![TelegramBotDialogueAdminState::WebhookDeliveriesEnterId]
+                .endpoint(handle_enter_id),
+        )
+    }
+
+    pub async fn show_list(
+        bot: &Bot,
+        chat_id: ChatId,
+        message_id: MessageId,
+        executors: &ApplicationBoostrapExecutors,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let query = ListWebhookDeliveriesQuery {
+            repository_full_name: None,
+            from: None,
+            to: None,
+            limit: RECENT_DELIVERIES_LIMIT,
+        };
+
+        let deliveries = match executors
+            .queries
+            .list_webhook_deliveries
+            .execute(&query)
+            .await
+        {
+            Ok(r) => r.deliveries,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to list webhook deliveries");
+
+                bot.edit_message_text(
+                    chat_id,
+                    message_id,
+                    t!("telegram_bot.dialogues.admin.webhook_deliveries.load_error").to_string(),
+                )
+                .await?;
+
+                return Ok(());
+            }
+        };
+
+        let mut builder = MessageBuilder::new()
+            .bold(t!("telegram_bot.dialogues.admin.webhook_deliveries.title").as_ref())
+            .empty_line();
+
+        if deliveries.is_empty() {
+            builder =
+                builder.line(t!("telegram_bot.dialogues.admin.webhook_deliveries.empty").as_ref());
+        } else {
+            for delivery in &deliveries {
+                let line = format!(
+                    "{} <code>#{}</code> {} · {} · {}\n",
+                    status_icon(&delivery.status),
+                    delivery.id.0,
+                    MessageBuilder::escape_html(&delivery.event_type),
+                    MessageBuilder::escape_html(
+                        delivery.repository_full_name.as_deref().unwrap_or("—")
+                    ),
+                    delivery.received_at.format("%d.%m %H:%M"),
+                );
+
+                builder = builder.raw(&line);
+            }
+        }
+
+        let keyboard = KeyboardBuilder::new()
+            .row::<TelegramBotAdminWebhookDeliveriesAction>(vec![
+                TelegramBotAdminWebhookDeliveriesAction::ReplayById,
+            ])
+            .row::<TelegramBotAdminWebhookDeliveriesAction>(vec![
+                TelegramBotAdminWebhookDeliveriesAction::ReplayRepository,
+            ])
+            .row::<TelegramBotAdminWebhookDeliveriesAction>(vec![
+                TelegramBotAdminWebhookDeliveriesAction::Cancel,
+            ])
+            .build();
+
+        bot.edit_message_text(chat_id, message_id, builder.build())
+            .parse_mode(ParseMode::Html)
+            .reply_markup(keyboard)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn status_icon(status: &WebhookDeliveryStatus) -> &'static str {
+    match status {
+        WebhookDeliveryStatus::Received => "⏳",
+        WebhookDeliveryStatus::Dispatched => "🟢",
+        WebhookDeliveryStatus::Skipped => "⚪",
+        WebhookDeliveryStatus::Failed => "🔴",
+        WebhookDeliveryStatus::Rejected => "⛔",
+    }
+}
+
+fn replay_keyboard() -> InlineKeyboardMarkup {
+    KeyboardBuilder::new()
+        .row::<TelegramBotAdminWebhookDeliveriesReplayAction>(vec![
+            TelegramBotAdminWebhookDeliveriesReplayAction::DryRun,
+            TelegramBotAdminWebhookDeliveriesReplayAction::Replay,
+        ])
+        .row::<TelegramBotAdminWebhookDeliveriesReplayAction>(vec![
+            TelegramBotAdminWebhookDeliveriesReplayAction::Cancel,
+        ])
+        .build()
+}
+
+fn describe_selector(selector: &ReplayWebhookDeliveriesSelector) -> String {
+    match selector {
+        ReplayWebhookDeliveriesSelector::Delivery(id) => t!(
+            "telegram_bot.dialogues.admin.webhook_deliveries.selector_delivery",
+            id = id.0
+        )
+        .to_string(),
+        ReplayWebhookDeliveriesSelector::RepositoryWindow {
+            repository_full_name,
+            from,
+            to,
+            ..
+        } => t!(
+            "telegram_bot.dialogues.admin.webhook_deliveries.selector_window",
+            repository = MessageBuilder::escape_html(repository_full_name),
+            from = from.format("%d.%m %H:%M"),
+            to = to.format("%d.%m %H:%M")
+        )
+        .to_string(),
+    }
+}
+
+fn format_report(response: &ReplayWebhookDeliveriesExecutorResponse) -> String {
+    let title = if response.dry_run {
+        t!("telegram_bot.dialogues.admin.webhook_deliveries.dry_run_title")
+    } else {
+        t!("telegram_bot.dialogues.admin.webhook_deliveries.replay_title")
+    };
+
+    let mut builder = MessageBuilder::new().bold(title.as_ref()).empty_line();
+
+    if response.results.is_empty() {
+        return builder
+            .line(t!("telegram_bot.dialogues.admin.webhook_deliveries.nothing_to_replay").as_ref())
+            .build();
+    }
+
+    for result in response.results.iter().take(REPORT_MAX_ITEMS) {
+        let (icon, reason) = match &result.outcome {
+            ReplayedWebhookDeliveryOutcome::Dispatched => ("✅", None),
+            ReplayedWebhookDeliveryOutcome::DryRun => ("🧪", None),
+            ReplayedWebhookDeliveryOutcome::Skipped(reason) => ("⚪", Some(reason)),
+            ReplayedWebhookDeliveryOutcome::Failed(reason) => ("🔴", Some(reason)),
+        };
+
+        let mut line = format!(
+            "{} <code>#{}</code> {}",
+            icon,
+            result.id.0,
+            MessageBuilder::escape_html(&result.event_type),
+        );
+
+        if let Some(reason) = reason {
+            line.push_str(&format!(
+                " — <i>{}</i>",
+                MessageBuilder::escape_html(reason)
+            ));
+        }
+
+        builder = builder.raw(&line).raw("\n");
+
+        if result.event_name.is_some() {
+            let listeners = if result.listeners.is_empty() {
+                t!("telegram_bot.dialogues.admin.webhook_deliveries.no_listeners").to_string()
+            } else {
+                result.listeners.join(", ")
+            };
+
+            builder = builder.raw(&format!(
+                "    👂 {}\n",
+                MessageBuilder::escape_html(&listeners)
+            ));
+        }
+    }
+
+    if response.results.len() > REPORT_MAX_ITEMS {
+        builder = builder.empty_line().line(
+            t!(
+                "telegram_bot.dialogues.admin.webhook_deliveries.more",
+                count = response.results.len() - REPORT_MAX_ITEMS
+            )
+            .as_ref(),
+        );
+    }
+
+    if let Some(next_after) = response.next_after {
+        builder = builder.empty_line().line(
+            t!(
+                "telegram_bot.dialogues.admin.webhook_deliveries.truncated",
+                count = response.results.len(),
+                after_id = next_after.id.0,
+                after_at = next_after.received_at.format("%d.%m %H:%M:%S")
+            )
+            .as_ref(),
+        );
+    }
+
+    builder.build()
+}
+
+async fn handle_menu_action(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let ctx = match parse_callback::<TelegramBotAdminWebhookDeliveriesAction>(&bot, &query).await? {
+        Some(c) => c,
+        None => {
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    match ctx.action {
+        TelegramBotAdminWebhookDeliveriesAction::ReplayById => {
+            dialogue
+                .update(TelegramBotDialogueState::Admin(
+                    TelegramBotDialogueAdminState::WebhookDeliveriesEnterId,
+                ))
+                .await?;
+
+            bot.send_message(
+                ctx.chat_id,
+                t!("telegram_bot.dialogues.admin.webhook_deliveries.enter_id").to_string(),
+            )
+            .await?;
+        }
+
+        TelegramBotAdminWebhookDeliveriesAction::ReplayRepository => {
+            let repositories = executors
+                .commands
+                .create_repository
+                .repository_repo
+                .find_all()
+                .await
+                .unwrap_or_default();
+
+            if repositories.is_empty() {
+                bot.send_message(
+                    ctx.chat_id,
+                    t!("telegram_bot.dialogues.admin.no_repositories").to_string(),
+                )
+                .await?;
+
+                dialogue.exit().await.ok();
+                return Ok(());
+            }
+
+            let rows: Vec<Vec<InlineKeyboardButton>> = repositories
+                .into_iter()
+                .map(|r| {
+                    vec![InlineKeyboardButton::callback(
+                        format!("{}/{}", r.owner, r.name),
+                        r.id.0.to_string(),
+                    )]
+                })
+                .collect();
+
+            dialogue
+                .update(TelegramBotDialogueState::Admin(
+                    TelegramBotDialogueAdminState::WebhookDeliveriesSelectRepository,
+                ))
+                .await?;
+
+            bot.edit_message_text(
+                ctx.chat_id,
+                ctx.message_id,
+                t!("telegram_bot.dialogues.admin.webhook_deliveries.select_repository").to_string(),
+            )
+            .reply_markup(InlineKeyboardMarkup::new(rows))
+            .await?;
+        }
+
+        TelegramBotAdminWebhookDeliveriesAction::Cancel => {
+            bot.send_message(ctx.chat_id, t!("telegram_bot.common.cancelled").to_string())
+                .await?;
+
+            dialogue.exit().await.ok();
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_enter_id(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    msg: Message,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let id = match parse_integer(&msg) {
+        Some(id) if id > 0 => id,
+        _ => {
+            bot.send_message(
+                msg.chat.id,
+                t!("telegram_bot.dialogues.admin.webhook_deliveries.id_required").to_string(),
+            )
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    let selector = ReplayWebhookDeliveriesSelector::Delivery(WebhookDeliveryId(id));
+    let text = describe_selector(&selector);
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::WebhookDeliveriesReplayConfirm { selector },
+        ))
+        .await?;
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(replay_keyboard())
+        .await?;
+
+    Ok(())
+}
+
+async fn handle_select_repository(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let repository_id: i32 = match query.data.as_deref().and_then(|d| d.parse().ok()) {
+        Some(id) => id,
+        None => {
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    let repository = match executors
+        .commands
+        .create_repository
+        .repository_repo
+        .find_by_id(RepositoryId(repository_id))
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::error!(error = %e, repository_id, "Failed to load repository");
+
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.dialogues.admin.repository.not_found").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    let repository_full_name = format!("{}/{}", repository.owner, repository.name);
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::WebhookDeliveriesSelectWindow {
+                repository_full_name,
+            },
+        ))
+        .await?;
+
+    let keyboard = KeyboardBuilder::new()
+        .row::<TelegramBotAdminWebhookDeliveriesWindowAction>(vec![
+            TelegramBotAdminWebhookDeliveriesWindowAction::LastHour,
+            TelegramBotAdminWebhookDeliveriesWindowAction::Last6Hours,
+        ])
+        .row::<TelegramBotAdminWebhookDeliveriesWindowAction>(vec![
+            TelegramBotAdminWebhookDeliveriesWindowAction::LastDay,
+            TelegramBotAdminWebhookDeliveriesWindowAction::Last3Days,
+        ])
+        .build();
+
+    bot.edit_message_text(
+        msg.chat().id,
+        msg.id(),
+        t!("telegram_bot.dialogues.admin.webhook_deliveries.select_window").to_string(),
+    )
+    .reply_markup(keyboard)
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_select_window(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    query: CallbackQuery,
+    repository_full_name: String,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let ctx = match parse_callback::<TelegramBotAdminWebhookDeliveriesWindowAction>(&bot, &query)
+        .await?
+    {
+        Some(c) => c,
+        None => {
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    let to = Utc::now();
+    let from = to - Duration::hours(ctx.action.hours());
+
+    let selector = ReplayWebhookDeliveriesSelector::RepositoryWindow {
+        repository_full_name,
+        from,
+        to,
+        after: None,
+    };
+    let text = describe_selector(&selector);
+
+    dialogue
+        .update(TelegramBotDialogueState::Admin(
+            TelegramBotDialogueAdminState::WebhookDeliveriesReplayConfirm { selector },
+        ))
+        .await?;
+
+    bot.edit_message_text(ctx.chat_id, ctx.message_id, text)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(replay_keyboard())
+        .await?;
+
+    Ok(())
+}
+
+async fn handle_replay_action(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+    selector: ReplayWebhookDeliveriesSelector,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let ctx = match parse_callback::<TelegramBotAdminWebhookDeliveriesReplayAction>(&bot, &query)
+        .await?
+    {
+        Some(c) => c,
+        None => {
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    let dry_run = match ctx.action {
+        TelegramBotAdminWebhookDeliveriesReplayAction::DryRun => true,
+        TelegramBotAdminWebhookDeliveriesReplayAction::Replay => false,
+        TelegramBotAdminWebhookDeliveriesReplayAction::Cancel => {
+            bot.send_message(ctx.chat_id, t!("telegram_bot.common.cancelled").to_string())
+                .await?;
+
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    let cmd = ReplayWebhookDeliveriesExecutorCommand {
+        selector: selector.clone(),
+        dry_run,
+    };
+
+    let response = match executors
+        .commands
+        .replay_webhook_deliveries
+        .execute(&cmd)
+        .await
+    {
+        Ok(r) => r,
+        Err(ReplayWebhookDeliveriesExecutorError::NotFound) => {
+            bot.send_message(
+                ctx.chat_id,
+                t!("telegram_bot.dialogues.admin.webhook_deliveries.not_found").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to replay webhook deliveries");
+
+            bot.send_message(
+                ctx.chat_id,
+                t!("telegram_bot.dialogues.admin.webhook_deliveries.replay_error").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+    };
+
+    let text = format_report(&response);
+
+    // Окно не влезло: переносим курсор в состояние, кнопки продолжат с него
+    if let (
+        false,
+        Some(next_after),
+        ReplayWebhookDeliveriesSelector::RepositoryWindow {
+            repository_full_name,
+            from,
+            to,
+            ..
+        },
+    ) = (dry_run, response.next_after, selector)
+    {
+        dialogue
+            .update(TelegramBotDialogueState::Admin(
+                TelegramBotDialogueAdminState::WebhookDeliveriesReplayConfirm {
+                    selector: ReplayWebhookDeliveriesSelector::RepositoryWindow {
+                        repository_full_name,
+                        from,
+                        to,
+                        after: Some(next_after),
+                    },
+                },
+            ))
+            .await?;
+
+        let text = MessageBuilder::new()
+            .raw(&text)
+            .empty_line()
+            .line(t!("telegram_bot.dialogues.admin.webhook_deliveries.continue").as_ref())
+            .build();
+
+        bot.send_message(ctx.chat_id, text)
+            .parse_mode(ParseMode::Html)
+            .reply_markup(replay_keyboard())
+            .await?;
+
+        return Ok(());
+    }
+
+    // После dry-run оставляем кнопки — можно сразу отправить по-настоящему
+    if dry_run {
+        bot.send_message(ctx.chat_id, text)
+            .parse_mode(ParseMode::Html)
+            .reply_markup(replay_keyboard())
+            .await?;
+    } else {
+        bot.send_message(ctx.chat_id, text)
+            .parse_mode(ParseMode::Html)
+            .await?;
+
+        dialogue.exit().await.ok();
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminState;
 use crate::delivery::bot::telegram::dialogues::bind_repository::TelegramBotBindRepositoryState;
 use crate::delivery::bot::telegram::dialogues::digest::TelegramBotDigestState;
+use crate::delivery::bot::telegram::dialogues::new_task::TelegramBotNewTaskState;
 use crate::delivery::bot::telegram::dialogues::notifications::TelegramBotNotificationsState;
 use crate::delivery::bot::telegram::dialogues::onboarding::TelegramBotOnboardingState;
 use crate::delivery::bot::telegram::dialogues::registration::TelegramBotDialogueRegistrationState;
@@ -9,6 +10,7 @@ use crate::delivery::bot::telegram::dialogues::release_plan_settings::TelegramBo
 use crate::delivery::bot::telegram::dialogues::report::TelegramBotDialogueReportByDateRangeState;
 use crate::delivery::bot::telegram::dialogues::setup_notifications::TelegramBotSetupNotificationsState;
 use crate::delivery::bot::telegram::dialogues::setup_webhook::TelegramBotSetupWebhookState;
+use crate::delivery::bot::telegram::dialogues::test_queue::TelegramBotTestQueueState;
 use teloxide::dispatching::dialogue::InMemStorage;
 use teloxide::prelude::Dialogue;
 
@@ -16,6 +18,7 @@ pub mod admin;
 pub mod bind_repository;
 pub mod digest;
 pub mod helpers;
+pub mod new_task;
 pub mod notifications;
 pub mod onboarding;
 pub mod registration;
@@ -24,6 +27,7 @@ pub mod release_plan_settings;
 pub mod report;
 pub mod setup_notifications;
 pub mod setup_webhook;
+pub mod test_queue;
 
 #[derive(Debug, Clone, Default)]
 pub enum TelegramBotDialogueState {
@@ -40,6 +44,8 @@ pub enum TelegramBotDialogueState {
     Onboarding(TelegramBotOnboardingState),
     ReleasePlan(TelegramBotReleasePlanState),
     ReleasePlanSettings(TelegramBotReleasePlanSettingsState),
+    TestQueue(TelegramBotTestQueueState),
+    NewTask(TelegramBotNewTaskState),
 }
 
 pub type TelegramBotDialogueType =
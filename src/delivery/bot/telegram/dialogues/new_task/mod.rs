@@ -0,0 +1,375 @@
+use crate::application::task::commands::create_task_card::command::CreateTaskCardExecutorCommand;
+use crate::application::task::commands::create_task_card::error::CreateTaskCardExecutorError;
+use crate::application::user::queries::get_user_overview::query::GetUserOverviewQuery;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::dialogues::{
+    TelegramBotDialogueState, TelegramBotDialogueType,
+};
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::new_task::TelegramBotNewTaskAction;
+use crate::delivery::bot::telegram::keyboards::builder::KeyboardBuilder;
+use crate::domain::repository::repositories::repository_task_tracker_repository::FindRepositoryTaskTrackerByRepositoryIdError;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use crate::utils::builder::message::MessageBuilder;
+use std::sync::Arc;
+use teloxide::dispatching::DpHandlerDescription;
+use teloxide::dptree::case;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+use teloxide::{Bot, dptree};
+
+#[derive(Debug, Clone, Default)]
+pub enum TelegramBotNewTaskState {
+    #[default]
+    ChooseRepository,
+
+    EnterTitle {
+        repository_id: i32,
+    },
+
+    EnterDescription {
+        repository_id: i32,
+        title: String,
+    },
+
+    ChooseAssignee {
+        repository_id: i32,
+        title: String,
+        description: String,
+    },
+}
+
+pub struct TelegramBotNewTaskDispatcher {}
+
+impl TelegramBotNewTaskDispatcher {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        let queries = Update::filter_callback_query()
+            .branch(
+                case![TelegramBotNewTaskState::ChooseRepository].endpoint(handle_choose_repository),
+            )
+            .branch(
+                case![TelegramBotNewTaskState::ChooseAssignee {
+                    repository_id,
+                    title,
+                    description
+                }]
+                .endpoint(handle_choose_assignee),
+            );
+
+        let messages = Update::filter_message()
+            .branch(
+                case![TelegramBotNewTaskState::EnterTitle { repository_id }]
+                    .endpoint(handle_enter_title),
+            )
+            .branch(
+                case![TelegramBotNewTaskState::EnterDescription {
+                    repository_id,
+                    title
+                }]
+                .endpoint(handle_enter_description),
+            )
+            .branch(
+                case![TelegramBotNewTaskState::ChooseAssignee {
+                    repository_id,
+                    title,
+                    description
+                }]
+                .endpoint(handle_enter_assignee),
+            );
+
+        dptree::entry().branch(queries).branch(messages)
+    }
+}
+
+async fn handle_choose_repository(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    query: CallbackQuery,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    if let Ok(TelegramBotNewTaskAction::Cancel) = TelegramBotNewTaskAction::from_callback_data(data)
+    {
+        bot.send_message(
+            msg.chat().id,
+            t!("telegram_bot.common.cancelled").to_string(),
+        )
+        .await?;
+
+        dialogue.exit().await.ok();
+        return Ok(());
+    }
+
+    let Some(repository_id) = data
+        .strip_prefix(TelegramBotNewTaskAction::REPOSITORY_PREFIX)
+        .and_then(|id| id.parse::<i32>().ok())
+    else {
+        return Ok(());
+    };
+
+    dialogue
+        .update(TelegramBotDialogueState::NewTask(
+            TelegramBotNewTaskState::EnterTitle { repository_id },
+        ))
+        .await?;
+
+    bot.send_message(
+        msg.chat().id,
+        t!("telegram_bot.commands.new_task.enter_title").to_string(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_enter_title(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    msg: Message,
+    repository_id: i32,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let title = msg.text().unwrap_or("").trim();
+
+    if title.is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            t!("telegram_bot.commands.new_task.title_required").to_string(),
+        )
+        .await?;
+
+        return Ok(());
+    }
+
+    dialogue
+        .update(TelegramBotDialogueState::NewTask(
+            TelegramBotNewTaskState::EnterDescription {
+                repository_id,
+                title: title.to_string(),
+            },
+        ))
+        .await?;
+
+    bot.send_message(
+        msg.chat.id,
+        t!("telegram_bot.commands.new_task.enter_description").to_string(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_enter_description(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    msg: Message,
+    (repository_id, title): (i32, String),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let text = msg.text().unwrap_or("").trim();
+
+    // «-» — без описания
+    let description = if text == "-" { "" } else { text };
+
+    dialogue
+        .update(TelegramBotDialogueState::NewTask(
+            TelegramBotNewTaskState::ChooseAssignee {
+                repository_id,
+                title,
+                description: description.to_string(),
+            },
+        ))
+        .await?;
+
+    let keyboard = KeyboardBuilder::new()
+        .row::<TelegramBotNewTaskAction>(vec![
+            TelegramBotNewTaskAction::AssignMe,
+            TelegramBotNewTaskAction::NoAssignee,
+        ])
+        .row::<TelegramBotNewTaskAction>(vec![TelegramBotNewTaskAction::Cancel])
+        .build();
+
+    bot.send_message(
+        msg.chat.id,
+        t!("telegram_bot.commands.new_task.choose_assignee").to_string(),
+    )
+    .reply_markup(keyboard)
+    .await?;
+
+    Ok(())
+}
+
+async fn handle_choose_assignee(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    query: CallbackQuery,
+    (repository_id, title, description): (i32, String, String),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    let assignee_login = match TelegramBotNewTaskAction::from_callback_data(data) {
+        Ok(TelegramBotNewTaskAction::Cancel) => {
+            bot.send_message(
+                msg.chat().id,
+                t!("telegram_bot.common.cancelled").to_string(),
+            )
+            .await?;
+
+            dialogue.exit().await.ok();
+            return Ok(());
+        }
+        Ok(TelegramBotNewTaskAction::NoAssignee) => None,
+        Ok(TelegramBotNewTaskAction::AssignMe) => {
+            let social_user_id = SocialUserId(query.from.id.0 as i32);
+
+            match executors
+                .queries
+                .get_user_overview
+                .execute(&GetUserOverviewQuery { social_user_id })
+                .await
+                .ok()
+                .and_then(|overview| overview.github_login)
+            {
+                Some(login) => Some(login),
+                None => {
+                    bot.send_message(
+                        msg.chat().id,
+                        t!("telegram_bot.commands.new_task.no_login").to_string(),
+                    )
+                    .await?;
+                    return Ok(());
+                }
+            }
+        }
+        Err(_) => return Ok(()),
+    };
+
+    create_card(
+        &bot,
+        &dialogue,
+        &executors,
+        msg.chat().id,
+        CreateTaskCardExecutorCommand {
+            repository_id: RepositoryId(repository_id),
+            title,
+            description,
+            column_id: None,
+            assignee_login,
+        },
+    )
+    .await
+}
+
+/// Логин исполнителя можно ввести текстом вместо кнопки
+async fn handle_enter_assignee(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    msg: Message,
+    (repository_id, title, description): (i32, String, String),
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let login = msg.text().unwrap_or("").trim().trim_start_matches('@');
+
+    if login.is_empty() || login.contains(char::is_whitespace) {
+        bot.send_message(
+            msg.chat.id,
+            t!("telegram_bot.commands.new_task.invalid_login").to_string(),
+        )
+        .await?;
+
+        return Ok(());
+    }
+
+    create_card(
+        &bot,
+        &dialogue,
+        &executors,
+        msg.chat.id,
+        CreateTaskCardExecutorCommand {
+            repository_id: RepositoryId(repository_id),
+            title,
+            description,
+            column_id: None,
+            assignee_login: Some(login.to_string()),
+        },
+    )
+    .await
+}
+
+async fn create_card(
+    bot: &Bot,
+    dialogue: &TelegramBotDialogueType,
+    executors: &ApplicationBoostrapExecutors,
+    chat_id: ChatId,
+    cmd: CreateTaskCardExecutorCommand,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    dialogue.exit().await.ok();
+
+    let created = match executors.commands.create_task_card.execute(&cmd).await {
+        Ok(created) => created,
+        Err(CreateTaskCardExecutorError::TrackerNotConfigured(
+            FindRepositoryTaskTrackerByRepositoryIdError::NotFound,
+        )) => {
+            bot.send_message(
+                chat_id,
+                t!("telegram_bot.commands.new_task.no_tracker").to_string(),
+            )
+            .await?;
+            return Ok(());
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to create task card");
+            bot.send_message(
+                chat_id,
+                t!("telegram_bot.commands.new_task.error").to_string(),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let mut builder = MessageBuilder::new().with_html_escape(false).raw(&format!(
+        "{} <a href=\"{}\">{}</a>\n",
+        t!("telegram_bot.commands.new_task.created"),
+        MessageBuilder::escape_html(&created.url),
+        MessageBuilder::escape_html(&created.task_id.0),
+    ));
+
+    builder = match &created.branch_name {
+        Some(branch) => builder.raw(&format!(
+            "{} <code>{}</code>\n",
+            t!("telegram_bot.commands.new_task.branch"),
+            MessageBuilder::escape_html(branch),
+        )),
+        None => builder.line(t!("telegram_bot.commands.new_task.no_branch").as_ref()),
+    };
+
+    if !created.assigned {
+        builder = builder.line(t!("telegram_bot.commands.new_task.assign_failed").as_ref());
+    }
+
+    bot.send_message(chat_id, builder.build())
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+use crate::application::repository::commands::ensure_repository_webhook::command::EnsureRepositoryWebhookCommand;
+use crate::application::repository::commands::ensure_repository_webhook::error::EnsureRepositoryWebhookError;
+use crate::application::repository::commands::ensure_repository_webhook::response::EnsureRepositoryWebhookOutcome;
 use crate::application::repository::commands::set_repository_notification_chat::command::SetRepositoryNotificationChatCommand;
 use crate::application::repository::commands::unset_repository_notification_chat::command::UnsetRepositoryNotificationChatCommand;
 use crate::bootstrap::executors::ApplicationBoostrapExecutors;
@@ -302,15 +305,19 @@ async fn bind_chat(
         Ok(r) => {
             let owner = teloxide::utils::html::escape(&r.repository.owner);
             let name = teloxide::utils::html::escape(&r.repository.name);
+            let hook = ensure_webhook_report(executors, r.repository.id).await;
             bot.edit_message_text(
                 chat_id,
                 message_id,
-                t!(
-                    "telegram_bot.dialogues.setup_webhook.bound_success",
-                    owner = owner,
-                    name = name
-                )
-                .to_string(),
+                format!(
+                    "{}\n\n{}",
+                    t!(
+                        "telegram_bot.dialogues.setup_webhook.bound_success",
+                        owner = owner,
+                        name = name
+                    ),
+                    hook
+                ),
             )
             .parse_mode(ParseMode::Html)
             .await?;
@@ -329,3 +336,70 @@ async fn bind_chat(
     dialogue.exit().await.ok();
     Ok(())
 }
+
+/// Ставит вебхук в репозиторий (или дополняет существующий) и
+/// возвращает строку с результатом для сообщения администратору
+pub async fn ensure_webhook_report(
+    executors: &Arc<ApplicationBoostrapExecutors>,
+    repository_id: RepositoryId,
+) -> String {
+    let result = executors
+        .commands
+        .ensure_repository_webhook
+        .execute(&EnsureRepositoryWebhookCommand { repository_id })
+        .await;
+
+    match result {
+        Ok(r) => {
+            let url = teloxide::utils::html::escape(&r.url);
+            match r.outcome {
+                EnsureRepositoryWebhookOutcome::Created => t!(
+                    "telegram_bot.dialogues.setup_webhook.hook.created",
+                    url = url
+                )
+                .to_string(),
+                EnsureRepositoryWebhookOutcome::Updated { missing_events }
+                    if missing_events.is_empty() =>
+                {
+                    t!(
+                        "telegram_bot.dialogues.setup_webhook.hook.reactivated",
+                        url = url
+                    )
+                    .to_string()
+                }
+                EnsureRepositoryWebhookOutcome::Updated { missing_events } => {
+                    let events = missing_events
+                        .iter()
+                        .map(|e| format!("<code>{}</code>", e))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    t!(
+                        "telegram_bot.dialogues.setup_webhook.hook.updated",
+                        url = url,
+                        events = events
+                    )
+                    .to_string()
+                }
+                EnsureRepositoryWebhookOutcome::UpToDate => t!(
+                    "telegram_bot.dialogues.setup_webhook.hook.up_to_date",
+                    url = url
+                )
+                .to_string(),
+            }
+        }
+        Err(EnsureRepositoryWebhookError::NoToken) => {
+            t!("telegram_bot.dialogues.setup_webhook.hook.no_token").to_string()
+        }
+        Err(EnsureRepositoryWebhookError::NoSecret) => {
+            t!("telegram_bot.dialogues.setup_webhook.hook.no_secret").to_string()
+        }
+        Err(EnsureRepositoryWebhookError::Unauthorized(msg)) => {
+            tracing::warn!(error = %msg, "No access to repository hooks");
+            t!("telegram_bot.dialogues.setup_webhook.hook.forbidden").to_string()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to ensure repository webhook");
+            t!("telegram_bot.dialogues.setup_webhook.hook.error").to_string()
+        }
+    }
+}
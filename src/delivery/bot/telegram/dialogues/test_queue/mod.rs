@@ -0,0 +1,152 @@
+use crate::application::task::commands::assign_task_card::command::AssignTaskCardExecutorCommand;
+use crate::application::task::commands::assign_task_card::error::AssignTaskCardExecutorError;
+use crate::application::task::commands::complete_task_testing::command::CompleteTaskTestingExecutorCommand;
+use crate::application::task::commands::complete_task_testing::error::CompleteTaskTestingExecutorError;
+use crate::bootstrap::executors::ApplicationBoostrapExecutors;
+use crate::delivery::bot::telegram::dialogues::TelegramBotDialogueType;
+use crate::delivery::bot::telegram::keyboards::actions::TelegramBotKeyboardAction;
+use crate::delivery::bot::telegram::keyboards::actions::test_queue::TelegramBotTestQueueAction;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClientWriteError;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use std::sync::Arc;
+use teloxide::dispatching::DpHandlerDescription;
+use teloxide::dptree::case;
+use teloxide::prelude::*;
+use teloxide::{Bot, dptree};
+
+/// Карточка из показанного списка — к ней относятся кнопки с тем же номером
+#[derive(Debug, Clone)]
+pub struct TelegramBotTestQueueCard {
+    pub task_id: TaskId,
+    pub repository_id: RepositoryId,
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub enum TelegramBotTestQueueState {
+    List {
+        cards: Vec<TelegramBotTestQueueCard>,
+    },
+}
+
+pub struct TelegramBotTestQueueDispatcher {}
+
+impl TelegramBotTestQueueDispatcher {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new()
+    -> Handler<'static, Result<(), Box<dyn std::error::Error + Send + Sync>>, DpHandlerDescription>
+    {
+        let queries = Update::filter_callback_query()
+            .branch(case![TelegramBotTestQueueState::List { cards }].endpoint(handle_list_action));
+
+        dptree::entry().branch(queries)
+    }
+}
+
+async fn handle_list_action(
+    bot: Bot,
+    dialogue: TelegramBotDialogueType,
+    executors: Arc<ApplicationBoostrapExecutors>,
+    cards: Vec<TelegramBotTestQueueCard>,
+    query: CallbackQuery,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let data = query.data.as_deref().unwrap_or("");
+
+    let msg = match query.message {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+
+    if TelegramBotTestQueueAction::from_callback_data(data)
+        .map(|a| matches!(a, TelegramBotTestQueueAction::Close))
+        .unwrap_or(false)
+    {
+        bot.edit_message_reply_markup(msg.chat().id, msg.id())
+            .await
+            .ok();
+        dialogue.exit().await.ok();
+        return Ok(());
+    }
+
+    let card_at = |prefix: &str| {
+        data.strip_prefix(prefix)
+            .and_then(|index| index.parse::<usize>().ok())
+            .and_then(|index| cards.get(index))
+    };
+
+    let reply = if let Some(card) = card_at(TelegramBotTestQueueAction::TAKE_PREFIX) {
+        let cmd = AssignTaskCardExecutorCommand {
+            task_id: card.task_id.clone(),
+            kind: card.kind,
+            connection_id: card.connection_id,
+            social_user_id: SocialUserId(query.from.id.0 as i32),
+        };
+
+        match executors.commands.assign_task_card.execute(&cmd).await {
+            Ok(_) => t!(
+                "telegram_bot.commands.test_queue.taken",
+                task = card.task_id.0
+            )
+            .to_string(),
+            Err(AssignTaskCardExecutorError::UserNotFound(_)) => {
+                t!("telegram_bot.commands.test_queue.not_registered").to_string()
+            }
+            Err(AssignTaskCardExecutorError::VersionControlAccountNotFound(_))
+            | Err(AssignTaskCardExecutorError::TaskTrackerClientWriteError(
+                TaskTrackerClientWriteError::AssigneeNotFound,
+            )) => t!("telegram_bot.commands.test_queue.assignee_not_found").to_string(),
+            Err(e) => {
+                tracing::error!(error = %e, task_id = %card.task_id.0, "Failed to assign task card");
+                t!("telegram_bot.commands.test_queue.action_error").to_string()
+            }
+        }
+    } else {
+        let (card, passed) = match (
+            card_at(TelegramBotTestQueueAction::DONE_PREFIX),
+            card_at(TelegramBotTestQueueAction::BACK_PREFIX),
+        ) {
+            (Some(card), _) => (card, true),
+            (None, Some(card)) => (card, false),
+            (None, None) => return Ok(()),
+        };
+
+        let cmd = CompleteTaskTestingExecutorCommand {
+            task_id: card.task_id.clone(),
+            repository_id: card.repository_id,
+            kind: card.kind,
+            connection_id: card.connection_id,
+            passed,
+        };
+
+        match executors.commands.complete_task_testing.execute(&cmd).await {
+            Ok(_) if passed => t!(
+                "telegram_bot.commands.test_queue.moved_done",
+                task = card.task_id.0
+            )
+            .to_string(),
+            Ok(_) => t!(
+                "telegram_bot.commands.test_queue.moved_back",
+                task = card.task_id.0
+            )
+            .to_string(),
+            Err(CompleteTaskTestingExecutorError::NotConfigured) => {
+                t!("telegram_bot.commands.test_queue.not_configured").to_string()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, task_id = %card.task_id.0, "Failed to complete task testing");
+                t!("telegram_bot.commands.test_queue.action_error").to_string()
+            }
+        }
+    };
+
+    bot.send_message(msg.chat().id, reply).await?;
+
+    Ok(())
+}
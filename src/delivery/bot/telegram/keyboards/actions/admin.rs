@@ -9,6 +9,8 @@ pub enum TelegramBotAdminAction {
     ConfigureRepository,
     #[strum(serialize = "admin_configure_task_tracker")]
     ConfigureTaskTracker,
+    #[strum(serialize = "admin_task_tracker_connections")]
+    TaskTrackerConnections,
     #[strum(serialize = "admin_queues_stats")]
     QueuesStats,
 
@@ -17,6 +19,9 @@ pub enum TelegramBotAdminAction {
 
     #[strum(serialize = "admin_manage_users")]
     ManageUsers,
+
+    #[strum(serialize = "admin_webhook_deliveries")]
+    WebhookDeliveries,
 }
 
 impl KeyboardActionLabel for TelegramBotAdminAction {
@@ -24,9 +29,11 @@ impl KeyboardActionLabel for TelegramBotAdminAction {
         match self {
             TelegramBotAdminAction::ConfigureRepository => "📦 Репозитории",
             TelegramBotAdminAction::ConfigureTaskTracker => "⚙️ Настроить таск-трекер",
+            TelegramBotAdminAction::TaskTrackerConnections => "🔌 Подключения трекеров",
             TelegramBotAdminAction::QueuesStats => "📊 Очереди и воркеры",
             TelegramBotAdminAction::HealthPings => "🏓 Пинги",
             TelegramBotAdminAction::ManageUsers => "👥 Пользователи",
+            TelegramBotAdminAction::WebhookDeliveries => "📨 Вебхук-доставки",
         }
     }
 }
@@ -11,6 +11,14 @@ pub enum TelegramBotAdminRepositoryEditField {
     Owner,
     #[strum(serialize = "repo_edit_url")]
     Url,
+    #[strum(serialize = "repo_edit_provider")]
+    Provider,
+    #[strum(serialize = "repo_edit_webhook_secret")]
+    WebhookSecret,
+    #[strum(serialize = "repo_edit_ref_events_filter")]
+    RefEventsFilter,
+    #[strum(serialize = "repo_edit_slack_channel")]
+    SlackChannel,
 }
 
 impl KeyboardActionLabel for TelegramBotAdminRepositoryEditField {
@@ -19,6 +27,10 @@ impl KeyboardActionLabel for TelegramBotAdminRepositoryEditField {
             TelegramBotAdminRepositoryEditField::Name => "✏️ Название",
             TelegramBotAdminRepositoryEditField::Owner => "👤 Владелец",
             TelegramBotAdminRepositoryEditField::Url => "🔗 URL",
+            TelegramBotAdminRepositoryEditField::Provider => "🌐 Провайдер",
+            TelegramBotAdminRepositoryEditField::WebhookSecret => "🔑 Секрет вебхука",
+            TelegramBotAdminRepositoryEditField::RefEventsFilter => "🌿 Ветки и теги",
+            TelegramBotAdminRepositoryEditField::SlackChannel => "💬 Канал Slack",
         }
     }
 }
@@ -0,0 +1,37 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(EnumString, AsRefStr, Debug, Clone)]
+pub enum TelegramBotAdminRepositoryProviderAction {
+    #[strum(serialize = "repo_provider_github")]
+    Github,
+    #[strum(serialize = "repo_provider_gitea")]
+    Gitea,
+    #[strum(serialize = "repo_provider_gitlab")]
+    Gitlab,
+}
+
+impl TelegramBotAdminRepositoryProviderAction {
+    pub fn to_version_control_type(&self) -> VersionControlType {
+        match self {
+            TelegramBotAdminRepositoryProviderAction::Github => VersionControlType::Github,
+            TelegramBotAdminRepositoryProviderAction::Gitea => VersionControlType::Gitea,
+            TelegramBotAdminRepositoryProviderAction::Gitlab => VersionControlType::Gitlab,
+        }
+    }
+}
+
+impl KeyboardActionLabel for TelegramBotAdminRepositoryProviderAction {
+    fn label(&self) -> &'static str {
+        match self {
+            TelegramBotAdminRepositoryProviderAction::Github => "GitHub",
+            TelegramBotAdminRepositoryProviderAction::Gitea => "Gitea / Forgejo",
+            TelegramBotAdminRepositoryProviderAction::Gitlab => "GitLab",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotAdminRepositoryProviderAction);
@@ -11,6 +11,10 @@ pub enum TelegramBotAdminTaskTrackerAction {
     Edit,
     #[strum(serialize = "admin_tt_reconfigure")]
     Reconfigure,
+    #[strum(serialize = "admin_tt_rules")]
+    Rules,
+    #[strum(serialize = "admin_tt_moves")]
+    Moves,
 }
 
 impl KeyboardActionLabel for TelegramBotAdminTaskTrackerAction {
@@ -19,6 +23,8 @@ impl KeyboardActionLabel for TelegramBotAdminTaskTrackerAction {
             TelegramBotAdminTaskTrackerAction::View => "👁 Посмотреть настройки",
             TelegramBotAdminTaskTrackerAction::Edit => "✏️ Редактировать поле",
             TelegramBotAdminTaskTrackerAction::Reconfigure => "🔄 Настроить заново",
+            TelegramBotAdminTaskTrackerAction::Rules => "⚙️ Правила колонок",
+            TelegramBotAdminTaskTrackerAction::Moves => "📜 Журнал переводов",
         }
     }
 }
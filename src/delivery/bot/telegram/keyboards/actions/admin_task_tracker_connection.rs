@@ -0,0 +1,26 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Clone, Debug, EnumString, AsRefStr)]
+pub enum TelegramBotAdminTaskTrackerConnectionAction {
+    #[strum(serialize = "ttc_create")]
+    Create,
+    #[strum(serialize = "ttc_delete")]
+    Delete,
+    #[strum(serialize = "ttc_cancel")]
+    Cancel,
+}
+
+impl KeyboardActionLabel for TelegramBotAdminTaskTrackerConnectionAction {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Create => "➕ Добавить",
+            Self::Delete => "🗑 Удалить",
+            Self::Cancel => "❌ Отмена",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotAdminTaskTrackerConnectionAction);
@@ -11,6 +11,8 @@ pub enum TelegramBotAdminTaskTrackerEditField {
     ExtractPattern,
     #[strum(serialize = "admin_tt_edit_reconfigure")]
     Reconfigure,
+    #[strum(serialize = "admin_tt_edit_require_task_id")]
+    RequireTaskId,
 }
 
 impl KeyboardActionLabel for TelegramBotAdminTaskTrackerEditField {
@@ -18,6 +20,7 @@ impl KeyboardActionLabel for TelegramBotAdminTaskTrackerEditField {
         match self {
             TelegramBotAdminTaskTrackerEditField::ExtractPattern => "✏️ Regex паттерн",
             TelegramBotAdminTaskTrackerEditField::Reconfigure => "🔄 Перевыбрать колонку/спейс",
+            TelegramBotAdminTaskTrackerEditField::RequireTaskId => "🛡 Проверка ID задачи в PR",
         }
     }
 }
@@ -0,0 +1,26 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Clone, Debug, EnumString, AsRefStr)]
+pub enum TelegramBotAdminTaskTrackerRuleAction {
+    #[strum(serialize = "ttr_add")]
+    Add,
+    #[strum(serialize = "ttr_delete")]
+    Delete,
+    #[strum(serialize = "ttr_cancel")]
+    Cancel,
+}
+
+impl KeyboardActionLabel for TelegramBotAdminTaskTrackerRuleAction {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Add => "➕ Добавить правило",
+            Self::Delete => "🗑 Удалить правило",
+            Self::Cancel => "❌ Отмена",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotAdminTaskTrackerRuleAction);
@@ -0,0 +1,26 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Clone, Debug, EnumString, AsRefStr)]
+pub enum TelegramBotAdminWebhookDeliveriesAction {
+    #[strum(serialize = "wd_replay_by_id")]
+    ReplayById,
+    #[strum(serialize = "wd_replay_repository")]
+    ReplayRepository,
+    #[strum(serialize = "wd_cancel")]
+    Cancel,
+}
+
+impl KeyboardActionLabel for TelegramBotAdminWebhookDeliveriesAction {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::ReplayById => "🔁 Повторить по ID",
+            Self::ReplayRepository => "📦 Повторить по репозиторию",
+            Self::Cancel => "❌ Отмена",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotAdminWebhookDeliveriesAction);
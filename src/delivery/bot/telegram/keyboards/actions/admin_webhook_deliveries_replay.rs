@@ -0,0 +1,26 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Clone, Debug, EnumString, AsRefStr)]
+pub enum TelegramBotAdminWebhookDeliveriesReplayAction {
+    #[strum(serialize = "wd_dry_run")]
+    DryRun,
+    #[strum(serialize = "wd_replay")]
+    Replay,
+    #[strum(serialize = "wd_replay_cancel")]
+    Cancel,
+}
+
+impl KeyboardActionLabel for TelegramBotAdminWebhookDeliveriesReplayAction {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::DryRun => "🧪 Dry-run",
+            Self::Replay => "▶️ Отправить",
+            Self::Cancel => "❌ Отмена",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotAdminWebhookDeliveriesReplayAction);
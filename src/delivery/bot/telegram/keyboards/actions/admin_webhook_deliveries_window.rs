@@ -0,0 +1,40 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Clone, Debug, EnumString, AsRefStr)]
+pub enum TelegramBotAdminWebhookDeliveriesWindowAction {
+    #[strum(serialize = "wd_window_1h")]
+    LastHour,
+    #[strum(serialize = "wd_window_6h")]
+    Last6Hours,
+    #[strum(serialize = "wd_window_24h")]
+    LastDay,
+    #[strum(serialize = "wd_window_72h")]
+    Last3Days,
+}
+
+impl TelegramBotAdminWebhookDeliveriesWindowAction {
+    pub fn hours(&self) -> i64 {
+        match self {
+            Self::LastHour => 1,
+            Self::Last6Hours => 6,
+            Self::LastDay => 24,
+            Self::Last3Days => 72,
+        }
+    }
+}
+
+impl KeyboardActionLabel for TelegramBotAdminWebhookDeliveriesWindowAction {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::LastHour => "🕐 Последний час",
+            Self::Last6Hours => "🕕 Последние 6 часов",
+            Self::LastDay => "📅 Последние сутки",
+            Self::Last3Days => "📅 Последние 3 дня",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotAdminWebhookDeliveriesWindowAction);
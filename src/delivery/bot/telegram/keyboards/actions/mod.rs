@@ -4,10 +4,16 @@ pub mod admin_health_ping_edit;
 pub mod admin_repository;
 pub mod admin_repository_delete;
 pub mod admin_repository_edit_field;
+pub mod admin_repository_provider;
 pub mod admin_task_tracker;
+pub mod admin_task_tracker_connection;
 pub mod admin_task_tracker_edit_field;
+pub mod admin_task_tracker_rule;
 pub mod admin_user_menu;
 pub mod admin_users;
+pub mod admin_webhook_deliveries;
+pub mod admin_webhook_deliveries_replay;
+pub mod admin_webhook_deliveries_window;
 pub mod choose_role;
 pub mod confirm;
 pub mod date_range;
@@ -15,6 +21,7 @@ pub mod digest_list;
 pub mod digest_repository;
 pub mod digest_type;
 pub mod for_who;
+pub mod new_task;
 pub mod notifications_events;
 pub mod notifications_menu;
 pub mod notifications_snooze;
@@ -22,6 +29,7 @@ pub mod notifications_vacation;
 pub mod onboarding;
 pub mod release_plan;
 pub mod release_plan_settings;
+pub mod test_queue;
 
 pub trait TelegramBotKeyboardAction {
     fn to_callback_data(&self) -> &str;
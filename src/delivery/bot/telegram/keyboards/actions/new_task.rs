@@ -0,0 +1,34 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Clone, Debug, EnumString, AsRefStr)]
+pub enum TelegramBotNewTaskAction {
+    #[strum(serialize = "new_task_assign_me")]
+    AssignMe,
+    #[strum(serialize = "new_task_no_assignee")]
+    NoAssignee,
+    #[strum(serialize = "cancel")]
+    Cancel,
+}
+
+impl TelegramBotNewTaskAction {
+    pub const REPOSITORY_PREFIX: &'static str = "nt_repo_";
+
+    pub fn repository_callback(id: i32) -> String {
+        format!("{}{}", Self::REPOSITORY_PREFIX, id)
+    }
+}
+
+impl KeyboardActionLabel for TelegramBotNewTaskAction {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::AssignMe => "🙋 Мне",
+            Self::NoAssignee => "➖ Без исполнителя",
+            Self::Cancel => "❌ Отмена",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotNewTaskAction);
@@ -0,0 +1,39 @@
+use crate::delivery::bot::telegram::keyboards::actions::{
+    KeyboardActionLabel, impl_keyboard_action,
+};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Clone, Debug, EnumString, AsRefStr)]
+pub enum TelegramBotTestQueueAction {
+    #[strum(serialize = "test_queue_close")]
+    Close,
+}
+
+/// В callback — номер карточки в списке: id задач трекеров могут не влезть в 64 байта
+impl TelegramBotTestQueueAction {
+    pub const TAKE_PREFIX: &'static str = "tq_take_";
+    pub const DONE_PREFIX: &'static str = "tq_done_";
+    pub const BACK_PREFIX: &'static str = "tq_back_";
+
+    pub fn take_callback(index: usize) -> String {
+        format!("{}{}", Self::TAKE_PREFIX, index)
+    }
+
+    pub fn done_callback(index: usize) -> String {
+        format!("{}{}", Self::DONE_PREFIX, index)
+    }
+
+    pub fn back_callback(index: usize) -> String {
+        format!("{}{}", Self::BACK_PREFIX, index)
+    }
+}
+
+impl KeyboardActionLabel for TelegramBotTestQueueAction {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Close => "✖️ Закрыть",
+        }
+    }
+}
+
+impl_keyboard_action!(TelegramBotTestQueueAction);
@@ -10,6 +10,7 @@ use crate::delivery::bot::telegram::dialogues::TelegramBotDialogueState;
 use crate::delivery::bot::telegram::dialogues::admin::TelegramBotDialogueAdminDispatcher;
 use crate::delivery::bot::telegram::dialogues::bind_repository::TelegramBotBindRepositoryDispatcher;
 use crate::delivery::bot::telegram::dialogues::digest::TelegramBotDigestDispatcher;
+use crate::delivery::bot::telegram::dialogues::new_task::TelegramBotNewTaskDispatcher;
 use crate::delivery::bot::telegram::dialogues::notifications::TelegramBotNotificationsDispatcher;
 use crate::delivery::bot::telegram::dialogues::onboarding::TelegramBotOnboardingDispatcher;
 use crate::delivery::bot::telegram::dialogues::registration::TelegramBotDialogueRegistrationDispatcher;
@@ -18,6 +19,7 @@ use crate::delivery::bot::telegram::dialogues::release_plan_settings::TelegramBo
 use crate::delivery::bot::telegram::dialogues::report::TelegramBotDialogueReportByDateRangeDispatcher;
 use crate::delivery::bot::telegram::dialogues::setup_notifications::TelegramBotSetupNotificationsDispatcher;
 use crate::delivery::bot::telegram::dialogues::setup_webhook::TelegramBotSetupWebhookDispatcher;
+use crate::delivery::bot::telegram::dialogues::test_queue::TelegramBotTestQueueDispatcher;
 use crate::delivery::contract::ApplicationDelivery;
 use std::sync::Arc;
 use teloxide::Bot;
@@ -105,6 +107,14 @@ impl ApplicationDelivery for DeliveryBotMessengerTelegram {
             .branch(
                 case![TelegramBotDialogueState::ReleasePlanSettings(state)]
                     .branch(TelegramBotReleasePlanSettingsDispatcher::new()),
+            )
+            .branch(
+                case![TelegramBotDialogueState::TestQueue(state)]
+                    .branch(TelegramBotTestQueueDispatcher::new()),
+            )
+            .branch(
+                case![TelegramBotDialogueState::NewTask(state)]
+                    .branch(TelegramBotNewTaskDispatcher::new()),
             );
 
         Dispatcher::builder(bot, handler)
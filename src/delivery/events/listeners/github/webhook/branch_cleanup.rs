@@ -0,0 +1,105 @@
+use crate::domain::merged_branch::entities::merged_branch::MergedBranch;
+use crate::domain::merged_branch::repositories::merged_branch_repository::MergedBranchRepository;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::webhook::events::git_ref::{WebhookRefAction, WebhookRefEvent, WebhookRefType};
+use crate::domain::webhook::events::pull_request::{
+    WebhookPullRequestEvent, WebhookPullRequestEventActionType,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// Долгоживущие ветки, которые мержат через PR, но не удаляют
+const LONG_LIVED_BRANCHES: &[&str] = &["main", "master", "develop", "dev", "staging", "production"];
+
+/// Запоминает head-ветки смерженных PR и забывает их после удаления —
+/// по оставшимся планировщик напоминает авторам
+pub struct WebhookBranchCleanupListener {
+    pub merged_branch_repo: Arc<dyn MergedBranchRepository>,
+}
+
+#[async_trait]
+impl EventListener<WebhookPullRequestEvent> for WebhookBranchCleanupListener {
+    async fn handle(&self, payload: &WebhookPullRequestEvent) {
+        if payload.action != WebhookPullRequestEventActionType::Closed || !payload.merged {
+            return;
+        }
+
+        if LONG_LIVED_BRANCHES.contains(&payload.head_ref.as_str()) {
+            return;
+        }
+
+        // PR из форка — ветка живёт не в нашем репозитории.
+        // GitHub всегда присылает owner:branch, Gitea — owner: только для форков.
+        let repo_owner = payload.repo.split('/').next();
+        if let Some((head_owner, _)) = payload.head_repo.split_once(':')
+            && Some(head_owner) != repo_owner
+        {
+            return;
+        }
+
+        let version_control_type = payload.provider.version_control_type();
+
+        // Напоминание уходит автору — без логина его некому отправить
+        let Some(author_login) = payload.author.clone() else {
+            tracing::debug!(
+                repo = %payload.repo,
+                branch = %payload.head_ref,
+                "PR author is unknown — skipping merged branch"
+            );
+            return;
+        };
+
+        let merged_at = payload
+            .merged_at
+            .as_deref()
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|d| d.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+
+        let branch = MergedBranch {
+            version_control_type,
+            repo: payload.repo.clone(),
+            branch: payload.head_ref.clone(),
+            pr_number: payload.number,
+            pr_url: payload.pr_url.clone(),
+            author_login,
+            merged_at,
+            reminded_at: None,
+        };
+
+        if let Err(e) = self.merged_branch_repo.upsert(&branch).await {
+            tracing::error!(
+                error = %e,
+                repo = %payload.repo,
+                branch = %payload.head_ref,
+                "Failed to record merged branch"
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookRefEvent> for WebhookBranchCleanupListener {
+    async fn handle(&self, payload: &WebhookRefEvent) {
+        if payload.action != WebhookRefAction::Deleted || payload.ref_type != WebhookRefType::Branch
+        {
+            return;
+        }
+
+        let version_control_type = payload.provider.version_control_type();
+
+        if let Err(e) = self
+            .merged_branch_repo
+            .delete(&version_control_type, &payload.repo, &payload.ref_name)
+            .await
+        {
+            tracing::error!(
+                error = %e,
+                repo = %payload.repo,
+                branch = %payload.ref_name,
+                "Failed to forget deleted branch"
+            );
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::application::commit_check::commands::record_commit_check::command::RecordCommitCheckExecutorCommand;
+use crate::application::commit_check::commands::record_commit_check::executor::RecordCommitCheckExecutor;
+use crate::domain::commit_check::entities::commit_check::CommitCheck;
+use crate::domain::commit_check::value_objects::commit_check_kind::CommitCheckKind;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::webhook::events::check_run::WebhookCheckRunEvent;
+use crate::domain::webhook::events::check_suite::WebhookCheckSuiteEvent;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Собирает check_run / check_suite в итоговое состояние CI по коммиту.
+/// Сам ничего не отправляет — при завершении всех проверок executor
+/// публикует `CommitChecksCompletedEvent`.
+pub struct WebhookCommitChecksAggregatorListener {
+    pub record_commit_check: Arc<RecordCommitCheckExecutor>,
+}
+
+impl WebhookCommitChecksAggregatorListener {
+    async fn record(&self, check: CommitCheck) {
+        let repo = check.repo.clone();
+        let sha = check.head_sha.clone();
+
+        match self
+            .record_commit_check
+            .execute(&RecordCommitCheckExecutorCommand { check })
+            .await
+        {
+            Ok(r) => {
+                tracing::debug!(
+                    repo = %repo,
+                    sha = %sha,
+                    state = %r.summary.state,
+                    total = r.summary.total,
+                    pending = r.summary.pending,
+                    reported = r.reported,
+                    "Commit check recorded"
+                );
+            }
+            Err(e) => {
+                tracing::error!(error = %e, repo = %repo, sha = %sha, "Failed to record commit check");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookCheckRunEvent> for WebhookCommitChecksAggregatorListener {
+    async fn handle(&self, payload: &WebhookCheckRunEvent) {
+        self.record(CommitCheck {
+            repo: payload.repo.clone(),
+            head_sha: payload.head_sha.clone(),
+            // По имени, а не по id: перезапуск создаёт новый run с тем же именем
+            check_key: format!("run:{}", payload.name),
+            kind: CommitCheckKind::Run,
+            name: payload.name.clone(),
+            check_suite_id: payload.check_suite_id,
+            status: payload.status.clone(),
+            conclusion: payload.conclusion.clone(),
+            html_url: payload.html_url.clone(),
+            head_branch: payload.head_branch.clone(),
+            pr_numbers: payload.pr_numbers.clone(),
+            actor: payload.sender.clone(),
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookCheckSuiteEvent> for WebhookCommitChecksAggregatorListener {
+    async fn handle(&self, payload: &WebhookCheckSuiteEvent) {
+        self.record(CommitCheck {
+            repo: payload.repo.clone(),
+            head_sha: payload.head_sha.clone(),
+            check_key: format!("suite:{}", payload.id),
+            kind: CommitCheckKind::Suite,
+            name: payload
+                .app
+                .clone()
+                .unwrap_or_else(|| format!("suite #{}", payload.id)),
+            check_suite_id: Some(payload.id),
+            status: payload.status.clone(),
+            conclusion: payload.conclusion.clone(),
+            html_url: None,
+            head_branch: payload.head_branch.clone(),
+            pr_numbers: payload.pr_numbers.clone(),
+            actor: payload.sender.clone(),
+        })
+        .await;
+    }
+}
@@ -1,10 +1,14 @@
+use crate::delivery::events::listeners::github::webhook::resolve_user_by_login;
 use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::commit_check::events::checks_completed::CommitChecksCompletedEvent;
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
 use crate::domain::notification_log::repositories::notification_log_repository::NotificationLogRepository;
 use crate::domain::shared::events::event_listener::EventListener;
 use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
 use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
 use crate::domain::user::value_objects::social_type::SocialType;
 use crate::domain::webhook::events::workflow::WebhookWorkflowEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
 use crate::utils::builder::message::MessageBuilder;
 use async_trait::async_trait;
@@ -21,38 +25,34 @@ pub struct WebhookCiFailDmListener {
     pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
 }
 
-#[async_trait]
-impl EventListener<WebhookWorkflowEvent> for WebhookCiFailDmListener {
-    async fn handle(&self, payload: &WebhookWorkflowEvent) {
-        if payload.status != "completed" {
-            return;
-        }
-        if payload.conclusion.as_deref() != Some("failure") {
-            return;
-        }
-
-        let actor_login = match &payload.actor {
-            Some(login) if !login.is_empty() => login.clone(),
+impl WebhookCiFailDmListener {
+    /// Одно ЛС на коммит: GitHub шлёт и `workflow_run`, и `check_run`, ключ
+    /// у обоих путей общий, поэтому дубля не будет
+    async fn notify(
+        &self,
+        provider: WebhookProvider,
+        actor: Option<&str>,
+        repo: &str,
+        head_sha: &str,
+        msg: MessageBuilder,
+    ) {
+        let actor_login = match actor {
+            Some(login) if !login.is_empty() => login,
             _ => return,
         };
 
-        let vc_account = match self.user_vc_accounts_repo.find_by_login(&actor_login).await {
-            Ok(a) => a,
-            Err(_) => {
-                tracing::debug!(actor = %actor_login, "CI fail actor not registered — skipping DM");
-                return;
-            }
-        };
-        let social = match self
-            .user_socials_repo
-            .find_by_user_id(&vc_account.user_id)
-            .await
-        {
-            Ok(s) => s,
-            Err(_) => return,
+        let Some((vc_account, social)) = resolve_user_by_login(
+            &self.user_vc_accounts_repo,
+            &self.user_socials_repo,
+            provider,
+            actor_login,
+        )
+        .await
+        else {
+            return;
         };
 
-        let key = format!("{}:{}", payload.repo, payload.id);
+        let key = format!("{}:{}", repo, head_sha);
         let since = Utc::now() - Duration::hours(COOLDOWN_HOURS);
 
         match self
@@ -68,29 +68,6 @@ impl EventListener<WebhookWorkflowEvent> for WebhookCiFailDmListener {
             }
         }
 
-        let workflow_url = payload.html_url.as_deref().unwrap_or("");
-        let mut msg = MessageBuilder::new()
-            .bold(&t!("telegram_bot.notifications.ci_fail.title").to_string())
-            .empty_line()
-            .with_html_escape(true)
-            .section(
-                &t!("telegram_bot.notifications.ci_fail.workflow").to_string(),
-                &payload.name,
-            )
-            .section(
-                &t!("telegram_bot.notifications.ci_fail.repository").to_string(),
-                &payload.repo,
-            )
-            .with_html_escape(false);
-
-        if !workflow_url.is_empty() {
-            msg = msg.empty_line().raw(&format!(
-                "<a href=\"{}\">{}</a>",
-                MessageBuilder::escape_html(workflow_url),
-                t!("telegram_bot.notifications.ci_fail.open").to_string()
-            ));
-        }
-
         self.publisher
             .publish(&SendSocialNotifyJob {
                 social_type: SocialType::Telegram,
@@ -109,3 +86,122 @@ impl EventListener<WebhookWorkflowEvent> for WebhookCiFailDmListener {
         }
     }
 }
+
+#[async_trait]
+impl EventListener<CommitChecksCompletedEvent> for WebhookCiFailDmListener {
+    async fn handle(&self, payload: &CommitChecksCompletedEvent) {
+        if payload.state != CommitCiState::Failure {
+            return;
+        }
+
+        let short_sha = &payload.head_sha[..7.min(payload.head_sha.len())];
+
+        let mut msg = MessageBuilder::new()
+            .bold(&t!("telegram_bot.notifications.ci_fail.title"))
+            .empty_line()
+            .with_html_escape(true)
+            .section(
+                &t!("telegram_bot.notifications.ci_fail.repository"),
+                &payload.repo,
+            );
+
+        if let Some(branch) = &payload.head_branch {
+            msg = msg.section_code(&t!("telegram_bot.notifications.ci_fail.branch"), branch);
+        }
+
+        msg = msg
+            .section_code(&t!("telegram_bot.notifications.ci_fail.commit"), short_sha)
+            .section(
+                &t!("telegram_bot.notifications.ci_fail.failing"),
+                &t!(
+                    "telegram_bot.notifications.ci_fail.failing_count",
+                    failing = payload.failing.len(),
+                    total = payload.total
+                ),
+            )
+            .with_html_escape(false)
+            .empty_line();
+
+        for check in &payload.failing {
+            let name = MessageBuilder::escape_html(&check.name);
+            let line = match check.html_url.as_deref().map(str::trim) {
+                Some(url) if url.starts_with("http://") || url.starts_with("https://") => format!(
+                    "• <a href=\"{}\">{}</a> — {}",
+                    MessageBuilder::escape_html(url),
+                    name,
+                    MessageBuilder::escape_html(&check.conclusion)
+                ),
+                _ => format!(
+                    "• {} — {}",
+                    name,
+                    MessageBuilder::escape_html(&check.conclusion)
+                ),
+            };
+            msg = msg.line(&line);
+        }
+
+        // check_run / check_suite приходят только от GitHub
+        self.notify(
+            WebhookProvider::Github,
+            payload.actor.as_deref(),
+            &payload.repo,
+            &payload.head_sha,
+            msg,
+        )
+        .await;
+    }
+}
+
+/// Пайплайны GitLab и Gitea приходят только как workflow — без этого пути
+/// ЛС о падении CI там не было бы
+#[async_trait]
+impl EventListener<WebhookWorkflowEvent> for WebhookCiFailDmListener {
+    async fn handle(&self, payload: &WebhookWorkflowEvent) {
+        if payload.status != "completed" {
+            return;
+        }
+        if payload.conclusion.as_deref() != Some("failure") {
+            return;
+        }
+
+        let short_sha = &payload.head_sha[..7.min(payload.head_sha.len())];
+
+        let mut msg = MessageBuilder::new()
+            .bold(&t!("telegram_bot.notifications.ci_fail.title"))
+            .empty_line()
+            .with_html_escape(true)
+            .section(
+                &t!("telegram_bot.notifications.ci_fail.workflow"),
+                &payload.name,
+            )
+            .section(
+                &t!("telegram_bot.notifications.ci_fail.repository"),
+                &payload.repo,
+            )
+            .section_code(
+                &t!("telegram_bot.notifications.ci_fail.branch"),
+                &payload.head_branch,
+            )
+            .section_code(&t!("telegram_bot.notifications.ci_fail.commit"), short_sha)
+            .with_html_escape(false);
+
+        if let Some(url) = payload.html_url.as_deref().map(str::trim)
+            && (url.starts_with("http://") || url.starts_with("https://"))
+        {
+            msg = msg.empty_line().raw(&format!(
+                "<a href=\"{}\">{}</a>",
+                MessageBuilder::escape_html(url),
+                t!("telegram_bot.notifications.ci_fail.open")
+            ));
+        }
+
+        self.notify(
+            payload.provider,
+            payload.actor.as_deref(),
+            &payload.repo,
+            &payload.head_sha,
+            msg,
+        )
+        .await;
+    }
+}
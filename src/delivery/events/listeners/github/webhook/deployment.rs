@@ -0,0 +1,112 @@
+use crate::application::deployment::commands::record_deployment::command::RecordDeploymentExecutorCommand;
+use crate::application::deployment::commands::record_deployment::executor::RecordDeploymentExecutor;
+use crate::delivery::events::listeners::github::webhook::resolve_chat_id;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::deployment::entities::environment_deployment::EnvironmentDeployment;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::events::deployment::WebhookDeploymentEvent;
+use crate::domain::webhook::events::deployment_status::WebhookDeploymentStatusEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct WebhookDeploymentEventListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub default_chat_id: SocialChatId,
+}
+
+impl WebhookDeploymentEventListener {
+    async fn notify(&self, provider: WebhookProvider, repo: &str, text: String) {
+        let chat_id =
+            resolve_chat_id(&self.repository_repo, provider, repo, self.default_chat_id).await;
+
+        self.publisher
+            .publish(&SendSocialNotifyJob {
+                social_type: SocialType::Telegram,
+                chat_id,
+                message: MessageBuilder::new().raw(text.as_str()),
+            })
+            .await
+            .ok();
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookDeploymentEvent> for WebhookDeploymentEventListener {
+    async fn handle(&self, payload: &WebhookDeploymentEvent) {
+        tracing::debug!(
+            repo = %payload.repo,
+            environment = %payload.environment,
+            sha = %payload.sha,
+            "Deployment webhook event received"
+        );
+
+        self.notify(WebhookProvider::Github, &payload.repo, payload.build_text())
+            .await;
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookDeploymentStatusEvent> for WebhookDeploymentEventListener {
+    async fn handle(&self, payload: &WebhookDeploymentStatusEvent) {
+        tracing::debug!(
+            repo = %payload.repo,
+            environment = %payload.environment,
+            state = %payload.state,
+            "Deployment status webhook event received"
+        );
+
+        // queued / in_progress идут сразу за созданием деплоя — не дублируем
+        if !payload.is_terminal() {
+            return;
+        }
+
+        self.notify(payload.provider, &payload.repo, payload.build_text())
+            .await;
+    }
+}
+
+/// Запоминает последний успешный деплой в окружение — для /deployments
+pub struct WebhookDeploymentRecorderListener {
+    pub record_deployment: Arc<RecordDeploymentExecutor>,
+}
+
+#[async_trait]
+impl EventListener<WebhookDeploymentStatusEvent> for WebhookDeploymentRecorderListener {
+    async fn handle(&self, payload: &WebhookDeploymentStatusEvent) {
+        if !payload.is_success() {
+            return;
+        }
+
+        let cmd = RecordDeploymentExecutorCommand {
+            deployment: EnvironmentDeployment {
+                version_control_type: payload.provider.version_control_type(),
+                repo: payload.repo.clone(),
+                environment: payload.environment.clone(),
+                deployment_id: payload.deployment_id,
+                sha: payload.sha.clone(),
+                ref_name: payload.ref_name.clone(),
+                deployed_by: payload.creator.clone(),
+                environment_url: payload.environment_url.clone(),
+                deployed_at: payload.created_at,
+            },
+        };
+
+        if let Err(e) = self.record_deployment.execute(&cmd).await {
+            tracing::error!(
+                error = %e,
+                repo = %payload.repo,
+                environment = %payload.environment,
+                "Failed to record deployment"
+            );
+        }
+    }
+}
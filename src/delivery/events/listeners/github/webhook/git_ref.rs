@@ -0,0 +1,61 @@
+use crate::delivery::events::listeners::github::webhook::find_repository;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::events::git_ref::WebhookRefEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct WebhookRefEventListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub default_chat_id: SocialChatId,
+}
+
+#[async_trait]
+impl EventListener<WebhookRefEvent> for WebhookRefEventListener {
+    async fn handle(&self, payload: &WebhookRefEvent) {
+        tracing::debug!(
+            repo = %payload.repo,
+            r#ref = %payload.ref_name,
+            ref_type = ?payload.ref_type,
+            action = ?payload.action,
+            "Ref webhook event received"
+        );
+
+        // Фильтр и чат берём из настроек репозитория, незарегистрированный — шлём всё
+        let repository =
+            find_repository(&self.repository_repo, payload.provider, &payload.repo).await;
+
+        if let Some(filter) = repository
+            .as_ref()
+            .and_then(|r| r.ref_events_filter.as_ref())
+            && !filter.matches(payload.ref_type, &payload.ref_name)
+        {
+            tracing::debug!(
+                repo = %payload.repo,
+                r#ref = %payload.ref_name,
+                "Ref event filtered out by repository settings"
+            );
+            return;
+        }
+
+        let chat_id = repository
+            .and_then(|r| r.social_chat_id)
+            .unwrap_or(self.default_chat_id);
+
+        self.publisher
+            .publish(&SendSocialNotifyJob {
+                social_type: SocialType::Telegram,
+                chat_id,
+                message: MessageBuilder::new().raw(payload.build_text().as_str()),
+            })
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,79 @@
+use crate::application::repository::commands::register_installation_repositories::command::RegisterInstallationRepositoriesCommand;
+use crate::application::repository::commands::register_installation_repositories::executor::RegisterInstallationRepositoriesExecutor;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::events::installation::{
+    WebhookInstallationAction, WebhookInstallationEvent,
+};
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::infrastructure::integrations::version_control::github::app::GithubAppClient;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Установка GitHub App: новые репозитории регистрируем, токен удалённой установки забываем
+pub struct WebhookInstallationEventListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub register_installation_repositories: Arc<RegisterInstallationRepositoriesExecutor>,
+    pub github_app: Option<Arc<GithubAppClient>>,
+    pub default_chat_id: SocialChatId,
+}
+
+#[async_trait]
+impl EventListener<WebhookInstallationEvent> for WebhookInstallationEventListener {
+    async fn handle(&self, payload: &WebhookInstallationEvent) {
+        tracing::debug!(
+            installation_id = payload.installation_id,
+            account = %payload.account,
+            action = ?payload.action,
+            added = payload.repositories_added.len(),
+            removed = payload.repositories_removed.len(),
+            "Installation webhook event received"
+        );
+
+        if matches!(
+            payload.action,
+            WebhookInstallationAction::Deleted | WebhookInstallationAction::Suspended
+        ) && let Some(app) = &self.github_app
+        {
+            app.forget_installation(payload.installation_id).await;
+        }
+
+        if !payload.repositories_added.is_empty() {
+            let cmd = RegisterInstallationRepositoriesCommand {
+                repositories: payload.repositories_added.clone(),
+            };
+
+            match self.register_installation_repositories.execute(&cmd).await {
+                Ok(r) if !r.created.is_empty() => {
+                    tracing::info!(
+                        installation_id = payload.installation_id,
+                        created = r.created.len(),
+                        "Repositories registered from GitHub App installation"
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::error!(
+                        error = %e,
+                        installation_id = payload.installation_id,
+                        "Failed to register installation repositories"
+                    );
+                }
+            }
+        }
+
+        self.publisher
+            .publish(&SendSocialNotifyJob {
+                social_type: SocialType::Telegram,
+                chat_id: self.default_chat_id,
+                message: MessageBuilder::new().raw(payload.build_text().as_str()),
+            })
+            .await
+            .ok();
+    }
+}
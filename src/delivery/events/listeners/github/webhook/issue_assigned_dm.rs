@@ -0,0 +1,89 @@
+use crate::delivery::events::listeners::github::webhook::resolve_user_by_login;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::webhook::events::issue::{WebhookIssueEvent, WebhookIssueEventActionType};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct WebhookIssueAssignedDmListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+}
+
+#[async_trait]
+impl EventListener<WebhookIssueEvent> for WebhookIssueAssignedDmListener {
+    async fn handle(&self, payload: &WebhookIssueEvent) {
+        if payload.action != WebhookIssueEventActionType::Assigned {
+            return;
+        }
+
+        let Some(assignee_login) = payload.assignee.as_deref() else {
+            return;
+        };
+
+        // Сам себя назначил — уведомлять незачем
+        if assignee_login.eq_ignore_ascii_case(&payload.source) {
+            return;
+        }
+
+        let Some((_, social_account)) = resolve_user_by_login(
+            &self.user_vc_accounts_repo,
+            &self.user_socials_repo,
+            WebhookProvider::Github,
+            assignee_login,
+        )
+        .await
+        else {
+            return;
+        };
+
+        let issue_url = payload.issue_url.as_deref().unwrap_or("");
+        let mut message = MessageBuilder::new()
+            .bold(t!("telegram_bot.notifications.issue_assigned.title").as_ref())
+            .empty_line()
+            .with_html_escape(true)
+            .section(
+                t!("telegram_bot.notifications.issue_assigned.issue").as_ref(),
+                &format!("#{} — {}", payload.number, payload.title),
+            )
+            .section(
+                t!("telegram_bot.notifications.issue_assigned.assigned_by").as_ref(),
+                &payload.source,
+            )
+            .section(
+                t!("telegram_bot.notifications.issue_assigned.repository").as_ref(),
+                &payload.repo,
+            )
+            .with_html_escape(false);
+
+        if !issue_url.is_empty() {
+            message = message.empty_line().raw(&format!(
+                "<a href=\"{}\">{}</a>",
+                MessageBuilder::escape_html(issue_url),
+                t!("telegram_bot.notifications.issue_assigned.open")
+            ));
+        }
+
+        tracing::debug!(
+            issue = payload.number,
+            assignee = %assignee_login,
+            "Sending issue-assigned DM"
+        );
+
+        self.publisher
+            .publish(&SendSocialNotifyJob {
+                social_type: SocialType::Telegram,
+                chat_id: social_account.social_chat_id,
+                message,
+            })
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,107 @@
+use crate::application::task::commands::create_task_card::command::CreateTaskCardExecutorCommand;
+use crate::application::task::commands::create_task_card::executor::CreateTaskCardExecutor;
+use crate::delivery::events::listeners::github::webhook::find_repository;
+use crate::delivery::jobs::consumers::move_task_to_test::payload::MoveTaskToTestJob;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::task::entities::task_move::TaskMoveOrigin;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_move_trigger::TaskMoveTrigger;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::domain::webhook::events::issue::{WebhookIssueEvent, WebhookIssueEventActionType};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Новый issue → карточка в колонке `issue_column_id` трекера. Без неё ничего не делает
+pub struct WebhookIssueTaskCardListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    pub create_task_card: Arc<CreateTaskCardExecutor>,
+}
+
+#[async_trait]
+impl EventListener<WebhookIssueEvent> for WebhookIssueTaskCardListener {
+    async fn handle(&self, payload: &WebhookIssueEvent) {
+        if payload.action != WebhookIssueEventActionType::Opened {
+            return;
+        }
+
+        let Some(repository) = find_repository(
+            &self.repository_repo,
+            WebhookProvider::Github,
+            &payload.repo,
+        )
+        .await
+        else {
+            return;
+        };
+
+        let Ok(tracker) = self
+            .repository_task_tracker_repo
+            .find_by_repository_id(repository.id)
+            .await
+        else {
+            return;
+        };
+
+        let Some(column_id) = tracker.issue_column_id.clone() else {
+            return;
+        };
+
+        // В GitHub Projects карточка и есть issue — его достаточно положить на доску
+        if tracker.kind == TaskTrackerKind::GithubProjects {
+            self.publisher
+                .publish(&MoveTaskToTestJob {
+                    task_id: TaskId(format!("{}#{}", payload.repo, payload.number)),
+                    column_id,
+                    kind: tracker.kind,
+                    connection_id: tracker.connection_id,
+                    origin: Some(TaskMoveOrigin {
+                        repository_id: repository.id,
+                        rule_id: None,
+                        trigger: TaskMoveTrigger::IssueOpened,
+                        pr_number: None,
+                    }),
+                })
+                .await
+                .ok();
+            return;
+        }
+
+        let mut description = payload.body.clone().unwrap_or_default();
+        if let Some(url) = &payload.issue_url {
+            if !description.is_empty() {
+                description.push_str("\n\n");
+            }
+            description.push_str(url);
+        }
+
+        let cmd = CreateTaskCardExecutorCommand {
+            repository_id: repository.id,
+            title: payload.title.clone(),
+            description,
+            column_id: Some(column_id),
+            assignee_login: None,
+        };
+
+        match self.create_task_card.execute(&cmd).await {
+            Ok(created) => tracing::info!(
+                task_id = %created.task_id.0,
+                repo = %payload.repo,
+                issue = payload.number,
+                "Issue mirrored to task tracker"
+            ),
+            Err(e) => tracing::error!(
+                error = %e,
+                repo = %payload.repo,
+                issue = payload.number,
+                "Failed to mirror issue to task tracker"
+            ),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::application::user::commands::sync_org_membership::command::SyncOrgMembershipExecutorCommand;
+use crate::application::user::commands::sync_org_membership::executor::SyncOrgMembershipExecutor;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::role::value_objects::role_name::RoleName;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::user::repositories::user_has_roles_repository::UserHasRolesRepository;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::webhook::events::member::{WebhookMemberAction, WebhookMemberEvent};
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use rust_i18n::t;
+use std::sync::Arc;
+
+/// Членство в организации → `is_active` пользователя, при изменении — ЛС админам
+pub struct WebhookMemberEventListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub sync_org_membership: Arc<SyncOrgMembershipExecutor>,
+    pub user_has_roles_repo: Arc<dyn UserHasRolesRepository>,
+    pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+}
+
+impl WebhookMemberEventListener {
+    async fn notify_admins(&self, payload: &WebhookMemberEvent) {
+        let admin_user_ids = match self
+            .user_has_roles_repo
+            .find_user_ids_by_role(RoleName::Admin)
+            .await
+        {
+            Ok(ids) => ids,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load admin user_ids for membership notify");
+                return;
+            }
+        };
+
+        let mut message = MessageBuilder::new();
+
+        message = match payload.action {
+            WebhookMemberAction::Removed => message
+                .bold(t!("telegram_bot.notifications.org_membership.deactivated_title").as_ref()),
+            WebhookMemberAction::Added => message
+                .bold(t!("telegram_bot.notifications.org_membership.reactivated_title").as_ref()),
+        };
+
+        message = message
+            .empty_line()
+            .with_html_escape(true)
+            .section(
+                t!("telegram_bot.notifications.org_membership.account").as_ref(),
+                &payload.login,
+            )
+            .section(
+                t!("telegram_bot.notifications.org_membership.organization").as_ref(),
+                &payload.organization,
+            );
+
+        if payload.action == WebhookMemberAction::Removed {
+            message = message
+                .empty_line()
+                .line(t!("telegram_bot.notifications.org_membership.deactivated_hint").as_ref());
+        }
+
+        for user_id in &admin_user_ids {
+            let social = match self.user_socials_repo.find_by_user_id(user_id).await {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+
+            self.publisher
+                .publish(&SendSocialNotifyJob {
+                    social_type: SocialType::Telegram,
+                    chat_id: social.social_chat_id,
+                    message: message.clone(),
+                })
+                .await
+                .ok();
+        }
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookMemberEvent> for WebhookMemberEventListener {
+    async fn handle(&self, payload: &WebhookMemberEvent) {
+        tracing::debug!(
+            organization = %payload.organization,
+            login = %payload.login,
+            scope = ?payload.scope,
+            action = ?payload.action,
+            "Member webhook event received"
+        );
+
+        let cmd = SyncOrgMembershipExecutorCommand {
+            action: payload.action,
+            scope: payload.scope,
+            organization: payload.organization.clone(),
+            version_control_user_id: payload.user_id,
+        };
+
+        match self.sync_org_membership.execute(&cmd).await {
+            Ok(r) if r.changed => self.notify_admins(payload).await,
+            Ok(_) => {}
+            Err(e) => {
+                tracing::error!(
+                    error = %e,
+                    login = %payload.login,
+                    "Failed to sync organization membership"
+                );
+            }
+        }
+    }
+}
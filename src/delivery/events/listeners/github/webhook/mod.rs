@@ -1,41 +1,74 @@
+pub mod branch_cleanup;
+pub mod check_aggregator;
 pub mod ci_fail_dm;
+pub mod deployment;
+pub mod git_ref;
+pub mod installation;
+pub mod issue;
+pub mod issue_assigned_dm;
+pub mod issue_task_card;
+pub mod member;
 pub mod pr_comment;
 pub mod pr_conflict;
 pub mod pr_mentions;
 pub mod pr_opened_tag_reviewers;
 pub mod pr_ready_to_merge;
+pub mod pr_task_id_guard;
+pub mod pr_task_links;
 pub mod pull_request;
 pub mod pull_request_review;
 pub mod push;
 pub mod re_review_nudge;
 pub mod release;
+pub mod repository;
 pub mod review_requested;
+pub mod task_card_dm;
+pub mod task_tracker_automation;
 pub mod workflow;
 
+use crate::domain::repository::entities::repository::Repository;
 use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::user::entities::user_social_account::UserSocialAccount;
+use crate::domain::user::entities::user_vc_account::UserVersionControlAccount;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
 use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use std::sync::Arc;
 
-/// Resolve the Telegram chat_id to notify for a given GitHub repository.
+/// Look up the registered repository an event belongs to.
 ///
-/// Parses `full_name` as `owner/repo`, looks up the repository record, and
-/// returns its `telegram_chat_id` if configured.  Falls back to
-/// `default_chat_id` when the repository is not found or has no chat
-/// configured.
+/// `full_name` is `owner/repo` as sent by `provider`; for GitLab the owner
+/// may include subgroups (`group/sub/repo`). `None` when the name is
+/// malformed or the repository is not registered with the bot.
+pub async fn find_repository(
+    repository_repo: &Arc<dyn RepositoryRepository>,
+    provider: WebhookProvider,
+    full_name: &str,
+) -> Option<Repository> {
+    let (owner, name) = full_name.rsplit_once('/')?;
+    let version_control_type = provider.version_control_type();
+
+    repository_repo
+        .find_by_owner_and_name(&version_control_type, owner, name)
+        .await
+        .ok()
+}
+
+/// Resolve the Telegram chat_id to notify for a given repository.
+///
+/// Looks up the repository record and returns its `telegram_chat_id` if
+/// configured.  Falls back to `default_chat_id` when the repository is not
+/// found or has no chat configured.
 pub async fn resolve_chat_id(
     repository_repo: &Arc<dyn RepositoryRepository>,
+    provider: WebhookProvider,
     full_name: &str,
     default_chat_id: SocialChatId,
 ) -> SocialChatId {
-    let mut parts = full_name.splitn(2, '/');
-    let (owner, name) = match (parts.next(), parts.next()) {
-        (Some(o), Some(n)) => (o, n),
-        _ => return default_chat_id,
-    };
-
-    match repository_repo.find_by_owner_and_name(owner, name).await {
-        Ok(repo) => repo.social_chat_id.unwrap_or(default_chat_id),
-        Err(_) => default_chat_id,
+    match find_repository(repository_repo, provider, full_name).await {
+        Some(repo) => repo.social_chat_id.unwrap_or(default_chat_id),
+        None => default_chat_id,
     }
 }
 
@@ -43,20 +76,51 @@ pub async fn resolve_chat_id(
 /// Priority: `notifications_chat_id` → `social_chat_id` → default.
 pub async fn resolve_notifications_chat_id(
     repository_repo: &Arc<dyn RepositoryRepository>,
+    provider: WebhookProvider,
     full_name: &str,
     default_chat_id: SocialChatId,
 ) -> SocialChatId {
-    let mut parts = full_name.splitn(2, '/');
-    let (owner, name) = match (parts.next(), parts.next()) {
-        (Some(o), Some(n)) => (o, n),
-        _ => return default_chat_id,
-    };
-
-    match repository_repo.find_by_owner_and_name(owner, name).await {
-        Ok(repo) => repo
+    match find_repository(repository_repo, provider, full_name).await {
+        Some(repo) => repo
             .notifications_chat_id
             .or(repo.social_chat_id)
             .unwrap_or(default_chat_id),
-        Err(_) => default_chat_id,
+        None => default_chat_id,
+    }
+}
+
+/// Resolve a version control login to the linked bot user.
+///
+/// Returns both accounts so callers can use the `user_id` (preferences,
+/// dedup log) and the social `chat_id` for the DM. `None` when the login is
+/// not registered with this provider or has no social account.
+pub async fn resolve_user_by_login(
+    user_vc_accounts_repo: &Arc<dyn UserVersionControlAccountsRepository>,
+    user_socials_repo: &Arc<dyn UserSocialAccountsRepository>,
+    provider: WebhookProvider,
+    login: &str,
+) -> Option<(UserVersionControlAccount, UserSocialAccount)> {
+    let version_control_type = provider.version_control_type();
+
+    let vc_account = match user_vc_accounts_repo
+        .find_by_login(&version_control_type, login)
+        .await
+    {
+        Ok(account) => account,
+        Err(_) => {
+            tracing::debug!(login = %login, "User not found in DB — skipping DM");
+            return None;
+        }
+    };
+
+    match user_socials_repo.find_by_user_id(&vc_account.user_id).await {
+        Ok(social_account) => Some((vc_account, social_account)),
+        Err(_) => {
+            tracing::debug!(
+                user_id = ?vc_account.user_id,
+                "User has no social account — skipping DM"
+            );
+            None
+        }
     }
 }
@@ -19,20 +19,30 @@ pub struct WebhookPrCommentEventListener {
 #[async_trait]
 impl EventListener<WebhookPrCommentEvent> for WebhookPrCommentEventListener {
     async fn handle(&self, payload: &WebhookPrCommentEvent) {
+        let Some(pr_author) = payload.pr_author.as_deref() else {
+            tracing::debug!(
+                pr = payload.pr_number,
+                "PR author is unknown — skipping comment notification"
+            );
+            return;
+        };
+
         // Don't notify the PR author when they comment on their own PR.
-        if payload.commenter.eq_ignore_ascii_case(&payload.pr_author) {
+        if payload.commenter.eq_ignore_ascii_case(pr_author) {
             return;
         }
 
+        let version_control_type = payload.provider.version_control_type();
+
         let vc_account = match self
             .user_vc_accounts_repo
-            .find_by_login(&payload.pr_author)
+            .find_by_login(&version_control_type, pr_author)
             .await
         {
             Ok(account) => account,
             Err(_) => {
                 tracing::debug!(
-                    pr_author = %payload.pr_author,
+                    pr_author = %pr_author,
                     "No VC account found for PR author — skipping comment notification"
                 );
                 return;
@@ -40,9 +40,14 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrConflictDetectedListene
             return;
         }
 
+        let Some(author) = payload.author.as_deref() else {
+            return;
+        };
+        let version_control_type = payload.provider.version_control_type();
+
         let vc_account = match self
             .user_vc_accounts_repo
-            .find_by_login(&payload.author)
+            .find_by_login(&version_control_type, author)
             .await
         {
             Ok(a) => a,
@@ -105,7 +110,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrConflictDetectedListene
 
         tracing::info!(
             pr = payload.number,
-            author = %payload.author,
+            author = %author,
             "Sending PR conflict DM"
         );
 
@@ -45,13 +45,19 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrMentionsListener {
             return;
         }
 
+        let version_control_type = payload.provider.version_control_type();
+
         let mut bound: Vec<(String, SocialChatId, Option<String>)> = Vec::new();
 
         for login in &mentions {
-            if login.eq_ignore_ascii_case(&payload.author) {
+            if payload.is_author(login) {
                 continue;
             }
-            let vc_account = match self.user_vc_accounts_repo.find_by_login(login).await {
+            let vc_account = match self
+                .user_vc_accounts_repo
+                .find_by_login(&version_control_type, login)
+                .await
+            {
                 Ok(a) => a,
                 Err(_) => continue,
             };
@@ -87,7 +93,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrMentionsListener {
                 )
                 .section(
                     &t!("telegram_bot.notifications.pr_mention.author").to_string(),
-                    &payload.author,
+                    payload.author.as_deref().unwrap_or("—"),
                 )
                 .section(
                     &t!("telegram_bot.notifications.pr_mention.repository").to_string(),
@@ -127,6 +133,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrMentionsListener {
         if !tags.is_empty() {
             let chat_id = resolve_notifications_chat_id(
                 &self.repository_repo,
+                payload.provider,
                 &payload.repo,
                 self.default_chat_id,
             )
@@ -9,6 +9,7 @@ use crate::domain::user::value_objects::social_type::SocialType;
 use crate::domain::webhook::events::pull_request::{
     WebhookPullRequestEvent, WebhookPullRequestEventActionType,
 };
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
 use crate::utils::builder::message::MessageBuilder;
 use async_trait::async_trait;
@@ -38,7 +39,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrOpenedTagReviewersListe
         } else {
             let mut tag_parts: Vec<String> = Vec::new();
             for login in &payload.requested_reviewers {
-                let display = match self.resolve_tg_username(login).await {
+                let display = match self.resolve_tg_username(payload.provider, login).await {
                     Some(tg_username) => format!("@{}", tg_username),
                     None => login.clone(),
                 };
@@ -49,6 +50,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrOpenedTagReviewersListe
 
         let chat_id = resolve_notifications_chat_id(
             &self.repository_repo,
+            payload.provider,
             &payload.repo,
             self.default_chat_id,
         )
@@ -65,7 +67,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrOpenedTagReviewersListe
             )
             .section(
                 &t!("telegram_bot.notifications.pr_opened_tag.author").to_string(),
-                &payload.author,
+                payload.author.as_deref().unwrap_or("—"),
             )
             .section(
                 &t!("telegram_bot.notifications.pr_opened_tag.repository").to_string(),
@@ -102,10 +104,10 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrOpenedTagReviewersListe
 }
 
 impl WebhookPrOpenedTagReviewersListener {
-    async fn resolve_tg_username(&self, github_login: &str) -> Option<String> {
+    async fn resolve_tg_username(&self, provider: WebhookProvider, login: &str) -> Option<String> {
         let vc = self
             .user_vc_accounts_repo
-            .find_by_login(github_login)
+            .find_by_login(&provider.version_control_type(), login)
             .await
             .ok()?;
         let social = self
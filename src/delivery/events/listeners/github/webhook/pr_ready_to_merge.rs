@@ -1,5 +1,12 @@
+use crate::delivery::events::listeners::github::webhook::find_repository;
 use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::commit_check::entities::commit_checks_summary::CommitChecksSummary;
+use crate::domain::commit_check::events::checks_completed::CommitChecksCompletedEvent;
+use crate::domain::commit_check::repositories::commit_check_repository::CommitCheckRepository;
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
 use crate::domain::notification_log::repositories::notification_log_repository::NotificationLogRepository;
+use crate::domain::pr_review::repositories::pr_review_repository::PrReviewRepository;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
 use crate::domain::role::value_objects::role_name::RoleName;
 use crate::domain::shared::events::event_listener::EventListener;
 use crate::domain::user::repositories::user_has_roles_repository::UserHasRolesRepository;
@@ -8,6 +15,7 @@ use crate::domain::user::value_objects::social_type::SocialType;
 use crate::domain::webhook::events::pull_request_review::{
     WebhookPullRequestReviewEvent, WebhookPullRequestReviewState,
 };
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
 use crate::utils::builder::message::MessageBuilder;
 use async_trait::async_trait;
@@ -22,19 +30,68 @@ pub struct WebhookPrReadyToMergeListener {
     pub notification_log_repo: Arc<dyn NotificationLogRepository>,
     pub user_has_roles_repo: Arc<dyn UserHasRolesRepository>,
     pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    pub commit_check_repo: Arc<dyn CommitCheckRepository>,
+    pub pr_review_repo: Arc<dyn PrReviewRepository>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
 }
 
-#[async_trait]
-impl EventListener<WebhookPullRequestReviewEvent> for WebhookPrReadyToMergeListener {
-    async fn handle(&self, payload: &WebhookPullRequestReviewEvent) {
-        if !matches!(payload.state, WebhookPullRequestReviewState::Approved) {
-            return;
+/// Что знаем о PR в момент уведомления
+struct ReadyPullRequest<'a> {
+    repo: &'a str,
+    number: u64,
+    title: Option<&'a str>,
+    author: Option<&'a str>,
+    url: String,
+}
+
+impl WebhookPrReadyToMergeListener {
+    /// Итог CI по коммиту, если по нему приходили check_run / check_suite
+    async fn ci_state(&self, repo: &str, sha: &str) -> Option<CommitCiState> {
+        match self.commit_check_repo.find_by_commit(repo, sha).await {
+            Ok(checks) if !checks.is_empty() => {
+                Some(CommitChecksSummary::from_checks(&checks).state)
+            }
+            Ok(_) => None,
+            Err(e) => {
+                tracing::warn!(error = %e, repo = %repo, sha = %sha, "Failed to load commit checks");
+                None
+            }
         }
+    }
 
-        if payload.mergeable_state.as_deref() != Some("clean") {
-            return;
+    /// Ссылка на PR по адресу репозитория; пустая, если репозиторий не заведён
+    async fn pull_request_url(
+        &self,
+        provider: WebhookProvider,
+        repo: &str,
+        pr_number: u64,
+    ) -> String {
+        match find_repository(&self.repository_repo, provider, repo).await {
+            Some(repository) => repository.pull_request_url(pr_number),
+            None => {
+                tracing::debug!(repo = %repo, "Repository not found for PR link");
+                String::new()
+            }
         }
+    }
 
+    /// Есть хотя бы один approve и никто не просил изменений
+    async fn is_approved(&self, repo: &str, pr_number: u64) -> bool {
+        match self.pr_review_repo.find_by_pr(repo, pr_number).await {
+            Ok(reviews) => {
+                reviews.iter().any(|r| r.last_review_state == "approved")
+                    && !reviews
+                        .iter()
+                        .any(|r| r.last_review_state == "changes_requested")
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, repo = %repo, pr = pr_number, "Failed to load PR reviews");
+                false
+            }
+        }
+    }
+
+    async fn notify_admins(&self, pr: ReadyPullRequest<'_>) {
         let admin_user_ids = match self
             .user_has_roles_repo
             .find_user_ids_by_role(RoleName::Admin)
@@ -51,9 +108,14 @@ impl EventListener<WebhookPullRequestReviewEvent> for WebhookPrReadyToMergeListe
             return;
         }
 
-        let key = format!("{}:{}", payload.repo, payload.pr_number);
+        let key = format!("{}:{}", pr.repo, pr.number);
         let since = Utc::now() - Duration::hours(COOLDOWN_HOURS);
 
+        let pr_label = match pr.title {
+            Some(title) => format!("#{} — {}", pr.number, title),
+            None => format!("#{}", pr.number),
+        };
+
         for user_id in &admin_user_ids {
             match self
                 .notification_log_repo
@@ -74,28 +136,32 @@ impl EventListener<WebhookPullRequestReviewEvent> for WebhookPrReadyToMergeListe
             };
 
             let mut msg = MessageBuilder::new()
-                .bold(&t!("telegram_bot.notifications.pr_ready_to_merge.title").to_string())
+                .bold(&t!("telegram_bot.notifications.pr_ready_to_merge.title"))
                 .empty_line()
                 .with_html_escape(true)
                 .section(
-                    &t!("telegram_bot.notifications.pr_ready_to_merge.pr").to_string(),
-                    &format!("#{} — {}", payload.pr_number, payload.pr_title),
-                )
-                .section(
-                    &t!("telegram_bot.notifications.pr_ready_to_merge.repository").to_string(),
-                    &payload.repo,
+                    &t!("telegram_bot.notifications.pr_ready_to_merge.pr"),
+                    &pr_label,
                 )
                 .section(
-                    &t!("telegram_bot.notifications.pr_ready_to_merge.author").to_string(),
-                    &payload.pr_author,
-                )
-                .with_html_escape(false);
+                    &t!("telegram_bot.notifications.pr_ready_to_merge.repository"),
+                    pr.repo,
+                );
+
+            if let Some(author) = pr.author {
+                msg = msg.section(
+                    &t!("telegram_bot.notifications.pr_ready_to_merge.author"),
+                    author,
+                );
+            }
+
+            msg = msg.with_html_escape(false);
 
-            if !payload.pr_url.is_empty() {
+            if !pr.url.is_empty() {
                 msg = msg.empty_line().raw(&format!(
                     "<a href=\"{}\">{}</a>",
-                    MessageBuilder::escape_html(&payload.pr_url),
-                    t!("telegram_bot.notifications.pr_ready_to_merge.open").to_string()
+                    MessageBuilder::escape_html(&pr.url),
+                    t!("telegram_bot.notifications.pr_ready_to_merge.open")
                 ));
             }
 
@@ -118,3 +184,68 @@ impl EventListener<WebhookPullRequestReviewEvent> for WebhookPrReadyToMergeListe
         }
     }
 }
+
+#[async_trait]
+impl EventListener<WebhookPullRequestReviewEvent> for WebhookPrReadyToMergeListener {
+    async fn handle(&self, payload: &WebhookPullRequestReviewEvent) {
+        if !matches!(payload.state, WebhookPullRequestReviewState::Approved) {
+            return;
+        }
+
+        if payload.mergeable_state.as_deref() != Some("clean") {
+            return;
+        }
+
+        // Проверки ещё идут или упали — уведомим по CommitChecksCompletedEvent
+        if let Some(sha) = &payload.head_sha {
+            match self.ci_state(&payload.repo, sha).await {
+                Some(CommitCiState::Success) | None => {}
+                Some(state) => {
+                    tracing::debug!(
+                        repo = %payload.repo,
+                        pr = payload.pr_number,
+                        state = %state,
+                        "PR approved but CI is not green — waiting for checks"
+                    );
+                    return;
+                }
+            }
+        }
+
+        self.notify_admins(ReadyPullRequest {
+            repo: &payload.repo,
+            number: payload.pr_number,
+            title: Some(&payload.pr_title),
+            author: Some(&payload.pr_author),
+            url: payload.pr_url.clone(),
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl EventListener<CommitChecksCompletedEvent> for WebhookPrReadyToMergeListener {
+    async fn handle(&self, payload: &CommitChecksCompletedEvent) {
+        if payload.state != CommitCiState::Success {
+            return;
+        }
+
+        for pr_number in &payload.pr_numbers {
+            if !self.is_approved(&payload.repo, *pr_number).await {
+                continue;
+            }
+
+            self.notify_admins(ReadyPullRequest {
+                repo: &payload.repo,
+                number: *pr_number,
+                title: None,
+                author: None,
+                // check_run / check_suite приходят только от GitHub
+                url: self
+                    .pull_request_url(WebhookProvider::Github, &payload.repo, *pr_number)
+                    .await,
+            })
+            .await;
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::delivery::events::listeners::github::webhook::find_repository;
+use crate::delivery::events::listeners::github::webhook::resolve_user_by_login;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::notification_log::repositories::notification_log_repository::NotificationLogRepository;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::task::services::task_tracker_service::TaskTrackerService;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::version_control::ports::version_control_client::{
+    CommitStatus, CommitStatusState,
+};
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
+use crate::domain::webhook::events::pull_request::{
+    WebhookPullRequestEvent, WebhookPullRequestEventActionType,
+};
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+const STATUS_CONTEXT: &str = "task-id/required";
+const KIND: &str = "pr_task_id_missing";
+const COOLDOWN_HOURS: i64 = 24;
+
+/// Проверяет, что в заголовке, ветке или описании PR есть ID задачи. Без него
+/// карточка не двигается по правилам трекера, поэтому PR получает красный
+/// статус, а автор — подсказку с ожидаемым шаблоном
+pub struct WebhookPrTaskIdGuardListener {
+    pub task_tracker_service: Arc<dyn TaskTrackerService>,
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    pub version_control_clients: Arc<dyn VersionControlClientProvider>,
+    pub version_control_tokens: Arc<dyn VersionControlTokenProvider>,
+    pub notification_log_repo: Arc<dyn NotificationLogRepository>,
+    pub user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+}
+
+#[async_trait]
+impl EventListener<WebhookPullRequestEvent> for WebhookPrTaskIdGuardListener {
+    async fn handle(&self, payload: &WebhookPullRequestEvent) {
+        if !matches!(
+            payload.action,
+            WebhookPullRequestEventActionType::Opened
+                | WebhookPullRequestEventActionType::Edited
+                | WebhookPullRequestEventActionType::Synchronize
+        ) {
+            return;
+        }
+
+        let Some(sha) = payload.head_sha.as_deref() else {
+            return;
+        };
+
+        let Some(repository) =
+            find_repository(&self.repository_repo, payload.provider, &payload.repo).await
+        else {
+            return;
+        };
+
+        let Ok(tracker) = self
+            .repository_task_tracker_repo
+            .find_by_repository_id(repository.id)
+            .await
+        else {
+            return;
+        };
+
+        if !tracker.require_task_id {
+            return;
+        }
+
+        let pattern = &tracker.extract_pattern_regexp;
+        let found = [
+            payload.title.as_str(),
+            payload.head_ref.as_str(),
+            payload.body.as_deref().unwrap_or_default(),
+        ]
+        .into_iter()
+        .find_map(|text| {
+            self.task_tracker_service
+                .extract_match_with_pattern(text, pattern)
+        });
+
+        let status = match &found {
+            Some((_, task_id)) => CommitStatus {
+                state: CommitStatusState::Success,
+                context: STATUS_CONTEXT.to_string(),
+                description: t!(
+                    "telegram_bot.notifications.pr_task_id_missing.status_success",
+                    task = task_id.0
+                )
+                .to_string(),
+                target_url: None,
+            },
+            None => CommitStatus {
+                state: CommitStatusState::Failure,
+                context: STATUS_CONTEXT.to_string(),
+                description: t!("telegram_bot.notifications.pr_task_id_missing.status_failure")
+                    .to_string(),
+                target_url: None,
+            },
+        };
+
+        match self
+            .version_control_tokens
+            .background_token(&repository)
+            .await
+        {
+            Some(token) => {
+                if let Err(e) = self
+                    .version_control_clients
+                    .client(&repository.version_control_type)
+                    .create_commit_status(&token, &repository.owner, &repository.name, sha, &status)
+                    .await
+                {
+                    tracing::warn!(
+                        error = %e,
+                        repo = %payload.repo,
+                        pr = payload.number,
+                        "Failed to publish task id commit status"
+                    );
+                }
+            }
+            None => {
+                tracing::debug!(
+                    repo = %payload.repo,
+                    "No token available — skipping task id commit status"
+                );
+            }
+        }
+
+        if found.is_none() {
+            self.notify_author(payload, pattern).await;
+        }
+    }
+}
+
+impl WebhookPrTaskIdGuardListener {
+    async fn notify_author(&self, payload: &WebhookPullRequestEvent, pattern: &str) {
+        let Some(author) = payload.author.as_deref() else {
+            return;
+        };
+
+        let Some((vc_account, social_account)) = resolve_user_by_login(
+            &self.user_vc_accounts_repo,
+            &self.user_socials_repo,
+            payload.provider,
+            author,
+        )
+        .await
+        else {
+            return;
+        };
+
+        // Каждый пуш в ветку снова присылает событие — пишем автору не чаще раза в сутки
+        let key = format!("{}:{}", payload.repo, payload.number);
+        let since = Utc::now() - Duration::hours(COOLDOWN_HOURS);
+
+        match self
+            .notification_log_repo
+            .was_sent_within(vc_account.user_id, KIND, &key, since)
+            .await
+        {
+            Ok(true) => return,
+            Ok(false) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed dedup check for task id notification");
+                return;
+            }
+        }
+
+        let mut message = MessageBuilder::new()
+            .bold(t!("telegram_bot.notifications.pr_task_id_missing.title").as_ref())
+            .empty_line()
+            .with_html_escape(true)
+            .section(
+                t!("telegram_bot.notifications.pr_task_id_missing.pr").as_ref(),
+                &format!("#{} — {}", payload.number, payload.title),
+            )
+            .section(
+                t!("telegram_bot.notifications.pr_task_id_missing.repository").as_ref(),
+                &payload.repo,
+            )
+            .section_code(
+                t!("telegram_bot.notifications.pr_task_id_missing.pattern").as_ref(),
+                pattern,
+            )
+            .empty_line()
+            .line(t!("telegram_bot.notifications.pr_task_id_missing.hint").as_ref())
+            .with_html_escape(false);
+
+        if let Some(pr_url) = payload.pr_url.as_deref() {
+            message = message.empty_line().raw(&format!(
+                "<a href=\"{}\">{}</a>",
+                MessageBuilder::escape_html(pr_url),
+                t!("telegram_bot.notifications.pr_task_id_missing.open")
+            ));
+        }
+
+        self.publisher
+            .publish(&SendSocialNotifyJob {
+                social_type: SocialType::Telegram,
+                chat_id: social_account.social_chat_id,
+                message,
+            })
+            .await
+            .ok();
+
+        if let Err(e) = self
+            .notification_log_repo
+            .record_sent(vc_account.user_id, KIND, &key)
+            .await
+        {
+            tracing::warn!(error = %e, "Failed to record task id notification");
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::delivery::events::listeners::github::webhook::find_repository;
+use crate::delivery::jobs::consumers::annotate_task_card::payload::AnnotateTaskCardJob;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::task::entities::pull_request_task_link::PullRequestTaskLink;
+use crate::domain::task::repositories::pull_request_task_link_repository::PullRequestTaskLinkRepository;
+use crate::domain::task::services::task_tracker_service::TaskTrackerService;
+use crate::domain::task::value_objects::task_card_annotation::TaskCardAnnotation;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::webhook::events::pull_request::{
+    WebhookPullRequestEvent, WebhookPullRequestEventActionType,
+};
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// Запоминает, какие задачи трекера упомянуты в PR, — по этим связям
+/// входящие события трекера находят автора PR. Заодно оставляет на карточке
+/// ссылку на PR, как только задача в нём появилась, и комментарий о мерже
+pub struct WebhookPullRequestTaskLinksListener {
+    pub task_tracker_service: Arc<dyn TaskTrackerService>,
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    pub pull_request_task_link_repo: Arc<dyn PullRequestTaskLinkRepository>,
+}
+
+impl WebhookPullRequestTaskLinksListener {
+    /// Ссылка пишется при создании связи, каким бы событием она ни появилась:
+    /// задачу часто дописывают в заголовок уже открытого PR
+    fn annotations(
+        payload: &WebhookPullRequestEvent,
+        link_created: bool,
+    ) -> Vec<TaskCardAnnotation> {
+        let Some(pr_url) = payload.pr_url.clone() else {
+            return Vec::new();
+        };
+
+        let mut annotations = Vec::new();
+
+        if link_created {
+            annotations.push(TaskCardAnnotation::Link {
+                title: t!(
+                    "telegram_bot.notifications.task_card_annotation.link_title",
+                    number = payload.number,
+                    title = payload.title
+                )
+                .to_string(),
+                url: pr_url.clone(),
+            });
+        }
+
+        if payload.action == WebhookPullRequestEventActionType::Closed && payload.merged {
+            annotations.push(TaskCardAnnotation::Comment {
+                text: t!(
+                    "telegram_bot.notifications.task_card_annotation.merged",
+                    number = payload.number,
+                    title = payload.title,
+                    base = payload.base_ref,
+                    merged_by = payload.merged_by.as_deref().unwrap_or(&payload.source),
+                    url = pr_url
+                )
+                .to_string(),
+            });
+        }
+
+        annotations
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookPullRequestEvent> for WebhookPullRequestTaskLinksListener {
+    async fn handle(&self, payload: &WebhookPullRequestEvent) {
+        let relevant = match payload.action {
+            WebhookPullRequestEventActionType::Opened
+            | WebhookPullRequestEventActionType::Reopened
+            | WebhookPullRequestEventActionType::Edited
+            | WebhookPullRequestEventActionType::ReadyForReview
+            | WebhookPullRequestEventActionType::Synchronize => true,
+            WebhookPullRequestEventActionType::Closed => payload.merged,
+            _ => false,
+        };
+        if !relevant {
+            return;
+        }
+
+        let Some(repository) =
+            find_repository(&self.repository_repo, payload.provider, &payload.repo).await
+        else {
+            return;
+        };
+
+        let Ok(tracker) = self
+            .repository_task_tracker_repo
+            .find_by_repository_id(repository.id)
+            .await
+        else {
+            return;
+        };
+
+        let text = format!(
+            "{}\n{}\n{}",
+            payload.title,
+            payload.body.as_deref().unwrap_or_default(),
+            payload.head_ref
+        );
+
+        let mut task_ids: Vec<TaskId> = Vec::new();
+        for (_, task_id) in self
+            .task_tracker_service
+            .extract_all_matches_with_pattern(&text, &tracker.extract_pattern_regexp)
+        {
+            let task_id = tracker.kind.qualify_task_id(task_id, &payload.repo);
+            if !task_ids.contains(&task_id) {
+                task_ids.push(task_id);
+            }
+        }
+
+        let merged_at = payload.merged.then(|| {
+            payload
+                .merged_at
+                .as_deref()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(Utc::now)
+        });
+
+        for task_id in task_ids {
+            let link = PullRequestTaskLink {
+                repository_id: repository.id,
+                task_id,
+                pr_number: payload.number,
+                pr_title: payload.title.clone(),
+                pr_url: payload.pr_url.clone(),
+                author_login: payload.author.clone(),
+                head_sha: payload.head_sha.clone(),
+                merged_at,
+            };
+
+            let link_created = match self.pull_request_task_link_repo.upsert(&link).await {
+                Ok(created) => created,
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        repo = %payload.repo,
+                        pr = payload.number,
+                        task_id = %link.task_id,
+                        "Failed to store pull request task link"
+                    );
+                    false
+                }
+            };
+
+            if !tracker.kind.accepts(&link.task_id) {
+                continue;
+            }
+
+            for annotation in Self::annotations(payload, link_created) {
+                self.publisher
+                    .publish(&AnnotateTaskCardJob {
+                        task_id: link.task_id.clone(),
+                        kind: tracker.kind,
+                        connection_id: tracker.connection_id,
+                        annotation,
+                    })
+                    .await
+                    .ok();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+
+    fn event(action: WebhookPullRequestEventActionType, merged: bool) -> WebhookPullRequestEvent {
+        WebhookPullRequestEvent {
+            provider: WebhookProvider::Github,
+            source: "octocat".to_string(),
+            author: Some("octocat".to_string()),
+            repo: "acme/app".to_string(),
+            repo_url: None,
+            title: "APP-42: fix login".to_string(),
+            body: None,
+            number: 7,
+            action,
+            merged,
+            merged_by: None,
+            draft: false,
+            state: "open".to_string(),
+            head_ref: "fix-login".to_string(),
+            base_ref: "main".to_string(),
+            head_repo: "acme:fix-login".to_string(),
+            base_repo: "acme:main".to_string(),
+            pr_url: Some("https://github.com/acme/app/pull/7".to_string()),
+            merge_commit: None,
+            head_sha: None,
+            assignees: Vec::new(),
+            created_at: String::new(),
+            updated_at: String::new(),
+            merged_at: None,
+            commits: 1,
+            additions: 1,
+            deletions: 0,
+            changed_files: 1,
+            requested_reviewer: None,
+            requested_reviewers: Vec::new(),
+            mergeable_state: None,
+        }
+    }
+
+    #[test]
+    fn edited_pr_that_creates_a_link_writes_it_to_the_card() {
+        let annotations = WebhookPullRequestTaskLinksListener::annotations(
+            &event(WebhookPullRequestEventActionType::Edited, false),
+            true,
+        );
+
+        assert_eq!(annotations.len(), 1);
+        match &annotations[0] {
+            TaskCardAnnotation::Link { url, .. } => {
+                assert_eq!(url, "https://github.com/acme/app/pull/7")
+            }
+            other => panic!("expected a link, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn edited_pr_with_an_existing_link_leaves_the_card_alone() {
+        let annotations = WebhookPullRequestTaskLinksListener::annotations(
+            &event(WebhookPullRequestEventActionType::Edited, false),
+            false,
+        );
+
+        assert!(annotations.is_empty());
+    }
+
+    #[test]
+    fn merge_comments_the_card_even_when_the_link_exists() {
+        let annotations = WebhookPullRequestTaskLinksListener::annotations(
+            &event(WebhookPullRequestEventActionType::Closed, true),
+            false,
+        );
+
+        assert!(matches!(
+            annotations.as_slice(),
+            [TaskCardAnnotation::Comment { .. }]
+        ));
+    }
+}
@@ -55,9 +55,11 @@ impl EventListener<WebhookPullRequestReviewEvent> for WebhookPullRequestReviewEv
             return;
         }
 
+        let version_control_type = payload.provider.version_control_type();
+
         let vc_account = match self
             .user_vc_accounts_repo
-            .find_by_login(&payload.pr_author)
+            .find_by_login(&version_control_type, &payload.pr_author)
             .await
         {
             Ok(account) => account,
@@ -27,8 +27,18 @@ impl EventListener<WebhookPushEvent> for WebhookPushEventListener {
             "Push webhook event received"
         );
 
-        let chat_id =
-            resolve_chat_id(&self.repository_repo, &payload.repo, self.default_chat_id).await;
+        // Создание/удаление ветки без коммитов придёт отдельным событием create/delete
+        if (payload.created || payload.deleted) && payload.commits.is_empty() {
+            return;
+        }
+
+        let chat_id = resolve_chat_id(
+            &self.repository_repo,
+            payload.provider,
+            &payload.repo,
+            self.default_chat_id,
+        )
+        .await;
 
         self.publisher
             .publish(&SendSocialNotifyJob {
@@ -32,6 +32,8 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrReReviewNudgeListener {
             return;
         }
 
+        let version_control_type = payload.provider.version_control_type();
+
         let reviews = match self
             .pr_review_repo
             .find_by_pr(&payload.repo, payload.number)
@@ -52,13 +54,13 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrReReviewNudgeListener {
         let since = Utc::now() - Duration::hours(self.dedup_hours);
 
         for review in reviews {
-            if review.reviewer_login.eq_ignore_ascii_case(&payload.author) {
+            if payload.is_author(&review.reviewer_login) {
                 continue;
             }
 
             let vc_account = match self
                 .user_vc_accounts_repo
-                .find_by_login(&review.reviewer_login)
+                .find_by_login(&version_control_type, &review.reviewer_login)
                 .await
             {
                 Ok(a) => a,
@@ -105,7 +107,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookPrReReviewNudgeListener {
                 )
                 .section(
                     &t!("telegram_bot.notifications.re_review_nudge.author").to_string(),
-                    &payload.author,
+                    payload.author.as_deref().unwrap_or("—"),
                 )
                 .section(
                     &t!("telegram_bot.notifications.re_review_nudge.repository").to_string(),
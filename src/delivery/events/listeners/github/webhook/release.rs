@@ -26,8 +26,13 @@ impl EventListener<WebhookReleaseEvent> for WebhookReleaseEventListener {
             "Release webhook event received"
         );
 
-        let chat_id =
-            resolve_chat_id(&self.repository_repo, &payload.repo, self.default_chat_id).await;
+        let chat_id = resolve_chat_id(
+            &self.repository_repo,
+            payload.provider,
+            &payload.repo,
+            self.default_chat_id,
+        )
+        .await;
 
         self.publisher
             .publish(&SendSocialNotifyJob {
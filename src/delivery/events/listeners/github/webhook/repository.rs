@@ -0,0 +1,64 @@
+use crate::application::repository::commands::sync_repository_from_webhook::command::SyncRepositoryFromWebhookCommand;
+use crate::application::repository::commands::sync_repository_from_webhook::executor::SyncRepositoryFromWebhookExecutor;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::events::repository::WebhookRepositoryEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Переименование / передача / архивирование → обновляем запись репозитория и шлём карточку
+pub struct WebhookRepositoryEventListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub sync_repository_from_webhook: Arc<SyncRepositoryFromWebhookExecutor>,
+    pub default_chat_id: SocialChatId,
+}
+
+#[async_trait]
+impl EventListener<WebhookRepositoryEvent> for WebhookRepositoryEventListener {
+    async fn handle(&self, payload: &WebhookRepositoryEvent) {
+        tracing::debug!(
+            repo = %payload.repo,
+            previous = ?payload.previous_repo,
+            action = ?payload.action,
+            "Repository webhook event received"
+        );
+
+        let cmd = SyncRepositoryFromWebhookCommand {
+            action: payload.action,
+            repo: payload.repo.clone(),
+            previous_repo: payload.previous_repo.clone(),
+            repo_url: payload.repo_url.clone(),
+        };
+
+        // Чат берём из уже обновлённой записи — после переименования старое имя не найдётся
+        let chat_id = match self.sync_repository_from_webhook.execute(&cmd).await {
+            Ok(r) => r
+                .repository
+                .and_then(|repository| repository.social_chat_id)
+                .unwrap_or(self.default_chat_id),
+            Err(e) => {
+                tracing::error!(
+                    error = %e,
+                    repo = %payload.repo,
+                    "Failed to sync repository from webhook"
+                );
+                self.default_chat_id
+            }
+        };
+
+        self.publisher
+            .publish(&SendSocialNotifyJob {
+                social_type: SocialType::Telegram,
+                chat_id,
+                message: MessageBuilder::new().raw(payload.build_text().as_str()),
+            })
+            .await
+            .ok();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::delivery::events::listeners::github::webhook::resolve_user_by_login;
 use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
 use crate::domain::role::value_objects::role_name::RoleName;
 use crate::domain::shared::events::event_listener::EventListener;
@@ -45,38 +46,19 @@ impl EventListener<WebhookPullRequestEvent> for WebhookReviewRequestedDmListener
             }
         };
 
-        if reviewer_login.eq_ignore_ascii_case(&payload.author) {
+        if payload.is_author(reviewer_login) {
             return;
         }
 
-        let vc_account = match self
-            .user_vc_accounts_repo
-            .find_by_login(reviewer_login)
-            .await
-        {
-            Ok(account) => account,
-            Err(_) => {
-                tracing::debug!(
-                    reviewer = %reviewer_login,
-                    "Reviewer not found in DB — skipping DM"
-                );
-                return;
-            }
-        };
-
-        let social_account = match self
-            .user_socials_repo
-            .find_by_user_id(&vc_account.user_id)
-            .await
-        {
-            Ok(account) => account,
-            Err(_) => {
-                tracing::debug!(
-                    user_id = ?vc_account.user_id,
-                    "Reviewer has no social account — skipping DM"
-                );
-                return;
-            }
+        let Some((vc_account, social_account)) = resolve_user_by_login(
+            &self.user_vc_accounts_repo,
+            &self.user_socials_repo,
+            payload.provider,
+            reviewer_login,
+        )
+        .await
+        else {
+            return;
         };
 
         let prefs = self
@@ -113,7 +95,7 @@ impl EventListener<WebhookPullRequestEvent> for WebhookReviewRequestedDmListener
             )
             .section(
                 &t!("telegram_bot.notifications.review_requested.author").to_string(),
-                &payload.author,
+                payload.author.as_deref().unwrap_or("—"),
             )
             .section(
                 &t!("telegram_bot.notifications.review_requested.repository").to_string(),
@@ -153,7 +135,7 @@ impl WebhookReviewRequestedDmListener {
         reviewer_login: &str,
         until: chrono::DateTime<Utc>,
     ) {
-        let (owner, name) = match payload.repo.split_once('/') {
+        let (owner, name) = match payload.repo.rsplit_once('/') {
             Some(parts) => parts,
             None => {
                 tracing::warn!(repo = %payload.repo, "Cannot parse repo as owner/name");
@@ -167,7 +149,7 @@ impl WebhookReviewRequestedDmListener {
             until.format("%d.%m.%Y")
         );
 
-        if let Some(token) = self.author_access_token(&payload.author).await {
+        if let Some(token) = self.author_access_token(payload).await {
             if let Err(e) = self
                 .version_control_client
                 .post_pr_comment(&token, owner, name, payload.number, &comment_body)
@@ -177,7 +159,7 @@ impl WebhookReviewRequestedDmListener {
             }
         } else {
             tracing::debug!(
-                author = %payload.author,
+                author = ?payload.author,
                 "PR author has no token — skipping vacation comment in PR"
             );
         }
@@ -185,10 +167,13 @@ impl WebhookReviewRequestedDmListener {
         self.notify_admins(payload, reviewer_login, until).await;
     }
 
-    async fn author_access_token(&self, author_login: &str) -> Option<String> {
+    async fn author_access_token(&self, payload: &WebhookPullRequestEvent) -> Option<String> {
         let vc = self
             .user_vc_accounts_repo
-            .find_by_login(author_login)
+            .find_by_login(
+                &payload.provider.version_control_type(),
+                payload.author.as_deref()?,
+            )
             .await
             .ok()?;
         match self.reversible_cipher.decrypt(vc.access_token.value()) {
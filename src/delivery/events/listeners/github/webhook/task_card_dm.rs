@@ -0,0 +1,283 @@
+use crate::delivery::events::listeners::github::webhook::resolve_user_by_login;
+use crate::delivery::jobs::consumers::send_social_notify::payload::SendSocialNotifyJob;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::task::entities::pull_request_task_link::PullRequestTaskLink;
+use crate::domain::task::ports::task_tracker_client_resolver::TaskTrackerClientResolver;
+use crate::domain::task::repositories::pull_request_task_link_repository::PullRequestTaskLinkRepository;
+use crate::domain::task::repositories::task_move_repository::TaskMoveRepository;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::domain::webhook::events::task_card::{WebhookTaskCardAction, WebhookTaskCardEvent};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use crate::utils::builder::message::MessageBuilder;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Сколько после перевода ботом вебхук трекера считаем эхом этого перевода
+const OWN_MOVE_WINDOW_MINUTES: i64 = 5;
+
+/// Карточку перенесли, прокомментировали или добавили в неё участника →
+/// ЛС авторам PR, в которых упомянута задача
+pub struct WebhookTaskCardDmListener {
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub pull_request_task_link_repo: Arc<dyn PullRequestTaskLinkRepository>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    pub task_tracker_client_resolver: Arc<dyn TaskTrackerClientResolver>,
+    pub task_move_repo: Arc<dyn TaskMoveRepository>,
+    pub user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    pub user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+}
+
+impl WebhookTaskCardDmListener {
+    /// Перенос, который только что сделал сам бот по событию PR: автор и так
+    /// знает о своём PR, ЛС о нём — шум
+    async fn is_own_move(&self, payload: &WebhookTaskCardEvent) -> bool {
+        if payload.action != WebhookTaskCardAction::Moved {
+            return false;
+        }
+
+        let Some(column_id) = &payload.column_id else {
+            return false;
+        };
+
+        match self
+            .task_move_repo
+            .find_last_successful_by_task_id(&payload.task_id)
+            .await
+        {
+            Ok(Some(task_move)) => {
+                task_move.column_id == *column_id
+                    && task_move.created_at
+                        >= Utc::now() - Duration::minutes(OWN_MOVE_WINDOW_MINUTES)
+            }
+            Ok(None) => false,
+            Err(e) => {
+                tracing::warn!(error = %e, task_id = %payload.task_id, "Failed to load task moves");
+                false
+            }
+        }
+    }
+
+    /// Оставляет связи из репозиториев, подключённых к тому же трекеру и
+    /// подключению, что и событие, и заодно собирает ссылку на карточку по
+    /// настройкам первого из них
+    async fn filter_links(
+        &self,
+        payload: &WebhookTaskCardEvent,
+        links: Vec<PullRequestTaskLink>,
+    ) -> (Vec<PullRequestTaskLink>, Option<String>) {
+        let mut matched = Vec::new();
+        let mut card_url = None;
+
+        for link in links {
+            let Ok(tracker) = self
+                .repository_task_tracker_repo
+                .find_by_repository_id(link.repository_id)
+                .await
+            else {
+                continue;
+            };
+
+            // Один id карточки в разных компаниях Kaiten — разные задачи
+            if tracker.kind != payload.tracker || tracker.connection_id != payload.connection_id {
+                continue;
+            }
+
+            if card_url.is_none()
+                && let Ok(client) = self
+                    .task_tracker_client_resolver
+                    .resolve(tracker.kind, tracker.connection_id)
+                    .await
+            {
+                card_url = Some(format!(
+                    "{}{}",
+                    client.base_url(),
+                    tracker.path_to_card.replace("{id}", &payload.task_id.0)
+                ));
+            }
+
+            matched.push(link);
+        }
+
+        (matched, card_url)
+    }
+
+    fn build_message(
+        payload: &WebhookTaskCardEvent,
+        links: &[PullRequestTaskLink],
+        card_url: Option<&str>,
+    ) -> MessageBuilder {
+        let title = match payload.action {
+            WebhookTaskCardAction::Moved => t!("telegram_bot.notifications.task_card.title_moved"),
+            WebhookTaskCardAction::Commented => {
+                t!("telegram_bot.notifications.task_card.title_commented")
+            }
+            WebhookTaskCardAction::MemberAdded => {
+                t!("telegram_bot.notifications.task_card.title_member_added")
+            }
+        };
+
+        let mut message = MessageBuilder::new()
+            .bold(&title)
+            .empty_line()
+            .with_html_escape(true)
+            .section(
+                t!("telegram_bot.notifications.task_card.card").as_ref(),
+                &format!("#{} — {}", payload.task_id.0, payload.card_title),
+            );
+
+        if let Some(column) = &payload.column {
+            message = message.section(
+                t!("telegram_bot.notifications.task_card.column").as_ref(),
+                column,
+            );
+        }
+
+        if let Some(member) = &payload.member {
+            message = message.section(
+                t!("telegram_bot.notifications.task_card.member").as_ref(),
+                member,
+            );
+        }
+
+        if let Some(actor) = &payload.actor {
+            message = message.section(
+                t!("telegram_bot.notifications.task_card.author").as_ref(),
+                actor,
+            );
+        }
+
+        if let Some(comment) = &payload.comment {
+            let comment: String = comment.chars().take(1000).collect();
+            message = message.section(
+                t!("telegram_bot.notifications.task_card.comment").as_ref(),
+                &comment,
+            );
+        }
+
+        message = message
+            .with_html_escape(false)
+            .empty_line()
+            .bold(t!("telegram_bot.notifications.task_card.pull_requests").as_ref());
+
+        for link in links {
+            let label =
+                MessageBuilder::escape_html(&format!("#{} {}", link.pr_number, link.pr_title));
+            let line = match &link.pr_url {
+                Some(url) => format!(
+                    "• <a href=\"{}\">{}</a>",
+                    MessageBuilder::escape_html(url),
+                    label
+                ),
+                None => format!("• {}", label),
+            };
+            message = message.line(&line);
+        }
+
+        if let Some(url) = card_url {
+            message = message.empty_line().raw(&format!(
+                "<a href=\"{}\">{}</a>",
+                MessageBuilder::escape_html(url),
+                t!("telegram_bot.notifications.task_card.open")
+            ));
+        }
+
+        message
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookTaskCardEvent> for WebhookTaskCardDmListener {
+    async fn handle(&self, payload: &WebhookTaskCardEvent) {
+        if self.is_own_move(payload).await {
+            tracing::debug!(task_id = %payload.task_id, "Task card moved by the bot itself — skipping DM");
+            return;
+        }
+
+        let links = match self
+            .pull_request_task_link_repo
+            .find_by_task_id(&payload.task_id)
+            .await
+        {
+            Ok(links) => links,
+            Err(e) => {
+                tracing::warn!(error = %e, task_id = %payload.task_id, "Failed to load PR links for task");
+                return;
+            }
+        };
+
+        let (links, card_url) = self.filter_links(payload, links).await;
+
+        if links.is_empty() {
+            tracing::debug!(task_id = %payload.task_id, "No pull requests linked to task card");
+            return;
+        }
+
+        // Одно сообщение на автора со всеми его PR по задаче. Логин ищем у
+        // провайдера репозитория PR — у другого провайдера это может быть другой человек
+        let mut by_author: BTreeMap<
+            (String, String),
+            (WebhookProvider, String, Vec<PullRequestTaskLink>),
+        > = BTreeMap::new();
+        for link in links {
+            let Some(author_login) = link.author_login.clone() else {
+                continue;
+            };
+
+            let Ok(repository) = self.repository_repo.find_by_id(link.repository_id).await else {
+                continue;
+            };
+
+            by_author
+                .entry((
+                    repository.version_control_type.to_string(),
+                    author_login.to_lowercase(),
+                ))
+                .or_insert_with(|| {
+                    (
+                        WebhookProvider::from(&repository.version_control_type),
+                        author_login,
+                        Vec::new(),
+                    )
+                })
+                .2
+                .push(link);
+        }
+
+        for (_, (provider, author_login, author_links)) in by_author {
+            let Some((_, social_account)) = resolve_user_by_login(
+                &self.user_vc_accounts_repo,
+                &self.user_socials_repo,
+                provider,
+                &author_login,
+            )
+            .await
+            else {
+                continue;
+            };
+
+            tracing::debug!(
+                task_id = %payload.task_id,
+                author = %author_login,
+                action = ?payload.action,
+                "Sending task card DM"
+            );
+
+            self.publisher
+                .publish(&SendSocialNotifyJob {
+                    social_type: SocialType::Telegram,
+                    chat_id: social_account.social_chat_id,
+                    message: Self::build_message(payload, &author_links, card_url.as_deref()),
+                })
+                .await
+                .ok();
+        }
+    }
+}
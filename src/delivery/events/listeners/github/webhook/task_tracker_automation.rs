@@ -0,0 +1,210 @@
+use crate::delivery::events::listeners::github::webhook::find_repository;
+use crate::delivery::jobs::consumers::move_task_to_test::payload::MoveTaskToTestJob;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::repository::repositories::repository_task_tracker_repository::RepositoryTaskTrackerRepository;
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::RepositoryTaskTrackerRuleRepository;
+use crate::domain::repository::value_objects::pull_request_lifecycle_trigger::PullRequestLifecycleTrigger;
+use crate::domain::shared::events::event_listener::EventListener;
+use crate::domain::task::entities::task_move::TaskMoveOrigin;
+use crate::domain::task::services::task_tracker_service::TaskTrackerService;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::webhook::events::pull_request::{
+    WebhookPullRequestEvent, WebhookPullRequestEventActionType,
+};
+use crate::domain::webhook::events::pull_request_review::{
+    WebhookPullRequestReviewEvent, WebhookPullRequestReviewState,
+};
+use crate::domain::webhook::events::release::WebhookReleaseEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerPublisher;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Переводит карточки задач по правилам репозитория на события жизненного цикла PR.
+/// Если правил нет — старое поведение: после мержа карточка уходит в `qa_column_id`
+#[derive(Clone)]
+pub struct WebhookTaskTrackerAutomationListener {
+    pub task_tracker_service: Arc<dyn TaskTrackerService>,
+    pub publisher: Arc<dyn MessageBrokerPublisher>,
+    pub repository_repo: Arc<dyn RepositoryRepository>,
+    pub repository_task_tracker_repo: Arc<dyn RepositoryTaskTrackerRepository>,
+    pub repository_task_tracker_rule_repo: Arc<dyn RepositoryTaskTrackerRuleRepository>,
+}
+
+impl WebhookTaskTrackerAutomationListener {
+    async fn schedule_moves(
+        &self,
+        provider: WebhookProvider,
+        repo: &str,
+        trigger: PullRequestLifecycleTrigger,
+        base_ref: Option<&str>,
+        text: &str,
+        pr_number: Option<u64>,
+    ) {
+        let Some(repository) = find_repository(&self.repository_repo, provider, repo).await else {
+            return;
+        };
+
+        let Ok(tracker) = self
+            .repository_task_tracker_repo
+            .find_by_repository_id(repository.id)
+            .await
+        else {
+            return;
+        };
+
+        let rules = match self
+            .repository_task_tracker_rule_repo
+            .find_by_repository_id(repository.id)
+            .await
+        {
+            Ok(rules) => rules,
+            Err(e) => {
+                tracing::warn!(error = %e, repo = %repo, "Failed to load task tracker rules");
+                return;
+            }
+        };
+
+        let targets: Vec<(Option<i32>, String)> = if rules.is_empty() {
+            if trigger != PullRequestLifecycleTrigger::Merged {
+                return;
+            }
+            vec![(None, tracker.qa_column_id.clone())]
+        } else {
+            rules
+                .into_iter()
+                .filter(|rule| rule.matches(trigger, base_ref))
+                .map(|rule| (Some(rule.id), rule.column_id))
+                .collect()
+        };
+
+        if targets.is_empty() {
+            return;
+        }
+
+        let mut task_ids: Vec<TaskId> = Vec::new();
+        for (_, task_id) in self
+            .task_tracker_service
+            .extract_all_matches_with_pattern(text, &tracker.extract_pattern_regexp)
+        {
+            let task_id = tracker.kind.qualify_task_id(task_id, repo);
+            if !task_ids.contains(&task_id) {
+                task_ids.push(task_id);
+            }
+        }
+
+        for task_id in task_ids {
+            for (rule_id, column_id) in &targets {
+                tracing::debug!(
+                    task_id = %task_id.0,
+                    column_id = %column_id,
+                    trigger = %trigger,
+                    repo = %repo,
+                    "Scheduling task move by lifecycle rule"
+                );
+
+                self.publisher
+                    .publish(&MoveTaskToTestJob {
+                        task_id: task_id.clone(),
+                        column_id: column_id.clone(),
+                        kind: tracker.kind,
+                        connection_id: tracker.connection_id,
+                        origin: Some(TaskMoveOrigin {
+                            repository_id: repository.id,
+                            rule_id: *rule_id,
+                            trigger: trigger.into(),
+                            pr_number,
+                        }),
+                    })
+                    .await
+                    .ok();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookPullRequestEvent> for WebhookTaskTrackerAutomationListener {
+    async fn handle(&self, payload: &WebhookPullRequestEvent) {
+        let trigger = match payload.action {
+            WebhookPullRequestEventActionType::Opened => PullRequestLifecycleTrigger::Opened,
+            WebhookPullRequestEventActionType::ReadyForReview => {
+                PullRequestLifecycleTrigger::ReadyForReview
+            }
+            WebhookPullRequestEventActionType::Closed if payload.merged => {
+                PullRequestLifecycleTrigger::Merged
+            }
+            _ => return,
+        };
+
+        // Тот же набор полей, что проверяет страж id задачи
+        let text = format!(
+            "{}\n{}\n{}",
+            payload.title,
+            payload.body.as_deref().unwrap_or_default(),
+            payload.head_ref
+        );
+
+        self.schedule_moves(
+            payload.provider,
+            &payload.repo,
+            trigger,
+            Some(&payload.base_ref),
+            &text,
+            Some(payload.number),
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookPullRequestReviewEvent> for WebhookTaskTrackerAutomationListener {
+    async fn handle(&self, payload: &WebhookPullRequestReviewEvent) {
+        if payload.state != WebhookPullRequestReviewState::Approved {
+            return;
+        }
+
+        let text = format!(
+            "{}\n{}\n{}",
+            payload.pr_title,
+            payload.pr_body.as_deref().unwrap_or_default(),
+            payload.head_ref.as_deref().unwrap_or_default()
+        );
+
+        self.schedule_moves(
+            payload.provider,
+            &payload.repo,
+            PullRequestLifecycleTrigger::Approved,
+            payload.base_ref.as_deref(),
+            &text,
+            Some(payload.pr_number),
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl EventListener<WebhookReleaseEvent> for WebhookTaskTrackerAutomationListener {
+    async fn handle(&self, payload: &WebhookReleaseEvent) {
+        if payload.draft {
+            return;
+        }
+
+        // Задачи релиза ищем в названии и описании (сгенерированные заметки перечисляют PR)
+        let text = format!(
+            "{}\n{}",
+            payload.name.as_deref().unwrap_or_default(),
+            payload.body.as_deref().unwrap_or_default()
+        );
+
+        self.schedule_moves(
+            payload.provider,
+            &payload.repo,
+            PullRequestLifecycleTrigger::Released,
+            Some(&payload.target_commitish),
+            &text,
+            None,
+        )
+        .await;
+    }
+}
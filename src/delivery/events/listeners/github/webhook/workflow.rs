@@ -28,8 +28,13 @@ impl EventListener<WebhookWorkflowEvent> for WebhookWorkflowEventListener {
             "Workflow webhook event received"
         );
 
-        let chat_id =
-            resolve_chat_id(&self.repository_repo, &payload.repo, self.default_chat_id).await;
+        let chat_id = resolve_chat_id(
+            &self.repository_repo,
+            payload.provider,
+            &payload.repo,
+            self.default_chat_id,
+        )
+        .await;
 
         self.publisher
             .publish(&SendSocialNotifyJob {
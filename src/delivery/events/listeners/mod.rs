@@ -5,22 +5,48 @@ use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::bootstrap::shared_dependency::ApplicationSharedDependency;
 use crate::config::application::ApplicationConfig;
 use crate::delivery::contract::ApplicationDelivery;
+use crate::delivery::events::listeners::github::webhook::branch_cleanup::WebhookBranchCleanupListener;
+use crate::delivery::events::listeners::github::webhook::check_aggregator::WebhookCommitChecksAggregatorListener;
 use crate::delivery::events::listeners::github::webhook::ci_fail_dm::WebhookCiFailDmListener;
+use crate::delivery::events::listeners::github::webhook::deployment::{
+    WebhookDeploymentEventListener, WebhookDeploymentRecorderListener,
+};
+use crate::delivery::events::listeners::github::webhook::git_ref::WebhookRefEventListener;
+use crate::delivery::events::listeners::github::webhook::installation::WebhookInstallationEventListener;
+use crate::delivery::events::listeners::github::webhook::issue::WebhookIssueEventListener;
+use crate::delivery::events::listeners::github::webhook::issue_assigned_dm::WebhookIssueAssignedDmListener;
+use crate::delivery::events::listeners::github::webhook::issue_task_card::WebhookIssueTaskCardListener;
+use crate::delivery::events::listeners::github::webhook::member::WebhookMemberEventListener;
 use crate::delivery::events::listeners::github::webhook::pr_conflict::WebhookPrConflictDetectedListener;
 use crate::delivery::events::listeners::github::webhook::pr_mentions::WebhookPrMentionsListener;
 use crate::delivery::events::listeners::github::webhook::pr_opened_tag_reviewers::WebhookPrOpenedTagReviewersListener;
 use crate::delivery::events::listeners::github::webhook::pr_ready_to_merge::WebhookPrReadyToMergeListener;
+use crate::delivery::events::listeners::github::webhook::pr_task_id_guard::WebhookPrTaskIdGuardListener;
+use crate::delivery::events::listeners::github::webhook::pr_task_links::WebhookPullRequestTaskLinksListener;
 use crate::delivery::events::listeners::github::webhook::pull_request::WebhookPullRequestEventListener;
 use crate::delivery::events::listeners::github::webhook::pull_request_review::WebhookPullRequestReviewEventListener;
 use crate::delivery::events::listeners::github::webhook::push::WebhookPushEventListener;
 use crate::delivery::events::listeners::github::webhook::re_review_nudge::WebhookPrReReviewNudgeListener;
 use crate::delivery::events::listeners::github::webhook::release::WebhookReleaseEventListener;
+use crate::delivery::events::listeners::github::webhook::repository::WebhookRepositoryEventListener;
 use crate::delivery::events::listeners::github::webhook::review_requested::WebhookReviewRequestedDmListener;
+use crate::delivery::events::listeners::github::webhook::task_card_dm::WebhookTaskCardDmListener;
+use crate::delivery::events::listeners::github::webhook::task_tracker_automation::WebhookTaskTrackerAutomationListener;
 use crate::delivery::events::listeners::github::webhook::workflow::WebhookWorkflowEventListener;
 use crate::delivery::events::listeners::user::registration::failed::UserRegistrationFailedListener;
 use crate::delivery::events::listeners::user::registration::success::UserRegistrationSuccessListener;
+use crate::domain::commit_check::events::checks_completed::CommitChecksCompletedEvent;
 use crate::domain::user::value_objects::social_chat_id::SocialChatId;
 use crate::domain::user::value_objects::social_user_id::SocialUserId;
+use crate::domain::webhook::events::check_run::WebhookCheckRunEvent;
+use crate::domain::webhook::events::check_suite::WebhookCheckSuiteEvent;
+use crate::domain::webhook::events::deployment::WebhookDeploymentEvent;
+use crate::domain::webhook::events::deployment_status::WebhookDeploymentStatusEvent;
+use crate::domain::webhook::events::git_ref::WebhookRefEvent;
+use crate::domain::webhook::events::pull_request::WebhookPullRequestEvent;
+use crate::domain::webhook::events::pull_request_review::WebhookPullRequestReviewEvent;
+use crate::domain::webhook::events::release::WebhookReleaseEvent;
+use crate::domain::webhook::events::workflow::WebhookWorkflowEvent;
 use async_trait::async_trait;
 use std::error::Error;
 use std::sync::Arc;
@@ -57,12 +83,7 @@ impl ApplicationDelivery for DeliveryEventListeners {
             .on(WebhookPullRequestEventListener {
                 publisher: self.shared_dependency.publisher.clone(),
                 repository_repo: repository_repo.clone(),
-                repository_task_tracker_repo: self
-                    .shared_dependency
-                    .repository_task_tracker_repo
-                    .clone(),
                 default_chat_id,
-                task_tracker_service: self.shared_dependency.task_tracker_service.clone(),
             })
             .await;
         self.shared_dependency
@@ -73,6 +94,14 @@ impl ApplicationDelivery for DeliveryEventListeners {
                 default_chat_id,
             })
             .await;
+        self.shared_dependency
+            .event_bus
+            .on(WebhookRefEventListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                repository_repo: repository_repo.clone(),
+                default_chat_id,
+            })
+            .await;
         self.shared_dependency
             .event_bus
             .on(WebhookReleaseEventListener {
@@ -90,10 +119,213 @@ impl ApplicationDelivery for DeliveryEventListeners {
             })
             .await;
 
-        // CI fail (workflow_run.failure) → ЛС инициатору если он привязан
         self.shared_dependency
             .event_bus
-            .on(WebhookCiFailDmListener {
+            .on(WebhookIssueEventListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                repository_repo: repository_repo.clone(),
+                default_chat_id,
+            })
+            .await;
+
+        // Жизненный цикл PR и релизы → перевод карточек задач по правилам репозитория
+        let task_tracker_automation = WebhookTaskTrackerAutomationListener {
+            task_tracker_service: self.shared_dependency.task_tracker_service.clone(),
+            publisher: self.shared_dependency.publisher.clone(),
+            repository_repo: repository_repo.clone(),
+            repository_task_tracker_repo: self
+                .shared_dependency
+                .repository_task_tracker_repo
+                .clone(),
+            repository_task_tracker_rule_repo: self
+                .shared_dependency
+                .repository_task_tracker_rule_repo
+                .clone(),
+        };
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookPullRequestEvent, _>(task_tracker_automation.clone())
+            .await;
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookPullRequestReviewEvent, _>(task_tracker_automation.clone())
+            .await;
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookReleaseEvent, _>(task_tracker_automation)
+            .await;
+
+        // Задачи, упомянутые в PR → связи для входящих событий трекера, ссылка и мерж на карточке
+        self.shared_dependency
+            .event_bus
+            .on(WebhookPullRequestTaskLinksListener {
+                task_tracker_service: self.shared_dependency.task_tracker_service.clone(),
+                publisher: self.shared_dependency.publisher.clone(),
+                repository_repo: repository_repo.clone(),
+                repository_task_tracker_repo: self
+                    .shared_dependency
+                    .repository_task_tracker_repo
+                    .clone(),
+                pull_request_task_link_repo: self
+                    .shared_dependency
+                    .pull_request_task_link_repo
+                    .clone(),
+            })
+            .await;
+
+        // Карточку перенесли / прокомментировали в трекере → ЛС авторам связанных PR
+        self.shared_dependency
+            .event_bus
+            .on(WebhookTaskCardDmListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                pull_request_task_link_repo: self
+                    .shared_dependency
+                    .pull_request_task_link_repo
+                    .clone(),
+                repository_repo: repository_repo.clone(),
+                repository_task_tracker_repo: self
+                    .shared_dependency
+                    .repository_task_tracker_repo
+                    .clone(),
+                task_tracker_client_resolver: self
+                    .shared_dependency
+                    .task_tracker_client_resolver
+                    .clone(),
+                task_move_repo: self.shared_dependency.task_move_repo.clone(),
+                user_vc_accounts_repo: self.shared_dependency.user_version_controls_repo.clone(),
+                user_socials_repo: self.shared_dependency.user_socials_repo.clone(),
+            })
+            .await;
+
+        // Issue assigned → ЛС исполнителю если он привязан
+        self.shared_dependency
+            .event_bus
+            .on(WebhookIssueAssignedDmListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                user_vc_accounts_repo: self.shared_dependency.user_version_controls_repo.clone(),
+                user_socials_repo: self.shared_dependency.user_socials_repo.clone(),
+            })
+            .await;
+
+        // Новый issue → карточка в трекере, если у репозитория выбрана колонка для issue
+        self.shared_dependency
+            .event_bus
+            .on(WebhookIssueTaskCardListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                repository_repo: repository_repo.clone(),
+                repository_task_tracker_repo: self
+                    .shared_dependency
+                    .repository_task_tracker_repo
+                    .clone(),
+                create_task_card: self.executors.commands.create_task_card.clone(),
+            })
+            .await;
+
+        // Деплои: карточка в чат + последний успешный SHA по окружению
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookDeploymentEvent, _>(WebhookDeploymentEventListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                repository_repo: repository_repo.clone(),
+                default_chat_id,
+            })
+            .await;
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookDeploymentStatusEvent, _>(WebhookDeploymentEventListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                repository_repo: repository_repo.clone(),
+                default_chat_id,
+            })
+            .await;
+        self.shared_dependency
+            .event_bus
+            .on(WebhookDeploymentRecorderListener {
+                record_deployment: self.executors.commands.record_deployment.clone(),
+            })
+            .await;
+
+        // Смерженные, но не удалённые ветки → напоминание автору по расписанию
+        if self.config.notifications.branch_cleanup_reminder_days > 0 {
+            self.shared_dependency
+                .event_bus
+                .on::<WebhookPullRequestEvent, _>(WebhookBranchCleanupListener {
+                    merged_branch_repo: self.shared_dependency.merged_branch_repo.clone(),
+                })
+                .await;
+            self.shared_dependency
+                .event_bus
+                .on::<WebhookRefEvent, _>(WebhookBranchCleanupListener {
+                    merged_branch_repo: self.shared_dependency.merged_branch_repo.clone(),
+                })
+                .await;
+        }
+
+        // Состав организации → активность пользователей, изменения репозиториев → запись в БД
+        self.shared_dependency
+            .event_bus
+            .on(WebhookMemberEventListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                sync_org_membership: self.executors.commands.sync_org_membership.clone(),
+                user_has_roles_repo: self.shared_dependency.user_has_roles_repo.clone(),
+                user_socials_repo: self.shared_dependency.user_socials_repo.clone(),
+            })
+            .await;
+        self.shared_dependency
+            .event_bus
+            .on(WebhookRepositoryEventListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                sync_repository_from_webhook: self
+                    .executors
+                    .commands
+                    .sync_repository_from_webhook
+                    .clone(),
+                default_chat_id,
+            })
+            .await;
+
+        // Установка GitHub App → регистрация репозиториев
+        self.shared_dependency
+            .event_bus
+            .on(WebhookInstallationEventListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                register_installation_repositories: self
+                    .executors
+                    .commands
+                    .register_installation_repositories
+                    .clone(),
+                github_app: self.shared_dependency.github_app.clone(),
+                default_chat_id,
+            })
+            .await;
+
+        // check_run / check_suite → итоговое состояние CI по коммиту
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookCheckRunEvent, _>(WebhookCommitChecksAggregatorListener {
+                record_commit_check: self.executors.commands.record_commit_check.clone(),
+            })
+            .await;
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookCheckSuiteEvent, _>(WebhookCommitChecksAggregatorListener {
+                record_commit_check: self.executors.commands.record_commit_check.clone(),
+            })
+            .await;
+
+        // Упал CI коммита (проверки GitHub или пайплайн GitLab/Gitea) → ЛС инициатору если он привязан
+        self.shared_dependency
+            .event_bus
+            .on::<CommitChecksCompletedEvent, _>(WebhookCiFailDmListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                notification_log_repo: self.shared_dependency.notification_log_repo.clone(),
+                user_vc_accounts_repo: self.shared_dependency.user_version_controls_repo.clone(),
+                user_socials_repo: self.shared_dependency.user_socials_repo.clone(),
+            })
+            .await;
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookWorkflowEvent, _>(WebhookCiFailDmListener {
                 publisher: self.shared_dependency.publisher.clone(),
                 notification_log_repo: self.shared_dependency.notification_log_repo.clone(),
                 user_vc_accounts_repo: self.shared_dependency.user_version_controls_repo.clone(),
@@ -112,14 +344,30 @@ impl ApplicationDelivery for DeliveryEventListeners {
             })
             .await;
 
-        // PR approved + mergeable=clean → ЛС всем админам (один раз на PR)
+        // PR approved + mergeable=clean + CI зелёный → ЛС всем админам (один раз на PR).
+        // Срабатывает и на approve, и на позеленевший CI — что придёт позже.
+        self.shared_dependency
+            .event_bus
+            .on::<WebhookPullRequestReviewEvent, _>(WebhookPrReadyToMergeListener {
+                publisher: self.shared_dependency.publisher.clone(),
+                notification_log_repo: self.shared_dependency.notification_log_repo.clone(),
+                user_has_roles_repo: self.shared_dependency.user_has_roles_repo.clone(),
+                user_socials_repo: self.shared_dependency.user_socials_repo.clone(),
+                commit_check_repo: self.shared_dependency.commit_check_repo.clone(),
+                pr_review_repo: self.shared_dependency.pr_review_repo.clone(),
+                repository_repo: repository_repo.clone(),
+            })
+            .await;
         self.shared_dependency
             .event_bus
-            .on(WebhookPrReadyToMergeListener {
+            .on::<CommitChecksCompletedEvent, _>(WebhookPrReadyToMergeListener {
                 publisher: self.shared_dependency.publisher.clone(),
                 notification_log_repo: self.shared_dependency.notification_log_repo.clone(),
                 user_has_roles_repo: self.shared_dependency.user_has_roles_repo.clone(),
                 user_socials_repo: self.shared_dependency.user_socials_repo.clone(),
+                commit_check_repo: self.shared_dependency.commit_check_repo.clone(),
+                pr_review_repo: self.shared_dependency.pr_review_repo.clone(),
+                repository_repo: repository_repo.clone(),
             })
             .await;
 
@@ -161,6 +409,25 @@ impl ApplicationDelivery for DeliveryEventListeners {
             })
             .await;
 
+        // Статус task-id/required и подсказка автору, если в PR нет ID задачи
+        self.shared_dependency
+            .event_bus
+            .on(WebhookPrTaskIdGuardListener {
+                task_tracker_service: self.shared_dependency.task_tracker_service.clone(),
+                publisher: self.shared_dependency.publisher.clone(),
+                repository_repo: repository_repo.clone(),
+                repository_task_tracker_repo: self
+                    .shared_dependency
+                    .repository_task_tracker_repo
+                    .clone(),
+                version_control_clients: self.shared_dependency.version_control_clients.clone(),
+                version_control_tokens: self.shared_dependency.version_control_tokens.clone(),
+                notification_log_repo: self.shared_dependency.notification_log_repo.clone(),
+                user_vc_accounts_repo: self.shared_dependency.user_version_controls_repo.clone(),
+                user_socials_repo: self.shared_dependency.user_socials_repo.clone(),
+            })
+            .await;
+
         // Авто-теги ревьюеров в групповой чат при открытии PR
         self.shared_dependency
             .event_bus
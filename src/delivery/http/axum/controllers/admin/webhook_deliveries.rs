@@ -0,0 +1,235 @@
+use crate::application::webhook::commands::replay_deliveries::command::{
+    ReplayWebhookDeliveriesExecutorCommand, ReplayWebhookDeliveriesSelector,
+};
+use crate::application::webhook::commands::replay_deliveries::error::ReplayWebhookDeliveriesExecutorError;
+use crate::application::webhook::commands::replay_deliveries::executor::ReplayWebhookDeliveriesExecutor;
+use crate::application::webhook::commands::replay_deliveries::response::{
+    ReplayWebhookDeliveriesExecutorResponse, ReplayedWebhookDeliveryOutcome,
+};
+use crate::application::webhook::queries::list_deliveries::executor::ListWebhookDeliveriesExecutor;
+use crate::application::webhook::queries::list_deliveries::query::ListWebhookDeliveriesQuery;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_cursor::WebhookDeliveryCursor;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+const DEFAULT_LIST_LIMIT: u64 = 50;
+
+#[derive(Debug, Deserialize)]
+pub struct ListDeliveriesParams {
+    repository: Option<String>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DryRunParams {
+    #[serde(default)]
+    dry_run: bool,
+}
+
+/// Курсор продолжения окна: `next_after` из прошлого ответа передаётся как `after`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CursorDto {
+    received_at: DateTime<Utc>,
+    id: i32,
+}
+
+impl From<WebhookDeliveryCursor> for CursorDto {
+    fn from(c: WebhookDeliveryCursor) -> Self {
+        Self {
+            received_at: c.received_at,
+            id: c.id.0,
+        }
+    }
+}
+
+impl From<CursorDto> for WebhookDeliveryCursor {
+    fn from(c: CursorDto) -> Self {
+        Self {
+            received_at: c.received_at,
+            id: WebhookDeliveryId(c.id),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplayWindowBody {
+    repository: String,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    #[serde(default)]
+    after: Option<CursorDto>,
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct DeliveryDto {
+    id: i32,
+    provider: String,
+    delivery_id: Option<String>,
+    event_type: String,
+    repository: Option<String>,
+    signature_valid: bool,
+    status: String,
+    error_message: Option<String>,
+    received_at: String,
+    processed_at: Option<String>,
+}
+
+impl From<WebhookDelivery> for DeliveryDto {
+    fn from(d: WebhookDelivery) -> Self {
+        Self {
+            id: d.id.0,
+            provider: d.provider.to_string(),
+            delivery_id: d.delivery_id,
+            event_type: d.event_type,
+            repository: d.repository_full_name,
+            signature_valid: d.signature_valid,
+            status: d.status.to_string(),
+            error_message: d.error_message,
+            received_at: d.received_at.to_rfc3339(),
+            processed_at: d.processed_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ReplayResultDto {
+    id: i32,
+    delivery_id: Option<String>,
+    event_type: String,
+    event_name: Option<String>,
+    listeners: Vec<String>,
+    outcome: &'static str,
+    reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReplayResponseDto {
+    dry_run: bool,
+    results: Vec<ReplayResultDto>,
+    truncated: bool,
+    next_after: Option<CursorDto>,
+}
+
+impl From<ReplayWebhookDeliveriesExecutorResponse> for ReplayResponseDto {
+    fn from(r: ReplayWebhookDeliveriesExecutorResponse) -> Self {
+        let results = r
+            .results
+            .into_iter()
+            .map(|item| {
+                let (outcome, reason) = match item.outcome {
+                    ReplayedWebhookDeliveryOutcome::Dispatched => ("dispatched", None),
+                    ReplayedWebhookDeliveryOutcome::DryRun => ("dry_run", None),
+                    ReplayedWebhookDeliveryOutcome::Skipped(reason) => ("skipped", Some(reason)),
+                    ReplayedWebhookDeliveryOutcome::Failed(reason) => ("failed", Some(reason)),
+                };
+
+                ReplayResultDto {
+                    id: item.id.0,
+                    delivery_id: item.delivery_id,
+                    event_type: item.event_type,
+                    event_name: item.event_name,
+                    listeners: item.listeners,
+                    outcome,
+                    reason,
+                }
+            })
+            .collect();
+
+        Self {
+            dry_run: r.dry_run,
+            results,
+            truncated: r.next_after.is_some(),
+            next_after: r.next_after.map(CursorDto::from),
+        }
+    }
+}
+
+pub struct AxumAdminWebhookDeliveriesController;
+
+impl AxumAdminWebhookDeliveriesController {
+    pub async fn handle_list(
+        Query(params): Query<ListDeliveriesParams>,
+        Extension(executor): Extension<Arc<ListWebhookDeliveriesExecutor>>,
+    ) -> Response {
+        let query = ListWebhookDeliveriesQuery {
+            repository_full_name: params.repository,
+            from: params.from,
+            to: params.to,
+            limit: params.limit.unwrap_or(DEFAULT_LIST_LIMIT),
+        };
+
+        match executor.execute(&query).await {
+            Ok(r) => {
+                let deliveries: Vec<DeliveryDto> =
+                    r.deliveries.into_iter().map(DeliveryDto::from).collect();
+                Json(deliveries).into_response()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to list webhook deliveries");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        }
+    }
+
+    pub async fn handle_replay_one(
+        Path(id): Path<i32>,
+        Query(params): Query<DryRunParams>,
+        Extension(executor): Extension<Arc<ReplayWebhookDeliveriesExecutor>>,
+    ) -> Response {
+        let cmd = ReplayWebhookDeliveriesExecutorCommand {
+            selector: ReplayWebhookDeliveriesSelector::Delivery(WebhookDeliveryId(id)),
+            dry_run: params.dry_run,
+        };
+
+        Self::replay(&executor, &cmd).await
+    }
+
+    pub async fn handle_replay_window(
+        Extension(executor): Extension<Arc<ReplayWebhookDeliveriesExecutor>>,
+        Json(body): Json<ReplayWindowBody>,
+    ) -> Response {
+        if body.from > body.to {
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+
+        let cmd = ReplayWebhookDeliveriesExecutorCommand {
+            selector: ReplayWebhookDeliveriesSelector::RepositoryWindow {
+                repository_full_name: body.repository,
+                from: body.from,
+                to: body.to,
+                after: body.after.map(WebhookDeliveryCursor::from),
+            },
+            dry_run: body.dry_run,
+        };
+
+        Self::replay(&executor, &cmd).await
+    }
+
+    async fn replay(
+        executor: &ReplayWebhookDeliveriesExecutor,
+        cmd: &ReplayWebhookDeliveriesExecutorCommand,
+    ) -> Response {
+        match executor.execute(cmd).await {
+            Ok(r) => Json(ReplayResponseDto::from(r)).into_response(),
+            Err(ReplayWebhookDeliveriesExecutorError::NotFound) => {
+                StatusCode::NOT_FOUND.into_response()
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to replay webhook deliveries");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        }
+    }
+}
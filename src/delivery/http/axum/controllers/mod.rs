@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod oauth;
 pub mod report;
 pub mod webhook;
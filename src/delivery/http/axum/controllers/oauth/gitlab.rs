@@ -0,0 +1,93 @@
+use crate::application::user::commands::link_version_control_account::command::LinkVersionControlAccountCommand;
+use crate::application::user::commands::link_version_control_account::error::LinkVersionControlAccountExecutorError;
+use crate::application::user::commands::link_version_control_account::executor::LinkVersionControlAccountExecutor;
+use crate::bootstrap::shared_dependency::ApplicationSharedDependency;
+use crate::config::application::ApplicationConfig;
+use crate::domain::notification::services::notification_service::NotificationService;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::entities::pending_version_control_account_link::PendingVersionControlAccountLink;
+use crate::domain::user::value_objects::social_type::SocialType;
+use crate::utils::builder::message::MessageBuilder;
+use axum::Extension;
+use axum::extract::Query;
+use axum::response::{IntoResponse, Redirect};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+pub struct AxumOAuthGitlabControllerPostQuery {
+    code: String,
+    state: String,
+}
+
+/// Привязка аккаунта GitLab к уже зарегистрированному пользователю (`/gitlab` в боте)
+pub struct AxumOAuthGitlabController {}
+
+impl AxumOAuthGitlabController {
+    pub async fn handle_post(
+        Extension(executor): Extension<Arc<LinkVersionControlAccountExecutor>>,
+        Extension(shared): Extension<Arc<ApplicationSharedDependency>>,
+        Extension(config): Extension<Arc<ApplicationConfig>>,
+        Query(query): Query<AxumOAuthGitlabControllerPostQuery>,
+    ) -> impl IntoResponse {
+        let bot_url = config.telegram.bot_url.as_str();
+
+        let pending = match shared
+            .cache
+            .take(&PendingVersionControlAccountLink::cache_key(&query.state))
+            .await
+        {
+            Ok(Some(json)) => match serde_json::from_str::<PendingVersionControlAccountLink>(&json)
+            {
+                Ok(pending) => pending,
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to parse account link state");
+                    return Redirect::to(bot_url);
+                }
+            },
+            Ok(None) => {
+                tracing::warn!("Account link state not found in cache (expired or invalid)");
+                return Redirect::to(bot_url);
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to retrieve account link state from cache");
+                return Redirect::to(bot_url);
+            }
+        };
+
+        let chat_id = pending.social_chat_id;
+
+        let cmd = LinkVersionControlAccountCommand {
+            code: query.code,
+            pending,
+        };
+
+        let message = match executor.execute(&cmd).await {
+            Ok(response) => MessageBuilder::new().line(
+                t!(
+                    "telegram_bot.commands.gitlab.linked",
+                    login = response.account.version_control_login
+                )
+                .as_ref(),
+            ),
+            Err(LinkVersionControlAccountExecutorError::AlreadyLinked(login)) => {
+                MessageBuilder::new()
+                    .line(t!("telegram_bot.commands.gitlab.already_linked", login = login).as_ref())
+            }
+            Err(error) => {
+                tracing::error!(error = %error, "Failed to link GitLab account");
+                MessageBuilder::new().line(t!("telegram_bot.commands.gitlab.error").as_ref())
+            }
+        };
+
+        if let Err(e) = shared
+            .notification_service
+            .send_message(&SocialType::Telegram, &chat_id, &message)
+            .await
+        {
+            tracing::warn!(error = %e, chat_id = chat_id.0, "Failed to report GitLab account link result");
+        }
+
+        Redirect::to(bot_url)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::application::webhook::commands::dispatch_event::command::DispatchWebhookEventExecutorCommand;
+use crate::application::webhook::commands::dispatch_event::executor::DispatchWebhookEventExecutor;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitea::event_type::GiteaEventType;
+use crate::infrastructure::contracts::gitea::headers::GiteaHeaders;
+use crate::infrastructure::contracts::gitea::mapper::{
+    GiteaWebhookEventMapper, GiteaWebhookEventMapperError,
+};
+use axum::http::HeaderMap;
+use axum::{Extension, Json};
+use reqwest::StatusCode;
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct AxumWebhookGiteaController {}
+
+impl AxumWebhookGiteaController {
+    pub async fn handle_post(
+        headers: HeaderMap,
+        Extension(executor): Extension<Arc<DispatchWebhookEventExecutor>>,
+        Json(payload): Json<serde_json::Value>,
+    ) -> StatusCode {
+        let raw_event_type = match headers
+            .get(GiteaHeaders::EVENT)
+            .and_then(|header_value| header_value.to_str().ok())
+        {
+            Some(value) => value,
+            None => {
+                tracing::warn!(header = GiteaHeaders::EVENT, "Missing Gitea event header");
+                return StatusCode::FORBIDDEN;
+            }
+        };
+
+        tracing::debug!(event_type = %raw_event_type, "Received Gitea webhook event");
+
+        let gitea_event_type = GiteaEventType::from_str(raw_event_type)
+            .unwrap_or(GiteaEventType::Unknown(raw_event_type.to_string()));
+
+        let repository_full_name = GiteaWebhookEventMapper::extract_repository_full_name(&payload);
+
+        let event = match GiteaWebhookEventMapper::map_to_domain_event(&gitea_event_type, payload) {
+            Ok(event) => event,
+            // Неинтересные события подтверждаем, чтобы не копить красные доставки в Gitea
+            Err(
+                error @ (GiteaWebhookEventMapperError::UnsupportedEventType
+                | GiteaWebhookEventMapperError::Skipped(_)),
+            ) => {
+                tracing::debug!(error = %error, event_type = %raw_event_type, "Ignoring Gitea event");
+                return StatusCode::OK;
+            }
+            Err(error) => {
+                tracing::error!(error = ?error, event_type = %raw_event_type, "Failed to map Gitea event");
+                return StatusCode::BAD_REQUEST;
+            }
+        };
+
+        let cmd = DispatchWebhookEventExecutorCommand {
+            event,
+            provider: WebhookProvider::Gitea,
+            repository_full_name,
+        };
+
+        match executor.execute(&cmd).await {
+            Ok(_) => StatusCode::OK,
+            Err(error) => {
+                tracing::error!(error = ?error, "Failed to dispatch webhook event");
+                StatusCode::BAD_REQUEST
+            }
+        }
+    }
+}
@@ -1,11 +1,21 @@
 use crate::application::webhook::commands::dispatch_event::command::DispatchWebhookEventExecutorCommand;
 use crate::application::webhook::commands::dispatch_event::executor::DispatchWebhookEventExecutor;
+use crate::application::webhook::commands::record_delivery::command::RecordWebhookDeliveryExecutorCommand;
+use crate::application::webhook::commands::record_delivery::executor::RecordWebhookDeliveryExecutor;
+use crate::application::webhook::commands::update_delivery_status::executor::UpdateWebhookDeliveryStatusExecutor;
 use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
 use crate::infrastructure::contracts::github::event_type::GithubEventType;
 use crate::infrastructure::contracts::github::headers::GithubHeaders;
-use crate::infrastructure::contracts::github::mapper::GithubWebhookEventMapper;
+use crate::infrastructure::contracts::github::mapper::{
+    GithubWebhookEventMapper, GithubWebhookEventMapperError,
+};
+use axum::Extension;
+use axum::body::Bytes;
 use axum::http::HeaderMap;
-use axum::{Extension, Json};
 use reqwest::StatusCode;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -16,7 +26,9 @@ impl AxumWebhookGithubController {
     pub async fn handle_post(
         headers: HeaderMap,
         Extension(executor): Extension<Arc<DispatchWebhookEventExecutor>>,
-        Json(payload): Json<serde_json::Value>,
+        Extension(record_delivery): Extension<Arc<RecordWebhookDeliveryExecutor>>,
+        Extension(update_delivery_status): Extension<Arc<UpdateWebhookDeliveryStatusExecutor>>,
+        body: Bytes,
     ) -> StatusCode {
         let raw_event_type = match headers
             .get(GithubHeaders::EVENT)
@@ -29,31 +41,129 @@ impl AxumWebhookGithubController {
             }
         };
 
-        tracing::debug!(event_type = %raw_event_type, "Received GitHub webhook event");
+        let delivery_id = headers
+            .get(GithubHeaders::DELIVERY)
+            .and_then(|header_value| header_value.to_str().ok());
+
+        tracing::debug!(
+            event_type = %raw_event_type,
+            delivery = ?delivery_id,
+            "Received GitHub webhook event"
+        );
+
+        let parsed_payload = serde_json::from_slice::<serde_json::Value>(&body);
+        let repository_full_name = parsed_payload
+            .as_ref()
+            .ok()
+            .and_then(GithubWebhookEventMapper::extract_repository_full_name);
+
+        // ── Журнал и дедупликация по X-GitHub-Delivery ────────────────────────
+        let journal_id: Option<WebhookDeliveryId> = match delivery_id {
+            Some(delivery_id) => {
+                let cmd = RecordWebhookDeliveryExecutorCommand {
+                    provider: VersionControlType::Github,
+                    delivery_id: delivery_id.to_string(),
+                    event_type: raw_event_type.to_string(),
+                    repository_full_name: repository_full_name.clone(),
+                    payload: String::from_utf8_lossy(&body).into_owned(),
+                };
+
+                match record_delivery.execute(&cmd).await {
+                    Ok(r) if r.duplicate => {
+                        tracing::info!(
+                            delivery = %delivery_id,
+                            event_type = %raw_event_type,
+                            status = %r.delivery.status,
+                            "Duplicate GitHub delivery, skipping"
+                        );
+                        return StatusCode::OK;
+                    }
+                    Ok(r) => Some(r.delivery.id),
+                    Err(error) => {
+                        // Журнал не должен блокировать доставку событий
+                        tracing::error!(error = %error, delivery = %delivery_id, "Failed to record GitHub delivery");
+                        None
+                    }
+                }
+            }
+            None => {
+                tracing::warn!(
+                    header = GithubHeaders::DELIVERY,
+                    "Missing GitHub delivery header, deduplication disabled"
+                );
+                None
+            }
+        };
+
+        let mark = |status: WebhookDeliveryStatus, error_message: Option<String>| {
+            let update_delivery_status = update_delivery_status.clone();
+            async move {
+                if let Some(id) = journal_id {
+                    update_delivery_status.mark(id, status, error_message).await;
+                }
+            }
+        };
 
         let github_event_type = GithubEventType::from_str(raw_event_type)
             .unwrap_or(GithubEventType::Unknown(raw_event_type.to_string()));
 
         if github_event_type == GithubEventType::Ping {
             tracing::info!(event_type = %raw_event_type, "Received GitHub ping event");
+            mark(WebhookDeliveryStatus::Skipped, None).await;
             return StatusCode::OK;
         }
 
+        let payload: serde_json::Value = match parsed_payload {
+            Ok(payload) => payload,
+            Err(error) => {
+                tracing::error!(error = %error, event_type = %raw_event_type, "Invalid GitHub webhook body");
+                mark(WebhookDeliveryStatus::Failed, Some(error.to_string())).await;
+                return StatusCode::BAD_REQUEST;
+            }
+        };
+
         let event = match GithubWebhookEventMapper::map_to_domain_event(&github_event_type, payload)
         {
             Ok(event) => event,
+            // Неподдерживаемое событие или неинтересное действие — подтверждаем
+            // без ошибки, иначе GitHub помечает доставку упавшей
+            Err(
+                error @ (GithubWebhookEventMapperError::UnsupportedEventType
+                | GithubWebhookEventMapperError::Skipped(_)),
+            ) => {
+                tracing::debug!(error = %error, event_type = %raw_event_type, "Ignoring GitHub event");
+                mark(WebhookDeliveryStatus::Skipped, Some(error.to_string())).await;
+                return StatusCode::OK;
+            }
             Err(error) => {
                 tracing::error!(error = ?error, event_type = %raw_event_type, "Failed to map GitHub event");
+                mark(WebhookDeliveryStatus::Failed, Some(error.to_string())).await;
                 return StatusCode::BAD_REQUEST;
             }
         };
 
-        let cmd = DispatchWebhookEventExecutorCommand { event };
+        let cmd = DispatchWebhookEventExecutorCommand {
+            event,
+            provider: WebhookProvider::Github,
+            repository_full_name,
+        };
 
         match executor.execute(&cmd).await {
-            Ok(_) => StatusCode::OK,
+            Ok(r) if r.skipped => {
+                mark(
+                    WebhookDeliveryStatus::Skipped,
+                    Some("Repository is archived".to_string()),
+                )
+                .await;
+                StatusCode::OK
+            }
+            Ok(_) => {
+                mark(WebhookDeliveryStatus::Dispatched, None).await;
+                StatusCode::OK
+            }
             Err(error) => {
                 tracing::error!(error = ?error, "Failed to dispatch webhook event");
+                mark(WebhookDeliveryStatus::Failed, Some(error.to_string())).await;
                 StatusCode::BAD_REQUEST
             }
         }
@@ -0,0 +1,73 @@
+use crate::application::webhook::commands::dispatch_event::command::DispatchWebhookEventExecutorCommand;
+use crate::application::webhook::commands::dispatch_event::executor::DispatchWebhookEventExecutor;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitlab::event_type::GitlabEventType;
+use crate::infrastructure::contracts::gitlab::headers::GitlabHeaders;
+use crate::infrastructure::contracts::gitlab::mapper::{
+    GitlabWebhookEventMapper, GitlabWebhookEventMapperError,
+};
+use axum::http::HeaderMap;
+use axum::{Extension, Json};
+use reqwest::StatusCode;
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct AxumWebhookGitlabController {}
+
+impl AxumWebhookGitlabController {
+    pub async fn handle_post(
+        headers: HeaderMap,
+        Extension(executor): Extension<Arc<DispatchWebhookEventExecutor>>,
+        Json(payload): Json<serde_json::Value>,
+    ) -> StatusCode {
+        let raw_event_type = match headers
+            .get(GitlabHeaders::EVENT)
+            .and_then(|header_value| header_value.to_str().ok())
+        {
+            Some(value) => value,
+            None => {
+                tracing::warn!(header = GitlabHeaders::EVENT, "Missing GitLab event header");
+                return StatusCode::FORBIDDEN;
+            }
+        };
+
+        tracing::debug!(event_type = %raw_event_type, "Received GitLab webhook event");
+
+        let gitlab_event_type = GitlabEventType::from_str(raw_event_type)
+            .unwrap_or(GitlabEventType::Unknown(raw_event_type.to_string()));
+
+        let repository_full_name = GitlabWebhookEventMapper::extract_repository_full_name(&payload);
+
+        let event = match GitlabWebhookEventMapper::map_to_domain_event(&gitlab_event_type, payload)
+        {
+            Ok(event) => event,
+            // GitLab отключает хук после серии 4xx, поэтому неинтересные события просто подтверждаем
+            Err(
+                error @ (GitlabWebhookEventMapperError::UnsupportedEventType
+                | GitlabWebhookEventMapperError::Skipped(_)),
+            ) => {
+                tracing::debug!(error = %error, event_type = %raw_event_type, "Ignoring GitLab event");
+                return StatusCode::OK;
+            }
+            Err(error) => {
+                tracing::error!(error = ?error, event_type = %raw_event_type, "Failed to map GitLab event");
+                return StatusCode::BAD_REQUEST;
+            }
+        };
+
+        let cmd = DispatchWebhookEventExecutorCommand {
+            event,
+            provider: WebhookProvider::Gitlab,
+            repository_full_name,
+        };
+
+        match executor.execute(&cmd).await {
+            Ok(_) => StatusCode::OK,
+            Err(error) => {
+                tracing::error!(error = ?error, "Failed to dispatch webhook event");
+                StatusCode::BAD_REQUEST
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::application::webhook::commands::dispatch_event::command::DispatchWebhookEventExecutorCommand;
+use crate::application::webhook::commands::dispatch_event::executor::DispatchWebhookEventExecutor;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::kaiten::event_type::KaitenEventType;
+use crate::infrastructure::contracts::kaiten::mapper::{
+    KaitenWebhookEventMapper, KaitenWebhookEventMapperError,
+};
+use axum::extract::Query;
+use axum::{Extension, Json};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// `?connection=<id>` — вебхук компании Kaiten, заведённой подключением в
+/// админке. Без параметра событие относится к Kaiten из окружения
+#[derive(Debug, Deserialize)]
+pub struct AxumWebhookKaitenControllerQuery {
+    connection: Option<i32>,
+}
+
+pub struct AxumWebhookKaitenController {}
+
+impl AxumWebhookKaitenController {
+    pub async fn handle_post(
+        Extension(executor): Extension<Arc<DispatchWebhookEventExecutor>>,
+        Query(query): Query<AxumWebhookKaitenControllerQuery>,
+        Json(mut payload): Json<serde_json::Value>,
+    ) -> StatusCode {
+        // Тип события Kaiten присылает в теле, а не в заголовке
+        let raw_event_type = match payload.get("event").and_then(|value| value.as_str()) {
+            Some(value) => value.to_string(),
+            None => {
+                tracing::warn!("Missing event field in Kaiten webhook");
+                return StatusCode::BAD_REQUEST;
+            }
+        };
+
+        tracing::debug!(event_type = %raw_event_type, "Received Kaiten webhook event");
+
+        let kaiten_event_type = KaitenEventType::from_str(&raw_event_type)
+            .unwrap_or(KaitenEventType::Unknown(raw_event_type.clone()));
+
+        let data = payload
+            .get_mut("data")
+            .map(serde_json::Value::take)
+            .unwrap_or_default();
+
+        let event = match KaitenWebhookEventMapper::map_to_domain_event(
+            &kaiten_event_type,
+            data,
+            query.connection,
+        ) {
+            Ok(event) => event,
+            Err(
+                error @ (KaitenWebhookEventMapperError::UnsupportedEventType
+                | KaitenWebhookEventMapperError::Skipped(_)),
+            ) => {
+                tracing::debug!(error = %error, event_type = %raw_event_type, "Ignoring Kaiten event");
+                return StatusCode::OK;
+            }
+            Err(error) => {
+                tracing::error!(error = ?error, event_type = %raw_event_type, "Failed to map Kaiten event");
+                return StatusCode::BAD_REQUEST;
+            }
+        };
+
+        let cmd = DispatchWebhookEventExecutorCommand {
+            event,
+            // Событие трекера без репозитория — провайдер не используется
+            provider: WebhookProvider::default(),
+            repository_full_name: None,
+        };
+
+        match executor.execute(&cmd).await {
+            Ok(_) => StatusCode::OK,
+            Err(error) => {
+                tracing::error!(error = ?error, "Failed to dispatch webhook event");
+                StatusCode::BAD_REQUEST
+            }
+        }
+    }
+}
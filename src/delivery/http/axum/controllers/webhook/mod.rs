@@ -1 +1,4 @@
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
+pub mod kaiten;
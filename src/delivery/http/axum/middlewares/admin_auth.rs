@@ -0,0 +1,35 @@
+use crate::utils::security::constant_time::constant_time_eq;
+use axum::body::Body;
+use axum::http::{StatusCode, header};
+use axum::{extract::Request, middleware::Next, response::Response};
+
+pub struct AdminApiAuthorizationMiddleware {
+    token: String,
+}
+
+impl AdminApiAuthorizationMiddleware {
+    pub fn new(token: String) -> Self {
+        Self { token }
+    }
+
+    pub async fn handle(self, request: Request<Body>, next: Next) -> Result<Response, StatusCode> {
+        if self.token.is_empty() {
+            tracing::error!("WEBHOOK_ADMIN_API_TOKEN is not configured; rejecting admin request");
+            return Err(StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        let token = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .unwrap_or("");
+
+        if !constant_time_eq(token.as_bytes(), self.token.as_bytes()) {
+            tracing::warn!("Admin API token verification failed");
+            return Err(StatusCode::UNAUTHORIZED);
+        }
+
+        Ok(next.run(request).await)
+    }
+}
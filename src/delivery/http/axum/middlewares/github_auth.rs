@@ -1,34 +1,131 @@
+use crate::application::repository::queries::resolve_repository_webhook_secrets::executor::ResolveRepositoryWebhookSecretsExecutor;
+use crate::application::repository::queries::resolve_repository_webhook_secrets::query::ResolveRepositoryWebhookSecretsQuery;
+use crate::application::webhook::commands::record_rejected_delivery::command::RecordRejectedWebhookDeliveryExecutorCommand;
+use crate::application::webhook::commands::record_rejected_delivery::executor::RecordRejectedWebhookDeliveryExecutor;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use crate::infrastructure::contracts::github::headers::GithubHeaders;
+use crate::infrastructure::contracts::github::mapper::GithubWebhookEventMapper;
 use axum::body::{Body, Bytes, to_bytes};
 use axum::http::StatusCode;
 use axum::{extract::Request, middleware::Next, response::Response};
 use hmac::Hmac;
 use sha2::Sha256;
 use sha2::digest::Mac;
+use std::sync::Arc;
 
 const MAX_WEBHOOK_BODY_BYTES: usize = 10 * 1024 * 1024;
 
 pub struct GithubWebhookAuthorizationMiddleware {
-    secret: String,
+    resolve_secrets: Arc<ResolveRepositoryWebhookSecretsExecutor>,
+    record_rejected: Arc<RecordRejectedWebhookDeliveryExecutor>,
 }
 
 type HmacSha256 = Hmac<Sha256>;
 
 impl GithubWebhookAuthorizationMiddleware {
-    pub fn new(secret: String) -> Self {
-        Self { secret }
+    pub fn new(
+        resolve_secrets: Arc<ResolveRepositoryWebhookSecretsExecutor>,
+        record_rejected: Arc<RecordRejectedWebhookDeliveryExecutor>,
+    ) -> Self {
+        Self {
+            resolve_secrets,
+            record_rejected,
+        }
+    }
+
+    /// Пишет отклонённый запрос в журнал. Ошибка записи не меняет ответ —
+    /// запрос всё равно отклоняется
+    async fn journal_rejected(
+        &self,
+        headers: &axum::http::HeaderMap,
+        repository_full_name: Option<String>,
+        payload: &[u8],
+    ) {
+        let event_type = headers
+            .get(GithubHeaders::EVENT)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("-")
+            .to_string();
+
+        match self
+            .record_rejected
+            .execute(&RecordRejectedWebhookDeliveryExecutorCommand {
+                provider: VersionControlType::Github,
+                event_type,
+                repository_full_name,
+                payload: String::from_utf8_lossy(payload).into_owned(),
+            })
+            .await
+        {
+            Ok(response) => tracing::debug!(
+                journal_id = response.delivery.id.0,
+                "Rejected GitHub webhook request recorded"
+            ),
+            Err(err) => {
+                tracing::error!(error = %err, "Failed to record rejected GitHub webhook request")
+            }
+        }
+    }
+
+    /// Подпись валидна, если совпала хотя бы с одним из секретов
+    /// (текущий и предыдущий во время ротации).
+    fn verify(secrets: &[String], payload: &[u8], signature: &[u8]) -> bool {
+        secrets.iter().any(|secret| {
+            let Ok(mut hmac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+                return false;
+            };
+            hmac.update(payload);
+            hmac.verify_slice(signature).is_ok()
+        })
     }
 
     pub async fn handle(self, request: Request<Body>, next: Next) -> Result<Response, StatusCode> {
-        if self.secret.is_empty() {
+        let (parts, body) = request.into_parts();
+
+        let payload: Bytes = match to_bytes(body, MAX_WEBHOOK_BODY_BYTES).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                tracing::warn!(error = ?err, "Webhook body too large or read failed");
+                return Err(StatusCode::PAYLOAD_TOO_LARGE);
+            }
+        };
+
+        // Репозиторий из ещё не проверенного payload — только чтобы выбрать секрет,
+        // подпись всё равно должна сойтись с ним
+        let parsed_payload = serde_json::from_slice::<serde_json::Value>(&payload).ok();
+        let repository_full_name = parsed_payload
+            .as_ref()
+            .and_then(GithubWebhookEventMapper::extract_repository_full_name);
+        let previous_repository_full_name = parsed_payload
+            .as_ref()
+            .and_then(GithubWebhookEventMapper::extract_previous_repository_full_name);
+
+        let secrets = match self
+            .resolve_secrets
+            .execute(&ResolveRepositoryWebhookSecretsQuery {
+                repository_full_name: repository_full_name.clone(),
+                previous_repository_full_name,
+            })
+            .await
+        {
+            Ok(response) => response.secrets,
+            Err(err) => {
+                tracing::error!(error = %err, "Failed to resolve GitHub webhook secrets");
+                return Err(StatusCode::SERVICE_UNAVAILABLE);
+            }
+        };
+
+        if secrets.is_empty() {
             tracing::error!(
-                "GITHUB_WEBHOOK_SECRET is not configured; rejecting webhook request"
+                repository = repository_full_name.as_deref().unwrap_or("-"),
+                "No GitHub webhook secret configured; rejecting webhook request"
             );
             return Err(StatusCode::SERVICE_UNAVAILABLE);
         }
 
-        let signature = request
-            .headers()
+        let signature = parts
+            .headers
             .get(GithubHeaders::SIGNATURE_256)
             .and_then(|v| v.to_str().ok())
             .unwrap_or("");
@@ -39,32 +136,26 @@ impl GithubWebhookAuthorizationMiddleware {
             Ok(bytes) => bytes,
             Err(_) => {
                 tracing::warn!("Invalid hex in GitHub signature header");
+                self.journal_rejected(&parts.headers, repository_full_name, &payload)
+                    .await;
                 return Err(StatusCode::FORBIDDEN);
             }
         };
 
-        let mut hmac = match HmacSha256::new_from_slice(self.secret.as_bytes()) {
-            Ok(hmac) => hmac,
-            Err(err) => {
-                tracing::error!(error = ?err, "Failed to create HMAC with secret");
-                return Err(StatusCode::INTERNAL_SERVER_ERROR);
-            }
-        };
-
-        let (parts, body) = request.into_parts();
-
-        let payload: Bytes = match to_bytes(body, MAX_WEBHOOK_BODY_BYTES).await {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                tracing::warn!(error = ?err, "Webhook body too large or read failed");
-                return Err(StatusCode::PAYLOAD_TOO_LARGE);
-            }
-        };
-
-        hmac.update(&payload);
-
-        if hmac.verify_slice(&signature_bytes).is_err() {
-            tracing::warn!("GitHub webhook signature verification failed");
+        if !Self::verify(&secrets, &payload, &signature_bytes) {
+            // В журнал пишем без id доставки: заголовок неподписанного запроса
+            // может подставить кто угодно и занять им настоящую доставку
+            tracing::warn!(
+                repository = repository_full_name.as_deref().unwrap_or("-"),
+                delivery = parts
+                    .headers
+                    .get(GithubHeaders::DELIVERY)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or("-"),
+                "GitHub webhook signature verification failed"
+            );
+            self.journal_rejected(&parts.headers, repository_full_name, &payload)
+                .await;
             return Err(StatusCode::FORBIDDEN);
         }
 
@@ -0,0 +1,36 @@
+use crate::infrastructure::contracts::gitlab::headers::GitlabHeaders;
+use crate::utils::security::constant_time::constant_time_eq;
+use axum::body::Body;
+use axum::http::StatusCode;
+use axum::{extract::Request, middleware::Next, response::Response};
+
+pub struct GitlabWebhookAuthorizationMiddleware {
+    secret: String,
+}
+
+impl GitlabWebhookAuthorizationMiddleware {
+    pub fn new(secret: String) -> Self {
+        Self { secret }
+    }
+
+    pub async fn handle(self, request: Request<Body>, next: Next) -> Result<Response, StatusCode> {
+        if self.secret.is_empty() {
+            tracing::error!("GITLAB_WEBHOOK_SECRET is not configured; rejecting webhook request");
+            return Err(StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        // GitLab не подписывает тело, а просто присылает секрет в заголовке
+        let token = request
+            .headers()
+            .get(GitlabHeaders::TOKEN)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("");
+
+        if !constant_time_eq(token.as_bytes(), self.secret.as_bytes()) {
+            tracing::warn!("GitLab webhook token verification failed");
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        Ok(next.run(request).await)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::utils::security::constant_time::constant_time_eq;
+use axum::body::Body;
+use axum::http::StatusCode;
+use axum::{extract::Request, middleware::Next, response::Response};
+
+pub struct KaitenWebhookAuthorizationMiddleware {
+    secret: String,
+}
+
+impl KaitenWebhookAuthorizationMiddleware {
+    pub fn new(secret: String) -> Self {
+        Self { secret }
+    }
+
+    pub async fn handle(self, request: Request<Body>, next: Next) -> Result<Response, StatusCode> {
+        if self.secret.is_empty() {
+            tracing::error!("KAITEN_WEBHOOK_SECRET is not configured; rejecting webhook request");
+            return Err(StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        // В Kaiten у вебхука задаётся только URL, поэтому секрет приходит в `?token=`.
+        // Секрет со спецсимволами в URL будет закодирован — сравниваем раскодированный
+        let token = request
+            .uri()
+            .query()
+            .and_then(|query| {
+                url::form_urlencoded::parse(query.as_bytes())
+                    .find(|(key, _)| key == "token")
+                    .map(|(_, value)| value.into_owned())
+            })
+            .unwrap_or_default();
+
+        if !constant_time_eq(token.as_bytes(), self.secret.as_bytes()) {
+            tracing::warn!("Kaiten webhook token verification failed");
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        Ok(next.run(request).await)
+    }
+}
@@ -1,3 +1,11 @@
+pub mod admin_auth;
+pub mod gitea_auth;
 pub mod github_auth;
+pub mod gitlab_auth;
+pub mod kaiten_auth;
 
+pub use admin_auth::AdminApiAuthorizationMiddleware;
+pub use gitea_auth::GiteaWebhookAuthorizationMiddleware;
 pub use github_auth::GithubWebhookAuthorizationMiddleware;
+pub use gitlab_auth::GitlabWebhookAuthorizationMiddleware;
+pub use kaiten_auth::KaitenWebhookAuthorizationMiddleware;
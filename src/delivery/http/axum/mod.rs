@@ -5,10 +5,19 @@ use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::bootstrap::shared_dependency::ApplicationSharedDependency;
 use crate::config::application::ApplicationConfig;
 use crate::delivery::contract::ApplicationDelivery;
+use crate::delivery::http::axum::controllers::admin::webhook_deliveries::AxumAdminWebhookDeliveriesController;
 use crate::delivery::http::axum::controllers::oauth::github::AxumOAuthGithubController;
+use crate::delivery::http::axum::controllers::oauth::gitlab::AxumOAuthGitlabController;
 use crate::delivery::http::axum::controllers::report::AxumReportController;
+use crate::delivery::http::axum::controllers::webhook::gitea::AxumWebhookGiteaController;
 use crate::delivery::http::axum::controllers::webhook::github::AxumWebhookGithubController;
-use crate::delivery::http::axum::middlewares::GithubWebhookAuthorizationMiddleware;
+use crate::delivery::http::axum::controllers::webhook::gitlab::AxumWebhookGitlabController;
+use crate::delivery::http::axum::controllers::webhook::kaiten::AxumWebhookKaitenController;
+use crate::delivery::http::axum::middlewares::{
+    AdminApiAuthorizationMiddleware, GiteaWebhookAuthorizationMiddleware,
+    GithubWebhookAuthorizationMiddleware, GitlabWebhookAuthorizationMiddleware,
+    KaitenWebhookAuthorizationMiddleware,
+};
 use axum::routing::post;
 use axum::{Extension, Router, routing::get};
 use std::sync::Arc;
@@ -43,30 +52,110 @@ impl DeliveryHttpServerAxum {
         config: &Arc<ApplicationConfig>,
         middleware_config: Arc<ApplicationConfig>,
     ) -> Router {
-        let oauth_routes = Router::new().route(
-            "/github",
-            get(AxumOAuthGithubController::handle_post).layer(Extension(
-                executors.commands.register_user_via_oauth.clone(),
-            )),
-        );
+        let oauth_routes = Router::new()
+            .route(
+                "/github",
+                get(AxumOAuthGithubController::handle_post).layer(Extension(
+                    executors.commands.register_user_via_oauth.clone(),
+                )),
+            )
+            .route(
+                "/gitlab",
+                get(AxumOAuthGitlabController::handle_post).layer(Extension(
+                    executors.commands.link_version_control_account.clone(),
+                )),
+            );
 
-        let webhook_routes = Router::new().route(
-            "/github",
-            post(AxumWebhookGithubController::handle_post)
-                .layer(Extension(executors.commands.dispatch_webhook_event.clone()))
-                .layer(axum::middleware::from_fn(move |req, next| {
-                    let mw = GithubWebhookAuthorizationMiddleware::new(
-                        middleware_config.github.webhook_secret.clone(),
-                    );
-                    async move { mw.handle(req, next).await }
-                })),
-        );
+        let github_resolve_secrets = executors.queries.resolve_repository_webhook_secrets.clone();
+        let github_record_rejected = executors.commands.record_rejected_webhook_delivery.clone();
+        let gitlab_middleware_config = middleware_config.clone();
+        let gitea_middleware_config = middleware_config.clone();
+        let kaiten_middleware_config = middleware_config.clone();
+        let admin_middleware_config = middleware_config.clone();
+
+        let webhook_routes = Router::new()
+            .route(
+                "/github",
+                post(AxumWebhookGithubController::handle_post)
+                    .layer((
+                        Extension(executors.commands.dispatch_webhook_event.clone()),
+                        Extension(executors.commands.record_webhook_delivery.clone()),
+                        Extension(executors.commands.update_webhook_delivery_status.clone()),
+                    ))
+                    .layer(axum::middleware::from_fn(move |req, next| {
+                        let mw = GithubWebhookAuthorizationMiddleware::new(
+                            github_resolve_secrets.clone(),
+                            github_record_rejected.clone(),
+                        );
+                        async move { mw.handle(req, next).await }
+                    })),
+            )
+            .route(
+                "/gitlab",
+                post(AxumWebhookGitlabController::handle_post)
+                    .layer(Extension(executors.commands.dispatch_webhook_event.clone()))
+                    .layer(axum::middleware::from_fn(move |req, next| {
+                        let mw = GitlabWebhookAuthorizationMiddleware::new(
+                            gitlab_middleware_config.gitlab.webhook_secret.clone(),
+                        );
+                        async move { mw.handle(req, next).await }
+                    })),
+            )
+            .route(
+                "/gitea",
+                post(AxumWebhookGiteaController::handle_post)
+                    .layer(Extension(executors.commands.dispatch_webhook_event.clone()))
+                    .layer(axum::middleware::from_fn(move |req, next| {
+                        let mw = GiteaWebhookAuthorizationMiddleware::new(
+                            gitea_middleware_config.gitea.webhook_secret.clone(),
+                        );
+                        async move { mw.handle(req, next).await }
+                    })),
+            )
+            // Входящие события трекера: перемещения, комментарии, участники карточек
+            .route(
+                "/kaiten",
+                post(AxumWebhookKaitenController::handle_post)
+                    .layer(Extension(executors.commands.dispatch_webhook_event.clone()))
+                    .layer(axum::middleware::from_fn(move |req, next| {
+                        let mw = KaitenWebhookAuthorizationMiddleware::new(
+                            kaiten_middleware_config.kaiten.webhook_secret.clone(),
+                        );
+                        async move { mw.handle(req, next).await }
+                    })),
+            );
+
+        // Журнал вебхук-доставок: просмотр и повторная отправка
+        let admin_routes = Router::new()
+            .route(
+                "/webhook/deliveries",
+                get(AxumAdminWebhookDeliveriesController::handle_list),
+            )
+            .route(
+                "/webhook/deliveries/replay",
+                post(AxumAdminWebhookDeliveriesController::handle_replay_window),
+            )
+            .route(
+                "/webhook/deliveries/{id}/replay",
+                post(AxumAdminWebhookDeliveriesController::handle_replay_one),
+            )
+            .route_layer(axum::middleware::from_fn(move |req, next| {
+                let mw = AdminApiAuthorizationMiddleware::new(
+                    admin_middleware_config.webhook.admin_api_token.clone(),
+                );
+                async move { mw.handle(req, next).await }
+            }))
+            .layer(Extension(executors.queries.list_webhook_deliveries.clone()))
+            .layer(Extension(
+                executors.commands.replay_webhook_deliveries.clone(),
+            ));
 
         Router::new()
             .route("/ping", get(|| async { "PONG" }))
             .route("/report/{token}", get(AxumReportController::handle_get))
             .nest("/oauth", oauth_routes)
             .nest("/webhook", webhook_routes)
+            .nest("/admin", admin_routes)
             .layer(Extension(config.clone()))
             .layer(Extension(shared_dependency.clone()))
     }
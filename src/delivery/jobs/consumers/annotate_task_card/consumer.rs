@@ -0,0 +1,53 @@
+use crate::application::task::commands::annotate_task_card::command::AnnotateTaskCardExecutorCommand;
+use crate::application::task::commands::annotate_task_card::error::AnnotateTaskCardExecutorError;
+use crate::application::task::commands::annotate_task_card::executor::AnnotateTaskCardExecutor;
+use crate::delivery::jobs::consumers::annotate_task_card::payload::AnnotateTaskCardJob;
+use crate::domain::shared::command::CommandExecutor;
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClientWriteError;
+use crate::infrastructure::processing::job::{JobConsumer, JobConsumerError, JobConsumerResponse};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AnnotateTaskCardJobConsumer {
+    pub executor: Arc<AnnotateTaskCardExecutor>,
+}
+
+#[async_trait]
+impl JobConsumer for AnnotateTaskCardJobConsumer {
+    fn name(&self) -> &'static str {
+        AnnotateTaskCardJob::NAME
+    }
+
+    async fn run(&self, payload: &[u8]) -> Result<JobConsumerResponse, JobConsumerError> {
+        let payload: AnnotateTaskCardJob = serde_json::from_slice(payload)
+            .map_err(|e| JobConsumerError::DeserializationError(e.to_string()))?;
+
+        tracing::debug!(task_id = %payload.task_id.0, "Processing annotate_task_card job");
+
+        match self
+            .executor
+            .execute(&AnnotateTaskCardExecutorCommand {
+                task_id: payload.task_id.clone(),
+                kind: payload.kind,
+                connection_id: payload.connection_id,
+                annotation: payload.annotation.clone(),
+            })
+            .await
+        {
+            Ok(_) => Ok(JobConsumerResponse::Ok),
+            // Упомянутой в PR задачи нет в трекере — повторять бессмысленно
+            Err(AnnotateTaskCardExecutorError::TaskTrackerClientWriteError(
+                TaskTrackerClientWriteError::NotFound,
+            )) => {
+                tracing::warn!(task_id = %payload.task_id.0, "Task card not found, skipping annotation");
+                Ok(JobConsumerResponse::Reject(
+                    "Task card not found".to_string(),
+                ))
+            }
+            Err(e) => {
+                tracing::error!(task_id = %payload.task_id.0, error = %e, "annotate_task_card failed, scheduling retry");
+                Ok(JobConsumerResponse::Retry(e.to_string()))
+            }
+        }
+    }
+}
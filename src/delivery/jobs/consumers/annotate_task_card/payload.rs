@@ -0,0 +1,30 @@
+use crate::domain::task::value_objects::task_card_annotation::TaskCardAnnotation;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind, MessageBrokerMessageKindJobPriority,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AnnotateTaskCardJob {
+    pub task_id: TaskId,
+    pub kind: TaskTrackerKind,
+    /// `None` — клиент трекера из окружения
+    pub connection_id: Option<i32>,
+    pub annotation: TaskCardAnnotation,
+}
+
+impl AnnotateTaskCardJob {
+    pub const NAME: &'static str = "annotate_task_card";
+}
+
+impl MessageBrokerMessage for AnnotateTaskCardJob {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Job(MessageBrokerMessageKindJobPriority::Normal)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod annotate_task_card;
 pub mod move_task_to_test;
 pub mod send_social_notify;
 
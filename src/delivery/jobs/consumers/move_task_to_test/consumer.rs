@@ -25,8 +25,11 @@ impl JobConsumer for MoveTaskToTestJobConsumer {
         if let Err(e) = self
             .executor
             .execute(&MoveTaskToTestExecutorCommand {
-                task_id: payload.task_id,
-                column_id: payload.column_id,
+                task_id: payload.task_id.clone(),
+                column_id: payload.column_id.clone(),
+                kind: payload.kind,
+                connection_id: payload.connection_id,
+                origin: payload.origin.clone(),
             })
             .await
         {
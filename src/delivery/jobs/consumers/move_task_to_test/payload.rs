@@ -1,13 +1,39 @@
+use crate::domain::task::entities::task_move::TaskMoveOrigin;
 use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::{
     MessageBrokerMessage, MessageBrokerMessageKind, MessageBrokerMessageKindJobPriority,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MoveTaskToTestJob {
     pub task_id: TaskId,
-    pub column_id: u64,
+    #[serde(deserialize_with = "deserialize_column_id")]
+    pub column_id: String,
+    /// Задачи из очереди, поставленные до появления поля, — Kaiten
+    #[serde(default)]
+    pub kind: TaskTrackerKind,
+    /// `None` — клиент трекера из окружения
+    #[serde(default)]
+    pub connection_id: Option<i32>,
+    #[serde(default)]
+    pub origin: Option<TaskMoveOrigin>,
+}
+
+/// Задачи в очереди, поставленные до перехода на строковые id, хранят число
+fn deserialize_column_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Number(u64),
+        Text(String),
+    }
+
+    Ok(match Raw::deserialize(deserializer)? {
+        Raw::Number(value) => value.to_string(),
+        Raw::Text(value) => value,
+    })
 }
 
 impl MoveTaskToTestJob {
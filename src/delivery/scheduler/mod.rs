@@ -1,10 +1,15 @@
+use crate::application::commit_check::commands::prune_commit_checks::command::PruneCommitChecksExecutorCommand;
 use crate::application::digest::commands::send_due_digests::command::SendDueDigestsCommand;
 use crate::application::health_ping::commands::check_all_health_pings::command::CheckAllHealthPingsCommand;
+use crate::application::merged_branch::commands::send_branch_cleanup_reminders::command::SendBranchCleanupRemindersExecutorCommand;
 use crate::application::notification::commands::flush_pending_notifications::command::FlushPendingNotificationsExecutorCommand;
+use crate::application::notification::commands::prune_slack_messages::command::PruneSlackMessagesExecutorCommand;
 use crate::application::notification::commands::scan_pr_conflicts::command::ScanPrConflictsExecutorCommand;
 use crate::application::notification::commands::scan_stale_pull_requests::command::ScanStalePullRequestsExecutorCommand;
 use crate::application::release_plan::commands::send_call_reminders::command::SendCallRemindersExecutorCommand;
 use crate::application::release_plan::commands::send_release_day_reminders::command::SendReleaseDayRemindersExecutorCommand;
+use crate::application::webhook::commands::prune_deliveries::command::PruneWebhookDeliveriesExecutorCommand;
+use crate::application::webhook::commands::recover_deliveries::command::RecoverWebhookDeliveriesExecutorCommand;
 use crate::bootstrap::executors::ApplicationBoostrapExecutors;
 use crate::config::application::ApplicationConfig;
 use crate::delivery::contract::ApplicationDelivery;
@@ -264,8 +269,154 @@ impl ApplicationDelivery for DeliveryScheduler {
             .await
             .expect("JobScheduler failed to add call reminder job");
 
+        // Очистка журнала вебхук-доставок — каждый день в 03:00 UTC
+        let prune_executors = self.executors.clone();
+        scheduler
+            .add(
+                Job::new_async("0 0 3 * * *", move |_uuid, _lock| {
+                    let executors = prune_executors.clone();
+                    Box::pin(async move {
+                        match executors
+                            .commands
+                            .prune_webhook_deliveries
+                            .execute(&PruneWebhookDeliveriesExecutorCommand)
+                            .await
+                        {
+                            Ok(r) if r.deleted_count > 0 => {
+                                tracing::info!(
+                                    deleted = r.deleted_count,
+                                    "Old webhook deliveries pruned"
+                                );
+                            }
+                            Err(e) => {
+                                tracing::error!(error = %e, "Webhook deliveries prune failed");
+                            }
+                            _ => {}
+                        }
+                    })
+                })
+                .expect("Webhook deliveries prune job create error"),
+            )
+            .await
+            .expect("JobScheduler failed to add webhook deliveries prune job");
+
+        // Очистка состояния CI-проверок — каждый день в 03:10 UTC
+        let checks_prune_executors = self.executors.clone();
+        scheduler
+            .add(
+                Job::new_async("0 10 3 * * *", move |_uuid, _lock| {
+                    let executors = checks_prune_executors.clone();
+                    Box::pin(async move {
+                        match executors
+                            .commands
+                            .prune_commit_checks
+                            .execute(&PruneCommitChecksExecutorCommand)
+                            .await
+                        {
+                            Ok(r) if r.deleted_count > 0 => {
+                                tracing::info!(
+                                    deleted = r.deleted_count,
+                                    "Old commit checks pruned"
+                                );
+                            }
+                            Err(e) => {
+                                tracing::error!(error = %e, "Commit checks prune failed");
+                            }
+                            _ => {}
+                        }
+                    })
+                })
+                .expect("Commit checks prune job create error"),
+            )
+            .await
+            .expect("JobScheduler failed to add commit checks prune job");
+
+        // Очистка ссылок на сообщения в Slack — каждый день в 03:20 UTC
+        let slack_prune_executors = self.executors.clone();
+        scheduler
+            .add(
+                Job::new_async("0 20 3 * * *", move |_uuid, _lock| {
+                    let executors = slack_prune_executors.clone();
+                    Box::pin(async move {
+                        match executors
+                            .commands
+                            .prune_slack_messages
+                            .execute(&PruneSlackMessagesExecutorCommand)
+                            .await
+                        {
+                            Ok(r) if r.deleted_count > 0 => {
+                                tracing::info!(
+                                    deleted = r.deleted_count,
+                                    "Old Slack messages pruned"
+                                );
+                            }
+                            Err(e) => {
+                                tracing::error!(error = %e, "Slack messages prune failed");
+                            }
+                            _ => {}
+                        }
+                    })
+                })
+                .expect("Slack messages prune job create error"),
+            )
+            .await
+            .expect("JobScheduler failed to add Slack messages prune job");
+
+        // Напоминания удалить ветки смерженных PR — каждый день в 11:00 МСК (08:00 UTC)
+        let branch_cleanup_executors = self.executors.clone();
+        scheduler
+            .add(
+                Job::new_async("0 0 8 * * *", move |_uuid, _lock| {
+                    let executors = branch_cleanup_executors.clone();
+                    Box::pin(async move {
+                        match executors
+                            .commands
+                            .send_branch_cleanup_reminders
+                            .execute(&SendBranchCleanupRemindersExecutorCommand)
+                            .await
+                        {
+                            Ok(r) if r.sent_count > 0 || r.cleaned_count > 0 => {
+                                tracing::info!(
+                                    sent = r.sent_count,
+                                    cleaned = r.cleaned_count,
+                                    "Branch cleanup reminders processed"
+                                );
+                            }
+                            Err(e) => {
+                                tracing::error!(error = %e, "Branch cleanup reminders failed");
+                            }
+                            _ => {}
+                        }
+                    })
+                })
+                .expect("Branch cleanup reminder job create error"),
+            )
+            .await
+            .expect("JobScheduler failed to add branch cleanup reminder job");
+
+        // Восстановление пропущенных доставок GitHub — каждые 10 минут (в :45)
+        let recover_executors = self.executors.clone();
+        scheduler
+            .add(
+                Job::new_async("45 */10 * * * *", move |_uuid, _lock| {
+                    let executors = recover_executors.clone();
+                    Box::pin(async move {
+                        recover_webhook_deliveries(&executors).await;
+                    })
+                })
+                .expect("Webhook recovery job create error"),
+            )
+            .await
+            .expect("JobScheduler failed to add webhook recovery job");
+
         scheduler.start().await.expect("JobScheduler start failed");
 
+        // И один раз сразу после старта — события, пропущенные пока под лежал
+        let startup_executors = self.executors.clone();
+        tokio::spawn(async move {
+            recover_webhook_deliveries(&startup_executors).await;
+        });
+
         tracing::info!("Scheduler started");
 
         // Keep the scheduler alive — dropping it stops all cron jobs
@@ -274,3 +425,25 @@ impl ApplicationDelivery for DeliveryScheduler {
         }
     }
 }
+
+async fn recover_webhook_deliveries(executors: &ApplicationBoostrapExecutors) {
+    match executors
+        .commands
+        .recover_webhook_deliveries
+        .execute(&RecoverWebhookDeliveriesExecutorCommand)
+        .await
+    {
+        Ok(r) if r.recovered_count > 0 || r.failed_count > 0 => {
+            tracing::info!(
+                repos = r.repos_scanned,
+                recovered = r.recovered_count,
+                failed = r.failed_count,
+                "Missed webhook deliveries recovered"
+            );
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Webhook deliveries recovery failed");
+        }
+        _ => {}
+    }
+}
@@ -16,6 +16,9 @@ pub struct OAuthClientExchangeCodeResponse {
     pub access_token: String,
     pub scope: String,
     pub token_type: String,
+    /// GitHub OAuth-токены бессрочные, токены GitLab живут два часа
+    #[serde(default)]
+    pub expires_in: Option<i64>,
 }
 
 #[async_trait]
@@ -0,0 +1,37 @@
+use crate::domain::commit_check::value_objects::commit_check_kind::CommitCheckKind;
+
+/// Последнее известное состояние одной проверки на коммите.
+/// Уникальна по (repo, head_sha, check_key): перезапуск проверки
+/// перезаписывает её состояние.
+#[derive(Debug, Clone)]
+pub struct CommitCheck {
+    pub repo: String,
+    pub head_sha: String,
+    /// `run:<name>` для check_run, `suite:<id>` для check_suite
+    pub check_key: String,
+    pub kind: CommitCheckKind,
+    pub name: String,
+    pub check_suite_id: Option<u64>,
+    pub status: String,             // queued, in_progress, completed
+    pub conclusion: Option<String>, // success, failure, neutral, skipped, cancelled, timed_out, action_required
+    pub html_url: Option<String>,
+    pub head_branch: Option<String>,
+    pub pr_numbers: Vec<u64>,
+    /// Кто запустил проверки (не бот) — адресат ЛС при падении
+    pub actor: Option<String>,
+}
+
+impl CommitCheck {
+    pub fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
+
+    /// neutral и skipped мерж не блокируют
+    pub fn is_failed(&self) -> bool {
+        self.is_completed()
+            && matches!(
+                self.conclusion.as_deref(),
+                Some("failure") | Some("cancelled") | Some("timed_out") | Some("action_required")
+            )
+    }
+}
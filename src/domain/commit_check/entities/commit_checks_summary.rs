@@ -0,0 +1,79 @@
+use crate::domain::commit_check::entities::commit_check::CommitCheck;
+use crate::domain::commit_check::value_objects::commit_check_kind::CommitCheckKind;
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedCommitCheck {
+    pub name: String,
+    pub conclusion: String,
+    pub html_url: Option<String>,
+}
+
+/// Сводка по всем проверкам на коммите
+#[derive(Debug, Clone)]
+pub struct CommitChecksSummary {
+    pub state: CommitCiState,
+    pub total: usize,
+    pub pending: usize,
+    pub failing: Vec<FailedCommitCheck>,
+    pub head_branch: Option<String>,
+    pub pr_numbers: Vec<u64>,
+    pub actor: Option<String>,
+}
+
+impl CommitChecksSummary {
+    /// Считаем по check_run. Suite без единого run учитывается сам: пока он
+    /// в `queued`, проверки ещё не начались и сводка остаётся в ожидании.
+    pub fn from_checks(checks: &[CommitCheck]) -> Self {
+        let runs: Vec<&CommitCheck> = checks
+            .iter()
+            .filter(|c| c.kind == CommitCheckKind::Run)
+            .collect();
+
+        let orphan_suites = checks.iter().filter(|c| {
+            c.kind == CommitCheckKind::Suite
+                && !runs
+                    .iter()
+                    .any(|r| r.check_suite_id.is_some() && r.check_suite_id == c.check_suite_id)
+        });
+
+        let counted: Vec<&CommitCheck> = runs.iter().copied().chain(orphan_suites).collect();
+
+        let pending = counted.iter().filter(|c| !c.is_completed()).count();
+        let failing: Vec<FailedCommitCheck> = counted
+            .iter()
+            .filter(|c| c.is_failed())
+            .map(|c| FailedCommitCheck {
+                name: c.name.clone(),
+                conclusion: c.conclusion.clone().unwrap_or_default(),
+                html_url: c.html_url.clone(),
+            })
+            .collect();
+
+        let state = if counted.is_empty() || pending > 0 {
+            CommitCiState::Pending
+        } else if failing.is_empty() {
+            CommitCiState::Success
+        } else {
+            CommitCiState::Failure
+        };
+
+        let mut pr_numbers: Vec<u64> = checks
+            .iter()
+            .flat_map(|c| c.pr_numbers.iter().copied())
+            .collect();
+        pr_numbers.sort_unstable();
+        pr_numbers.dedup();
+
+        Self {
+            state,
+            total: counted.len(),
+            pending,
+            failing,
+            head_branch: checks.iter().find_map(|c| c.head_branch.clone()),
+            pr_numbers,
+            actor: checks.iter().find_map(|c| c.actor.clone()),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod commit_check;
+pub mod commit_checks_summary;
@@ -0,0 +1,35 @@
+use crate::domain::commit_check::entities::commit_checks_summary::FailedCommitCheck;
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
+use crate::domain::shared::events::event::DomainEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use serde::{Deserialize, Serialize};
+
+/// Все проверки на коммите завершились. Публикуется один раз на смену
+/// итогового состояния SHA (зелёный → красный после перезапуска и т.п.).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommitChecksCompletedEvent {
+    pub repo: String,
+    pub head_sha: String,
+    pub head_branch: Option<String>,
+    pub pr_numbers: Vec<u64>,
+    pub actor: Option<String>,
+    pub state: CommitCiState,
+    pub total: usize,
+    pub failing: Vec<FailedCommitCheck>,
+}
+
+impl DomainEvent for CommitChecksCompletedEvent {
+    const EVENT_NAME: &'static str = "ci.checks_completed";
+}
+
+impl MessageBrokerMessage for CommitChecksCompletedEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
@@ -0,0 +1,4 @@
+pub mod entities;
+pub mod events;
+pub mod repositories;
+pub mod value_objects;
@@ -0,0 +1,63 @@
+use crate::domain::commit_check::entities::commit_check::CommitCheck;
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UpsertCommitCheckError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FindCommitCheckError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteCommitCheckError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait::async_trait]
+pub trait CommitCheckRepository: Send + Sync {
+    /// Вставка или обновление по (repo, head_sha, check_key)
+    async fn upsert(&self, check: &CommitCheck) -> Result<(), UpsertCommitCheckError>;
+
+    async fn find_by_commit(
+        &self,
+        repo: &str,
+        head_sha: &str,
+    ) -> Result<Vec<CommitCheck>, FindCommitCheckError>;
+
+    /// Последнее сводное состояние, о котором уже сообщили
+    async fn find_reported_state(
+        &self,
+        repo: &str,
+        head_sha: &str,
+    ) -> Result<Option<CommitCiState>, FindCommitCheckError>;
+
+    /// Атомарно меняет сообщённое состояние. `false` — оно уже такое:
+    /// параллельный обработчик успел сообщить первым
+    async fn save_reported_state(
+        &self,
+        repo: &str,
+        head_sha: &str,
+        state: CommitCiState,
+    ) -> Result<bool, UpsertCommitCheckError>;
+
+    /// Возвращает `previous`, если сообщённое состояние всё ещё `state`
+    async fn restore_reported_state(
+        &self,
+        repo: &str,
+        head_sha: &str,
+        state: CommitCiState,
+        previous: Option<CommitCiState>,
+    ) -> Result<(), UpsertCommitCheckError>;
+
+    /// Удаляет проверки и сводные состояния, не обновлявшиеся с `before`
+    async fn delete_older_than(&self, before: DateTime<Utc>)
+    -> Result<u64, DeleteCommitCheckError>;
+}
@@ -0,0 +1 @@
+pub mod commit_check_repository;
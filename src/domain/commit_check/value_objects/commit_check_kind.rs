@@ -0,0 +1,37 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitCheckKind {
+    /// Отдельная проверка (job, анализатор)
+    Run,
+    /// Набор проверок одного приложения
+    Suite,
+}
+
+impl CommitCheckKind {
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            CommitCheckKind::Run => "run",
+            CommitCheckKind::Suite => "suite",
+        }
+    }
+}
+
+impl fmt::Display for CommitCheckKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+impl FromStr for CommitCheckKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "run" => Ok(CommitCheckKind::Run),
+            "suite" => Ok(CommitCheckKind::Suite),
+            _ => Err(format!("Unknown commit check kind: {}", s)),
+        }
+    }
+}
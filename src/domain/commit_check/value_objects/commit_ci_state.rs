@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Сводное состояние всех проверок на коммите
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitCiState {
+    Pending,
+    Success,
+    Failure,
+}
+
+impl CommitCiState {
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, CommitCiState::Pending)
+    }
+
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            CommitCiState::Pending => "pending",
+            CommitCiState::Success => "success",
+            CommitCiState::Failure => "failure",
+        }
+    }
+}
+
+impl fmt::Display for CommitCiState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+impl FromStr for CommitCiState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(CommitCiState::Pending),
+            "success" => Ok(CommitCiState::Success),
+            "failure" => Ok(CommitCiState::Failure),
+            _ => Err(format!("Unknown commit CI state: {}", s)),
+        }
+    }
+}
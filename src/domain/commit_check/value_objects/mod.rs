@@ -0,0 +1,2 @@
+pub mod commit_check_kind;
+pub mod commit_ci_state;
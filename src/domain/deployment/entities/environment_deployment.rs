@@ -0,0 +1,24 @@
+use crate::domain::deployment::value_objects::environment_kind::EnvironmentKind;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use chrono::{DateTime, Utc};
+
+/// Последний успешный деплой в окружение репозитория
+#[derive(Debug, Clone)]
+pub struct EnvironmentDeployment {
+    pub version_control_type: VersionControlType,
+    pub repo: String, // owner/repo
+    pub environment: String,
+    pub deployment_id: u64,
+    pub sha: String,
+    pub ref_name: Option<String>,
+    /// Кто запустил деплой (login в системе контроля версий)
+    pub deployed_by: Option<String>,
+    pub environment_url: Option<String>,
+    pub deployed_at: DateTime<Utc>,
+}
+
+impl EnvironmentDeployment {
+    pub fn kind(&self) -> EnvironmentKind {
+        EnvironmentKind::from_environment_name(&self.environment)
+    }
+}
@@ -0,0 +1 @@
+pub mod environment_deployment;
@@ -0,0 +1,32 @@
+use crate::domain::deployment::entities::environment_deployment::EnvironmentDeployment;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UpsertEnvironmentDeploymentError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FindEnvironmentDeploymentError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait::async_trait]
+pub trait EnvironmentDeploymentRepository: Send + Sync {
+    /// Вставка или обновление по (провайдер, repo, environment). Более старый деплой
+    /// (вебхуки приходят не по порядку) запись не перезаписывает.
+    async fn upsert(
+        &self,
+        deployment: &EnvironmentDeployment,
+    ) -> Result<(), UpsertEnvironmentDeploymentError>;
+
+    /// owner/name у зеркал в разных провайдерах совпадает — ищем по паре
+    async fn find_by_repo(
+        &self,
+        version_control_type: &VersionControlType,
+        repo: &str,
+    ) -> Result<Vec<EnvironmentDeployment>, FindEnvironmentDeploymentError>;
+}
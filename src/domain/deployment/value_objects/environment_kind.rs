@@ -0,0 +1,22 @@
+/// Назначение окружения GitHub Environments. Имена окружений произвольные,
+/// поэтому распознаём распространённые варианты.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EnvironmentKind {
+    Production,
+    Staging,
+    Other,
+}
+
+impl EnvironmentKind {
+    pub fn from_environment_name(name: &str) -> Self {
+        let name = name.trim().to_lowercase();
+
+        if name == "prod" || name.starts_with("production") {
+            EnvironmentKind::Production
+        } else if name == "stage" || name.starts_with("staging") {
+            EnvironmentKind::Staging
+        } else {
+            EnvironmentKind::Other
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use chrono::{DateTime, Utc};
+
+/// Head-ветка смерженного PR, которую ещё не удалили
+#[derive(Debug, Clone)]
+pub struct MergedBranch {
+    pub version_control_type: VersionControlType,
+    pub repo: String, // owner/repo
+    pub branch: String,
+    pub pr_number: u64,
+    pub pr_url: Option<String>,
+    /// Автор PR (login в системе контроля версий) — ему и напоминаем
+    pub author_login: String,
+    pub merged_at: DateTime<Utc>,
+    /// Когда отправили напоминание. Напоминаем один раз.
+    pub reminded_at: Option<DateTime<Utc>>,
+}
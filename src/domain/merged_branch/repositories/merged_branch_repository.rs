@@ -0,0 +1,56 @@
+use crate::domain::merged_branch::entities::merged_branch::MergedBranch;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UpsertMergedBranchError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteMergedBranchError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FindMergedBranchError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum MarkMergedBranchRemindedError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait::async_trait]
+pub trait MergedBranchRepository: Send + Sync {
+    /// Вставка или замена по (провайдер, repo, branch): ветку с тем же именем
+    /// могли пересоздать и смержить новым PR
+    async fn upsert(&self, branch: &MergedBranch) -> Result<(), UpsertMergedBranchError>;
+
+    async fn delete(
+        &self,
+        version_control_type: &VersionControlType,
+        repo: &str,
+        branch: &str,
+    ) -> Result<(), DeleteMergedBranchError>;
+
+    /// Ветки без напоминания, смерженные не позже `merged_before`
+    async fn find_unreminded_merged_before(
+        &self,
+        merged_before: DateTime<Utc>,
+    ) -> Result<Vec<MergedBranch>, FindMergedBranchError>;
+
+    async fn mark_reminded(
+        &self,
+        version_control_type: &VersionControlType,
+        repo: &str,
+        branch: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), MarkMergedBranchRemindedError>;
+}
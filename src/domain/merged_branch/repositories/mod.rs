@@ -0,0 +1 @@
+pub mod merged_branch_repository;
@@ -1,6 +1,9 @@
 pub mod auth;
+pub mod commit_check;
+pub mod deployment;
 pub mod digest;
 pub mod health_ping;
+pub mod merged_branch;
 pub mod monitoring;
 pub mod notification;
 pub mod notification_log;
@@ -15,3 +18,4 @@ pub mod user;
 pub mod user_preferences;
 pub mod version_control;
 pub mod webhook;
+pub mod webhook_delivery;
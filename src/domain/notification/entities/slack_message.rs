@@ -0,0 +1,11 @@
+use crate::domain::user::value_objects::slack_message_ref::SlackMessageRef;
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::social_message_id::SocialMessageId;
+
+/// Отправленное в Slack сообщение. Для дубля уведомления из Telegram `mirrored`
+/// указывает на исходное сообщение
+#[derive(Debug, Clone)]
+pub struct SlackMessage {
+    pub mirrored: Option<(SocialChatId, SocialMessageId)>,
+    pub message_ref: SlackMessageRef,
+}
@@ -0,0 +1 @@
+pub mod slack_message_repository;
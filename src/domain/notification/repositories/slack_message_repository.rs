@@ -0,0 +1,20 @@
+use crate::domain::notification::entities::slack_message::SlackMessage;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SlackMessageRepositoryError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait::async_trait]
+pub trait SlackMessageRepository: Send + Sync {
+    async fn create(&self, message: &SlackMessage) -> Result<(), SlackMessageRepositoryError>;
+
+    /// Удаляет записи, отправленные раньше `before`. Возвращает число удалённых
+    async fn delete_older_than(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<u64, SlackMessageRepositoryError>;
+}
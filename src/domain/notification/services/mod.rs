@@ -1 +1,2 @@
 pub mod notification_service;
+pub mod slack_notification_service;
@@ -35,12 +35,13 @@ pub enum NotificationServiceParseMode {
 
 #[async_trait::async_trait]
 pub trait NotificationService: Send + Sync {
+    /// Возвращает ID отправленного сообщения — по нему его можно изменить или удалить
     async fn send_message(
         &self,
         social_type: &SocialType,
         chat_id: &SocialChatId,
         message: &MessageBuilder,
-    ) -> Result<(), NotificationServiceSendError>;
+    ) -> Result<SocialMessageId, NotificationServiceSendError>;
 
     async fn delete_message(
         &self,
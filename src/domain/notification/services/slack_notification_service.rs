@@ -0,0 +1,15 @@
+use crate::domain::notification::services::notification_service::NotificationServiceSendError;
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use crate::domain::user::value_objects::slack_message_ref::SlackMessageRef;
+use crate::utils::builder::message::MessageBuilder;
+
+/// Slack адресует чаты и сообщения строками, поэтому не помещается
+/// в `NotificationService` с числовыми ID Telegram
+#[async_trait::async_trait]
+pub trait SlackNotificationService: Send + Sync {
+    async fn send_message(
+        &self,
+        channel_id: &SlackChannelId,
+        message: &MessageBuilder,
+    ) -> Result<SlackMessageRef, NotificationServiceSendError>;
+}
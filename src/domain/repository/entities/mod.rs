@@ -1,3 +1,4 @@
 pub mod repository;
 pub mod repository_pull_request;
 pub mod repository_task_tracker;
+pub mod repository_task_tracker_rule;
@@ -1,5 +1,9 @@
+use crate::domain::repository::value_objects::ref_events_filter::RefEventsFilter;
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
 use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::utils::security::crypto::reversible::ReversibleCipherValue;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -9,12 +13,38 @@ pub struct Repository {
     pub name: String,
     pub owner: String,
     pub url: String,
+    /// Провайдер, в котором живёт репозиторий (GitHub / Gitea)
+    pub version_control_type: VersionControlType,
     /// Chat ID where raw GitHub webhook notifications go (push / release / workflow / большая PR-карточка).
     /// `None` means fallback to the global `TELEGRAM_CHAT_ID`.
     pub social_chat_id: Option<SocialChatId>,
     /// Chat ID where curated team-relevant notifications go (теги ревьюеров, cc-mentions, approve, stale digest, релизы).
     /// `None` means fallback to `social_chat_id`.
     pub notifications_chat_id: Option<SocialChatId>,
+    /// Секрет вебхука этого репозитория. `None` — используется глобальный `GITHUB_WEBHOOK_SECRET`.
+    pub webhook_secret: Option<ReversibleCipherValue>,
+    /// Секрет до последней ротации. `None` при заданном `webhook_secret_rotated_at` —
+    /// до ротации действовал глобальный секрет.
+    pub previous_webhook_secret: Option<ReversibleCipherValue>,
+    pub webhook_secret_rotated_at: Option<DateTime<Utc>>,
+    /// Какие создания/удаления веток и тегов слать в чат. `None` — все.
+    pub ref_events_filter: Option<RefEventsFilter>,
+    /// Репозиторий заархивирован на стороне провайдера — уведомления по нему не шлём
+    pub is_archived: bool,
+    /// Канал Slack, куда дублируются уведомления из чатов репозитория. `None` — только Telegram.
+    pub slack_channel_id: Option<SlackChannelId>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+impl Repository {
+    /// Ссылка на PR в веб-интерфейсе провайдера
+    pub fn pull_request_url(&self, number: u64) -> String {
+        let base = self.url.trim_end_matches('/');
+        match self.version_control_type {
+            VersionControlType::Github => format!("{}/pull/{}", base, number),
+            VersionControlType::Gitea => format!("{}/pulls/{}", base, number),
+            VersionControlType::Gitlab => format!("{}/-/merge_requests/{}", base, number),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -6,10 +7,21 @@ use serde::{Deserialize, Serialize};
 pub struct RepositoryTaskTracker {
     pub id: i32,
     pub repository_id: RepositoryId,
-    pub space_id: i32,
-    pub qa_column_id: i32,
+    pub space_id: String,
+    pub qa_column_id: String,
     pub extract_pattern_regexp: String,
     pub path_to_card: String,
+    pub kind: TaskTrackerKind,
+    /// Подключение из админки. `None` — клиент трекера `kind` из окружения.
+    pub connection_id: Option<i32>,
+    /// Без ID задачи в заголовке, ветке или описании PR получает красный статус
+    pub require_task_id: bool,
+    /// Куда `/test_queue` переводит принятую задачу. `None` — кнопки «принято» нет
+    pub qa_passed_column_id: Option<String>,
+    /// Куда `/test_queue` возвращает задачу на доработку. `None` — кнопки «вернуть» нет
+    pub qa_failed_column_id: Option<String>,
+    /// Куда заводить карточку для нового GitHub issue. `None` — issue не зеркалируются
+    pub issue_column_id: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
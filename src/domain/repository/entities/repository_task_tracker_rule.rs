@@ -0,0 +1,33 @@
+use crate::domain::repository::value_objects::pull_request_lifecycle_trigger::PullRequestLifecycleTrigger;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::utils::parsing::glob::glob_matches;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Правило: при событии `trigger` перевести карточку задачи в колонку `column_id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryTaskTrackerRule {
+    pub id: i32,
+    pub repository_id: RepositoryId,
+    pub trigger: PullRequestLifecycleTrigger,
+    pub column_id: String,
+    /// Glob по целевой ветке PR (для релиза — по `target_commitish`). `None` — любая ветка
+    pub base_branch_pattern: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RepositoryTaskTrackerRule {
+    /// Если ветка события неизвестна, срабатывают только правила без шаблона
+    pub fn matches(&self, trigger: PullRequestLifecycleTrigger, base_ref: Option<&str>) -> bool {
+        if self.trigger != trigger {
+            return false;
+        }
+
+        match (&self.base_branch_pattern, base_ref) {
+            (None, _) => true,
+            (Some(pattern), Some(base_ref)) => glob_matches(pattern, base_ref),
+            (Some(_), None) => false,
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod repository_pull_request_repository;
 pub mod repository_repository;
 pub mod repository_task_tracker_repository;
+pub mod repository_task_tracker_rule_repository;
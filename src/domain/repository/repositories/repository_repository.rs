@@ -1,5 +1,7 @@
 use crate::domain::repository::entities::repository::Repository;
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use async_trait::async_trait;
 use sea_orm::DatabaseTransaction;
 use thiserror::Error;
@@ -40,6 +42,12 @@ pub enum FindAllRepositoriesError {
     DbError(String),
 }
 
+#[derive(Debug, Error)]
+pub enum FindRepositoriesBySocialChatIdError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
 #[derive(Debug, Error)]
 pub enum DeleteRepositoryError {
     #[error("Database error: {0}")]
@@ -74,8 +82,16 @@ pub trait RepositoryRepository: Send + Sync {
 
     async fn find_all(&self) -> Result<Vec<Repository>, FindAllRepositoriesError>;
 
+    /// Репозитории, привязанные к чату — основным или чатом уведомлений
+    async fn find_by_social_chat_id(
+        &self,
+        chat_id: &SocialChatId,
+    ) -> Result<Vec<Repository>, FindRepositoriesBySocialChatIdError>;
+
+    /// Один `owner/name` может быть и в GitHub, и в зеркале Gitea
     async fn find_by_owner_and_name(
         &self,
+        version_control_type: &VersionControlType,
         owner: &str,
         name: &str,
     ) -> Result<Repository, FindRepositoryByOwnerAndNameError>;
@@ -0,0 +1,40 @@
+use crate::domain::repository::entities::repository_task_tracker_rule::RepositoryTaskTrackerRule;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CreateRepositoryTaskTrackerRuleError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FindRepositoryTaskTrackerRulesError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteRepositoryTaskTrackerRuleError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Repository task tracker rule not found")]
+    NotFound,
+}
+
+#[async_trait]
+pub trait RepositoryTaskTrackerRuleRepository: Send + Sync {
+    async fn create(
+        &self,
+        rule: &RepositoryTaskTrackerRule,
+    ) -> Result<RepositoryTaskTrackerRule, CreateRepositoryTaskTrackerRuleError>;
+
+    async fn find_by_repository_id(
+        &self,
+        repository_id: RepositoryId,
+    ) -> Result<Vec<RepositoryTaskTrackerRule>, FindRepositoryTaskTrackerRulesError>;
+
+    async fn delete(&self, id: i32) -> Result<(), DeleteRepositoryTaskTrackerRuleError>;
+}
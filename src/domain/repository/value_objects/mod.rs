@@ -1,2 +1,4 @@
+pub mod pull_request_lifecycle_trigger;
 pub mod pull_request_status;
+pub mod ref_events_filter;
 pub mod repository_id;
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Событие жизненного цикла PR, по которому срабатывает правило перевода карточки
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum PullRequestLifecycleTrigger {
+    Opened,
+    /// PR выведен из драфта
+    ReadyForReview,
+    Approved,
+    Merged,
+    /// Задача упомянута в описании опубликованного релиза
+    Released,
+}
+
+impl PullRequestLifecycleTrigger {
+    pub const ALL: [PullRequestLifecycleTrigger; 5] = [
+        PullRequestLifecycleTrigger::Opened,
+        PullRequestLifecycleTrigger::ReadyForReview,
+        PullRequestLifecycleTrigger::Approved,
+        PullRequestLifecycleTrigger::Merged,
+        PullRequestLifecycleTrigger::Released,
+    ];
+}
+
+impl fmt::Display for PullRequestLifecycleTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            PullRequestLifecycleTrigger::Opened => "opened",
+            PullRequestLifecycleTrigger::ReadyForReview => "ready_for_review",
+            PullRequestLifecycleTrigger::Approved => "approved",
+            PullRequestLifecycleTrigger::Merged => "merged",
+            PullRequestLifecycleTrigger::Released => "released",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for PullRequestLifecycleTrigger {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "opened" => Ok(PullRequestLifecycleTrigger::Opened),
+            "ready_for_review" => Ok(PullRequestLifecycleTrigger::ReadyForReview),
+            "approved" => Ok(PullRequestLifecycleTrigger::Approved),
+            "merged" => Ok(PullRequestLifecycleTrigger::Merged),
+            "released" => Ok(PullRequestLifecycleTrigger::Released),
+            _ => Err(format!("Unknown pull request lifecycle trigger: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::domain::webhook::events::git_ref::WebhookRefType;
+use crate::utils::parsing::glob::glob_matches;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Какие события создания/удаления веток и тегов репозитория слать в чат.
+///
+/// Правила через запятую: `tag`, `branch`, `tag:v*`, `branch:release/*`.
+/// Событие проходит, если совпало хотя бы с одним правилом.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefEventsFilter {
+    rules: Vec<RefEventsFilterRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct RefEventsFilterRule {
+    ref_type: WebhookRefType,
+    pattern: Option<String>, // None — любое имя
+}
+
+impl RefEventsFilter {
+    pub fn matches(&self, ref_type: WebhookRefType, ref_name: &str) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.ref_type == ref_type
+                && rule
+                    .pattern
+                    .as_deref()
+                    .is_none_or(|pattern| glob_matches(pattern, ref_name))
+        })
+    }
+}
+
+impl fmt::Display for RefEventsFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<String> = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let ref_type = match rule.ref_type {
+                    WebhookRefType::Branch => "branch",
+                    WebhookRefType::Tag => "tag",
+                };
+                match &rule.pattern {
+                    Some(pattern) => format!("{}:{}", ref_type, pattern),
+                    None => ref_type.to_string(),
+                }
+            })
+            .collect();
+
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+impl FromStr for RefEventsFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = Vec::new();
+
+        for raw in s.split(',').map(str::trim).filter(|r| !r.is_empty()) {
+            let (kind, pattern) = match raw.split_once(':') {
+                Some((kind, pattern)) => (kind.trim(), Some(pattern.trim())),
+                None => (raw, None),
+            };
+
+            let ref_type = match kind.to_lowercase().as_str() {
+                "branch" => WebhookRefType::Branch,
+                "tag" => WebhookRefType::Tag,
+                _ => return Err(format!("Unknown ref type in rule: {}", raw)),
+            };
+
+            let pattern = match pattern {
+                Some("") => return Err(format!("Empty pattern in rule: {}", raw)),
+                Some(p) => Some(p.to_string()),
+                None => None,
+            };
+
+            rules.push(RefEventsFilterRule { ref_type, pattern });
+        }
+
+        if rules.is_empty() {
+            return Err("Filter has no rules".to_string());
+        }
+
+        Ok(Self { rules })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_rule_passes_any_ref_of_its_type() {
+        let filter: RefEventsFilter = "tag".parse().unwrap();
+
+        assert!(filter.matches(WebhookRefType::Tag, "v1.0"));
+        assert!(!filter.matches(WebhookRefType::Branch, "main"));
+    }
+
+    #[test]
+    fn patterned_rules_are_combined_with_or() {
+        let filter: RefEventsFilter = "tag:v*, branch:release/*".parse().unwrap();
+
+        assert!(filter.matches(WebhookRefType::Tag, "v2.1.0"));
+        assert!(filter.matches(WebhookRefType::Branch, "release/2.1"));
+        assert!(!filter.matches(WebhookRefType::Tag, "nightly"));
+        assert!(!filter.matches(WebhookRefType::Branch, "feature/login"));
+        assert!(!filter.matches(WebhookRefType::Branch, "v2.1.0"));
+    }
+
+    #[test]
+    fn parse_normalizes_spacing_and_type_case_and_round_trips() {
+        let filter: RefEventsFilter = " Branch : release/* ,tag ".parse().unwrap();
+
+        assert_eq!(filter.to_string(), "branch:release/*, tag");
+        assert_eq!(filter.to_string().parse::<RefEventsFilter>(), Ok(filter));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_rules() {
+        assert!("".parse::<RefEventsFilter>().is_err());
+        assert!(" , ".parse::<RefEventsFilter>().is_err());
+        assert!("commit".parse::<RefEventsFilter>().is_err());
+        assert!("tag:".parse::<RefEventsFilter>().is_err());
+    }
+}
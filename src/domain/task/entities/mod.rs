@@ -0,0 +1,3 @@
+pub mod pull_request_task_link;
+pub mod task_move;
+pub mod task_tracker_connection;
@@ -0,0 +1,18 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::value_objects::task_id::TaskId;
+use chrono::{DateTime, Utc};
+
+/// Задача трекера, упомянутая в PR (заголовок, описание или ветка)
+#[derive(Debug, Clone)]
+pub struct PullRequestTaskLink {
+    pub repository_id: RepositoryId,
+    pub task_id: TaskId,
+    pub pr_number: u64,
+    pub pr_title: String,
+    pub pr_url: Option<String>,
+    /// `None` — провайдер не сообщил автора (GitLab присылает только `author_id`)
+    pub author_login: Option<String>,
+    /// Последний известный head-коммит — по нему берётся статус CI
+    pub head_sha: Option<String>,
+    pub merged_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,29 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_move_trigger::TaskMoveTrigger;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Запись журнала автоматического перевода карточки. `error` заполнен, если трекер отказал
+#[derive(Debug, Clone)]
+pub struct TaskMove {
+    pub id: i32,
+    pub repository_id: RepositoryId,
+    /// `None` — перевод не по правилу: `qa_column_id` без правил, решение QA или новый issue
+    pub rule_id: Option<i32>,
+    pub trigger: TaskMoveTrigger,
+    pub task_id: TaskId,
+    pub column_id: String,
+    pub pr_number: Option<u64>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Откуда пришёл перевод: сохраняется в журнал вместе с результатом
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskMoveOrigin {
+    pub repository_id: RepositoryId,
+    pub rule_id: Option<i32>,
+    pub trigger: TaskMoveTrigger,
+    pub pr_number: Option<u64>,
+}
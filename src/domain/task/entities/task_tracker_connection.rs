@@ -0,0 +1,20 @@
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::utils::security::crypto::reversible::ReversibleCipherValue;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Подключение к трекеру, заведённое через админку, — вместо глобальных
+/// `KAITEN_*` / `JIRA_*` / ... из окружения
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTrackerConnection {
+    pub id: i32,
+    pub kind: TaskTrackerKind,
+    /// Название для админки, например «Kaiten — Acme»
+    pub name: String,
+    pub base_url: String,
+    /// Только для Jira Cloud (basic auth)
+    pub email: Option<String>,
+    pub api_token: ReversibleCipherValue,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -1 +1,4 @@
 pub mod task_tracker_client;
+pub mod task_tracker_client_factory;
+pub mod task_tracker_client_provider;
+pub mod task_tracker_client_resolver;
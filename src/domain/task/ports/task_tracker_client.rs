@@ -29,33 +29,62 @@ pub enum TaskTrackerClientListError {
     ClientError(String),
 }
 
+#[derive(Error, Debug)]
+pub enum TaskTrackerClientWriteError {
+    #[error("Card not found")]
+    NotFound,
+
+    #[error("Assignee not found in task tracker")]
+    AssigneeNotFound,
+
+    #[error("{0}")]
+    ClientError(String),
+}
+
 pub struct TaskTrackerCard {
     pub id: TaskId,
     pub title: String,
     pub url: String,
 }
 
+/// Id пространств, досок и колонок — строки: у Linear это UUID
 pub struct TaskTrackerSpace {
-    pub id: i32,
+    pub id: String,
     pub title: String,
 }
 
 pub struct TaskTrackerBoard {
-    pub id: i32,
+    pub id: String,
     pub title: String,
 }
 
 pub struct TaskTrackerColumn {
-    pub id: i32,
+    pub id: String,
     pub title: String,
 }
 
+/// Черновик новой карточки
+pub struct TaskTrackerCardDraft {
+    pub title: String,
+    pub description: String,
+}
+
+/// Кого назначить на карточку. Трекеры ищут пользователя по email,
+/// GitHub — по логину
+pub struct TaskTrackerAssignee {
+    pub login: String,
+    pub email: Option<String>,
+}
+
 #[async_trait]
 pub trait TaskTrackerClient: Send + Sync {
+    /// Адрес трекера, к которому дописывается `path_to_card`
+    fn base_url(&self) -> &str;
+
     async fn move_task_to_column(
         &self,
         task_id: TaskId,
-        column_id: u64,
+        column_id: &str,
     ) -> Result<(), TaskTrackerClientMoveToColumnError>;
 
     async fn get_card(
@@ -67,11 +96,49 @@ pub trait TaskTrackerClient: Send + Sync {
 
     async fn list_boards(
         &self,
-        space_id: i32,
+        space_id: &str,
     ) -> Result<Vec<TaskTrackerBoard>, TaskTrackerClientListError>;
 
     async fn list_columns(
         &self,
-        board_id: i32,
+        board_id: &str,
     ) -> Result<Vec<TaskTrackerColumn>, TaskTrackerClientListError>;
+
+    /// Карточки колонки `column_id` в пространстве `space_id`
+    async fn list_cards(
+        &self,
+        space_id: &str,
+        column_id: &str,
+    ) -> Result<Vec<TaskTrackerCard>, TaskTrackerClientListError>;
+
+    /// Создаёт карточку в пространстве. `column_id = None` — колонка по умолчанию трекера
+    async fn create_card(
+        &self,
+        space_id: &str,
+        column_id: Option<&str>,
+        draft: &TaskTrackerCardDraft,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientWriteError>;
+
+    async fn assign(
+        &self,
+        task_id: TaskId,
+        assignee: &TaskTrackerAssignee,
+    ) -> Result<(), TaskTrackerClientWriteError>;
+
+    async fn add_comment(
+        &self,
+        task_id: TaskId,
+        text: &str,
+    ) -> Result<(), TaskTrackerClientWriteError>;
+
+    /// Внешняя ссылка на карточке. Трекеры без ссылок оставляют её комментарием
+    async fn add_link(
+        &self,
+        task_id: TaskId,
+        url: &str,
+        title: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        self.add_comment(task_id, &format!("{}: {}", title, url))
+            .await
+    }
 }
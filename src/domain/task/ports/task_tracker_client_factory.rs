@@ -0,0 +1,16 @@
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use std::sync::Arc;
+
+/// Расшифрованные данные подключения
+pub struct TaskTrackerCredentials {
+    pub kind: TaskTrackerKind,
+    pub base_url: String,
+    pub email: Option<String>,
+    pub api_token: String,
+}
+
+/// Собирает HTTP-клиент трекера по данным подключения
+pub trait TaskTrackerClientFactory: Send + Sync {
+    fn build(&self, credentials: &TaskTrackerCredentials) -> Arc<dyn TaskTrackerClient>;
+}
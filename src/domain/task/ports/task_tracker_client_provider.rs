@@ -0,0 +1,12 @@
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use std::sync::Arc;
+
+/// Выбирает клиент по трекеру, настроенному для репозитория
+pub trait TaskTrackerClientProvider: Send + Sync {
+    /// `None` — трекер не настроен в окружении.
+    fn client(&self, kind: &TaskTrackerKind) -> Option<Arc<dyn TaskTrackerClient>>;
+
+    /// Трекеры, для которых есть клиент
+    fn kinds(&self) -> Vec<TaskTrackerKind>;
+}
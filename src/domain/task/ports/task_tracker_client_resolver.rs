@@ -0,0 +1,39 @@
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TaskTrackerClientResolveError {
+    #[error("Task tracker {0} is not configured")]
+    NotConfigured(TaskTrackerKind),
+
+    #[error("Task tracker connection {0} not found")]
+    ConnectionNotFound(i32),
+
+    #[error("{0}")]
+    ClientError(String),
+}
+
+/// Откуда брать клиент: подключение из БД или трекер из окружения (`connection_id = None`)
+#[derive(Debug, Clone)]
+pub struct TaskTrackerSource {
+    pub kind: TaskTrackerKind,
+    pub connection_id: Option<i32>,
+    /// Название подключения; у трекеров из окружения — `None`
+    pub name: Option<String>,
+}
+
+/// Выбирает клиент трекера для репозитория в момент выполнения задачи
+#[async_trait]
+pub trait TaskTrackerClientResolver: Send + Sync {
+    async fn resolve(
+        &self,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+    ) -> Result<Arc<dyn TaskTrackerClient>, TaskTrackerClientResolveError>;
+
+    /// Все доступные трекеры: сначала из окружения, затем подключения
+    async fn sources(&self) -> Result<Vec<TaskTrackerSource>, TaskTrackerClientResolveError>;
+}
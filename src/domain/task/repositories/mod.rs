@@ -0,0 +1,3 @@
+pub mod pull_request_task_link_repository;
+pub mod task_move_repository;
+pub mod task_tracker_connection_repository;
@@ -0,0 +1,32 @@
+use crate::domain::task::entities::pull_request_task_link::PullRequestTaskLink;
+use crate::domain::task::value_objects::task_id::TaskId;
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UpsertPullRequestTaskLinkError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FindPullRequestTaskLinksError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait]
+pub trait PullRequestTaskLinkRepository: Send + Sync {
+    /// Одна запись на (репозиторий, PR, задача); заголовок и автор обновляются.
+    /// `true` — связь создана этим вызовом
+    async fn upsert(
+        &self,
+        link: &PullRequestTaskLink,
+    ) -> Result<bool, UpsertPullRequestTaskLinkError>;
+
+    /// Все PR, в которых упомянута задача, свежие первыми
+    async fn find_by_task_id(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Vec<PullRequestTaskLink>, FindPullRequestTaskLinksError>;
+}
@@ -0,0 +1,35 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::entities::task_move::TaskMove;
+use crate::domain::task::value_objects::task_id::TaskId;
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CreateTaskMoveError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FindTaskMovesError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait]
+pub trait TaskMoveRepository: Send + Sync {
+    async fn create(&self, task_move: &TaskMove) -> Result<TaskMove, CreateTaskMoveError>;
+
+    /// Последние `limit` записей, новые первыми
+    async fn find_recent_by_repository_id(
+        &self,
+        repository_id: RepositoryId,
+        limit: u64,
+    ) -> Result<Vec<TaskMove>, FindTaskMovesError>;
+
+    /// Последний успешный перевод задачи ботом
+    async fn find_last_successful_by_task_id(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<TaskMove>, FindTaskMovesError>;
+}
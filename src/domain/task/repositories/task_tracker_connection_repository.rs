@@ -0,0 +1,47 @@
+use crate::domain::task::entities::task_tracker_connection::TaskTrackerConnection;
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CreateTaskTrackerConnectionError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum FindTaskTrackerConnectionError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Task tracker connection not found")]
+    NotFound,
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteTaskTrackerConnectionError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Task tracker connection not found")]
+    NotFound,
+
+    #[error("Task tracker connection is used by repositories")]
+    InUse,
+}
+
+#[async_trait]
+pub trait TaskTrackerConnectionRepository: Send + Sync {
+    async fn create(
+        &self,
+        connection: &TaskTrackerConnection,
+    ) -> Result<TaskTrackerConnection, CreateTaskTrackerConnectionError>;
+
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<TaskTrackerConnection, FindTaskTrackerConnectionError>;
+
+    async fn find_all(&self) -> Result<Vec<TaskTrackerConnection>, FindTaskTrackerConnectionError>;
+
+    async fn delete(&self, id: i32) -> Result<(), DeleteTaskTrackerConnectionError>;
+}
@@ -11,4 +11,8 @@ pub trait TaskTrackerService: Send + Sync {
 
     /// Возвращает все совпадения (matched_fragment, task_id) используя явно указанный паттерн.
     fn extract_all_matches_with_pattern(&self, text: &str, pattern: &str) -> Vec<(String, TaskId)>;
+
+    /// Имя ветки для задачи, из которого `pattern` снова извлечёт `task_id`.
+    /// `None` — подобрать такое имя по паттерну не получилось.
+    fn suggest_branch_name(&self, task_id: &TaskId, title: &str, pattern: &str) -> Option<String>;
 }
@@ -1 +1,4 @@
+pub mod task_card_annotation;
 pub mod task_id;
+pub mod task_move_trigger;
+pub mod task_tracker_kind;
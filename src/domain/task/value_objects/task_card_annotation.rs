@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Что бот оставляет на карточке задачи: внешнюю ссылку или комментарий
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TaskCardAnnotation {
+    Link { url: String, title: String },
+    Comment { text: String },
+}
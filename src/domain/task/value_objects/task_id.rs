@@ -1,4 +1,30 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
-pub struct TaskId(pub u64);
+/// Идентификатор задачи в трекере: числовой id карточки Kaiten
+/// или ключ задачи Jira вида `PROJ-123`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TaskId(pub String);
+
+impl fmt::Display for TaskId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// Задачи, поставленные в очередь до перехода на строковые id, хранят число
+impl<'de> Deserialize<'de> for TaskId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(u64),
+            Text(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Number(value) => TaskId(value.to_string()),
+            Raw::Text(value) => TaskId(value),
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::domain::repository::value_objects::pull_request_lifecycle_trigger::PullRequestLifecycleTrigger;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Почему карточку перевели: правило по событию PR, решение QA из `/test_queue`
+/// или новый GitHub issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TaskMoveTrigger {
+    PullRequest(PullRequestLifecycleTrigger),
+    /// QA принял задачу — колонка `qa_passed_column_id`
+    TestPassed,
+    /// QA вернул задачу в разработку — колонка `qa_failed_column_id`
+    TestFailed,
+    /// Новый issue положен на доску GitHub Projects — колонка `issue_column_id`
+    IssueOpened,
+}
+
+impl From<PullRequestLifecycleTrigger> for TaskMoveTrigger {
+    fn from(trigger: PullRequestLifecycleTrigger) -> Self {
+        TaskMoveTrigger::PullRequest(trigger)
+    }
+}
+
+impl fmt::Display for TaskMoveTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskMoveTrigger::PullRequest(trigger) => write!(f, "{}", trigger),
+            TaskMoveTrigger::TestPassed => write!(f, "test_passed"),
+            TaskMoveTrigger::TestFailed => write!(f, "test_failed"),
+            TaskMoveTrigger::IssueOpened => write!(f, "issue_opened"),
+        }
+    }
+}
+
+impl FromStr for TaskMoveTrigger {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "test_passed" => Ok(TaskMoveTrigger::TestPassed),
+            "test_failed" => Ok(TaskMoveTrigger::TestFailed),
+            "issue_opened" => Ok(TaskMoveTrigger::IssueOpened),
+            _ => PullRequestLifecycleTrigger::from_str(s).map(TaskMoveTrigger::PullRequest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_journal_string() {
+        let triggers = PullRequestLifecycleTrigger::ALL
+            .into_iter()
+            .map(TaskMoveTrigger::from)
+            .chain([
+                TaskMoveTrigger::TestPassed,
+                TaskMoveTrigger::TestFailed,
+                TaskMoveTrigger::IssueOpened,
+            ]);
+
+        for trigger in triggers {
+            assert_eq!(TaskMoveTrigger::from_str(&trigger.to_string()), Ok(trigger));
+        }
+    }
+
+    #[test]
+    fn keeps_pull_request_strings() {
+        assert_eq!(
+            TaskMoveTrigger::from_str("merged"),
+            Ok(TaskMoveTrigger::PullRequest(
+                PullRequestLifecycleTrigger::Merged
+            ))
+        );
+        assert!(TaskMoveTrigger::from_str("unknown").is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::domain::task::value_objects::task_id::TaskId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, Default)]
+pub enum TaskTrackerKind {
+    #[default]
+    Kaiten,
+    /// Jira Cloud и Jira Server/Data Center
+    Jira,
+    Linear,
+    YouTrack,
+    /// GitHub Issues с досками Projects v2
+    GithubProjects,
+}
+
+impl TaskTrackerKind {
+    pub const ALL: [TaskTrackerKind; 5] = [
+        TaskTrackerKind::Kaiten,
+        TaskTrackerKind::Jira,
+        TaskTrackerKind::Linear,
+        TaskTrackerKind::YouTrack,
+        TaskTrackerKind::GithubProjects,
+    ];
+
+    /// Kaiten знает только числовые id карточек, GitHub — номера `#123`
+    /// или `owner/repo#123`, остальные — ключи вида `MOB-123`
+    pub fn accepts(&self, task_id: &TaskId) -> bool {
+        match self {
+            TaskTrackerKind::Kaiten => {
+                !task_id.0.is_empty() && task_id.0.chars().all(|c| c.is_ascii_digit())
+            }
+            TaskTrackerKind::GithubProjects => {
+                let number = task_id.0.rsplit('#').next().unwrap_or_default();
+                !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+            }
+            TaskTrackerKind::Jira | TaskTrackerKind::Linear | TaskTrackerKind::YouTrack => true,
+        }
+    }
+
+    /// Номер issue без репозитория (`123`, `#123`), найденный в PR, у GitHub
+    /// относится к репозиторию этого PR, а не к репозиторию подключения
+    pub fn qualify_task_id(&self, task_id: TaskId, repository_full_name: &str) -> TaskId {
+        match self {
+            TaskTrackerKind::GithubProjects => {
+                let number = task_id.0.trim().trim_start_matches('#');
+
+                if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+                    TaskId(format!("{}#{}", repository_full_name, number))
+                } else {
+                    task_id
+                }
+            }
+            _ => task_id,
+        }
+    }
+}
+
+impl fmt::Display for TaskTrackerKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TaskTrackerKind::Kaiten => "kaiten",
+            TaskTrackerKind::Jira => "jira",
+            TaskTrackerKind::Linear => "linear",
+            TaskTrackerKind::YouTrack => "youtrack",
+            TaskTrackerKind::GithubProjects => "github_projects",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for TaskTrackerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "kaiten" => Ok(TaskTrackerKind::Kaiten),
+            "jira" => Ok(TaskTrackerKind::Jira),
+            "linear" => Ok(TaskTrackerKind::Linear),
+            "youtrack" => Ok(TaskTrackerKind::YouTrack),
+            "github_projects" => Ok(TaskTrackerKind::GithubProjects),
+            _ => Err(format!("Unknown task tracker kind: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualify_task_id_binds_bare_github_number_to_pull_request_repository() {
+        let kind = TaskTrackerKind::GithubProjects;
+
+        assert_eq!(
+            kind.qualify_task_id(TaskId("#12".to_string()), "acme/api"),
+            TaskId("acme/api#12".to_string())
+        );
+        assert_eq!(
+            kind.qualify_task_id(TaskId("12".to_string()), "acme/api"),
+            TaskId("acme/api#12".to_string())
+        );
+        assert_eq!(
+            kind.qualify_task_id(TaskId("acme/app#12".to_string()), "acme/api"),
+            TaskId("acme/app#12".to_string())
+        );
+    }
+
+    #[test]
+    fn qualify_task_id_keeps_other_trackers_untouched() {
+        assert_eq!(
+            TaskTrackerKind::Kaiten.qualify_task_id(TaskId("12".to_string()), "acme/api"),
+            TaskId("12".to_string())
+        );
+        assert_eq!(
+            TaskTrackerKind::Jira.qualify_task_id(TaskId("MOB-12".to_string()), "acme/api"),
+            TaskId("MOB-12".to_string())
+        );
+    }
+}
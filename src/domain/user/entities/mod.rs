@@ -1,5 +1,8 @@
+pub mod pending_slack_account_link;
+pub mod pending_version_control_account_link;
 pub mod user;
 pub mod user_connection_repository;
 pub mod user_notification;
+pub mod user_slack_account;
 pub mod user_social_account;
 pub mod user_vc_account;
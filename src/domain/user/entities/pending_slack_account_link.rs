@@ -0,0 +1,17 @@
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use crate::domain::user::value_objects::user_id::UserId;
+use serde::{Deserialize, Serialize};
+
+/// Запрошенная привязка Slack: код ушёл в личку `slack_user_id` и ждёт подтверждения
+/// из Telegram. Кто прочитал код в Slack, тот и владелец аккаунта
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PendingSlackAccountLink {
+    pub slack_user_id: SlackChannelId,
+    pub code: String,
+}
+
+impl PendingSlackAccountLink {
+    pub fn cache_key(user_id: &UserId) -> String {
+        format!("slack_link_pending:user:{}", user_id.0)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::user_id::UserId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use serde::{Deserialize, Serialize};
+
+/// Привязка дополнительного аккаунта провайдера к уже зарегистрированному
+/// пользователю: лежит в кэше под OAuth `state`, пока провайдер не вернёт код
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PendingVersionControlAccountLink {
+    pub user_id: UserId,
+    pub version_control_type: VersionControlType,
+    pub social_chat_id: SocialChatId,
+}
+
+impl PendingVersionControlAccountLink {
+    pub fn cache_key(state: &str) -> String {
+        format!("vc_link_pending:state:{}", state)
+    }
+}
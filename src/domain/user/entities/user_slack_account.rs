@@ -0,0 +1,10 @@
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use crate::domain::user::value_objects::user_id::UserId;
+
+/// Подтверждённый Slack-аккаунт пользователя — сюда дублируются его личные уведомления
+#[derive(Debug, Clone)]
+pub struct UserSlackAccount {
+    pub user_id: UserId,
+    /// Member ID (`U…`) — бот пишет ему в личку
+    pub slack_user_id: SlackChannelId,
+}
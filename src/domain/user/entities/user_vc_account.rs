@@ -22,3 +22,11 @@ pub struct UserVersionControlAccount {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+impl UserVersionControlAccount {
+    /// Просроченным токеном не ходим в API — берём сервисные учётные данные
+    pub fn is_token_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now().timestamp())
+    }
+}
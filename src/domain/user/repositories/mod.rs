@@ -2,5 +2,6 @@ pub mod user_connection_repositories_repository;
 pub mod user_has_roles_repository;
 pub mod user_notifications_repository;
 pub mod user_repository;
+pub mod user_slack_accounts_repository;
 pub mod user_social_accounts_repository;
 pub mod user_vc_accounts_repository;
@@ -0,0 +1,37 @@
+use crate::domain::user::entities::user_slack_account::UserSlackAccount;
+use crate::domain::user::value_objects::user_id::UserId;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FindUserSlackAccountError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum UpsertUserSlackAccountError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteUserSlackAccountError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait::async_trait]
+pub trait UserSlackAccountsRepository: Send + Sync {
+    async fn find_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<UserSlackAccount>, FindUserSlackAccountError>;
+
+    /// Slack-аккаунт у пользователя один — новый заменяет прежний
+    async fn upsert(
+        &self,
+        account: &UserSlackAccount,
+    ) -> Result<UserSlackAccount, UpsertUserSlackAccountError>;
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), DeleteUserSlackAccountError>;
+}
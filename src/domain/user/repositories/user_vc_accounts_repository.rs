@@ -1,5 +1,6 @@
 use crate::domain::user::entities::user_vc_account::UserVersionControlAccount;
 use crate::domain::user::value_objects::user_id::UserId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use crate::domain::user::value_objects::version_control_user_id::VersionControlUserId;
 use sea_orm::DatabaseTransaction;
 use thiserror::Error;
@@ -10,6 +11,12 @@ pub enum CreateVersionControlServiceError {
     DbError(String),
 }
 
+#[derive(Debug, Error)]
+pub enum DeleteVersionControlServiceError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
 #[derive(Debug, Error)]
 pub enum FindVersionControlServiceByIdError {
     #[error("Database error: {0}")]
@@ -45,18 +52,31 @@ pub trait UserVersionControlAccountsRepository: Send + Sync {
         user: &UserVersionControlAccount,
     ) -> Result<UserVersionControlAccount, CreateVersionControlServiceError>;
 
+    /// Перед привязкой нового аккаунта того же провайдера
+    async fn delete_by_user_id(
+        &self,
+        txn: &DatabaseTransaction,
+        version_control_type: &VersionControlType,
+        id: &UserId,
+    ) -> Result<(), DeleteVersionControlServiceError>;
+
     async fn find_by_version_control_user_id(
         &self,
+        version_control_type: &VersionControlType,
         id: &VersionControlUserId,
     ) -> Result<UserVersionControlAccount, FindVersionControlServiceByIdError>;
 
+    /// У пользователя может быть по аккаунту на каждого провайдера
     async fn find_by_user_id(
         &self,
+        version_control_type: &VersionControlType,
         id: &UserId,
     ) -> Result<UserVersionControlAccount, FindVersionControlServiceByUserIdError>;
 
+    /// Логины уникальны только внутри провайдера
     async fn find_by_login(
         &self,
+        version_control_type: &VersionControlType,
         login: &str,
     ) -> Result<UserVersionControlAccount, FindVersionControlServiceByLoginError>;
 }
@@ -1,4 +1,6 @@
 pub mod notification_type;
+pub mod slack_channel_id;
+pub mod slack_message_ref;
 pub mod social_chat_id;
 pub mod social_message_id;
 pub mod social_type;
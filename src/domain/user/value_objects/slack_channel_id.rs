@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Адресат в Slack: канал (`C…`) или пользователь (`U…`) — бот пишет ему в личку
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SlackChannelId(pub String);
+
+impl SlackChannelId {
+    /// ID из Slack — заглавные латинские буквы и цифры, первая буква задаёт тип
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+
+        let valid = value.len() >= 2
+            && value.starts_with(['C', 'G', 'D', 'U', 'W'])
+            && value
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+
+        valid.then(|| Self(value.to_string()))
+    }
+
+    /// Пользователь (`U…`, `W…`), а не канал
+    pub fn is_user(&self) -> bool {
+        self.0.starts_with(['U', 'W'])
+    }
+}
@@ -0,0 +1,10 @@
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use serde::{Deserialize, Serialize};
+
+/// Отправленное в Slack сообщение. `channel_id` — диалог, куда оно реально
+/// попало: для личных сообщений это `D…`, а не ID пользователя
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SlackMessageRef {
+    pub channel_id: SlackChannelId,
+    pub ts: String,
+}
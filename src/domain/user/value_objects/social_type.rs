@@ -5,12 +5,14 @@ use std::str::FromStr;
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum SocialType {
     Telegram,
+    Slack,
 }
 
 impl fmt::Display for SocialType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
             SocialType::Telegram => "telegram",
+            SocialType::Slack => "slack",
         };
         write!(f, "{}", s)
     }
@@ -22,6 +24,7 @@ impl FromStr for SocialType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "telegram" => Ok(SocialType::Telegram),
+            "slack" => Ok(SocialType::Slack),
             _ => Err(format!("Unknown social type: {}", s)),
         }
     }
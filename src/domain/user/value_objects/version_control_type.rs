@@ -5,12 +5,18 @@ use std::str::FromStr;
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum VersionControlType {
     Github,
+    /// Gitea и Forgejo — API и вебхуки совместимы
+    Gitea,
+    /// GitLab (в том числе self-hosted) — `owner` может содержать подгруппы
+    Gitlab,
 }
 
 impl fmt::Display for VersionControlType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
             VersionControlType::Github => "github",
+            VersionControlType::Gitea => "gitea",
+            VersionControlType::Gitlab => "gitlab",
         };
         write!(f, "{}", s)
     }
@@ -22,7 +28,30 @@ impl FromStr for VersionControlType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "github" => Ok(VersionControlType::Github),
+            "gitea" | "forgejo" => Ok(VersionControlType::Gitea),
+            "gitlab" => Ok(VersionControlType::Gitlab),
             _ => Err(format!("Unknown version control type: {}", s)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_value_round_trips_for_every_provider() {
+        for version_control_type in [
+            VersionControlType::Github,
+            VersionControlType::Gitea,
+            VersionControlType::Gitlab,
+        ] {
+            assert_eq!(
+                version_control_type
+                    .to_string()
+                    .parse::<VersionControlType>(),
+                Ok(version_control_type)
+            );
+        }
+    }
+}
@@ -1 +1,3 @@
 pub mod version_control_client;
+pub mod version_control_client_provider;
+pub mod version_control_token_provider;
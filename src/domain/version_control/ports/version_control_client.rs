@@ -25,6 +25,95 @@ pub struct UserPullRequestSummary {
     pub created_at: DateTime<Utc>,
 }
 
+/// Коммит из сравнения двух ref
+#[derive(Debug, Clone)]
+pub struct ComparedCommit {
+    pub message: String,
+    pub author_login: Option<String>,
+}
+
+/// Вебхук, настроенный в репозитории
+#[derive(Debug, Clone)]
+pub struct RepositoryHook {
+    pub id: u64,
+    pub url: String,
+    pub events: Vec<String>,
+    pub active: bool,
+}
+
+/// Параметры создания/обновления вебхука; секрет провайдер назад не отдаёт
+#[derive(Debug, Clone)]
+pub struct RepositoryHookSettings {
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+}
+
+/// Попытка доставки вебхука, как её видит провайдер
+#[derive(Debug, Clone)]
+pub struct RepositoryHookDelivery {
+    pub id: u64,
+    /// Совпадает с `X-GitHub-Delivery`, общий для всех повторов
+    pub guid: String,
+    pub event: String,
+    pub status_code: u16,
+    pub delivered_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RepositoryHookDeliveryPayload {
+    pub guid: String,
+    pub event: String,
+    pub payload: String,
+}
+
+/// Итог проверки, которую бот выставляет коммиту сам
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitStatusState {
+    Success,
+    Failure,
+}
+
+impl CommitStatusState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitStatusState::Success => "success",
+            CommitStatusState::Failure => "failure",
+        }
+    }
+}
+
+/// Статус коммита; провайдер показывает его в PR рядом с проверками CI
+#[derive(Debug, Clone)]
+pub struct CommitStatus {
+    pub state: CommitStatusState,
+    /// Имя проверки, например `task-id/required`
+    pub context: String,
+    pub description: String,
+    pub target_url: Option<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum VersionControlClientCommitStatusError {
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Transport error: {0}")]
+    Transport(String),
+}
+
+#[derive(Debug, Error)]
+pub enum VersionControlClientRepositoryHookError {
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Not found")]
+    NotFound,
+
+    #[error("Transport error: {0}")]
+    Transport(String),
+}
+
 #[derive(Debug, Error)]
 pub enum VersionControlClientSearchPrsError {
     #[error("Unauthorized: {0}")]
@@ -110,6 +199,30 @@ pub enum VersionControlClientOrgMembershipError {
     Transport(String),
 }
 
+#[derive(Debug, Error)]
+pub enum VersionControlClientGetRepositoryError {
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Not found")]
+    NotFound,
+
+    #[error("Transport error: {0}")]
+    Transport(String),
+}
+
+#[derive(Debug, Error)]
+pub enum VersionControlClientCompareError {
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Not found")]
+    NotFound,
+
+    #[error("Transport error: {0}")]
+    Transport(String),
+}
+
 #[async_trait]
 pub trait VersionControlClient: Send + Sync {
     async fn get_user(
@@ -178,4 +291,82 @@ pub trait VersionControlClient: Send + Sync {
         access_token: &str,
         org: &str,
     ) -> Result<bool, VersionControlClientOrgMembershipError>;
+
+    async fn get_default_branch(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<String, VersionControlClientGetRepositoryError>;
+
+    /// Коммиты, которые есть в `head`, но нет в `base`
+    async fn compare_commits(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ComparedCommit>, VersionControlClientCompareError>;
+
+    async fn list_repository_hooks(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<RepositoryHook>, VersionControlClientRepositoryHookError>;
+
+    async fn create_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError>;
+
+    async fn update_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError>;
+
+    async fn delete_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+    ) -> Result<(), VersionControlClientRepositoryHookError>;
+
+    /// Последние попытки доставки, от новых к старым, не старше `since`
+    async fn list_repository_hook_deliveries(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RepositoryHookDelivery>, VersionControlClientRepositoryHookError>;
+
+    async fn get_repository_hook_delivery(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        delivery_id: u64,
+    ) -> Result<RepositoryHookDeliveryPayload, VersionControlClientRepositoryHookError>;
+
+    /// Выставляет статус коммиту; статус с тем же `context` перезаписывается
+    async fn create_commit_status(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &CommitStatus,
+    ) -> Result<(), VersionControlClientCommitStatusError>;
 }
@@ -0,0 +1,12 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client::VersionControlClient;
+use std::sync::Arc;
+
+/// Выбирает клиент по провайдеру репозитория
+pub trait VersionControlClientProvider: Send + Sync {
+    fn client(&self, version_control_type: &VersionControlType) -> Arc<dyn VersionControlClient>;
+
+    /// Сервисный токен провайдера для фоновых задач.
+    /// `None` — используем токен администратора/пользователя.
+    fn service_token(&self, version_control_type: &VersionControlType) -> Option<String>;
+}
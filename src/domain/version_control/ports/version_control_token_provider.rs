@@ -0,0 +1,14 @@
+use crate::domain::repository::entities::repository::Repository;
+use async_trait::async_trait;
+
+/// Токены для обращений к провайдеру не от имени конкретного пользователя
+#[async_trait]
+pub trait VersionControlTokenProvider: Send + Sync {
+    /// Сервисные учётные данные: токен бота Gitea или токен установки GitHub App.
+    /// `None` — сервисных учётных данных для репозитория нет.
+    async fn service_token(&self, repository: &Repository) -> Option<String>;
+
+    /// Токен для фоновых задач: сервисный, а без него — OAuth-токен администратора
+    /// с аккаунтом того же провайдера. `None` — вызывающий пропускает репозиторий
+    async fn background_token(&self, repository: &Repository) -> Option<String>;
+}
@@ -0,0 +1,86 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+/// Отдельная проверка на коммите: job из Actions, SonarQube, CodeQL и т.п.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookCheckRunEvent {
+    pub id: u64,
+    pub name: String,
+    pub app: Option<String>, // приложение, создавшее проверку
+    pub head_sha: String,
+    pub head_branch: Option<String>,
+    pub status: String,             // queued, in_progress, completed
+    pub conclusion: Option<String>, // success, failure, neutral, skipped, cancelled, timed_out, action_required
+    pub html_url: Option<String>,
+    pub check_suite_id: Option<u64>,
+    pub pr_numbers: Vec<u64>,
+    pub sender: Option<String>, // инициатор, если это не бот
+
+    pub repo: String,
+    pub repo_url: Option<String>,
+}
+
+impl WebhookEvent for WebhookCheckRunEvent {
+    fn build_text(&self) -> String {
+        let title = match self.conclusion.as_deref() {
+            Some("success") => "✅ Проверка пройдена",
+            Some("failure") | Some("timed_out") => "❌ Проверка не пройдена",
+            Some("cancelled") => "🚫 Проверка отменена",
+            Some(_) => "⚪ Проверка завершена",
+            None => "🔄 Проверка выполняется",
+        };
+
+        let short_sha = &self.head_sha[..7.min(self.head_sha.len())];
+
+        let mut builder = MessageBuilder::new()
+            .bold(title)
+            .empty_line()
+            .section_bold("🔍 Проверка", &MessageBuilder::escape_html(&self.name));
+
+        if let Some(app) = &self.app {
+            builder = builder.section("🧩 Приложение", &MessageBuilder::escape_html(app));
+        }
+
+        if let Some(branch) = &self.head_branch {
+            builder = builder.section_code("🌿 Ветка", &MessageBuilder::escape_html(branch));
+        }
+
+        builder = builder
+            .section_code("🔐 Коммит", short_sha)
+            .section("📦 Репозиторий", &MessageBuilder::escape_html(&self.repo));
+
+        if let Some(url) = &self.html_url {
+            let trimmed = url.trim();
+            if trimmed.starts_with("http://") || trimmed.starts_with("https://") {
+                builder = builder.section(
+                    "🔗 Проверка",
+                    &format!(
+                        "<a href=\"{}\">Перейти →</a>",
+                        MessageBuilder::escape_html(trimmed)
+                    ),
+                );
+            }
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookCheckRunEvent {
+    const EVENT_NAME: &'static str = "webhook.check_run";
+}
+
+impl MessageBrokerMessage for WebhookCheckRunEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
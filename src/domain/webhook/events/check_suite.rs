@@ -0,0 +1,66 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+/// Набор проверок одного приложения на коммите
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookCheckSuiteEvent {
+    pub id: u64,
+    pub app: Option<String>,
+    pub head_sha: String,
+    pub head_branch: Option<String>,
+    pub status: String,             // requested, queued, in_progress, completed
+    pub conclusion: Option<String>, // success, failure, neutral, cancelled, timed_out, action_required, stale
+    pub pr_numbers: Vec<u64>,
+    pub sender: Option<String>, // инициатор, если это не бот
+
+    pub repo: String,
+    pub repo_url: Option<String>,
+}
+
+impl WebhookEvent for WebhookCheckSuiteEvent {
+    fn build_text(&self) -> String {
+        let title = match self.conclusion.as_deref() {
+            Some("success") => "✅ Набор проверок пройден",
+            Some("failure") | Some("timed_out") => "❌ Набор проверок не пройден",
+            Some("cancelled") => "🚫 Набор проверок отменён",
+            Some(_) => "⚪ Набор проверок завершён",
+            None => "🔄 Набор проверок выполняется",
+        };
+
+        let short_sha = &self.head_sha[..7.min(self.head_sha.len())];
+
+        let mut builder = MessageBuilder::new().bold(title).empty_line();
+
+        if let Some(app) = &self.app {
+            builder = builder.section_bold("🧩 Приложение", &MessageBuilder::escape_html(app));
+        }
+
+        if let Some(branch) = &self.head_branch {
+            builder = builder.section_code("🌿 Ветка", &MessageBuilder::escape_html(branch));
+        }
+
+        builder
+            .section_code("🔐 Коммит", short_sha)
+            .section("📦 Репозиторий", &MessageBuilder::escape_html(&self.repo))
+            .build()
+    }
+}
+
+impl DomainEvent for WebhookCheckSuiteEvent {
+    const EVENT_NAME: &'static str = "webhook.check_suite";
+}
+
+impl MessageBrokerMessage for WebhookCheckSuiteEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
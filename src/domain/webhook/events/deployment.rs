@@ -0,0 +1,66 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+/// Создан деплой в окружение (GitHub Environments)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookDeploymentEvent {
+    pub id: u64,
+    pub environment: String,
+    pub sha: String,
+    pub ref_name: Option<String>, // ветка, тег или SHA
+    pub description: Option<String>,
+    pub creator: Option<String>, // кто запустил деплой
+
+    pub repo: String,
+    pub repo_url: Option<String>,
+}
+
+impl WebhookEvent for WebhookDeploymentEvent {
+    fn build_text(&self) -> String {
+        let short_sha = &self.sha[..7.min(self.sha.len())];
+
+        let mut builder = MessageBuilder::new()
+            .bold("🚀 Деплой запущен")
+            .empty_line()
+            .section_bold(
+                "🌐 Окружение",
+                &MessageBuilder::escape_html(&self.environment),
+            )
+            .section("📦 Репозиторий", &MessageBuilder::escape_html(&self.repo));
+
+        if let Some(ref_name) = &self.ref_name {
+            builder = builder.section_code("🌿 Ref", &MessageBuilder::escape_html(ref_name));
+        }
+
+        builder = builder.section_code("🔐 Коммит", short_sha);
+
+        if let Some(creator) = &self.creator {
+            builder = builder.section("👤 Запустил", &MessageBuilder::escape_html(creator));
+        }
+
+        if let Some(description) = self.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            builder = builder.section("📝 Описание", &MessageBuilder::escape_html(description));
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookDeploymentEvent {
+    const EVENT_NAME: &'static str = "webhook.deployment";
+}
+
+impl MessageBrokerMessage for WebhookDeploymentEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
@@ -0,0 +1,113 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Смена статуса деплоя
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookDeploymentStatusEvent {
+    /// Провайдер, приславший событие
+    #[serde(default)]
+    pub provider: WebhookProvider,
+    pub deployment_id: u64,
+    pub state: String, // queued, pending, in_progress, success, failure, error, inactive
+    pub environment: String,
+    pub sha: String,
+    pub ref_name: Option<String>,
+    pub description: Option<String>,
+    pub creator: Option<String>,         // кто запустил деплой
+    pub log_url: Option<String>,         // ссылка на прогон
+    pub environment_url: Option<String>, // адрес развёрнутого окружения
+    pub created_at: DateTime<Utc>,
+
+    pub repo: String,
+    pub repo_url: Option<String>,
+}
+
+impl WebhookDeploymentStatusEvent {
+    pub fn is_success(&self) -> bool {
+        self.state == "success"
+    }
+
+    /// Финальные статусы — о промежуточных в чат не пишем
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.state.as_str(), "success" | "failure" | "error")
+    }
+}
+
+impl WebhookEvent for WebhookDeploymentStatusEvent {
+    fn build_text(&self) -> String {
+        let title = match self.state.as_str() {
+            "success" => "✅ Деплой завершён",
+            "failure" | "error" => "❌ Деплой упал",
+            "in_progress" => "🔄 Деплой выполняется",
+            "queued" | "pending" => "⏳ Деплой в очереди",
+            "inactive" => "💤 Деплой неактивен",
+            _ => "🚀 Деплой",
+        };
+
+        let short_sha = &self.sha[..7.min(self.sha.len())];
+
+        let mut builder = MessageBuilder::new()
+            .bold(title)
+            .empty_line()
+            .section_bold(
+                "🌐 Окружение",
+                &MessageBuilder::escape_html(&self.environment),
+            )
+            .section("📦 Репозиторий", &MessageBuilder::escape_html(&self.repo));
+
+        if let Some(ref_name) = &self.ref_name {
+            builder = builder.section_code("🌿 Ref", &MessageBuilder::escape_html(ref_name));
+        }
+
+        builder = builder.section_code("🔐 Коммит", short_sha);
+
+        if let Some(creator) = &self.creator {
+            builder = builder.section("👤 Запустил", &MessageBuilder::escape_html(creator));
+        }
+
+        if let Some(description) = self.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            builder = builder.section("📝 Описание", &MessageBuilder::escape_html(description));
+        }
+
+        for (label, url) in [
+            ("🔗 Окружение", &self.environment_url),
+            ("📜 Лог", &self.log_url),
+        ] {
+            if let Some(url) = url {
+                let trimmed = url.trim();
+                if trimmed.starts_with("http://") || trimmed.starts_with("https://") {
+                    builder = builder.section(
+                        label,
+                        &format!(
+                            "<a href=\"{}\">Перейти →</a>",
+                            MessageBuilder::escape_html(trimmed)
+                        ),
+                    );
+                }
+            }
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookDeploymentStatusEvent {
+    const EVENT_NAME: &'static str = "webhook.deployment_status";
+}
+
+impl MessageBrokerMessage for WebhookDeploymentStatusEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
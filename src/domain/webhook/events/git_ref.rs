@@ -0,0 +1,89 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookRefType {
+    Branch,
+    Tag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookRefAction {
+    Created,
+    Deleted,
+}
+
+/// Создание / удаление ветки или тега (события `create` / `delete`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookRefEvent {
+    /// Провайдер, приславший событие
+    #[serde(default)]
+    pub provider: WebhookProvider,
+    pub action: WebhookRefAction,
+    pub ref_type: WebhookRefType,
+    pub ref_name: String, // короткое имя без refs/heads/ и refs/tags/
+    pub sender: String,
+
+    pub repo: String,
+    pub repo_url: Option<String>,
+}
+
+impl WebhookEvent for WebhookRefEvent {
+    fn build_text(&self) -> String {
+        let (title, label) = match (self.ref_type, self.action) {
+            (WebhookRefType::Branch, WebhookRefAction::Created) => ("🌱 Ветка создана", "🌿 Ветка"),
+            (WebhookRefType::Branch, WebhookRefAction::Deleted) => ("🗑️ Ветка удалена", "🌿 Ветка"),
+            (WebhookRefType::Tag, WebhookRefAction::Created) => ("🏷️ Тег создан", "🏷️ Тег"),
+            (WebhookRefType::Tag, WebhookRefAction::Deleted) => ("🗑️ Тег удалён", "🏷️ Тег"),
+        };
+
+        let safe_repo = MessageBuilder::escape_html(&self.repo);
+
+        let mut builder = MessageBuilder::new()
+            .bold(title)
+            .empty_line()
+            .section_bold("👤 Автор", &MessageBuilder::escape_html(&self.sender));
+
+        match &self.repo_url {
+            Some(url)
+                if url.trim().starts_with("http://") || url.trim().starts_with("https://") =>
+            {
+                builder = builder.section(
+                    "📦 Репозиторий",
+                    &format!(
+                        "<a href=\"{}\">{}</a>",
+                        MessageBuilder::escape_html(url.trim()),
+                        safe_repo
+                    ),
+                )
+            }
+            _ => builder = builder.section("📦 Репозиторий", &safe_repo),
+        }
+
+        builder
+            .section_code(label, &MessageBuilder::escape_html(&self.ref_name))
+            .build()
+    }
+}
+
+impl DomainEvent for WebhookRefEvent {
+    const EVENT_NAME: &'static str = "webhook.ref";
+}
+
+impl MessageBrokerMessage for WebhookRefEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
@@ -0,0 +1,94 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+/// Сколько репозиториев перечислять в карточке
+const MAX_LISTED_REPOSITORIES: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookInstallationAction {
+    Created,
+    Deleted,
+    Suspended,
+    Unsuspended,
+    RepositoriesAdded,
+    RepositoriesRemoved,
+}
+
+/// Установка GitHub App и изменение её набора репозиториев
+/// (события `installation` и `installation_repositories`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookInstallationEvent {
+    pub action: WebhookInstallationAction,
+    pub installation_id: u64,
+    pub account: String, // организация или пользователь, куда установлено приложение
+    pub repositories_added: Vec<String>, // owner/name
+    pub repositories_removed: Vec<String>,
+    pub sender: Option<String>,
+}
+
+impl WebhookInstallationEvent {
+    fn push_repositories(builder: MessageBuilder, title: &str, repos: &[String]) -> MessageBuilder {
+        if repos.is_empty() {
+            return builder;
+        }
+
+        let mut listed: Vec<String> = repos
+            .iter()
+            .take(MAX_LISTED_REPOSITORIES)
+            .map(|r| MessageBuilder::escape_html(r))
+            .collect();
+
+        if repos.len() > MAX_LISTED_REPOSITORIES {
+            listed.push(format!("… +{}", repos.len() - MAX_LISTED_REPOSITORIES));
+        }
+
+        builder.section(title, &listed.join(", "))
+    }
+}
+
+impl WebhookEvent for WebhookInstallationEvent {
+    fn build_text(&self) -> String {
+        let title = match self.action {
+            WebhookInstallationAction::Created => "🔌 GitHub App установлен",
+            WebhookInstallationAction::Deleted => "🔌 GitHub App удалён",
+            WebhookInstallationAction::Suspended => "⏸️ GitHub App приостановлен",
+            WebhookInstallationAction::Unsuspended => "▶️ GitHub App возобновлён",
+            WebhookInstallationAction::RepositoriesAdded => "➕ GitHub App: добавлены репозитории",
+            WebhookInstallationAction::RepositoriesRemoved => "➖ GitHub App: убраны репозитории",
+        };
+
+        let mut builder = MessageBuilder::new()
+            .bold(title)
+            .empty_line()
+            .section("🏢 Аккаунт", &MessageBuilder::escape_html(&self.account));
+
+        builder = Self::push_repositories(builder, "📦 Добавлены", &self.repositories_added);
+        builder = Self::push_repositories(builder, "📦 Убраны", &self.repositories_removed);
+
+        if let Some(sender) = &self.sender {
+            builder = builder.section("👤 Кто изменил", &MessageBuilder::escape_html(sender));
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookInstallationEvent {
+    const EVENT_NAME: &'static str = "webhook.installation";
+}
+
+impl MessageBrokerMessage for WebhookInstallationEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
@@ -0,0 +1,194 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumString};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, EnumString, AsRefStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookIssueEventActionType {
+    Opened,     // issue создан
+    Closed,     // issue закрыт (completed / not_planned — смотри state_reason)
+    Reopened,   // issue переоткрыт
+    Assigned,   // назначили assignee
+    Labeled,    // добавили лейбл
+    Milestoned, // добавили в milestone
+
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookIssueEvent {
+    pub source: String, // кто вызвал событие
+    pub author: String, // автор issue
+    pub repo: String,
+    pub repo_url: Option<String>,
+    pub number: u64,
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    pub action: WebhookIssueEventActionType,
+    pub state: String, // open / closed
+    #[serde(default)]
+    pub state_reason: Option<String>, // completed / not_planned / reopened
+    pub issue_url: Option<String>,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    #[serde(default)]
+    pub assignee: Option<String>, // login назначенного (только для action=Assigned)
+    #[serde(default)]
+    pub label: Option<String>, // добавленный лейбл (только для action=Labeled)
+    #[serde(default)]
+    pub milestone: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl WebhookEvent for WebhookIssueEvent {
+    fn build_text(&self) -> String {
+        let title = match self.action {
+            WebhookIssueEventActionType::Opened => "🐞 Открыт issue",
+            WebhookIssueEventActionType::Closed
+                if self.state_reason.as_deref() == Some("not_planned") =>
+            {
+                "🚫 Issue закрыт как неактуальный"
+            }
+            WebhookIssueEventActionType::Closed => "✅ Issue закрыт",
+            WebhookIssueEventActionType::Reopened => "🔁 Issue переоткрыт",
+            WebhookIssueEventActionType::Assigned => "👤 Назначен исполнитель issue",
+            WebhookIssueEventActionType::Labeled => "🏷️ Issue помечен лейблом",
+            WebhookIssueEventActionType::Milestoned => "🎯 Issue добавлен в milestone",
+            WebhookIssueEventActionType::Unknown => "🐞 Issue",
+        };
+
+        let state_label = if self.state == "closed" {
+            "✅ Закрыт"
+        } else {
+            "🟢 Открыт"
+        };
+
+        let join_escaped = |items: &[String]| {
+            if items.is_empty() {
+                "—".to_string()
+            } else {
+                items
+                    .iter()
+                    .map(|i| MessageBuilder::escape_html(i))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+
+        let safe_repo = MessageBuilder::escape_html(&self.repo);
+
+        // ── Заголовок ──────────────────────────────────────
+        let mut builder = MessageBuilder::new()
+            .bold(&format!("{} #{}", title, self.number))
+            .empty_line();
+
+        // ── Issue инфо ─────────────────────────────────────
+        builder = builder
+            .section_bold("👤 Автор", &MessageBuilder::escape_html(&self.author))
+            .section("📝 Заголовок", &MessageBuilder::escape_html(&self.title))
+            .empty_line();
+
+        // ── Что изменилось ─────────────────────────────────
+        match self.action {
+            WebhookIssueEventActionType::Assigned => {
+                if let Some(assignee) = &self.assignee {
+                    builder = builder
+                        .section_bold("👤 Исполнитель", &MessageBuilder::escape_html(assignee));
+                }
+            }
+            WebhookIssueEventActionType::Labeled => {
+                if let Some(label) = &self.label {
+                    builder = builder.section_code("🏷️ Лейбл", &MessageBuilder::escape_html(label));
+                }
+            }
+            WebhookIssueEventActionType::Milestoned => {
+                if let Some(milestone) = &self.milestone {
+                    builder = builder
+                        .section_bold("🎯 Milestone", &MessageBuilder::escape_html(milestone));
+                }
+            }
+            _ => {}
+        }
+
+        // ── Статус ─────────────────────────────────────────
+        builder = builder
+            .section("📌 Состояние", state_label)
+            .section("👥 Назначены", &join_escaped(&self.assignees))
+            .section("🏷️ Лейблы", &join_escaped(&self.labels))
+            .section_bold("⚡️ Инициатор", &MessageBuilder::escape_html(&self.source))
+            .empty_line();
+
+        // ── Описание (только для новых issue) ─────────────
+        if self.action == WebhookIssueEventActionType::Opened
+            && let Some(body) = &self.body
+            && !body.trim().is_empty()
+        {
+            const MAX_CHARS: usize = 500;
+            let truncated = if body.chars().count() > MAX_CHARS {
+                let cut: String = body.chars().take(MAX_CHARS).collect();
+                format!("{}…", cut)
+            } else {
+                body.clone()
+            };
+
+            builder = builder
+                .bold("📋 Описание")
+                .line(&MessageBuilder::escape_html(&truncated))
+                .empty_line();
+        }
+
+        // ── Ссылки ─────────────────────────────────────────
+        if let Some(url) = &self.issue_url {
+            let trimmed = url.trim();
+            if trimmed.starts_with("http://") || trimmed.starts_with("https://") {
+                builder = builder.section(
+                    "🔗 Issue",
+                    &format!(
+                        "<a href=\"{}\">Перейти →</a>",
+                        MessageBuilder::escape_html(trimmed)
+                    ),
+                );
+            }
+        }
+
+        match &self.repo_url {
+            Some(url)
+                if url.trim().starts_with("http://") || url.trim().starts_with("https://") =>
+            {
+                builder = builder.section(
+                    "📦 Репозиторий",
+                    &format!(
+                        "<a href=\"{}\">{}</a>",
+                        MessageBuilder::escape_html(url.trim()),
+                        safe_repo
+                    ),
+                )
+            }
+            _ => builder = builder.section("📦 Репозиторий", &safe_repo),
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookIssueEvent {
+    const EVENT_NAME: &'static str = "webhook.issue";
+}
+
+impl MessageBrokerMessage for WebhookIssueEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
@@ -0,0 +1,88 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookMemberAction {
+    Added,
+    Removed,
+}
+
+/// Где изменилось членство: в самой организации или в её команде
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookMemberScope {
+    Organization,
+    Team,
+}
+
+/// Участника добавили в организацию / команду или удалили из неё
+/// (события `organization` и `membership`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookMemberEvent {
+    pub action: WebhookMemberAction,
+    pub scope: WebhookMemberScope,
+    pub organization: String,
+    pub team: Option<String>,
+
+    pub login: String,
+    pub user_id: u64, // id аккаунта у провайдера — login могли сменить
+    pub sender: Option<String>,
+}
+
+impl WebhookEvent for WebhookMemberEvent {
+    fn build_text(&self) -> String {
+        let title = match (self.scope, self.action) {
+            (WebhookMemberScope::Organization, WebhookMemberAction::Added) => {
+                "👋 Новый участник организации"
+            }
+            (WebhookMemberScope::Organization, WebhookMemberAction::Removed) => {
+                "🚪 Участник покинул организацию"
+            }
+            (WebhookMemberScope::Team, WebhookMemberAction::Added) => {
+                "👥 Участник добавлен в команду"
+            }
+            (WebhookMemberScope::Team, WebhookMemberAction::Removed) => {
+                "👥 Участник удалён из команды"
+            }
+        };
+
+        let mut builder = MessageBuilder::new()
+            .bold(title)
+            .empty_line()
+            .section_bold("👤 Участник", &MessageBuilder::escape_html(&self.login))
+            .section(
+                "🏢 Организация",
+                &MessageBuilder::escape_html(&self.organization),
+            );
+
+        if let Some(team) = &self.team {
+            builder = builder.section("👥 Команда", &MessageBuilder::escape_html(team));
+        }
+
+        if let Some(sender) = self.sender.as_deref().filter(|s| *s != self.login) {
+            builder = builder.section("✍️ Кто изменил", &MessageBuilder::escape_html(sender));
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookMemberEvent {
+    const EVENT_NAME: &'static str = "webhook.member";
+}
+
+impl MessageBrokerMessage for WebhookMemberEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
@@ -1,11 +1,21 @@
 use crate::domain::shared::events::event::StaticDomainEvent;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::MessageBrokerMessage;
 
+pub mod check_run;
+pub mod check_suite;
+pub mod deployment;
+pub mod deployment_status;
+pub mod git_ref;
+pub mod installation;
+pub mod issue;
+pub mod member;
 pub mod pr_comment;
 pub mod pull_request;
 pub mod pull_request_review;
 pub mod push;
 pub mod release;
+pub mod repository;
+pub mod task_card;
 pub mod workflow;
 
 pub trait WebhookEvent: StaticDomainEvent + MessageBrokerMessage + Send + Sync {
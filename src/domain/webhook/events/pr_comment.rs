@@ -1,5 +1,6 @@
 use crate::domain::shared::events::event::DomainEvent;
 use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::{
     MessageBrokerMessage, MessageBrokerMessageKind,
 };
@@ -9,10 +10,14 @@ use serde::{Deserialize, Serialize};
 /// A comment on a pull request (includes both review comments and issue comments on PRs).
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WebhookPrCommentEvent {
+    /// Провайдер, приславший событие
+    #[serde(default)]
+    pub provider: WebhookProvider,
     /// GitHub login of the person who left the comment.
     pub commenter: String,
     /// GitHub login of the PR author (notification recipient).
-    pub pr_author: String,
+    /// `None` when the provider does not report it (GitLab only sends `author_id`).
+    pub pr_author: Option<String>,
     /// Repository full name, e.g. "owner/repo".
     pub repo: String,
     /// PR number.
@@ -1,5 +1,6 @@
 use crate::domain::shared::events::event::DomainEvent;
 use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::{
     MessageBrokerMessage, MessageBrokerMessageKind,
 };
@@ -46,8 +47,11 @@ pub enum WebhookPullRequestEventActionType {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WebhookPullRequestEvent {
-    pub source: String, // кто вызвал событие (lykianovsky)
-    pub author: String, // автор PR (schwarz0ker)
+    /// Провайдер, приславший событие
+    #[serde(default)]
+    pub provider: WebhookProvider,
+    pub source: String,         // кто вызвал событие (lykianovsky)
+    pub author: Option<String>, // автор PR (schwarz0ker), GitLab сообщает его не всегда
     pub repo: String,
     pub repo_url: Option<String>,
     pub title: String, // заголовок PR
@@ -65,6 +69,8 @@ pub struct WebhookPullRequestEvent {
     pub base_repo: String,            // owner:branch назначения
     pub pr_url: Option<String>,       // ссылка на PR
     pub merge_commit: Option<String>, // хэш merge commit
+    #[serde(default)]
+    pub head_sha: Option<String>, // последний коммит ветки источника
     pub assignees: Vec<String>,       // назначенные
     pub created_at: String,
     pub updated_at: String,
@@ -81,6 +87,15 @@ pub struct WebhookPullRequestEvent {
     pub mergeable_state: Option<String>, // GitHub: clean / dirty / unknown / blocked / behind / draft
 }
 
+impl WebhookPullRequestEvent {
+    /// Неизвестный автор не совпадает ни с кем
+    pub fn is_author(&self, login: &str) -> bool {
+        self.author
+            .as_deref()
+            .is_some_and(|author| author.eq_ignore_ascii_case(login))
+    }
+}
+
 impl WebhookEvent for WebhookPullRequestEvent {
     fn build_text(&self) -> String {
         let title = match self.action.as_ref() {
@@ -110,7 +125,7 @@ impl WebhookEvent for WebhookPullRequestEvent {
                 .join(", ")
         };
 
-        let safe_author = MessageBuilder::escape_html(&self.author);
+        let safe_author = MessageBuilder::escape_html(self.author.as_deref().unwrap_or("—"));
         let safe_title = MessageBuilder::escape_html(&self.title);
         let safe_head_repo = MessageBuilder::escape_html(&self.head_repo);
         let safe_base_repo = MessageBuilder::escape_html(&self.base_repo);
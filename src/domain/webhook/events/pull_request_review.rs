@@ -1,5 +1,6 @@
 use crate::domain::shared::events::event::DomainEvent;
 use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::{
     MessageBrokerMessage, MessageBrokerMessageKind,
 };
@@ -18,6 +19,9 @@ pub enum WebhookPullRequestReviewState {
 /// Fired when a reviewer submits a pull-request review (approved / changes_requested / commented).
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WebhookPullRequestReviewEvent {
+    /// Провайдер, приславший событие
+    #[serde(default)]
+    pub provider: WebhookProvider,
     pub reviewer: String,  // GitHub login of the reviewer
     pub pr_author: String, // GitHub login of the PR author (notification recipient)
     pub repo: String,      // full repository name, e.g. "owner/repo"
@@ -30,6 +34,14 @@ pub struct WebhookPullRequestReviewEvent {
     pub review_comments: u64, // total review-comment count on the PR at submission time
     #[serde(default)]
     pub mergeable_state: Option<String>, // GitHub: clean / dirty / unknown / blocked / behind / draft
+    #[serde(default)]
+    pub head_sha: Option<String>, // head-коммит PR — по нему смотрим итог CI
+    #[serde(default)]
+    pub base_ref: Option<String>, // целевая ветка PR — для правил перевода карточек
+    #[serde(default)]
+    pub head_ref: Option<String>, // ветка источника — в ней тоже ищем id задачи
+    #[serde(default)]
+    pub pr_body: Option<String>, // тело PR — в нём тоже ищем id задачи
 }
 
 impl WebhookEvent for WebhookPullRequestReviewEvent {
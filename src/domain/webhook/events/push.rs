@@ -1,6 +1,7 @@
 use crate::domain::shared::events::event::DomainEvent;
 use crate::domain::webhook::entities::commit::WebhookCommit;
 use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::{
     MessageBrokerMessage, MessageBrokerMessageKind,
 };
@@ -9,6 +10,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WebhookPushEvent {
+    /// Провайдер, приславший событие
+    #[serde(default)]
+    pub provider: WebhookProvider,
     pub source: String,           // кто пушнул
     pub repo: String,             // полное имя репозитория
     pub repo_url: Option<String>, // ссылка на репозиторий
@@ -1,5 +1,6 @@
 use crate::domain::shared::events::event::DomainEvent;
 use crate::domain::webhook::events::WebhookEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::drivers::message_broker::contracts::publisher::{
     MessageBrokerMessage, MessageBrokerMessageKind,
 };
@@ -8,6 +9,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WebhookReleaseEvent {
+    /// Провайдер, приславший событие
+    #[serde(default)]
+    pub provider: WebhookProvider,
     pub id: u64,                      // уникальный ID релиза
     pub tag_name: String,             // тег релиза
     pub target_commitish: String,     // на какой ветке/коммите основан релиз
@@ -0,0 +1,86 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookRepositoryAction {
+    Created,
+    Deleted,
+    Archived,
+    Unarchived,
+    Renamed,
+    Transferred,
+}
+
+/// Изменение самого репозитория (событие `repository`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookRepositoryEvent {
+    pub action: WebhookRepositoryAction,
+    pub repo: String, // текущее полное имя owner/name
+    /// Полное имя до переименования / передачи
+    pub previous_repo: Option<String>,
+    pub repo_url: Option<String>,
+    pub sender: Option<String>,
+}
+
+impl WebhookEvent for WebhookRepositoryEvent {
+    fn build_text(&self) -> String {
+        let title = match self.action {
+            WebhookRepositoryAction::Created => "🆕 Репозиторий создан",
+            WebhookRepositoryAction::Deleted => "🗑️ Репозиторий удалён",
+            WebhookRepositoryAction::Archived => "📦 Репозиторий заархивирован",
+            WebhookRepositoryAction::Unarchived => "📤 Репозиторий разархивирован",
+            WebhookRepositoryAction::Renamed => "✏️ Репозиторий переименован",
+            WebhookRepositoryAction::Transferred => "🔁 Репозиторий передан",
+        };
+
+        let safe_repo = MessageBuilder::escape_html(&self.repo);
+
+        let mut builder = MessageBuilder::new().bold(title).empty_line();
+
+        match &self.repo_url {
+            Some(url)
+                if url.trim().starts_with("http://") || url.trim().starts_with("https://") =>
+            {
+                builder = builder.section(
+                    "📦 Репозиторий",
+                    &format!(
+                        "<a href=\"{}\">{}</a>",
+                        MessageBuilder::escape_html(url.trim()),
+                        safe_repo
+                    ),
+                )
+            }
+            _ => builder = builder.section("📦 Репозиторий", &safe_repo),
+        }
+
+        if let Some(previous) = &self.previous_repo {
+            builder = builder.section("↩️ Было", &MessageBuilder::escape_html(previous));
+        }
+
+        if let Some(sender) = &self.sender {
+            builder = builder.section("👤 Кто изменил", &MessageBuilder::escape_html(sender));
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookRepositoryEvent {
+    const EVENT_NAME: &'static str = "webhook.repository";
+}
+
+impl MessageBrokerMessage for WebhookRepositoryEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
@@ -0,0 +1,85 @@
+use crate::domain::shared::events::event::DomainEvent;
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::drivers::message_broker::contracts::publisher::{
+    MessageBrokerMessage, MessageBrokerMessageKind,
+};
+use crate::utils::builder::message::MessageBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookTaskCardAction {
+    Moved,
+    Commented,
+    MemberAdded,
+}
+
+/// Изменение карточки во внешнем трекере (входящий вебхук трекера)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookTaskCardEvent {
+    pub tracker: TaskTrackerKind,
+    pub connection_id: Option<i32>, // подключение из админки; None — трекер из окружения
+    pub action: WebhookTaskCardAction,
+    pub task_id: TaskId,
+    pub card_title: String,
+    pub column: Option<String>, // название колонки, если трекер его прислал, иначе id
+    pub column_id: Option<String>,
+    pub comment: Option<String>,
+    pub member: Option<String>, // кого добавили в карточку
+    pub actor: Option<String>,  // кто изменил карточку
+}
+
+impl WebhookEvent for WebhookTaskCardEvent {
+    fn build_text(&self) -> String {
+        let title = match self.action {
+            WebhookTaskCardAction::Moved => "🗂 Карточка перемещена",
+            WebhookTaskCardAction::Commented => "💬 Комментарий к карточке",
+            WebhookTaskCardAction::MemberAdded => "👥 Участник добавлен в карточку",
+        };
+
+        let mut builder = MessageBuilder::new().bold(title).empty_line().section_bold(
+            "📌 Карточка",
+            &format!(
+                "#{} — {}",
+                MessageBuilder::escape_html(&self.task_id.0),
+                MessageBuilder::escape_html(&self.card_title)
+            ),
+        );
+
+        if let Some(column) = &self.column {
+            builder = builder.section("🗂 Колонка", &MessageBuilder::escape_html(column));
+        }
+
+        if let Some(member) = &self.member {
+            builder = builder.section("👤 Участник", &MessageBuilder::escape_html(member));
+        }
+
+        if let Some(actor) = &self.actor {
+            builder = builder.section("✍️ Кто изменил", &MessageBuilder::escape_html(actor));
+        }
+
+        if let Some(comment) = &self.comment {
+            builder = builder
+                .empty_line()
+                .line(&MessageBuilder::escape_html(comment));
+        }
+
+        builder.build()
+    }
+}
+
+impl DomainEvent for WebhookTaskCardEvent {
+    const EVENT_NAME: &'static str = "webhook.task_card";
+}
+
+impl MessageBrokerMessage for WebhookTaskCardEvent {
+    fn name(&self) -> &'static str {
+        Self::EVENT_NAME
+    }
+
+    fn kind(&self) -> MessageBrokerMessageKind {
+        MessageBrokerMessageKind::Event
+    }
+}
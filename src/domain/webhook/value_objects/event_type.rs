@@ -11,6 +11,17 @@ pub enum WebhookEventType {
     Issues,
     Release,
     Workflow,
+    CheckRun,
+    CheckSuite,
+    Deployment,
+    DeploymentStatus,
+    Create,
+    Delete,
+    Organization,
+    Membership,
+    Repository,
+    Installation,
+    InstallationRepositories,
     Unknown(String),
 }
 
@@ -28,6 +39,17 @@ impl FromStr for WebhookEventType {
             "issues" => Ok(WebhookEventType::Issues),
             "release" => Ok(WebhookEventType::Release),
             "workflow_run" => Ok(WebhookEventType::Workflow),
+            "check_run" => Ok(WebhookEventType::CheckRun),
+            "check_suite" => Ok(WebhookEventType::CheckSuite),
+            "deployment" => Ok(WebhookEventType::Deployment),
+            "deployment_status" => Ok(WebhookEventType::DeploymentStatus),
+            "create" => Ok(WebhookEventType::Create),
+            "delete" => Ok(WebhookEventType::Delete),
+            "organization" => Ok(WebhookEventType::Organization),
+            "membership" => Ok(WebhookEventType::Membership),
+            "repository" => Ok(WebhookEventType::Repository),
+            "installation" => Ok(WebhookEventType::Installation),
+            "installation_repositories" => Ok(WebhookEventType::InstallationRepositories),
             other => Ok(WebhookEventType::Unknown(other.to_string())),
         }
     }
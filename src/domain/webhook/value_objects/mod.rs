@@ -1 +1,2 @@
 pub mod event_type;
+pub mod webhook_provider;
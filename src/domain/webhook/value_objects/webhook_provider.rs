@@ -0,0 +1,34 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use serde::{Deserialize, Serialize};
+
+/// Провайдер, приславший вебхук. Логины и `owner/name` из события имеют смысл
+/// только внутри него — сопоставлять их с аккаунтами и репозиториями другого
+/// провайдера нельзя.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookProvider {
+    #[default]
+    Github,
+    Gitea,
+    Gitlab,
+}
+
+impl WebhookProvider {
+    pub fn version_control_type(&self) -> VersionControlType {
+        match self {
+            WebhookProvider::Github => VersionControlType::Github,
+            WebhookProvider::Gitea => VersionControlType::Gitea,
+            WebhookProvider::Gitlab => VersionControlType::Gitlab,
+        }
+    }
+}
+
+impl From<&VersionControlType> for WebhookProvider {
+    fn from(version_control_type: &VersionControlType) -> Self {
+        match version_control_type {
+            VersionControlType::Github => WebhookProvider::Github,
+            VersionControlType::Gitea => WebhookProvider::Gitea,
+            VersionControlType::Gitlab => WebhookProvider::Gitlab,
+        }
+    }
+}
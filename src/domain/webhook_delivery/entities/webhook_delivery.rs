@@ -0,0 +1,23 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use chrono::{DateTime, Utc};
+
+/// Сырая входящая доставка вебхука — журнал для дедупликации и разбора
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub provider: VersionControlType,
+    /// X-GitHub-Delivery. Для отклонённых запросов не сохраняется: заголовок
+    /// неподписанного запроса может подставить кто угодно и занять им настоящую доставку
+    pub delivery_id: Option<String>,
+    pub event_type: String,
+    /// owner/name репозитория из payload, если удалось извлечь
+    pub repository_full_name: Option<String>,
+    pub payload: String,
+    pub signature_valid: bool,
+    pub status: WebhookDeliveryStatus,
+    pub error_message: Option<String>,
+    pub received_at: DateTime<Utc>,
+    pub processed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,86 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_cursor::WebhookDeliveryCursor;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CreateWebhookDeliveryError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Webhook delivery already exists")]
+    AlreadyExists,
+}
+
+#[derive(Debug, Error)]
+pub enum FindWebhookDeliveryError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Webhook delivery not found")]
+    NotFound,
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateWebhookDeliveryError {
+    #[error("Database error: {0}")]
+    DbError(String),
+
+    #[error("Webhook delivery not found")]
+    NotFound,
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteWebhookDeliveryError {
+    #[error("Database error: {0}")]
+    DbError(String),
+}
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    async fn create(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<WebhookDelivery, CreateWebhookDeliveryError>;
+
+    async fn find_by_id(
+        &self,
+        id: WebhookDeliveryId,
+    ) -> Result<WebhookDelivery, FindWebhookDeliveryError>;
+
+    async fn find_by_delivery_id(
+        &self,
+        provider: &VersionControlType,
+        delivery_id: &str,
+    ) -> Result<WebhookDelivery, FindWebhookDeliveryError>;
+
+    /// Последние доставки, новые сверху
+    async fn find_recent(
+        &self,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, FindWebhookDeliveryError>;
+
+    /// Доставки по репозиторию за окно `[from, to]` в порядке `(received_at, id)`;
+    /// с `after` — только идущие строго после курсора
+    async fn find_by_repository_between(
+        &self,
+        repository_full_name: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after: Option<WebhookDeliveryCursor>,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, FindWebhookDeliveryError>;
+
+    async fn update(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<WebhookDelivery, UpdateWebhookDeliveryError>;
+
+    /// Удаляет записи старше `before`, возвращает количество удалённых
+    async fn delete_received_before(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<u64, DeleteWebhookDeliveryError>;
+}
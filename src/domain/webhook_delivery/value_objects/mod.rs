@@ -0,0 +1,3 @@
+pub mod webhook_delivery_cursor;
+pub mod webhook_delivery_id;
+pub mod webhook_delivery_status;
@@ -0,0 +1,21 @@
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use chrono::{DateTime, Utc};
+
+/// Позиция в журнале при постраничном обходе. Доставки упорядочены по
+/// `(received_at, id)`: received_at хранится с точностью до секунды, и без id
+/// доставки одной секунды на границе страницы терялись бы или повторялись
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookDeliveryCursor {
+    pub received_at: DateTime<Utc>,
+    pub id: WebhookDeliveryId,
+}
+
+impl From<&WebhookDelivery> for WebhookDeliveryCursor {
+    fn from(delivery: &WebhookDelivery) -> Self {
+        Self {
+            received_at: delivery.received_at,
+            id: delivery.id,
+        }
+    }
+}
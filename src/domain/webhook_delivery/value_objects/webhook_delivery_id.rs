@@ -0,0 +1,2 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WebhookDeliveryId(pub i32);
@@ -0,0 +1,57 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    /// Принята, ещё не отправлена в брокер
+    Received,
+    Dispatched,
+    /// Событие не поддерживается — подтвердили без обработки
+    Skipped,
+    Failed,
+    /// Подпись не сошлась — запрос записан для разбора и не обрабатывался
+    Rejected,
+}
+
+impl WebhookDeliveryStatus {
+    /// Повторно не обрабатываем только доведённые до конца доставки.
+    /// `Received` сюда не входит: если процесс упал посреди обработки,
+    /// событие должно прийти заново.
+    pub fn blocks_redelivery(&self) -> bool {
+        matches!(
+            self,
+            WebhookDeliveryStatus::Dispatched | WebhookDeliveryStatus::Skipped
+        )
+    }
+
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Received => "received",
+            WebhookDeliveryStatus::Dispatched => "dispatched",
+            WebhookDeliveryStatus::Skipped => "skipped",
+            WebhookDeliveryStatus::Failed => "failed",
+            WebhookDeliveryStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+impl FromStr for WebhookDeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "received" => Ok(WebhookDeliveryStatus::Received),
+            "dispatched" => Ok(WebhookDeliveryStatus::Dispatched),
+            "skipped" => Ok(WebhookDeliveryStatus::Skipped),
+            "failed" => Ok(WebhookDeliveryStatus::Failed),
+            "rejected" => Ok(WebhookDeliveryStatus::Rejected),
+            _ => Err(format!("Unknown webhook delivery status: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::domain::webhook::events::WebhookEvent;
+use std::str::FromStr;
+
+pub trait GiteaEvent {
+    type WebhookEvent: WebhookEvent + Send + Sync + 'static;
+
+    fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized;
+
+    /// Причина, по которой событие не нужно превращать в доменное
+    fn skip_reason(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent;
+}
+
+/// События, на которые подписывается вебхук репозитория. В настройках
+/// ревью называются `pull_request_review_*`, а в заголовке — `pull_request_*`.
+pub const GITEA_REPOSITORY_HOOK_EVENTS: &[&str] = &[
+    "push",
+    "pull_request",
+    "pull_request_review_approved",
+    "pull_request_review_rejected",
+    "pull_request_comment",
+    "issue_comment",
+    "release",
+    "create",
+    "delete",
+];
+
+/// Значения заголовка `X-Gitea-Event` (Forgejo присылает те же)
+#[derive(Debug, PartialEq)]
+pub enum GiteaEventType {
+    Push,
+    PullRequest,
+    PullRequestApproved,
+    PullRequestRejected,
+    PullRequestComment,
+    IssueComment,
+    Release,
+    Create,
+    Delete,
+    Unknown(String),
+}
+
+impl FromStr for GiteaEventType {
+    type Err = ();
+
+    fn from_str(external_string: &str) -> Result<Self, Self::Err> {
+        match external_string {
+            "push" => Ok(GiteaEventType::Push),
+            "pull_request" => Ok(GiteaEventType::PullRequest),
+            "pull_request_approved" => Ok(GiteaEventType::PullRequestApproved),
+            "pull_request_rejected" => Ok(GiteaEventType::PullRequestRejected),
+            "pull_request_comment" => Ok(GiteaEventType::PullRequestComment),
+            "issue_comment" => Ok(GiteaEventType::IssueComment),
+            "release" => Ok(GiteaEventType::Release),
+            "create" => Ok(GiteaEventType::Create),
+            "delete" => Ok(GiteaEventType::Delete),
+            other => Ok(GiteaEventType::Unknown(other.to_string())),
+        }
+    }
+}
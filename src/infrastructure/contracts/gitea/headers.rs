@@ -0,0 +1,8 @@
+pub struct GiteaHeaders;
+
+impl GiteaHeaders {
+    pub const EVENT: &'static str = "x-gitea-event";
+    pub const SIGNATURE: &'static str = "x-gitea-signature";
+    // pub const DELIVERY: &'static str = "x-gitea-delivery";
+    // pub const EVENT_TYPE: &'static str = "x-gitea-event-type";
+}
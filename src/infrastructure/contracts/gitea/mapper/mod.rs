@@ -0,0 +1,79 @@
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::contracts::gitea::event_type::{GiteaEvent, GiteaEventType};
+use crate::infrastructure::contracts::gitea::payloads::git_ref::{
+    GiteaCreateEvent, GiteaDeleteEvent,
+};
+use crate::infrastructure::contracts::gitea::payloads::pr_comment::GiteaIssueCommentEvent;
+use crate::infrastructure::contracts::gitea::payloads::pull_request::GiteaPullRequestEvent;
+use crate::infrastructure::contracts::gitea::payloads::pull_request_review::GiteaPullRequestReviewEvent;
+use crate::infrastructure::contracts::gitea::payloads::push::GiteaPushEvent;
+use crate::infrastructure::contracts::gitea::payloads::release::GiteaReleaseEvent;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GiteaWebhookEventMapperError {
+    #[error("Unsupported Gitea event type")]
+    UnsupportedEventType,
+    #[error("Gitea event skipped: {0}")]
+    Skipped(&'static str),
+    #[error("Invalid payload for Gitea event: {0}")]
+    InvalidPayload(String),
+}
+
+pub struct GiteaWebhookEventMapper;
+
+impl GiteaWebhookEventMapper {
+    pub fn map_to_domain_event(
+        gitea_event_type: &GiteaEventType,
+        payload: serde_json::Value,
+    ) -> Result<Box<dyn WebhookEvent>, GiteaWebhookEventMapperError> {
+        match gitea_event_type {
+            GiteaEventType::Push => Self::parse_to_domain::<GiteaPushEvent>(payload),
+            GiteaEventType::PullRequest => Self::parse_to_domain::<GiteaPullRequestEvent>(payload),
+            GiteaEventType::PullRequestApproved
+            | GiteaEventType::PullRequestRejected
+            | GiteaEventType::PullRequestComment => {
+                Self::parse_to_domain::<GiteaPullRequestReviewEvent>(payload)
+            }
+            GiteaEventType::IssueComment => {
+                Self::parse_to_domain::<GiteaIssueCommentEvent>(payload)
+            }
+            GiteaEventType::Release => Self::parse_to_domain::<GiteaReleaseEvent>(payload),
+            GiteaEventType::Create => Self::parse_to_domain::<GiteaCreateEvent>(payload),
+            GiteaEventType::Delete => Self::parse_to_domain::<GiteaDeleteEvent>(payload),
+
+            _ => {
+                tracing::warn!(
+                    "No mapping found for Gitea event type: {:?}",
+                    gitea_event_type
+                );
+                Err(GiteaWebhookEventMapperError::UnsupportedEventType)
+            }
+        }
+    }
+
+    /// owner/name из `repository.full_name` — есть во всех событиях репозитория
+    pub fn extract_repository_full_name(payload: &serde_json::Value) -> Option<String> {
+        payload
+            .get("repository")?
+            .get("full_name")?
+            .as_str()
+            .map(|s| s.to_string())
+    }
+
+    fn parse_to_domain<E>(
+        payload: serde_json::Value,
+    ) -> Result<Box<dyn WebhookEvent>, GiteaWebhookEventMapperError>
+    where
+        E: GiteaEvent,
+    {
+        let event = E::from_value(payload)
+            .map_err(|e| GiteaWebhookEventMapperError::InvalidPayload(e.to_string()))?;
+
+        if let Some(reason) = event.skip_reason() {
+            return Err(GiteaWebhookEventMapperError::Skipped(reason));
+        }
+
+        Ok(Box::new(event.to_webhook_event()))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::domain::webhook::events::git_ref::{WebhookRefAction, WebhookRefEvent, WebhookRefType};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitea::event_type::GiteaEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// `X-Gitea-Event: create` — создана ветка или тег
+#[derive(Debug, Deserialize)]
+pub struct GiteaCreateEvent {
+    #[serde(flatten)]
+    pub payload: GiteaRefPayload,
+}
+
+/// `X-Gitea-Event: delete` — удалена ветка или тег
+#[derive(Debug, Deserialize)]
+pub struct GiteaDeleteEvent {
+    #[serde(flatten)]
+    pub payload: GiteaRefPayload,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaRefPayload {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub ref_type: WebhookRefType,
+    pub repository: GiteaRefRepository,
+    pub sender: GiteaRefUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaRefRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaRefUser {
+    pub login: String,
+}
+
+impl GiteaRefPayload {
+    fn to_webhook_event(&self, action: WebhookRefAction) -> WebhookRefEvent {
+        // Старые версии Gitea присылали полный ref
+        let ref_name = self
+            .ref_name
+            .strip_prefix("refs/heads/")
+            .or_else(|| self.ref_name.strip_prefix("refs/tags/"))
+            .unwrap_or(&self.ref_name);
+
+        WebhookRefEvent {
+            provider: WebhookProvider::Gitea,
+            action,
+            ref_type: self.ref_type,
+            ref_name: ref_name.to_string(),
+            sender: self.sender.login.clone(),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+        }
+    }
+}
+
+impl GiteaEvent for GiteaCreateEvent {
+    type WebhookEvent = WebhookRefEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        self.payload.to_webhook_event(WebhookRefAction::Created)
+    }
+}
+
+impl GiteaEvent for GiteaDeleteEvent {
+    type WebhookEvent = WebhookRefEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        self.payload.to_webhook_event(WebhookRefAction::Deleted)
+    }
+}
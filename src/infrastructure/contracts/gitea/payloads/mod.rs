@@ -0,0 +1,6 @@
+pub mod git_ref;
+pub mod pr_comment;
+pub mod pull_request;
+pub mod pull_request_review;
+pub mod push;
+pub mod release;
@@ -0,0 +1,71 @@
+use crate::domain::webhook::events::pr_comment::WebhookPrCommentEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitea::event_type::GiteaEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Payload for `issue_comment` events. Gitea шлёт его и для PR, отличаем по `is_pull`
+#[derive(Debug, Deserialize)]
+pub struct GiteaIssueCommentEvent {
+    pub issue: GiteaIssue,
+    pub comment: GiteaComment,
+    pub repository: GiteaCommentRepository,
+    #[serde(default)]
+    pub is_pull: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaIssue {
+    pub number: u64,
+    pub title: String,
+    pub user: GiteaCommentUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaComment {
+    pub body: String,
+    pub html_url: String,
+    pub user: GiteaCommentUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaCommentUser {
+    pub login: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaCommentRepository {
+    pub full_name: String,
+}
+
+impl GiteaEvent for GiteaIssueCommentEvent {
+    type WebhookEvent = WebhookPrCommentEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        if !self.is_pull {
+            return Some("comment is not attached to a pull request");
+        }
+
+        None
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        WebhookPrCommentEvent {
+            provider: WebhookProvider::Gitea,
+            commenter: self.comment.user.login.clone(),
+            pr_author: Some(self.issue.user.login.clone()),
+            repo: self.repository.full_name.clone(),
+            pr_number: self.issue.number,
+            pr_title: self.issue.title.clone(),
+            comment_body: self.comment.body.clone(),
+            comment_url: self.comment.html_url.clone(),
+        }
+    }
+}
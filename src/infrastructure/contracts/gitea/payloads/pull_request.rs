@@ -0,0 +1,162 @@
+use crate::domain::webhook::events::pull_request::{
+    WebhookPullRequestEvent, WebhookPullRequestEventActionType,
+};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitea::event_type::GiteaEvent;
+use crate::infrastructure::contracts::gitea::payloads::pull_request_review::mergeable_to_state;
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use serde_json::Value;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaPullRequestEvent {
+    pub action: String, // opened, closed, reopened, synchronized, label_updated и т.д.
+    pub number: u64,
+    pub pull_request: GiteaPullRequest,
+    pub repository: GiteaRepository,
+    pub sender: GiteaUser,
+    #[serde(default)]
+    pub requested_reviewer: Option<GiteaUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaPullRequest {
+    pub title: String,
+    pub body: Option<String>,
+    pub html_url: String,
+
+    pub state: String,
+    #[serde(default)]
+    pub draft: bool,
+
+    pub user: GiteaUser,
+    #[serde(default)]
+    pub assignees: Option<Vec<GiteaUser>>,
+    #[serde(default)]
+    pub requested_reviewers: Option<Vec<GiteaUser>>,
+
+    pub created_at: String,
+    pub updated_at: String,
+    pub merged_at: Option<String>,
+
+    pub merge_commit_sha: Option<String>,
+    #[serde(default)]
+    pub merged: bool,
+    pub merged_by: Option<GiteaUser>,
+    pub mergeable: Option<bool>,
+
+    #[serde(default)]
+    pub additions: u64,
+    #[serde(default)]
+    pub deletions: u64,
+    #[serde(default)]
+    pub changed_files: u64,
+
+    pub base: GiteaPullRequestBranch,
+    pub head: GiteaPullRequestBranch,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaPullRequestBranch {
+    pub label: String,
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    #[serde(default)]
+    pub sha: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaUser {
+    pub login: String,
+}
+
+impl GiteaPullRequestEvent {
+    fn action_type(&self) -> WebhookPullRequestEventActionType {
+        // Gitea называет часть действий иначе, чем GitHub
+        let action = match self.action.as_str() {
+            "synchronized" => "synchronize",
+            "label_updated" => "labeled",
+            "label_cleared" => "unlabeled",
+            other => other,
+        };
+
+        WebhookPullRequestEventActionType::from_str(action)
+            .unwrap_or(WebhookPullRequestEventActionType::Unknown)
+    }
+}
+
+impl GiteaEvent for GiteaPullRequestEvent {
+    type WebhookEvent = WebhookPullRequestEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let pr = &self.pull_request;
+
+        WebhookPullRequestEvent {
+            provider: WebhookProvider::Gitea,
+            source: self.sender.login.clone(),
+            author: Some(pr.user.login.clone()),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+            title: pr.title.clone(),
+            body: pr.body.clone(),
+            number: self.number,
+            action: self.action_type(),
+            merged: pr.merged,
+            merged_by: pr.merged_by.as_ref().map(|u| u.login.clone()),
+            draft: pr.draft,
+            state: pr.state.clone(),
+            head_ref: pr.head.ref_field.clone(),
+            base_ref: pr.base.ref_field.clone(),
+            head_repo: pr.head.label.clone(),
+            base_repo: pr.base.label.clone(),
+            pr_url: Some(pr.html_url.clone()),
+            merge_commit: pr.merge_commit_sha.clone(),
+            head_sha: pr.head.sha.clone(),
+            assignees: pr
+                .assignees
+                .iter()
+                .flatten()
+                .map(|u| u.login.clone())
+                .collect(),
+            created_at: format_datetime(&pr.created_at),
+            updated_at: format_datetime(&pr.updated_at),
+            merged_at: pr.merged_at.as_deref().map(format_datetime),
+            commits: 0, // Gitea не присылает количество коммитов
+            additions: pr.additions,
+            deletions: pr.deletions,
+            changed_files: pr.changed_files,
+            requested_reviewer: self.requested_reviewer.as_ref().map(|u| u.login.clone()),
+            requested_reviewers: pr
+                .requested_reviewers
+                .iter()
+                .flatten()
+                .map(|u| u.login.clone())
+                .collect(),
+            mergeable_state: pr.mergeable.map(mergeable_to_state),
+        }
+    }
+}
+
+fn format_datetime(ts: &str) -> String {
+    DateTime::parse_from_rfc3339(ts)
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%d.%m.%Y %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|_| ts.to_string())
+}
@@ -0,0 +1,120 @@
+use crate::domain::webhook::events::pull_request_review::{
+    WebhookPullRequestReviewEvent, WebhookPullRequestReviewState,
+};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitea::event_type::GiteaEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Payload for `pull_request_approved` / `pull_request_rejected` / `pull_request_comment`.
+/// Ревьюер приходит в `sender`, тип ревью — в `review.type`
+#[derive(Debug, Deserialize)]
+pub struct GiteaPullRequestReviewEvent {
+    pub pull_request: GiteaReviewPullRequest,
+    pub repository: GiteaReviewRepository,
+    pub sender: GiteaReviewUser,
+    pub review: Option<GiteaReview>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaReview {
+    #[serde(rename = "type")]
+    pub review_type: String,
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaReviewPullRequest {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub html_url: String,
+    pub user: GiteaReviewUser,
+    #[serde(default)]
+    pub comments: u64,
+    pub mergeable: Option<bool>,
+    pub head: Option<GiteaReviewPullRequestHead>,
+    pub base: Option<GiteaReviewPullRequestBase>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaReviewPullRequestHead {
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaReviewPullRequestBase {
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaReviewRepository {
+    pub full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaReviewUser {
+    pub login: String,
+}
+
+impl GiteaEvent for GiteaPullRequestReviewEvent {
+    type WebhookEvent = WebhookPullRequestReviewEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let review_type = self
+            .review
+            .as_ref()
+            .map(|r| r.review_type.as_str())
+            .unwrap_or_default();
+
+        let state = match review_type {
+            "pull_request_review_approved" => WebhookPullRequestReviewState::Approved,
+            "pull_request_review_rejected" => WebhookPullRequestReviewState::ChangesRequested,
+            "pull_request_review_comment" => WebhookPullRequestReviewState::Commented,
+            _ => WebhookPullRequestReviewState::Unknown,
+        };
+
+        WebhookPullRequestReviewEvent {
+            provider: WebhookProvider::Gitea,
+            reviewer: self.sender.login.clone(),
+            pr_author: self.pull_request.user.login.clone(),
+            repo: self.repository.full_name.clone(),
+            pr_number: self.pull_request.number,
+            pr_title: self.pull_request.title.clone(),
+            pr_url: self.pull_request.html_url.clone(),
+            // У Gitea нет отдельной ссылки на ревью
+            review_url: format!("{}/files", self.pull_request.html_url),
+            review_body: self
+                .review
+                .as_ref()
+                .and_then(|r| r.content.clone())
+                .filter(|b| !b.trim().is_empty()),
+            state,
+            review_comments: self.pull_request.comments,
+            mergeable_state: self.pull_request.mergeable.map(mergeable_to_state),
+            head_sha: self.pull_request.head.as_ref().map(|h| h.sha.clone()),
+            base_ref: self.pull_request.base.as_ref().map(|b| b.ref_field.clone()),
+            head_ref: self.pull_request.head.as_ref().map(|h| h.ref_field.clone()),
+            pr_body: self.pull_request.body.clone(),
+        }
+    }
+}
+
+/// Gitea отдаёт `mergeable: bool`, слушатели ждут значения GitHub
+pub fn mergeable_to_state(mergeable: bool) -> String {
+    if mergeable {
+        "clean".to_string()
+    } else {
+        "dirty".to_string()
+    }
+}
@@ -0,0 +1,99 @@
+use crate::domain::webhook::entities::commit::WebhookCommit;
+use crate::domain::webhook::events::push::WebhookPushEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitea::event_type::GiteaEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Gitea присылает нули вместо sha, если ветку создали или удалили
+const GITEA_BLANK_SHA: &str = "0000000000000000000000000000000000000000";
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaPushEvent {
+    #[serde(rename = "ref")]
+    pub ref_field: String, // "refs/heads/main"
+    pub before: String,
+    pub after: String,
+    pub compare_url: Option<String>,
+
+    #[serde(default)]
+    pub commits: Vec<GiteaCommit>,
+
+    pub repository: GiteaRepository,
+    pub pusher: GiteaUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaUser {
+    pub login: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaCommit {
+    pub id: String,
+    pub message: String,
+    pub url: String,
+    pub timestamp: Option<String>,
+    pub author: Option<GiteaCommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaCommitAuthor {
+    pub name: Option<String>,
+}
+
+impl GiteaEvent for GiteaPushEvent {
+    type WebhookEvent = WebhookPushEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let commits = self
+            .commits
+            .iter()
+            .map(|c| WebhookCommit {
+                id: c.id.clone(),
+                short_id: c.id.chars().take(7).collect(),
+                message: c.message.clone(),
+                author: c
+                    .author
+                    .as_ref()
+                    .and_then(|a| a.name.clone())
+                    .unwrap_or_else(|| "unknown".to_string()),
+                url: c.url.clone(),
+                timestamp: c.timestamp.clone(),
+            })
+            .collect();
+
+        let branch = self
+            .ref_field
+            .strip_prefix("refs/heads/")
+            .unwrap_or(&self.ref_field);
+
+        WebhookPushEvent {
+            provider: WebhookProvider::Gitea,
+            source: self.pusher.login.clone(),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+            ref_field: branch.to_string(),
+            before: self.before.clone(),
+            after: self.after.clone(),
+            compare_url: self.compare_url.clone().filter(|u| !u.is_empty()),
+            created: self.before == GITEA_BLANK_SHA,
+            deleted: self.after == GITEA_BLANK_SHA,
+            forced: false, // Gitea не сообщает о force-push
+            commits,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::domain::webhook::events::release::WebhookReleaseEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitea::event_type::GiteaEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaReleaseEvent {
+    pub action: String, // "published", "updated", "deleted"
+    pub release: GiteaRelease,
+    pub repository: GiteaRepository,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaRelease {
+    pub id: u64,
+    pub tag_name: String,
+    pub target_commitish: Option<String>,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub created_at: Option<String>,
+    pub published_at: Option<String>,
+    pub html_url: Option<String>,
+    pub author: Option<GiteaUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GiteaUser {
+    pub login: String,
+}
+
+impl GiteaEvent for GiteaReleaseEvent {
+    type WebhookEvent = WebhookReleaseEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        if self.action == "deleted" {
+            return Some("release deleted");
+        }
+
+        None
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        WebhookReleaseEvent {
+            provider: WebhookProvider::Gitea,
+            id: self.release.id,
+            tag_name: self.release.tag_name.clone(),
+            target_commitish: self
+                .release
+                .target_commitish
+                .clone()
+                .unwrap_or_else(|| self.release.tag_name.clone()),
+            name: self.release.name.clone(),
+            body: self.release.body.clone(),
+            draft: self.release.draft,
+            prerelease: self.release.prerelease,
+            created_at: self.release.created_at.clone(),
+            published_at: self.release.published_at.clone(),
+            html_url: self.release.html_url.clone(),
+            author: self.release.author.as_ref().map(|a| a.login.clone()),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+        }
+    }
+}
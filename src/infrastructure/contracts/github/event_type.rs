@@ -9,9 +9,35 @@ pub trait GithubEvent {
     where
         Self: Sized;
 
+    /// Причина, по которой событие не нужно превращать в доменное
+    fn skip_reason(&self) -> Option<&'static str> {
+        None
+    }
+
     fn to_webhook_event(&self) -> Self::WebhookEvent;
 }
 
+/// События, на которые подписывается вебхук репозитория.
+/// organization/membership приходят только из вебхука организации,
+/// installation* — только в вебхук GitHub App.
+pub const GITHUB_REPOSITORY_HOOK_EVENTS: &[&str] = &[
+    "push",
+    "pull_request",
+    "pull_request_review",
+    "pull_request_review_comment",
+    "issue_comment",
+    "issues",
+    "release",
+    "workflow_run",
+    "check_run",
+    "check_suite",
+    "deployment",
+    "deployment_status",
+    "create",
+    "delete",
+    "repository",
+];
+
 #[derive(Debug, PartialEq)]
 pub enum GithubEventType {
     Ping,
@@ -23,6 +49,17 @@ pub enum GithubEventType {
     Issues,
     Release,
     Workflow,
+    CheckRun,
+    CheckSuite,
+    Deployment,
+    DeploymentStatus,
+    Create,
+    Delete,
+    Organization,
+    Membership,
+    Repository,
+    Installation,
+    InstallationRepositories,
     Unknown(String),
 }
 
@@ -40,6 +77,17 @@ impl FromStr for GithubEventType {
             "issues" => Ok(GithubEventType::Issues),
             "release" => Ok(GithubEventType::Release),
             "workflow_run" => Ok(GithubEventType::Workflow),
+            "check_run" => Ok(GithubEventType::CheckRun),
+            "check_suite" => Ok(GithubEventType::CheckSuite),
+            "deployment" => Ok(GithubEventType::Deployment),
+            "deployment_status" => Ok(GithubEventType::DeploymentStatus),
+            "create" => Ok(GithubEventType::Create),
+            "delete" => Ok(GithubEventType::Delete),
+            "organization" => Ok(GithubEventType::Organization),
+            "membership" => Ok(GithubEventType::Membership),
+            "repository" => Ok(GithubEventType::Repository),
+            "installation" => Ok(GithubEventType::Installation),
+            "installation_repositories" => Ok(GithubEventType::InstallationRepositories),
             other => Ok(GithubEventType::Unknown(other.to_string())),
         }
     }
@@ -57,6 +105,17 @@ impl From<GithubEventType> for WebhookEventType {
             GithubEventType::Issues => WebhookEventType::Issues,
             GithubEventType::Release => WebhookEventType::Release,
             GithubEventType::Workflow => WebhookEventType::Workflow,
+            GithubEventType::CheckRun => WebhookEventType::CheckRun,
+            GithubEventType::CheckSuite => WebhookEventType::CheckSuite,
+            GithubEventType::Deployment => WebhookEventType::Deployment,
+            GithubEventType::DeploymentStatus => WebhookEventType::DeploymentStatus,
+            GithubEventType::Create => WebhookEventType::Create,
+            GithubEventType::Delete => WebhookEventType::Delete,
+            GithubEventType::Organization => WebhookEventType::Organization,
+            GithubEventType::Membership => WebhookEventType::Membership,
+            GithubEventType::Repository => WebhookEventType::Repository,
+            GithubEventType::Installation => WebhookEventType::Installation,
+            GithubEventType::InstallationRepositories => WebhookEventType::InstallationRepositories,
             GithubEventType::Unknown(s) => WebhookEventType::Unknown(s),
         }
     }
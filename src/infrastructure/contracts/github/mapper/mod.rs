@@ -1,5 +1,18 @@
 use crate::domain::webhook::events::WebhookEvent;
 use crate::infrastructure::contracts::github::event_type::{GithubEvent, GithubEventType};
+use crate::infrastructure::contracts::github::payloads::check_run::GithubCheckRunEvent;
+use crate::infrastructure::contracts::github::payloads::check_suite::GithubCheckSuiteEvent;
+use crate::infrastructure::contracts::github::payloads::deployment::GithubDeploymentEvent;
+use crate::infrastructure::contracts::github::payloads::deployment_status::GithubDeploymentStatusEvent;
+use crate::infrastructure::contracts::github::payloads::git_ref::{
+    GithubCreateEvent, GithubDeleteEvent,
+};
+use crate::infrastructure::contracts::github::payloads::installation::{
+    GithubInstallationEvent, GithubInstallationRepositoriesEvent,
+};
+use crate::infrastructure::contracts::github::payloads::issues::GithubIssuesEvent;
+use crate::infrastructure::contracts::github::payloads::membership::GithubMembershipEvent;
+use crate::infrastructure::contracts::github::payloads::organization::GithubOrganizationEvent;
 use crate::infrastructure::contracts::github::payloads::pr_comment::{
     GithubIssueCommentEvent, GithubPrReviewCommentEvent,
 };
@@ -7,13 +20,17 @@ use crate::infrastructure::contracts::github::payloads::pull_request::GithubPull
 use crate::infrastructure::contracts::github::payloads::pull_request_review::GithubPullRequestReviewEvent;
 use crate::infrastructure::contracts::github::payloads::push::GithubPushEvent;
 use crate::infrastructure::contracts::github::payloads::release::GithubReleaseEvent;
+use crate::infrastructure::contracts::github::payloads::repository::GithubRepositoryEvent;
 use crate::infrastructure::contracts::github::payloads::workflow::GithubWorkflowEvent;
+use serde::Deserialize;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum GithubWebhookEventMapperError {
     #[error("Unsupported GitHub event type")]
     UnsupportedEventType,
+    #[error("GitHub event skipped: {0}")]
+    Skipped(&'static str),
     #[error("Invalid payload for GitHub event: {0}")]
     InvalidPayload(String),
 }
@@ -41,6 +58,26 @@ impl GithubWebhookEventMapper {
             GithubEventType::IssueComment => {
                 Self::parse_to_domain::<GithubIssueCommentEvent>(payload)
             }
+            GithubEventType::Issues => Self::parse_to_domain::<GithubIssuesEvent>(payload),
+            GithubEventType::CheckRun => Self::parse_to_domain::<GithubCheckRunEvent>(payload),
+            GithubEventType::CheckSuite => Self::parse_to_domain::<GithubCheckSuiteEvent>(payload),
+            GithubEventType::Deployment => Self::parse_to_domain::<GithubDeploymentEvent>(payload),
+            GithubEventType::DeploymentStatus => {
+                Self::parse_to_domain::<GithubDeploymentStatusEvent>(payload)
+            }
+            GithubEventType::Create => Self::parse_to_domain::<GithubCreateEvent>(payload),
+            GithubEventType::Delete => Self::parse_to_domain::<GithubDeleteEvent>(payload),
+            GithubEventType::Organization => {
+                Self::parse_to_domain::<GithubOrganizationEvent>(payload)
+            }
+            GithubEventType::Membership => Self::parse_to_domain::<GithubMembershipEvent>(payload),
+            GithubEventType::Repository => Self::parse_to_domain::<GithubRepositoryEvent>(payload),
+            GithubEventType::Installation => {
+                Self::parse_to_domain::<GithubInstallationEvent>(payload)
+            }
+            GithubEventType::InstallationRepositories => {
+                Self::parse_to_domain::<GithubInstallationRepositoriesEvent>(payload)
+            }
 
             _ => {
                 tracing::warn!(
@@ -52,6 +89,23 @@ impl GithubWebhookEventMapper {
         }
     }
 
+    /// owner/name из `repository.full_name` — есть почти во всех событиях
+    pub fn extract_repository_full_name(payload: &serde_json::Value) -> Option<String> {
+        payload
+            .get("repository")?
+            .get("full_name")?
+            .as_str()
+            .map(|s| s.to_string())
+    }
+
+    /// owner/name до переименования / передачи из события `repository`.
+    /// В базе репозиторий ещё записан под прежним именем, пока это событие не обработано
+    pub fn extract_previous_repository_full_name(payload: &serde_json::Value) -> Option<String> {
+        GithubRepositoryEvent::deserialize(payload)
+            .ok()?
+            .moved_from()
+    }
+
     fn parse_to_domain<E>(
         payload: serde_json::Value,
     ) -> Result<Box<dyn WebhookEvent>, GithubWebhookEventMapperError>
@@ -61,6 +115,58 @@ impl GithubWebhookEventMapper {
         let event = E::from_value(payload)
             .map_err(|e| GithubWebhookEventMapperError::InvalidPayload(e.to_string()))?;
 
+        if let Some(reason) = event.skip_reason() {
+            return Err(GithubWebhookEventMapperError::Skipped(reason));
+        }
+
         Ok(Box::new(event.to_webhook_event()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn extracts_name_before_rename() {
+        let payload = json!({
+            "action": "renamed",
+            "repository": { "name": "app", "full_name": "team/app" },
+            "changes": { "repository": { "name": { "from": "old-app" } } }
+        });
+
+        assert_eq!(
+            GithubWebhookEventMapper::extract_previous_repository_full_name(&payload).as_deref(),
+            Some("team/old-app")
+        );
+    }
+
+    #[test]
+    fn extracts_name_before_transfer() {
+        let payload = json!({
+            "action": "transferred",
+            "repository": { "name": "app", "full_name": "team/app" },
+            "changes": { "owner": { "from": { "user": { "login": "alice" } } } }
+        });
+
+        assert_eq!(
+            GithubWebhookEventMapper::extract_previous_repository_full_name(&payload).as_deref(),
+            Some("alice/app")
+        );
+    }
+
+    #[test]
+    fn ignores_other_events() {
+        let payload = json!({
+            "action": "edited",
+            "repository": { "name": "app", "full_name": "team/app" },
+            "changes": { "title": { "from": "Old title" } }
+        });
+
+        assert_eq!(
+            GithubWebhookEventMapper::extract_previous_repository_full_name(&payload),
+            None
+        );
+    }
+}
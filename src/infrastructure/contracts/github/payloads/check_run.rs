@@ -0,0 +1,93 @@
+use crate::domain::webhook::events::check_run::WebhookCheckRunEvent;
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckRunEvent {
+    pub check_run: GithubCheckRun,
+    pub repository: GithubCheckRepository,
+    pub sender: GithubCheckSender,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckRun {
+    pub id: u64,
+    pub name: String,
+    pub head_sha: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub html_url: Option<String>,
+    pub app: Option<GithubCheckApp>,
+    pub check_suite: Option<GithubCheckRunSuite>,
+    #[serde(default)]
+    pub pull_requests: Vec<GithubCheckPullRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckRunSuite {
+    pub id: u64,
+    pub head_branch: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckApp {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckPullRequest {
+    pub number: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckSender {
+    pub login: String,
+    #[serde(rename = "type", default)]
+    pub user_type: Option<String>, // User / Bot
+}
+
+impl GithubCheckSender {
+    /// Боты (github-actions[bot] и т.п.) адресатами уведомлений не бывают
+    pub fn human_login(&self) -> Option<String> {
+        let is_bot = self.user_type.as_deref() == Some("Bot") || self.login.ends_with("[bot]");
+        (!is_bot).then(|| self.login.clone())
+    }
+}
+
+impl GithubEvent for GithubCheckRunEvent {
+    type WebhookEvent = WebhookCheckRunEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let run = &self.check_run;
+
+        WebhookCheckRunEvent {
+            id: run.id,
+            name: run.name.clone(),
+            app: run.app.as_ref().map(|a| a.name.clone()),
+            head_sha: run.head_sha.clone(),
+            head_branch: run.check_suite.as_ref().and_then(|s| s.head_branch.clone()),
+            status: run.status.clone(),
+            conclusion: run.conclusion.clone(),
+            html_url: run.html_url.clone(),
+            check_suite_id: run.check_suite.as_ref().map(|s| s.id),
+            pr_numbers: run.pull_requests.iter().map(|p| p.number).collect(),
+            sender: self.sender.human_login(),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+        }
+    }
+}
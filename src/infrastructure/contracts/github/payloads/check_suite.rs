@@ -0,0 +1,55 @@
+use crate::domain::webhook::events::check_suite::WebhookCheckSuiteEvent;
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use crate::infrastructure::contracts::github::payloads::check_run::{
+    GithubCheckApp, GithubCheckPullRequest, GithubCheckRepository, GithubCheckSender,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckSuiteEvent {
+    pub action: String, // requested, rerequested, completed
+    pub check_suite: GithubCheckSuite,
+    pub repository: GithubCheckRepository,
+    pub sender: GithubCheckSender,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubCheckSuite {
+    pub id: u64,
+    pub head_sha: String,
+    pub head_branch: Option<String>,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub app: Option<GithubCheckApp>,
+    #[serde(default)]
+    pub pull_requests: Vec<GithubCheckPullRequest>,
+}
+
+impl GithubEvent for GithubCheckSuiteEvent {
+    type WebhookEvent = WebhookCheckSuiteEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let suite = &self.check_suite;
+
+        WebhookCheckSuiteEvent {
+            id: suite.id,
+            app: suite.app.as_ref().map(|a| a.name.clone()),
+            head_sha: suite.head_sha.clone(),
+            head_branch: suite.head_branch.clone(),
+            status: suite.status.clone().unwrap_or_else(|| self.action.clone()),
+            conclusion: suite.conclusion.clone(),
+            pr_numbers: suite.pull_requests.iter().map(|p| p.number).collect(),
+            sender: self.sender.human_login(),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::domain::webhook::events::deployment::WebhookDeploymentEvent;
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GithubDeploymentEvent {
+    pub deployment: GithubDeployment,
+    pub repository: GithubDeploymentRepository,
+    pub sender: Option<GithubDeploymentUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubDeployment {
+    pub id: u64,
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub ref_name: Option<String>,
+    pub environment: String,
+    pub description: Option<String>,
+    pub creator: Option<GithubDeploymentUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubDeploymentRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubDeploymentUser {
+    pub login: String,
+    #[serde(rename = "type", default)]
+    pub user_type: Option<String>, // User / Bot
+}
+
+impl GithubDeploymentUser {
+    fn is_bot(&self) -> bool {
+        self.user_type.as_deref() == Some("Bot") || self.login.ends_with("[bot]")
+    }
+}
+
+impl GithubDeployment {
+    /// Деплои из Actions создаёт github-actions[bot] — тогда берём
+    /// отправителя события, если он человек
+    pub fn deployed_by(&self, sender: Option<&GithubDeploymentUser>) -> Option<String> {
+        let creator = self.creator.as_ref();
+
+        creator
+            .filter(|u| !u.is_bot())
+            .or(sender.filter(|u| !u.is_bot()))
+            .or(creator)
+            .map(|u| u.login.clone())
+    }
+}
+
+impl GithubEvent for GithubDeploymentEvent {
+    type WebhookEvent = WebhookDeploymentEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        WebhookDeploymentEvent {
+            id: self.deployment.id,
+            environment: self.deployment.environment.clone(),
+            sha: self.deployment.sha.clone(),
+            ref_name: self.deployment.ref_name.clone(),
+            description: self.deployment.description.clone(),
+            creator: self.deployment.deployed_by(self.sender.as_ref()),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::domain::webhook::events::deployment_status::WebhookDeploymentStatusEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use crate::infrastructure::contracts::github::payloads::deployment::{
+    GithubDeployment, GithubDeploymentRepository, GithubDeploymentUser,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GithubDeploymentStatusEvent {
+    pub deployment_status: GithubDeploymentStatus,
+    pub deployment: GithubDeployment,
+    pub repository: GithubDeploymentRepository,
+    pub sender: Option<GithubDeploymentUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubDeploymentStatus {
+    pub state: String,
+    pub description: Option<String>,
+    pub environment: Option<String>,
+    pub log_url: Option<String>,
+    pub target_url: Option<String>,
+    pub environment_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl GithubEvent for GithubDeploymentStatusEvent {
+    type WebhookEvent = WebhookDeploymentStatusEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let status = &self.deployment_status;
+
+        WebhookDeploymentStatusEvent {
+            provider: WebhookProvider::Github,
+            deployment_id: self.deployment.id,
+            state: status.state.clone(),
+            environment: status
+                .environment
+                .clone()
+                .unwrap_or_else(|| self.deployment.environment.clone()),
+            sha: self.deployment.sha.clone(),
+            ref_name: self.deployment.ref_name.clone(),
+            description: status
+                .description
+                .clone()
+                .or_else(|| self.deployment.description.clone()),
+            creator: self.deployment.deployed_by(self.sender.as_ref()),
+            // log_url появился позже target_url, старые интеграции шлют только его
+            log_url: status.log_url.clone().or_else(|| status.target_url.clone()),
+            environment_url: status.environment_url.clone().filter(|u| !u.is_empty()),
+            created_at: status.created_at,
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+        }
+    }
+}
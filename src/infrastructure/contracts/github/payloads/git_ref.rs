@@ -0,0 +1,83 @@
+use crate::domain::webhook::events::git_ref::{WebhookRefAction, WebhookRefEvent, WebhookRefType};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Событие `create` — создана ветка или тег
+#[derive(Debug, Deserialize)]
+pub struct GithubCreateEvent {
+    #[serde(flatten)]
+    pub payload: GithubRefPayload,
+}
+
+/// Событие `delete` — удалена ветка или тег
+#[derive(Debug, Deserialize)]
+pub struct GithubDeleteEvent {
+    #[serde(flatten)]
+    pub payload: GithubRefPayload,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRefPayload {
+    #[serde(rename = "ref")]
+    pub ref_name: String, // короткое имя: "main", "v1.2.0"
+    pub ref_type: WebhookRefType, // "branch" / "tag"
+    pub repository: GithubRefRepository,
+    pub sender: GithubRefUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRefRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRefUser {
+    pub login: String,
+}
+
+impl GithubRefPayload {
+    fn to_webhook_event(&self, action: WebhookRefAction) -> WebhookRefEvent {
+        WebhookRefEvent {
+            provider: WebhookProvider::Github,
+            action,
+            ref_type: self.ref_type,
+            ref_name: self.ref_name.clone(),
+            sender: self.sender.login.clone(),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+        }
+    }
+}
+
+impl GithubEvent for GithubCreateEvent {
+    type WebhookEvent = WebhookRefEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        self.payload.to_webhook_event(WebhookRefAction::Created)
+    }
+}
+
+impl GithubEvent for GithubDeleteEvent {
+    type WebhookEvent = WebhookRefEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        self.payload.to_webhook_event(WebhookRefAction::Deleted)
+    }
+}
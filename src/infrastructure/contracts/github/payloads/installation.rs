@@ -0,0 +1,134 @@
+use crate::domain::webhook::events::installation::{
+    WebhookInstallationAction, WebhookInstallationEvent,
+};
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Событие `installation` — приложение установили, удалили или приостановили
+#[derive(Debug, Deserialize)]
+pub struct GithubInstallationEvent {
+    pub action: String, // created, deleted, suspend, unsuspend, new_permissions_accepted
+    pub installation: GithubInstallation,
+    #[serde(default)]
+    pub repositories: Vec<GithubInstallationRepository>,
+    pub sender: Option<GithubInstallationAccount>,
+}
+
+/// Событие `installation_repositories` — изменился набор репозиториев установки
+#[derive(Debug, Deserialize)]
+pub struct GithubInstallationRepositoriesEvent {
+    pub action: String, // added, removed
+    pub installation: GithubInstallation,
+    #[serde(default)]
+    pub repositories_added: Vec<GithubInstallationRepository>,
+    #[serde(default)]
+    pub repositories_removed: Vec<GithubInstallationRepository>,
+    pub sender: Option<GithubInstallationAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubInstallation {
+    pub id: u64,
+    pub account: GithubInstallationAccount,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubInstallationAccount {
+    pub login: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubInstallationRepository {
+    pub full_name: String,
+}
+
+fn full_names(repos: &[GithubInstallationRepository]) -> Vec<String> {
+    repos.iter().map(|r| r.full_name.clone()).collect()
+}
+
+impl GithubInstallationEvent {
+    fn parse_action(&self) -> Option<WebhookInstallationAction> {
+        match self.action.as_str() {
+            "created" => Some(WebhookInstallationAction::Created),
+            "deleted" => Some(WebhookInstallationAction::Deleted),
+            "suspend" => Some(WebhookInstallationAction::Suspended),
+            "unsuspend" => Some(WebhookInstallationAction::Unsuspended),
+            _ => None,
+        }
+    }
+}
+
+impl GithubEvent for GithubInstallationEvent {
+    type WebhookEvent = WebhookInstallationEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        match self.parse_action() {
+            Some(_) => None,
+            None => Some("installation action is not tracked"),
+        }
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let action = self
+            .parse_action()
+            .unwrap_or(WebhookInstallationAction::Created);
+
+        // В created приходят репозитории, к которым дали доступ, в deleted — у которых забрали
+        let (repositories_added, repositories_removed) = match action {
+            WebhookInstallationAction::Created => (full_names(&self.repositories), Vec::new()),
+            WebhookInstallationAction::Deleted => (Vec::new(), full_names(&self.repositories)),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        WebhookInstallationEvent {
+            action,
+            installation_id: self.installation.id,
+            account: self.installation.account.login.clone(),
+            repositories_added,
+            repositories_removed,
+            sender: self.sender.as_ref().map(|s| s.login.clone()),
+        }
+    }
+}
+
+impl GithubEvent for GithubInstallationRepositoriesEvent {
+    type WebhookEvent = WebhookInstallationEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        match self.action.as_str() {
+            "added" | "removed" => None,
+            _ => Some("installation_repositories action is not tracked"),
+        }
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let action = match self.action.as_str() {
+            "removed" => WebhookInstallationAction::RepositoriesRemoved,
+            _ => WebhookInstallationAction::RepositoriesAdded,
+        };
+
+        WebhookInstallationEvent {
+            action,
+            installation_id: self.installation.id,
+            account: self.installation.account.login.clone(),
+            repositories_added: full_names(&self.repositories_added),
+            repositories_removed: full_names(&self.repositories_removed),
+            sender: self.sender.as_ref().map(|s| s.login.clone()),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::webhook::events::issue::{WebhookIssueEvent, WebhookIssueEventActionType};
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use serde_json::Value;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+pub struct GithubIssuesEvent {
+    pub action: String, // opened, closed, reopened, assigned, labeled, milestoned и т.д.
+    pub issue: GithubIssue,
+    pub repository: GithubIssueRepository,
+    pub sender: GithubIssueUser,
+    #[serde(default)]
+    pub assignee: Option<GithubIssueUser>,
+    #[serde(default)]
+    pub label: Option<GithubLabel>,
+    #[serde(default)]
+    pub milestone: Option<GithubMilestone>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub html_url: String,
+    pub state: String,
+    #[serde(default)]
+    pub state_reason: Option<String>,
+    pub user: GithubIssueUser,
+    #[serde(default)]
+    pub assignees: Vec<GithubIssueUser>,
+    #[serde(default)]
+    pub labels: Vec<GithubLabel>,
+    pub milestone: Option<GithubMilestone>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubMilestone {
+    pub title: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubIssueRepository {
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubIssueUser {
+    pub login: String,
+}
+
+impl GithubEvent for GithubIssuesEvent {
+    type WebhookEvent = WebhookIssueEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let issue = &self.issue;
+
+        WebhookIssueEvent {
+            source: self.sender.login.clone(),
+            author: issue.user.login.clone(),
+            repo: self.repository.full_name.clone(),
+            repo_url: self.repository.html_url.clone(),
+            number: issue.number,
+            title: issue.title.clone(),
+            body: issue.body.clone(),
+            action: WebhookIssueEventActionType::from_str(self.action.as_str())
+                .unwrap_or(WebhookIssueEventActionType::Unknown),
+            state: issue.state.clone(),
+            state_reason: issue.state_reason.clone(),
+            issue_url: Some(issue.html_url.clone()),
+            labels: issue.labels.iter().map(|l| l.name.clone()).collect(),
+            assignees: issue.assignees.iter().map(|u| u.login.clone()).collect(),
+            assignee: self.assignee.as_ref().map(|u| u.login.clone()),
+            label: self.label.as_ref().map(|l| l.name.clone()),
+            milestone: self
+                .milestone
+                .as_ref()
+                .or(issue.milestone.as_ref())
+                .map(|m| m.title.clone()),
+            created_at: format_datetime(&issue.created_at),
+            updated_at: format_datetime(&issue.updated_at),
+        }
+    }
+}
+
+fn format_datetime(ts: &str) -> String {
+    DateTime::parse_from_rfc3339(ts)
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%d.%m.%Y %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|_| ts.to_string())
+}
@@ -0,0 +1,73 @@
+use crate::domain::webhook::events::member::{
+    WebhookMemberAction, WebhookMemberEvent, WebhookMemberScope,
+};
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Событие `membership` — участника добавили в команду организации или удалили из неё
+#[derive(Debug, Deserialize)]
+pub struct GithubMembershipEvent {
+    pub action: String, // added, removed
+    pub scope: String,  // сейчас только "team"
+    pub member: GithubMembershipUser,
+    pub team: GithubMembershipTeam,
+    pub organization: GithubMembershipOrganization,
+    pub sender: Option<GithubMembershipUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubMembershipUser {
+    pub login: String,
+    #[serde(default)]
+    pub id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubMembershipTeam {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubMembershipOrganization {
+    pub login: String,
+}
+
+impl GithubEvent for GithubMembershipEvent {
+    type WebhookEvent = WebhookMemberEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        if self.scope != "team" {
+            return Some("membership scope is not tracked");
+        }
+
+        match self.action.as_str() {
+            "added" | "removed" => None,
+            _ => Some("membership action is not tracked"),
+        }
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let action = match self.action.as_str() {
+            "removed" => WebhookMemberAction::Removed,
+            _ => WebhookMemberAction::Added,
+        };
+
+        WebhookMemberEvent {
+            action,
+            scope: WebhookMemberScope::Team,
+            organization: self.organization.login.clone(),
+            team: Some(self.team.name.clone()),
+            login: self.member.login.clone(),
+            user_id: self.member.id,
+            sender: self.sender.as_ref().map(|s| s.login.clone()),
+        }
+    }
+}
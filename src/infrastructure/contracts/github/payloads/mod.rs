@@ -1,6 +1,16 @@
+pub mod check_run;
+pub mod check_suite;
+pub mod deployment;
+pub mod deployment_status;
+pub mod git_ref;
+pub mod installation;
+pub mod issues;
+pub mod membership;
+pub mod organization;
 pub mod pr_comment;
 pub mod pull_request;
 pub mod pull_request_review;
 pub mod push;
 pub mod release;
+pub mod repository;
 pub mod workflow;
@@ -0,0 +1,74 @@
+use crate::domain::webhook::events::member::{
+    WebhookMemberAction, WebhookMemberEvent, WebhookMemberScope,
+};
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Событие `organization` — нас интересует только состав участников
+#[derive(Debug, Deserialize)]
+pub struct GithubOrganizationEvent {
+    pub action: String, // member_added, member_removed, member_invited, renamed, deleted
+    pub membership: Option<GithubOrganizationMembership>,
+    pub organization: GithubOrganization,
+    pub sender: Option<GithubOrganizationUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubOrganizationMembership {
+    pub user: GithubOrganizationUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubOrganization {
+    pub login: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubOrganizationUser {
+    pub login: String,
+    #[serde(default)]
+    pub id: u64,
+}
+
+impl GithubEvent for GithubOrganizationEvent {
+    type WebhookEvent = WebhookMemberEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        match self.action.as_str() {
+            "member_added" | "member_removed" if self.membership.is_some() => None,
+            "member_added" | "member_removed" => Some("organization event without membership"),
+            _ => Some("organization action is not tracked"),
+        }
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let action = match self.action.as_str() {
+            "member_removed" => WebhookMemberAction::Removed,
+            _ => WebhookMemberAction::Added,
+        };
+
+        let (login, user_id) = self
+            .membership
+            .as_ref()
+            .map(|m| (m.user.login.clone(), m.user.id))
+            .unwrap_or_default();
+
+        WebhookMemberEvent {
+            action,
+            scope: WebhookMemberScope::Organization,
+            organization: self.organization.login.clone(),
+            team: None,
+            login,
+            user_id,
+            sender: self.sender.as_ref().map(|s| s.login.clone()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::webhook::events::pr_comment::WebhookPrCommentEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::contracts::github::event_type::GithubEvent;
 use serde::Deserialize;
 use serde_json::Value;
@@ -68,8 +69,9 @@ impl GithubEvent for GithubPrReviewCommentEvent {
 
     fn to_webhook_event(&self) -> Self::WebhookEvent {
         WebhookPrCommentEvent {
+            provider: WebhookProvider::Github,
             commenter: self.comment.user.login.clone(),
-            pr_author: self.pull_request.user.login.clone(),
+            pr_author: Some(self.pull_request.user.login.clone()),
             repo: self.repository.full_name.clone(),
             pr_number: self.pull_request.number,
             pr_title: self.pull_request.title.clone(),
@@ -91,8 +93,9 @@ impl GithubEvent for GithubIssueCommentEvent {
 
     fn to_webhook_event(&self) -> Self::WebhookEvent {
         WebhookPrCommentEvent {
+            provider: WebhookProvider::Github,
             commenter: self.comment.user.login.clone(),
-            pr_author: self.issue.user.login.clone(),
+            pr_author: Some(self.issue.user.login.clone()),
             repo: self.repository.full_name.clone(),
             pr_number: self.issue.number,
             pr_title: self.issue.title.clone(),
@@ -1,6 +1,7 @@
 use crate::domain::webhook::events::pull_request::{
     WebhookPullRequestEvent, WebhookPullRequestEventActionType,
 };
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::contracts::github::event_type::GithubEvent;
 use chrono::{DateTime, Local};
 use serde::Deserialize;
@@ -91,8 +92,9 @@ impl GithubEvent for GithubPullRequestEvent {
         let pr = &self.pull_request;
 
         WebhookPullRequestEvent {
+            provider: WebhookProvider::Github,
             source: self.sender.login.clone(),
-            author: pr.user.login.clone(),
+            author: Some(pr.user.login.clone()),
             repo: self.repository.full_name.clone(),
             repo_url: self.repository.html_url.clone(),
             title: pr.title.clone(),
@@ -110,6 +112,7 @@ impl GithubEvent for GithubPullRequestEvent {
             base_repo: pr.base.label.clone(),
             pr_url: Some(pr.html_url.clone()),
             merge_commit: pr.merge_commit_sha.clone(),
+            head_sha: Some(pr.head.sha.clone()),
             assignees: pr.assignees.iter().map(|u| u.login.clone()).collect(),
             created_at: format_datetime(&pr.created_at),
             updated_at: format_datetime(&pr.updated_at),
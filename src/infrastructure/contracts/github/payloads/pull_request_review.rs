@@ -1,6 +1,7 @@
 use crate::domain::webhook::events::pull_request_review::{
     WebhookPullRequestReviewEvent, WebhookPullRequestReviewState,
 };
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::contracts::github::event_type::GithubEvent;
 use serde::Deserialize;
 use serde_json::Value;
@@ -25,12 +26,28 @@ pub struct GithubReview {
 pub struct GithubReviewPullRequest {
     pub number: u64,
     pub title: String,
+    pub body: Option<String>,
     pub html_url: String,
     pub user: GithubReviewUser,
     #[serde(default)]
     pub review_comments: u64,
     #[serde(default)]
     pub mergeable_state: Option<String>,
+    pub head: Option<GithubReviewPullRequestHead>,
+    pub base: Option<GithubReviewPullRequestBase>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubReviewPullRequestHead {
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubReviewPullRequestBase {
+    #[serde(rename = "ref")]
+    pub ref_field: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -62,6 +79,7 @@ impl GithubEvent for GithubPullRequestReviewEvent {
         };
 
         WebhookPullRequestReviewEvent {
+            provider: WebhookProvider::Github,
             reviewer: self.review.user.login.clone(),
             pr_author: self.pull_request.user.login.clone(),
             repo: self.repository.full_name.clone(),
@@ -73,6 +91,10 @@ impl GithubEvent for GithubPullRequestReviewEvent {
             state,
             review_comments: self.pull_request.review_comments,
             mergeable_state: self.pull_request.mergeable_state.clone(),
+            head_sha: self.pull_request.head.as_ref().map(|h| h.sha.clone()),
+            base_ref: self.pull_request.base.as_ref().map(|b| b.ref_field.clone()),
+            head_ref: self.pull_request.head.as_ref().map(|h| h.ref_field.clone()),
+            pr_body: self.pull_request.body.clone(),
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::domain::webhook::entities::commit::WebhookCommit;
 use crate::domain::webhook::events::push::WebhookPushEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::contracts::github::event_type::GithubEvent;
 use serde::Deserialize;
 use serde_json::Value;
@@ -111,6 +112,7 @@ impl GithubEvent for GithubPushEvent {
             .unwrap_or(&self.ref_field);
 
         WebhookPushEvent {
+            provider: WebhookProvider::Github,
             source: self.pusher.name.clone(),
             repo: self.repository.full_name.clone(),
             repo_url: self.repository.html_url.clone(),
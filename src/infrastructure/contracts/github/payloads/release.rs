@@ -1,4 +1,5 @@
 use crate::domain::webhook::events::release::WebhookReleaseEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::contracts::github::event_type::GithubEvent;
 use serde::Deserialize;
 use serde_json::Value;
@@ -52,6 +53,7 @@ impl GithubEvent for GithubReleaseEvent {
 
     fn to_webhook_event(&self) -> Self::WebhookEvent {
         WebhookReleaseEvent {
+            provider: WebhookProvider::Github,
             id: self.release.id,
             tag_name: self.release.tag_name.clone(),
             target_commitish: self.release.tag_name.clone(), // GitHub обычно не присылает target_commit, можно временно использовать тег
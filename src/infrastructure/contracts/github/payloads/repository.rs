@@ -0,0 +1,123 @@
+use crate::domain::webhook::events::repository::{WebhookRepositoryAction, WebhookRepositoryEvent};
+use crate::infrastructure::contracts::github::event_type::GithubEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Событие `repository` — создание, переименование, архивирование и т.п.
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryEvent {
+    pub action: String,
+    pub repository: GithubRepositoryInfo,
+    pub changes: Option<GithubRepositoryChanges>,
+    pub sender: Option<GithubRepositoryAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryInfo {
+    pub name: String,
+    pub full_name: String,
+    pub html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryChanges {
+    pub repository: Option<GithubRepositoryNameChange>,
+    pub owner: Option<GithubRepositoryOwnerChange>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryNameChange {
+    pub name: GithubRepositoryFrom<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryOwnerChange {
+    pub from: GithubRepositoryPreviousOwner,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryFrom<T> {
+    pub from: T,
+}
+
+/// Прежний владелец приходит либо как `user`, либо как `organization`
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryPreviousOwner {
+    pub user: Option<GithubRepositoryAccount>,
+    pub organization: Option<GithubRepositoryAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GithubRepositoryAccount {
+    pub login: String,
+}
+
+impl GithubRepositoryEvent {
+    fn parse_action(&self) -> Option<WebhookRepositoryAction> {
+        match self.action.as_str() {
+            "created" => Some(WebhookRepositoryAction::Created),
+            "deleted" => Some(WebhookRepositoryAction::Deleted),
+            "archived" => Some(WebhookRepositoryAction::Archived),
+            "unarchived" => Some(WebhookRepositoryAction::Unarchived),
+            "renamed" => Some(WebhookRepositoryAction::Renamed),
+            "transferred" => Some(WebhookRepositoryAction::Transferred),
+            _ => None,
+        }
+    }
+
+    /// Полное имя до переименования / передачи — только для `renamed` / `transferred`
+    pub fn moved_from(&self) -> Option<String> {
+        match self.parse_action()? {
+            WebhookRepositoryAction::Renamed | WebhookRepositoryAction::Transferred => {
+                self.previous_full_name()
+            }
+            _ => None,
+        }
+    }
+
+    fn previous_full_name(&self) -> Option<String> {
+        let changes = self.changes.as_ref()?;
+        let (owner, _) = self.repository.full_name.split_once('/')?;
+
+        if let Some(name) = &changes.repository {
+            return Some(format!("{}/{}", owner, name.name.from));
+        }
+
+        let previous = &changes.owner.as_ref()?.from;
+        let previous_owner = previous.user.as_ref().or(previous.organization.as_ref())?;
+
+        Some(format!("{}/{}", previous_owner.login, self.repository.name))
+    }
+}
+
+impl GithubEvent for GithubRepositoryEvent {
+    type WebhookEvent = WebhookRepositoryEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        match self.parse_action() {
+            Some(_) => None,
+            None => Some("repository action is not tracked"),
+        }
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let action = self
+            .parse_action()
+            .unwrap_or(WebhookRepositoryAction::Created);
+
+        WebhookRepositoryEvent {
+            action,
+            repo: self.repository.full_name.clone(),
+            previous_repo: self.moved_from(),
+            repo_url: self.repository.html_url.clone(),
+            sender: self.sender.as_ref().map(|s| s.login.clone()),
+        }
+    }
+}
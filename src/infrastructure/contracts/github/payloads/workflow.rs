@@ -1,4 +1,5 @@
 use crate::domain::webhook::events::workflow::WebhookWorkflowEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
 use crate::infrastructure::contracts::github::event_type::GithubEvent;
 use serde::Deserialize;
 use serde_json::Value;
@@ -86,6 +87,7 @@ impl GithubEvent for GithubWorkflowEvent {
         let run = self.workflow_run.as_ref();
 
         WebhookWorkflowEvent {
+            provider: WebhookProvider::Github,
             id: run.map(|r| r.id).unwrap_or(0),
             name: run.map(|r| r.name.clone()).unwrap_or_default(),
             run_number: 0,              // GitHub webhook не присылает, оставляем 0
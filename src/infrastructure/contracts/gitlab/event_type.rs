@@ -0,0 +1,53 @@
+use crate::domain::webhook::events::WebhookEvent;
+use std::str::FromStr;
+
+pub trait GitlabEvent {
+    type WebhookEvent: WebhookEvent + Send + Sync + 'static;
+
+    fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized;
+
+    /// Причина, по которой событие не нужно превращать в доменное
+    /// (удаление тега, комментарий не к MR и т.д.)
+    fn skip_reason(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent;
+}
+
+/// Флаги вебхука проекта, которые бот включает. В GitLab у хука нет списка
+/// событий — каждое включается своим полем с тем же именем.
+pub const GITLAB_REPOSITORY_HOOK_EVENTS: &[&str] = &[
+    "push_events",
+    "tag_push_events",
+    "merge_requests_events",
+    "note_events",
+    "pipeline_events",
+];
+
+#[derive(Debug, PartialEq)]
+pub enum GitlabEventType {
+    Push,
+    TagPush,
+    MergeRequest,
+    Note,
+    Pipeline,
+    Unknown(String),
+}
+
+impl FromStr for GitlabEventType {
+    type Err = ();
+
+    fn from_str(external_string: &str) -> Result<Self, Self::Err> {
+        match external_string {
+            "Push Hook" => Ok(GitlabEventType::Push),
+            "Tag Push Hook" => Ok(GitlabEventType::TagPush),
+            "Merge Request Hook" => Ok(GitlabEventType::MergeRequest),
+            "Note Hook" => Ok(GitlabEventType::Note),
+            "Pipeline Hook" => Ok(GitlabEventType::Pipeline),
+            other => Ok(GitlabEventType::Unknown(other.to_string())),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub struct GitlabHeaders;
+
+impl GitlabHeaders {
+    pub const EVENT: &'static str = "x-gitlab-event";
+    pub const TOKEN: &'static str = "x-gitlab-token";
+    // pub const EVENT_UUID: &'static str = "x-gitlab-event-uuid";
+    // pub const INSTANCE: &'static str = "x-gitlab-instance";
+}
@@ -0,0 +1,70 @@
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::contracts::gitlab::event_type::{GitlabEvent, GitlabEventType};
+use crate::infrastructure::contracts::gitlab::payloads::merge_request::GitlabMergeRequestEvent;
+use crate::infrastructure::contracts::gitlab::payloads::note::GitlabNoteEvent;
+use crate::infrastructure::contracts::gitlab::payloads::pipeline::GitlabPipelineEvent;
+use crate::infrastructure::contracts::gitlab::payloads::push::GitlabPushEvent;
+use crate::infrastructure::contracts::gitlab::payloads::tag_push::GitlabTagPushEvent;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GitlabWebhookEventMapperError {
+    #[error("Unsupported GitLab event type")]
+    UnsupportedEventType,
+    #[error("GitLab event skipped: {0}")]
+    Skipped(&'static str),
+    #[error("Invalid payload for GitLab event: {0}")]
+    InvalidPayload(String),
+}
+
+pub struct GitlabWebhookEventMapper;
+
+impl GitlabWebhookEventMapper {
+    pub fn map_to_domain_event(
+        gitlab_event_type: &GitlabEventType,
+        payload: serde_json::Value,
+    ) -> Result<Box<dyn WebhookEvent>, GitlabWebhookEventMapperError> {
+        match gitlab_event_type {
+            GitlabEventType::Push => Self::parse_to_domain::<GitlabPushEvent>(payload),
+            GitlabEventType::TagPush => Self::parse_to_domain::<GitlabTagPushEvent>(payload),
+            GitlabEventType::MergeRequest => {
+                Self::parse_to_domain::<GitlabMergeRequestEvent>(payload)
+            }
+            GitlabEventType::Note => Self::parse_to_domain::<GitlabNoteEvent>(payload),
+            GitlabEventType::Pipeline => Self::parse_to_domain::<GitlabPipelineEvent>(payload),
+
+            _ => {
+                tracing::warn!(
+                    "No mapping found for GitLab event type: {:?}",
+                    gitlab_event_type
+                );
+                Err(GitlabWebhookEventMapperError::UnsupportedEventType)
+            }
+        }
+    }
+
+    /// owner/name из `project.path_with_namespace` — есть во всех поддерживаемых событиях
+    pub fn extract_repository_full_name(payload: &serde_json::Value) -> Option<String> {
+        payload
+            .get("project")?
+            .get("path_with_namespace")?
+            .as_str()
+            .map(|s| s.to_string())
+    }
+
+    fn parse_to_domain<E>(
+        payload: serde_json::Value,
+    ) -> Result<Box<dyn WebhookEvent>, GitlabWebhookEventMapperError>
+    where
+        E: GitlabEvent,
+    {
+        let event = E::from_value(payload)
+            .map_err(|e| GitlabWebhookEventMapperError::InvalidPayload(e.to_string()))?;
+
+        if let Some(reason) = event.skip_reason() {
+            return Err(GitlabWebhookEventMapperError::Skipped(reason));
+        }
+
+        Ok(Box::new(event.to_webhook_event()))
+    }
+}
@@ -0,0 +1,4 @@
+pub mod event_type;
+pub mod headers;
+pub mod mapper;
+pub mod payloads;
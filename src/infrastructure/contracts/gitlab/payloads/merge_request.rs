@@ -0,0 +1,283 @@
+use crate::domain::webhook::events::pull_request::{
+    WebhookPullRequestEvent, WebhookPullRequestEventActionType,
+};
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitlab::event_type::GitlabEvent;
+use chrono::{DateTime, Local, NaiveDateTime};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabMergeRequestEvent {
+    pub user: GitlabUser,
+    pub project: GitlabProject,
+    pub object_attributes: GitlabMergeRequest,
+    #[serde(default)]
+    pub assignees: Vec<GitlabUser>,
+    #[serde(default)]
+    pub reviewers: Vec<GitlabUser>,
+    pub changes: Option<GitlabMergeRequestChanges>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabMergeRequest {
+    pub iid: u64,
+    pub title: String,
+    pub description: Option<String>,
+    pub url: String,
+
+    pub state: String,          // opened, closed, merged, locked
+    pub action: Option<String>, // open, close, reopen, update, approved, unapproved, merge
+    pub oldrev: Option<String>, // есть только если в MR запушили новые коммиты
+    #[serde(default)]
+    pub draft: bool,
+    pub merge_status: Option<String>, // can_be_merged, cannot_be_merged, unchecked
+
+    pub author_id: u64,
+    pub source_branch: String,
+    pub target_branch: String,
+    pub source: Option<GitlabMergeRequestProject>,
+    pub target: Option<GitlabMergeRequestProject>,
+    pub merge_commit_sha: Option<String>,
+    pub last_commit: Option<GitlabMergeRequestCommit>,
+
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabMergeRequestCommit {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabMergeRequestProject {
+    pub path_with_namespace: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabMergeRequestChanges {
+    pub reviewers: Option<GitlabChange<Vec<GitlabUser>>>,
+    pub draft: Option<GitlabChange<bool>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabChange<T> {
+    pub previous: Option<T>,
+    pub current: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabUser {
+    pub id: u64,
+    pub username: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabProject {
+    pub path_with_namespace: String,
+    pub web_url: String,
+}
+
+impl GitlabMergeRequestEvent {
+    /// Ревьюеры, которых добавили этим событием
+    fn added_reviewers(&self) -> Vec<&GitlabUser> {
+        let Some(change) = self.changes.as_ref().and_then(|c| c.reviewers.as_ref()) else {
+            return Vec::new();
+        };
+
+        let previous = change.previous.as_deref().unwrap_or_default();
+
+        change
+            .current
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|u| !previous.iter().any(|p| p.id == u.id))
+            .collect()
+    }
+
+    /// В Merge Request Hook есть только `author_id` — логин ищем среди
+    /// пользователей, которых хук присылает целиком
+    fn author_username(&self) -> Option<String> {
+        let author_id = self.object_attributes.author_id;
+
+        let changed_reviewers = self
+            .changes
+            .as_ref()
+            .and_then(|c| c.reviewers.as_ref())
+            .into_iter()
+            .flat_map(|change| change.previous.iter().chain(change.current.iter()))
+            .flatten();
+
+        std::iter::once(&self.user)
+            .chain(&self.assignees)
+            .chain(&self.reviewers)
+            .chain(changed_reviewers)
+            .find(|u| u.id == author_id)
+            .map(|u| u.username.clone())
+    }
+
+    fn action_type(&self) -> WebhookPullRequestEventActionType {
+        let mr = &self.object_attributes;
+
+        match mr.action.as_deref() {
+            Some("open") => WebhookPullRequestEventActionType::Opened,
+            Some("close") | Some("merge") => WebhookPullRequestEventActionType::Closed,
+            Some("reopen") => WebhookPullRequestEventActionType::Reopened,
+            Some("update") => {
+                if let Some(draft) = self.changes.as_ref().and_then(|c| c.draft.as_ref()) {
+                    return match draft.current {
+                        Some(true) => WebhookPullRequestEventActionType::ConvertedToDraft,
+                        _ => WebhookPullRequestEventActionType::ReadyForReview,
+                    };
+                }
+
+                if !self.added_reviewers().is_empty() {
+                    return WebhookPullRequestEventActionType::ReviewRequested;
+                }
+
+                if mr.oldrev.is_some() {
+                    return WebhookPullRequestEventActionType::Synchronize;
+                }
+
+                WebhookPullRequestEventActionType::Edited
+            }
+            _ => WebhookPullRequestEventActionType::Unknown,
+        }
+    }
+}
+
+impl GitlabEvent for GitlabMergeRequestEvent {
+    type WebhookEvent = WebhookPullRequestEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let mr = &self.object_attributes;
+        let action = self.action_type();
+        let merged = mr.state == "merged" || mr.action.as_deref() == Some("merge");
+
+        let requested_reviewer = if action == WebhookPullRequestEventActionType::ReviewRequested {
+            self.added_reviewers().first().map(|u| u.username.clone())
+        } else {
+            None
+        };
+
+        let source_repo = mr
+            .source
+            .as_ref()
+            .map(|p| p.path_with_namespace.as_str())
+            .unwrap_or(self.project.path_with_namespace.as_str());
+        let target_repo = mr
+            .target
+            .as_ref()
+            .map(|p| p.path_with_namespace.as_str())
+            .unwrap_or(self.project.path_with_namespace.as_str());
+
+        // Приводим к значениям GitHub, которые понимают слушатели
+        let mergeable_state = match mr.merge_status.as_deref() {
+            Some("can_be_merged") => Some("clean".to_string()),
+            Some("cannot_be_merged") => Some("dirty".to_string()),
+            Some(_) => Some("unknown".to_string()),
+            None => None,
+        };
+
+        WebhookPullRequestEvent {
+            provider: WebhookProvider::Gitlab,
+            source: self.user.username.clone(),
+            author: self.author_username(),
+            repo: self.project.path_with_namespace.clone(),
+            repo_url: Some(self.project.web_url.clone()),
+            title: mr.title.clone(),
+            body: mr.description.clone(),
+            number: mr.iid,
+            action,
+            merged,
+            merged_by: merged.then(|| self.user.username.clone()),
+            draft: mr.draft,
+            state: if mr.state == "opened" {
+                "open".to_string()
+            } else {
+                "closed".to_string()
+            },
+            head_ref: mr.source_branch.clone(),
+            base_ref: mr.target_branch.clone(),
+            head_repo: format!("{}:{}", source_repo, mr.source_branch),
+            base_repo: format!("{}:{}", target_repo, mr.target_branch),
+            pr_url: Some(mr.url.clone()),
+            merge_commit: mr.merge_commit_sha.clone(),
+            head_sha: mr.last_commit.as_ref().map(|c| c.id.clone()),
+            assignees: self.assignees.iter().map(|u| u.username.clone()).collect(),
+            created_at: format_datetime(&mr.created_at),
+            updated_at: format_datetime(&mr.updated_at),
+            merged_at: merged.then(|| format_datetime(&mr.updated_at)),
+            // GitLab не присылает статистику изменений в хуке
+            commits: 0,
+            additions: 0,
+            deletions: 0,
+            changed_files: 0,
+            requested_reviewer,
+            requested_reviewers: self.reviewers.iter().map(|u| u.username.clone()).collect(),
+            mergeable_state,
+        }
+    }
+}
+
+/// GitLab отдаёт даты то в RFC 3339, то в формате "2024-01-01 10:00:00 UTC"
+fn format_datetime(ts: &str) -> String {
+    DateTime::parse_from_rfc3339(ts)
+        .map(|dt| dt.with_timezone(&Local))
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S UTC")
+                .map(|dt| dt.and_utc().with_timezone(&Local))
+        })
+        .map(|dt| dt.format("%d.%m.%Y %H:%M:%S").to_string())
+        .unwrap_or_else(|_| ts.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(assignees: Value) -> GitlabMergeRequestEvent {
+        GitlabMergeRequestEvent::from_value(json!({
+            "user": { "id": 2, "username": "reviewer" },
+            "project": { "path_with_namespace": "group/app", "web_url": "https://gitlab.com/group/app" },
+            "object_attributes": {
+                "iid": 7,
+                "title": "Fix login",
+                "url": "https://gitlab.com/group/app/-/merge_requests/7",
+                "state": "opened",
+                "action": "update",
+                "author_id": 1,
+                "source_branch": "fix-login",
+                "target_branch": "main",
+                "created_at": "2026-10-18 10:00:00 UTC",
+                "updated_at": "2026-10-18 11:00:00 UTC"
+            },
+            "assignees": assignees
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn resolves_author_from_assignees() {
+        let event = event(json!([{ "id": 1, "username": "author" }]));
+
+        assert_eq!(event.to_webhook_event().author.as_deref(), Some("author"));
+    }
+
+    #[test]
+    fn leaves_unknown_author_empty() {
+        let event = event(json!([]));
+
+        assert_eq!(event.to_webhook_event().author, None);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod merge_request;
+pub mod note;
+pub mod pipeline;
+pub mod push;
+pub mod tag_push;
@@ -0,0 +1,79 @@
+use crate::domain::webhook::events::pr_comment::WebhookPrCommentEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitlab::event_type::GitlabEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Payload for `Note Hook` events. Нас интересуют только комментарии к MR
+#[derive(Debug, Deserialize)]
+pub struct GitlabNoteEvent {
+    pub user: GitlabNoteUser,
+    pub project: GitlabNoteProject,
+    pub object_attributes: GitlabNote,
+    pub merge_request: Option<GitlabNoteMergeRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabNote {
+    pub note: String,
+    pub noteable_type: String, // MergeRequest, Issue, Commit, Snippet
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabNoteMergeRequest {
+    pub iid: u64,
+    pub title: String,
+    pub author_id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabNoteUser {
+    pub id: u64,
+    pub username: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabNoteProject {
+    pub path_with_namespace: String,
+}
+
+impl GitlabEvent for GitlabNoteEvent {
+    type WebhookEvent = WebhookPrCommentEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        if self.object_attributes.noteable_type != "MergeRequest" || self.merge_request.is_none() {
+            return Some("note is not attached to a merge request");
+        }
+
+        None
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let mr = self.merge_request.as_ref();
+
+        // В Note Hook есть только author_id автора MR, логин известен,
+        // только если комментирует сам автор
+        let pr_author = mr
+            .filter(|mr| mr.author_id == self.user.id)
+            .map(|_| self.user.username.clone());
+
+        WebhookPrCommentEvent {
+            provider: WebhookProvider::Gitlab,
+            commenter: self.user.username.clone(),
+            pr_author,
+            repo: self.project.path_with_namespace.clone(),
+            pr_number: mr.map(|mr| mr.iid).unwrap_or(0),
+            pr_title: mr.map(|mr| mr.title.clone()).unwrap_or_default(),
+            comment_body: self.object_attributes.note.clone(),
+            comment_url: self.object_attributes.url.clone(),
+        }
+    }
+}
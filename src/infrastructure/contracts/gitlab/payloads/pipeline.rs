@@ -0,0 +1,95 @@
+use crate::domain::webhook::events::workflow::WebhookWorkflowEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitlab::event_type::GitlabEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabPipelineEvent {
+    pub object_attributes: GitlabPipeline,
+    pub user: Option<GitlabUser>,
+    pub project: GitlabProject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabPipeline {
+    pub id: u64,
+    pub iid: Option<u64>,
+    pub name: Option<String>,
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    pub sha: String,
+    pub status: String, // created, pending, running, success, failed, canceled, skipped, manual
+    pub created_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabUser {
+    pub username: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabProject {
+    pub path_with_namespace: String,
+    pub web_url: String,
+}
+
+impl GitlabPipelineEvent {
+    /// Переводим статус пайплайна GitLab в пару status/conclusion как у GitHub Actions,
+    /// чтобы существующие слушатели (ci_fail_dm и т.д.) работали без изменений
+    fn status_and_conclusion(&self) -> (String, Option<String>) {
+        let conclusion = match self.object_attributes.status.as_str() {
+            "success" => "success",
+            "failed" => "failure",
+            "canceled" => "cancelled",
+            "skipped" => "skipped",
+            "pending" | "created" | "waiting_for_resource" | "preparing" | "scheduled" => {
+                return ("queued".to_string(), None);
+            }
+            _ => return ("in_progress".to_string(), None),
+        };
+
+        ("completed".to_string(), Some(conclusion.to_string()))
+    }
+}
+
+impl GitlabEvent for GitlabPipelineEvent {
+    type WebhookEvent = WebhookWorkflowEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let pipeline = &self.object_attributes;
+        let (status, conclusion) = self.status_and_conclusion();
+
+        WebhookWorkflowEvent {
+            provider: WebhookProvider::Gitlab,
+            id: pipeline.id,
+            name: pipeline
+                .name
+                .clone()
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| "Pipeline".to_string()),
+            run_number: pipeline.iid.unwrap_or(pipeline.id),
+            head_branch: pipeline.ref_field.clone(),
+            head_sha: pipeline.sha.clone(),
+            status,
+            conclusion,
+            html_url: Some(pipeline.url.clone().unwrap_or_else(|| {
+                format!("{}/-/pipelines/{}", self.project.web_url, pipeline.id)
+            })),
+            actor: self.user.as_ref().map(|u| u.username.clone()),
+            repo: self.project.path_with_namespace.clone(),
+            repo_url: Some(self.project.web_url.clone()),
+            created_at: pipeline.created_at.clone(),
+            updated_at: pipeline.finished_at.clone(),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::domain::webhook::entities::commit::WebhookCommit;
+use crate::domain::webhook::events::push::WebhookPushEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitlab::event_type::GitlabEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// GitLab присылает нули вместо sha, если ветку создали или удалили
+pub const GITLAB_BLANK_SHA: &str = "0000000000000000000000000000000000000000";
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabPushEvent {
+    #[serde(rename = "ref")]
+    pub ref_field: String, // "refs/heads/main"
+    pub before: String,
+    pub after: String,
+
+    pub user_username: String,
+
+    #[serde(default)]
+    pub commits: Vec<GitlabCommit>,
+
+    pub project: GitlabProject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabProject {
+    pub path_with_namespace: String,
+    pub web_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabCommit {
+    pub id: String,
+    pub message: String,
+    pub timestamp: Option<String>,
+    pub url: String,
+    pub author: Option<GitlabCommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabCommitAuthor {
+    pub name: Option<String>,
+}
+
+impl GitlabEvent for GitlabPushEvent {
+    type WebhookEvent = WebhookPushEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let commits = self
+            .commits
+            .iter()
+            .map(|c| WebhookCommit {
+                id: c.id.clone(),
+                short_id: c.id.chars().take(7).collect(),
+                message: c.message.clone(),
+                author: c
+                    .author
+                    .as_ref()
+                    .and_then(|a| a.name.clone())
+                    .unwrap_or_else(|| "unknown".to_string()),
+                url: c.url.clone(),
+                timestamp: c.timestamp.clone(),
+            })
+            .collect();
+
+        let branch = self
+            .ref_field
+            .strip_prefix("refs/heads/")
+            .unwrap_or(&self.ref_field);
+
+        let created = self.before == GITLAB_BLANK_SHA;
+        let deleted = self.after == GITLAB_BLANK_SHA;
+
+        // У GitLab нет готовой ссылки на сравнение, собираем сами
+        let compare_url = if created || deleted {
+            None
+        } else {
+            Some(format!(
+                "{}/-/compare/{}...{}",
+                self.project.web_url, self.before, self.after
+            ))
+        };
+
+        WebhookPushEvent {
+            provider: WebhookProvider::Gitlab,
+            source: self.user_username.clone(),
+            repo: self.project.path_with_namespace.clone(),
+            repo_url: Some(self.project.web_url.clone()),
+            ref_field: branch.to_string(),
+            before: self.before.clone(),
+            after: self.after.clone(),
+            compare_url,
+            created,
+            deleted,
+            forced: false, // GitLab не сообщает о force-push
+            commits,
+        }
+    }
+}
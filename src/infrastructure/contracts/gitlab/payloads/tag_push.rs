@@ -0,0 +1,72 @@
+use crate::domain::webhook::events::release::WebhookReleaseEvent;
+use crate::domain::webhook::value_objects::webhook_provider::WebhookProvider;
+use crate::infrastructure::contracts::gitlab::event_type::GitlabEvent;
+use crate::infrastructure::contracts::gitlab::payloads::push::GITLAB_BLANK_SHA;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabTagPushEvent {
+    #[serde(rename = "ref")]
+    pub ref_field: String, // "refs/tags/v1.0.0"
+    pub after: String,
+    pub checkout_sha: Option<String>,
+    pub message: Option<String>, // сообщение аннотированного тега
+
+    pub user_username: String,
+
+    pub project: GitlabProject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitlabProject {
+    pub path_with_namespace: String,
+    pub web_url: String,
+}
+
+impl GitlabEvent for GitlabTagPushEvent {
+    type WebhookEvent = WebhookReleaseEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        if self.after == GITLAB_BLANK_SHA {
+            return Some("tag deleted");
+        }
+
+        None
+    }
+
+    fn to_webhook_event(&self) -> Self::WebhookEvent {
+        let tag_name = self
+            .ref_field
+            .strip_prefix("refs/tags/")
+            .unwrap_or(&self.ref_field)
+            .to_string();
+
+        WebhookReleaseEvent {
+            provider: WebhookProvider::Gitlab,
+            id: 0, // у тега нет отдельного id
+            target_commitish: self
+                .checkout_sha
+                .clone()
+                .unwrap_or_else(|| self.after.clone()),
+            name: Some(tag_name.clone()),
+            body: self.message.clone().filter(|m| !m.trim().is_empty()),
+            draft: false,
+            prerelease: false,
+            created_at: None,
+            published_at: None,
+            html_url: Some(format!("{}/-/tags/{}", self.project.web_url, tag_name)),
+            author: Some(self.user_username.clone()),
+            repo: self.project.path_with_namespace.clone(),
+            repo_url: Some(self.project.web_url.clone()),
+            tag_name,
+        }
+    }
+}
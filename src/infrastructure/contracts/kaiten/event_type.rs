@@ -0,0 +1,40 @@
+use crate::domain::webhook::events::WebhookEvent;
+use std::str::FromStr;
+
+pub trait KaitenEvent {
+    type WebhookEvent: WebhookEvent + Send + Sync + 'static;
+
+    fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized;
+
+    /// Причина, по которой событие не нужно превращать в доменное
+    /// (карточку изменили, но колонка осталась прежней и т.д.)
+    fn skip_reason(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// `connection_id` — подключение, на адрес которого пришёл вебхук
+    fn to_webhook_event(&self, connection_id: Option<i32>) -> Self::WebhookEvent;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum KaitenEventType {
+    CardUpdate,
+    CommentAdd,
+    CardMemberAdd,
+    Unknown(String),
+}
+
+impl FromStr for KaitenEventType {
+    type Err = ();
+
+    fn from_str(external_string: &str) -> Result<Self, Self::Err> {
+        match external_string {
+            "card:update" => Ok(KaitenEventType::CardUpdate),
+            "comment:add" => Ok(KaitenEventType::CommentAdd),
+            "card_member:add" => Ok(KaitenEventType::CardMemberAdd),
+            other => Ok(KaitenEventType::Unknown(other.to_string())),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::domain::webhook::events::WebhookEvent;
+use crate::infrastructure::contracts::kaiten::event_type::{KaitenEvent, KaitenEventType};
+use crate::infrastructure::contracts::kaiten::payloads::card_member::KaitenCardMemberAddEvent;
+use crate::infrastructure::contracts::kaiten::payloads::card_update::KaitenCardUpdateEvent;
+use crate::infrastructure::contracts::kaiten::payloads::comment::KaitenCommentAddEvent;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum KaitenWebhookEventMapperError {
+    #[error("Unsupported Kaiten event type")]
+    UnsupportedEventType,
+    #[error("Kaiten event skipped: {0}")]
+    Skipped(&'static str),
+    #[error("Invalid payload for Kaiten event: {0}")]
+    InvalidPayload(String),
+}
+
+pub struct KaitenWebhookEventMapper;
+
+impl KaitenWebhookEventMapper {
+    /// `data` — содержимое одноимённого поля конверта `{ "event": ..., "data": ... }`
+    pub fn map_to_domain_event(
+        kaiten_event_type: &KaitenEventType,
+        data: serde_json::Value,
+        connection_id: Option<i32>,
+    ) -> Result<Box<dyn WebhookEvent>, KaitenWebhookEventMapperError> {
+        match kaiten_event_type {
+            KaitenEventType::CardUpdate => {
+                Self::parse_to_domain::<KaitenCardUpdateEvent>(data, connection_id)
+            }
+            KaitenEventType::CommentAdd => {
+                Self::parse_to_domain::<KaitenCommentAddEvent>(data, connection_id)
+            }
+            KaitenEventType::CardMemberAdd => {
+                Self::parse_to_domain::<KaitenCardMemberAddEvent>(data, connection_id)
+            }
+
+            _ => Err(KaitenWebhookEventMapperError::UnsupportedEventType),
+        }
+    }
+
+    fn parse_to_domain<E>(
+        data: serde_json::Value,
+        connection_id: Option<i32>,
+    ) -> Result<Box<dyn WebhookEvent>, KaitenWebhookEventMapperError>
+    where
+        E: KaitenEvent,
+    {
+        let event = E::from_value(data)
+            .map_err(|e| KaitenWebhookEventMapperError::InvalidPayload(e.to_string()))?;
+
+        if let Some(reason) = event.skip_reason() {
+            return Err(KaitenWebhookEventMapperError::Skipped(reason));
+        }
+
+        Ok(Box::new(event.to_webhook_event(connection_id)))
+    }
+}
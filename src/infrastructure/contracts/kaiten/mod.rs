@@ -0,0 +1,3 @@
+pub mod event_type;
+pub mod mapper;
+pub mod payloads;
@@ -0,0 +1,41 @@
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::domain::webhook::events::task_card::{WebhookTaskCardAction, WebhookTaskCardEvent};
+use crate::infrastructure::contracts::kaiten::event_type::KaitenEvent;
+use crate::infrastructure::contracts::kaiten::payloads::{KaitenWebhookCard, KaitenWebhookUser};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// `data` события `card_member:add`
+#[derive(Debug, Deserialize)]
+pub struct KaitenCardMemberAddEvent {
+    pub card: KaitenWebhookCard,
+    pub member: KaitenWebhookUser,
+    pub author: Option<KaitenWebhookUser>,
+}
+
+impl KaitenEvent for KaitenCardMemberAddEvent {
+    type WebhookEvent = WebhookTaskCardEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn to_webhook_event(&self, connection_id: Option<i32>) -> Self::WebhookEvent {
+        WebhookTaskCardEvent {
+            tracker: TaskTrackerKind::Kaiten,
+            connection_id,
+            action: WebhookTaskCardAction::MemberAdded,
+            task_id: TaskId(self.card.id.to_string()),
+            card_title: self.card.title.clone(),
+            column: self.card.column_label(),
+            column_id: self.card.column_id.map(|id| id.to_string()),
+            comment: None,
+            member: self.member.display_name(),
+            actor: self.author.as_ref().and_then(|a| a.display_name()),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::domain::webhook::events::task_card::{WebhookTaskCardAction, WebhookTaskCardEvent};
+use crate::infrastructure::contracts::kaiten::event_type::KaitenEvent;
+use crate::infrastructure::contracts::kaiten::payloads::{KaitenWebhookCard, KaitenWebhookUser};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// `data` события `card:update`. В `old` Kaiten кладёт прежние значения
+/// изменённых полей — по нему понимаем, что карточку перенесли в другую колонку
+#[derive(Debug, Deserialize)]
+pub struct KaitenCardUpdateEvent {
+    pub card: KaitenWebhookCard,
+    pub old: Option<KaitenCardUpdateOld>,
+    pub author: Option<KaitenWebhookUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KaitenCardUpdateOld {
+    pub column_id: Option<u64>,
+}
+
+impl KaitenEvent for KaitenCardUpdateEvent {
+    type WebhookEvent = WebhookTaskCardEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        let old_column_id = self.old.as_ref().and_then(|old| old.column_id);
+
+        match (old_column_id, self.card.column_id) {
+            (Some(old), Some(new)) if old != new => None,
+            _ => Some("card was updated without changing its column"),
+        }
+    }
+
+    fn to_webhook_event(&self, connection_id: Option<i32>) -> Self::WebhookEvent {
+        WebhookTaskCardEvent {
+            tracker: TaskTrackerKind::Kaiten,
+            connection_id,
+            action: WebhookTaskCardAction::Moved,
+            task_id: TaskId(self.card.id.to_string()),
+            card_title: self.card.title.clone(),
+            column: self.card.column_label(),
+            column_id: self.card.column_id.map(|id| id.to_string()),
+            comment: None,
+            member: None,
+            actor: self.author.as_ref().and_then(|a| a.display_name()),
+        }
+    }
+}
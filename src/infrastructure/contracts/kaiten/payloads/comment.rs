@@ -0,0 +1,54 @@
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::domain::webhook::events::task_card::{WebhookTaskCardAction, WebhookTaskCardEvent};
+use crate::infrastructure::contracts::kaiten::event_type::KaitenEvent;
+use crate::infrastructure::contracts::kaiten::payloads::{KaitenWebhookCard, KaitenWebhookUser};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// `data` события `comment:add`
+#[derive(Debug, Deserialize)]
+pub struct KaitenCommentAddEvent {
+    pub card: KaitenWebhookCard,
+    pub comment: KaitenWebhookComment,
+    pub author: Option<KaitenWebhookUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KaitenWebhookComment {
+    pub text: String,
+}
+
+impl KaitenEvent for KaitenCommentAddEvent {
+    type WebhookEvent = WebhookTaskCardEvent;
+
+    fn from_value(value: Value) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        serde_json::from_value(value)
+    }
+
+    fn skip_reason(&self) -> Option<&'static str> {
+        if self.comment.text.trim().is_empty() {
+            return Some("comment is empty");
+        }
+
+        None
+    }
+
+    fn to_webhook_event(&self, connection_id: Option<i32>) -> Self::WebhookEvent {
+        WebhookTaskCardEvent {
+            tracker: TaskTrackerKind::Kaiten,
+            connection_id,
+            action: WebhookTaskCardAction::Commented,
+            task_id: TaskId(self.card.id.to_string()),
+            card_title: self.card.title.clone(),
+            column: self.card.column_label(),
+            column_id: self.card.column_id.map(|id| id.to_string()),
+            comment: Some(self.comment.text.clone()),
+            member: None,
+            actor: self.author.as_ref().and_then(|a| a.display_name()),
+        }
+    }
+}
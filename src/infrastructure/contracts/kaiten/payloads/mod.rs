@@ -0,0 +1,41 @@
+pub mod card_member;
+pub mod card_update;
+pub mod comment;
+
+use serde::Deserialize;
+
+/// Карточка в том виде, в каком её присылает вебхук Kaiten
+#[derive(Debug, Deserialize)]
+pub struct KaitenWebhookCard {
+    pub id: u64,
+    pub title: String,
+    pub column_id: Option<u64>,
+    pub column: Option<KaitenWebhookColumn>,
+}
+
+impl KaitenWebhookCard {
+    /// Название колонки, если Kaiten его прислал, иначе её id
+    pub fn column_label(&self) -> Option<String> {
+        self.column
+            .as_ref()
+            .map(|column| column.title.clone())
+            .or_else(|| self.column_id.map(|id| id.to_string()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KaitenWebhookColumn {
+    pub title: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KaitenWebhookUser {
+    pub full_name: Option<String>,
+    pub username: Option<String>,
+}
+
+impl KaitenWebhookUser {
+    pub fn display_name(&self) -> Option<String> {
+        self.full_name.clone().or_else(|| self.username.clone())
+    }
+}
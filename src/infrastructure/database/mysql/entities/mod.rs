@@ -2,21 +2,32 @@
 
 pub mod prelude;
 
+pub mod commit_checks;
+pub mod commit_ci_statuses;
 pub mod digest_subscriptions;
+pub mod environment_deployments;
 pub mod health_pings;
+pub mod merged_branches;
 pub mod notification_log;
 pub mod pending_notifications;
 pub mod pr_reviews;
+pub mod pull_request_task_links;
 pub mod release_plan_repositories;
 pub mod release_plans;
 pub mod repositories;
 pub mod repository_pull_requests;
 pub mod repository_task_tracker;
+pub mod repository_task_tracker_rules;
 pub mod roles;
+pub mod slack_messages;
+pub mod task_tracker_connections;
+pub mod task_tracker_moves;
 pub mod user_connection_repositories;
 pub mod user_has_roles;
 pub mod user_notifications;
 pub mod user_preferences;
+pub mod user_slack_accounts;
 pub mod user_social_accounts;
 pub mod user_version_control_accounts;
 pub mod users;
+pub mod webhook_deliveries;
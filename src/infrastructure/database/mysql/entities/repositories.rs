@@ -15,6 +15,15 @@ pub struct Model {
     pub updated_at: DateTimeUtc,
     pub social_chat_id: Option<i64>,
     pub notifications_chat_id: Option<i64>,
+    pub version_control_type: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub webhook_secret: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub previous_webhook_secret: Option<String>,
+    pub webhook_secret_rotated_at: Option<DateTimeUtc>,
+    pub ref_events_filter: Option<String>,
+    pub is_archived: i8,
+    pub slack_channel_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -9,10 +9,16 @@ pub struct Model {
     pub id: i32,
     #[sea_orm(unique)]
     pub repository_id: i32,
-    pub space_id: i32,
-    pub qa_column_id: i32,
+    pub space_id: String,
+    pub qa_column_id: String,
     pub extract_pattern_regexp: String,
     pub path_to_card: String,
+    pub kind: String,
+    pub connection_id: Option<i32>,
+    pub require_task_id: bool,
+    pub qa_passed_column_id: Option<String>,
+    pub qa_failed_column_id: Option<String>,
+    pub issue_column_id: Option<String>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -27,6 +33,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Repositories,
+    #[sea_orm(
+        belongs_to = "super::task_tracker_connections::Entity",
+        from = "Column::ConnectionId",
+        to = "super::task_tracker_connections::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    TaskTrackerConnections,
 }
 
 impl Related<super::repositories::Entity> for Entity {
@@ -35,4 +49,10 @@ impl Related<super::repositories::Entity> for Entity {
     }
 }
 
+impl Related<super::task_tracker_connections::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskTrackerConnections.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
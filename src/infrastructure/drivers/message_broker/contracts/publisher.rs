@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use erased_serde::Serialize;
 use std::fmt;
 
+#[derive(Debug)]
 pub enum MessageBrokerPublisherPublishError {
     Serialization(String),
     PublishCreation(String),
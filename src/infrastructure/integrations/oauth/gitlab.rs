@@ -0,0 +1,66 @@
+use crate::domain::auth::ports::oauth_client::{
+    OAuthClient, OAuthClientExchangeCodeError, OAuthClientExchangeCodeResponse,
+};
+use async_trait::async_trait;
+use reqwest::Client;
+
+/// GitLab требует тот же `redirect_uri`, что был в ссылке авторизации
+pub struct GitlabOAuthClient {
+    base: String,
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    client: Client,
+}
+
+impl GitlabOAuthClient {
+    pub fn new(
+        base: String,
+        client_id: String,
+        client_secret: String,
+        redirect_uri: String,
+    ) -> Self {
+        Self {
+            base,
+            client_id,
+            client_secret,
+            redirect_uri,
+            client: Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl OAuthClient for GitlabOAuthClient {
+    async fn exchange_code(
+        &self,
+        code: &str,
+    ) -> Result<OAuthClientExchangeCodeResponse, OAuthClientExchangeCodeError> {
+        tracing::debug!("Starting GitLab OAuth code exchange");
+
+        let resp = self
+            .client
+            .post(format!("{}/oauth/token", self.base))
+            .header("Accept", "application/json")
+            .form(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("code", code),
+                ("grant_type", "authorization_code"),
+                ("redirect_uri", self.redirect_uri.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| OAuthClientExchangeCodeError::Transport(e.to_string()))?;
+
+        let body_text = resp
+            .text()
+            .await
+            .map_err(|e| OAuthClientExchangeCodeError::Transport(e.to_string()))?;
+
+        serde_json::from_str::<OAuthClientExchangeCodeResponse>(&body_text).map_err(|e| {
+            tracing::error!(error = %e, "Failed to parse GitLab OAuth response");
+            OAuthClientExchangeCodeError::Transport(e.to_string())
+        })
+    }
+}
@@ -0,0 +1,774 @@
+use crate::domain::task::ports::task_tracker_client::{
+    TaskTrackerAssignee, TaskTrackerBoard, TaskTrackerCard, TaskTrackerCardDraft,
+    TaskTrackerClient, TaskTrackerClientGetCardError, TaskTrackerClientListError,
+    TaskTrackerClientMoveToColumnError, TaskTrackerClientWriteError, TaskTrackerColumn,
+    TaskTrackerSpace,
+};
+use crate::domain::task::value_objects::task_id::TaskId;
+use async_trait::async_trait;
+use graphql_client::GraphQLQuery;
+use reqwest::Client;
+use serde::Deserialize;
+
+/// Поле доски, значения которого считаются колонками
+const STATUS_FIELD: &str = "Status";
+
+#[allow(clippy::upper_case_acronyms)]
+type URI = String;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/get_issue.graphql"
+)]
+pub struct GithubProjectsIssue;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/get_projects.graphql"
+)]
+pub struct GithubProjectsList;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/get_project.graphql"
+)]
+pub struct GithubProjectsProject;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/get_project_status_field.graphql"
+)]
+pub struct GithubProjectsStatusField;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/get_project_items.graphql"
+)]
+pub struct GithubProjectsItems;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/get_repository.graphql"
+)]
+pub struct GithubProjectsRepository;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/get_user.graphql"
+)]
+pub struct GithubProjectsUser;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/create_issue.graphql"
+)]
+pub struct GithubProjectsCreateIssue;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/add_assignees.graphql"
+)]
+pub struct GithubProjectsAddAssignees;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/add_project_item.graphql"
+)]
+pub struct GithubProjectsAddItem;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/update_item_status.graphql"
+)]
+pub struct GithubProjectsUpdateItemStatus;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/infrastructure/integrations/version_control/github/graphql/schema.docs.graphql",
+    query_path = "src/infrastructure/integrations/task_tracker/github_projects/graphql/queries/add_comment.graphql"
+)]
+pub struct GithubProjectsAddComment;
+
+/// Issue с его элементами на досках
+struct GithubIssue {
+    id: String,
+    title: String,
+    url: String,
+    /// (id элемента, id доски)
+    project_items: Vec<(String, String)>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GithubProjectsGraphQLResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GithubProjectsGraphQLError>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GithubProjectsGraphQLError {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    message: String,
+}
+
+enum GithubProjectsRequestError {
+    NotFound,
+    Other(String),
+}
+
+impl std::fmt::Display for GithubProjectsRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GithubProjectsRequestError::NotFound => write!(f, "Not found"),
+            GithubProjectsRequestError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Задача в GitHub Issues: `owner/repo#123`, `#123` или просто `123`.
+/// Без репозитория берётся репозиторий из адреса подключения — номера из PR
+/// приходят уже с репозиторием PR, см. `TaskTrackerKind::qualify_task_id`
+struct GithubIssueRef {
+    owner: String,
+    name: String,
+    number: u64,
+}
+
+/// Адрес репозитория по умолчанию, например `https://github.com/acme/app`:
+/// из него берутся владелец досок и репозиторий для ссылок вида `#123`,
+/// к нему же дописывается `path_to_card`
+pub struct GithubProjectsClientBase(pub String);
+
+pub struct GithubProjectsClientToken(pub String);
+
+pub struct GithubProjectsClient {
+    base: GithubProjectsClientBase,
+    graphql_url: String,
+    owner: String,
+    repository: Option<String>,
+    token: GithubProjectsClientToken,
+    client: Client,
+}
+
+impl GithubProjectsClient {
+    pub fn new(base: GithubProjectsClientBase, token: GithubProjectsClientToken) -> Self {
+        let base = base.0.trim_end_matches('/').to_string();
+
+        let (origin, path) = match base.split_once("://") {
+            Some((scheme, rest)) => match rest.split_once('/') {
+                Some((host, path)) => (format!("{}://{}", scheme, host), path.to_string()),
+                None => (base.clone(), String::new()),
+            },
+            None => (base.clone(), String::new()),
+        };
+
+        // GitHub Enterprise Server отдаёт GraphQL на своём хосте
+        let graphql_url = if origin.ends_with("://github.com") {
+            "https://api.github.com/graphql".to_string()
+        } else {
+            format!("{}/api/graphql", origin)
+        };
+
+        let mut segments = path.split('/').filter(|s| !s.is_empty());
+        let owner = segments.next().unwrap_or_default().to_string();
+        let repository = segments.next().map(str::to_string);
+
+        Self {
+            base: GithubProjectsClientBase(base),
+            graphql_url,
+            owner,
+            repository,
+            token,
+            client: Client::new(),
+        }
+    }
+
+    fn parse_issue_ref(&self, task_id: &TaskId) -> Option<GithubIssueRef> {
+        let raw = task_id.0.trim();
+        let (repository, number) = raw.rsplit_once('#').unwrap_or(("", raw));
+        let number = number.parse::<u64>().ok()?;
+
+        let (owner, name) = match repository.split_once('/') {
+            Some((owner, name)) => (owner.to_string(), name.to_string()),
+            None if repository.is_empty() => (self.owner.clone(), self.repository.clone()?),
+            None => return None,
+        };
+
+        Some(GithubIssueRef {
+            owner,
+            name,
+            number,
+        })
+    }
+
+    /// Обратное к `parse_issue_ref`: задачи репозитория по умолчанию — просто номер,
+    /// как их вытаскивает паттерн `#(\d+)`
+    fn task_id(&self, repository: &str, number: u64) -> TaskId {
+        let is_default = self.repository.as_ref().is_some_and(|name| {
+            repository.eq_ignore_ascii_case(&format!("{}/{}", self.owner, name))
+        });
+
+        if is_default {
+            TaskId(number.to_string())
+        } else {
+            TaskId(format!("{}#{}", repository, number))
+        }
+    }
+
+    async fn request<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData, GithubProjectsRequestError> {
+        let body = Q::build_query(variables);
+
+        tracing::debug!(operation = %body.operation_name, "GitHub Projects API request");
+
+        let resp = self
+            .client
+            .post(&self.graphql_url)
+            .bearer_auth(&self.token.0)
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| GithubProjectsRequestError::Other(e.to_string()))?;
+
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| GithubProjectsRequestError::Other(e.to_string()))?;
+
+        tracing::debug!(status = %status, body_len = text.len(), "GitHub Projects API response");
+
+        let parsed: GithubProjectsGraphQLResponse<Q::ResponseData> = serde_json::from_str(&text)
+            .map_err(|e| {
+                GithubProjectsRequestError::Other(format!("GitHub API {}: {}", status, e))
+            })?;
+
+        if let Some(error) = parsed.errors.first() {
+            if error.kind.as_deref() == Some("NOT_FOUND") {
+                return Err(GithubProjectsRequestError::NotFound);
+            }
+            return Err(GithubProjectsRequestError::Other(error.message.clone()));
+        }
+
+        parsed.data.ok_or_else(|| {
+            GithubProjectsRequestError::Other(format!("GitHub API {}: empty data", status))
+        })
+    }
+
+    async fn get_issue(&self, task_id: &TaskId) -> Result<GithubIssue, GithubProjectsRequestError> {
+        let issue_ref = self.parse_issue_ref(task_id).ok_or_else(|| {
+            GithubProjectsRequestError::Other(format!(
+                "Invalid GitHub issue reference: {}",
+                task_id
+            ))
+        })?;
+
+        let issue = self
+            .request::<GithubProjectsIssue>(github_projects_issue::Variables {
+                owner: issue_ref.owner,
+                name: issue_ref.name,
+                number: issue_ref.number as i64,
+            })
+            .await?
+            .repository
+            .and_then(|repository| repository.issue)
+            .ok_or(GithubProjectsRequestError::NotFound)?;
+
+        Ok(GithubIssue {
+            id: issue.id,
+            title: issue.title,
+            url: issue.url,
+            project_items: issue
+                .project_items
+                .and_then(|items| items.nodes)
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .map(|item| (item.id, item.project.id))
+                .collect(),
+        })
+    }
+
+    /// Кладёт issue на доску и возвращает id элемента
+    async fn add_to_project(
+        &self,
+        project_id: &str,
+        content_id: &str,
+    ) -> Result<String, GithubProjectsRequestError> {
+        self.request::<GithubProjectsAddItem>(github_projects_add_item::Variables {
+            project_id: project_id.to_string(),
+            content_id: content_id.to_string(),
+        })
+        .await?
+        .add_project_v2_item_by_id
+        .and_then(|payload| payload.item)
+        .map(|item| item.id)
+        .ok_or_else(|| {
+            GithubProjectsRequestError::Other("GitHub did not return the project item".to_string())
+        })
+    }
+}
+
+/// Колонка — вариант поля Status конкретной доски. Для перемещения нужны
+/// и доска, и поле, поэтому id колонки: `project:field:option`
+fn column_id(project_id: &str, field_id: &str, option_id: &str) -> String {
+    format!("{}:{}:{}", project_id, field_id, option_id)
+}
+
+#[async_trait]
+impl TaskTrackerClient for GithubProjectsClient {
+    fn base_url(&self) -> &str {
+        &self.base.0
+    }
+
+    /// Задача, которой ещё нет на доске, сначала добавляется на неё
+    async fn move_task_to_column(
+        &self,
+        task_id: TaskId,
+        column_id: &str,
+    ) -> Result<(), TaskTrackerClientMoveToColumnError> {
+        use github_projects_update_item_status::GithubProjectsUpdateItemStatusUpdateProjectV2ItemFieldValueProjectV2ItemFieldValueByName as FieldValue;
+
+        let mut parts = column_id.splitn(3, ':');
+        let (Some(project_id), Some(field_id), Some(option_id)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(TaskTrackerClientMoveToColumnError::ParseError(
+                column_id.to_string(),
+            ));
+        };
+
+        let issue = self
+            .get_issue(&task_id)
+            .await
+            .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        let existing_item = issue
+            .project_items
+            .into_iter()
+            .find(|(_, item_project_id)| item_project_id == project_id)
+            .map(|(item_id, _)| item_id);
+
+        let item_id = match existing_item {
+            Some(item_id) => item_id,
+            None => self
+                .add_to_project(project_id, &issue.id)
+                .await
+                .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?,
+        };
+
+        let data = self
+            .request::<GithubProjectsUpdateItemStatus>(
+                github_projects_update_item_status::Variables {
+                    project_id: project_id.to_string(),
+                    item_id,
+                    field_id: field_id.to_string(),
+                    option_id: option_id.to_string(),
+                    field: STATUS_FIELD.to_string(),
+                },
+            )
+            .await
+            .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        let moved = data
+            .update_project_v2_item_field_value
+            .and_then(|payload| payload.project_v2_item)
+            .and_then(|item| item.field_value_by_name)
+            .and_then(|value| match value {
+                FieldValue::ProjectV2ItemFieldSingleSelectValue(value) => value.option_id,
+                _ => None,
+            })
+            .is_some_and(|id| id == option_id);
+
+        if !moved {
+            tracing::error!(
+                task_id = %task_id,
+                column_id,
+                "GitHub project item status differs from the requested one after update"
+            );
+            return Err(TaskTrackerClientMoveToColumnError::MoveValidationFailed);
+        }
+
+        Ok(())
+    }
+
+    async fn get_card(
+        &self,
+        task_id: TaskId,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientGetCardError> {
+        let issue = self.get_issue(&task_id).await.map_err(|e| match e {
+            GithubProjectsRequestError::NotFound => TaskTrackerClientGetCardError::NotFound,
+            GithubProjectsRequestError::Other(msg) => {
+                TaskTrackerClientGetCardError::ClientError(msg)
+            }
+        })?;
+
+        Ok(TaskTrackerCard {
+            id: task_id,
+            title: issue.title,
+            url: issue.url,
+        })
+    }
+
+    /// Пространства — открытые доски Projects v2 владельца репозитория
+    async fn list_spaces(&self) -> Result<Vec<TaskTrackerSpace>, TaskTrackerClientListError> {
+        use github_projects_list::GithubProjectsListRepositoryOwner as Owner;
+
+        let data = self
+            .request::<GithubProjectsList>(github_projects_list::Variables {
+                login: self.owner.clone(),
+            })
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        let projects = data
+            .repository_owner
+            .and_then(|owner| match owner {
+                Owner::Organization(organization) => organization.projects_v2.nodes,
+                Owner::User(user) => user.projects_v2.nodes,
+            })
+            .unwrap_or_default();
+
+        Ok(projects
+            .into_iter()
+            .flatten()
+            .filter(|p| !p.closed)
+            .map(|p| TaskTrackerSpace {
+                id: p.id,
+                title: format!("{} (#{})", p.title, p.number),
+            })
+            .collect())
+    }
+
+    /// Доска у проекта одна — он сам
+    async fn list_boards(
+        &self,
+        space_id: &str,
+    ) -> Result<Vec<TaskTrackerBoard>, TaskTrackerClientListError> {
+        let data = self
+            .request::<GithubProjectsProject>(github_projects_project::Variables {
+                id: space_id.to_string(),
+            })
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(data
+            .node
+            .and_then(|node| match node {
+                github_projects_project::GithubProjectsProjectNode::ProjectV2(project) => {
+                    Some(TaskTrackerBoard {
+                        id: project.id,
+                        title: project.title,
+                    })
+                }
+                _ => None,
+            })
+            .into_iter()
+            .collect())
+    }
+
+    async fn list_columns(
+        &self,
+        board_id: &str,
+    ) -> Result<Vec<TaskTrackerColumn>, TaskTrackerClientListError> {
+        use github_projects_status_field::{
+            GithubProjectsStatusFieldNode as Node,
+            GithubProjectsStatusFieldNodeOnProjectV2Field as Field,
+        };
+
+        let data = self
+            .request::<GithubProjectsStatusField>(github_projects_status_field::Variables {
+                id: board_id.to_string(),
+                field: STATUS_FIELD.to_string(),
+            })
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        let field = match data.node {
+            Some(Node::ProjectV2(project)) => match project.field {
+                Some(Field::ProjectV2SingleSelectField(field)) => Some(field),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let Some(field) = field else {
+            tracing::warn!(
+                project_id = board_id,
+                field = STATUS_FIELD,
+                "GitHub project has no single-select status field"
+            );
+            return Ok(vec![]);
+        };
+
+        Ok(field
+            .options
+            .into_iter()
+            .map(|option| TaskTrackerColumn {
+                id: column_id(board_id, &field.id, &option.id),
+                title: option.name,
+            })
+            .collect())
+    }
+
+    async fn list_cards(
+        &self,
+        space_id: &str,
+        column_id: &str,
+    ) -> Result<Vec<TaskTrackerCard>, TaskTrackerClientListError> {
+        use github_projects_items::{
+            GithubProjectsItemsNode as Node,
+            GithubProjectsItemsNodeOnProjectV2ItemsNodesContent as Content,
+            GithubProjectsItemsNodeOnProjectV2ItemsNodesFieldValueByName as FieldValue,
+        };
+
+        let option_id = column_id.rsplit(':').next().unwrap_or(column_id);
+
+        // Статус — поле элемента: фильтруем все элементы проекта, страница за страницей
+        let mut items = Vec::new();
+        let mut after = None;
+
+        loop {
+            let data = self
+                .request::<GithubProjectsItems>(github_projects_items::Variables {
+                    id: space_id.to_string(),
+                    field: STATUS_FIELD.to_string(),
+                    after: after.take(),
+                })
+                .await
+                .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+            let Some(Node::ProjectV2(project)) = data.node else {
+                break;
+            };
+
+            items.extend(project.items.nodes.unwrap_or_default());
+
+            let page = project.items.page_info;
+            match page.end_cursor {
+                Some(cursor) if page.has_next_page => after = Some(cursor),
+                _ => break,
+            }
+        }
+
+        Ok(items
+            .into_iter()
+            .flatten()
+            .filter(|item| match &item.field_value_by_name {
+                Some(FieldValue::ProjectV2ItemFieldSingleSelectValue(value)) => {
+                    value.option_id.as_deref() == Some(option_id)
+                }
+                _ => false,
+            })
+            .filter_map(|item| match item.content? {
+                Content::Issue(issue) => Some(TaskTrackerCard {
+                    id: self.task_id(&issue.repository.name_with_owner, issue.number as u64),
+                    title: issue.title,
+                    url: issue.url,
+                }),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Issue заводится в репозитории по умолчанию и сразу кладётся на доску `space_id`
+    async fn create_card(
+        &self,
+        space_id: &str,
+        column_id: Option<&str>,
+        draft: &TaskTrackerCardDraft,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientWriteError> {
+        let name = self.repository.as_deref().ok_or_else(|| {
+            TaskTrackerClientWriteError::ClientError(
+                "GitHub Projects connection has no default repository".to_string(),
+            )
+        })?;
+
+        let repository = self
+            .request::<GithubProjectsRepository>(github_projects_repository::Variables {
+                owner: self.owner.clone(),
+                name: name.to_string(),
+            })
+            .await
+            .map_err(write_error)?
+            .repository
+            .ok_or(TaskTrackerClientWriteError::NotFound)?;
+
+        let issue = self
+            .request::<GithubProjectsCreateIssue>(github_projects_create_issue::Variables {
+                repository_id: repository.id,
+                title: draft.title.clone(),
+                body: Some(draft.description.clone()),
+            })
+            .await
+            .map_err(write_error)?
+            .create_issue
+            .and_then(|payload| payload.issue)
+            .ok_or_else(|| {
+                TaskTrackerClientWriteError::ClientError("GitHub rejected the issue".to_string())
+            })?;
+
+        let task_id = TaskId(issue.number.to_string());
+
+        let placed = match column_id {
+            Some(column_id) => self
+                .move_task_to_column(task_id.clone(), column_id)
+                .await
+                .map_err(|e| e.to_string()),
+            None => self
+                .add_to_project(space_id, &issue.id)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+        };
+
+        if let Err(e) = placed {
+            tracing::warn!(error = %e, issue = issue.number, "Created GitHub issue was not added to the project");
+        }
+
+        Ok(TaskTrackerCard {
+            id: task_id,
+            title: issue.title,
+            url: issue.url,
+        })
+    }
+
+    /// Логин в GitHub и есть аккаунт VCS — email не нужен
+    async fn assign(
+        &self,
+        task_id: TaskId,
+        assignee: &TaskTrackerAssignee,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let issue = self.get_issue(&task_id).await.map_err(write_error)?;
+
+        let user = self
+            .request::<GithubProjectsUser>(github_projects_user::Variables {
+                login: assignee.login.clone(),
+            })
+            .await
+            .ok()
+            .and_then(|data| data.user)
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        self.request::<GithubProjectsAddAssignees>(github_projects_add_assignees::Variables {
+            assignable_id: issue.id,
+            assignee_ids: vec![user.id],
+        })
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn add_comment(
+        &self,
+        task_id: TaskId,
+        text: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let issue = self.get_issue(&task_id).await.map_err(write_error)?;
+
+        self.request::<GithubProjectsAddComment>(github_projects_add_comment::Variables {
+            subject_id: issue.id,
+            body: text.to_string(),
+        })
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+}
+
+fn write_error(error: GithubProjectsRequestError) -> TaskTrackerClientWriteError {
+    match error {
+        GithubProjectsRequestError::NotFound => TaskTrackerClientWriteError::NotFound,
+        GithubProjectsRequestError::Other(msg) => TaskTrackerClientWriteError::ClientError(msg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(base: &str) -> GithubProjectsClient {
+        GithubProjectsClient::new(
+            GithubProjectsClientBase(base.to_string()),
+            GithubProjectsClientToken(String::new()),
+        )
+    }
+
+    fn issue_ref(client: &GithubProjectsClient, raw: &str) -> Option<(String, String, u64)> {
+        client
+            .parse_issue_ref(&TaskId(raw.to_string()))
+            .map(|r| (r.owner, r.name, r.number))
+    }
+
+    #[test]
+    fn parse_issue_ref_keeps_explicit_repository() {
+        let client = client("https://github.com/acme/app");
+
+        assert_eq!(
+            issue_ref(&client, "acme/api#42"),
+            Some(("acme".to_string(), "api".to_string(), 42))
+        );
+    }
+
+    #[test]
+    fn parse_issue_ref_resolves_bare_number_against_default_repository() {
+        let client = client("https://github.com/acme/app/");
+
+        assert_eq!(
+            issue_ref(&client, "#7"),
+            Some(("acme".to_string(), "app".to_string(), 7))
+        );
+        assert_eq!(
+            issue_ref(&client, " 7 "),
+            Some(("acme".to_string(), "app".to_string(), 7))
+        );
+    }
+
+    #[test]
+    fn parse_issue_ref_rejects_bare_number_without_default_repository() {
+        let client = client("https://github.com/acme");
+
+        assert_eq!(issue_ref(&client, "7"), None);
+        assert_eq!(
+            issue_ref(&client, "acme/app#7"),
+            Some(("acme".to_string(), "app".to_string(), 7))
+        );
+    }
+
+    #[test]
+    fn parse_issue_ref_rejects_malformed_references() {
+        let client = client("https://github.com/acme/app");
+
+        assert_eq!(issue_ref(&client, "app#7"), None);
+        assert_eq!(issue_ref(&client, "acme/app#"), None);
+        assert_eq!(issue_ref(&client, "MOB-7"), None);
+    }
+
+    #[test]
+    fn task_id_is_inverse_of_parse_issue_ref() {
+        let client = client("https://github.com/acme/app");
+
+        assert_eq!(client.task_id("Acme/App", 7), TaskId("7".to_string()));
+        assert_eq!(
+            client.task_id("acme/api", 7),
+            TaskId("acme/api#7".to_string())
+        );
+    }
+}
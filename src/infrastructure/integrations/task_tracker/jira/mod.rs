@@ -0,0 +1,719 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::task::ports::task_tracker_client::{
+    TaskTrackerAssignee, TaskTrackerBoard, TaskTrackerCard, TaskTrackerCardDraft,
+    TaskTrackerClient, TaskTrackerClientGetCardError, TaskTrackerClientListError,
+    TaskTrackerClientMoveToColumnError, TaskTrackerClientWriteError, TaskTrackerColumn,
+    TaskTrackerSpace,
+};
+use crate::domain::task::value_objects::task_id::TaskId;
+use reqwest::{Client, Method, StatusCode};
+use serde_json::json;
+
+/// Сколько карточек колонки показываем при выборе
+const SEARCH_LIMIT: usize = 100;
+
+#[derive(Deserialize, Debug)]
+pub struct JiraProject {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraBoardPage {
+    pub values: Vec<JiraBoard>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraBoard {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraBoardConfiguration {
+    pub column_config: JiraColumnConfig,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraColumnConfig {
+    pub columns: Vec<JiraBoardColumn>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraBoardColumn {
+    pub name: String,
+    #[serde(default)]
+    pub statuses: Vec<JiraStatusRef>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraStatusRef {
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraIssue {
+    pub key: String,
+    pub fields: JiraIssueFields,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraIssueFields {
+    pub summary: String,
+    pub status: Option<JiraStatusRef>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraCreatedIssue {
+    pub key: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraSearchResult {
+    pub issues: Vec<JiraIssue>,
+    /// Только `/rest/api/3/search/jql`: на последней странице токена нет
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+}
+
+/// В Jira Cloud пользователь адресуется `accountId`, в Server/DC — `name`
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraUser {
+    pub account_id: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraTransitions {
+    pub transitions: Vec<JiraTransition>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JiraTransition {
+    pub id: String,
+    pub to: JiraStatusRef,
+}
+
+pub struct JiraClientBase(pub String);
+
+/// Пустой email — Jira Server/DC с Personal Access Token
+pub struct JiraClientCredentials {
+    pub email: String,
+    pub api_token: String,
+}
+
+enum JiraRequestError {
+    NotFound,
+    Other(String),
+}
+
+impl std::fmt::Display for JiraRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JiraRequestError::NotFound => write!(f, "Not found"),
+            JiraRequestError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+pub struct JiraClient {
+    base: JiraClientBase,
+    credentials: JiraClientCredentials,
+    client: Client,
+}
+
+impl JiraClient {
+    pub fn new(base: JiraClientBase, credentials: JiraClientCredentials) -> Self {
+        Self {
+            base: JiraClientBase(base.0.trim_end_matches('/').to_string()),
+            credentials,
+            client: Client::new(),
+        }
+    }
+
+    async fn send<Body>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Body>,
+    ) -> Result<String, JiraRequestError>
+    where
+        Body: Serialize + ?Sized,
+    {
+        let url = format!("{}{}", self.base.0, path);
+
+        tracing::debug!(method = %method, path = %path, "Jira API request");
+
+        let mut req = self
+            .client
+            .request(method, &url)
+            .header("Accept", "application/json");
+
+        req = if self.credentials.email.is_empty() {
+            req.bearer_auth(&self.credentials.api_token)
+        } else {
+            req.basic_auth(&self.credentials.email, Some(&self.credentials.api_token))
+        };
+
+        if let Some(body) = body {
+            req = req.json(body);
+        }
+
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| JiraRequestError::Other(e.to_string()))?;
+
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| JiraRequestError::Other(e.to_string()))?;
+
+        tracing::debug!(status = %status, path = %path, body_len = text.len(), "Jira API response");
+
+        if status == StatusCode::NOT_FOUND {
+            return Err(JiraRequestError::NotFound);
+        }
+
+        if !status.is_success() {
+            return Err(JiraRequestError::Other(format!(
+                "Jira API {} for {}: {}",
+                status, path, text
+            )));
+        }
+
+        Ok(text)
+    }
+
+    async fn get<Response>(&self, path: &str) -> Result<Response, JiraRequestError>
+    where
+        Response: for<'de> Deserialize<'de>,
+    {
+        let text = self.send::<()>(Method::GET, path, None).await?;
+
+        serde_json::from_str::<Response>(&text).map_err(|e| JiraRequestError::Other(e.to_string()))
+    }
+
+    fn card_url(&self, key: &str) -> String {
+        format!("{}/browse/{}", self.base.0, key)
+    }
+
+    /// Cloud ходит по email + API token, Server/DC — по Personal Access Token
+    fn is_cloud(&self) -> bool {
+        !self.credentials.email.is_empty()
+    }
+
+    async fn search_page(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<JiraSearchResult, JiraRequestError> {
+        let text = self.send(Method::POST, path, Some(body)).await?;
+
+        serde_json::from_str(&text).map_err(|e| JiraRequestError::Other(e.to_string()))
+    }
+
+    /// Cloud убрал `/rest/api/2/search` — там только `/rest/api/3/search/jql`
+    /// с постраничным `nextPageToken`. В Server/DC нового поиска нет, остаётся v2.
+    async fn search(&self, jql: &str) -> Result<Vec<JiraIssue>, JiraRequestError> {
+        if !self.is_cloud() {
+            let body = json!({
+                "jql": jql,
+                "fields": ["summary", "status"],
+                "maxResults": SEARCH_LIMIT,
+            });
+
+            return Ok(self.search_page("/rest/api/2/search", &body).await?.issues);
+        }
+
+        let mut issues = Vec::new();
+        let mut next_page_token: Option<String> = None;
+
+        loop {
+            let mut body = json!({
+                "jql": jql,
+                "fields": ["summary", "status"],
+                "maxResults": SEARCH_LIMIT - issues.len(),
+            });
+            if let Some(token) = &next_page_token {
+                body["nextPageToken"] = json!(token);
+            }
+
+            let page = self.search_page("/rest/api/3/search/jql", &body).await?;
+            let empty = page.issues.is_empty();
+            issues.extend(page.issues);
+            next_page_token = page.next_page_token;
+
+            if empty || next_page_token.is_none() || issues.len() >= SEARCH_LIMIT {
+                break;
+            }
+        }
+
+        issues.truncate(SEARCH_LIMIT);
+        Ok(issues)
+    }
+}
+
+#[async_trait]
+impl TaskTrackerClient for JiraClient {
+    fn base_url(&self) -> &str {
+        &self.base.0
+    }
+
+    /// `column_id` — id статуса Jira. Ищем переход, ведущий в этот статус.
+    async fn move_task_to_column(
+        &self,
+        task_id: TaskId,
+        column_id: &str,
+    ) -> Result<(), TaskTrackerClientMoveToColumnError> {
+        let issue: JiraIssue = self
+            .get(&format!(
+                "/rest/api/2/issue/{}?fields=summary,status",
+                task_id.0
+            ))
+            .await
+            .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        if issue.fields.status.as_ref().map(|s| s.id.as_str()) == Some(column_id) {
+            tracing::debug!(task_id = %task_id, column_id, "Jira issue already in target status");
+            return Ok(());
+        }
+
+        let transitions: JiraTransitions = self
+            .get(&format!("/rest/api/2/issue/{}/transitions", task_id.0))
+            .await
+            .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        let Some(transition) = transitions
+            .transitions
+            .iter()
+            .find(|t| t.to.id == column_id)
+        else {
+            tracing::error!(
+                task_id = %task_id,
+                column_id,
+                "No Jira transition leads to the target status from the current one"
+            );
+            return Err(TaskTrackerClientMoveToColumnError::MoveValidationFailed);
+        };
+
+        let body = json!({ "transition": { "id": transition.id } });
+
+        self.send(
+            Method::POST,
+            &format!("/rest/api/2/issue/{}/transitions", task_id.0),
+            Some(&body),
+        )
+        .await
+        .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn get_card(
+        &self,
+        task_id: TaskId,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientGetCardError> {
+        let issue: JiraIssue = self
+            .get(&format!(
+                "/rest/api/2/issue/{}?fields=summary,status",
+                task_id.0
+            ))
+            .await
+            .map_err(|e| match e {
+                JiraRequestError::NotFound => TaskTrackerClientGetCardError::NotFound,
+                JiraRequestError::Other(msg) => TaskTrackerClientGetCardError::ClientError(msg),
+            })?;
+
+        Ok(TaskTrackerCard {
+            url: self.card_url(&issue.key),
+            id: TaskId(issue.key),
+            title: issue.fields.summary,
+        })
+    }
+
+    async fn list_spaces(&self) -> Result<Vec<TaskTrackerSpace>, TaskTrackerClientListError> {
+        let projects: Vec<JiraProject> = self
+            .get("/rest/api/2/project")
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(projects
+            .into_iter()
+            .map(|p| TaskTrackerSpace {
+                id: p.id,
+                title: format!("{} ({})", p.name, p.key),
+            })
+            .collect())
+    }
+
+    async fn list_boards(
+        &self,
+        space_id: &str,
+    ) -> Result<Vec<TaskTrackerBoard>, TaskTrackerClientListError> {
+        let page: JiraBoardPage = self
+            .get(&format!(
+                "/rest/agile/1.0/board?projectKeyOrId={}&maxResults=50",
+                space_id
+            ))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(page
+            .values
+            .into_iter()
+            .map(|b| TaskTrackerBoard {
+                id: b.id.to_string(),
+                title: b.name,
+            })
+            .collect())
+    }
+
+    /// Колонка доски может объединять несколько статусов — берём первый,
+    /// в него и будет вести переход при перемещении.
+    async fn list_columns(
+        &self,
+        board_id: &str,
+    ) -> Result<Vec<TaskTrackerColumn>, TaskTrackerClientListError> {
+        let configuration: JiraBoardConfiguration = self
+            .get(&format!("/rest/agile/1.0/board/{}/configuration", board_id))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(configuration
+            .column_config
+            .columns
+            .into_iter()
+            .filter_map(|c| {
+                let status = c.statuses.first()?;
+                Some(TaskTrackerColumn {
+                    id: status.id.clone(),
+                    title: c.name,
+                })
+            })
+            .collect())
+    }
+
+    /// `space_id` — id проекта, `column_id` — id статуса
+    async fn list_cards(
+        &self,
+        space_id: &str,
+        column_id: &str,
+    ) -> Result<Vec<TaskTrackerCard>, TaskTrackerClientListError> {
+        let jql = format!(
+            "project = {} AND status = {} ORDER BY updated DESC",
+            space_id, column_id
+        );
+
+        let issues = self
+            .search(&jql)
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(issues
+            .into_iter()
+            .map(|issue| TaskTrackerCard {
+                url: self.card_url(&issue.key),
+                id: TaskId(issue.key),
+                title: issue.fields.summary,
+            })
+            .collect())
+    }
+
+    /// Тип задачи — «Task»; статус после создания выставляется переходом
+    async fn create_card(
+        &self,
+        space_id: &str,
+        column_id: Option<&str>,
+        draft: &TaskTrackerCardDraft,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientWriteError> {
+        let body = json!({
+            "fields": {
+                "project": { "id": space_id },
+                "summary": draft.title,
+                "description": draft.description,
+                "issuetype": { "name": "Task" },
+            }
+        });
+
+        let text = self
+            .send(Method::POST, "/rest/api/2/issue", Some(&body))
+            .await
+            .map_err(write_error)?;
+
+        let issue: JiraCreatedIssue = serde_json::from_str(&text)
+            .map_err(|e| TaskTrackerClientWriteError::ClientError(e.to_string()))?;
+
+        if let Some(column_id) = column_id
+            && let Err(e) = self
+                .move_task_to_column(TaskId(issue.key.clone()), column_id)
+                .await
+        {
+            tracing::warn!(error = %e, task_id = %issue.key, "Created Jira issue left in initial status");
+        }
+
+        Ok(TaskTrackerCard {
+            url: self.card_url(&issue.key),
+            id: TaskId(issue.key),
+            title: draft.title.clone(),
+        })
+    }
+
+    async fn assign(
+        &self,
+        task_id: TaskId,
+        assignee: &TaskTrackerAssignee,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let email = assignee
+            .email
+            .as_deref()
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        let email: String = url::form_urlencoded::byte_serialize(email.as_bytes()).collect();
+
+        // Server/DC ищет по `username`, Cloud — по `query`
+        let param = if self.credentials.email.is_empty() {
+            "username"
+        } else {
+            "query"
+        };
+
+        let users: Vec<JiraUser> = self
+            .get(&format!("/rest/api/2/user/search?{}={}", param, email))
+            .await
+            .map_err(write_error)?;
+
+        let body = match users.into_iter().next() {
+            Some(JiraUser {
+                account_id: Some(account_id),
+                ..
+            }) => json!({ "accountId": account_id }),
+            Some(JiraUser {
+                name: Some(name), ..
+            }) => json!({ "name": name }),
+            _ => return Err(TaskTrackerClientWriteError::AssigneeNotFound),
+        };
+
+        self.send(
+            Method::PUT,
+            &format!("/rest/api/2/issue/{}/assignee", task_id.0),
+            Some(&body),
+        )
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn add_comment(
+        &self,
+        task_id: TaskId,
+        text: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let body = json!({ "body": text });
+
+        self.send(
+            Method::POST,
+            &format!("/rest/api/2/issue/{}/comment", task_id.0),
+            Some(&body),
+        )
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    /// Remote link: повторная отправка того же `globalId` обновляет ссылку, а не дублирует
+    async fn add_link(
+        &self,
+        task_id: TaskId,
+        url: &str,
+        title: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let body = json!({
+            "globalId": url,
+            "object": { "url": url, "title": title },
+        });
+
+        self.send(
+            Method::POST,
+            &format!("/rest/api/2/issue/{}/remotelink", task_id.0),
+            Some(&body),
+        )
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+}
+
+fn write_error(error: JiraRequestError) -> TaskTrackerClientWriteError {
+    match error {
+        JiraRequestError::NotFound => TaskTrackerClientWriteError::NotFound,
+        JiraRequestError::Other(msg) => TaskTrackerClientWriteError::ClientError(msg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Bytes;
+    use axum::extract::State;
+    use axum::http::header::AUTHORIZATION;
+    use axum::http::{HeaderMap, Uri};
+    use axum::response::{IntoResponse, Response};
+    use axum::{Json, Router};
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+
+    /// Метод, путь с query, заголовок `Authorization` и тело запроса к заглушке
+    type Requests = Arc<Mutex<Vec<(String, String, Option<String>, Value)>>>;
+
+    fn issue(key: &str, summary: &str) -> Value {
+        json!({ "key": key, "fields": { "summary": summary, "status": { "id": "3" } } })
+    }
+
+    async fn jira_stub(
+        State(requests): State<Requests>,
+        method: axum::http::Method,
+        uri: Uri,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> Response {
+        let auth = headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        let path = uri.to_string();
+
+        requests
+            .lock()
+            .unwrap()
+            .push((method.to_string(), path.clone(), auth, body.clone()));
+
+        match (method.as_str(), uri.path()) {
+            ("POST", "/rest/api/3/search/jql") => Json(match body["nextPageToken"].as_str() {
+                None => json!({ "issues": [issue("APP-1", "First")], "nextPageToken": "p2" }),
+                Some(_) => json!({ "issues": [issue("APP-2", "Second")], "isLast": true }),
+            })
+            .into_response(),
+            ("POST", "/rest/api/2/search") => {
+                Json(json!({ "issues": [issue("APP-1", "First")], "total": 1 })).into_response()
+            }
+            ("GET", "/rest/api/2/issue/APP-1") => Json(issue("APP-1", "First")).into_response(),
+            ("POST", "/rest/api/2/issue/APP-1/remotelink") => {
+                (axum::http::StatusCode::CREATED, Json(json!({ "id": 10 }))).into_response()
+            }
+            _ => axum::http::StatusCode::NOT_FOUND.into_response(),
+        }
+    }
+
+    /// Поднимает заглушку Jira на свободном порту; пустой email — Server/DC
+    async fn start_stub(email: &str) -> (JiraClient, Requests) {
+        let requests = Requests::default();
+        let app = Router::new()
+            .fallback(jira_stub)
+            .with_state(requests.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = JiraClient::new(
+            JiraClientBase(base),
+            JiraClientCredentials {
+                email: email.to_string(),
+                api_token: "secret".to_string(),
+            },
+        );
+
+        (client, requests)
+    }
+
+    #[tokio::test]
+    async fn cloud_list_cards_follows_next_page_token() {
+        let (client, requests) = start_stub("bot@acme.io").await;
+
+        let cards = client.list_cards("10000", "3").await.unwrap();
+
+        let ids: Vec<&str> = cards.iter().map(|c| c.id.0.as_str()).collect();
+        assert_eq!(ids, ["APP-1", "APP-2"]);
+        assert!(cards[0].url.ends_with("/browse/APP-1"));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+
+        let (method, path, auth, body) = &requests[0];
+        assert_eq!(method, "POST");
+        assert_eq!(path, "/rest/api/3/search/jql");
+        assert!(auth.as_deref().unwrap().starts_with("Basic "));
+        assert_eq!(
+            body["jql"],
+            "project = 10000 AND status = 3 ORDER BY updated DESC"
+        );
+        assert!(body.get("nextPageToken").is_none());
+
+        let (_, path, _, body) = &requests[1];
+        assert_eq!(path, "/rest/api/3/search/jql");
+        assert_eq!(body["nextPageToken"], "p2");
+        assert_eq!(body["maxResults"], 99);
+    }
+
+    #[tokio::test]
+    async fn server_list_cards_keeps_v2_search() {
+        let (client, requests) = start_stub("").await;
+
+        let cards = client.list_cards("10000", "3").await.unwrap();
+
+        assert_eq!(cards.len(), 1);
+
+        let requests = requests.lock().unwrap();
+        let (method, path, auth, body) = &requests[0];
+        assert_eq!(method, "POST");
+        assert_eq!(path, "/rest/api/2/search");
+        assert_eq!(auth.as_deref(), Some("Bearer secret"));
+        assert_eq!(body["maxResults"], 100);
+    }
+
+    #[tokio::test]
+    async fn get_card_maps_missing_issue_to_not_found() {
+        let (client, _) = start_stub("bot@acme.io").await;
+
+        let card = client.get_card(TaskId("APP-1".to_string())).await.unwrap();
+        assert_eq!(card.title, "First");
+
+        let missing = client.get_card(TaskId("APP-404".to_string())).await;
+        assert!(matches!(
+            missing,
+            Err(TaskTrackerClientGetCardError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_link_sends_remote_link_keyed_by_url() {
+        let (client, requests) = start_stub("bot@acme.io").await;
+
+        client
+            .add_link(
+                TaskId("APP-1".to_string()),
+                "https://github.com/acme/app/pull/7",
+                "PR #7",
+            )
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        let (_, path, _, body) = &requests[0];
+        assert_eq!(path, "/rest/api/2/issue/APP-1/remotelink");
+        assert_eq!(body["globalId"], "https://github.com/acme/app/pull/7");
+        assert_eq!(body["object"]["title"], "PR #7");
+    }
+}
@@ -2,8 +2,9 @@ use async_trait::async_trait;
 use serde::Deserialize;
 
 use crate::domain::task::ports::task_tracker_client::{
-    TaskTrackerBoard, TaskTrackerCard, TaskTrackerClient, TaskTrackerClientGetCardError,
-    TaskTrackerClientListError, TaskTrackerClientMoveToColumnError, TaskTrackerColumn,
+    TaskTrackerAssignee, TaskTrackerBoard, TaskTrackerCard, TaskTrackerCardDraft,
+    TaskTrackerClient, TaskTrackerClientGetCardError, TaskTrackerClientListError,
+    TaskTrackerClientMoveToColumnError, TaskTrackerClientWriteError, TaskTrackerColumn,
     TaskTrackerSpace,
 };
 use crate::domain::task::value_objects::task_id::TaskId;
@@ -18,6 +19,12 @@ pub struct KaitenCard {
     pub column_id: u64,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct KaitenUser {
+    pub id: u64,
+    pub email: Option<String>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct KaitenSpace {
     pub id: i32,
@@ -94,15 +101,35 @@ impl KaitenClient {
 
         Ok(parsed)
     }
+
+    fn card_url(&self, card_id: u64) -> String {
+        format!("{}/space/0/boards/card/{}", self.base.0, card_id)
+    }
+
+    fn write_error(msg: String) -> TaskTrackerClientWriteError {
+        if msg.contains("Not found") {
+            TaskTrackerClientWriteError::NotFound
+        } else {
+            TaskTrackerClientWriteError::ClientError(msg)
+        }
+    }
 }
 
 #[async_trait]
 impl TaskTrackerClient for KaitenClient {
+    fn base_url(&self) -> &str {
+        &self.base.0
+    }
+
     async fn move_task_to_column(
         &self,
         task_id: TaskId,
-        column_id: u64,
+        column_id: &str,
     ) -> Result<(), TaskTrackerClientMoveToColumnError> {
+        let column_id: u64 = column_id
+            .parse()
+            .map_err(|_| TaskTrackerClientMoveToColumnError::ParseError(column_id.to_string()))?;
+
         let body = json!({ "column_id": column_id });
 
         let response: KaitenCard = self
@@ -112,7 +139,7 @@ impl TaskTrackerClient for KaitenClient {
 
         let span = tracing::debug_span!(
             "move_card",
-            task_id = task_id.0,
+            task_id = %task_id,
             column_id = column_id,
             response_column_id = response.column_id
         );
@@ -144,12 +171,10 @@ impl TaskTrackerClient for KaitenClient {
                 }
             })?;
 
-        let url = format!("{}/space/0/boards/card/{}", self.base.0, card.id);
-
         Ok(TaskTrackerCard {
-            id: TaskId(card.id),
+            id: TaskId(card.id.to_string()),
+            url: self.card_url(card.id),
             title: card.title,
-            url,
         })
     }
 
@@ -162,7 +187,7 @@ impl TaskTrackerClient for KaitenClient {
         Ok(spaces
             .into_iter()
             .map(|s| TaskTrackerSpace {
-                id: s.id,
+                id: s.id.to_string(),
                 title: s.title,
             })
             .collect())
@@ -170,7 +195,7 @@ impl TaskTrackerClient for KaitenClient {
 
     async fn list_boards(
         &self,
-        space_id: i32,
+        space_id: &str,
     ) -> Result<Vec<TaskTrackerBoard>, TaskTrackerClientListError> {
         let boards: Vec<KaitenBoard> = self
             .request::<(), Vec<KaitenBoard>>(
@@ -184,7 +209,7 @@ impl TaskTrackerClient for KaitenClient {
         Ok(boards
             .into_iter()
             .map(|b| TaskTrackerBoard {
-                id: b.id,
+                id: b.id.to_string(),
                 title: b.title,
             })
             .collect())
@@ -192,7 +217,7 @@ impl TaskTrackerClient for KaitenClient {
 
     async fn list_columns(
         &self,
-        board_id: i32,
+        board_id: &str,
     ) -> Result<Vec<TaskTrackerColumn>, TaskTrackerClientListError> {
         let columns: Vec<KaitenColumn> = self
             .request::<(), Vec<KaitenColumn>>(
@@ -206,9 +231,171 @@ impl TaskTrackerClient for KaitenClient {
         Ok(columns
             .into_iter()
             .map(|c| TaskTrackerColumn {
-                id: c.id,
+                id: c.id.to_string(),
+                title: c.title,
+            })
+            .collect())
+    }
+
+    /// Колонка в Kaiten уникальна сама по себе — пространство не нужно
+    async fn list_cards(
+        &self,
+        _space_id: &str,
+        column_id: &str,
+    ) -> Result<Vec<TaskTrackerCard>, TaskTrackerClientListError> {
+        let cards: Vec<KaitenCard> = self
+            .request::<(), Vec<KaitenCard>>(
+                Method::GET,
+                &format!("/cards?column_id={}&condition=1&limit=100", column_id),
+                None,
+            )
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(cards
+            .into_iter()
+            .map(|c| TaskTrackerCard {
+                id: TaskId(c.id.to_string()),
+                url: self.card_url(c.id),
                 title: c.title,
             })
             .collect())
     }
+
+    /// Карточке в Kaiten нужна доска: берём доску, где лежит колонка, или первую в пространстве
+    async fn create_card(
+        &self,
+        space_id: &str,
+        column_id: Option<&str>,
+        draft: &TaskTrackerCardDraft,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientWriteError> {
+        let boards = self
+            .list_boards(space_id)
+            .await
+            .map_err(|e| Self::write_error(e.to_string()))?;
+
+        let mut board_id = None;
+        match column_id {
+            Some(column_id) => {
+                for board in &boards {
+                    let columns = self
+                        .list_columns(&board.id)
+                        .await
+                        .map_err(|e| Self::write_error(e.to_string()))?;
+
+                    if columns.iter().any(|c| c.id == column_id) {
+                        board_id = Some(board.id.clone());
+                        break;
+                    }
+                }
+            }
+            None => board_id = boards.first().map(|b| b.id.clone()),
+        }
+
+        let board_id: u64 = board_id
+            .ok_or(TaskTrackerClientWriteError::NotFound)?
+            .parse()
+            .map_err(|_| TaskTrackerClientWriteError::ClientError(space_id.to_string()))?;
+
+        let mut body = json!({
+            "board_id": board_id,
+            "title": draft.title,
+            "description": draft.description,
+        });
+        if let Some(column_id) = column_id.and_then(|c| c.parse::<u64>().ok()) {
+            body["column_id"] = json!(column_id);
+        }
+
+        let card: KaitenCard = self
+            .request(Method::POST, "/cards", Some(&body))
+            .await
+            .map_err(|e| Self::write_error(e.to_string()))?;
+
+        Ok(TaskTrackerCard {
+            id: TaskId(card.id.to_string()),
+            url: self.card_url(card.id),
+            title: card.title,
+        })
+    }
+
+    /// Участник карточки с типом 2 — ответственный
+    async fn assign(
+        &self,
+        task_id: TaskId,
+        assignee: &TaskTrackerAssignee,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let email = assignee
+            .email
+            .as_deref()
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        let users: Vec<KaitenUser> = self
+            .request::<(), Vec<KaitenUser>>(Method::GET, "/users", None)
+            .await
+            .map_err(|e| Self::write_error(e.to_string()))?;
+
+        let user = users
+            .into_iter()
+            .find(|u| {
+                u.email
+                    .as_deref()
+                    .is_some_and(|e| e.eq_ignore_ascii_case(email))
+            })
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        self.request::<_, serde_json::Value>(
+            Method::POST,
+            &format!("/cards/{}/members", task_id.0),
+            Some(&json!({ "user_id": user.id })),
+        )
+        .await
+        .map_err(|e| Self::write_error(e.to_string()))?;
+
+        self.request::<_, serde_json::Value>(
+            Method::PATCH,
+            &format!("/cards/{}/members/{}", task_id.0, user.id),
+            Some(&json!({ "type": 2 })),
+        )
+        .await
+        .map_err(|e| Self::write_error(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn add_comment(
+        &self,
+        task_id: TaskId,
+        text: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let body = json!({ "text": text });
+
+        self.request::<_, serde_json::Value>(
+            Method::POST,
+            &format!("/cards/{}/comments", task_id.0),
+            Some(&body),
+        )
+        .await
+        .map_err(|e| Self::write_error(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn add_link(
+        &self,
+        task_id: TaskId,
+        url: &str,
+        title: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let body = json!({ "url": url, "description": title });
+
+        self.request::<_, serde_json::Value>(
+            Method::POST,
+            &format!("/cards/{}/external-links", task_id.0),
+            Some(&body),
+        )
+        .await
+        .map_err(|e| Self::write_error(e.to_string()))?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,662 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::domain::task::ports::task_tracker_client::{
+    TaskTrackerAssignee, TaskTrackerBoard, TaskTrackerCard, TaskTrackerCardDraft,
+    TaskTrackerClient, TaskTrackerClientGetCardError, TaskTrackerClientListError,
+    TaskTrackerClientMoveToColumnError, TaskTrackerClientWriteError, TaskTrackerColumn,
+    TaskTrackerSpace,
+};
+use crate::domain::task::value_objects::task_id::TaskId;
+use reqwest::Client;
+
+const LINEAR_GRAPHQL_URL: &str = "https://api.linear.app/graphql";
+
+const TEAMS_QUERY: &str = "query { teams(first: 100) { nodes { id key name } } }";
+
+const TEAM_QUERY: &str = "query($id: String!) { team(id: $id) { id key name } }";
+
+const TEAM_STATES_QUERY: &str =
+    "query($id: String!) { team(id: $id) { states { nodes { id name position } } } }";
+
+const ISSUE_QUERY: &str = "query($id: String!) { issue(id: $id) { identifier title url } }";
+
+const ISSUE_UPDATE_MUTATION: &str = "mutation($id: String!, $stateId: String!) { \
+     issueUpdate(id: $id, input: { stateId: $stateId }) { success issue { state { id } } } }";
+
+const STATE_ISSUES_QUERY: &str = "query($stateId: ID!) { \
+     issues(first: 100, filter: { state: { id: { eq: $stateId } } }) { nodes { identifier title url } } }";
+
+const ISSUE_CREATE_MUTATION: &str = "mutation($input: IssueCreateInput!) { \
+     issueCreate(input: $input) { success issue { identifier title url } } }";
+
+const USER_BY_EMAIL_QUERY: &str =
+    "query($email: String!) { users(filter: { email: { eq: $email } }) { nodes { id } } }";
+
+const ISSUE_ASSIGN_MUTATION: &str = "mutation($id: String!, $assigneeId: String!) { \
+     issueUpdate(id: $id, input: { assigneeId: $assigneeId }) { success } }";
+
+const COMMENT_CREATE_MUTATION: &str = "mutation($issueId: String!, $body: String!) { \
+     commentCreate(input: { issueId: $issueId, body: $body }) { success } }";
+
+const ATTACHMENT_LINK_MUTATION: &str = "mutation($issueId: String!, $url: String!, $title: String) { \
+     attachmentLinkURL(issueId: $issueId, url: $url, title: $title) { success } }";
+
+#[derive(Deserialize, Debug)]
+pub struct LinearConnection<T> {
+    pub nodes: Vec<T>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LinearTeam {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LinearWorkflowState {
+    pub id: String,
+    pub name: String,
+    pub position: f64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LinearStateRef {
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LinearIssue {
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LinearUser {
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearTeamsData {
+    teams: LinearConnection<LinearTeam>,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearTeamData {
+    team: LinearTeam,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearTeamStates {
+    states: LinearConnection<LinearWorkflowState>,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearTeamStatesData {
+    team: LinearTeamStates,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearIssueData {
+    issue: LinearIssue,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearIssuesData {
+    issues: LinearConnection<LinearIssue>,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearUsersData {
+    users: LinearConnection<LinearUser>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LinearIssueCreateData {
+    issue_create: LinearIssueCreatePayload,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearIssueCreatePayload {
+    success: bool,
+    issue: Option<LinearIssue>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LinearIssueAssignData {
+    issue_update: LinearSuccessPayload,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LinearIssueUpdateData {
+    issue_update: LinearIssueUpdatePayload,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearIssueUpdatePayload {
+    success: bool,
+    issue: Option<LinearIssueStateOnly>,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearIssueStateOnly {
+    state: LinearStateRef,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LinearCommentCreateData {
+    comment_create: LinearSuccessPayload,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearAttachmentLinkData {
+    #[serde(rename = "attachmentLinkURL")]
+    attachment_link_url: LinearSuccessPayload,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearSuccessPayload {
+    success: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearGraphQLResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<LinearGraphQLError>,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinearGraphQLError {
+    message: String,
+}
+
+enum LinearRequestError {
+    NotFound,
+    Other(String),
+}
+
+impl std::fmt::Display for LinearRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinearRequestError::NotFound => write!(f, "Not found"),
+            LinearRequestError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Адрес рабочего пространства, например `https://linear.app/acme` —
+/// к нему дописывается `path_to_card` в отчётах
+pub struct LinearClientBase(pub String);
+
+pub struct LinearClientToken(pub String);
+
+pub struct LinearClient {
+    base: LinearClientBase,
+    token: LinearClientToken,
+    api_url: String,
+    client: Client,
+}
+
+impl LinearClient {
+    pub fn new(base: LinearClientBase, token: LinearClientToken) -> Self {
+        Self {
+            base: LinearClientBase(base.0.trim_end_matches('/').to_string()),
+            token,
+            api_url: LINEAR_GRAPHQL_URL.to_string(),
+            client: Client::new(),
+        }
+    }
+
+    /// GraphQL-эндпоинт заглушки вместо api.linear.app
+    #[cfg(test)]
+    fn with_api_url(mut self, api_url: String) -> Self {
+        self.api_url = api_url;
+        self
+    }
+
+    async fn request<Response>(
+        &self,
+        query: &str,
+        variables: Value,
+    ) -> Result<Response, LinearRequestError>
+    where
+        Response: DeserializeOwned,
+    {
+        tracing::debug!(query = %query, "Linear API request");
+
+        // Personal API key передаётся без префикса Bearer
+        let resp = self
+            .client
+            .post(&self.api_url)
+            .header("Authorization", &self.token.0)
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()
+            .await
+            .map_err(|e| LinearRequestError::Other(e.to_string()))?;
+
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| LinearRequestError::Other(e.to_string()))?;
+
+        tracing::debug!(status = %status, body_len = text.len(), "Linear API response");
+
+        let parsed: LinearGraphQLResponse<Response> = serde_json::from_str(&text)
+            .map_err(|e| LinearRequestError::Other(format!("Linear API {}: {}", status, e)))?;
+
+        if let Some(error) = parsed.errors.first() {
+            if error.message.to_lowercase().contains("not found") {
+                return Err(LinearRequestError::NotFound);
+            }
+            return Err(LinearRequestError::Other(error.message.clone()));
+        }
+
+        parsed
+            .data
+            .ok_or_else(|| LinearRequestError::Other(format!("Linear API {}: empty data", status)))
+    }
+}
+
+#[async_trait]
+impl TaskTrackerClient for LinearClient {
+    fn base_url(&self) -> &str {
+        &self.base.0
+    }
+
+    /// `column_id` — id состояния workflow команды
+    async fn move_task_to_column(
+        &self,
+        task_id: TaskId,
+        column_id: &str,
+    ) -> Result<(), TaskTrackerClientMoveToColumnError> {
+        let data: LinearIssueUpdateData = self
+            .request(
+                ISSUE_UPDATE_MUTATION,
+                json!({ "id": task_id.0, "stateId": column_id }),
+            )
+            .await
+            .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        let moved = data.issue_update.success
+            && data
+                .issue_update
+                .issue
+                .is_some_and(|issue| issue.state.id == column_id);
+
+        if !moved {
+            tracing::error!(
+                task_id = %task_id,
+                column_id,
+                "Linear issue state differs from the requested one after update"
+            );
+            return Err(TaskTrackerClientMoveToColumnError::MoveValidationFailed);
+        }
+
+        Ok(())
+    }
+
+    async fn get_card(
+        &self,
+        task_id: TaskId,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientGetCardError> {
+        let data: LinearIssueData = self
+            .request(ISSUE_QUERY, json!({ "id": task_id.0 }))
+            .await
+            .map_err(|e| match e {
+                LinearRequestError::NotFound => TaskTrackerClientGetCardError::NotFound,
+                LinearRequestError::Other(msg) => TaskTrackerClientGetCardError::ClientError(msg),
+            })?;
+
+        Ok(TaskTrackerCard {
+            id: TaskId(data.issue.identifier),
+            title: data.issue.title,
+            url: data.issue.url,
+        })
+    }
+
+    async fn list_spaces(&self) -> Result<Vec<TaskTrackerSpace>, TaskTrackerClientListError> {
+        let data: LinearTeamsData = self
+            .request(TEAMS_QUERY, json!({}))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(data
+            .teams
+            .nodes
+            .into_iter()
+            .map(|t| TaskTrackerSpace {
+                id: t.id,
+                title: format!("{} ({})", t.name, t.key),
+            })
+            .collect())
+    }
+
+    /// У Linear нет досок в понимании Kaiten — одна доска на команду
+    async fn list_boards(
+        &self,
+        space_id: &str,
+    ) -> Result<Vec<TaskTrackerBoard>, TaskTrackerClientListError> {
+        let data: LinearTeamData = self
+            .request(TEAM_QUERY, json!({ "id": space_id }))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(vec![TaskTrackerBoard {
+            id: data.team.id,
+            title: data.team.name,
+        }])
+    }
+
+    async fn list_columns(
+        &self,
+        board_id: &str,
+    ) -> Result<Vec<TaskTrackerColumn>, TaskTrackerClientListError> {
+        let data: LinearTeamStatesData = self
+            .request(TEAM_STATES_QUERY, json!({ "id": board_id }))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        let mut states = data.team.states.nodes;
+        states.sort_by(|a, b| a.position.total_cmp(&b.position));
+
+        Ok(states
+            .into_iter()
+            .map(|s| TaskTrackerColumn {
+                id: s.id,
+                title: s.name,
+            })
+            .collect())
+    }
+
+    /// Состояние workflow принадлежит команде, поэтому `space_id` не нужен
+    async fn list_cards(
+        &self,
+        _space_id: &str,
+        column_id: &str,
+    ) -> Result<Vec<TaskTrackerCard>, TaskTrackerClientListError> {
+        let data: LinearIssuesData = self
+            .request(STATE_ISSUES_QUERY, json!({ "stateId": column_id }))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(data
+            .issues
+            .nodes
+            .into_iter()
+            .map(|issue| TaskTrackerCard {
+                id: TaskId(issue.identifier),
+                title: issue.title,
+                url: issue.url,
+            })
+            .collect())
+    }
+
+    /// `space_id` — команда, `column_id` — состояние workflow
+    async fn create_card(
+        &self,
+        space_id: &str,
+        column_id: Option<&str>,
+        draft: &TaskTrackerCardDraft,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientWriteError> {
+        let mut input = json!({
+            "teamId": space_id,
+            "title": draft.title,
+            "description": draft.description,
+        });
+        if let Some(column_id) = column_id {
+            input["stateId"] = json!(column_id);
+        }
+
+        let data: LinearIssueCreateData = self
+            .request(ISSUE_CREATE_MUTATION, json!({ "input": input }))
+            .await
+            .map_err(write_error)?;
+
+        let issue = data
+            .issue_create
+            .issue
+            .filter(|_| data.issue_create.success)
+            .ok_or_else(|| {
+                TaskTrackerClientWriteError::ClientError("Linear rejected the issue".to_string())
+            })?;
+
+        Ok(TaskTrackerCard {
+            id: TaskId(issue.identifier),
+            title: issue.title,
+            url: issue.url,
+        })
+    }
+
+    async fn assign(
+        &self,
+        task_id: TaskId,
+        assignee: &TaskTrackerAssignee,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let email = assignee
+            .email
+            .as_deref()
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        let users: LinearUsersData = self
+            .request(USER_BY_EMAIL_QUERY, json!({ "email": email }))
+            .await
+            .map_err(write_error)?;
+
+        let user = users
+            .users
+            .nodes
+            .into_iter()
+            .next()
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        let data: LinearIssueAssignData = self
+            .request(
+                ISSUE_ASSIGN_MUTATION,
+                json!({ "id": task_id.0, "assigneeId": user.id }),
+            )
+            .await
+            .map_err(write_error)?;
+
+        if !data.issue_update.success {
+            return Err(TaskTrackerClientWriteError::ClientError(
+                "Linear rejected the assignee".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn add_comment(
+        &self,
+        task_id: TaskId,
+        text: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let data: LinearCommentCreateData = self
+            .request(
+                COMMENT_CREATE_MUTATION,
+                json!({ "issueId": task_id.0, "body": text }),
+            )
+            .await
+            .map_err(write_error)?;
+
+        if !data.comment_create.success {
+            return Err(TaskTrackerClientWriteError::ClientError(
+                "Linear rejected the comment".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Ссылка становится вложением задачи; тот же URL повторно не дублируется
+    async fn add_link(
+        &self,
+        task_id: TaskId,
+        url: &str,
+        title: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let data: LinearAttachmentLinkData = self
+            .request(
+                ATTACHMENT_LINK_MUTATION,
+                json!({ "issueId": task_id.0, "url": url, "title": title }),
+            )
+            .await
+            .map_err(write_error)?;
+
+        if !data.attachment_link_url.success {
+            return Err(TaskTrackerClientWriteError::ClientError(
+                "Linear rejected the attachment".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn write_error(error: LinearRequestError) -> TaskTrackerClientWriteError {
+    match error {
+        LinearRequestError::NotFound => TaskTrackerClientWriteError::NotFound,
+        LinearRequestError::Other(msg) => TaskTrackerClientWriteError::ClientError(msg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::State;
+    use axum::http::HeaderMap;
+    use axum::http::header::AUTHORIZATION;
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use std::sync::{Arc, Mutex};
+
+    /// Заголовок `Authorization` и тело GraphQL-запроса к заглушке
+    type Requests = Arc<Mutex<Vec<(Option<String>, Value)>>>;
+
+    async fn linear_stub(
+        State(requests): State<Requests>,
+        headers: HeaderMap,
+        Json(body): Json<Value>,
+    ) -> Json<Value> {
+        let auth = headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        requests.lock().unwrap().push((auth, body.clone()));
+
+        let query = body["query"].as_str().unwrap_or_default();
+        let variables = &body["variables"];
+
+        Json(if query.contains("issueUpdate") {
+            // Workflow не пускает в «s-blocked» — состояние остаётся прежним
+            let state = match variables["stateId"].as_str() {
+                Some("s-blocked") => "s-todo",
+                other => other.unwrap_or_default(),
+            };
+            json!({ "data": { "issueUpdate": { "success": true, "issue": { "state": { "id": state } } } } })
+        } else if query.contains("issue(") && variables["id"] == "ENG-404" {
+            json!({ "data": null, "errors": [{ "message": "Entity not found: Issue" }] })
+        } else if query.contains("issue(") {
+            json!({ "data": { "issue": {
+                "identifier": "ENG-1",
+                "title": "Fix login",
+                "url": "https://linear.app/acme/issue/ENG-1"
+            } } })
+        } else if query.contains("issues(") {
+            json!({ "data": { "issues": { "nodes": [
+                { "identifier": "ENG-1", "title": "Fix login", "url": "https://linear.app/acme/issue/ENG-1" }
+            ] } } })
+        } else {
+            json!({ "data": null, "errors": [{ "message": "Unknown query" }] })
+        })
+    }
+
+    /// Поднимает заглушку GraphQL API на свободном порту
+    async fn start_stub() -> (LinearClient, Requests) {
+        let requests = Requests::default();
+        let app = Router::new()
+            .route("/graphql", post(linear_stub))
+            .with_state(requests.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let api_url = format!("http://{}/graphql", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = LinearClient::new(
+            LinearClientBase("https://linear.app/acme".to_string()),
+            LinearClientToken("lin_api_test".to_string()),
+        )
+        .with_api_url(api_url);
+
+        (client, requests)
+    }
+
+    #[tokio::test]
+    async fn get_card_sends_raw_api_key_and_maps_not_found() {
+        let (client, requests) = start_stub().await;
+
+        let card = client.get_card(TaskId("ENG-1".to_string())).await.unwrap();
+        assert_eq!(card.id.0, "ENG-1");
+        assert_eq!(card.url, "https://linear.app/acme/issue/ENG-1");
+
+        let missing = client.get_card(TaskId("ENG-404".to_string())).await;
+        assert!(matches!(
+            missing,
+            Err(TaskTrackerClientGetCardError::NotFound)
+        ));
+
+        let requests = requests.lock().unwrap();
+        let (auth, body) = &requests[0];
+        assert_eq!(auth.as_deref(), Some("lin_api_test"));
+        assert_eq!(body["query"], ISSUE_QUERY);
+        assert_eq!(body["variables"]["id"], "ENG-1");
+    }
+
+    #[tokio::test]
+    async fn list_cards_filters_by_workflow_state() {
+        let (client, requests) = start_stub().await;
+
+        let cards = client.list_cards("team-1", "s-review").await.unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].title, "Fix login");
+
+        let requests = requests.lock().unwrap();
+        let (_, body) = &requests[0];
+        assert_eq!(body["query"], STATE_ISSUES_QUERY);
+        assert_eq!(body["variables"]["stateId"], "s-review");
+    }
+
+    #[tokio::test]
+    async fn move_checks_the_resulting_state() {
+        let (client, requests) = start_stub().await;
+
+        client
+            .move_task_to_column(TaskId("ENG-1".to_string()), "s-done")
+            .await
+            .unwrap();
+
+        let blocked = client
+            .move_task_to_column(TaskId("ENG-1".to_string()), "s-blocked")
+            .await;
+        assert!(matches!(
+            blocked,
+            Err(TaskTrackerClientMoveToColumnError::MoveValidationFailed)
+        ));
+
+        let requests = requests.lock().unwrap();
+        let (_, body) = &requests[0];
+        assert_eq!(body["variables"]["id"], "ENG-1");
+        assert_eq!(body["variables"]["stateId"], "s-done");
+    }
+}
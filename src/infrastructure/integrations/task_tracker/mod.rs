@@ -1 +1,5 @@
+pub mod github_projects;
+pub mod jira;
 pub mod kaiten;
+pub mod linear;
+pub mod youtrack;
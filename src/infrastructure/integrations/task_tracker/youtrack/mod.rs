@@ -0,0 +1,606 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::task::ports::task_tracker_client::{
+    TaskTrackerAssignee, TaskTrackerBoard, TaskTrackerCard, TaskTrackerCardDraft,
+    TaskTrackerClient, TaskTrackerClientGetCardError, TaskTrackerClientListError,
+    TaskTrackerClientMoveToColumnError, TaskTrackerClientWriteError, TaskTrackerColumn,
+    TaskTrackerSpace,
+};
+use crate::domain::task::value_objects::task_id::TaskId;
+use reqwest::{Client, Method, StatusCode};
+use serde_json::json;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTrackProject {
+    pub id: String,
+    pub name: String,
+    pub short_name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct YouTrackProjectRef {
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct YouTrackAgile {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub projects: Vec<YouTrackProjectRef>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTrackAgileColumns {
+    pub column_settings: YouTrackColumnSettings,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct YouTrackColumnSettings {
+    pub field: YouTrackNamed,
+    #[serde(default)]
+    pub columns: Vec<YouTrackAgileColumn>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTrackAgileColumn {
+    pub presentation: String,
+    #[serde(default)]
+    pub field_values: Vec<YouTrackNamed>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct YouTrackNamed {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTrackIssue {
+    pub id_readable: String,
+    pub summary: String,
+    #[serde(default)]
+    pub custom_fields: Vec<YouTrackCustomField>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTrackIssueSummary {
+    pub id_readable: String,
+    pub summary: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTrackProjectShortName {
+    pub short_name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct YouTrackUser {
+    pub login: String,
+    pub email: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct YouTrackCustomField {
+    pub name: String,
+    pub value: Option<YouTrackNamed>,
+}
+
+pub struct YouTrackClientBase(pub String);
+
+pub struct YouTrackClientToken(pub String);
+
+enum YouTrackRequestError {
+    NotFound,
+    Other(String),
+}
+
+impl std::fmt::Display for YouTrackRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            YouTrackRequestError::NotFound => write!(f, "Not found"),
+            YouTrackRequestError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+pub struct YouTrackClient {
+    base: YouTrackClientBase,
+    token: YouTrackClientToken,
+    client: Client,
+}
+
+impl YouTrackClient {
+    pub fn new(base: YouTrackClientBase, token: YouTrackClientToken) -> Self {
+        Self {
+            base: YouTrackClientBase(base.0.trim_end_matches('/').to_string()),
+            token,
+            client: Client::new(),
+        }
+    }
+
+    async fn send<Body>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Body>,
+    ) -> Result<String, YouTrackRequestError>
+    where
+        Body: Serialize + ?Sized,
+    {
+        let url = format!("{}/api{}", self.base.0, path);
+
+        tracing::debug!(method = %method, path = %path, "YouTrack API request");
+
+        let mut req = self
+            .client
+            .request(method, &url)
+            .bearer_auth(&self.token.0)
+            .header("Accept", "application/json");
+
+        if let Some(body) = body {
+            req = req.json(body);
+        }
+
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| YouTrackRequestError::Other(e.to_string()))?;
+
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| YouTrackRequestError::Other(e.to_string()))?;
+
+        tracing::debug!(status = %status, path = %path, body_len = text.len(), "YouTrack API response");
+
+        if status == StatusCode::NOT_FOUND {
+            return Err(YouTrackRequestError::NotFound);
+        }
+
+        if !status.is_success() {
+            return Err(YouTrackRequestError::Other(format!(
+                "YouTrack API {} for {}: {}",
+                status, path, text
+            )));
+        }
+
+        Ok(text)
+    }
+
+    async fn get<Response>(&self, path: &str) -> Result<Response, YouTrackRequestError>
+    where
+        Response: for<'de> Deserialize<'de>,
+    {
+        let text = self.send::<()>(Method::GET, path, None).await?;
+
+        serde_json::from_str::<Response>(&text)
+            .map_err(|e| YouTrackRequestError::Other(e.to_string()))
+    }
+
+    fn card_url(&self, id_readable: &str) -> String {
+        format!("{}/issue/{}", self.base.0, id_readable)
+    }
+
+    async fn get_issue(&self, task_id: &TaskId) -> Result<YouTrackIssue, YouTrackRequestError> {
+        self.get(&format!(
+            "/issues/{}?fields=idReadable,summary,customFields(name,value(name))",
+            task_id.0
+        ))
+        .await
+    }
+}
+
+/// Колонка доски — значение поля, по которому она построена (обычно State).
+/// В id колонки храним и поле, и значение: `State:QA`.
+fn column_id(field: &str, value: &str) -> String {
+    format!("{}:{}", field, value)
+}
+
+#[async_trait]
+impl TaskTrackerClient for YouTrackClient {
+    fn base_url(&self) -> &str {
+        &self.base.0
+    }
+
+    /// Перемещение — команда вида `State {QA}`, как если бы её ввели в YouTrack
+    async fn move_task_to_column(
+        &self,
+        task_id: TaskId,
+        column_id: &str,
+    ) -> Result<(), TaskTrackerClientMoveToColumnError> {
+        let (field, value) = column_id
+            .split_once(':')
+            .ok_or_else(|| TaskTrackerClientMoveToColumnError::ParseError(column_id.to_string()))?;
+
+        let body = json!({
+            "query": format!("{} {{{}}}", field, value),
+            "issues": [{ "idReadable": task_id.0 }],
+        });
+
+        self.send(Method::POST, "/commands", Some(&body))
+            .await
+            .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        let issue = self
+            .get_issue(&task_id)
+            .await
+            .map_err(|e| TaskTrackerClientMoveToColumnError::ClientError(e.to_string()))?;
+
+        let current = issue
+            .custom_fields
+            .iter()
+            .find(|f| f.name == field)
+            .and_then(|f| f.value.as_ref())
+            .map(|v| v.name.as_str());
+
+        if current != Some(value) {
+            tracing::error!(
+                task_id = %task_id,
+                column_id,
+                current = current.unwrap_or("-"),
+                "YouTrack issue field differs from the requested value after command"
+            );
+            return Err(TaskTrackerClientMoveToColumnError::MoveValidationFailed);
+        }
+
+        Ok(())
+    }
+
+    async fn get_card(
+        &self,
+        task_id: TaskId,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientGetCardError> {
+        let issue = self.get_issue(&task_id).await.map_err(|e| match e {
+            YouTrackRequestError::NotFound => TaskTrackerClientGetCardError::NotFound,
+            YouTrackRequestError::Other(msg) => TaskTrackerClientGetCardError::ClientError(msg),
+        })?;
+
+        Ok(TaskTrackerCard {
+            url: self.card_url(&issue.id_readable),
+            id: TaskId(issue.id_readable),
+            title: issue.summary,
+        })
+    }
+
+    async fn list_spaces(&self) -> Result<Vec<TaskTrackerSpace>, TaskTrackerClientListError> {
+        let projects: Vec<YouTrackProject> = self
+            .get("/admin/projects?fields=id,name,shortName&$top=100")
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(projects
+            .into_iter()
+            .map(|p| TaskTrackerSpace {
+                id: p.id,
+                title: format!("{} ({})", p.name, p.short_name),
+            })
+            .collect())
+    }
+
+    async fn list_boards(
+        &self,
+        space_id: &str,
+    ) -> Result<Vec<TaskTrackerBoard>, TaskTrackerClientListError> {
+        let agiles: Vec<YouTrackAgile> = self
+            .get("/agiles?fields=id,name,projects(id)&$top=100")
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(agiles
+            .into_iter()
+            .filter(|a| a.projects.iter().any(|p| p.id == space_id))
+            .map(|a| TaskTrackerBoard {
+                id: a.id,
+                title: a.name,
+            })
+            .collect())
+    }
+
+    async fn list_columns(
+        &self,
+        board_id: &str,
+    ) -> Result<Vec<TaskTrackerColumn>, TaskTrackerClientListError> {
+        let agile: YouTrackAgileColumns = self
+            .get(&format!(
+                "/agiles/{}?fields=columnSettings(field(name),columns(presentation,fieldValues(name)))",
+                board_id
+            ))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        let field = agile.column_settings.field.name;
+
+        Ok(agile
+            .column_settings
+            .columns
+            .into_iter()
+            .filter_map(|c| {
+                let value = c.field_values.first()?;
+                Some(TaskTrackerColumn {
+                    id: column_id(&field, &value.name),
+                    title: c.presentation,
+                })
+            })
+            .collect())
+    }
+
+    /// Колонка — значение поля, поэтому ищем запросом `project: {P} State: {QA}`
+    async fn list_cards(
+        &self,
+        space_id: &str,
+        column_id: &str,
+    ) -> Result<Vec<TaskTrackerCard>, TaskTrackerClientListError> {
+        let (field, value) = column_id
+            .split_once(':')
+            .ok_or_else(|| TaskTrackerClientListError::ClientError(column_id.to_string()))?;
+
+        let project: YouTrackProjectShortName = self
+            .get(&format!("/admin/projects/{}?fields=shortName", space_id))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        let query = format!(
+            "project: {{{}}} {}: {{{}}}",
+            project.short_name, field, value
+        );
+        let query: String = url::form_urlencoded::byte_serialize(query.as_bytes()).collect();
+
+        let issues: Vec<YouTrackIssueSummary> = self
+            .get(&format!(
+                "/issues?query={}&fields=idReadable,summary&$top=100",
+                query
+            ))
+            .await
+            .map_err(|e| TaskTrackerClientListError::ClientError(e.to_string()))?;
+
+        Ok(issues
+            .into_iter()
+            .map(|issue| TaskTrackerCard {
+                url: self.card_url(&issue.id_readable),
+                id: TaskId(issue.id_readable),
+                title: issue.summary,
+            })
+            .collect())
+    }
+
+    /// Колонка выставляется той же командой, что и при перемещении
+    async fn create_card(
+        &self,
+        space_id: &str,
+        column_id: Option<&str>,
+        draft: &TaskTrackerCardDraft,
+    ) -> Result<TaskTrackerCard, TaskTrackerClientWriteError> {
+        let body = json!({
+            "project": { "id": space_id },
+            "summary": draft.title,
+            "description": draft.description,
+        });
+
+        let text = self
+            .send(
+                Method::POST,
+                "/issues?fields=idReadable,summary",
+                Some(&body),
+            )
+            .await
+            .map_err(write_error)?;
+
+        let issue: YouTrackIssueSummary = serde_json::from_str(&text)
+            .map_err(|e| TaskTrackerClientWriteError::ClientError(e.to_string()))?;
+
+        if let Some(column_id) = column_id
+            && let Err(e) = self
+                .move_task_to_column(TaskId(issue.id_readable.clone()), column_id)
+                .await
+        {
+            tracing::warn!(error = %e, task_id = %issue.id_readable, "Created YouTrack issue left in initial state");
+        }
+
+        Ok(TaskTrackerCard {
+            url: self.card_url(&issue.id_readable),
+            id: TaskId(issue.id_readable),
+            title: issue.summary,
+        })
+    }
+
+    async fn assign(
+        &self,
+        task_id: TaskId,
+        assignee: &TaskTrackerAssignee,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let email = assignee
+            .email
+            .as_deref()
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        let query: String = url::form_urlencoded::byte_serialize(email.as_bytes()).collect();
+
+        let users: Vec<YouTrackUser> = self
+            .get(&format!(
+                "/users?query={}&fields=login,email&$top=10",
+                query
+            ))
+            .await
+            .map_err(write_error)?;
+
+        let user = users
+            .into_iter()
+            .find(|u| {
+                u.email
+                    .as_deref()
+                    .is_some_and(|e| e.eq_ignore_ascii_case(email))
+            })
+            .ok_or(TaskTrackerClientWriteError::AssigneeNotFound)?;
+
+        let body = json!({
+            "query": format!("Assignee {}", user.login),
+            "issues": [{ "idReadable": task_id.0 }],
+        });
+
+        self.send(Method::POST, "/commands", Some(&body))
+            .await
+            .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    /// Внешних ссылок в YouTrack нет — `add_link` по умолчанию пишет комментарий
+    async fn add_comment(
+        &self,
+        task_id: TaskId,
+        text: &str,
+    ) -> Result<(), TaskTrackerClientWriteError> {
+        let body = json!({ "text": text });
+
+        self.send(
+            Method::POST,
+            &format!("/issues/{}/comments", task_id.0),
+            Some(&body),
+        )
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+}
+
+fn write_error(error: YouTrackRequestError) -> TaskTrackerClientWriteError {
+    match error {
+        YouTrackRequestError::NotFound => TaskTrackerClientWriteError::NotFound,
+        YouTrackRequestError::Other(msg) => TaskTrackerClientWriteError::ClientError(msg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Bytes;
+    use axum::extract::State;
+    use axum::http::header::AUTHORIZATION;
+    use axum::http::{HeaderMap, Uri};
+    use axum::response::{IntoResponse, Response};
+    use axum::{Json, Router};
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+
+    /// Метод, путь с query, заголовок `Authorization` и тело запроса к заглушке
+    type Requests = Arc<Mutex<Vec<(String, String, Option<String>, Value)>>>;
+
+    async fn youtrack_stub(
+        State(requests): State<Requests>,
+        method: axum::http::Method,
+        uri: Uri,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> Response {
+        let auth = headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+        requests
+            .lock()
+            .unwrap()
+            .push((method.to_string(), uri.to_string(), auth, body));
+
+        match (method.as_str(), uri.path()) {
+            ("GET", "/api/admin/projects/0-1") => {
+                Json(json!({ "shortName": "APP" })).into_response()
+            }
+            ("GET", "/api/issues") => Json(json!([
+                { "idReadable": "APP-1", "summary": "Fix login" }
+            ]))
+            .into_response(),
+            ("POST", "/api/commands") => Json(json!({})).into_response(),
+            // Команда не применилась — поле осталось прежним
+            ("GET", "/api/issues/APP-1") => Json(json!({
+                "idReadable": "APP-1",
+                "summary": "Fix login",
+                "customFields": [{ "name": "State", "value": { "name": "Open" } }]
+            }))
+            .into_response(),
+            _ => axum::http::StatusCode::NOT_FOUND.into_response(),
+        }
+    }
+
+    /// Поднимает заглушку YouTrack на свободном порту
+    async fn start_stub() -> (YouTrackClient, Requests) {
+        let requests = Requests::default();
+        let app = Router::new()
+            .fallback(youtrack_stub)
+            .with_state(requests.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = YouTrackClient::new(
+            YouTrackClientBase(base),
+            YouTrackClientToken("perm:test".to_string()),
+        );
+
+        (client, requests)
+    }
+
+    #[tokio::test]
+    async fn list_cards_queries_by_project_and_column_field() {
+        let (client, requests) = start_stub().await;
+
+        let cards = client.list_cards("0-1", "State:In Review").await.unwrap();
+
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].id.0, "APP-1");
+        assert!(cards[0].url.ends_with("/issue/APP-1"));
+
+        let requests = requests.lock().unwrap();
+        let (_, path, auth, _) = &requests[0];
+        assert_eq!(path, "/api/admin/projects/0-1?fields=shortName");
+        assert_eq!(auth.as_deref(), Some("Bearer perm:test"));
+
+        let (_, path, _, _) = &requests[1];
+        assert_eq!(
+            path,
+            "/api/issues?query=project%3A+%7BAPP%7D+State%3A+%7BIn+Review%7D&fields=idReadable,summary&$top=100"
+        );
+    }
+
+    #[tokio::test]
+    async fn move_sends_command_and_checks_the_field() {
+        let (client, requests) = start_stub().await;
+
+        let result = client
+            .move_task_to_column(TaskId("APP-1".to_string()), "State:Done")
+            .await;
+        assert!(matches!(
+            result,
+            Err(TaskTrackerClientMoveToColumnError::MoveValidationFailed)
+        ));
+
+        let requests = requests.lock().unwrap();
+        let (method, path, _, body) = &requests[0];
+        assert_eq!(method, "POST");
+        assert_eq!(path, "/api/commands");
+        assert_eq!(body["query"], "State {Done}");
+        assert_eq!(body["issues"][0]["idReadable"], "APP-1");
+    }
+
+    #[tokio::test]
+    async fn get_card_maps_missing_issue_to_not_found() {
+        let (client, _) = start_stub().await;
+
+        let missing = client.get_card(TaskId("APP-404".to_string())).await;
+        assert!(matches!(
+            missing,
+            Err(TaskTrackerClientGetCardError::NotFound)
+        ));
+    }
+}
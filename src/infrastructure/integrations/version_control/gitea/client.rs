@@ -0,0 +1,950 @@
+use crate::domain::shared::date::range::DateRange;
+use crate::domain::version_control::ports::version_control_client::{
+    CommitStatus, ComparedCommit, OpenPullRequestSummary, RepositoryHook, RepositoryHookDelivery,
+    RepositoryHookDeliveryPayload, RepositoryHookSettings, UserPullRequestSummary,
+    VersionControlClient, VersionControlClientBranchCheckError,
+    VersionControlClientCommitStatusError, VersionControlClientCompareError,
+    VersionControlClientDateRangeReportError, VersionControlClientGetPrError,
+    VersionControlClientGetRepositoryError, VersionControlClientGetUserError,
+    VersionControlClientGetUserResponse, VersionControlClientListPullRequestsError,
+    VersionControlClientOrgMembershipError, VersionControlClientPostCommentError,
+    VersionControlClientRepositoryHookError, VersionControlClientSearchPrsError,
+};
+use crate::domain::version_control::value_objects::report::{
+    VersionControlDateRangeReport, VersionControlDateRangeReportAuthor,
+    VersionControlDateRangeReportCommit, VersionControlDateRangeReportPullRequest,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::Deserialize;
+
+/// Максимальный размер страницы, который Gitea отдаёт без настройки MAX_RESPONSE_ITEMS
+const GITEA_PAGE_LIMIT: usize = 50;
+
+#[derive(Debug, Deserialize)]
+struct GiteaRestUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRestHook {
+    id: u64,
+    #[serde(default)]
+    active: bool,
+    #[serde(default)]
+    events: Vec<String>,
+    #[serde(default)]
+    config: std::collections::HashMap<String, String>,
+}
+
+impl From<GiteaRestHook> for RepositoryHook {
+    fn from(mut hook: GiteaRestHook) -> Self {
+        Self {
+            id: hook.id,
+            url: hook.config.remove("url").unwrap_or_default(),
+            events: hook.events,
+            active: hook.active,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRestPullRequest {
+    number: u64,
+    title: String,
+    html_url: String,
+    state: String,
+    #[serde(default)]
+    merged: bool,
+    user: Option<GiteaRestUser>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    merged_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    requested_reviewers: Option<Vec<GiteaRestUser>>,
+    #[serde(default)]
+    additions: Option<i64>,
+    #[serde(default)]
+    deletions: Option<i64>,
+    #[serde(default)]
+    changed_files: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRestCommit {
+    sha: String,
+    commit: GiteaRestCommitDetails,
+    author: Option<GiteaRestUser>,
+    #[serde(default)]
+    stats: Option<GiteaRestCommitStats>,
+    #[serde(default)]
+    files: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRestCommitDetails {
+    message: String,
+    author: Option<GiteaRestCommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRestCommitAuthor {
+    name: Option<String>,
+    email: Option<String>,
+    date: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRestCommitStats {
+    additions: i64,
+    deletions: i64,
+}
+
+pub struct GiteaVersionControlClient {
+    base: String,
+    client: Client,
+}
+
+impl GiteaVersionControlClient {
+    pub fn new(base: String) -> Self {
+        Self {
+            base: base.trim_end_matches('/').to_string(),
+            client: Client::new(),
+        }
+    }
+
+    fn get(&self, access_token: &str, url: &str) -> RequestBuilder {
+        self.client
+            .get(url)
+            .header("Authorization", format!("token {}", access_token))
+            .header("User-Agent", "Telegram-Git-App")
+    }
+
+    /// `type` Gitea принимает только при создании, при обновлении он игнорируется
+    fn hook_body(settings: &RepositoryHookSettings) -> serde_json::Value {
+        serde_json::json!({
+            "type": "gitea",
+            "active": true,
+            "events": settings.events,
+            "config": {
+                "url": settings.url,
+                "content_type": "json",
+                "secret": settings.secret,
+            },
+        })
+    }
+
+    fn hook_status_error(status: StatusCode) -> VersionControlClientRepositoryHookError {
+        match status {
+            StatusCode::NOT_FOUND => VersionControlClientRepositoryHookError::NotFound,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                VersionControlClientRepositoryHookError::Unauthorized(format!(
+                    "Gitea returned {}",
+                    status
+                ))
+            }
+            s => VersionControlClientRepositoryHookError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            )),
+        }
+    }
+
+    async fn list_pull_requests_page(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        state: &str,
+        page: u32,
+    ) -> Result<Vec<GiteaRestPullRequest>, (StatusCode, String)> {
+        let url = format!(
+            "{}/repos/{}/{}/pulls?state={}&limit={}&page={}",
+            self.base, owner, repo, state, GITEA_PAGE_LIMIT, page
+        );
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err((status, format!("Gitea returned {}", status)));
+        }
+
+        resp.json()
+            .await
+            .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))
+    }
+
+    /// В Gitea нет аналога GitHub search API — обходим открытые PR каждого репозитория
+    async fn search_prs_internal(
+        &self,
+        access_token: &str,
+        repos: &[String],
+        matches: impl Fn(&GiteaRestPullRequest) -> bool,
+    ) -> Result<Vec<UserPullRequestSummary>, VersionControlClientSearchPrsError> {
+        let mut result = Vec::new();
+
+        for full_name in repos.iter().take(10) {
+            let Some((owner, repo)) = full_name.split_once('/') else {
+                continue;
+            };
+
+            let mut page: u32 = 1;
+            loop {
+                let prs = self
+                    .list_pull_requests_page(access_token, owner, repo, "open", page)
+                    .await
+                    .map_err(|(status, msg)| {
+                        if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+                            VersionControlClientSearchPrsError::Unauthorized(msg)
+                        } else {
+                            VersionControlClientSearchPrsError::Transport(msg)
+                        }
+                    })?;
+
+                let count = prs.len();
+                for pr in prs.into_iter().filter(|pr| matches(pr)) {
+                    result.push(UserPullRequestSummary {
+                        number: pr.number,
+                        title: pr.title,
+                        url: pr.html_url,
+                        repo: full_name.clone(),
+                        author_login: pr.user.map(|u| u.login).unwrap_or_default(),
+                        updated_at: pr.updated_at,
+                        created_at: pr.created_at,
+                    });
+                }
+
+                if count < GITEA_PAGE_LIMIT {
+                    break;
+                }
+                page += 1;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl VersionControlClient for GiteaVersionControlClient {
+    async fn get_user(
+        &self,
+        access_token: &str,
+    ) -> Result<VersionControlClientGetUserResponse, VersionControlClientGetUserError> {
+        #[derive(Debug, Deserialize)]
+        struct UserResponse {
+            id: i64,
+            login: String,
+            #[serde(default)]
+            email: Option<String>,
+        }
+
+        let url = format!("{}/user", self.base);
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientGetUserError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let user: UserResponse = resp
+                    .json()
+                    .await
+                    .map_err(|e| VersionControlClientGetUserError::Transport(e.to_string()))?;
+                Ok(VersionControlClientGetUserResponse {
+                    id: user.id,
+                    login: user.login,
+                    email: user.email.filter(|e| !e.is_empty()),
+                })
+            }
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => Err(
+                VersionControlClientGetUserError::Unauthorized(format!("Gitea returned {}", s)),
+            ),
+            s => Err(VersionControlClientGetUserError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn get_details_by_range(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        date_range: &DateRange,
+        author: Option<&str>,
+    ) -> Result<VersionControlDateRangeReport, VersionControlClientDateRangeReportError> {
+        let map_status = |s: StatusCode| {
+            if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN {
+                VersionControlClientDateRangeReportError::Unauthorized(format!(
+                    "Gitea returned {}",
+                    s
+                ))
+            } else {
+                VersionControlClientDateRangeReportError::Transport(format!(
+                    "Unexpected status: {}",
+                    s
+                ))
+            }
+        };
+
+        // ── Коммиты ветки за период ────────────────────────────────────────────
+        let mut commits = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let url = format!("{}/repos/{}/{}/commits", self.base, owner, repo);
+            let resp = self
+                .get(access_token, &url)
+                .query(&[
+                    ("sha", branch.to_string()),
+                    ("since", date_range.since.to_rfc3339()),
+                    ("until", date_range.until.to_rfc3339()),
+                    ("limit", GITEA_PAGE_LIMIT.to_string()),
+                    ("page", page.to_string()),
+                ])
+                .send()
+                .await
+                .map_err(|e| VersionControlClientDateRangeReportError::Transport(e.to_string()))?;
+
+            let status = resp.status();
+            if status == StatusCode::NOT_FOUND {
+                return Err(VersionControlClientDateRangeReportError::BranchNotFound(
+                    branch.to_string(),
+                ));
+            }
+            if !status.is_success() {
+                return Err(map_status(status));
+            }
+
+            let items: Vec<GiteaRestCommit> = resp
+                .json()
+                .await
+                .map_err(|e| VersionControlClientDateRangeReportError::Transport(e.to_string()))?;
+
+            let count = items.len();
+            for c in items {
+                let commit_author = c.commit.author;
+                commits.push(VersionControlDateRangeReportCommit {
+                    sha: c.sha,
+                    message: c.commit.message,
+                    authored_at: commit_author
+                        .as_ref()
+                        .map(|a| a.date)
+                        .unwrap_or_else(Utc::now),
+                    additions: c.stats.as_ref().map(|s| s.additions).unwrap_or(0),
+                    deletions: c.stats.as_ref().map(|s| s.deletions).unwrap_or(0),
+                    changed_files: c.files.map(|f| f.len() as i64),
+                    author: Some(VersionControlDateRangeReportAuthor {
+                        login: c.author.map(|u| u.login),
+                        name: commit_author.as_ref().and_then(|a| a.name.clone()),
+                        email: commit_author.and_then(|a| a.email),
+                    }),
+                });
+            }
+
+            if count < GITEA_PAGE_LIMIT {
+                break;
+            }
+            page += 1;
+        }
+
+        // ── PR, созданные за период ────────────────────────────────────────────
+        // Список отсортирован от новых к старым — останавливаемся на первой
+        // странице, где всё старше начала периода
+        let mut pull_requests = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let prs = self
+                .list_pull_requests_page(access_token, owner, repo, "all", page)
+                .await
+                .map_err(|(status, msg)| {
+                    if status == StatusCode::BAD_GATEWAY {
+                        VersionControlClientDateRangeReportError::Transport(msg)
+                    } else {
+                        map_status(status)
+                    }
+                })?;
+
+            let count = prs.len();
+            let mut reached_older = true;
+
+            for pr in prs {
+                if pr.created_at >= date_range.since {
+                    reached_older = false;
+                }
+                if pr.created_at < date_range.since || pr.created_at > date_range.until {
+                    continue;
+                }
+
+                let login = pr.user.map(|u| u.login);
+                if let Some(a) = author
+                    && !login
+                        .as_deref()
+                        .map(|l| l.eq_ignore_ascii_case(a))
+                        .unwrap_or(false)
+                {
+                    continue;
+                }
+
+                let state = if pr.merged {
+                    "merged".to_string()
+                } else {
+                    pr.state.to_lowercase()
+                };
+
+                pull_requests.push(VersionControlDateRangeReportPullRequest {
+                    number: pr.number as i64,
+                    title: pr.title,
+                    state,
+                    created_at: pr.created_at,
+                    merged_at: pr.merged_at,
+                    closed_at: pr.closed_at,
+                    additions: pr.additions.unwrap_or(0),
+                    deletions: pr.deletions.unwrap_or(0),
+                    changed_files: pr.changed_files.unwrap_or(0),
+                    author: login,
+                });
+            }
+
+            if count < GITEA_PAGE_LIMIT || reached_older {
+                break;
+            }
+            page += 1;
+        }
+
+        if let Some(login) = author {
+            commits.retain(|c| {
+                c.author
+                    .as_ref()
+                    .and_then(|a| a.login.as_deref())
+                    .map(|l| l.eq_ignore_ascii_case(login))
+                    .unwrap_or(false)
+            });
+        }
+
+        tracing::debug!(
+            repo = %repo,
+            commits = commits.len(),
+            prs = pull_requests.len(),
+            "Gitea report fetched (paginated)"
+        );
+
+        Ok(VersionControlDateRangeReport {
+            commits,
+            pull_requests,
+        })
+    }
+
+    async fn branch_exists(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<bool, VersionControlClientBranchCheckError> {
+        let url = format!("{}/repos/{}/{}/branches/{}", self.base, owner, repo, branch);
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientBranchCheckError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            status if status.is_success() => Ok(true),
+            status if status == StatusCode::NOT_FOUND => Ok(false),
+            status if status == StatusCode::FORBIDDEN || status == StatusCode::UNAUTHORIZED => {
+                Err(VersionControlClientBranchCheckError::Unauthorized(format!(
+                    "Gitea returned {}",
+                    status
+                )))
+            }
+            status => Err(VersionControlClientBranchCheckError::Transport(format!(
+                "Unexpected status: {}",
+                status
+            ))),
+        }
+    }
+
+    async fn post_pr_comment(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        body: &str,
+    ) -> Result<(), VersionControlClientPostCommentError> {
+        // PR в Gitea — это issue, комментарии общие
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/comments",
+            self.base, owner, repo, pr_number
+        );
+
+        let resp = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("token {}", access_token))
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientPostCommentError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => Ok(()),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => Err(
+                VersionControlClientPostCommentError::Unauthorized(format!("Gitea returned {}", s)),
+            ),
+            s => Err(VersionControlClientPostCommentError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn list_open_pull_requests(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<OpenPullRequestSummary>, VersionControlClientListPullRequestsError> {
+        let mut all = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let prs = self
+                .list_pull_requests_page(access_token, owner, repo, "open", page)
+                .await
+                .map_err(|(status, msg)| {
+                    if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+                        VersionControlClientListPullRequestsError::Unauthorized(msg)
+                    } else {
+                        VersionControlClientListPullRequestsError::Transport(msg)
+                    }
+                })?;
+
+            let count = prs.len();
+            for pr in prs {
+                all.push(OpenPullRequestSummary {
+                    number: pr.number,
+                    title: pr.title,
+                    url: pr.html_url,
+                    author_login: pr.user.map(|u| u.login).unwrap_or_default(),
+                    updated_at: pr.updated_at,
+                    requested_reviewers: pr
+                        .requested_reviewers
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|u| u.login)
+                        .collect(),
+                });
+            }
+
+            if count < GITEA_PAGE_LIMIT {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all)
+    }
+
+    async fn get_pr_mergeable_state(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<Option<String>, VersionControlClientGetPrError> {
+        #[derive(Debug, Deserialize)]
+        struct PrDetail {
+            #[serde(default)]
+            mergeable: Option<bool>,
+        }
+
+        let url = format!("{}/repos/{}/{}/pulls/{}", self.base, owner, repo, pr_number);
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientGetPrError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let detail: PrDetail = resp
+                    .json()
+                    .await
+                    .map_err(|e| VersionControlClientGetPrError::Transport(e.to_string()))?;
+                // Приводим к терминам GitHub, которые понимают сканеры конфликтов
+                Ok(detail.mergeable.map(|m| {
+                    if m {
+                        "clean".to_string()
+                    } else {
+                        "dirty".to_string()
+                    }
+                }))
+            }
+            s if s == StatusCode::NOT_FOUND => Err(VersionControlClientGetPrError::NotFound),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => Err(
+                VersionControlClientGetPrError::Unauthorized(format!("Gitea returned {}", s)),
+            ),
+            s => Err(VersionControlClientGetPrError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn search_user_authored_open_prs(
+        &self,
+        access_token: &str,
+        login: &str,
+        repos: &[String],
+    ) -> Result<Vec<UserPullRequestSummary>, VersionControlClientSearchPrsError> {
+        self.search_prs_internal(access_token, repos, |pr| {
+            pr.user
+                .as_ref()
+                .map(|u| u.login.eq_ignore_ascii_case(login))
+                .unwrap_or(false)
+        })
+        .await
+    }
+
+    async fn search_user_pending_reviews(
+        &self,
+        access_token: &str,
+        login: &str,
+        repos: &[String],
+    ) -> Result<Vec<UserPullRequestSummary>, VersionControlClientSearchPrsError> {
+        self.search_prs_internal(access_token, repos, |pr| {
+            pr.requested_reviewers
+                .as_ref()
+                .map(|rs| rs.iter().any(|u| u.login.eq_ignore_ascii_case(login)))
+                .unwrap_or(false)
+        })
+        .await
+    }
+
+    async fn is_user_in_organization(
+        &self,
+        access_token: &str,
+        org: &str,
+    ) -> Result<bool, VersionControlClientOrgMembershipError> {
+        #[derive(Debug, Deserialize)]
+        struct Org {
+            username: String,
+        }
+
+        let url = format!("{}/user/orgs?limit={}", self.base, GITEA_PAGE_LIMIT);
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientOrgMembershipError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let orgs: Vec<Org> = resp.json().await.map_err(|e| {
+                    VersionControlClientOrgMembershipError::Transport(e.to_string())
+                })?;
+                Ok(orgs.iter().any(|o| o.username.eq_ignore_ascii_case(org)))
+            }
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => {
+                Err(VersionControlClientOrgMembershipError::Unauthorized(
+                    format!("Gitea returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientOrgMembershipError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn get_default_branch(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<String, VersionControlClientGetRepositoryError> {
+        #[derive(Debug, Deserialize)]
+        struct RepositoryResponse {
+            default_branch: String,
+        }
+
+        let url = format!("{}/repos/{}/{}", self.base, owner, repo);
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientGetRepositoryError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let body: RepositoryResponse = resp.json().await.map_err(|e| {
+                    VersionControlClientGetRepositoryError::Transport(e.to_string())
+                })?;
+                Ok(body.default_branch)
+            }
+            s if s == StatusCode::NOT_FOUND => {
+                Err(VersionControlClientGetRepositoryError::NotFound)
+            }
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => {
+                Err(VersionControlClientGetRepositoryError::Unauthorized(
+                    format!("Gitea returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientGetRepositoryError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    /// `/compare` есть в Gitea с 1.22 и отдаёт коммиты одним ответом
+    async fn compare_commits(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ComparedCommit>, VersionControlClientCompareError> {
+        #[derive(Debug, Deserialize)]
+        struct CompareResponse {
+            #[serde(default)]
+            commits: Vec<GiteaRestCommit>,
+        }
+
+        let url = format!(
+            "{}/repos/{}/{}/compare/{}...{}",
+            self.base, owner, repo, base, head
+        );
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientCompareError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let body: CompareResponse = resp
+                    .json()
+                    .await
+                    .map_err(|e| VersionControlClientCompareError::Transport(e.to_string()))?;
+                Ok(body
+                    .commits
+                    .into_iter()
+                    .map(|c| ComparedCommit {
+                        message: c.commit.message,
+                        author_login: c.author.map(|a| a.login),
+                    })
+                    .collect())
+            }
+            s if s == StatusCode::NOT_FOUND => Err(VersionControlClientCompareError::NotFound),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => Err(
+                VersionControlClientCompareError::Unauthorized(format!("Gitea returned {}", s)),
+            ),
+            s => Err(VersionControlClientCompareError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn list_repository_hooks(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<RepositoryHook>, VersionControlClientRepositoryHookError> {
+        let url = format!(
+            "{}/repos/{}/{}/hooks?limit={}",
+            self.base, owner, repo, GITEA_PAGE_LIMIT
+        );
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hooks: Vec<GiteaRestHook> = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hooks.into_iter().map(RepositoryHook::from).collect())
+    }
+
+    async fn create_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/repos/{}/{}/hooks", self.base, owner, repo);
+
+        let resp = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("token {}", access_token))
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&Self::hook_body(settings))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hook: GiteaRestHook = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hook.into())
+    }
+
+    async fn update_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/repos/{}/{}/hooks/{}", self.base, owner, repo, hook_id);
+
+        let resp = self
+            .client
+            .patch(&url)
+            .header("Authorization", format!("token {}", access_token))
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&Self::hook_body(settings))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hook: GiteaRestHook = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hook.into())
+    }
+
+    async fn delete_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+    ) -> Result<(), VersionControlClientRepositoryHookError> {
+        let url = format!("{}/repos/{}/{}/hooks/{}", self.base, owner, repo, hook_id);
+
+        let resp = self
+            .client
+            .delete(&url)
+            .header("Authorization", format!("token {}", access_token))
+            .header("User-Agent", "Telegram-Git-App")
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        Ok(())
+    }
+
+    /// Gitea не отдаёт историю доставок через API — восстанавливать нечего
+    async fn list_repository_hook_deliveries(
+        &self,
+        _access_token: &str,
+        _owner: &str,
+        _repo: &str,
+        _hook_id: u64,
+        _since: DateTime<Utc>,
+    ) -> Result<Vec<RepositoryHookDelivery>, VersionControlClientRepositoryHookError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_repository_hook_delivery(
+        &self,
+        _access_token: &str,
+        _owner: &str,
+        _repo: &str,
+        _hook_id: u64,
+        _delivery_id: u64,
+    ) -> Result<RepositoryHookDeliveryPayload, VersionControlClientRepositoryHookError> {
+        Err(VersionControlClientRepositoryHookError::NotFound)
+    }
+
+    async fn create_commit_status(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &CommitStatus,
+    ) -> Result<(), VersionControlClientCommitStatusError> {
+        let url = format!("{}/repos/{}/{}/statuses/{}", self.base, owner, repo, sha);
+
+        let resp = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("token {}", access_token))
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&serde_json::json!({
+                "state": status.state.as_str(),
+                "context": status.context,
+                "description": status.description,
+                "target_url": status.target_url,
+            }))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientCommitStatusError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => Ok(()),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => {
+                Err(VersionControlClientCommitStatusError::Unauthorized(
+                    format!("Gitea returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientCommitStatusError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::infrastructure::drivers::cache::contract::CacheService;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Токен установки живёт час; за пять минут до истечения берём новый
+const INSTALLATION_TOKEN_EXPIRY_MARGIN_SECS: i64 = 300;
+/// Установка для репозитория меняется редко
+const INSTALLATION_ID_TTL_SECS: u64 = 3600;
+
+#[derive(Debug, Error)]
+pub enum GithubAppError {
+    #[error("Invalid GitHub App private key: {0}")]
+    InvalidKey(String),
+
+    #[error("Failed to sign GitHub App JWT: {0}")]
+    Jwt(String),
+
+    #[error("HTTP request error: {0}")]
+    Transport(String),
+
+    #[error("GitHub App is not installed for {0}")]
+    NotInstalled(String),
+
+    #[error("Unexpected status code: {0}")]
+    Status(StatusCode),
+}
+
+#[derive(Debug, Serialize)]
+struct GithubAppClaims {
+    iat: i64,
+    exp: i64,
+    iss: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubAppInstallation {
+    id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubAppInstallationToken {
+    token: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// Аутентификация GitHub App: JWT приложения → токен установки.
+/// Токены установок кэшируются в Redis в зашифрованном виде до истечения.
+pub struct GithubAppClient {
+    base: String,
+    app_id: String,
+    key: EncodingKey,
+    cache: Arc<dyn CacheService>,
+    reversible_cipher: Arc<ReversibleCipher>,
+    client: Client,
+}
+
+impl GithubAppClient {
+    pub fn new(
+        base: String,
+        app_id: String,
+        private_key_pem: &str,
+        cache: Arc<dyn CacheService>,
+        reversible_cipher: Arc<ReversibleCipher>,
+    ) -> Result<Self, GithubAppError> {
+        let key = EncodingKey::from_rsa_pem(private_key_pem.as_bytes())
+            .map_err(|e| GithubAppError::InvalidKey(e.to_string()))?;
+
+        Ok(Self {
+            base,
+            app_id,
+            key,
+            cache,
+            reversible_cipher,
+            client: Client::new(),
+        })
+    }
+
+    fn installation_cache_key(owner: &str, name: &str) -> String {
+        format!("github_app:installation:{}/{}", owner, name)
+    }
+
+    fn token_cache_key(installation_id: u64) -> String {
+        format!("github_app:token:{}", installation_id)
+    }
+
+    /// JWT приложения живёт не дольше 10 минут; `iat` сдвигаем назад
+    /// на случай расхождения часов с GitHub
+    fn jwt(&self) -> Result<String, GithubAppError> {
+        let now = Utc::now().timestamp();
+        let claims = GithubAppClaims {
+            iat: now - 60,
+            exp: now + 540,
+            iss: self.app_id.clone(),
+        };
+
+        jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.key)
+            .map_err(|e| GithubAppError::Jwt(e.to_string()))
+    }
+
+    /// Установка приложения, в которую входит репозиторий
+    pub async fn installation_id(&self, owner: &str, name: &str) -> Result<u64, GithubAppError> {
+        let key = Self::installation_cache_key(owner, name);
+
+        if let Ok(Some(cached)) = self.cache.get(&key).await
+            && let Ok(id) = cached.parse()
+        {
+            return Ok(id);
+        }
+
+        let resp = self
+            .client
+            .get(format!(
+                "{}/repos/{}/{}/installation",
+                self.base, owner, name
+            ))
+            .bearer_auth(self.jwt()?)
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "Telegram-Git-App")
+            .send()
+            .await
+            .map_err(|e| GithubAppError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {}
+            StatusCode::NOT_FOUND => {
+                return Err(GithubAppError::NotInstalled(format!("{}/{}", owner, name)));
+            }
+            s => return Err(GithubAppError::Status(s)),
+        }
+
+        let installation: GithubAppInstallation = resp
+            .json()
+            .await
+            .map_err(|e| GithubAppError::Transport(e.to_string()))?;
+
+        if let Err(e) = self
+            .cache
+            .set(&key, &installation.id.to_string(), INSTALLATION_ID_TTL_SECS)
+            .await
+        {
+            tracing::warn!(error = %e, key = %key, "Failed to cache GitHub App installation id");
+        }
+
+        Ok(installation.id)
+    }
+
+    /// Токен установки — из кэша, а если истёк — новый от GitHub
+    pub async fn installation_token(&self, installation_id: u64) -> Result<String, GithubAppError> {
+        let key = Self::token_cache_key(installation_id);
+
+        match self.cache.get(&key).await {
+            Ok(Some(cached)) => match self.reversible_cipher.decrypt(cached.as_str()) {
+                Ok(token) => return Ok(token),
+                Err(e) => {
+                    tracing::warn!(error = %e, key = %key, "Failed to decrypt cached installation token")
+                }
+            },
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, key = %key, "Cache read failed; requesting new installation token")
+            }
+        }
+
+        let resp = self
+            .client
+            .post(format!(
+                "{}/app/installations/{}/access_tokens",
+                self.base, installation_id
+            ))
+            .bearer_auth(self.jwt()?)
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "Telegram-Git-App")
+            .send()
+            .await
+            .map_err(|e| GithubAppError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {}
+            StatusCode::NOT_FOUND => {
+                return Err(GithubAppError::NotInstalled(format!(
+                    "installation {}",
+                    installation_id
+                )));
+            }
+            s => return Err(GithubAppError::Status(s)),
+        }
+
+        let body: GithubAppInstallationToken = resp
+            .json()
+            .await
+            .map_err(|e| GithubAppError::Transport(e.to_string()))?;
+
+        let ttl =
+            (body.expires_at - Utc::now()).num_seconds() - INSTALLATION_TOKEN_EXPIRY_MARGIN_SECS;
+
+        if ttl > 0 {
+            match self.reversible_cipher.encrypt(&body.token) {
+                Ok(encrypted) => {
+                    if let Err(e) = self.cache.set(&key, encrypted.value(), ttl as u64).await {
+                        tracing::warn!(error = %e, key = %key, "Failed to cache installation token");
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to encrypt installation token for cache")
+                }
+            }
+        }
+
+        Ok(body.token)
+    }
+
+    /// Токен установки, в которую входит репозиторий
+    pub async fn repository_token(
+        &self,
+        owner: &str,
+        name: &str,
+    ) -> Result<String, GithubAppError> {
+        let installation_id = self.installation_id(owner, name).await?;
+
+        let result = self.installation_token(installation_id).await;
+
+        // Установку удалили — забываем её, при следующем запросе найдём заново
+        if let Err(GithubAppError::NotInstalled(_)) = &result {
+            self.cache
+                .del(&Self::installation_cache_key(owner, name))
+                .await
+                .ok();
+        }
+
+        result
+    }
+
+    /// Установку удалили или приостановили — её токен больше не действует
+    pub async fn forget_installation(&self, installation_id: u64) {
+        let key = Self::token_cache_key(installation_id);
+
+        if let Err(e) = self.cache.del(&key).await {
+            tracing::warn!(error = %e, key = %key, "Failed to drop cached installation token");
+        }
+    }
+}
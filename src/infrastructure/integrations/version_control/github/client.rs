@@ -1,11 +1,14 @@
 use crate::domain::shared::date::range::DateRange;
 use crate::domain::version_control::ports::version_control_client::{
-    OpenPullRequestSummary, UserPullRequestSummary, VersionControlClient,
-    VersionControlClientBranchCheckError, VersionControlClientDateRangeReportError,
-    VersionControlClientGetPrError, VersionControlClientGetUserError,
+    CommitStatus, ComparedCommit, OpenPullRequestSummary, RepositoryHook, RepositoryHookDelivery,
+    RepositoryHookDeliveryPayload, RepositoryHookSettings, UserPullRequestSummary,
+    VersionControlClient, VersionControlClientBranchCheckError,
+    VersionControlClientCommitStatusError, VersionControlClientCompareError,
+    VersionControlClientDateRangeReportError, VersionControlClientGetPrError,
+    VersionControlClientGetRepositoryError, VersionControlClientGetUserError,
     VersionControlClientGetUserResponse, VersionControlClientListPullRequestsError,
     VersionControlClientOrgMembershipError, VersionControlClientPostCommentError,
-    VersionControlClientSearchPrsError,
+    VersionControlClientRepositoryHookError, VersionControlClientSearchPrsError,
 };
 use crate::domain::version_control::value_objects::report::{
     VersionControlDateRangeReport, VersionControlDateRangeReportAuthor,
@@ -32,11 +35,79 @@ struct GithubRestPullRequest {
     requested_reviewers: Vec<GithubRestUser>,
 }
 
+/// История доставок листается курсором из `Link`; дальше 500 попыток не смотрим
+const GITHUB_HOOK_DELIVERIES_PER_PAGE: usize = 100;
+const GITHUB_HOOK_DELIVERIES_MAX_PAGES: u32 = 5;
+
+/// Сравнение отдаёт коммиты страницами; дальше 1000 коммитов не смотрим
+const GITHUB_COMPARE_PER_PAGE: usize = 100;
+const GITHUB_COMPARE_MAX_PAGES: u32 = 10;
+
+#[derive(Debug, Deserialize)]
+struct GithubRestCompareCommit {
+    commit: GithubRestCompareCommitDetails,
+    author: Option<GithubRestUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRestCompareCommitDetails {
+    message: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct GithubRestUser {
     login: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct GithubRestHook {
+    id: u64,
+    #[serde(default)]
+    active: bool,
+    #[serde(default)]
+    events: Vec<String>,
+    config: GithubRestHookConfig,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRestHookConfig {
+    #[serde(default)]
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRestHookDelivery {
+    id: u64,
+    guid: String,
+    event: String,
+    #[serde(default)]
+    status_code: u16,
+    delivered_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<GithubRestHookDelivery> for RepositoryHookDelivery {
+    fn from(delivery: GithubRestHookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            guid: delivery.guid,
+            event: delivery.event,
+            status_code: delivery.status_code,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+impl From<GithubRestHook> for RepositoryHook {
+    fn from(hook: GithubRestHook) -> Self {
+        Self {
+            id: hook.id,
+            url: hook.config.url.unwrap_or_default(),
+            events: hook.events,
+            active: hook.active,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum GithubClientError {
     #[error("HTTP request error: {0}")]
@@ -129,6 +200,50 @@ impl GithubVersionControlClient {
         }
     }
 
+    fn hook_body(settings: &RepositoryHookSettings) -> serde_json::Value {
+        serde_json::json!({
+            "name": "web",
+            "active": true,
+            "events": settings.events,
+            "config": {
+                "url": settings.url,
+                "content_type": "json",
+                "secret": settings.secret,
+                "insecure_ssl": "0",
+            },
+        })
+    }
+
+    /// Ссылка `rel="next"` из заголовка `Link`
+    fn next_page_url(headers: &reqwest::header::HeaderMap) -> Option<String> {
+        let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+        link.split(',').find_map(|part| {
+            let (url, rel) = part.split_once(';')?;
+            rel.contains("rel=\"next\"").then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+        })
+    }
+
+    fn hook_status_error(status: reqwest::StatusCode) -> VersionControlClientRepositoryHookError {
+        match status {
+            reqwest::StatusCode::NOT_FOUND => VersionControlClientRepositoryHookError::NotFound,
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                VersionControlClientRepositoryHookError::Unauthorized(format!(
+                    "GitHub returned {}",
+                    status
+                ))
+            }
+            s => VersionControlClientRepositoryHookError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            )),
+        }
+    }
+
     async fn search_prs_internal(
         &self,
         access_token: &str,
@@ -679,6 +794,121 @@ impl VersionControlClient for GithubVersionControlClient {
         }
     }
 
+    async fn get_default_branch(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<String, VersionControlClientGetRepositoryError> {
+        #[derive(Debug, Deserialize)]
+        struct RepositoryResponse {
+            default_branch: String,
+        }
+
+        let url = format!("{}/repos/{}/{}", self.base, owner, repo);
+
+        let resp = self
+            .client
+            .get(&url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "Telegram-Git-App")
+            .send()
+            .await
+            .map_err(|e| VersionControlClientGetRepositoryError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let body: RepositoryResponse = resp.json().await.map_err(|e| {
+                    VersionControlClientGetRepositoryError::Transport(e.to_string())
+                })?;
+                Ok(body.default_branch)
+            }
+            s if s == reqwest::StatusCode::NOT_FOUND => {
+                Err(VersionControlClientGetRepositoryError::NotFound)
+            }
+            s if s == reqwest::StatusCode::UNAUTHORIZED || s == reqwest::StatusCode::FORBIDDEN => {
+                Err(VersionControlClientGetRepositoryError::Unauthorized(
+                    format!("GitHub returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientGetRepositoryError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn compare_commits(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ComparedCommit>, VersionControlClientCompareError> {
+        #[derive(Debug, Deserialize)]
+        struct CompareResponse {
+            #[serde(default)]
+            commits: Vec<GithubRestCompareCommit>,
+        }
+
+        let mut all = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let url = format!(
+                "{}/repos/{}/{}/compare/{}...{}?per_page={}&page={}",
+                self.base, owner, repo, base, head, GITHUB_COMPARE_PER_PAGE, page
+            );
+
+            let resp = self
+                .client
+                .get(&url)
+                .bearer_auth(access_token)
+                .header("User-Agent", "Telegram-Git-App")
+                .send()
+                .await
+                .map_err(|e| VersionControlClientCompareError::Transport(e.to_string()))?;
+
+            match resp.status() {
+                s if s.is_success() => {
+                    let body: CompareResponse = resp
+                        .json()
+                        .await
+                        .map_err(|e| VersionControlClientCompareError::Transport(e.to_string()))?;
+                    let count = body.commits.len();
+                    all.extend(body.commits.into_iter().map(|c| ComparedCommit {
+                        message: c.commit.message,
+                        author_login: c.author.map(|a| a.login),
+                    }));
+                    if count < GITHUB_COMPARE_PER_PAGE || page >= GITHUB_COMPARE_MAX_PAGES {
+                        break;
+                    }
+                    page += 1;
+                }
+                s if s == reqwest::StatusCode::NOT_FOUND => {
+                    return Err(VersionControlClientCompareError::NotFound);
+                }
+                s if s == reqwest::StatusCode::UNAUTHORIZED
+                    || s == reqwest::StatusCode::FORBIDDEN =>
+                {
+                    return Err(VersionControlClientCompareError::Unauthorized(format!(
+                        "GitHub returned {}",
+                        s
+                    )));
+                }
+                s => {
+                    return Err(VersionControlClientCompareError::Transport(format!(
+                        "Unexpected status: {}",
+                        s
+                    )));
+                }
+            }
+        }
+
+        Ok(all)
+    }
+
     async fn branch_exists(
         &self,
         access_token: &str,
@@ -715,4 +945,262 @@ impl VersionControlClient for GithubVersionControlClient {
             ))),
         }
     }
+
+    async fn list_repository_hooks(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<RepositoryHook>, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/repos/{}/{}/hooks?per_page=100", self.base, owner, repo);
+
+        let resp = self
+            .client
+            .get(&url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "Telegram-Git-App")
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hooks: Vec<GithubRestHook> = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hooks.into_iter().map(RepositoryHook::from).collect())
+    }
+
+    async fn create_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/repos/{}/{}/hooks", self.base, owner, repo);
+
+        let resp = self
+            .client
+            .post(&url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&Self::hook_body(settings))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hook: GithubRestHook = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hook.into())
+    }
+
+    async fn update_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/repos/{}/{}/hooks/{}", self.base, owner, repo, hook_id);
+
+        let resp = self
+            .client
+            .patch(&url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&Self::hook_body(settings))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hook: GithubRestHook = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hook.into())
+    }
+
+    async fn delete_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+    ) -> Result<(), VersionControlClientRepositoryHookError> {
+        let url = format!("{}/repos/{}/{}/hooks/{}", self.base, owner, repo, hook_id);
+
+        let resp = self
+            .client
+            .delete(&url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "Telegram-Git-App")
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        Ok(())
+    }
+
+    async fn list_repository_hook_deliveries(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<RepositoryHookDelivery>, VersionControlClientRepositoryHookError> {
+        let mut all = Vec::new();
+        let mut url = format!(
+            "{}/repos/{}/{}/hooks/{}/deliveries?per_page={}",
+            self.base, owner, repo, hook_id, GITHUB_HOOK_DELIVERIES_PER_PAGE
+        );
+
+        for _ in 0..GITHUB_HOOK_DELIVERIES_MAX_PAGES {
+            let resp = self
+                .client
+                .get(&url)
+                .bearer_auth(access_token)
+                .header("User-Agent", "Telegram-Git-App")
+                .send()
+                .await
+                .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+            if !resp.status().is_success() {
+                return Err(Self::hook_status_error(resp.status()));
+            }
+
+            let next = Self::next_page_url(resp.headers());
+
+            let page: Vec<GithubRestHookDelivery> = resp
+                .json()
+                .await
+                .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+            let reached_since = page.iter().any(|d| d.delivered_at < since);
+
+            all.extend(
+                page.into_iter()
+                    .filter(|d| d.delivered_at >= since)
+                    .map(RepositoryHookDelivery::from),
+            );
+
+            match next {
+                Some(next) if !reached_since => url = next,
+                _ => break,
+            }
+        }
+
+        Ok(all)
+    }
+
+    async fn get_repository_hook_delivery(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        delivery_id: u64,
+    ) -> Result<RepositoryHookDeliveryPayload, VersionControlClientRepositoryHookError> {
+        #[derive(Debug, Deserialize)]
+        struct DeliveryResponse {
+            guid: String,
+            event: String,
+            request: DeliveryRequest,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct DeliveryRequest {
+            payload: serde_json::Value,
+        }
+
+        let url = format!(
+            "{}/repos/{}/{}/hooks/{}/deliveries/{}",
+            self.base, owner, repo, hook_id, delivery_id
+        );
+
+        let resp = self
+            .client
+            .get(&url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "Telegram-Git-App")
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let body: DeliveryResponse = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(RepositoryHookDeliveryPayload {
+            guid: body.guid,
+            event: body.event,
+            payload: body.request.payload.to_string(),
+        })
+    }
+
+    async fn create_commit_status(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &CommitStatus,
+    ) -> Result<(), VersionControlClientCommitStatusError> {
+        let url = format!("{}/repos/{}/{}/statuses/{}", self.base, owner, repo, sha);
+
+        let resp = self
+            .client
+            .post(&url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "Telegram-Git-App")
+            .json(&serde_json::json!({
+                "state": status.state.as_str(),
+                "context": status.context,
+                "description": status.description,
+                "target_url": status.target_url,
+            }))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientCommitStatusError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => Ok(()),
+            s if s == reqwest::StatusCode::UNAUTHORIZED || s == reqwest::StatusCode::FORBIDDEN => {
+                Err(VersionControlClientCommitStatusError::Unauthorized(
+                    format!("GitHub returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientCommitStatusError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
 }
@@ -1,2 +1,3 @@
+pub mod app;
 pub mod client;
 pub mod error;
@@ -0,0 +1,926 @@
+use crate::domain::shared::date::range::DateRange;
+use crate::domain::version_control::ports::version_control_client::{
+    CommitStatus, CommitStatusState, ComparedCommit, OpenPullRequestSummary, RepositoryHook,
+    RepositoryHookDelivery, RepositoryHookDeliveryPayload, RepositoryHookSettings,
+    UserPullRequestSummary, VersionControlClient, VersionControlClientBranchCheckError,
+    VersionControlClientCommitStatusError, VersionControlClientCompareError,
+    VersionControlClientDateRangeReportError, VersionControlClientGetPrError,
+    VersionControlClientGetRepositoryError, VersionControlClientGetUserError,
+    VersionControlClientGetUserResponse, VersionControlClientListPullRequestsError,
+    VersionControlClientOrgMembershipError, VersionControlClientPostCommentError,
+    VersionControlClientRepositoryHookError, VersionControlClientSearchPrsError,
+};
+use crate::domain::version_control::value_objects::report::{
+    VersionControlDateRangeReport, VersionControlDateRangeReportAuthor,
+    VersionControlDateRangeReportCommit, VersionControlDateRangeReportPullRequest,
+};
+use crate::infrastructure::contracts::gitlab::event_type::GITLAB_REPOSITORY_HOOK_EVENTS;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::Deserialize;
+
+/// Максимальный `per_page`, который принимает GitLab
+const GITLAB_PAGE_LIMIT: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct GitlabRestUser {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabRestHook {
+    id: u64,
+    url: String,
+    /// `disabled` — GitLab отключил хук после череды ошибок доставки
+    #[serde(default)]
+    alert_status: Option<String>,
+    #[serde(flatten)]
+    flags: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl From<GitlabRestHook> for RepositoryHook {
+    fn from(hook: GitlabRestHook) -> Self {
+        Self {
+            id: hook.id,
+            url: hook.url,
+            events: GITLAB_REPOSITORY_HOOK_EVENTS
+                .iter()
+                .filter(|event| hook.flags.get(**event).and_then(|v| v.as_bool()) == Some(true))
+                .map(|event| event.to_string())
+                .collect(),
+            active: hook.alert_status.as_deref() != Some("disabled"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabRestMergeRequest {
+    iid: u64,
+    title: String,
+    web_url: String,
+    state: String,
+    author: Option<GitlabRestUser>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    merged_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    reviewers: Vec<GitlabRestUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabRestCommit {
+    id: String,
+    message: String,
+    author_name: Option<String>,
+    author_email: Option<String>,
+    authored_date: DateTime<Utc>,
+    #[serde(default)]
+    stats: Option<GitlabRestCommitStats>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabRestCommitStats {
+    additions: i64,
+    deletions: i64,
+}
+
+pub struct GitlabVersionControlClient {
+    base: String,
+    client: Client,
+}
+
+impl GitlabVersionControlClient {
+    pub fn new(base: String) -> Self {
+        Self {
+            base: base.trim_end_matches('/').to_string(),
+            client: Client::new(),
+        }
+    }
+
+    /// Проект адресуется полным путём; `owner` может содержать подгруппы
+    fn project_url(&self, owner: &str, repo: &str) -> String {
+        format!(
+            "{}/projects/{}",
+            self.base,
+            Self::encode(&format!("{}/{}", owner, repo))
+        )
+    }
+
+    fn encode(value: &str) -> String {
+        url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+    }
+
+    fn authorized(&self, builder: RequestBuilder, access_token: &str) -> RequestBuilder {
+        builder
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("User-Agent", "Telegram-Git-App")
+    }
+
+    fn get(&self, access_token: &str, url: &str) -> RequestBuilder {
+        self.authorized(self.client.get(url), access_token)
+    }
+
+    fn hook_body(settings: &RepositoryHookSettings) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "url": settings.url,
+            "token": settings.secret,
+            "enable_ssl_verification": true,
+        });
+        for event in GITLAB_REPOSITORY_HOOK_EVENTS {
+            body[*event] = serde_json::Value::Bool(settings.events.iter().any(|e| e == event));
+        }
+        body
+    }
+
+    fn hook_status_error(status: StatusCode) -> VersionControlClientRepositoryHookError {
+        match status {
+            StatusCode::NOT_FOUND => VersionControlClientRepositoryHookError::NotFound,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                VersionControlClientRepositoryHookError::Unauthorized(format!(
+                    "GitLab returned {}",
+                    status
+                ))
+            }
+            s => VersionControlClientRepositoryHookError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            )),
+        }
+    }
+
+    async fn list_merge_requests_page(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        filters: &[(&str, String)],
+        page: u32,
+    ) -> Result<Vec<GitlabRestMergeRequest>, (StatusCode, String)> {
+        let url = format!("{}/merge_requests", self.project_url(owner, repo));
+
+        let resp = self
+            .get(access_token, &url)
+            .query(filters)
+            .query(&[
+                ("per_page", GITLAB_PAGE_LIMIT.to_string()),
+                ("page", page.to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err((status, format!("GitLab returned {}", status)));
+        }
+
+        resp.json()
+            .await
+            .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))
+    }
+
+    /// Глобальный `/merge_requests` видит и чужие проекты — обходим только переданные
+    async fn search_merge_requests(
+        &self,
+        access_token: &str,
+        repos: &[String],
+        filter: (&str, &str),
+    ) -> Result<Vec<UserPullRequestSummary>, VersionControlClientSearchPrsError> {
+        let mut result = Vec::new();
+
+        for full_name in repos.iter().take(10) {
+            let Some((owner, repo)) = full_name.rsplit_once('/') else {
+                continue;
+            };
+
+            let filters = [
+                ("state", "opened".to_string()),
+                (filter.0, filter.1.to_string()),
+            ];
+
+            let mut page: u32 = 1;
+            loop {
+                let mrs = self
+                    .list_merge_requests_page(access_token, owner, repo, &filters, page)
+                    .await
+                    .map_err(|(status, msg)| {
+                        if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+                            VersionControlClientSearchPrsError::Unauthorized(msg)
+                        } else {
+                            VersionControlClientSearchPrsError::Transport(msg)
+                        }
+                    })?;
+
+                let count = mrs.len();
+                for mr in mrs {
+                    result.push(UserPullRequestSummary {
+                        number: mr.iid,
+                        title: mr.title,
+                        url: mr.web_url,
+                        repo: full_name.clone(),
+                        author_login: mr.author.map(|u| u.username).unwrap_or_default(),
+                        updated_at: mr.updated_at,
+                        created_at: mr.created_at,
+                    });
+                }
+
+                if count < GITLAB_PAGE_LIMIT {
+                    break;
+                }
+                page += 1;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl VersionControlClient for GitlabVersionControlClient {
+    async fn get_user(
+        &self,
+        access_token: &str,
+    ) -> Result<VersionControlClientGetUserResponse, VersionControlClientGetUserError> {
+        #[derive(Debug, Deserialize)]
+        struct UserResponse {
+            id: i64,
+            username: String,
+            #[serde(default)]
+            email: Option<String>,
+        }
+
+        let url = format!("{}/user", self.base);
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientGetUserError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let user: UserResponse = resp
+                    .json()
+                    .await
+                    .map_err(|e| VersionControlClientGetUserError::Transport(e.to_string()))?;
+                Ok(VersionControlClientGetUserResponse {
+                    id: user.id,
+                    login: user.username,
+                    email: user.email.filter(|e| !e.is_empty()),
+                })
+            }
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => Err(
+                VersionControlClientGetUserError::Unauthorized(format!("GitLab returned {}", s)),
+            ),
+            s => Err(VersionControlClientGetUserError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn get_details_by_range(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        date_range: &DateRange,
+        author: Option<&str>,
+    ) -> Result<VersionControlDateRangeReport, VersionControlClientDateRangeReportError> {
+        let map_status = |s: StatusCode| {
+            if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN {
+                VersionControlClientDateRangeReportError::Unauthorized(format!(
+                    "GitLab returned {}",
+                    s
+                ))
+            } else {
+                VersionControlClientDateRangeReportError::Transport(format!(
+                    "Unexpected status: {}",
+                    s
+                ))
+            }
+        };
+
+        // ── Коммиты ветки за период ────────────────────────────────────────────
+        // Логина у коммита в GitLab нет: `author` API сравнивает с именем и почтой
+        let mut commits = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let url = format!("{}/repository/commits", self.project_url(owner, repo));
+            let mut query = vec![
+                ("ref_name", branch.to_string()),
+                ("since", date_range.since.to_rfc3339()),
+                ("until", date_range.until.to_rfc3339()),
+                ("with_stats", "true".to_string()),
+                ("per_page", GITLAB_PAGE_LIMIT.to_string()),
+                ("page", page.to_string()),
+            ];
+            if let Some(login) = author {
+                query.push(("author", login.to_string()));
+            }
+
+            let resp = self
+                .get(access_token, &url)
+                .query(&query)
+                .send()
+                .await
+                .map_err(|e| VersionControlClientDateRangeReportError::Transport(e.to_string()))?;
+
+            let status = resp.status();
+            if status == StatusCode::NOT_FOUND {
+                return Err(VersionControlClientDateRangeReportError::BranchNotFound(
+                    branch.to_string(),
+                ));
+            }
+            if !status.is_success() {
+                return Err(map_status(status));
+            }
+
+            let items: Vec<GitlabRestCommit> = resp
+                .json()
+                .await
+                .map_err(|e| VersionControlClientDateRangeReportError::Transport(e.to_string()))?;
+
+            let count = items.len();
+            for c in items {
+                commits.push(VersionControlDateRangeReportCommit {
+                    sha: c.id,
+                    message: c.message,
+                    authored_at: c.authored_date,
+                    additions: c.stats.as_ref().map(|s| s.additions).unwrap_or(0),
+                    deletions: c.stats.as_ref().map(|s| s.deletions).unwrap_or(0),
+                    changed_files: None,
+                    author: Some(VersionControlDateRangeReportAuthor {
+                        login: None,
+                        name: c.author_name,
+                        email: c.author_email,
+                    }),
+                });
+            }
+
+            if count < GITLAB_PAGE_LIMIT {
+                break;
+            }
+            page += 1;
+        }
+
+        // ── MR, созданные за период ────────────────────────────────────────────
+        let mut filters = vec![
+            ("state", "all".to_string()),
+            ("created_after", date_range.since.to_rfc3339()),
+            ("created_before", date_range.until.to_rfc3339()),
+        ];
+        if let Some(login) = author {
+            filters.push(("author_username", login.to_string()));
+        }
+
+        let mut pull_requests = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let mrs = self
+                .list_merge_requests_page(access_token, owner, repo, &filters, page)
+                .await
+                .map_err(|(status, msg)| {
+                    if status == StatusCode::BAD_GATEWAY {
+                        VersionControlClientDateRangeReportError::Transport(msg)
+                    } else {
+                        map_status(status)
+                    }
+                })?;
+
+            let count = mrs.len();
+            for mr in mrs {
+                pull_requests.push(VersionControlDateRangeReportPullRequest {
+                    number: mr.iid as i64,
+                    title: mr.title,
+                    // Приводим к терминам GitHub: `opened` → `open`
+                    state: match mr.state.as_str() {
+                        "opened" => "open".to_string(),
+                        other => other.to_string(),
+                    },
+                    created_at: mr.created_at,
+                    merged_at: mr.merged_at,
+                    closed_at: mr.closed_at,
+                    // В списке MR размер изменений не отдаётся
+                    additions: 0,
+                    deletions: 0,
+                    changed_files: 0,
+                    author: mr.author.map(|u| u.username),
+                });
+            }
+
+            if count < GITLAB_PAGE_LIMIT {
+                break;
+            }
+            page += 1;
+        }
+
+        tracing::debug!(
+            repo = %repo,
+            commits = commits.len(),
+            prs = pull_requests.len(),
+            "GitLab report fetched (paginated)"
+        );
+
+        Ok(VersionControlDateRangeReport {
+            commits,
+            pull_requests,
+        })
+    }
+
+    async fn branch_exists(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<bool, VersionControlClientBranchCheckError> {
+        let url = format!(
+            "{}/repository/branches/{}",
+            self.project_url(owner, repo),
+            Self::encode(branch)
+        );
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientBranchCheckError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            status if status.is_success() => Ok(true),
+            status if status == StatusCode::NOT_FOUND => Ok(false),
+            status if status == StatusCode::FORBIDDEN || status == StatusCode::UNAUTHORIZED => {
+                Err(VersionControlClientBranchCheckError::Unauthorized(format!(
+                    "GitLab returned {}",
+                    status
+                )))
+            }
+            status => Err(VersionControlClientBranchCheckError::Transport(format!(
+                "Unexpected status: {}",
+                status
+            ))),
+        }
+    }
+
+    async fn post_pr_comment(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        body: &str,
+    ) -> Result<(), VersionControlClientPostCommentError> {
+        let url = format!(
+            "{}/merge_requests/{}/notes",
+            self.project_url(owner, repo),
+            pr_number
+        );
+
+        let resp = self
+            .authorized(self.client.post(&url), access_token)
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientPostCommentError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => Ok(()),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => {
+                Err(VersionControlClientPostCommentError::Unauthorized(format!(
+                    "GitLab returned {}",
+                    s
+                )))
+            }
+            s => Err(VersionControlClientPostCommentError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn list_open_pull_requests(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<OpenPullRequestSummary>, VersionControlClientListPullRequestsError> {
+        let filters = [("state", "opened".to_string())];
+        let mut all = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let mrs = self
+                .list_merge_requests_page(access_token, owner, repo, &filters, page)
+                .await
+                .map_err(|(status, msg)| {
+                    if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+                        VersionControlClientListPullRequestsError::Unauthorized(msg)
+                    } else {
+                        VersionControlClientListPullRequestsError::Transport(msg)
+                    }
+                })?;
+
+            let count = mrs.len();
+            for mr in mrs {
+                all.push(OpenPullRequestSummary {
+                    number: mr.iid,
+                    title: mr.title,
+                    url: mr.web_url,
+                    author_login: mr.author.map(|u| u.username).unwrap_or_default(),
+                    updated_at: mr.updated_at,
+                    requested_reviewers: mr.reviewers.into_iter().map(|u| u.username).collect(),
+                });
+            }
+
+            if count < GITLAB_PAGE_LIMIT {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all)
+    }
+
+    async fn get_pr_mergeable_state(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<Option<String>, VersionControlClientGetPrError> {
+        #[derive(Debug, Deserialize)]
+        struct MergeRequestDetail {
+            #[serde(default)]
+            merge_status: Option<String>,
+            #[serde(default)]
+            has_conflicts: bool,
+        }
+
+        let url = format!(
+            "{}/merge_requests/{}",
+            self.project_url(owner, repo),
+            pr_number
+        );
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientGetPrError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let detail: MergeRequestDetail = resp
+                    .json()
+                    .await
+                    .map_err(|e| VersionControlClientGetPrError::Transport(e.to_string()))?;
+                // Пока GitLab не пересчитал MR, конфликтов ещё не знаем.
+                // Приводим к терминам GitHub, которые понимают сканеры конфликтов
+                Ok(match detail.merge_status.as_deref() {
+                    Some("unchecked" | "checking" | "cannot_be_merged_recheck") | None => None,
+                    Some(_) if detail.has_conflicts => Some("dirty".to_string()),
+                    Some(_) => Some("clean".to_string()),
+                })
+            }
+            s if s == StatusCode::NOT_FOUND => Err(VersionControlClientGetPrError::NotFound),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => Err(
+                VersionControlClientGetPrError::Unauthorized(format!("GitLab returned {}", s)),
+            ),
+            s => Err(VersionControlClientGetPrError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn search_user_authored_open_prs(
+        &self,
+        access_token: &str,
+        login: &str,
+        repos: &[String],
+    ) -> Result<Vec<UserPullRequestSummary>, VersionControlClientSearchPrsError> {
+        self.search_merge_requests(access_token, repos, ("author_username", login))
+            .await
+    }
+
+    async fn search_user_pending_reviews(
+        &self,
+        access_token: &str,
+        login: &str,
+        repos: &[String],
+    ) -> Result<Vec<UserPullRequestSummary>, VersionControlClientSearchPrsError> {
+        self.search_merge_requests(access_token, repos, ("reviewer_username", login))
+            .await
+    }
+
+    /// Организация в GitLab — группа; состоим в ней, если видим себя среди
+    /// участников (включая унаследованных из родительских групп)
+    async fn is_user_in_organization(
+        &self,
+        access_token: &str,
+        org: &str,
+    ) -> Result<bool, VersionControlClientOrgMembershipError> {
+        let user = self.get_user(access_token).await.map_err(|e| match e {
+            VersionControlClientGetUserError::Unauthorized(msg) => {
+                VersionControlClientOrgMembershipError::Unauthorized(msg)
+            }
+            VersionControlClientGetUserError::Transport(msg) => {
+                VersionControlClientOrgMembershipError::Transport(msg)
+            }
+        })?;
+
+        let url = format!(
+            "{}/groups/{}/members/all/{}",
+            self.base,
+            Self::encode(org),
+            user.id
+        );
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientOrgMembershipError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => Ok(true),
+            s if s == StatusCode::NOT_FOUND => Ok(false),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => {
+                Err(VersionControlClientOrgMembershipError::Unauthorized(
+                    format!("GitLab returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientOrgMembershipError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn get_default_branch(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<String, VersionControlClientGetRepositoryError> {
+        #[derive(Debug, Deserialize)]
+        struct ProjectResponse {
+            default_branch: String,
+        }
+
+        let url = self.project_url(owner, repo);
+
+        let resp = self
+            .get(access_token, &url)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientGetRepositoryError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let body: ProjectResponse = resp.json().await.map_err(|e| {
+                    VersionControlClientGetRepositoryError::Transport(e.to_string())
+                })?;
+                Ok(body.default_branch)
+            }
+            s if s == StatusCode::NOT_FOUND => {
+                Err(VersionControlClientGetRepositoryError::NotFound)
+            }
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => {
+                Err(VersionControlClientGetRepositoryError::Unauthorized(
+                    format!("GitLab returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientGetRepositoryError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn compare_commits(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ComparedCommit>, VersionControlClientCompareError> {
+        #[derive(Debug, Deserialize)]
+        struct CompareResponse {
+            #[serde(default)]
+            commits: Vec<GitlabRestCommit>,
+        }
+
+        let url = format!("{}/repository/compare", self.project_url(owner, repo));
+
+        let resp = self
+            .get(access_token, &url)
+            .query(&[("from", base), ("to", head)])
+            .send()
+            .await
+            .map_err(|e| VersionControlClientCompareError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => {
+                let body: CompareResponse = resp
+                    .json()
+                    .await
+                    .map_err(|e| VersionControlClientCompareError::Transport(e.to_string()))?;
+                Ok(body
+                    .commits
+                    .into_iter()
+                    .map(|c| ComparedCommit {
+                        message: c.message,
+                        author_login: None,
+                    })
+                    .collect())
+            }
+            s if s == StatusCode::NOT_FOUND => Err(VersionControlClientCompareError::NotFound),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => Err(
+                VersionControlClientCompareError::Unauthorized(format!("GitLab returned {}", s)),
+            ),
+            s => Err(VersionControlClientCompareError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn list_repository_hooks(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<RepositoryHook>, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/hooks", self.project_url(owner, repo));
+
+        let resp = self
+            .get(access_token, &url)
+            .query(&[("per_page", GITLAB_PAGE_LIMIT.to_string())])
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hooks: Vec<GitlabRestHook> = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hooks.into_iter().map(RepositoryHook::from).collect())
+    }
+
+    async fn create_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/hooks", self.project_url(owner, repo));
+
+        let resp = self
+            .authorized(self.client.post(&url), access_token)
+            .json(&Self::hook_body(settings))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hook: GitlabRestHook = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hook.into())
+    }
+
+    async fn update_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        settings: &RepositoryHookSettings,
+    ) -> Result<RepositoryHook, VersionControlClientRepositoryHookError> {
+        let url = format!("{}/hooks/{}", self.project_url(owner, repo), hook_id);
+
+        let resp = self
+            .authorized(self.client.put(&url), access_token)
+            .json(&Self::hook_body(settings))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        let hook: GitlabRestHook = resp
+            .json()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        Ok(hook.into())
+    }
+
+    async fn delete_repository_hook(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+    ) -> Result<(), VersionControlClientRepositoryHookError> {
+        let url = format!("{}/hooks/{}", self.project_url(owner, repo), hook_id);
+
+        let resp = self
+            .authorized(self.client.delete(&url), access_token)
+            .send()
+            .await
+            .map_err(|e| VersionControlClientRepositoryHookError::Transport(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            return Err(Self::hook_status_error(resp.status()));
+        }
+
+        Ok(())
+    }
+
+    /// История доставок хуков в API GitLab не отдаётся — восстанавливать нечего
+    async fn list_repository_hook_deliveries(
+        &self,
+        _access_token: &str,
+        _owner: &str,
+        _repo: &str,
+        _hook_id: u64,
+        _since: DateTime<Utc>,
+    ) -> Result<Vec<RepositoryHookDelivery>, VersionControlClientRepositoryHookError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_repository_hook_delivery(
+        &self,
+        _access_token: &str,
+        _owner: &str,
+        _repo: &str,
+        _hook_id: u64,
+        _delivery_id: u64,
+    ) -> Result<RepositoryHookDeliveryPayload, VersionControlClientRepositoryHookError> {
+        Err(VersionControlClientRepositoryHookError::NotFound)
+    }
+
+    async fn create_commit_status(
+        &self,
+        access_token: &str,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &CommitStatus,
+    ) -> Result<(), VersionControlClientCommitStatusError> {
+        let url = format!("{}/statuses/{}", self.project_url(owner, repo), sha);
+
+        // В GitLab проваленный статус называется `failed`, а имя проверки — `name`
+        let state = match status.state {
+            CommitStatusState::Success => "success",
+            CommitStatusState::Failure => "failed",
+        };
+
+        let resp = self
+            .authorized(self.client.post(&url), access_token)
+            .json(&serde_json::json!({
+                "state": state,
+                "name": status.context,
+                "description": status.description,
+                "target_url": status.target_url,
+            }))
+            .send()
+            .await
+            .map_err(|e| VersionControlClientCommitStatusError::Transport(e.to_string()))?;
+
+        match resp.status() {
+            s if s.is_success() => Ok(()),
+            s if s == StatusCode::UNAUTHORIZED || s == StatusCode::FORBIDDEN => {
+                Err(VersionControlClientCommitStatusError::Unauthorized(
+                    format!("GitLab returned {}", s),
+                ))
+            }
+            s => Err(VersionControlClientCommitStatusError::Transport(format!(
+                "Unexpected status: {}",
+                s
+            ))),
+        }
+    }
+}
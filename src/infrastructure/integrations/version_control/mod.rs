@@ -1 +1,3 @@
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
@@ -55,6 +55,8 @@ struct ListenerWrapper<E: DomainEvent> {
 pub struct EventBus {
     listeners: Mutex<HashMap<TypeId, Vec<Box<dyn Any + Send + Sync>>>>,
     registry: Mutex<HashMap<String, Arc<dyn EventDeserializer>>>,
+    // Имена слушателей по event_name — для dry-run повторной отправки
+    listener_names: Mutex<HashMap<String, Vec<&'static str>>>,
 }
 
 impl EventBus {
@@ -62,6 +64,7 @@ impl EventBus {
         Self {
             listeners: Mutex::new(HashMap::new()),
             registry: Mutex::new(HashMap::new()),
+            listener_names: Mutex::new(HashMap::new()),
         }
     }
 
@@ -90,6 +93,29 @@ impl EventBus {
                 registry.insert(E::EVENT_NAME.to_string(), handler);
             }
         }
+
+        {
+            let type_name = std::any::type_name::<L>();
+            let short_name = type_name.rsplit("::").next().unwrap_or(type_name);
+
+            self.listener_names
+                .lock()
+                .await
+                .entry(E::EVENT_NAME.to_string())
+                .or_default()
+                .push(short_name);
+        }
+    }
+
+    /// Слушатели, подписанные на событие. Сами слушатели могут дополнительно
+    /// отфильтровать событие внутри `handle`.
+    pub async fn listener_names(&self, event_name: &str) -> Vec<String> {
+        self.listener_names
+            .lock()
+            .await
+            .get(event_name)
+            .map(|names| names.iter().map(|n| n.to_string()).collect())
+            .unwrap_or_default()
     }
 
     pub async fn dispatch<E: DomainEvent + 'static>(&self, event: &E) {
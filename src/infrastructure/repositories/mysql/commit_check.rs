@@ -0,0 +1,241 @@
+use crate::domain::commit_check::entities::commit_check::CommitCheck;
+use crate::domain::commit_check::repositories::commit_check_repository::{
+    CommitCheckRepository, DeleteCommitCheckError, FindCommitCheckError, UpsertCommitCheckError,
+};
+use crate::domain::commit_check::value_objects::commit_check_kind::CommitCheckKind;
+use crate::domain::commit_check::value_objects::commit_ci_state::CommitCiState;
+use crate::infrastructure::database::mysql::entities::{commit_checks, commit_ci_statuses};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, SqlErr,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct MySQLCommitCheckRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLCommitCheckRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: commit_checks::Model) -> CommitCheck {
+        CommitCheck {
+            repo: model.repo,
+            head_sha: model.head_sha,
+            check_key: model.check_key,
+            kind: CommitCheckKind::from_str(&model.kind).unwrap_or(CommitCheckKind::Run),
+            name: model.name,
+            check_suite_id: model.check_suite_id.map(|id| id as u64),
+            status: model.status,
+            conclusion: model.conclusion,
+            html_url: model.html_url,
+            head_branch: model.head_branch,
+            pr_numbers: model
+                .pr_numbers
+                .split(',')
+                .filter_map(|n| n.trim().parse().ok())
+                .collect(),
+            actor: model.actor,
+        }
+    }
+
+    fn join_pr_numbers(pr_numbers: &[u64]) -> String {
+        pr_numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+#[async_trait]
+impl CommitCheckRepository for MySQLCommitCheckRepository {
+    async fn upsert(&self, check: &CommitCheck) -> Result<(), UpsertCommitCheckError> {
+        let existing = commit_checks::Entity::find()
+            .filter(commit_checks::Column::Repo.eq(&check.repo))
+            .filter(commit_checks::Column::HeadSha.eq(&check.head_sha))
+            .filter(commit_checks::Column::CheckKey.eq(&check.check_key))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| UpsertCommitCheckError::DbError(e.to_string()))?;
+
+        match existing {
+            Some(model) => {
+                // Номера PR и инициатора не затираем пустыми значениями:
+                // в check_run из форков pull_requests приходит пустым
+                let pr_numbers = if check.pr_numbers.is_empty() {
+                    model.pr_numbers.clone()
+                } else {
+                    Self::join_pr_numbers(&check.pr_numbers)
+                };
+                let actor = check.actor.clone().or(model.actor.clone());
+
+                let mut active: commit_checks::ActiveModel = model.into();
+                active.name = Set(check.name.clone());
+                active.check_suite_id = Set(check.check_suite_id.map(|id| id as i64));
+                active.status = Set(check.status.clone());
+                active.conclusion = Set(check.conclusion.clone());
+                active.html_url = Set(check.html_url.clone());
+                active.head_branch = Set(check.head_branch.clone());
+                active.pr_numbers = Set(pr_numbers);
+                active.actor = Set(actor);
+                active
+                    .update(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertCommitCheckError::DbError(e.to_string()))?;
+            }
+            None => {
+                let active = commit_checks::ActiveModel {
+                    repo: Set(check.repo.clone()),
+                    head_sha: Set(check.head_sha.clone()),
+                    check_key: Set(check.check_key.clone()),
+                    kind: Set(check.kind.to_string()),
+                    name: Set(check.name.clone()),
+                    check_suite_id: Set(check.check_suite_id.map(|id| id as i64)),
+                    status: Set(check.status.clone()),
+                    conclusion: Set(check.conclusion.clone()),
+                    html_url: Set(check.html_url.clone()),
+                    head_branch: Set(check.head_branch.clone()),
+                    pr_numbers: Set(Self::join_pr_numbers(&check.pr_numbers)),
+                    actor: Set(check.actor.clone()),
+                    ..Default::default()
+                };
+                active
+                    .insert(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertCommitCheckError::DbError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_commit(
+        &self,
+        repo: &str,
+        head_sha: &str,
+    ) -> Result<Vec<CommitCheck>, FindCommitCheckError> {
+        let models = commit_checks::Entity::find()
+            .filter(commit_checks::Column::Repo.eq(repo))
+            .filter(commit_checks::Column::HeadSha.eq(head_sha))
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindCommitCheckError::DbError(e.to_string()))?;
+
+        Ok(models.into_iter().map(Self::from_mysql).collect())
+    }
+
+    async fn find_reported_state(
+        &self,
+        repo: &str,
+        head_sha: &str,
+    ) -> Result<Option<CommitCiState>, FindCommitCheckError> {
+        let model = commit_ci_statuses::Entity::find()
+            .filter(commit_ci_statuses::Column::Repo.eq(repo))
+            .filter(commit_ci_statuses::Column::HeadSha.eq(head_sha))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| FindCommitCheckError::DbError(e.to_string()))?;
+
+        Ok(model.and_then(|m| CommitCiState::from_str(&m.reported_state).ok()))
+    }
+
+    async fn save_reported_state(
+        &self,
+        repo: &str,
+        head_sha: &str,
+        state: CommitCiState,
+    ) -> Result<bool, UpsertCommitCheckError> {
+        let result = commit_ci_statuses::Entity::update_many()
+            .col_expr(
+                commit_ci_statuses::Column::ReportedState,
+                Expr::value(state.to_string()),
+            )
+            .filter(commit_ci_statuses::Column::Repo.eq(repo))
+            .filter(commit_ci_statuses::Column::HeadSha.eq(head_sha))
+            .filter(commit_ci_statuses::Column::ReportedState.ne(state.to_string()))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| UpsertCommitCheckError::DbError(e.to_string()))?;
+
+        if result.rows_affected > 0 {
+            return Ok(true);
+        }
+
+        // Ничего не обновили: либо состояние уже такое, либо строки ещё нет.
+        // Вставку от параллельного обработчика отсекает уникальный индекс
+        let active = commit_ci_statuses::ActiveModel {
+            repo: Set(repo.to_string()),
+            head_sha: Set(head_sha.to_string()),
+            reported_state: Set(state.to_string()),
+            ..Default::default()
+        };
+
+        match active.insert(self.db.as_ref()).await {
+            Ok(_) => Ok(true),
+            Err(e) => match e.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => Ok(false),
+                _ => Err(UpsertCommitCheckError::DbError(e.to_string())),
+            },
+        }
+    }
+
+    async fn restore_reported_state(
+        &self,
+        repo: &str,
+        head_sha: &str,
+        state: CommitCiState,
+        previous: Option<CommitCiState>,
+    ) -> Result<(), UpsertCommitCheckError> {
+        match previous {
+            Some(previous) => {
+                commit_ci_statuses::Entity::update_many()
+                    .col_expr(
+                        commit_ci_statuses::Column::ReportedState,
+                        Expr::value(previous.to_string()),
+                    )
+                    .filter(commit_ci_statuses::Column::Repo.eq(repo))
+                    .filter(commit_ci_statuses::Column::HeadSha.eq(head_sha))
+                    .filter(commit_ci_statuses::Column::ReportedState.eq(state.to_string()))
+                    .exec(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertCommitCheckError::DbError(e.to_string()))?;
+            }
+            None => {
+                commit_ci_statuses::Entity::delete_many()
+                    .filter(commit_ci_statuses::Column::Repo.eq(repo))
+                    .filter(commit_ci_statuses::Column::HeadSha.eq(head_sha))
+                    .filter(commit_ci_statuses::Column::ReportedState.eq(state.to_string()))
+                    .exec(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertCommitCheckError::DbError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_older_than(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<u64, DeleteCommitCheckError> {
+        let checks = commit_checks::Entity::delete_many()
+            .filter(commit_checks::Column::UpdatedAt.lt(before))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| DeleteCommitCheckError::DbError(e.to_string()))?;
+
+        let statuses = commit_ci_statuses::Entity::delete_many()
+            .filter(commit_ci_statuses::Column::UpdatedAt.lt(before))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| DeleteCommitCheckError::DbError(e.to_string()))?;
+
+        Ok(checks.rows_affected + statuses.rows_affected)
+    }
+}
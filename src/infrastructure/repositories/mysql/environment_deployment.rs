@@ -0,0 +1,116 @@
+use crate::domain::deployment::entities::environment_deployment::EnvironmentDeployment;
+use crate::domain::deployment::repositories::environment_deployment_repository::{
+    EnvironmentDeploymentRepository, FindEnvironmentDeploymentError,
+    UpsertEnvironmentDeploymentError,
+};
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::infrastructure::database::mysql::entities::environment_deployments;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct MySQLEnvironmentDeploymentRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLEnvironmentDeploymentRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: environment_deployments::Model) -> EnvironmentDeployment {
+        EnvironmentDeployment {
+            version_control_type: VersionControlType::from_str(&model.version_control_type)
+                .unwrap_or(VersionControlType::Github),
+            repo: model.repo,
+            environment: model.environment,
+            deployment_id: model.deployment_id as u64,
+            sha: model.sha,
+            ref_name: model.ref_name,
+            deployed_by: model.deployed_by,
+            environment_url: model.environment_url,
+            deployed_at: model.deployed_at,
+        }
+    }
+}
+
+#[async_trait]
+impl EnvironmentDeploymentRepository for MySQLEnvironmentDeploymentRepository {
+    async fn upsert(
+        &self,
+        deployment: &EnvironmentDeployment,
+    ) -> Result<(), UpsertEnvironmentDeploymentError> {
+        let existing = environment_deployments::Entity::find()
+            .filter(
+                environment_deployments::Column::VersionControlType
+                    .eq(deployment.version_control_type.to_string()),
+            )
+            .filter(environment_deployments::Column::Repo.eq(&deployment.repo))
+            .filter(environment_deployments::Column::Environment.eq(&deployment.environment))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| UpsertEnvironmentDeploymentError::DbError(e.to_string()))?;
+
+        match existing {
+            Some(model) => {
+                if model.deployed_at > deployment.deployed_at {
+                    return Ok(());
+                }
+
+                let mut active: environment_deployments::ActiveModel = model.into();
+                active.deployment_id = Set(deployment.deployment_id as i64);
+                active.sha = Set(deployment.sha.clone());
+                active.ref_name = Set(deployment.ref_name.clone());
+                active.deployed_by = Set(deployment.deployed_by.clone());
+                active.environment_url = Set(deployment.environment_url.clone());
+                active.deployed_at = Set(deployment.deployed_at);
+                active
+                    .update(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertEnvironmentDeploymentError::DbError(e.to_string()))?;
+            }
+            None => {
+                let active = environment_deployments::ActiveModel {
+                    version_control_type: Set(deployment.version_control_type.to_string()),
+                    repo: Set(deployment.repo.clone()),
+                    environment: Set(deployment.environment.clone()),
+                    deployment_id: Set(deployment.deployment_id as i64),
+                    sha: Set(deployment.sha.clone()),
+                    ref_name: Set(deployment.ref_name.clone()),
+                    deployed_by: Set(deployment.deployed_by.clone()),
+                    environment_url: Set(deployment.environment_url.clone()),
+                    deployed_at: Set(deployment.deployed_at),
+                    ..Default::default()
+                };
+                active
+                    .insert(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertEnvironmentDeploymentError::DbError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_repo(
+        &self,
+        version_control_type: &VersionControlType,
+        repo: &str,
+    ) -> Result<Vec<EnvironmentDeployment>, FindEnvironmentDeploymentError> {
+        let models = environment_deployments::Entity::find()
+            .filter(
+                environment_deployments::Column::VersionControlType
+                    .eq(version_control_type.to_string()),
+            )
+            .filter(environment_deployments::Column::Repo.eq(repo))
+            .order_by_asc(environment_deployments::Column::Environment)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindEnvironmentDeploymentError::DbError(e.to_string()))?;
+
+        Ok(models.into_iter().map(Self::from_mysql).collect())
+    }
+}
@@ -0,0 +1,152 @@
+use crate::domain::merged_branch::entities::merged_branch::MergedBranch;
+use crate::domain::merged_branch::repositories::merged_branch_repository::{
+    DeleteMergedBranchError, FindMergedBranchError, MarkMergedBranchRemindedError,
+    MergedBranchRepository, UpsertMergedBranchError,
+};
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::infrastructure::database::mysql::entities::merged_branches;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct MySQLMergedBranchRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLMergedBranchRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: merged_branches::Model) -> MergedBranch {
+        MergedBranch {
+            version_control_type: VersionControlType::from_str(&model.version_control_type)
+                .unwrap_or(VersionControlType::Github),
+            repo: model.repo,
+            branch: model.branch,
+            pr_number: model.pr_number as u64,
+            pr_url: model.pr_url,
+            author_login: model.author_login,
+            merged_at: model.merged_at,
+            reminded_at: model.reminded_at,
+        }
+    }
+}
+
+#[async_trait]
+impl MergedBranchRepository for MySQLMergedBranchRepository {
+    async fn upsert(&self, branch: &MergedBranch) -> Result<(), UpsertMergedBranchError> {
+        let existing = merged_branches::Entity::find()
+            .filter(
+                merged_branches::Column::VersionControlType
+                    .eq(branch.version_control_type.to_string()),
+            )
+            .filter(merged_branches::Column::Repo.eq(&branch.repo))
+            .filter(merged_branches::Column::Branch.eq(&branch.branch))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| UpsertMergedBranchError::DbError(e.to_string()))?;
+
+        match existing {
+            Some(model) => {
+                let mut active: merged_branches::ActiveModel = model.into();
+                active.pr_number = Set(branch.pr_number as i64);
+                active.pr_url = Set(branch.pr_url.clone());
+                active.author_login = Set(branch.author_login.clone());
+                active.merged_at = Set(branch.merged_at);
+                active.reminded_at = Set(branch.reminded_at);
+                active
+                    .update(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertMergedBranchError::DbError(e.to_string()))?;
+            }
+            None => {
+                let active = merged_branches::ActiveModel {
+                    version_control_type: Set(branch.version_control_type.to_string()),
+                    repo: Set(branch.repo.clone()),
+                    branch: Set(branch.branch.clone()),
+                    pr_number: Set(branch.pr_number as i64),
+                    pr_url: Set(branch.pr_url.clone()),
+                    author_login: Set(branch.author_login.clone()),
+                    merged_at: Set(branch.merged_at),
+                    reminded_at: Set(branch.reminded_at),
+                    ..Default::default()
+                };
+                active
+                    .insert(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertMergedBranchError::DbError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        version_control_type: &VersionControlType,
+        repo: &str,
+        branch: &str,
+    ) -> Result<(), DeleteMergedBranchError> {
+        merged_branches::Entity::delete_many()
+            .filter(
+                merged_branches::Column::VersionControlType.eq(version_control_type.to_string()),
+            )
+            .filter(merged_branches::Column::Repo.eq(repo))
+            .filter(merged_branches::Column::Branch.eq(branch))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| DeleteMergedBranchError::DbError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_unreminded_merged_before(
+        &self,
+        merged_before: DateTime<Utc>,
+    ) -> Result<Vec<MergedBranch>, FindMergedBranchError> {
+        let models = merged_branches::Entity::find()
+            .filter(merged_branches::Column::RemindedAt.is_null())
+            .filter(merged_branches::Column::MergedAt.lte(merged_before))
+            .order_by_asc(merged_branches::Column::MergedAt)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindMergedBranchError::DbError(e.to_string()))?;
+
+        Ok(models.into_iter().map(Self::from_mysql).collect())
+    }
+
+    async fn mark_reminded(
+        &self,
+        version_control_type: &VersionControlType,
+        repo: &str,
+        branch: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), MarkMergedBranchRemindedError> {
+        let Some(model) = merged_branches::Entity::find()
+            .filter(
+                merged_branches::Column::VersionControlType.eq(version_control_type.to_string()),
+            )
+            .filter(merged_branches::Column::Repo.eq(repo))
+            .filter(merged_branches::Column::Branch.eq(branch))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| MarkMergedBranchRemindedError::DbError(e.to_string()))?
+        else {
+            return Ok(());
+        };
+
+        let mut active: merged_branches::ActiveModel = model.into();
+        active.reminded_at = Set(Some(at));
+        active
+            .update(self.db.as_ref())
+            .await
+            .map_err(|e| MarkMergedBranchRemindedError::DbError(e.to_string()))?;
+
+        Ok(())
+    }
+}
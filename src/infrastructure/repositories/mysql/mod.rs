@@ -1,17 +1,27 @@
+pub mod commit_check;
 pub mod digest_subscription;
+pub mod environment_deployment;
 pub mod health_ping;
+pub mod merged_branch;
 pub mod notification_log;
 pub mod pending_notifications;
 pub mod pr_review;
+pub mod pull_request_task_link;
 pub mod release_plan;
 pub mod repository;
 pub mod repository_pull_request;
 pub mod repository_task_tracker;
+pub mod repository_task_tracker_rule;
 pub mod role;
+pub mod slack_message;
+pub mod task_move;
+pub mod task_tracker_connection;
 pub mod user;
 pub mod user_connection_repositories;
 pub mod user_has_roles;
 pub mod user_notifications;
 pub mod user_preferences;
+pub mod user_slack_accounts;
 pub mod user_social_accounts;
 pub mod user_vc_accounts;
+pub mod webhook_delivery;
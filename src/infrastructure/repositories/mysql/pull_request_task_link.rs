@@ -0,0 +1,108 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::entities::pull_request_task_link::PullRequestTaskLink;
+use crate::domain::task::repositories::pull_request_task_link_repository::{
+    FindPullRequestTaskLinksError, PullRequestTaskLinkRepository, UpsertPullRequestTaskLinkError,
+};
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::infrastructure::database::mysql::entities::pull_request_task_links;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use std::sync::Arc;
+
+pub struct MySQLPullRequestTaskLinkRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLPullRequestTaskLinkRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: pull_request_task_links::Model) -> PullRequestTaskLink {
+        PullRequestTaskLink {
+            repository_id: RepositoryId(model.repository_id),
+            task_id: TaskId(model.task_id),
+            pr_number: model.pr_number as u64,
+            pr_title: model.pr_title,
+            pr_url: model.pr_url,
+            author_login: model.author_login,
+            head_sha: model.head_sha,
+            merged_at: model.merged_at,
+        }
+    }
+}
+
+#[async_trait]
+impl PullRequestTaskLinkRepository for MySQLPullRequestTaskLinkRepository {
+    async fn upsert(
+        &self,
+        link: &PullRequestTaskLink,
+    ) -> Result<bool, UpsertPullRequestTaskLinkError> {
+        let existing = pull_request_task_links::Entity::find()
+            .filter(pull_request_task_links::Column::RepositoryId.eq(link.repository_id.0))
+            .filter(pull_request_task_links::Column::PrNumber.eq(link.pr_number as i64))
+            .filter(pull_request_task_links::Column::TaskId.eq(&link.task_id.0))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| UpsertPullRequestTaskLinkError::DbError(e.to_string()))?;
+
+        match existing {
+            Some(model) => {
+                let mut active: pull_request_task_links::ActiveModel = model.into();
+                active.pr_title = Set(link.pr_title.clone());
+                active.pr_url = Set(link.pr_url.clone());
+                // Событие без этих данных не должно затирать известные
+                if link.author_login.is_some() {
+                    active.author_login = Set(link.author_login.clone());
+                }
+                if link.head_sha.is_some() {
+                    active.head_sha = Set(link.head_sha.clone());
+                }
+                if link.merged_at.is_some() {
+                    active.merged_at = Set(link.merged_at);
+                }
+                active
+                    .update(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertPullRequestTaskLinkError::DbError(e.to_string()))?;
+
+                Ok(false)
+            }
+            None => {
+                let active = pull_request_task_links::ActiveModel {
+                    repository_id: Set(link.repository_id.0),
+                    task_id: Set(link.task_id.0.clone()),
+                    pr_number: Set(link.pr_number as i64),
+                    pr_title: Set(link.pr_title.clone()),
+                    pr_url: Set(link.pr_url.clone()),
+                    author_login: Set(link.author_login.clone()),
+                    head_sha: Set(link.head_sha.clone()),
+                    merged_at: Set(link.merged_at),
+                    ..Default::default()
+                };
+                active
+                    .insert(self.db.as_ref())
+                    .await
+                    .map_err(|e| UpsertPullRequestTaskLinkError::DbError(e.to_string()))?;
+
+                Ok(true)
+            }
+        }
+    }
+
+    async fn find_by_task_id(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Vec<PullRequestTaskLink>, FindPullRequestTaskLinksError> {
+        let models = pull_request_task_links::Entity::find()
+            .filter(pull_request_task_links::Column::TaskId.eq(&task_id.0))
+            .order_by_desc(pull_request_task_links::Column::UpdatedAt)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindPullRequestTaskLinksError::DbError(e.to_string()))?;
+
+        Ok(models.into_iter().map(Self::from_mysql).collect())
+    }
+}
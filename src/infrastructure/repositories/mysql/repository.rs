@@ -1,17 +1,22 @@
 use crate::domain::repository::entities::repository::Repository;
 use crate::domain::repository::repositories::repository_repository::{
     CreateRepositoryError, DeleteRepositoryError, FindAllRepositoriesError,
-    FindRepositoryByIdError, FindRepositoryByOwnerAndNameError, RepositoryRepository,
-    UpdateRepositoryError,
+    FindRepositoriesBySocialChatIdError, FindRepositoryByIdError,
+    FindRepositoryByOwnerAndNameError, RepositoryRepository, UpdateRepositoryError,
 };
+use crate::domain::repository::value_objects::ref_events_filter::RefEventsFilter;
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
 use crate::domain::user::value_objects::social_chat_id::SocialChatId;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
 use crate::infrastructure::database::mysql::entities::repositories;
+use crate::utils::security::crypto::reversible::ReversibleCipherValue;
 use async_trait::async_trait;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait,
     ModelTrait, QueryFilter, Set,
 };
+use std::str::FromStr;
 use std::sync::Arc;
 
 pub struct MySQLRepositoryRepository {
@@ -37,6 +42,19 @@ impl RepositoryRepository for MySQLRepositoryRepository {
             url: Set(repository.url.clone()),
             social_chat_id: Set(repository.social_chat_id.map(|id| id.0)),
             notifications_chat_id: Set(repository.notifications_chat_id.map(|id| id.0)),
+            version_control_type: Set(repository.version_control_type.to_string()),
+            webhook_secret: Set(repository
+                .webhook_secret
+                .as_ref()
+                .map(|v| v.value().to_string())),
+            previous_webhook_secret: Set(repository
+                .previous_webhook_secret
+                .as_ref()
+                .map(|v| v.value().to_string())),
+            webhook_secret_rotated_at: Set(repository.webhook_secret_rotated_at),
+            ref_events_filter: Set(repository.ref_events_filter.as_ref().map(|f| f.to_string())),
+            is_archived: Set(repository.is_archived as i8),
+            slack_channel_id: Set(repository.slack_channel_id.as_ref().map(|c| c.0.clone())),
             ..Default::default()
         };
 
@@ -60,6 +78,19 @@ impl RepositoryRepository for MySQLRepositoryRepository {
             url: Set(repository.url.clone()),
             social_chat_id: Set(repository.social_chat_id.map(|id| id.0)),
             notifications_chat_id: Set(repository.notifications_chat_id.map(|id| id.0)),
+            version_control_type: Set(repository.version_control_type.to_string()),
+            webhook_secret: Set(repository
+                .webhook_secret
+                .as_ref()
+                .map(|v| v.value().to_string())),
+            previous_webhook_secret: Set(repository
+                .previous_webhook_secret
+                .as_ref()
+                .map(|v| v.value().to_string())),
+            webhook_secret_rotated_at: Set(repository.webhook_secret_rotated_at),
+            ref_events_filter: Set(repository.ref_events_filter.as_ref().map(|f| f.to_string())),
+            is_archived: Set(repository.is_archived as i8),
+            slack_channel_id: Set(repository.slack_channel_id.as_ref().map(|c| c.0.clone())),
             ..Default::default()
         };
 
@@ -91,12 +122,31 @@ impl RepositoryRepository for MySQLRepositoryRepository {
         Ok(results.into_iter().map(Repository::from_mysql).collect())
     }
 
+    async fn find_by_social_chat_id(
+        &self,
+        chat_id: &SocialChatId,
+    ) -> Result<Vec<Repository>, FindRepositoriesBySocialChatIdError> {
+        let results = repositories::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(repositories::Column::SocialChatId.eq(chat_id.0))
+                    .add(repositories::Column::NotificationsChatId.eq(chat_id.0)),
+            )
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindRepositoriesBySocialChatIdError::DbError(e.to_string()))?;
+
+        Ok(results.into_iter().map(Repository::from_mysql).collect())
+    }
+
     async fn find_by_owner_and_name(
         &self,
+        version_control_type: &VersionControlType,
         owner: &str,
         name: &str,
     ) -> Result<Repository, FindRepositoryByOwnerAndNameError> {
         let result = repositories::Entity::find()
+            .filter(repositories::Column::VersionControlType.eq(version_control_type.to_string()))
             .filter(repositories::Column::Owner.eq(owner))
             .filter(repositories::Column::Name.eq(name))
             .one(self.db.as_ref())
@@ -135,8 +185,22 @@ impl Repository {
             name: model.name,
             owner: model.owner,
             url: model.url,
+            version_control_type: VersionControlType::from_str(&model.version_control_type)
+                .unwrap_or(VersionControlType::Github),
             social_chat_id: model.social_chat_id.map(SocialChatId),
             notifications_chat_id: model.notifications_chat_id.map(SocialChatId),
+            webhook_secret: model
+                .webhook_secret
+                .and_then(|v| ReversibleCipherValue::new(v).ok()),
+            previous_webhook_secret: model
+                .previous_webhook_secret
+                .and_then(|v| ReversibleCipherValue::new(v).ok()),
+            webhook_secret_rotated_at: model.webhook_secret_rotated_at,
+            ref_events_filter: model
+                .ref_events_filter
+                .and_then(|v| RefEventsFilter::from_str(&v).ok()),
+            is_archived: model.is_archived != 0,
+            slack_channel_id: model.slack_channel_id.map(SlackChannelId),
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -5,12 +5,14 @@ use crate::domain::repository::repositories::repository_task_tracker_repository:
     UpdateRepositoryTaskTrackerError,
 };
 use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
 use crate::infrastructure::database::mysql::entities::repository_task_tracker;
 use async_trait::async_trait;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
     QueryFilter, Set,
 };
+use std::str::FromStr;
 use std::sync::Arc;
 
 pub struct MySQLRepositoryTaskTrackerRepository {
@@ -32,10 +34,16 @@ impl RepositoryTaskTrackerRepository for MySQLRepositoryTaskTrackerRepository {
     ) -> Result<RepositoryTaskTracker, CreateRepositoryTaskTrackerError> {
         let model = repository_task_tracker::ActiveModel {
             repository_id: Set(tracker.repository_id.0),
-            space_id: Set(tracker.space_id),
-            qa_column_id: Set(tracker.qa_column_id),
+            space_id: Set(tracker.space_id.clone()),
+            qa_column_id: Set(tracker.qa_column_id.clone()),
             extract_pattern_regexp: Set(tracker.extract_pattern_regexp.clone()),
             path_to_card: Set(tracker.path_to_card.clone()),
+            kind: Set(tracker.kind.to_string()),
+            connection_id: Set(tracker.connection_id),
+            require_task_id: Set(tracker.require_task_id),
+            qa_passed_column_id: Set(tracker.qa_passed_column_id.clone()),
+            qa_failed_column_id: Set(tracker.qa_failed_column_id.clone()),
+            issue_column_id: Set(tracker.issue_column_id.clone()),
             ..Default::default()
         };
 
@@ -55,10 +63,16 @@ impl RepositoryTaskTrackerRepository for MySQLRepositoryTaskTrackerRepository {
         let model = repository_task_tracker::ActiveModel {
             id: Set(tracker.id),
             repository_id: Set(tracker.repository_id.0),
-            space_id: Set(tracker.space_id),
-            qa_column_id: Set(tracker.qa_column_id),
+            space_id: Set(tracker.space_id.clone()),
+            qa_column_id: Set(tracker.qa_column_id.clone()),
             extract_pattern_regexp: Set(tracker.extract_pattern_regexp.clone()),
             path_to_card: Set(tracker.path_to_card.clone()),
+            kind: Set(tracker.kind.to_string()),
+            connection_id: Set(tracker.connection_id),
+            require_task_id: Set(tracker.require_task_id),
+            qa_passed_column_id: Set(tracker.qa_passed_column_id.clone()),
+            qa_failed_column_id: Set(tracker.qa_failed_column_id.clone()),
+            issue_column_id: Set(tracker.issue_column_id.clone()),
             ..Default::default()
         };
 
@@ -108,6 +122,12 @@ impl RepositoryTaskTracker {
             qa_column_id: model.qa_column_id,
             extract_pattern_regexp: model.extract_pattern_regexp,
             path_to_card: model.path_to_card,
+            kind: TaskTrackerKind::from_str(&model.kind).unwrap_or_default(),
+            connection_id: model.connection_id,
+            require_task_id: model.require_task_id,
+            qa_passed_column_id: model.qa_passed_column_id,
+            qa_failed_column_id: model.qa_failed_column_id,
+            issue_column_id: model.issue_column_id,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -0,0 +1,95 @@
+use crate::domain::repository::entities::repository_task_tracker_rule::RepositoryTaskTrackerRule;
+use crate::domain::repository::repositories::repository_task_tracker_rule_repository::{
+    CreateRepositoryTaskTrackerRuleError, DeleteRepositoryTaskTrackerRuleError,
+    FindRepositoryTaskTrackerRulesError, RepositoryTaskTrackerRuleRepository,
+};
+use crate::domain::repository::value_objects::pull_request_lifecycle_trigger::PullRequestLifecycleTrigger;
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::infrastructure::database::mysql::entities::repository_task_tracker_rules;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct MySQLRepositoryTaskTrackerRuleRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLRepositoryTaskTrackerRuleRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(
+        model: repository_task_tracker_rules::Model,
+    ) -> Result<RepositoryTaskTrackerRule, String> {
+        let trigger = PullRequestLifecycleTrigger::from_str(&model.trigger)?;
+
+        Ok(RepositoryTaskTrackerRule {
+            id: model.id,
+            repository_id: RepositoryId(model.repository_id),
+            trigger,
+            column_id: model.column_id,
+            base_branch_pattern: model.base_branch_pattern,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl RepositoryTaskTrackerRuleRepository for MySQLRepositoryTaskTrackerRuleRepository {
+    async fn create(
+        &self,
+        rule: &RepositoryTaskTrackerRule,
+    ) -> Result<RepositoryTaskTrackerRule, CreateRepositoryTaskTrackerRuleError> {
+        let model = repository_task_tracker_rules::ActiveModel {
+            repository_id: Set(rule.repository_id.0),
+            trigger: Set(rule.trigger.to_string()),
+            column_id: Set(rule.column_id.clone()),
+            base_branch_pattern: Set(rule.base_branch_pattern.clone()),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(|e| CreateRepositoryTaskTrackerRuleError::DbError(e.to_string()))?;
+
+        Self::from_mysql(result).map_err(CreateRepositoryTaskTrackerRuleError::DbError)
+    }
+
+    async fn find_by_repository_id(
+        &self,
+        repository_id: RepositoryId,
+    ) -> Result<Vec<RepositoryTaskTrackerRule>, FindRepositoryTaskTrackerRulesError> {
+        let models = repository_task_tracker_rules::Entity::find()
+            .filter(repository_task_tracker_rules::Column::RepositoryId.eq(repository_id.0))
+            .order_by_asc(repository_task_tracker_rules::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindRepositoryTaskTrackerRulesError::DbError(e.to_string()))?;
+
+        models
+            .into_iter()
+            .map(|model| {
+                Self::from_mysql(model).map_err(FindRepositoryTaskTrackerRulesError::DbError)
+            })
+            .collect()
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DeleteRepositoryTaskTrackerRuleError> {
+        let result = repository_task_tracker_rules::Entity::delete_by_id(id)
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| DeleteRepositoryTaskTrackerRuleError::DbError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            return Err(DeleteRepositoryTaskTrackerRuleError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::domain::notification::entities::slack_message::SlackMessage;
+use crate::domain::notification::repositories::slack_message_repository::{
+    SlackMessageRepository, SlackMessageRepositoryError,
+};
+use crate::infrastructure::database::mysql::entities::slack_messages;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use std::sync::Arc;
+
+pub struct MySQLSlackMessageRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLSlackMessageRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SlackMessageRepository for MySQLSlackMessageRepository {
+    async fn create(&self, message: &SlackMessage) -> Result<(), SlackMessageRepositoryError> {
+        let active = slack_messages::ActiveModel {
+            social_chat_id: Set(message.mirrored.map(|(chat_id, _)| chat_id.0)),
+            social_message_id: Set(message.mirrored.map(|(_, message_id)| message_id.0)),
+            slack_channel_id: Set(message.message_ref.channel_id.0.clone()),
+            slack_ts: Set(message.message_ref.ts.clone()),
+            created_at: Set(Utc::now()),
+            ..Default::default()
+        };
+
+        active
+            .insert(self.db.as_ref())
+            .await
+            .map_err(|e| SlackMessageRepositoryError::DbError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete_older_than(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<u64, SlackMessageRepositoryError> {
+        let result = slack_messages::Entity::delete_many()
+            .filter(slack_messages::Column::CreatedAt.lt(before))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| SlackMessageRepositoryError::DbError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::domain::repository::value_objects::repository_id::RepositoryId;
+use crate::domain::task::entities::task_move::TaskMove;
+use crate::domain::task::repositories::task_move_repository::{
+    CreateTaskMoveError, FindTaskMovesError, TaskMoveRepository,
+};
+use crate::domain::task::value_objects::task_id::TaskId;
+use crate::domain::task::value_objects::task_move_trigger::TaskMoveTrigger;
+use crate::infrastructure::database::mysql::entities::task_tracker_moves;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct MySQLTaskMoveRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLTaskMoveRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: task_tracker_moves::Model) -> Result<TaskMove, String> {
+        let trigger = TaskMoveTrigger::from_str(&model.trigger)?;
+
+        Ok(TaskMove {
+            id: model.id,
+            repository_id: RepositoryId(model.repository_id),
+            rule_id: model.rule_id,
+            trigger,
+            task_id: TaskId(model.task_id),
+            column_id: model.column_id,
+            pr_number: model.pr_number.map(|n| n as u64),
+            error: model.error,
+            created_at: model.created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl TaskMoveRepository for MySQLTaskMoveRepository {
+    async fn create(&self, task_move: &TaskMove) -> Result<TaskMove, CreateTaskMoveError> {
+        let model = task_tracker_moves::ActiveModel {
+            repository_id: Set(task_move.repository_id.0),
+            rule_id: Set(task_move.rule_id),
+            trigger: Set(task_move.trigger.to_string()),
+            task_id: Set(task_move.task_id.0.clone()),
+            column_id: Set(task_move.column_id.clone()),
+            pr_number: Set(task_move.pr_number.map(|n| n as i64)),
+            error: Set(task_move.error.clone()),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(|e| CreateTaskMoveError::DbError(e.to_string()))?;
+
+        Self::from_mysql(result).map_err(CreateTaskMoveError::DbError)
+    }
+
+    async fn find_recent_by_repository_id(
+        &self,
+        repository_id: RepositoryId,
+        limit: u64,
+    ) -> Result<Vec<TaskMove>, FindTaskMovesError> {
+        let models = task_tracker_moves::Entity::find()
+            .filter(task_tracker_moves::Column::RepositoryId.eq(repository_id.0))
+            .order_by_desc(task_tracker_moves::Column::CreatedAt)
+            .order_by_desc(task_tracker_moves::Column::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindTaskMovesError::DbError(e.to_string()))?;
+
+        models
+            .into_iter()
+            .map(|model| Self::from_mysql(model).map_err(FindTaskMovesError::DbError))
+            .collect()
+    }
+
+    async fn find_last_successful_by_task_id(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<TaskMove>, FindTaskMovesError> {
+        let model = task_tracker_moves::Entity::find()
+            .filter(task_tracker_moves::Column::TaskId.eq(task_id.0.clone()))
+            .filter(task_tracker_moves::Column::Error.is_null())
+            .order_by_desc(task_tracker_moves::Column::CreatedAt)
+            .order_by_desc(task_tracker_moves::Column::Id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| FindTaskMovesError::DbError(e.to_string()))?;
+
+        model
+            .map(|model| Self::from_mysql(model).map_err(FindTaskMovesError::DbError))
+            .transpose()
+    }
+}
@@ -0,0 +1,113 @@
+use crate::domain::task::entities::task_tracker_connection::TaskTrackerConnection;
+use crate::domain::task::repositories::task_tracker_connection_repository::{
+    CreateTaskTrackerConnectionError, DeleteTaskTrackerConnectionError,
+    FindTaskTrackerConnectionError, TaskTrackerConnectionRepository,
+};
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::infrastructure::database::mysql::entities::task_tracker_connections;
+use crate::utils::security::crypto::reversible::ReversibleCipherValue;
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryOrder, Set, SqlErr};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct MySQLTaskTrackerConnectionRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLTaskTrackerConnectionRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: task_tracker_connections::Model) -> Result<TaskTrackerConnection, String> {
+        let kind = TaskTrackerKind::from_str(&model.kind)
+            .map_err(|e| format!("Invalid task tracker kind: {}", e))?;
+
+        let api_token = ReversibleCipherValue::new(model.api_token)
+            .map_err(|e| format!("Error for reverse api token from database: {}", e))?;
+
+        Ok(TaskTrackerConnection {
+            id: model.id,
+            kind,
+            name: model.name,
+            base_url: model.base_url,
+            email: model.email,
+            api_token,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl TaskTrackerConnectionRepository for MySQLTaskTrackerConnectionRepository {
+    async fn create(
+        &self,
+        connection: &TaskTrackerConnection,
+    ) -> Result<TaskTrackerConnection, CreateTaskTrackerConnectionError> {
+        let model = task_tracker_connections::ActiveModel {
+            kind: Set(connection.kind.to_string()),
+            name: Set(connection.name.clone()),
+            base_url: Set(connection.base_url.clone()),
+            email: Set(connection.email.clone()),
+            api_token: Set(connection.api_token.value().to_string()),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(|e| CreateTaskTrackerConnectionError::DbError(e.to_string()))?;
+
+        Self::from_mysql(result).map_err(CreateTaskTrackerConnectionError::DbError)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<TaskTrackerConnection, FindTaskTrackerConnectionError> {
+        let model = task_tracker_connections::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| FindTaskTrackerConnectionError::DbError(e.to_string()))?
+            .ok_or(FindTaskTrackerConnectionError::NotFound)?;
+
+        Self::from_mysql(model).map_err(FindTaskTrackerConnectionError::DbError)
+    }
+
+    async fn find_all(&self) -> Result<Vec<TaskTrackerConnection>, FindTaskTrackerConnectionError> {
+        let models = task_tracker_connections::Entity::find()
+            .order_by_asc(task_tracker_connections::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindTaskTrackerConnectionError::DbError(e.to_string()))?;
+
+        models
+            .into_iter()
+            .map(|model| Self::from_mysql(model).map_err(FindTaskTrackerConnectionError::DbError))
+            .collect()
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DeleteTaskTrackerConnectionError> {
+        let model = task_tracker_connections::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| DeleteTaskTrackerConnectionError::DbError(e.to_string()))?
+            .ok_or(DeleteTaskTrackerConnectionError::NotFound)?;
+
+        let active_model: task_tracker_connections::ActiveModel = model.into();
+
+        active_model
+            .delete(self.db.as_ref())
+            .await
+            .map_err(|e| match e.sql_err() {
+                Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+                    DeleteTaskTrackerConnectionError::InUse
+                }
+                _ => DeleteTaskTrackerConnectionError::DbError(e.to_string()),
+            })?;
+
+        Ok(())
+    }
+}
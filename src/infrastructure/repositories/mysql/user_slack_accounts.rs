@@ -0,0 +1,84 @@
+use crate::domain::user::entities::user_slack_account::UserSlackAccount;
+use crate::domain::user::repositories::user_slack_accounts_repository::{
+    DeleteUserSlackAccountError, FindUserSlackAccountError, UpsertUserSlackAccountError,
+    UserSlackAccountsRepository,
+};
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use crate::domain::user::value_objects::user_id::UserId;
+use crate::infrastructure::database::mysql::entities::user_slack_accounts;
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use std::sync::Arc;
+
+pub struct MySQLUserSlackAccountsRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLUserSlackAccountsRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: user_slack_accounts::Model) -> UserSlackAccount {
+        UserSlackAccount {
+            user_id: UserId(model.user_id),
+            slack_user_id: SlackChannelId(model.slack_user_id),
+        }
+    }
+}
+
+#[async_trait]
+impl UserSlackAccountsRepository for MySQLUserSlackAccountsRepository {
+    async fn find_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<UserSlackAccount>, FindUserSlackAccountError> {
+        let model = user_slack_accounts::Entity::find()
+            .filter(user_slack_accounts::Column::UserId.eq(user_id.0))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| FindUserSlackAccountError::DbError(e.to_string()))?;
+
+        Ok(model.map(Self::from_mysql))
+    }
+
+    async fn upsert(
+        &self,
+        account: &UserSlackAccount,
+    ) -> Result<UserSlackAccount, UpsertUserSlackAccountError> {
+        let existing = user_slack_accounts::Entity::find()
+            .filter(user_slack_accounts::Column::UserId.eq(account.user_id.0))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| UpsertUserSlackAccountError::DbError(e.to_string()))?;
+
+        let model = match existing {
+            Some(model) => {
+                let mut active: user_slack_accounts::ActiveModel = model.into();
+                active.slack_user_id = Set(account.slack_user_id.0.clone());
+                active.update(self.db.as_ref()).await
+            }
+            None => {
+                let active = user_slack_accounts::ActiveModel {
+                    user_id: Set(account.user_id.0),
+                    slack_user_id: Set(account.slack_user_id.0.clone()),
+                    ..Default::default()
+                };
+                active.insert(self.db.as_ref()).await
+            }
+        }
+        .map_err(|e| UpsertUserSlackAccountError::DbError(e.to_string()))?;
+
+        Ok(Self::from_mysql(model))
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), DeleteUserSlackAccountError> {
+        user_slack_accounts::Entity::delete_many()
+            .filter(user_slack_accounts::Column::UserId.eq(user_id.0))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| DeleteUserSlackAccountError::DbError(e.to_string()))?;
+
+        Ok(())
+    }
+}
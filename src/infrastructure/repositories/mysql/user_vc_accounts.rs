@@ -1,8 +1,8 @@
 use crate::domain::user::entities::user_vc_account::UserVersionControlAccount;
 use crate::domain::user::repositories::user_vc_accounts_repository::{
-    CreateVersionControlServiceError, FindVersionControlServiceByIdError,
-    FindVersionControlServiceByLoginError, FindVersionControlServiceByUserIdError,
-    UserVersionControlAccountsRepository,
+    CreateVersionControlServiceError, DeleteVersionControlServiceError,
+    FindVersionControlServiceByIdError, FindVersionControlServiceByLoginError,
+    FindVersionControlServiceByUserIdError, UserVersionControlAccountsRepository,
 };
 use crate::domain::user::value_objects::user_id::UserId;
 use crate::domain::user::value_objects::version_control_type::VersionControlType;
@@ -57,11 +57,35 @@ impl UserVersionControlAccountsRepository for MySQLUserVersionControlServicesRep
             .map_err(CreateVersionControlServiceError::DbError)
     }
 
+    async fn delete_by_user_id(
+        &self,
+        txn: &DatabaseTransaction,
+        version_control_type: &VersionControlType,
+        id: &UserId,
+    ) -> Result<(), DeleteVersionControlServiceError> {
+        user_version_control_accounts::Entity::delete_many()
+            .filter(
+                user_version_control_accounts::Column::VersionControlType
+                    .eq(version_control_type.to_string()),
+            )
+            .filter(user_version_control_accounts::Column::UserId.eq(id.0 as i64))
+            .exec(txn)
+            .await
+            .map_err(|e| DeleteVersionControlServiceError::DbError(e.to_string()))?;
+
+        Ok(())
+    }
+
     async fn find_by_version_control_user_id(
         &self,
+        version_control_type: &VersionControlType,
         id: &VersionControlUserId,
     ) -> Result<UserVersionControlAccount, FindVersionControlServiceByIdError> {
         let result = user_version_control_accounts::Entity::find()
+            .filter(
+                user_version_control_accounts::Column::VersionControlType
+                    .eq(version_control_type.to_string()),
+            )
             .filter(user_version_control_accounts::Column::VersionControlUserId.eq(id.0 as i64))
             .one(self.db.as_ref())
             .await
@@ -74,9 +98,14 @@ impl UserVersionControlAccountsRepository for MySQLUserVersionControlServicesRep
 
     async fn find_by_user_id(
         &self,
+        version_control_type: &VersionControlType,
         id: &UserId,
     ) -> Result<UserVersionControlAccount, FindVersionControlServiceByUserIdError> {
         let result = user_version_control_accounts::Entity::find()
+            .filter(
+                user_version_control_accounts::Column::VersionControlType
+                    .eq(version_control_type.to_string()),
+            )
             .filter(user_version_control_accounts::Column::UserId.eq(id.0 as i64))
             .one(self.db.as_ref())
             .await
@@ -89,11 +118,16 @@ impl UserVersionControlAccountsRepository for MySQLUserVersionControlServicesRep
 
     async fn find_by_login(
         &self,
+        version_control_type: &VersionControlType,
         login: &str,
     ) -> Result<UserVersionControlAccount, FindVersionControlServiceByLoginError> {
         // JOIN users: деактивированные юзеры (users.is_active = 0) считаются "не найденными",
         // чтобы webhook-листенеры скипали их так же, как незарегистрированных.
         let (vc_model, user_opt) = user_version_control_accounts::Entity::find()
+            .filter(
+                user_version_control_accounts::Column::VersionControlType
+                    .eq(version_control_type.to_string()),
+            )
             .filter(user_version_control_accounts::Column::VersionControlLogin.eq(login))
             .find_also_related(users::Entity)
             .one(self.db.as_ref())
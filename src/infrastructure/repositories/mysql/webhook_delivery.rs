@@ -0,0 +1,201 @@
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::webhook_delivery::entities::webhook_delivery::WebhookDelivery;
+use crate::domain::webhook_delivery::repositories::webhook_delivery_repository::{
+    CreateWebhookDeliveryError, DeleteWebhookDeliveryError, FindWebhookDeliveryError,
+    UpdateWebhookDeliveryError, WebhookDeliveryRepository,
+};
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_cursor::WebhookDeliveryCursor;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_id::WebhookDeliveryId;
+use crate::domain::webhook_delivery::value_objects::webhook_delivery_status::WebhookDeliveryStatus;
+use crate::infrastructure::database::mysql::entities::webhook_deliveries;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, SqlErr,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct MySQLWebhookDeliveryRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl MySQLWebhookDeliveryRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn from_mysql(model: webhook_deliveries::Model) -> WebhookDelivery {
+        WebhookDelivery {
+            id: WebhookDeliveryId(model.id),
+            provider: VersionControlType::from_str(&model.provider)
+                .unwrap_or(VersionControlType::Github),
+            delivery_id: model.delivery_id,
+            event_type: model.event_type,
+            repository_full_name: model.repository_full_name,
+            payload: model.payload,
+            signature_valid: model.signature_valid != 0,
+            status: WebhookDeliveryStatus::from_str(&model.status)
+                .unwrap_or(WebhookDeliveryStatus::Failed),
+            error_message: model.error_message,
+            received_at: model.received_at,
+            processed_at: model.processed_at,
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for MySQLWebhookDeliveryRepository {
+    async fn create(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<WebhookDelivery, CreateWebhookDeliveryError> {
+        let model = webhook_deliveries::ActiveModel {
+            provider: Set(delivery.provider.to_string()),
+            delivery_id: Set(delivery.delivery_id.clone()),
+            event_type: Set(delivery.event_type.clone()),
+            repository_full_name: Set(delivery.repository_full_name.clone()),
+            payload: Set(delivery.payload.clone()),
+            signature_valid: Set(delivery.signature_valid as i8),
+            status: Set(delivery.status.to_string()),
+            error_message: Set(delivery.error_message.clone()),
+            received_at: Set(delivery.received_at),
+            processed_at: Set(delivery.processed_at),
+            ..Default::default()
+        };
+
+        let result = model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(|e| match e.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => {
+                    CreateWebhookDeliveryError::AlreadyExists
+                }
+                _ => CreateWebhookDeliveryError::DbError(e.to_string()),
+            })?;
+
+        Ok(Self::from_mysql(result))
+    }
+
+    async fn find_by_id(
+        &self,
+        id: WebhookDeliveryId,
+    ) -> Result<WebhookDelivery, FindWebhookDeliveryError> {
+        let model = webhook_deliveries::Entity::find_by_id(id.0)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| FindWebhookDeliveryError::DbError(e.to_string()))?
+            .ok_or(FindWebhookDeliveryError::NotFound)?;
+
+        Ok(Self::from_mysql(model))
+    }
+
+    async fn find_by_delivery_id(
+        &self,
+        provider: &VersionControlType,
+        delivery_id: &str,
+    ) -> Result<WebhookDelivery, FindWebhookDeliveryError> {
+        let model = webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::Provider.eq(provider.to_string()))
+            .filter(webhook_deliveries::Column::DeliveryId.eq(delivery_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| FindWebhookDeliveryError::DbError(e.to_string()))?
+            .ok_or(FindWebhookDeliveryError::NotFound)?;
+
+        Ok(Self::from_mysql(model))
+    }
+
+    async fn find_recent(
+        &self,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, FindWebhookDeliveryError> {
+        let models = webhook_deliveries::Entity::find()
+            .order_by_desc(webhook_deliveries::Column::ReceivedAt)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindWebhookDeliveryError::DbError(e.to_string()))?;
+
+        Ok(models.into_iter().map(Self::from_mysql).collect())
+    }
+
+    async fn find_by_repository_between(
+        &self,
+        repository_full_name: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after: Option<WebhookDeliveryCursor>,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, FindWebhookDeliveryError> {
+        let mut query = webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::RepositoryFullName.eq(repository_full_name))
+            .filter(webhook_deliveries::Column::ReceivedAt.gte(from))
+            .filter(webhook_deliveries::Column::ReceivedAt.lte(to));
+
+        if let Some(after) = after {
+            query = query.filter(
+                Condition::any()
+                    .add(webhook_deliveries::Column::ReceivedAt.gt(after.received_at))
+                    .add(
+                        Condition::all()
+                            .add(webhook_deliveries::Column::ReceivedAt.eq(after.received_at))
+                            .add(webhook_deliveries::Column::Id.gt(after.id.0)),
+                    ),
+            );
+        }
+
+        let models = query
+            .order_by_asc(webhook_deliveries::Column::ReceivedAt)
+            .order_by_asc(webhook_deliveries::Column::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| FindWebhookDeliveryError::DbError(e.to_string()))?;
+
+        Ok(models.into_iter().map(Self::from_mysql).collect())
+    }
+
+    async fn update(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<WebhookDelivery, UpdateWebhookDeliveryError> {
+        let model = webhook_deliveries::Entity::find_by_id(delivery.id.0)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| UpdateWebhookDeliveryError::DbError(e.to_string()))?
+            .ok_or(UpdateWebhookDeliveryError::NotFound)?;
+
+        let mut active_model: webhook_deliveries::ActiveModel = model.into();
+
+        active_model.event_type = Set(delivery.event_type.clone());
+        active_model.repository_full_name = Set(delivery.repository_full_name.clone());
+        active_model.payload = Set(delivery.payload.clone());
+        active_model.signature_valid = Set(delivery.signature_valid as i8);
+        active_model.status = Set(delivery.status.to_string());
+        active_model.error_message = Set(delivery.error_message.clone());
+        active_model.received_at = Set(delivery.received_at);
+        active_model.processed_at = Set(delivery.processed_at);
+
+        let result = active_model
+            .update(self.db.as_ref())
+            .await
+            .map_err(|e| UpdateWebhookDeliveryError::DbError(e.to_string()))?;
+
+        Ok(Self::from_mysql(result))
+    }
+
+    async fn delete_received_before(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<u64, DeleteWebhookDeliveryError> {
+        let result = webhook_deliveries::Entity::delete_many()
+            .filter(webhook_deliveries::Column::ReceivedAt.lt(before))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| DeleteWebhookDeliveryError::DbError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -1,2 +1,3 @@
 pub mod notification;
 pub mod task_tracker;
+pub mod version_control;
@@ -1,23 +1,132 @@
+use crate::domain::notification::entities::slack_message::SlackMessage;
+use crate::domain::notification::repositories::slack_message_repository::SlackMessageRepository;
 use crate::domain::notification::services::notification_service::{
     NotificationService, NotificationServiceDeleteMessageError,
     NotificationServiceEditMessageError, NotificationServiceSendError,
 };
+use crate::domain::notification::services::slack_notification_service::SlackNotificationService;
+use crate::domain::repository::repositories::repository_repository::RepositoryRepository;
+use crate::domain::user::repositories::user_slack_accounts_repository::UserSlackAccountsRepository;
+use crate::domain::user::repositories::user_social_accounts_repository::UserSocialAccountsRepository;
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
 use crate::domain::user::value_objects::social_chat_id::SocialChatId;
 use crate::domain::user::value_objects::social_message_id::SocialMessageId;
 use crate::domain::user::value_objects::social_type::SocialType;
 use crate::infrastructure::services::notification::telegram::TelegramNotificationClient;
 use crate::utils::builder::message::MessageBuilder;
+use std::sync::Arc;
 
+pub mod slack;
 pub mod telegram;
 
 pub struct CompositionNotificationService {
     telegram: TelegramNotificationClient,
+    slack: Option<Arc<dyn SlackNotificationService>>,
+    repository_repo: Arc<dyn RepositoryRepository>,
+    user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+    user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository>,
+    slack_message_repo: Arc<dyn SlackMessageRepository>,
 }
 
 impl CompositionNotificationService {
-    pub fn new(telegram_token: String) -> Self {
+    pub fn new(
+        telegram_token: String,
+        slack: Option<Arc<dyn SlackNotificationService>>,
+        repository_repo: Arc<dyn RepositoryRepository>,
+        user_socials_repo: Arc<dyn UserSocialAccountsRepository>,
+        user_slack_accounts_repo: Arc<dyn UserSlackAccountsRepository>,
+        slack_message_repo: Arc<dyn SlackMessageRepository>,
+    ) -> Self {
         Self {
             telegram: TelegramNotificationClient::new(telegram_token),
+            slack,
+            repository_repo,
+            user_socials_repo,
+            user_slack_accounts_repo,
+            slack_message_repo,
+        }
+    }
+
+    /// Куда дублировать сообщение из Telegram-чата: личка пользователя —
+    /// в его Slack, чат репозитория — в Slack-канал репозитория
+    async fn resolve_slack_destinations(&self, chat_id: &SocialChatId) -> Vec<SlackChannelId> {
+        let account = self
+            .user_socials_repo
+            .find_by_social_chat_id(chat_id, &SocialType::Telegram)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, chat_id = chat_id.0, "Failed to resolve user for Slack mirror");
+                None
+            });
+
+        if let Some(account) = account {
+            return match self
+                .user_slack_accounts_repo
+                .find_by_user_id(&account.user_id)
+                .await
+            {
+                Ok(slack_account) => slack_account.map(|a| a.slack_user_id).into_iter().collect(),
+                Err(e) => {
+                    tracing::warn!(error = %e, chat_id = chat_id.0, "Failed to load Slack account for Slack mirror");
+                    Vec::new()
+                }
+            };
+        }
+
+        let repositories = match self.repository_repo.find_by_social_chat_id(chat_id).await {
+            Ok(repositories) => repositories,
+            Err(e) => {
+                tracing::warn!(error = %e, chat_id = chat_id.0, "Failed to load repositories for Slack mirror");
+                return Vec::new();
+            }
+        };
+
+        let mut destinations: Vec<SlackChannelId> = Vec::new();
+
+        for channel_id in repositories.into_iter().filter_map(|r| r.slack_channel_id) {
+            if !destinations.contains(&channel_id) {
+                destinations.push(channel_id);
+            }
+        }
+
+        destinations
+    }
+
+    /// Ошибки Slack только логируются — доставка в Telegram от них не зависит
+    async fn mirror_to_slack(
+        &self,
+        chat_id: &SocialChatId,
+        message_id: &SocialMessageId,
+        message: &MessageBuilder,
+    ) {
+        let Some(slack) = &self.slack else {
+            return;
+        };
+
+        for channel_id in self.resolve_slack_destinations(chat_id).await {
+            let message_ref = match slack.send_message(&channel_id, message).await {
+                Ok(message_ref) => message_ref,
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        chat_id = chat_id.0,
+                        channel_id = %channel_id.0,
+                        "Failed to mirror notification to Slack"
+                    );
+                    continue;
+                }
+            };
+
+            if let Err(e) = self
+                .slack_message_repo
+                .create(&SlackMessage {
+                    mirrored: Some((*chat_id, *message_id)),
+                    message_ref,
+                })
+                .await
+            {
+                tracing::warn!(error = %e, chat_id = chat_id.0, "Failed to store Slack mirror");
+            }
         }
     }
 }
@@ -29,13 +138,22 @@ impl NotificationService for CompositionNotificationService {
         social_type: &SocialType,
         chat_id: &SocialChatId,
         message: &MessageBuilder,
-    ) -> Result<(), NotificationServiceSendError> {
+    ) -> Result<SocialMessageId, NotificationServiceSendError> {
         match social_type {
             SocialType::Telegram => {
-                self.telegram
+                let message_id = self
+                    .telegram
                     .send_message(social_type, chat_id, message)
-                    .await
+                    .await?;
+
+                self.mirror_to_slack(chat_id, &message_id, message).await;
+
+                Ok(message_id)
             }
+            // Slack получает только дубли Telegram-уведомлений — см. `mirror_to_slack`
+            SocialType::Slack => Err(NotificationServiceSendError::UnsupportedSocialType(
+                social_type.to_string(),
+            )),
         }
     }
 
@@ -51,6 +169,11 @@ impl NotificationService for CompositionNotificationService {
                     .delete_message(social_type, chat_id, message_id)
                     .await
             }
+            SocialType::Slack => Err(
+                NotificationServiceDeleteMessageError::UnsupportedSocialType(
+                    social_type.to_string(),
+                ),
+            ),
         }
     }
 
@@ -67,6 +190,9 @@ impl NotificationService for CompositionNotificationService {
                     .edit_message(social_type, chat_id, message_id, message)
                     .await
             }
+            SocialType::Slack => Err(NotificationServiceEditMessageError::UnsupportedSocialType(
+                social_type.to_string(),
+            )),
         }
     }
 }
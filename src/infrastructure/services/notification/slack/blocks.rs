@@ -0,0 +1,166 @@
+use crate::utils::builder::message::MessageBuilder;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde_json::{Value, json};
+
+/// Лимит текста в section-блоке Block Kit
+const SECTION_TEXT_LIMIT: usize = 3000;
+
+/// Лимит блоков в одном сообщении
+const MAX_BLOCKS: usize = 50;
+
+static PRE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)<pre>(?:<code[^>]*>)?(.*?)(?:</code>)?</pre>").unwrap());
+
+static CODE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)<code>(.*?)</code>").unwrap());
+
+static BOLD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"</?(?:b|strong)>").unwrap());
+
+static ITALIC_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"</?(?:i|em)>").unwrap());
+
+static STRIKE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"</?(?:s|strike|del)>").unwrap());
+
+static STRIP_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"</?(?:u|ins|span|tg-spoiler|blockquote|tg-emoji)(?:\s[^>]*)?>").unwrap()
+});
+
+static LINK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?s)<a href="([^"]*)">(.*?)</a>"#).unwrap());
+
+/// Сообщение для `chat.postMessage`/`chat.update`: `text` — запасной текст
+/// для уведомлений и клиентов без Block Kit
+pub struct SlackMessagePayload {
+    pub text: String,
+    pub blocks: Vec<Value>,
+}
+
+impl SlackMessagePayload {
+    pub fn from_message(message: &MessageBuilder) -> Self {
+        let mrkdwn = html_to_mrkdwn(&message.parts().concat());
+
+        let blocks = mrkdwn
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|paragraph| !paragraph.is_empty())
+            .flat_map(split_to_limit)
+            .take(MAX_BLOCKS)
+            .map(|text| {
+                json!({
+                    "type": "section",
+                    "text": { "type": "mrkdwn", "text": text },
+                })
+            })
+            .collect();
+
+        Self {
+            text: mrkdwn.trim().chars().take(SECTION_TEXT_LIMIT).collect(),
+            blocks,
+        }
+    }
+}
+
+/// Telegram-HTML из `MessageBuilder` в mrkdwn. `&amp;`, `&lt;` и `&gt;`
+/// Slack ждёт экранированными — их оставляем как есть
+pub fn html_to_mrkdwn(html: &str) -> String {
+    let text = PRE_RE.replace_all(html, "```\n$1\n```");
+    let text = CODE_RE.replace_all(&text, "`$1`");
+    let text = BOLD_RE.replace_all(&text, "*");
+    let text = ITALIC_RE.replace_all(&text, "_");
+    let text = STRIKE_RE.replace_all(&text, "~");
+    let text = STRIP_RE.replace_all(&text, "");
+
+    // Ссылки последними: иначе `<url|text>` зацепят правила выше
+    let text = LINK_RE.replace_all(&text, |caps: &Captures| {
+        let url = &caps[1];
+        let label = &caps[2];
+
+        if url == "#" {
+            label.to_string()
+        } else {
+            format!("<{}|{}>", url, label)
+        }
+    });
+
+    text.replace("&quot;", "\"").replace("&#x27;", "'")
+}
+
+/// Режет абзац по строкам на куски не длиннее лимита section-блока
+fn split_to_limit(paragraph: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for line in paragraph.lines() {
+        let line: String = line.chars().take(SECTION_TEXT_LIMIT).collect();
+        let needed = current.chars().count() + line.chars().count() + 1;
+
+        if !current.is_empty() && needed > SECTION_TEXT_LIMIT {
+            chunks.push(std::mem::take(&mut current));
+        }
+
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(&line);
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_formatting_to_mrkdwn() {
+        assert_eq!(
+            html_to_mrkdwn("<b>PR</b> <i>opened</i> <s>old</s> <u>new</u>"),
+            "*PR* _opened_ ~old~ new"
+        );
+        assert_eq!(html_to_mrkdwn("<code>main</code>"), "`main`");
+        assert_eq!(
+            html_to_mrkdwn("<pre><code class=\"language-rust\">fn main() {}</code></pre>"),
+            "```\nfn main() {}\n```"
+        );
+    }
+
+    #[test]
+    fn converts_links_and_unescapes_quotes() {
+        assert_eq!(
+            html_to_mrkdwn(r#"<a href="https://github.com/o/r/pull/1">#1 &quot;fix&quot;</a>"#),
+            "<https://github.com/o/r/pull/1|#1 \"fix\">"
+        );
+        assert_eq!(html_to_mrkdwn(r##"<a href="#">label</a>"##), "label");
+        assert_eq!(
+            html_to_mrkdwn("a &lt; b &amp;&amp; it&#x27;s"),
+            "a &lt; b &amp;&amp; it's"
+        );
+    }
+
+    #[test]
+    fn split_keeps_short_paragraph_whole() {
+        assert_eq!(split_to_limit("one\ntwo"), vec!["one\ntwo".to_string()]);
+        assert!(split_to_limit("").is_empty());
+    }
+
+    #[test]
+    fn split_breaks_on_lines_within_limit() {
+        let line = "x".repeat(2000);
+        let paragraph = format!("{line}\n{line}\n{line}");
+
+        let chunks = split_to_limit(&paragraph);
+
+        assert_eq!(chunks, vec![line.clone(), line.clone(), line]);
+    }
+
+    #[test]
+    fn split_truncates_overlong_line() {
+        let chunks = split_to_limit(&"й".repeat(SECTION_TEXT_LIMIT + 10));
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].chars().count(), SECTION_TEXT_LIMIT);
+    }
+}
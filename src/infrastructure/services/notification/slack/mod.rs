@@ -0,0 +1,205 @@
+use crate::domain::notification::services::notification_service::NotificationServiceSendError;
+use crate::domain::notification::services::slack_notification_service::SlackNotificationService;
+use crate::domain::user::value_objects::slack_channel_id::SlackChannelId;
+use crate::domain::user::value_objects::slack_message_ref::SlackMessageRef;
+use crate::infrastructure::services::notification::slack::blocks::SlackMessagePayload;
+use crate::utils::builder::message::MessageBuilder;
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+pub mod blocks;
+
+#[derive(Deserialize, Debug)]
+struct SlackApiResponse {
+    ok: bool,
+    error: Option<String>,
+    channel: Option<String>,
+    ts: Option<String>,
+}
+
+/// Адрес Web API, по умолчанию `https://slack.com/api` — в тестах
+/// подменяется локальной заглушкой
+pub struct SlackClientBase(pub String);
+
+pub struct SlackClientToken(pub String);
+
+pub struct SlackNotificationClient {
+    base: SlackClientBase,
+    token: SlackClientToken,
+    client: Client,
+}
+
+impl SlackNotificationClient {
+    pub fn new(base: SlackClientBase, token: SlackClientToken) -> Self {
+        Self {
+            base: SlackClientBase(base.0.trim_end_matches('/').to_string()),
+            token,
+            client: Client::new(),
+        }
+    }
+
+    async fn request(&self, method: &str, body: Value) -> Result<SlackApiResponse, String> {
+        tracing::debug!(method = %method, "Slack API request");
+
+        let resp = self
+            .client
+            .post(format!("{}/{}", self.base.0, method))
+            .bearer_auth(&self.token.0)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = resp.status();
+        let text = resp.text().await.map_err(|e| e.to_string())?;
+
+        tracing::debug!(status = %status, body_len = text.len(), "Slack API response");
+
+        // На 429 Slack отвечает без JSON, ретраи остаются за очередью
+        if !status.is_success() {
+            return Err(format!("Slack API {} {}: {}", method, status, text));
+        }
+
+        let parsed: SlackApiResponse = serde_json::from_str(&text)
+            .map_err(|e| format!("Slack API {} {}: {}", method, status, e))?;
+
+        if !parsed.ok {
+            return Err(format!(
+                "Slack API {}: {}",
+                method,
+                parsed.error.as_deref().unwrap_or("unknown_error")
+            ));
+        }
+
+        Ok(parsed)
+    }
+}
+
+#[async_trait::async_trait]
+impl SlackNotificationService for SlackNotificationClient {
+    async fn send_message(
+        &self,
+        channel_id: &SlackChannelId,
+        message: &MessageBuilder,
+    ) -> Result<SlackMessageRef, NotificationServiceSendError> {
+        let payload = SlackMessagePayload::from_message(message);
+
+        let response = self
+            .request(
+                "chat.postMessage",
+                json!({
+                    "channel": channel_id.0,
+                    "text": payload.text,
+                    "blocks": payload.blocks,
+                    "unfurl_links": false,
+                }),
+            )
+            .await
+            .map_err(|e| {
+                tracing::error!(
+                    error = %e,
+                    channel_id = %channel_id.0,
+                    "Failed to send Slack notification"
+                );
+                NotificationServiceSendError::Transport(e)
+            })?;
+
+        let ts = response.ts.ok_or_else(|| {
+            NotificationServiceSendError::Transport("Slack API chat.postMessage: no ts".to_string())
+        })?;
+
+        tracing::debug!(channel_id = %channel_id.0, "Slack notification sent");
+
+        Ok(SlackMessageRef {
+            channel_id: response
+                .channel
+                .map(SlackChannelId)
+                .unwrap_or_else(|| channel_id.clone()),
+            ts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::{Path, State};
+    use axum::http::HeaderMap;
+    use axum::http::header::AUTHORIZATION;
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use std::sync::{Arc, Mutex};
+
+    /// Метод, заголовок `Authorization` и тело запроса к заглушке
+    type Requests = Arc<Mutex<Vec<(String, Option<String>, Value)>>>;
+
+    async fn slack_stub(
+        State(requests): State<Requests>,
+        Path(method): Path<String>,
+        headers: HeaderMap,
+        Json(body): Json<Value>,
+    ) -> Json<Value> {
+        let auth = headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        requests.lock().unwrap().push((method.clone(), auth, body));
+
+        Json(match method.as_str() {
+            "chat.postMessage" => {
+                json!({ "ok": true, "channel": "D42", "ts": "1700000000.000100" })
+            }
+            _ => json!({ "ok": false, "error": "message_not_found" }),
+        })
+    }
+
+    /// Поднимает заглушку Web API на свободном порту — её адрес идёт в `SLACK_API_BASE`
+    async fn start_stub() -> (SlackNotificationClient, Requests) {
+        let requests = Requests::default();
+        let app = Router::new()
+            .route("/{method}", post(slack_stub))
+            .with_state(requests.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}/", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = SlackNotificationClient::new(
+            SlackClientBase(base),
+            SlackClientToken("xoxb-test".to_string()),
+        );
+
+        (client, requests)
+    }
+
+    #[tokio::test]
+    async fn send_message_returns_conversation_ref() {
+        let (client, requests) = start_stub().await;
+
+        let message_ref = client
+            .send_message(
+                &SlackChannelId("U42".to_string()),
+                &MessageBuilder::new().bold("PR").line("opened"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            message_ref,
+            SlackMessageRef {
+                channel_id: SlackChannelId("D42".to_string()),
+                ts: "1700000000.000100".to_string(),
+            }
+        );
+
+        let requests = requests.lock().unwrap();
+        let (method, auth, body) = &requests[0];
+        assert_eq!(method, "chat.postMessage");
+        assert_eq!(auth.as_deref(), Some("Bearer xoxb-test"));
+        assert_eq!(body["channel"], "U42");
+        assert_eq!(body["blocks"][0]["text"]["type"], "mrkdwn");
+        assert!(body["text"].as_str().unwrap().starts_with("*PR*"));
+    }
+}
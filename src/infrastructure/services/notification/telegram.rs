@@ -28,14 +28,15 @@ impl NotificationService for TelegramNotificationClient {
         social_type: &SocialType,
         chat_id: &SocialChatId,
         message: &MessageBuilder,
-    ) -> Result<(), NotificationServiceSendError> {
+    ) -> Result<SocialMessageId, NotificationServiceSendError> {
         if *social_type != SocialType::Telegram {
             return Err(NotificationServiceSendError::UnsupportedSocialType(
                 social_type.to_string(),
             ));
         }
 
-        self.bot
+        let sent = self
+            .bot
             .send_message(ChatId(chat_id.0), message.to_string())
             .parse_mode(ParseMode::Html)
             .await
@@ -50,7 +51,7 @@ impl NotificationService for TelegramNotificationClient {
 
         tracing::debug!(chat_id = chat_id.0, "Telegram notification sent");
 
-        Ok(())
+        Ok(SocialMessageId(sent.id.0))
     }
 
     async fn delete_message(
@@ -102,6 +103,7 @@ impl NotificationService for TelegramNotificationClient {
                 MessageId(message_id.0),
                 message.to_string(),
             )
+            .parse_mode(ParseMode::Html)
             .await
             .map_err(|e| {
                 tracing::error!(
@@ -0,0 +1,56 @@
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::ports::task_tracker_client_factory::{
+    TaskTrackerClientFactory, TaskTrackerCredentials,
+};
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::infrastructure::integrations::task_tracker::github_projects::{
+    GithubProjectsClient, GithubProjectsClientBase, GithubProjectsClientToken,
+};
+use crate::infrastructure::integrations::task_tracker::jira::{
+    JiraClient, JiraClientBase, JiraClientCredentials,
+};
+use crate::infrastructure::integrations::task_tracker::kaiten::{
+    KaitenClient, KaitenClientBase, KaitenClientToken,
+};
+use crate::infrastructure::integrations::task_tracker::linear::{
+    LinearClient, LinearClientBase, LinearClientToken,
+};
+use crate::infrastructure::integrations::task_tracker::youtrack::{
+    YouTrackClient, YouTrackClientBase, YouTrackClientToken,
+};
+use std::sync::Arc;
+
+pub struct HttpTaskTrackerClientFactory;
+
+impl TaskTrackerClientFactory for HttpTaskTrackerClientFactory {
+    fn build(&self, credentials: &TaskTrackerCredentials) -> Arc<dyn TaskTrackerClient> {
+        let base = credentials.base_url.clone();
+        let api_token = credentials.api_token.clone();
+
+        match credentials.kind {
+            TaskTrackerKind::Kaiten => Arc::new(KaitenClient::new(
+                KaitenClientBase(base),
+                KaitenClientToken(api_token),
+            )),
+            TaskTrackerKind::Jira => Arc::new(JiraClient::new(
+                JiraClientBase(base),
+                JiraClientCredentials {
+                    email: credentials.email.clone().unwrap_or_default(),
+                    api_token,
+                },
+            )),
+            TaskTrackerKind::Linear => Arc::new(LinearClient::new(
+                LinearClientBase(base),
+                LinearClientToken(api_token),
+            )),
+            TaskTrackerKind::YouTrack => Arc::new(YouTrackClient::new(
+                YouTrackClientBase(base),
+                YouTrackClientToken(api_token),
+            )),
+            TaskTrackerKind::GithubProjects => Arc::new(GithubProjectsClient::new(
+                GithubProjectsClientBase(base),
+                GithubProjectsClientToken(api_token),
+            )),
+        }
+    }
+}
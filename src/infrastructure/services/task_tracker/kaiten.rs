@@ -2,6 +2,9 @@ use crate::domain::task::services::task_tracker_service::TaskTrackerService;
 use crate::domain::task::value_objects::task_id::TaskId;
 use regex::Regex;
 
+/// Длина части имени ветки, взятой из заголовка задачи
+const BRANCH_SLUG_MAX_LEN: usize = 40;
+
 pub struct KaitenTaskTrackerService {
     extract_pattern: String,
 }
@@ -19,8 +22,7 @@ impl TaskTrackerService for KaitenTaskTrackerService {
         regex
             .captures(text)
             .and_then(|caps| caps.get(1))
-            .and_then(|m| m.as_str().parse::<u64>().ok())
-            .map(TaskId)
+            .map(|m| TaskId(m.as_str().to_string()))
     }
 
     fn extract_task_match(&self, text: &str) -> Option<(String, TaskId)> {
@@ -28,7 +30,7 @@ impl TaskTrackerService for KaitenTaskTrackerService {
 
         regex.captures(text).and_then(|caps| {
             let full_match = caps.get(0)?.as_str().to_string();
-            let task_id = caps.get(1)?.as_str().parse::<u64>().ok().map(TaskId)?;
+            let task_id = TaskId(caps.get(1)?.as_str().to_string());
             Some((full_match, task_id))
         })
     }
@@ -37,7 +39,7 @@ impl TaskTrackerService for KaitenTaskTrackerService {
         let regex = Regex::new(pattern).ok()?;
         regex.captures(text).and_then(|caps| {
             let full_match = caps.get(0)?.as_str().to_string();
-            let task_id = caps.get(1)?.as_str().parse::<u64>().ok().map(TaskId)?;
+            let task_id = TaskId(caps.get(1)?.as_str().to_string());
             Some((full_match, task_id))
         })
     }
@@ -51,9 +53,149 @@ impl TaskTrackerService for KaitenTaskTrackerService {
             .captures_iter(text)
             .filter_map(|caps| {
                 let full_match = caps.get(0)?.as_str().to_string();
-                let task_id = caps.get(1)?.as_str().parse::<u64>().ok().map(TaskId)?;
+                let task_id = TaskId(caps.get(1)?.as_str().to_string());
                 Some((full_match, task_id))
             })
             .collect()
     }
+
+    /// Перебирает варианты записи id: с литеральным префиксом паттерна (`ZB-`), как есть и
+    /// через `#`, — и берёт первый, который паттерн разбирает обратно в тот же id
+    fn suggest_branch_name(&self, task_id: &TaskId, title: &str, pattern: &str) -> Option<String> {
+        let slug = branch_slug(title);
+        // Паттерн, привязанный к началу строки, не найдёт id после `feature/`
+        let kind = if pattern.starts_with('^') {
+            ""
+        } else {
+            "feature/"
+        };
+
+        let mut fragments: Vec<String> = Vec::new();
+        if let Some(prefix) = literal_prefix(pattern) {
+            fragments.push(format!("{}{}", prefix, task_id.0));
+        }
+        fragments.push(task_id.0.clone());
+        fragments.push(format!("#{}", task_id.0));
+
+        fragments.into_iter().find_map(|fragment| {
+            let branch = if slug.is_empty() {
+                format!("{}{}", kind, fragment)
+            } else {
+                format!("{}{}-{}", kind, fragment, slug)
+            };
+
+            let (_, extracted) = self.extract_match_with_pattern(&branch, pattern)?;
+            (extracted == *task_id).then_some(branch)
+        })
+    }
+}
+
+/// Текст паттерна до первой группы: `\bZB-(\d+)` → `ZB-`. `None`, если до группы есть
+/// что-то кроме литералов и `\b` / `^`
+fn literal_prefix(pattern: &str) -> Option<String> {
+    let mut prefix = String::new();
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => return Some(prefix),
+            '^' => {}
+            '\\' => match chars.next()? {
+                'b' => {}
+                escaped if escaped.is_ascii_punctuation() => prefix.push(escaped),
+                _ => return None,
+            },
+            '[' | ']' | '{' | '}' | '*' | '+' | '?' | '|' | '.' | ')' | '$' => return None,
+            c => prefix.push(c),
+        }
+    }
+
+    None
+}
+
+/// Латиница и цифры из заголовка через дефис; кириллица в имя ветки не попадает
+fn branch_slug(title: &str) -> String {
+    let mut slug = String::new();
+
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    let mut slug: String = slug.chars().take(BRANCH_SLUG_MAX_LEN).collect();
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggest(pattern: &str, task_id: &str, title: &str) -> Option<String> {
+        KaitenTaskTrackerService::new(pattern.to_string()).suggest_branch_name(
+            &TaskId(task_id.to_string()),
+            title,
+            pattern,
+        )
+    }
+
+    #[test]
+    fn literal_prefix_stops_at_first_group() {
+        assert_eq!(literal_prefix(r"\bZB-(\d+)\b").as_deref(), Some("ZB-"));
+        assert_eq!(literal_prefix(r"^(PROJ-\d+)").as_deref(), Some(""));
+        assert_eq!(literal_prefix(r"#(\d+)").as_deref(), Some("#"));
+        assert_eq!(literal_prefix(r"task\.(\d+)").as_deref(), Some("task."));
+    }
+
+    #[test]
+    fn literal_prefix_rejects_non_literals() {
+        assert_eq!(literal_prefix(r"[A-Z]+-(\d+)"), None);
+        assert_eq!(literal_prefix(r"ZB-\d+"), None);
+        assert_eq!(literal_prefix(r"ZB|KB-(\d+)"), None);
+        assert_eq!(literal_prefix("ZB-"), None);
+    }
+
+    #[test]
+    fn suggest_branch_name_uses_pattern_prefix() {
+        assert_eq!(
+            suggest(r"\bZB-(\d+)\b", "123", "Fix login page!").as_deref(),
+            Some("feature/ZB-123-fix-login-page")
+        );
+        assert_eq!(
+            suggest(r"#(\d+)", "42", "Bug").as_deref(),
+            Some("feature/#42-bug")
+        );
+    }
+
+    #[test]
+    fn suggest_branch_name_handles_anchors_and_empty_slug() {
+        assert_eq!(
+            suggest(r"^ZB-(\d+)", "5", "Add X").as_deref(),
+            Some("ZB-5-add-x")
+        );
+        assert_eq!(
+            suggest(r"\b(PROJ-\d+)\b", "PROJ-7", "Починить вход").as_deref(),
+            Some("feature/PROJ-7")
+        );
+    }
+
+    #[test]
+    fn suggest_branch_name_gives_up_when_pattern_cannot_match() {
+        assert_eq!(suggest(r"\bZB-(\d+)\b", "abc", "Title"), None);
+        assert_eq!(suggest(r"(", "1", "Title"), None);
+    }
+
+    #[test]
+    fn branch_slug_is_truncated_without_trailing_dash() {
+        let slug = branch_slug(&"word ".repeat(20));
+
+        assert!(slug.len() <= BRANCH_SLUG_MAX_LEN);
+        assert!(!slug.ends_with('-'));
+    }
 }
@@ -1 +1,53 @@
+pub mod factory;
 pub mod kaiten;
+pub mod resolver;
+
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::ports::task_tracker_client_provider::TaskTrackerClientProvider;
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use std::sync::Arc;
+
+pub struct CompositionTaskTrackerClientProvider {
+    kaiten: Arc<dyn TaskTrackerClient>,
+    jira: Option<Arc<dyn TaskTrackerClient>>,
+    linear: Option<Arc<dyn TaskTrackerClient>>,
+    youtrack: Option<Arc<dyn TaskTrackerClient>>,
+    github_projects: Option<Arc<dyn TaskTrackerClient>>,
+}
+
+impl CompositionTaskTrackerClientProvider {
+    pub fn new(
+        kaiten: Arc<dyn TaskTrackerClient>,
+        jira: Option<Arc<dyn TaskTrackerClient>>,
+        linear: Option<Arc<dyn TaskTrackerClient>>,
+        youtrack: Option<Arc<dyn TaskTrackerClient>>,
+        github_projects: Option<Arc<dyn TaskTrackerClient>>,
+    ) -> Self {
+        Self {
+            kaiten,
+            jira,
+            linear,
+            youtrack,
+            github_projects,
+        }
+    }
+}
+
+impl TaskTrackerClientProvider for CompositionTaskTrackerClientProvider {
+    fn client(&self, kind: &TaskTrackerKind) -> Option<Arc<dyn TaskTrackerClient>> {
+        match kind {
+            TaskTrackerKind::Kaiten => Some(self.kaiten.clone()),
+            TaskTrackerKind::Jira => self.jira.clone(),
+            TaskTrackerKind::Linear => self.linear.clone(),
+            TaskTrackerKind::YouTrack => self.youtrack.clone(),
+            TaskTrackerKind::GithubProjects => self.github_projects.clone(),
+        }
+    }
+
+    fn kinds(&self) -> Vec<TaskTrackerKind> {
+        TaskTrackerKind::ALL
+            .into_iter()
+            .filter(|kind| self.client(kind).is_some())
+            .collect()
+    }
+}
@@ -0,0 +1,108 @@
+use crate::domain::task::ports::task_tracker_client::TaskTrackerClient;
+use crate::domain::task::ports::task_tracker_client_factory::{
+    TaskTrackerClientFactory, TaskTrackerCredentials,
+};
+use crate::domain::task::ports::task_tracker_client_provider::TaskTrackerClientProvider;
+use crate::domain::task::ports::task_tracker_client_resolver::{
+    TaskTrackerClientResolveError, TaskTrackerClientResolver, TaskTrackerSource,
+};
+use crate::domain::task::repositories::task_tracker_connection_repository::{
+    FindTaskTrackerConnectionError, TaskTrackerConnectionRepository,
+};
+use crate::domain::task::value_objects::task_tracker_kind::TaskTrackerKind;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Подключение из БД → клиент трекера из окружения
+pub struct CompositionTaskTrackerClientResolver {
+    task_tracker_clients: Arc<dyn TaskTrackerClientProvider>,
+    task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>,
+    task_tracker_client_factory: Arc<dyn TaskTrackerClientFactory>,
+    reversible_cipher: Arc<ReversibleCipher>,
+}
+
+impl CompositionTaskTrackerClientResolver {
+    pub fn new(
+        task_tracker_clients: Arc<dyn TaskTrackerClientProvider>,
+        task_tracker_connection_repo: Arc<dyn TaskTrackerConnectionRepository>,
+        task_tracker_client_factory: Arc<dyn TaskTrackerClientFactory>,
+        reversible_cipher: Arc<ReversibleCipher>,
+    ) -> Self {
+        Self {
+            task_tracker_clients,
+            task_tracker_connection_repo,
+            task_tracker_client_factory,
+            reversible_cipher,
+        }
+    }
+}
+
+#[async_trait]
+impl TaskTrackerClientResolver for CompositionTaskTrackerClientResolver {
+    async fn resolve(
+        &self,
+        kind: TaskTrackerKind,
+        connection_id: Option<i32>,
+    ) -> Result<Arc<dyn TaskTrackerClient>, TaskTrackerClientResolveError> {
+        let Some(connection_id) = connection_id else {
+            return self
+                .task_tracker_clients
+                .client(&kind)
+                .ok_or(TaskTrackerClientResolveError::NotConfigured(kind));
+        };
+
+        let connection = self
+            .task_tracker_connection_repo
+            .find_by_id(connection_id)
+            .await
+            .map_err(|e| match e {
+                FindTaskTrackerConnectionError::NotFound => {
+                    TaskTrackerClientResolveError::ConnectionNotFound(connection_id)
+                }
+                FindTaskTrackerConnectionError::DbError(msg) => {
+                    TaskTrackerClientResolveError::ClientError(msg)
+                }
+            })?;
+
+        let api_token = self
+            .reversible_cipher
+            .decrypt(connection.api_token.value())
+            .map_err(|e| TaskTrackerClientResolveError::ClientError(e.to_string()))?;
+
+        Ok(self
+            .task_tracker_client_factory
+            .build(&TaskTrackerCredentials {
+                kind: connection.kind,
+                base_url: connection.base_url,
+                email: connection.email,
+                api_token,
+            }))
+    }
+
+    async fn sources(&self) -> Result<Vec<TaskTrackerSource>, TaskTrackerClientResolveError> {
+        let connections = self
+            .task_tracker_connection_repo
+            .find_all()
+            .await
+            .map_err(|e| TaskTrackerClientResolveError::ClientError(e.to_string()))?;
+
+        let env = self
+            .task_tracker_clients
+            .kinds()
+            .into_iter()
+            .map(|kind| TaskTrackerSource {
+                kind,
+                connection_id: None,
+                name: None,
+            });
+
+        let stored = connections.into_iter().map(|c| TaskTrackerSource {
+            kind: c.kind,
+            connection_id: Some(c.id),
+            name: Some(c.name),
+        });
+
+        Ok(env.chain(stored).collect())
+    }
+}
@@ -0,0 +1,50 @@
+pub mod token_provider;
+
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client::VersionControlClient;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use std::sync::Arc;
+
+pub struct CompositionVersionControlClientProvider {
+    github: Arc<dyn VersionControlClient>,
+    gitea: Arc<dyn VersionControlClient>,
+    gitea_token: String,
+    gitlab: Arc<dyn VersionControlClient>,
+    gitlab_token: String,
+}
+
+impl CompositionVersionControlClientProvider {
+    pub fn new(
+        github: Arc<dyn VersionControlClient>,
+        gitea: Arc<dyn VersionControlClient>,
+        gitea_token: String,
+        gitlab: Arc<dyn VersionControlClient>,
+        gitlab_token: String,
+    ) -> Self {
+        Self {
+            github,
+            gitea,
+            gitea_token,
+            gitlab,
+            gitlab_token,
+        }
+    }
+}
+
+impl VersionControlClientProvider for CompositionVersionControlClientProvider {
+    fn client(&self, version_control_type: &VersionControlType) -> Arc<dyn VersionControlClient> {
+        match version_control_type {
+            VersionControlType::Github => self.github.clone(),
+            VersionControlType::Gitea => self.gitea.clone(),
+            VersionControlType::Gitlab => self.gitlab.clone(),
+        }
+    }
+
+    fn service_token(&self, version_control_type: &VersionControlType) -> Option<String> {
+        match version_control_type {
+            VersionControlType::Github => None,
+            VersionControlType::Gitea => Some(self.gitea_token.clone()).filter(|t| !t.is_empty()),
+            VersionControlType::Gitlab => Some(self.gitlab_token.clone()).filter(|t| !t.is_empty()),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::domain::repository::entities::repository::Repository;
+use crate::domain::role::value_objects::role_name::RoleName;
+use crate::domain::user::repositories::user_has_roles_repository::UserHasRolesRepository;
+use crate::domain::user::repositories::user_vc_accounts_repository::UserVersionControlAccountsRepository;
+use crate::domain::user::value_objects::version_control_type::VersionControlType;
+use crate::domain::version_control::ports::version_control_client_provider::VersionControlClientProvider;
+use crate::domain::version_control::ports::version_control_token_provider::VersionControlTokenProvider;
+use crate::infrastructure::integrations::version_control::github::app::GithubAppClient;
+use crate::utils::security::crypto::reversible::ReversibleCipher;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Сервисный токен провайдера → токен установки GitHub App → OAuth-токен администратора
+pub struct CompositionVersionControlTokenProvider {
+    version_control_clients: Arc<dyn VersionControlClientProvider>,
+    github_app: Option<Arc<GithubAppClient>>,
+    user_has_roles_repo: Arc<dyn UserHasRolesRepository>,
+    user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+    reversible_cipher: Arc<ReversibleCipher>,
+}
+
+impl CompositionVersionControlTokenProvider {
+    pub fn new(
+        version_control_clients: Arc<dyn VersionControlClientProvider>,
+        github_app: Option<Arc<GithubAppClient>>,
+        user_has_roles_repo: Arc<dyn UserHasRolesRepository>,
+        user_vc_accounts_repo: Arc<dyn UserVersionControlAccountsRepository>,
+        reversible_cipher: Arc<ReversibleCipher>,
+    ) -> Self {
+        Self {
+            version_control_clients,
+            github_app,
+            user_has_roles_repo,
+            user_vc_accounts_repo,
+            reversible_cipher,
+        }
+    }
+
+    /// Токен первого администратора с аккаунтом того же провайдера, что и
+    /// репозиторий — токен GitHub нельзя отдавать стороннему хосту
+    async fn admin_token(&self, version_control_type: &VersionControlType) -> Option<String> {
+        let admin_ids = self
+            .user_has_roles_repo
+            .find_user_ids_by_role(RoleName::Admin)
+            .await
+            .ok()?;
+        for user_id in admin_ids {
+            if let Ok(vc) = self
+                .user_vc_accounts_repo
+                .find_by_user_id(version_control_type, &user_id)
+                .await
+                && !vc.is_token_expired()
+                && let Ok(token) = self.reversible_cipher.decrypt(vc.access_token.value())
+            {
+                return Some(token);
+            }
+        }
+        None
+    }
+}
+
+#[async_trait]
+impl VersionControlTokenProvider for CompositionVersionControlTokenProvider {
+    async fn service_token(&self, repository: &Repository) -> Option<String> {
+        if let Some(token) = self
+            .version_control_clients
+            .service_token(&repository.version_control_type)
+        {
+            return Some(token);
+        }
+
+        if repository.version_control_type != VersionControlType::Github {
+            return None;
+        }
+
+        let app = self.github_app.as_ref()?;
+
+        match app
+            .repository_token(&repository.owner, &repository.name)
+            .await
+        {
+            Ok(token) => Some(token),
+            Err(e) => {
+                tracing::warn!(
+                    repo = %format!("{}/{}", repository.owner, repository.name),
+                    error = %e,
+                    "Failed to get GitHub App installation token"
+                );
+                None
+            }
+        }
+    }
+
+    async fn background_token(&self, repository: &Repository) -> Option<String> {
+        match self.service_token(repository).await {
+            Some(token) => Some(token),
+            None => self.admin_token(&repository.version_control_type).await,
+        }
+    }
+}
@@ -149,6 +149,11 @@ impl MessageBuilder {
         self.parts.push(text.to_string());
         self
     }
+
+    /// Части сообщения в Telegram-HTML — для каналов с другой разметкой
+    pub fn parts(&self) -> &[String] {
+        &self.parts
+    }
 }
 
 impl fmt::Display for MessageBuilder {
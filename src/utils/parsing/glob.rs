@@ -0,0 +1,70 @@
+/// Простое сопоставление с glob-шаблоном: `*` — любая последовательность
+/// символов (в т.ч. `/`), `?` — ровно один символ. Регистр учитывается.
+///
+/// `release/*` совпадёт с `release/1.2` и `release/1.2/hotfix`.
+pub fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Позиция последней `*` в шаблоне и позиция в тексте, с которой она начала совпадать
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_matches_any_sequence_including_slashes() {
+        assert!(glob_matches("release/*", "release/1.2"));
+        assert!(glob_matches("release/*", "release/1.2/hotfix"));
+        assert!(glob_matches("release/*", "release/"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("release/*", "feature/release/1.2"));
+    }
+
+    #[test]
+    fn star_backtracks_to_find_a_later_match() {
+        assert!(glob_matches("v*-rc*", "v1.2-beta-rc3"));
+        assert!(glob_matches("*fix", "hotfix-fix"));
+        assert!(!glob_matches("*fix", "hotfix-fixed"));
+    }
+
+    #[test]
+    fn question_mark_matches_exactly_one_char() {
+        assert!(glob_matches("v?", "v1"));
+        assert!(!glob_matches("v?", "v"));
+        assert!(!glob_matches("v?", "v12"));
+    }
+
+    #[test]
+    fn literal_pattern_is_case_sensitive() {
+        assert!(glob_matches("main", "main"));
+        assert!(!glob_matches("main", "Main"));
+        assert!(!glob_matches("main", "main2"));
+    }
+}
@@ -1 +1,2 @@
+pub mod constant_time;
 pub mod crypto;